@@ -0,0 +1,534 @@
+//! Typed interpolation and spring-driven animations for compound values
+//!
+//! `AnimatedValue` springs a single `f32`. For values made of several
+//! components (a `Rect`, a full `Transform`) animating each component with its
+//! own spring lets them drift out of sync. Instead, [`Animated<T>`] drives a
+//! single 0→1 progress spring and blends the whole value through the
+//! [`Interpolate`] trait, so every component moves together.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use blinc_animation::{AnimatedTransform, SpringConfig};
+//! use blinc_core::Transform;
+//!
+//! let mut transform = AnimatedTransform::new(handle, Transform::identity(), SpringConfig::gentle());
+//! transform.set_target(Transform::translate(100.0, 40.0));
+//!
+//! // Each frame:
+//! ctx.push_transform(transform.get());
+//! ```
+
+use blinc_core::{Affine2D, Color, Mat4, Point, Rect, Size, Transform};
+
+use crate::scheduler::{AnimatedValue, SchedulerHandle};
+use crate::spring::SpringConfig;
+
+/// Values that can be blended between a start and end state
+///
+/// `t` is the blend factor: `0.0` yields `self`, `1.0` yields `to`. Values
+/// outside `0.0..=1.0` extrapolate, which lets underdamped springs overshoot.
+pub trait Interpolate: Clone {
+    fn interpolate(&self, to: &Self, t: f32) -> Self;
+}
+
+#[inline]
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Interpolate between two angles (radians) along the shortest arc
+fn lerp_angle(a: f32, b: f32, t: f32) -> f32 {
+    use std::f32::consts::{PI, TAU};
+    let mut delta = (b - a) % TAU;
+    if delta > PI {
+        delta -= TAU;
+    } else if delta < -PI {
+        delta += TAU;
+    }
+    a + delta * t
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        lerp(*self, *to, t)
+    }
+}
+
+impl Interpolate for Point {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        Point::new(lerp(self.x, to.x, t), lerp(self.y, to.y, t))
+    }
+}
+
+impl Interpolate for Size {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        Size::new(
+            lerp(self.width, to.width, t),
+            lerp(self.height, to.height, t),
+        )
+    }
+}
+
+impl Interpolate for Rect {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        Rect::from_origin_size(
+            self.origin.interpolate(&to.origin, t),
+            self.size.interpolate(&to.size, t),
+        )
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        // Colors are clamped: overshooting a channel past 0/1 is never useful
+        Color::lerp(self, to, t)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// 2D affine decomposition
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// An `Affine2D` split into translation, rotation, scale and skew
+///
+/// The matrix is reconstructed as `translate * rotate * skew_x * scale`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DecomposedAffine2D {
+    pub translate: (f32, f32),
+    /// Rotation in radians
+    pub rotation: f32,
+    pub scale: (f32, f32),
+    /// Horizontal shear factor
+    pub skew: f32,
+}
+
+impl DecomposedAffine2D {
+    /// Decompose an affine transform into its components
+    pub fn from_affine(m: &Affine2D) -> Self {
+        let [a, b, c, d, tx, ty] = m.elements;
+        let sx = (a * a + b * b).sqrt();
+        if sx <= f32::EPSILON {
+            // Degenerate (collapsed x axis) - keep translation, zero the rest
+            return Self {
+                translate: (tx, ty),
+                rotation: 0.0,
+                scale: (0.0, (c * c + d * d).sqrt()),
+                skew: 0.0,
+            };
+        }
+        let rotation = b.atan2(a);
+        let sy = (a * d - b * c) / sx;
+        let skew = (a * c + b * d) / (sx * sx);
+        Self {
+            translate: (tx, ty),
+            rotation,
+            scale: (sx, sy),
+            skew,
+        }
+    }
+
+    /// Recompose into an affine transform
+    pub fn to_affine(&self) -> Affine2D {
+        let (cos, sin) = (self.rotation.cos(), self.rotation.sin());
+        let (sx, sy) = self.scale;
+        Affine2D {
+            elements: [
+                sx * cos,
+                sx * sin,
+                cos * sx * self.skew - sin * sy,
+                sin * sx * self.skew + cos * sy,
+                self.translate.0,
+                self.translate.1,
+            ],
+        }
+    }
+}
+
+impl Interpolate for DecomposedAffine2D {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        Self {
+            translate: (
+                lerp(self.translate.0, to.translate.0, t),
+                lerp(self.translate.1, to.translate.1, t),
+            ),
+            rotation: lerp_angle(self.rotation, to.rotation, t),
+            scale: (
+                lerp(self.scale.0, to.scale.0, t),
+                lerp(self.scale.1, to.scale.1, t),
+            ),
+            skew: lerp(self.skew, to.skew, t),
+        }
+    }
+}
+
+impl Interpolate for Affine2D {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        DecomposedAffine2D::from_affine(self)
+            .interpolate(&DecomposedAffine2D::from_affine(to), t)
+            .to_affine()
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// 3D matrix decomposition
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A `Mat4` split into translation, rotation (unit quaternion) and scale
+///
+/// Perspective and shear are discarded; this covers the TRS matrices produced
+/// by layout and scene transforms.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DecomposedMat4 {
+    pub translate: [f32; 3],
+    /// Rotation quaternion `[x, y, z, w]`
+    pub rotation: [f32; 4],
+    pub scale: [f32; 3],
+}
+
+impl DecomposedMat4 {
+    /// Decompose a column-major TRS matrix
+    pub fn from_mat4(m: &Mat4) -> Self {
+        let c = &m.cols;
+        let len = |v: &[f32; 4]| (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+        let scale = [len(&c[0]), len(&c[1]), len(&c[2])];
+        let inv = |s: f32| if s > f32::EPSILON { 1.0 / s } else { 0.0 };
+        let (ix, iy, iz) = (inv(scale[0]), inv(scale[1]), inv(scale[2]));
+
+        // Pure rotation matrix, r[col][row]
+        let r = [
+            [c[0][0] * ix, c[0][1] * ix, c[0][2] * ix],
+            [c[1][0] * iy, c[1][1] * iy, c[1][2] * iy],
+            [c[2][0] * iz, c[2][1] * iz, c[2][2] * iz],
+        ];
+        let (m00, m11, m22) = (r[0][0], r[1][1], r[2][2]);
+        let trace = m00 + m11 + m22;
+        let rotation = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            [
+                (r[1][2] - r[2][1]) / s,
+                (r[2][0] - r[0][2]) / s,
+                (r[0][1] - r[1][0]) / s,
+                0.25 * s,
+            ]
+        } else if m00 > m11 && m00 > m22 {
+            let s = (1.0 + m00 - m11 - m22).sqrt() * 2.0;
+            [
+                0.25 * s,
+                (r[1][0] + r[0][1]) / s,
+                (r[2][0] + r[0][2]) / s,
+                (r[1][2] - r[2][1]) / s,
+            ]
+        } else if m11 > m22 {
+            let s = (1.0 + m11 - m00 - m22).sqrt() * 2.0;
+            [
+                (r[1][0] + r[0][1]) / s,
+                0.25 * s,
+                (r[2][1] + r[1][2]) / s,
+                (r[2][0] - r[0][2]) / s,
+            ]
+        } else {
+            let s = (1.0 + m22 - m00 - m11).sqrt() * 2.0;
+            [
+                (r[2][0] + r[0][2]) / s,
+                (r[2][1] + r[1][2]) / s,
+                0.25 * s,
+                (r[0][1] - r[1][0]) / s,
+            ]
+        };
+
+        Self {
+            translate: [c[3][0], c[3][1], c[3][2]],
+            rotation,
+            scale,
+        }
+    }
+
+    /// Recompose into a column-major matrix
+    pub fn to_mat4(&self) -> Mat4 {
+        let [x, y, z, w] = self.rotation;
+        let [sx, sy, sz] = self.scale;
+        let [tx, ty, tz] = self.translate;
+        Mat4 {
+            cols: [
+                [
+                    (1.0 - 2.0 * (y * y + z * z)) * sx,
+                    2.0 * (x * y + z * w) * sx,
+                    2.0 * (x * z - y * w) * sx,
+                    0.0,
+                ],
+                [
+                    2.0 * (x * y - z * w) * sy,
+                    (1.0 - 2.0 * (x * x + z * z)) * sy,
+                    2.0 * (y * z + x * w) * sy,
+                    0.0,
+                ],
+                [
+                    2.0 * (x * z + y * w) * sz,
+                    2.0 * (y * z - x * w) * sz,
+                    (1.0 - 2.0 * (x * x + y * y)) * sz,
+                    0.0,
+                ],
+                [tx, ty, tz, 1.0],
+            ],
+        }
+    }
+}
+
+/// Spherical interpolation between unit quaternions along the shortest arc
+fn slerp(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+    let mut b = b;
+    let mut dot = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3];
+    // q and -q are the same rotation; flip to take the short way round
+    if dot < 0.0 {
+        b = [-b[0], -b[1], -b[2], -b[3]];
+        dot = -dot;
+    }
+
+    let (wa, wb) = if dot > 0.9995 {
+        // Nearly parallel - fall back to normalized lerp
+        (1.0 - t, t)
+    } else {
+        let theta = dot.clamp(-1.0, 1.0).acos();
+        let sin = theta.sin();
+        (((1.0 - t) * theta).sin() / sin, (t * theta).sin() / sin)
+    };
+
+    let q = [
+        a[0] * wa + b[0] * wb,
+        a[1] * wa + b[1] * wb,
+        a[2] * wa + b[2] * wb,
+        a[3] * wa + b[3] * wb,
+    ];
+    let n = (q[0] * q[0] + q[1] * q[1] + q[2] * q[2] + q[3] * q[3]).sqrt();
+    if n > f32::EPSILON {
+        [q[0] / n, q[1] / n, q[2] / n, q[3] / n]
+    } else {
+        a
+    }
+}
+
+impl Interpolate for DecomposedMat4 {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        let l3 = |a: [f32; 3], b: [f32; 3]| {
+            [
+                lerp(a[0], b[0], t),
+                lerp(a[1], b[1], t),
+                lerp(a[2], b[2], t),
+            ]
+        };
+        Self {
+            translate: l3(self.translate, to.translate),
+            rotation: slerp(self.rotation, to.rotation, t),
+            scale: l3(self.scale, to.scale),
+        }
+    }
+}
+
+impl Interpolate for Mat4 {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        DecomposedMat4::from_mat4(self)
+            .interpolate(&DecomposedMat4::from_mat4(to), t)
+            .to_mat4()
+    }
+}
+
+/// Promote a 2D affine transform into the XY plane of a 4x4 matrix
+fn affine_to_mat4(m: &Affine2D) -> Mat4 {
+    let [a, b, c, d, tx, ty] = m.elements;
+    Mat4 {
+        cols: [
+            [a, b, 0.0, 0.0],
+            [c, d, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [tx, ty, 0.0, 1.0],
+        ],
+    }
+}
+
+impl Interpolate for Transform {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        match (self, to) {
+            (Transform::Affine2D(a), Transform::Affine2D(b)) => {
+                Transform::Affine2D(a.interpolate(b, t))
+            }
+            (Transform::Mat4(a), Transform::Mat4(b)) => Transform::Mat4(a.interpolate(b, t)),
+            // Mixed 2D/3D: lift the 2D side into 3D and blend there
+            (Transform::Affine2D(a), Transform::Mat4(b)) => {
+                Transform::Mat4(affine_to_mat4(a).interpolate(b, t))
+            }
+            (Transform::Mat4(a), Transform::Affine2D(b)) => {
+                Transform::Mat4(a.interpolate(&affine_to_mat4(b), t))
+            }
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Spring-driven typed animation
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A spring animation over any [`Interpolate`] value
+///
+/// A single progress spring drives the blend from the value at the moment the
+/// target last changed to the new target, so compound values (all four edges
+/// of a rect, or translation/rotation/scale of a transform) stay in lockstep.
+/// Retargeting mid-flight starts the next blend from the current on-screen
+/// value, so there is no visual jump.
+#[derive(Clone)]
+pub struct Animated<T: Interpolate> {
+    progress: AnimatedValue,
+    from: T,
+    to: T,
+}
+
+/// Spring animation over a full [`Transform`]
+pub type AnimatedTransform = Animated<Transform>;
+
+/// Spring animation over a [`Rect`] (position and size together)
+pub type AnimatedRect = Animated<Rect>;
+
+impl<T: Interpolate> Animated<T> {
+    /// Create a new typed animation resting at `initial`
+    pub fn new(handle: SchedulerHandle, initial: T, config: SpringConfig) -> Self {
+        Self {
+            progress: AnimatedValue::new(handle, 1.0, config),
+            from: initial.clone(),
+            to: initial,
+        }
+    }
+
+    /// Create with default spring config (stiff)
+    pub fn with_default(handle: SchedulerHandle, initial: T) -> Self {
+        Self::new(handle, initial, SpringConfig::stiff())
+    }
+
+    /// Animate from the current value to `target`
+    pub fn set_target(&mut self, target: T) {
+        self.from = self.get();
+        self.to = target;
+        self.progress.set_immediate(0.0);
+        self.progress.set_target(1.0);
+    }
+
+    /// Animate from `from` to `to`, ignoring the current value
+    pub fn animate(&mut self, from: T, to: T) {
+        self.from = from;
+        self.to = to;
+        self.progress.set_immediate(0.0);
+        self.progress.set_target(1.0);
+    }
+
+    /// Get the current blended value
+    pub fn get(&self) -> T {
+        self.from.interpolate(&self.to, self.progress.get())
+    }
+
+    /// Set value immediately without animation
+    pub fn set_immediate(&mut self, value: T) {
+        self.from = value.clone();
+        self.to = value;
+        self.progress.set_immediate(1.0);
+    }
+
+    /// Check if currently animating
+    pub fn is_animating(&self) -> bool {
+        self.progress.is_animating()
+    }
+
+    /// Get the current target value
+    pub fn target(&self) -> &T {
+        &self.to
+    }
+
+    /// Current blend progress (0.0 at the start, 1.0 when settled)
+    pub fn progress(&self) -> f32 {
+        self.progress.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scheduler::AnimationScheduler;
+
+    fn approx(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    #[test]
+    fn test_transform_blend_translate_and_scale() {
+        let from = Transform::identity();
+        let to = Transform::Affine2D(
+            Affine2D::translation(100.0, 50.0).then(&Affine2D::scale(3.0, 2.0)),
+        );
+
+        let Transform::Affine2D(mid) = from.interpolate(&to, 0.5) else {
+            panic!("2D blend should stay 2D");
+        };
+        let parts = DecomposedAffine2D::from_affine(&mid);
+        assert!(approx(parts.translate.0, 50.0));
+        assert!(approx(parts.translate.1, 25.0));
+        assert!(approx(parts.scale.0, 2.0));
+        assert!(approx(parts.scale.1, 1.5));
+        assert!(approx(parts.rotation, 0.0));
+    }
+
+    #[test]
+    fn test_rotation_takes_shortest_path() {
+        use std::f32::consts::PI;
+        let from = Affine2D::rotation(PI * 0.9);
+        let to = Affine2D::rotation(-PI * 0.9);
+
+        // Shortest arc crosses PI rather than sweeping back through 0
+        let mid = DecomposedAffine2D::from_affine(&from.interpolate(&to, 0.5));
+        assert!(approx(mid.rotation.abs(), PI));
+    }
+
+    #[test]
+    fn test_mat4_round_trip_and_slerp() {
+        let m = Mat4::translation(1.0, 2.0, 3.0)
+            .mul(&Mat4::rotation_y(0.8))
+            .mul(&Mat4::scale(2.0, 2.0, 2.0));
+        let back = DecomposedMat4::from_mat4(&m).to_mat4();
+        for (a, b) in m.cols.iter().flatten().zip(back.cols.iter().flatten()) {
+            assert!(approx(*a, *b));
+        }
+
+        let mid = Mat4::IDENTITY.interpolate(&Mat4::rotation_y(1.0), 0.5);
+        let expected = Mat4::rotation_y(0.5);
+        for (a, b) in mid
+            .cols
+            .iter()
+            .flatten()
+            .zip(expected.cols.iter().flatten())
+        {
+            assert!(approx(*a, *b));
+        }
+    }
+
+    #[test]
+    fn test_rect_blend() {
+        let a = Rect::new(0.0, 0.0, 100.0, 100.0);
+        let b = Rect::new(200.0, 100.0, 50.0, 300.0);
+        assert_eq!(a.interpolate(&b, 0.5), Rect::new(100.0, 50.0, 75.0, 200.0));
+    }
+
+    #[test]
+    fn test_animated_rect_retarget() {
+        let scheduler = AnimationScheduler::new();
+        let start = Rect::new(0.0, 0.0, 10.0, 10.0);
+        let mut rect = AnimatedRect::with_default(scheduler.handle(), start);
+        assert_eq!(rect.get(), start);
+
+        let end = Rect::new(100.0, 100.0, 20.0, 20.0);
+        rect.set_target(end);
+        // Nothing has ticked yet, so we're still at the start
+        assert_eq!(rect.get(), start);
+        assert_eq!(*rect.target(), end);
+
+        rect.set_immediate(end);
+        assert_eq!(rect.get(), end);
+        assert!(!rect.is_animating());
+    }
+}
@@ -4,6 +4,33 @@
 //! keyframe animations for animating multiple properties simultaneously.
 
 use crate::easing::Easing;
+use crate::spring::{Spring, SpringConfig};
+
+/// Simulation step for spring segments, in seconds
+const SPRING_STEP: f32 = 1.0 / 240.0;
+
+/// How a keyframe segment moves from its start value to its end value
+#[derive(Clone, Copy, Debug)]
+pub enum Interpolation {
+    /// Stay at the start value until the next keyframe, then jump
+    Hold,
+    /// Move at constant speed
+    Linear,
+    /// Follow an easing curve
+    Eased(Easing),
+    /// Spring toward the end value, carrying over the velocity the
+    /// previous segment ended with
+    ///
+    /// The spring runs for the segment's duration; if it hasn't settled by
+    /// the next keyframe, the value snaps to that keyframe.
+    Spring(SpringConfig),
+}
+
+impl From<Easing> for Interpolation {
+    fn from(easing: Easing) -> Self {
+        Interpolation::Eased(easing)
+    }
+}
 
 /// A single keyframe in a single-value animation
 #[derive(Clone, Debug)]
@@ -14,6 +41,33 @@ pub struct Keyframe {
     pub value: f32,
     /// Easing function to use when transitioning TO this keyframe
     pub easing: Easing,
+    /// Interpolation for the segment ending at this keyframe, overriding
+    /// `easing` when set
+    pub interpolation: Option<Interpolation>,
+}
+
+impl Keyframe {
+    /// Create a keyframe that eases in with `easing`
+    pub fn new(time: f32, value: f32, easing: Easing) -> Self {
+        Self {
+            time,
+            value,
+            easing,
+            interpolation: None,
+        }
+    }
+
+    /// Builder: set the interpolation for the segment ending here
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = Some(interpolation);
+        self
+    }
+
+    /// The interpolation used to reach this keyframe
+    pub fn segment(&self) -> Interpolation {
+        self.interpolation
+            .unwrap_or(Interpolation::Eased(self.easing))
+    }
 }
 
 /// A keyframe-based animation (single value)
@@ -54,35 +108,71 @@ impl KeyframeAnimation {
 
     /// Get the current interpolated value
     pub fn value(&self) -> f32 {
-        if self.keyframes.is_empty() {
-            return 0.0;
-        }
+        self.sample_at(self.progress())
+    }
 
-        let progress = self.progress().clamp(0.0, 1.0);
+    /// Sample the animation at a specific progress (0.0 to 1.0)
+    pub fn sample_at(&self, progress: f32) -> f32 {
+        let Some(last) = self.keyframes.last() else {
+            return 0.0;
+        };
 
-        // Find surrounding keyframes
-        let mut prev_kf = &self.keyframes[0];
-        let mut next_kf = &self.keyframes[0];
+        let progress = progress.clamp(0.0, 1.0);
 
-        for kf in &self.keyframes {
-            if kf.time <= progress {
-                prev_kf = kf;
-            }
-            if kf.time >= progress {
-                next_kf = kf;
-                break;
+        // First keyframe at or after the progress ends the current segment
+        match self.keyframes.iter().position(|kf| kf.time >= progress) {
+            None => last.value,
+            Some(index) if index == 0 || self.keyframes[index].time <= progress => {
+                self.keyframes[index].value
             }
+            Some(index) => self.segment_state(index, progress).0,
         }
+    }
 
-        if (prev_kf.time - next_kf.time).abs() < f32::EPSILON {
-            return prev_kf.value;
+    /// Value and velocity (per second) within the segment ending at `index`
+    fn segment_state(&self, index: usize, progress: f32) -> (f32, f32) {
+        let (from, to) = (&self.keyframes[index - 1], &self.keyframes[index]);
+        let span = to.time - from.time;
+        let duration = span * self.duration_ms as f32 / 1000.0;
+        if span < f32::EPSILON || duration <= 0.0 {
+            return (to.value, 0.0);
         }
 
-        // Interpolate between keyframes
-        let local_progress = (progress - prev_kf.time) / (next_kf.time - prev_kf.time);
-        let eased = next_kf.easing.apply(local_progress);
-
-        prev_kf.value + (next_kf.value - prev_kf.value) * eased
+        let t = ((progress - from.time) / span).clamp(0.0, 1.0);
+        let delta = to.value - from.value;
+
+        match to.segment() {
+            Interpolation::Hold if t < 1.0 => (from.value, 0.0),
+            Interpolation::Hold => (to.value, 0.0),
+            Interpolation::Linear => (from.value + delta * t, delta / duration),
+            Interpolation::Eased(easing) => {
+                // Central difference, one-sided at the segment ends
+                const H: f32 = 1e-3;
+                let (t0, t1) = ((t - H).max(0.0), (t + H).min(1.0));
+                let slope = (easing.apply(t1) - easing.apply(t0)) / (t1 - t0);
+                (
+                    from.value + delta * easing.apply(t),
+                    slope * delta / duration,
+                )
+            }
+            Interpolation::Spring(config) => {
+                let velocity = if index > 1 {
+                    self.segment_state(index - 1, from.time).1
+                } else {
+                    0.0
+                };
+                let mut spring = Spring::new(config, from.value).with_velocity(velocity);
+                spring.set_target(to.value);
+
+                let mut remaining = t * duration;
+                while remaining > 0.0 {
+                    let dt = remaining.min(SPRING_STEP);
+                    spring.step(dt);
+                    remaining -= dt;
+                }
+                (spring.value(), spring.velocity())
+            }
+        }
     }
 
     /// Advance the animation by delta time (in milliseconds)
@@ -551,6 +641,9 @@ pub struct KeyframePoint {
     pub value: f32,
     /// Easing to use when transitioning TO this point
     pub easing: Easing,
+    /// Interpolation for the segment ending at this point, overriding
+    /// `easing` when set
+    pub interpolation: Option<Interpolation>,
 }
 
 /// Builder for creating keyframe animations with a fluent API
@@ -610,6 +703,7 @@ impl KeyframeTrackBuilder {
             time_ms,
             value,
             easing: self.default_easing,
+            interpolation: None,
         });
         self
     }
@@ -620,6 +714,32 @@ impl KeyframeTrackBuilder {
             time_ms,
             value,
             easing,
+            interpolation: None,
+        });
+        self
+    }
+
+    /// Add a keyframe reached with a specific interpolation mode
+    ///
+    /// ```ignore
+    /// // Blink: hold fully visible, then snap off and spring back
+    /// KeyframeTrack::builder()
+    ///     .at(0, 1.0)
+    ///     .at_with_interpolation(900, 0.0, Interpolation::Hold)
+    ///     .at_with_interpolation(1400, 1.0, Interpolation::Spring(SpringConfig::wobbly()))
+    ///     .build();
+    /// ```
+    pub fn at_with_interpolation(
+        mut self,
+        time_ms: u32,
+        value: f32,
+        interpolation: Interpolation,
+    ) -> Self {
+        self.points.push(KeyframePoint {
+            time_ms,
+            value,
+            easing: self.default_easing,
+            interpolation: Some(interpolation),
         });
         self
     }
@@ -713,6 +833,7 @@ impl KeyframeTrackBuilder {
                     time,
                     value: p.value,
                     easing: p.easing,
+                    interpolation: p.interpolation,
                 }
             })
             .collect();
@@ -789,11 +910,6 @@ impl KeyframeTrack {
 
     /// Get the current value with ping-pong and iteration support
     pub fn value(&self) -> f32 {
-        let keyframes = self.animation.keyframes();
-        if keyframes.is_empty() {
-            return 0.0;
-        }
-
         let progress = self.animation.progress().clamp(0.0, 1.0);
 
         // Apply reverse if in ping-pong mode and on reverse phase
@@ -803,29 +919,7 @@ impl KeyframeTrack {
             progress
         };
 
-        // Find surrounding keyframes
-        let mut prev_kf = &keyframes[0];
-        let mut next_kf = &keyframes[0];
-
-        for kf in keyframes {
-            if kf.time <= effective_progress {
-                prev_kf = kf;
-            }
-            if kf.time >= effective_progress {
-                next_kf = kf;
-                break;
-            }
-        }
-
-        if (prev_kf.time - next_kf.time).abs() < f32::EPSILON {
-            return prev_kf.value;
-        }
-
-        // Interpolate between keyframes
-        let local_progress = (effective_progress - prev_kf.time) / (next_kf.time - prev_kf.time);
-        let eased = next_kf.easing.apply(local_progress);
-
-        prev_kf.value + (next_kf.value - prev_kf.value) * eased
+        self.animation.sample_at(effective_progress)
     }
 
     /// Get the current progress (0.0 to 1.0)
@@ -864,3 +958,77 @@ impl KeyframeTrack {
         self.duration_ms
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hold_segment_steps_at_next_keyframe() {
+        let track = KeyframeTrack::builder()
+            .at(0, 10.0)
+            .at_with_interpolation(500, 20.0, Interpolation::Hold)
+            .at(1000, 30.0)
+            .build();
+        let animation = &track.animation;
+
+        assert_eq!(animation.sample_at(0.0), 10.0);
+        assert_eq!(animation.sample_at(0.25), 10.0);
+        assert_eq!(animation.sample_at(0.4999), 10.0);
+        assert_eq!(animation.sample_at(0.5), 20.0);
+        // The following linear segment is unaffected
+        assert!((animation.sample_at(0.75) - 25.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_segment_modes() {
+        let animation = KeyframeAnimation::new(
+            1000,
+            vec![
+                Keyframe::new(0.0, 0.0, Easing::Linear),
+                Keyframe::new(1.0, 100.0, Easing::EaseIn).with_interpolation(Interpolation::Linear),
+            ],
+        );
+        // The interpolation overrides the keyframe's easing
+        assert!((animation.sample_at(0.25) - 25.0).abs() < 1e-4);
+
+        let eased = KeyframeAnimation::new(
+            1000,
+            vec![
+                Keyframe::new(0.0, 0.0, Easing::Linear),
+                Keyframe::new(1.0, 100.0, Easing::EaseIn),
+            ],
+        );
+        assert!(eased.sample_at(0.25) < 25.0);
+    }
+
+    #[test]
+    fn test_spring_segment_carries_velocity() {
+        let spring = Interpolation::Spring(SpringConfig::stiff());
+        // A fast linear segment into a spring segment that holds position
+        let moving = KeyframeAnimation::new(
+            1000,
+            vec![
+                Keyframe::new(0.0, 0.0, Easing::Linear),
+                Keyframe::new(0.5, 100.0, Easing::Linear),
+                Keyframe::new(1.0, 100.0, Easing::Linear).with_interpolation(spring),
+            ],
+        );
+        // Same spring segment entered from rest
+        let resting = KeyframeAnimation::new(
+            1000,
+            vec![
+                Keyframe::new(0.0, 100.0, Easing::Linear),
+                Keyframe::new(0.5, 100.0, Easing::Linear),
+                Keyframe::new(1.0, 100.0, Easing::Linear).with_interpolation(spring),
+            ],
+        );
+
+        // The incoming 200/s velocity overshoots the target
+        assert!(moving.sample_at(0.52) > 100.5);
+        assert_eq!(moving.sample_at(0.5), 100.0);
+        assert_eq!(resting.sample_at(0.52), 100.0);
+        // ...and the spring settles back by the end
+        assert!((moving.sample_at(0.99) - 100.0).abs() < 0.5);
+    }
+}
@@ -8,13 +8,17 @@
 //! - **Keyframe Animations**: Timed sequences with easing functions
 //! - **Multi-Property Keyframes**: Animate multiple properties simultaneously
 //! - **Timelines**: Orchestrate multiple animations with offsets
+//! - **Path Following**: Move a point along a `Path` at constant speed
+//! - **Typed Animations**: Spring a whole `Transform` or `Rect` via `Interpolate`
 //! - **Interruptible**: Animations inherit velocity when interrupted
 //! - **Animation Presets**: Common entry/exit animations
 //! - **AnimationContext**: Platform-agnostic animation management trait
 
 pub mod context;
 pub mod easing;
+pub mod interpolate;
 pub mod keyframe;
+pub mod path;
 pub mod presets;
 pub mod scheduler;
 pub mod spring;
@@ -24,10 +28,14 @@ pub use context::{
     AnimationContext, AnimationContextExt, SharedAnimatedTimeline, SharedAnimatedValue,
 };
 pub use easing::Easing;
+pub use interpolate::{
+    Animated, AnimatedRect, AnimatedTransform, DecomposedAffine2D, DecomposedMat4, Interpolate,
+};
 pub use keyframe::{
-    FillMode, Keyframe, KeyframeAnimation, KeyframePoint, KeyframeProperties, KeyframeTrack,
-    KeyframeTrackBuilder, MultiKeyframe, MultiKeyframeAnimation, PlayDirection,
+    FillMode, Interpolation, Keyframe, KeyframeAnimation, KeyframePoint, KeyframeProperties,
+    KeyframeTrack, KeyframeTrackBuilder, MultiKeyframe, MultiKeyframeAnimation, PlayDirection,
 };
+pub use path::{AnimatedPath, ArcLengthPath, PathSample};
 pub use presets::AnimationPreset;
 pub use scheduler::{
     get_scheduler, is_scheduler_initialized, set_global_scheduler, try_get_scheduler,
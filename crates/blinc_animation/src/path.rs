@@ -0,0 +1,348 @@
+//! Path-following animation
+//!
+//! [`AnimatedPath`] moves a point along a [`Path`] over time, e.g. to fly an
+//! element along a curve. Progress runs from 0 to 1 through the scheduler
+//! with an easing, and maps to distance along the path rather than to the
+//! curves' own parameter, so the point travels at constant speed before
+//! easing.
+
+use blinc_core::{Path, PathCommand, Point};
+
+use crate::easing::Easing;
+use crate::scheduler::{AnimatedKeyframe, SchedulerHandle};
+
+/// Longest line segment a curve is flattened to, in pixels
+const FLATTEN_STEP: f32 = 1.0;
+
+/// Position and direction at a point along a path
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PathSample {
+    /// Position on the path
+    pub point: Point,
+    /// Direction of travel, in radians clockwise from the +x axis
+    pub angle: f32,
+}
+
+/// A line piece of a flattened path
+#[derive(Clone, Copy, Debug)]
+struct Segment {
+    from: Point,
+    to: Point,
+    /// Distance along the path where this segment starts
+    start: f32,
+}
+
+impl Segment {
+    fn length(&self) -> f32 {
+        (self.to.x - self.from.x).hypot(self.to.y - self.from.y)
+    }
+
+    fn angle(&self) -> f32 {
+        (self.to.y - self.from.y).atan2(self.to.x - self.from.x)
+    }
+}
+
+/// A path flattened to line segments with their cumulative lengths, for
+/// sampling by distance
+///
+/// Moves between subpaths don't count towards the length: the sample jumps
+/// from the end of one subpath to the start of the next.
+#[derive(Clone, Debug)]
+pub struct ArcLengthPath {
+    segments: Vec<Segment>,
+    /// First point of the path, for paths without any length
+    origin: Point,
+    length: f32,
+}
+
+impl ArcLengthPath {
+    /// Flatten `path` and measure it
+    pub fn new(path: &Path) -> Self {
+        let mut segments = Vec::new();
+        let mut length = 0.0;
+        let mut push = |from: Point, to: Point| {
+            let segment = Segment {
+                from,
+                to,
+                start: length,
+            };
+            let segment_length = segment.length();
+            if segment_length > 0.0 && segment_length.is_finite() {
+                length += segment_length;
+                segments.push(segment);
+            }
+        };
+
+        for polyline in path.flatten(FLATTEN_STEP) {
+            for pair in polyline.windows(2) {
+                push(pair[0], pair[1]);
+            }
+        }
+        let origin = match path.commands().first() {
+            Some(&PathCommand::MoveTo(p)) => p,
+            _ => Point::ZERO,
+        };
+
+        Self {
+            segments,
+            origin,
+            length,
+        }
+    }
+
+    /// Total length of the path
+    pub fn length(&self) -> f32 {
+        self.length
+    }
+
+    /// Sample the path at `progress` (0.0 to 1.0) of its length
+    ///
+    /// Progress is clamped. An empty path samples its first point, heading
+    /// along +x.
+    pub fn sample(&self, progress: f32) -> PathSample {
+        if self.segments.is_empty() {
+            return PathSample {
+                point: self.origin,
+                angle: 0.0,
+            };
+        }
+        let progress = if progress.is_nan() { 0.0 } else { progress };
+        let distance = progress.clamp(0.0, 1.0) * self.length;
+
+        // Last segment starting at or before the distance
+        let index = self
+            .segments
+            .partition_point(|segment| segment.start <= distance)
+            .saturating_sub(1);
+        let segment = &self.segments[index];
+        let t = ((distance - segment.start) / segment.length()).clamp(0.0, 1.0);
+        PathSample {
+            point: Point::new(
+                segment.from.x + (segment.to.x - segment.from.x) * t,
+                segment.from.y + (segment.to.y - segment.from.y) * t,
+            ),
+            angle: segment.angle(),
+        }
+    }
+
+    /// Point at `progress` (0.0 to 1.0) of the path's length
+    pub fn point_at(&self, progress: f32) -> Point {
+        self.sample(progress).point
+    }
+}
+
+/// A point animated along a path
+///
+/// Progress is a keyframe animation from 0 to 1 with the configured easing.
+///
+/// # Example
+///
+/// ```ignore
+/// let orbit = Path::circle(Point::new(200.0, 200.0), 120.0);
+/// let mut satellite = AnimatedPath::new(ctx.animation_handle(), &orbit, 4000)
+///     .easing(Easing::Linear)
+///     .loop_infinite()
+///     .auto_start(true)
+///     .build();
+///
+/// // Every frame
+/// let PathSample { point, angle } = satellite.sample();
+/// ```
+pub struct AnimatedPath {
+    handle: SchedulerHandle,
+    path: ArcLengthPath,
+    duration_ms: u32,
+    easing: Easing,
+    auto_start: bool,
+    iterations: i32,
+    ping_pong: bool,
+    delay_ms: u32,
+    /// Progress from 0 to 1, created on build or first start
+    progress: Option<AnimatedKeyframe>,
+}
+
+impl AnimatedPath {
+    /// Create an animation along `path` lasting `duration_ms`
+    pub fn new(handle: SchedulerHandle, path: &Path, duration_ms: u32) -> Self {
+        Self {
+            handle,
+            path: ArcLengthPath::new(path),
+            duration_ms,
+            easing: Easing::Linear,
+            auto_start: false,
+            iterations: 1,
+            ping_pong: false,
+            delay_ms: 0,
+            progress: None,
+        }
+    }
+
+    /// Set the easing applied to progress along the path
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Set whether to auto-start when built
+    pub fn auto_start(mut self, auto: bool) -> Self {
+        self.auto_start = auto;
+        self
+    }
+
+    /// Set number of iterations (-1 for infinite)
+    pub fn iterations(mut self, count: i32) -> Self {
+        self.iterations = count;
+        self
+    }
+
+    /// Enable infinite looping
+    pub fn loop_infinite(mut self) -> Self {
+        self.iterations = -1;
+        self
+    }
+
+    /// Enable ping-pong mode (travel back along the path on every other
+    /// iteration)
+    pub fn ping_pong(mut self, enabled: bool) -> Self {
+        self.ping_pong = enabled;
+        self
+    }
+
+    /// Set delay before animation starts (in milliseconds)
+    pub fn delay(mut self, delay_ms: u32) -> Self {
+        self.delay_ms = delay_ms;
+        self
+    }
+
+    /// Build and register the animation, returning self for chaining
+    pub fn build(mut self) -> Self {
+        self.progress = Some(self.progress_keyframe().auto_start(self.auto_start).build());
+        self
+    }
+
+    fn progress_keyframe(&self) -> AnimatedKeyframe {
+        AnimatedKeyframe::new(self.handle.clone(), self.duration_ms)
+            .keyframe(0.0, 0.0, Easing::Linear)
+            .keyframe(1.0, 1.0, self.easing)
+            .iterations(self.iterations)
+            .ping_pong(self.ping_pong)
+            .delay(self.delay_ms)
+    }
+
+    /// Start the animation from the beginning of the path
+    pub fn start(&mut self) {
+        if self.progress.is_none() {
+            self.progress = Some(self.progress_keyframe());
+        }
+        if let Some(progress) = self.progress.as_mut() {
+            progress.start();
+        }
+    }
+
+    /// Stop the animation where it is
+    pub fn stop(&mut self) {
+        if let Some(progress) = self.progress.as_mut() {
+            progress.stop();
+        }
+    }
+
+    /// Restart the animation from the beginning of the path
+    pub fn restart(&mut self) {
+        self.stop();
+        self.start();
+    }
+
+    /// Current eased progress along the path (0.0 to 1.0)
+    pub fn progress(&mut self) -> f32 {
+        self.progress
+            .as_mut()
+            .map_or(0.0, |progress| progress.get())
+    }
+
+    /// Current position and direction of travel
+    pub fn sample(&mut self) -> PathSample {
+        let progress = self.progress();
+        self.path.sample(progress)
+    }
+
+    /// Current position on the path
+    pub fn get(&mut self) -> Point {
+        self.sample().point
+    }
+
+    /// Check if the animation is playing (including during delay and looping)
+    pub fn is_playing(&mut self) -> bool {
+        self.progress
+            .as_mut()
+            .is_some_and(|progress| progress.is_playing())
+    }
+
+    /// The measured path being followed
+    pub fn path(&self) -> &ArcLengthPath {
+        &self.path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scheduler::AnimationScheduler;
+    use blinc_core::Vec2;
+
+    fn assert_near(point: Point, x: f32, y: f32) {
+        assert!(
+            (point.x - x).abs() < 1e-3 && (point.y - y).abs() < 1e-3,
+            "expected ({x}, {y}), got {point:?}"
+        );
+    }
+
+    #[test]
+    fn test_diagonal_path_samples_by_distance() {
+        let scheduler = AnimationScheduler::new();
+        let diagonal = Path::new().move_to(0.0, 0.0).line_to(100.0, 100.0);
+        let mut anim = AnimatedPath::new(scheduler.handle(), &diagonal, 1000).build();
+        anim.start();
+        assert_near(anim.get(), 0.0, 0.0);
+
+        scheduler.tick_with_dt(0.5);
+        assert_near(anim.get(), 50.0, 50.0);
+        scheduler.tick_with_dt(0.25);
+        let sample = anim.sample();
+        assert_near(sample.point, 75.0, 75.0);
+        assert!((sample.angle - std::f32::consts::FRAC_PI_4).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_uneven_segments_travel_at_constant_speed() {
+        // A short leg then a long one: halfway is measured along the length,
+        // not by segment count
+        let path = Path::new()
+            .move_to(0.0, 0.0)
+            .line_to(10.0, 0.0)
+            .line_to(10.0, 90.0);
+        let measured = ArcLengthPath::new(&path);
+        assert_eq!(measured.length(), 100.0);
+        assert_near(measured.point_at(0.5), 10.0, 40.0);
+        assert_near(measured.point_at(0.05), 5.0, 0.0);
+
+        // Curves are measured along the curve, not their parameter
+        let quarter = Path::new().move_to(10.0, 0.0).arc_to(
+            Vec2 { x: 10.0, y: 10.0 },
+            0.0,
+            false,
+            true,
+            0.0,
+            10.0,
+        );
+        let measured = ArcLengthPath::new(&quarter);
+        assert!((measured.length() - std::f32::consts::FRAC_PI_2 * 10.0).abs() < 0.05);
+        let half = std::f32::consts::FRAC_PI_4;
+        let mid = measured.point_at(0.5);
+        assert!((mid.x - 10.0 * half.cos()).abs() < 0.05, "{mid:?}");
+        assert!((mid.y - 10.0 * half.sin()).abs() < 0.05, "{mid:?}");
+
+        // Paths without length stay at their start
+        let dot = ArcLengthPath::new(&Path::new().move_to(3.0, 4.0));
+        assert_near(dot.point_at(0.5), 3.0, 4.0);
+    }
+}
@@ -108,6 +108,39 @@ struct SchedulerInner {
     target_fps: u32,
 }
 
+impl SchedulerInner {
+    /// Step all animations by `dt` seconds
+    ///
+    /// Returns true if any animations are still active.
+    fn tick(&mut self, dt: f32) -> bool {
+        let dt_ms = dt * 1000.0;
+
+        // Update all springs
+        for (_, spring) in self.springs.iter_mut() {
+            spring.step(dt);
+        }
+
+        // Update all keyframe animations
+        for (_, keyframe) in self.keyframes.iter_mut() {
+            keyframe.tick(dt_ms);
+        }
+
+        // Update all timelines
+        for (_, timeline) in self.timelines.iter_mut() {
+            timeline.tick(dt_ms);
+        }
+
+        // NOTE: We do NOT remove animations here!
+        // Springs, keyframes, and timelines are only removed when their wrappers drop.
+        // This ensures animations can be restarted after completing.
+
+        // Return true if there are still active (playing, not just present) animations
+        self.springs.iter().any(|(_, s)| !s.is_settled())
+            || self.keyframes.iter().any(|(_, k)| k.is_playing())
+            || self.timelines.iter().any(|(_, t)| t.is_playing())
+    }
+}
+
 /// Callback type for waking up the main thread from the animation thread
 ///
 /// This is called when there are active animations that need to be rendered.
@@ -126,7 +159,7 @@ pub type WakeCallback = Arc<dyn Fn() + Send + Sync>;
 ///
 /// ```ignore
 /// let scheduler = AnimationScheduler::new();
-/// scheduler.start_background(); // Runs at 120fps in background thread
+/// scheduler.start_background(); // Runs at 120fps (see `set_target_fps`) in background thread
 /// ```
 pub struct AnimationScheduler {
     inner: Arc<Mutex<SchedulerInner>>,
@@ -203,8 +236,6 @@ impl AnimationScheduler {
         let wake_callback = self.wake_callback.clone();
 
         self.thread_handle = Some(thread::spawn(move || {
-            let frame_duration = Duration::from_micros(1_000_000 / 120); // 120fps
-
             while !stop_flag.load(Ordering::Relaxed) {
                 let start = Instant::now();
 
@@ -212,7 +243,7 @@ impl AnimationScheduler {
                 let wants_continuous = continuous_redraw.load(Ordering::Relaxed);
 
                 // Tick animations and check if any are active
-                let has_active = {
+                let (has_active, frame_duration) = {
                     let mut inner = inner.lock().unwrap();
                     let now = Instant::now();
                     let dt = (now - inner.last_frame).as_secs_f32();
@@ -241,9 +272,11 @@ impl AnimationScheduler {
                     // This ensures animations can be restarted after completing.
 
                     // Check if any animations are still active (playing, not just present)
-                    inner.springs.iter().any(|(_, s)| !s.is_settled())
+                    let has_active = inner.springs.iter().any(|(_, s)| !s.is_settled())
                         || inner.keyframes.iter().any(|(_, k)| k.is_playing())
-                        || inner.timelines.iter().any(|(_, t)| t.is_playing())
+                        || inner.timelines.iter().any(|(_, t)| t.is_playing());
+                    let fps = inner.target_fps.max(1) as u64;
+                    (has_active, Duration::from_micros(1_000_000 / fps))
                 };
 
                 // Signal main thread that it needs to redraw
@@ -336,43 +369,39 @@ impl AnimationScheduler {
         }
     }
 
+    /// Set the rate the background thread ticks at (120 by default)
+    ///
+    /// Takes effect from the next frame; the windowed runner lowers this on
+    /// battery power.
     pub fn set_target_fps(&mut self, fps: u32) {
         self.inner.lock().unwrap().target_fps = fps;
     }
 
+    /// The rate the background thread ticks at
+    pub fn target_fps(&self) -> u32 {
+        self.inner.lock().unwrap().target_fps
+    }
+
     /// Tick all animations
     ///
     /// Returns true if any animations are still active (need another tick).
     pub fn tick(&self) -> bool {
-        let mut inner = self.inner.lock().unwrap();
-        let now = Instant::now();
-        let dt = (now - inner.last_frame).as_secs_f32();
-        let dt_ms = dt * 1000.0;
-        inner.last_frame = now;
-
-        // Update all springs
-        for (_, spring) in inner.springs.iter_mut() {
-            spring.step(dt);
-        }
-
-        // Update all keyframe animations
-        for (_, keyframe) in inner.keyframes.iter_mut() {
-            keyframe.tick(dt_ms);
-        }
-
-        // Update all timelines
-        for (_, timeline) in inner.timelines.iter_mut() {
-            timeline.tick(dt_ms);
-        }
-
-        // NOTE: We do NOT remove animations here!
-        // Springs, keyframes, and timelines are only removed when their wrappers drop.
-        // This ensures animations can be restarted after completing.
+        let dt = {
+            let mut inner = self.inner.lock().unwrap();
+            let now = Instant::now();
+            let dt = (now - inner.last_frame).as_secs_f32();
+            inner.last_frame = now;
+            dt
+        };
+        self.tick_with_dt(dt)
+    }
 
-        // Return true if there are still active (playing, not just present) animations
-        inner.springs.iter().any(|(_, s)| !s.is_settled())
-            || inner.keyframes.iter().any(|(_, k)| k.is_playing())
-            || inner.timelines.iter().any(|(_, t)| t.is_playing())
+    /// Tick all animations by a fixed time step (in seconds)
+    ///
+    /// Ignores wall-clock time, so animations can be stepped deterministically
+    /// (e.g. from headless tests). Returns true if any animations are still active.
+    pub fn tick_with_dt(&self, dt: f32) -> bool {
+        self.inner.lock().unwrap().tick(dt)
     }
 
     /// Check if any animations are still active
@@ -813,6 +842,17 @@ impl SchedulerHandle {
     pub fn is_alive(&self) -> bool {
         self.inner.strong_count() > 0
     }
+
+    /// Tick all animations by a fixed time step (in seconds)
+    ///
+    /// Same as [`AnimationScheduler::tick_with_dt`], for code that only has
+    /// a handle (e.g. to [`get_scheduler`]). Returns true if any animations
+    /// are still active, false if the scheduler was dropped.
+    pub fn tick_with_dt(&self, dt: f32) -> bool {
+        self.inner
+            .upgrade()
+            .is_some_and(|inner| inner.lock().unwrap().tick(dt))
+    }
 }
 
 /// Implement AnimationAccess for SchedulerHandle
@@ -1048,11 +1088,7 @@ impl AnimatedKeyframe {
 
     /// Add a keyframe at the given time position (0.0 to 1.0)
     pub fn keyframe(mut self, time: f32, value: f32, easing: Easing) -> Self {
-        self.keyframes.push(Keyframe {
-            time,
-            value,
-            easing,
-        });
+        self.keyframes.push(Keyframe::new(time, value, easing));
         self
     }
 
@@ -1550,6 +1586,29 @@ impl AnimatedTimeline {
         }
     }
 
+    /// Seek to a fraction (0.0 to 1.0) of the duration
+    ///
+    /// On a looping timeline this maps within one loop, which is what a
+    /// scrubber wants.
+    pub fn set_progress(&self, progress: f32) {
+        if let Some(id) = self.timeline_id {
+            self.handle.with_timeline(id, |timeline| {
+                timeline.set_progress(progress);
+            });
+        }
+    }
+
+    /// Play or pause the timeline
+    ///
+    /// Playing a finished timeline starts it from the beginning again.
+    pub fn set_playing(&self, playing: bool) {
+        if let Some(id) = self.timeline_id {
+            self.handle.with_timeline(id, |timeline| {
+                timeline.set_playing(playing);
+            });
+        }
+    }
+
     /// Get the current value for a timeline entry
     pub fn get(&self, entry_id: crate::timeline::TimelineEntryId) -> Option<f32> {
         if let Some(id) = self.timeline_id {
@@ -1570,6 +1629,30 @@ impl AnimatedTimeline {
         }
     }
 
+    /// Get the length of one loop in milliseconds
+    ///
+    /// This is where the last entry ends.
+    pub fn duration(&self) -> u32 {
+        if let Some(id) = self.timeline_id {
+            self.handle
+                .with_timeline(id, |timeline| timeline.duration())
+                .unwrap_or(0)
+        } else {
+            0
+        }
+    }
+
+    /// Get the playhead position within the current loop, in milliseconds
+    pub fn current_time(&self) -> f32 {
+        if let Some(id) = self.timeline_id {
+            self.handle
+                .with_timeline(id, |timeline| timeline.current_time())
+                .unwrap_or(0.0)
+        } else {
+            0.0
+        }
+    }
+
     /// Get the overall timeline progress (0.0 to 1.0)
     pub fn progress(&self) -> f32 {
         if let Some(id) = self.timeline_id {
@@ -1649,6 +1732,39 @@ mod tests {
         assert!(value > 0.0);
     }
 
+    #[test]
+    fn test_scheduler_tick_with_fixed_dt_is_deterministic() {
+        let step = || {
+            let scheduler = AnimationScheduler::new();
+            let id = scheduler.add_spring(Spring::new(SpringConfig::stiff(), 0.0));
+            scheduler.set_spring_target(id, 100.0);
+            for _ in 0..5 {
+                scheduler.tick_with_dt(1.0 / 60.0);
+            }
+            scheduler.get_spring_value(id).unwrap()
+        };
+
+        let value = step();
+        assert!(value > 0.0);
+        assert_eq!(value, step());
+    }
+
+    #[test]
+    fn test_handle_tick_with_dt_steps_scheduler() {
+        let scheduler = AnimationScheduler::new();
+        let handle = scheduler.handle();
+        let mut value = AnimatedValue::new(handle.clone(), 0.0, SpringConfig::stiff());
+        value.set_target(100.0);
+
+        assert!(handle.tick_with_dt(1.0 / 60.0));
+        assert!(value.get() > 0.0);
+
+        // A dropped scheduler has nothing to tick
+        drop(value);
+        drop(scheduler);
+        assert!(!handle.tick_with_dt(1.0 / 60.0));
+    }
+
     #[test]
     fn test_animated_value() {
         let scheduler = AnimationScheduler::new();
@@ -1717,6 +1833,35 @@ mod tests {
         assert!(timeline.is_playing());
     }
 
+    #[test]
+    fn test_animated_timeline_scrubbing() {
+        let scheduler = AnimationScheduler::new();
+        let mut timeline = AnimatedTimeline::new(scheduler.handle());
+        let fade = timeline.add(0, 1000, 0.0, 1.0);
+        let slide = timeline.add(500, 1500, 0.0, 300.0);
+        assert_eq!(timeline.duration(), 2000);
+
+        timeline.set_progress(0.5);
+        assert_eq!(timeline.current_time(), 1000.0);
+        assert_eq!(timeline.progress(), 0.5);
+        assert_eq!(timeline.get(fade), Some(1.0));
+        assert_eq!(timeline.get(slide), Some(100.0));
+
+        // Out-of-range scrubs clamp to one loop
+        timeline.set_loop(-1);
+        timeline.set_progress(1.5);
+        assert_eq!(timeline.current_time(), 2000.0);
+
+        // Playing from the end starts over; pausing keeps the playhead
+        timeline.set_playing(true);
+        assert!(timeline.is_playing());
+        assert_eq!(timeline.current_time(), 0.0);
+        timeline.set_progress(0.25);
+        timeline.set_playing(false);
+        assert!(!timeline.is_playing());
+        assert_eq!(timeline.current_time(), 500.0);
+    }
+
     #[test]
     fn test_handle_weak_reference() {
         let handle = {
@@ -1745,14 +1890,8 @@ mod tests {
         let spring = Spring::new(SpringConfig::stiff(), 0.0);
         scheduler.add_spring(spring);
 
-        let mut keyframe = KeyframeAnimation::new(
-            1000,
-            vec![Keyframe {
-                time: 0.0,
-                value: 0.0,
-                easing: Easing::Linear,
-            }],
-        );
+        let mut keyframe =
+            KeyframeAnimation::new(1000, vec![Keyframe::new(0.0, 0.0, Easing::Linear)]);
         keyframe.start();
         scheduler.add_keyframe(keyframe);
 
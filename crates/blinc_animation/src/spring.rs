@@ -112,6 +112,12 @@ impl Spring {
         }
     }
 
+    /// Start the spring moving at `velocity` (units per second)
+    pub fn with_velocity(mut self, velocity: f32) -> Self {
+        self.velocity = velocity;
+        self
+    }
+
     pub fn value(&self) -> f32 {
         self.value
     }
@@ -151,6 +151,30 @@ impl Timeline {
         self.current_time = time_ms.clamp(0.0, self.duration_ms as f32);
     }
 
+    /// Seek to a fraction (0.0 to 1.0) of the duration
+    ///
+    /// On a looping timeline this positions the playhead within the
+    /// current loop; the loop count is left alone.
+    pub fn set_progress(&mut self, progress: f32) {
+        self.seek(progress.clamp(0.0, 1.0) * self.duration_ms as f32);
+    }
+
+    /// Play or pause
+    ///
+    /// Playing a timeline that has run to its end starts it over.
+    pub fn set_playing(&mut self, playing: bool) {
+        let at_end = if self.reversed {
+            self.current_time <= 0.0
+        } else {
+            self.current_time >= self.duration_ms as f32
+        };
+        if playing && !self.playing && at_end {
+            self.start();
+        } else {
+            self.playing = playing;
+        }
+    }
+
     /// Set loop count (-1 for infinite, 0 to disable, positive for specific count)
     pub fn set_loop(&mut self, count: i32) {
         self.loop_count = count;
@@ -263,7 +263,7 @@ impl AndroidApp {
                                     Ok((app_instance, surf)) => {
                                         let format = app_instance.texture_format();
                                         let config = wgpu::SurfaceConfiguration {
-                                            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+                                            usage: app_instance.surface_usage(&surf),
                                             format,
                                             width,
                                             height,
@@ -691,6 +691,20 @@ impl AndroidApp {
                 needs_redraw_next_frame = true;
             }
 
+            // Tick press-and-hold gestures (long press, press repeat)
+            if let (Some(ref mut windowed_ctx), Some(ref mut tree)) = (&mut ctx, &mut render_tree) {
+                let router = &mut windowed_ctx.event_router;
+                if router.press_hold_ms().is_some() {
+                    let (mouse_x, mouse_y) = router.mouse_position();
+                    let hold_events = router.on_press_hold(blinc_layout::prelude::elapsed_ms());
+                    let hold_ms = router.press_hold_ms().unwrap_or(0);
+                    for (node, _) in hold_events {
+                        tree.dispatch_press_hold(node, hold_ms, mouse_x, mouse_y);
+                    }
+                    needs_redraw_next_frame = true;
+                }
+            }
+
             // =========================================================
             // PHASE 1: Check for incremental updates (prop changes, subtree rebuilds)
             // This avoids full rebuild for simple state changes
@@ -789,8 +803,11 @@ impl AndroidApp {
                     &mut ctx,
                     &render_state,
                 ) {
-                    // Build UI
-                    let element = ui_builder(windowed_ctx);
+                    // Build UI, rendering a fallback instead of crashing if the builder panics
+                    let element = crate::error_boundary::error_boundary(
+                        || ui_builder(windowed_ctx),
+                        crate::error_boundary::default_error_fallback,
+                    );
 
                     // Create or update render tree
                     if render_tree.is_none() {
@@ -801,8 +818,10 @@ impl AndroidApp {
                         tree.clear_dirty(); // Start clean
                         render_tree = Some(tree);
                     } else if let Some(ref mut tree) = render_tree {
-                        // Full rebuild
-                        *tree = RenderTree::from_element(&element);
+                        // Full rebuild (canvas frame timing carries over)
+                        let mut new_tree = RenderTree::from_element(&element);
+                        new_tree.transfer_canvas_frame_from(tree);
+                        *tree = new_tree;
                         tree.set_scale_factor(windowed_ctx.scale_factor as f32);
                         tree.compute_layout(windowed_ctx.width, windowed_ctx.height);
                         // Clear dirty on the NEW tree to prevent immediate re-rebuild
@@ -824,6 +843,10 @@ impl AndroidApp {
                 if count % 120 == 0 {
                     tracing::info!("REDRAW #{} (every 120th logged)", count);
                 }
+                // Advance frame timing for canvas render callbacks
+                if let Some(ref mut tree) = render_tree {
+                    tree.begin_canvas_frame(blinc_layout::prelude::elapsed_ms());
+                }
                 if let (
                     Some(ref mut app_instance),
                     Some(ref surf),
@@ -852,6 +875,7 @@ impl AndroidApp {
                             ) {
                                 tracing::error!("Render error: {}", e);
                             }
+                            windowed_ctx.save_requested_screenshot(app_instance, &output.texture);
                             output.present();
                         }
                         Err(wgpu::SurfaceError::Lost) => {
@@ -902,6 +926,14 @@ impl AndroidApp {
                 if blinc_layout::has_pending_subtree_rebuilds() {
                     needs_redraw_next_frame = true;
                 }
+
+                // Check if a canvas asked to keep animating
+                if render_tree
+                    .as_ref()
+                    .is_some_and(|tree| tree.canvas_animation_requested())
+                {
+                    needs_redraw_next_frame = true;
+                }
             }
         }
 
@@ -911,7 +943,7 @@ impl AndroidApp {
 
     /// Initialize GPU with a native window
     fn init_gpu(window: &NativeWindow) -> Result<(BlincApp, wgpu::Surface<'static>)> {
-        use blinc_gpu::{GpuRenderer, RendererConfig, TextRenderingContext};
+        use blinc_gpu::{ColorSpace, GpuRenderer, RendererConfig, TextRenderingContext};
 
         let config = crate::BlincConfig::default();
 
@@ -922,6 +954,9 @@ impl AndroidApp {
             sample_count: 1,
             texture_format: None,
             unified_text_rendering: true,
+            temporal_aa: false,
+            glyph_atlas: None,
+            color_space: ColorSpace::Srgb,
         };
 
         // Create instance with Vulkan backend
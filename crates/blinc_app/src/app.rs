@@ -2,7 +2,7 @@
 //!
 //! The main entry point for Blinc applications.
 
-use blinc_gpu::{FontRegistry, GpuRenderer, RendererConfig, TextRenderingContext};
+use blinc_gpu::{ColorSpace, FontRegistry, GpuRenderer, RendererConfig, TextRenderingContext};
 use blinc_layout::prelude::*;
 use blinc_layout::RenderTree;
 use std::sync::{Arc, Mutex};
@@ -83,6 +83,9 @@ impl BlincApp {
             sample_count: 1, // SDF pipelines always use single-sampled textures
             texture_format: None,
             unified_text_rendering: true,
+            temporal_aa: false,
+            glyph_atlas: None,
+            color_space: ColorSpace::Srgb,
         };
 
         let renderer = pollster::block_on(GpuRenderer::new(renderer_config))
@@ -269,6 +272,30 @@ impl BlincApp {
         self.ctx.texture_format()
     }
 
+    /// Texture usages to configure a window surface with
+    ///
+    /// Adds `COPY_SRC` when the surface supports it, so frames can be read
+    /// back with [`capture_frame`](Self::capture_frame), and `COPY_DST`, so
+    /// a retained frame can be copied in when only part of it was redrawn.
+    pub fn surface_usage(&self, surface: &wgpu::Surface) -> wgpu::TextureUsages {
+        let supported = surface
+            .get_capabilities(self.ctx.renderer().adapter())
+            .usages;
+        wgpu::TextureUsages::RENDER_ATTACHMENT
+            | (supported & (wgpu::TextureUsages::COPY_SRC | wgpu::TextureUsages::COPY_DST))
+    }
+
+    /// Read a rendered frame back as a straight-alpha RGBA image
+    ///
+    /// Call before presenting the frame; the texture needs `COPY_SRC` usage.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn capture_frame(&self, texture: &wgpu::Texture) -> Result<blinc_gpu::RgbaImage> {
+        self.ctx
+            .renderer()
+            .capture_frame(texture)
+            .map_err(|e| BlincError::Render(e.to_string()))
+    }
+
     /// Get the shared font registry
     ///
     /// This can be used to share fonts between text measurement and rendering,
@@ -324,6 +351,9 @@ impl BlincApp {
             sample_count: 1,
             texture_format: None,
             unified_text_rendering: true,
+            temporal_aa: false,
+            glyph_atlas: None,
+            color_space: ColorSpace::Srgb,
         };
 
         let (renderer, surface) =
@@ -14,7 +14,7 @@ use blinc_layout::div::{FontFamily, FontWeight, GenericFont, TextAlign, TextVert
 use blinc_layout::prelude::*;
 use blinc_layout::render_state::Overlay;
 use blinc_layout::renderer::ElementType;
-use blinc_svg::{RasterizedSvg, SvgDocument};
+use blinc_svg::{RasterizedSvg, SvgCache, SvgDocument};
 use lru::LruCache;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
@@ -49,6 +49,8 @@ pub struct RenderContext {
     image_cache: LruCache<String, GpuImage>,
     // LRU cache for parsed SVG documents (avoids re-parsing)
     svg_cache: LruCache<u64, SvgDocument>,
+    // Scaled draw commands for tessellated SVGs (avoids re-converting paths every frame)
+    svg_commands: SvgCache,
     // LRU cache for rasterized SVG textures (CPU-rasterized with proper AA)
     rasterized_svg_cache: LruCache<u64, GpuImage>,
     // Scratch buffers for per-frame allocations (reused to avoid allocations)
@@ -56,6 +58,8 @@ pub struct RenderContext {
     scratch_texts: Vec<TextElement>,
     scratch_svgs: Vec<SvgElement>,
     scratch_images: Vec<ImageElement>,
+    // Damage region (physical pixels) for the next render_tree_with_motion call
+    damage: Option<Rect>,
 }
 
 struct CachedTexture {
@@ -86,6 +90,12 @@ struct TextElement {
     motion_opacity: f32,
     /// Whether to wrap text at container bounds
     wrap: bool,
+    /// Keep leading whitespace on wrapped lines
+    preformatted: bool,
+    /// Apply font ligatures
+    ligatures: bool,
+    /// Apply font kerning
+    kerning: bool,
     /// Line height multiplier
     line_height: f32,
     /// Measured width (before layout constraints) - used to determine if wrap is needed
@@ -185,6 +195,7 @@ impl RenderContext {
             msaa_texture: None,
             image_cache: LruCache::new(NonZeroUsize::new(IMAGE_CACHE_CAPACITY).unwrap()),
             svg_cache: LruCache::new(NonZeroUsize::new(SVG_CACHE_CAPACITY).unwrap()),
+            svg_commands: SvgCache::with_capacity(SVG_CACHE_CAPACITY),
             rasterized_svg_cache: LruCache::new(
                 NonZeroUsize::new(RASTERIZED_SVG_CACHE_CAPACITY).unwrap(),
             ),
@@ -192,6 +203,7 @@ impl RenderContext {
             scratch_texts: Vec::with_capacity(64),    // Pre-allocate for text elements
             scratch_svgs: Vec::with_capacity(32),     // Pre-allocate for SVG elements
             scratch_images: Vec::with_capacity(32),   // Pre-allocate for image elements
+            damage: None,
         }
     }
 
@@ -219,17 +231,19 @@ impl RenderContext {
         // Create paint contexts for each layer with text rendering support
         let mut bg_ctx =
             GpuPaintContext::with_text_context(width as f32, height as f32, &mut self.text_ctx);
+        bg_ctx.set_cached_layers(self.renderer.cached_layer_ids());
 
         // Render layout layers (background and glass go to bg_ctx)
         tree.render_to_layer(&mut bg_ctx, RenderLayer::Background);
         tree.render_to_layer(&mut bg_ctx, RenderLayer::Glass);
 
         // Take the batch from bg_ctx before we can reuse text_ctx for fg_ctx
-        let bg_batch = bg_ctx.take_batch();
+        let mut bg_batch = bg_ctx.take_batch();
 
         // Create foreground context with text rendering support
         let mut fg_ctx =
             GpuPaintContext::with_text_context(width as f32, height as f32, &mut self.text_ctx);
+        fg_ctx.set_cached_layers(self.renderer.cached_layer_ids());
         tree.render_to_layer(&mut fg_ctx, RenderLayer::Foreground);
 
         // Take the batch from fg_ctx before reusing text_ctx for text elements
@@ -311,6 +325,9 @@ impl RenderContext {
                 generic,
                 font_weight,
                 text.italic,
+                text.preformatted,
+                text.ligatures,
+                text.kerning,
                 layout_height,
             ) {
                 Ok(mut glyphs) => {
@@ -345,6 +362,10 @@ impl RenderContext {
         // They will be rendered later via render_rasterized_svgs
 
         self.renderer.resize(width, height);
+        self.renderer
+            .rasterize_cached_layers(std::mem::take(&mut bg_batch.cached_layers));
+        self.renderer
+            .rasterize_cached_layers(std::mem::take(&mut fg_batch.cached_layers));
 
         let has_glass = bg_batch.glass_count() > 0;
 
@@ -390,8 +411,9 @@ impl RenderContext {
             self.render_images_ref(target, &fg_images);
 
             // Step 6: Render foreground and text
-            // Use batch-based rendering when layer effects are present
-            let has_layer_effects = fg_batch.has_layer_effects();
+            // Use batch-based rendering when layer effects or cached layers
+            // are present
+            let has_layer_effects = fg_batch.has_layer_effects() || fg_batch.has_cached_layers();
             if has_layer_effects {
                 // Layer effects require batch-based rendering to process layer commands
                 fg_batch.convert_glyphs_to_primitives();
@@ -468,9 +490,9 @@ impl RenderContext {
             self.render_images(target, &images, width as f32, height as f32);
 
             // Render foreground and text
-            // Use batch-based rendering when layer effects are present to preserve
-            // layer commands for effect processing
-            let has_layer_effects = fg_batch.has_layer_effects();
+            // Use batch-based rendering when layer effects or cached layers are
+            // present to preserve layer commands for effect processing
+            let has_layer_effects = fg_batch.has_layer_effects() || fg_batch.has_cached_layers();
             if has_layer_effects {
                 // Layer effects require batch-based rendering to process layer commands
                 // First convert glyphs to primitives so they're included in the batch
@@ -688,6 +710,32 @@ impl RenderContext {
         }
     }
 
+    /// Render the layout inspector overlay
+    ///
+    /// Enabled with `BLINC_DEBUG=inspect` or [`RenderTree::set_debug_overlay`].
+    /// Drawn in its own pass, so it never takes part in hit-testing.
+    fn render_inspect_overlay(
+        &mut self,
+        target: &wgpu::TextureView,
+        tree: &RenderTree,
+        width: u32,
+        height: u32,
+    ) {
+        let mut batch = {
+            let mut ctx =
+                GpuPaintContext::with_text_context(width as f32, height as f32, &mut self.text_ctx);
+            tree.render_debug_overlay(&mut ctx);
+            ctx.take_batch()
+        };
+        let glyphs = std::mem::take(&mut batch.glyphs);
+        if !batch.is_empty() {
+            self.renderer.render_overlay(target, &batch);
+        }
+        if !glyphs.is_empty() {
+            self.render_text(target, &glyphs);
+        }
+    }
+
     /// Render debug visualization for motion/animations
     ///
     /// When `BLINC_DEBUG=motion` (or `all`) is set, this renders:
@@ -1144,12 +1192,14 @@ impl RenderContext {
             ctx.push_opacity(svg.motion_opacity);
         }
 
-        // Render the SVG with optional tint color override
-        if let Some(tint) = svg.tint {
-            // Render with tint - we need to modify the SVG commands
-            self.render_svg_with_tint(ctx, &doc, svg.x, svg.y, svg.width, svg.height, tint);
-        } else {
-            doc.render_fit(ctx, Rect::new(svg.x, svg.y, svg.width, svg.height));
+        // Render the SVG's cached commands, with an optional tint color override
+        let (x, y, scale) = doc.fit(Rect::new(svg.x, svg.y, svg.width, svg.height));
+        let tint = svg.tint.map(Brush::Solid);
+        for cmd in self.svg_commands.commands(svg_hash, &doc, scale) {
+            match &tint {
+                Some(tint) => cmd.clone().with_brush(tint.clone()).render(ctx, x, y, 1.0),
+                None => cmd.render(ctx, x, y, 1.0),
+            }
         }
 
         // Pop opacity if applied
@@ -1163,54 +1213,6 @@ impl RenderContext {
         }
     }
 
-    /// Render an SVG with a tint color applied to all fills and strokes
-    fn render_svg_with_tint(
-        &self,
-        ctx: &mut GpuPaintContext,
-        doc: &SvgDocument,
-        x: f32,
-        y: f32,
-        width: f32,
-        height: f32,
-        tint: blinc_core::Color,
-    ) {
-        use blinc_svg::SvgDrawCommand;
-
-        // Calculate scale to fit within bounds while maintaining aspect ratio
-        let scale_x = width / doc.width;
-        let scale_y = height / doc.height;
-        let scale = scale_x.min(scale_y);
-
-        // Center within bounds
-        let scaled_width = doc.width * scale;
-        let scaled_height = doc.height * scale;
-        let offset_x = x + (width - scaled_width) / 2.0;
-        let offset_y = y + (height - scaled_height) / 2.0;
-
-        let commands = doc.commands();
-        let tint_brush = Brush::Solid(tint);
-
-        for cmd in commands {
-            match cmd {
-                SvgDrawCommand::FillPath { path, brush: _ } => {
-                    let scaled = scale_and_translate_path(&path, offset_x, offset_y, scale);
-                    ctx.fill_path(&scaled, tint_brush.clone());
-                }
-                SvgDrawCommand::StrokePath {
-                    path,
-                    stroke,
-                    brush: _,
-                } => {
-                    let scaled = scale_and_translate_path(&path, offset_x, offset_y, scale);
-                    let scaled_stroke = Stroke::new(stroke.width * scale)
-                        .with_cap(stroke.cap)
-                        .with_join(stroke.join);
-                    ctx.stroke_path(&scaled, &scaled_stroke, tint_brush.clone());
-                }
-            }
-        }
-    }
-
     /// Render SVG elements using CPU rasterization for high-quality anti-aliased output
     ///
     /// This method rasterizes SVGs using resvg/tiny-skia and renders them as textures,
@@ -1348,12 +1350,17 @@ impl RenderContext {
         // Get the scale factor from the tree for DPI scaling
         let scale = tree.scale_factor();
 
-        if let Some(root) = tree.root() {
-            let mut z_layer = 0u32;
+        let roots = tree
+            .root()
+            .map(|root| (root, (0.0, 0.0)))
+            .into_iter()
+            .chain(tree.portals());
+        let mut z_layer = 0u32;
+        for (node, offset) in roots {
             self.collect_elements_recursive(
                 tree,
-                root,
-                (0.0, 0.0),
+                node,
+                offset,
                 false,      // inside_glass
                 false,      // inside_foreground
                 None,       // No initial clip bounds
@@ -1680,6 +1687,9 @@ impl RenderContext {
                         clip_bounds: scaled_clip,
                         motion_opacity: effective_motion_opacity,
                         wrap: text_data.wrap,
+                        preformatted: text_data.preformatted,
+                        ligatures: text_data.ligatures,
+                        kerning: text_data.kerning,
                         line_height: text_data.line_height,
                         measured_width: scaled_measured_width,
                         font_family: text_data.font_family.clone(),
@@ -1818,143 +1828,60 @@ impl RenderContext {
                             (final_x, final_y, base_width, base_height)
                         };
 
-                    let scaled_font_size = styled_data.font_size * effective_motion_scale.1 * scale;
                     let scaled_clip = current_clip
                         .map(|[cx, cy, cw, ch]| [cx * scale, cy * scale, cw * scale, ch * scale]);
 
-                    // Build non-overlapping segments from potentially overlapping spans
-                    // This handles nested tags like <span color="red"><b>text</b></span>
-                    let content = &styled_data.content;
-                    let content_len = content.len();
-
-                    // Get default styles from element config
-                    let default_bold = styled_data.weight == FontWeight::Bold;
-                    let default_italic = styled_data.italic;
-
-                    // Collect all boundary positions where style might change
-                    let mut boundaries: Vec<usize> = vec![0, content_len];
-                    for span in &styled_data.spans {
-                        if span.start < content_len {
-                            boundaries.push(span.start);
-                        }
-                        if span.end <= content_len {
-                            boundaries.push(span.end);
-                        }
-                    }
-                    boundaries.sort();
-                    boundaries.dedup();
-
-                    // Build segments between boundaries
-                    let mut segments: Vec<(usize, usize, [f32; 4], bool, bool, bool, bool)> =
-                        Vec::new();
-
-                    for window in boundaries.windows(2) {
-                        let seg_start = window[0];
-                        let seg_end = window[1];
-                        if seg_start >= seg_end {
-                            continue;
-                        }
-
-                        // Determine style for this segment by merging all overlapping spans
-                        let mut color: Option<[f32; 4]> = None;
-                        let mut bold = default_bold;
-                        let mut italic = default_italic;
-                        let mut underline = false;
-                        let mut strikethrough = false;
-
-                        for span in &styled_data.spans {
-                            // Check if span overlaps this segment
-                            if span.start <= seg_start && span.end >= seg_end {
-                                // This span covers this segment - merge styles
-                                if span.bold {
-                                    bold = true;
-                                }
-                                if span.italic {
-                                    italic = true;
-                                }
-                                if span.underline {
-                                    underline = true;
-                                }
-                                if span.strikethrough {
-                                    strikethrough = true;
-                                }
-                                // Use color if span has explicit color (not transparent)
-                                if span.color[3] > 0.0 {
-                                    color = Some(span.color);
-                                }
-                            }
-                        }
-
-                        let final_color = color.unwrap_or(styled_data.default_color);
-                        segments.push((
-                            seg_start,
-                            seg_end,
-                            final_color,
-                            bold,
-                            italic,
-                            underline,
-                            strikethrough,
-                        ));
-                    }
-
-                    // Use consistent ascender from element for baseline alignment
-                    let scaled_ascender = styled_data.ascender * scale;
+                    // Resolve overlapping spans (e.g. <span color="red"><b>text</b></span>)
+                    // into measured, non-overlapping segments
+                    let segments = styled_data.segments();
 
-                    // Calculate x offsets for each segment and push as TextElements
-                    let mut x_offset = 0.0f32;
-                    for (start, end, color, bold, italic, underline, strikethrough) in segments {
-                        if start >= end || start >= content.len() {
-                            continue;
-                        }
-                        let segment_text = &content[start..end.min(content.len())];
-                        if segment_text.is_empty() {
-                            continue;
-                        }
+                    // Segments with their own font or size share the line's baseline;
+                    // otherwise use the element's ascender for consistent alignment
+                    let (v_align, ascender) = if styled_data.has_font_overrides() {
+                        let (_, baseline) = styled_data.line_metrics(&segments);
+                        (TextVerticalAlign::Baseline, baseline)
+                    } else {
+                        (styled_data.v_align, styled_data.ascender)
+                    };
+                    let scaled_ascender = ascender * scale;
 
-                        // Measure segment width for positioning
-                        let mut options = blinc_layout::text_measure::TextLayoutOptions::new();
-                        options.font_name = styled_data.font_family.name.clone();
-                        options.generic_font = styled_data.font_family.generic;
-                        options.font_weight = if bold { 700 } else { 400 };
-                        options.italic = italic;
-                        let metrics = blinc_layout::text_measure::measure_text_with_options(
-                            segment_text,
-                            styled_data.font_size,
-                            &options,
-                        );
-                        // Apply both DPI scale and motion scale to segment width
-                        let segment_width = metrics.width * scale * effective_motion_scale.0;
+                    for segment in segments {
+                        let segment_text = &styled_data.content[segment.start..segment.end];
+                        // Apply both DPI scale and motion scale to segment position and width
+                        let segment_x = segment.x * scale * effective_motion_scale.0;
+                        let segment_width = segment.width * scale * effective_motion_scale.0;
 
                         texts.push(TextElement {
                             content: segment_text.to_string(),
-                            x: scaled_x + x_offset,
+                            x: scaled_x + segment_x,
                             y: scaled_y,
                             width: segment_width,
                             height: scaled_height,
-                            font_size: scaled_font_size,
-                            color,
+                            font_size: segment.font_size * effective_motion_scale.1 * scale,
+                            color: segment.color,
                             align: TextAlign::Left, // Always left-align segments
-                            weight: if bold {
+                            weight: if segment.bold {
                                 FontWeight::Bold
                             } else {
                                 FontWeight::Normal
                             },
-                            italic,
-                            v_align: styled_data.v_align,
+                            italic: segment.italic,
+                            v_align,
                             clip_bounds: scaled_clip,
                             motion_opacity: effective_motion_opacity,
                             wrap: false, // Don't wrap individual segments
+                            preformatted: false,
+                            ligatures: true,
+                            kerning: true,
                             line_height: styled_data.line_height,
                             measured_width: segment_width,
-                            font_family: styled_data.font_family.clone(),
+                            font_family: segment.font_family,
                             word_spacing: 0.0,
                             z_index: *z_layer,
                             ascender: scaled_ascender * effective_motion_scale.1, // Scale ascender with motion
-                            strikethrough,
-                            underline,
+                            strikethrough: segment.strikethrough,
+                            underline: segment.underline,
                         });
-
-                        x_offset += segment_width;
                     }
                 }
             }
@@ -1974,7 +1901,8 @@ impl RenderContext {
             abs_x + scroll_offset.0 + static_motion_offset.0,
             abs_y + scroll_offset.1 + static_motion_offset.1,
         );
-        for child_id in tree.layout().children(node) {
+        // Portals are collected after the root, outside this subtree's clip
+        for child_id in tree.paint_children(node) {
             self.collect_elements_recursive(
                 tree,
                 child_id,
@@ -2020,6 +1948,21 @@ impl RenderContext {
         self.renderer.texture_format()
     }
 
+    /// Get the underlying GPU renderer
+    pub fn renderer(&self) -> &GpuRenderer {
+        &self.renderer
+    }
+
+    /// Drop cached images and SVGs so they are re-read on the next render
+    ///
+    /// Used by dev-mode hot-reload when asset files change on disk.
+    pub fn clear_asset_caches(&mut self) {
+        self.image_cache.clear();
+        self.svg_cache.clear();
+        self.svg_commands.clear();
+        self.rasterized_svg_cache.clear();
+    }
+
     /// Render a layout tree with dynamic render state overlays
     ///
     /// This method renders:
@@ -2067,12 +2010,13 @@ impl RenderContext {
         // Create a single paint context for all layers with text rendering support
         let mut ctx =
             GpuPaintContext::with_text_context(width as f32, height as f32, &mut self.text_ctx);
+        ctx.set_cached_layers(self.renderer.cached_layer_ids());
 
         // Render with motion animations applied (all layers to same context)
         tree.render_with_motion(&mut ctx, render_state);
 
         // Take the batch
-        let batch = ctx.take_batch();
+        let mut batch = ctx.take_batch();
 
         // Collect text, SVG, and image elements WITH motion state
         let (texts, svgs, images) =
@@ -2178,6 +2122,9 @@ impl RenderContext {
                 generic,
                 font_weight,
                 text.italic,
+                text.preformatted,
+                text.ligatures,
+                text.kerning,
                 layout_height,
             ) {
                 Ok(mut glyphs) => {
@@ -2219,10 +2166,17 @@ impl RenderContext {
         // They will be rendered later via render_rasterized_svgs
 
         self.renderer.resize(width, height);
+        self.renderer
+            .rasterize_cached_layers(std::mem::take(&mut batch.cached_layers));
 
         let has_glass = batch.glass_count() > 0;
         let has_layer_effects_in_batch = batch.has_layer_effects();
 
+        // Glass samples the backdrop outside the damage region, so glass
+        // frames are always redrawn in full
+        let damage = self.damage.take().filter(|_| !has_glass);
+        self.renderer.set_damage_rect(damage);
+
         // Only allocate glass textures when glass is actually used
         if has_glass {
             self.ensure_glass_textures(width, height);
@@ -2345,7 +2299,12 @@ impl RenderContext {
                     self.render_images_ref(target, z0_images);
                 }
 
-                // Render subsequent layers interleaved (primitives and images)
+                // Cached layers composite at the z-layer they were drawn at
+                self.renderer
+                    .render_cached_layers_for_layer(target, &batch, 0);
+
+                // Render subsequent layers interleaved (primitives, cached
+                // layers and images)
                 for z in 1..=max_layer {
                     // Render primitives for this layer
                     let layer_primitives = batch.primitives_for_layer(z);
@@ -2353,6 +2312,8 @@ impl RenderContext {
                         self.renderer
                             .render_primitives_overlay(target, &layer_primitives);
                     }
+                    self.renderer
+                        .render_cached_layers_for_layer(target, &batch, z);
 
                     // Render images for this layer
                     if let Some(layer_images) = images_by_layer.get(&z) {
@@ -2433,13 +2394,29 @@ impl RenderContext {
         if debug.motion {
             self.render_motion_debug(target, tree, width, height);
         }
+        if debug.inspect || tree.is_debug_overlay_enabled() {
+            self.render_inspect_overlay(target, tree, width, height);
+        }
 
         // Return scratch buffers for reuse on next frame
         self.return_scratch_elements(texts, svgs, images);
+        self.renderer.set_damage_rect(None);
 
         Ok(())
     }
 
+    /// Redraw only a region of the target on the next
+    /// [`render_tree_with_motion`](Self::render_tree_with_motion) call
+    ///
+    /// `damage` is in physical pixels, typically
+    /// [`RenderTree::damage_rect`] for the nodes that changed. Everything
+    /// outside it is left untouched, so `target` must still hold the previous
+    /// frame (a retained texture, not a fresh swapchain image). Frames with
+    /// glass, layer effects or a multisampled main pass are redrawn in full.
+    pub fn set_damage_rect(&mut self, damage: Option<Rect>) {
+        self.damage = damage;
+    }
+
     /// Render a tree on top of existing content (no clear)
     ///
     /// This is used for overlay trees (modals, toasts, dialogs) that render
@@ -2458,12 +2435,13 @@ impl RenderContext {
         // Create a single paint context for all layers with text rendering support
         let mut ctx =
             GpuPaintContext::with_text_context(width as f32, height as f32, &mut self.text_ctx);
+        ctx.set_cached_layers(self.renderer.cached_layer_ids());
 
         // Render with motion animations applied (all layers to same context)
         tree.render_with_motion(&mut ctx, render_state);
 
         // Take the batch
-        let batch = ctx.take_batch();
+        let mut batch = ctx.take_batch();
 
         // Collect text, SVG, and image elements WITH motion state
         let (texts, svgs, images) =
@@ -2537,6 +2515,9 @@ impl RenderContext {
                 generic,
                 font_weight,
                 text.italic,
+                text.preformatted,
+                text.ligatures,
+                text.kerning,
                 layout_height,
             ) {
                 let mut glyphs = glyphs;
@@ -2556,6 +2537,8 @@ impl RenderContext {
         // They will be rendered later via render_rasterized_svgs
 
         self.renderer.resize(width, height);
+        self.renderer
+            .rasterize_cached_layers(std::mem::take(&mut batch.cached_layers));
 
         // For overlay rendering, we DON'T have glass effects (overlays are simple)
         // Render primitives without clearing (LoadOp::Load)
@@ -2595,6 +2578,8 @@ impl RenderContext {
             }
         }
 
+        self.renderer.render_cached_layers(target, &batch);
+
         // Images render on top
         self.render_images(target, &images, width as f32, height as f32);
 
@@ -2616,6 +2601,9 @@ impl RenderContext {
         if debug.motion {
             self.render_motion_debug(target, tree, width, height);
         }
+        if debug.inspect || tree.is_debug_overlay_enabled() {
+            self.render_inspect_overlay(target, tree, width, height);
+        }
 
         // Return scratch buffers for reuse on next frame
         self.return_scratch_elements(texts, svgs, images);
@@ -2703,6 +2691,8 @@ fn to_gpu_generic_font(generic: GenericFont) -> GpuGenericFont {
 /// - `text`: Show text bounding boxes and baselines
 /// - `layout`: Show all element bounding boxes (useful for debugging hit-testing)
 /// - `motion`: Show active animation stats overlay
+/// - `inspect`: Show the layout inspector (padding/margin bands, flex arrows and
+///   a panel for the hovered element); not included in `all`
 /// - `all` or `1` or `true`: Show all debug visualizations
 #[derive(Clone, Copy)]
 pub struct DebugMode {
@@ -2712,6 +2702,8 @@ pub struct DebugMode {
     pub layout: bool,
     /// Show motion/animation debug info
     pub motion: bool,
+    /// Show the layout inspector overlay
+    pub inspect: bool,
 }
 
 impl DebugMode {
@@ -2725,17 +2717,19 @@ impl DebugMode {
         let text = all || debug_value == "text";
         let layout = all || debug_value == "layout";
         let motion = all || debug_value == "motion";
+        let inspect = debug_value == "inspect";
 
         Self {
             text,
             layout,
             motion,
+            inspect,
         }
     }
 
     /// Check if any debug mode is enabled
     pub fn any_enabled(&self) -> bool {
-        self.text || self.layout || self.motion
+        self.text || self.layout || self.motion || self.inspect
     }
 }
 
@@ -2920,7 +2914,7 @@ fn collect_debug_bounds_recursive(
 ) {
     use blinc_layout::renderer::ElementType;
 
-    let Some(node_bounds) = tree.layout().get_bounds(node, parent_offset) else {
+    let Some(node_bounds) = tree.paint_bounds(node, parent_offset) else {
         return;
     };
 
@@ -2999,57 +2993,3 @@ fn generate_layout_debug_primitives(bounds: &[DebugBoundsElement]) -> Vec<GpuPri
 
     primitives
 }
-
-/// Scale and translate a path for SVG rendering with tint
-fn scale_and_translate_path(
-    path: &blinc_core::Path,
-    x: f32,
-    y: f32,
-    scale: f32,
-) -> blinc_core::Path {
-    use blinc_core::{PathCommand, Point, Vec2};
-
-    if scale == 1.0 && x == 0.0 && y == 0.0 {
-        return path.clone();
-    }
-
-    let transform_point = |p: Point| -> Point { Point::new(p.x * scale + x, p.y * scale + y) };
-
-    let new_commands: Vec<PathCommand> = path
-        .commands()
-        .iter()
-        .map(|cmd| match cmd {
-            PathCommand::MoveTo(p) => PathCommand::MoveTo(transform_point(*p)),
-            PathCommand::LineTo(p) => PathCommand::LineTo(transform_point(*p)),
-            PathCommand::QuadTo { control, end } => PathCommand::QuadTo {
-                control: transform_point(*control),
-                end: transform_point(*end),
-            },
-            PathCommand::CubicTo {
-                control1,
-                control2,
-                end,
-            } => PathCommand::CubicTo {
-                control1: transform_point(*control1),
-                control2: transform_point(*control2),
-                end: transform_point(*end),
-            },
-            PathCommand::ArcTo {
-                radii,
-                rotation,
-                large_arc,
-                sweep,
-                end,
-            } => PathCommand::ArcTo {
-                radii: Vec2::new(radii.x * scale, radii.y * scale),
-                rotation: *rotation,
-                large_arc: *large_arc,
-                sweep: *sweep,
-                end: transform_point(*end),
-            },
-            PathCommand::Close => PathCommand::Close,
-        })
-        .collect();
-
-    blinc_core::Path::from_commands(new_commands)
-}
@@ -0,0 +1,160 @@
+//! Error boundaries - render a fallback when a UI builder panics
+//!
+//! A panic while building a subtree (an `unwrap` in user code, an index out
+//! of range) would otherwise take the whole app down. An error boundary runs
+//! the builder under `catch_unwind`, logs the panic and renders a fallback in
+//! place of the subtree, so the rest of the UI keeps working.
+//!
+//! The windowed runner wraps the root UI builder in a boundary; use
+//! [`error_boundary`] or [`ErrorBoundaryExt::error_boundary`] to contain
+//! failures closer to where they happen.
+//!
+//! Only code that runs inside the boundary is covered: pass the subtree as a
+//! closure, since children passed to `.child()` have already been built.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use blinc_app::prelude::*;
+//!
+//! div()
+//!     .child(header())
+//!     .error_boundary(
+//!         || user_profile(&profile_id), // may panic
+//!         |message| div().child(text(format!("Couldn't load profile: {message}"))),
+//!     )
+//!     .child(footer())
+//! ```
+
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+
+use blinc_core::Color;
+use blinc_layout::div::{div, Div, ElementBuilder};
+use blinc_layout::text::text;
+
+/// Build a subtree, rendering `fallback` with the panic message if it panics
+///
+/// The panic is logged. If `fallback` panics too, a minimal built-in fallback
+/// is rendered instead, so a failing fallback can't take the app down either.
+pub fn error_boundary<E, B, F>(build: B, fallback: F) -> Div
+where
+    E: ElementBuilder + 'static,
+    B: FnOnce() -> E,
+    F: FnOnce(&str) -> Div,
+{
+    let payload = match panic::catch_unwind(AssertUnwindSafe(build)) {
+        Ok(element) => return div().child(element),
+        Err(payload) => payload,
+    };
+    let message = panic_message(payload.as_ref());
+    tracing::error!("UI builder panicked: {}", message);
+
+    match panic::catch_unwind(AssertUnwindSafe(|| fallback(&message))) {
+        Ok(fallback) => fallback,
+        Err(payload) => {
+            tracing::error!(
+                "Error boundary fallback panicked: {}",
+                panic_message(payload.as_ref())
+            );
+            div().child(text("Something went wrong").color(Color::RED))
+        }
+    }
+}
+
+/// The default fallback: a short notice, with the panic message in debug builds
+pub fn default_error_fallback(message: &str) -> Div {
+    let notice = div()
+        .flex_col()
+        .gap(1.0)
+        .p(3.0)
+        .rounded(6.0)
+        .border(1.0, Color::RED)
+        .bg(Color::rgba(1.0, 0.0, 0.0, 0.08))
+        .child(text("Something went wrong").size(14.0).color(Color::RED));
+    if cfg!(debug_assertions) {
+        notice.child(text(message).size(12.0).color(Color::RED))
+    } else {
+        notice
+    }
+}
+
+/// Extract the message from a panic payload
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Adds [`error_boundary`] children to a `Div`
+pub trait ErrorBoundaryExt {
+    /// Add a child built by `build`, or by `fallback` if `build` panics
+    fn error_boundary<E, B, F>(self, build: B, fallback: F) -> Self
+    where
+        E: ElementBuilder + 'static,
+        B: FnOnce() -> E,
+        F: FnOnce(&str) -> Div;
+}
+
+impl ErrorBoundaryExt for Div {
+    fn error_boundary<E, B, F>(self, build: B, fallback: F) -> Self
+    where
+        E: ElementBuilder + 'static,
+        B: FnOnce() -> E,
+        F: FnOnce(&str) -> Div,
+    {
+        self.child(error_boundary(build, fallback))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(element: &dyn ElementBuilder, out: &mut Vec<String>) {
+        if let Some(info) = element.text_render_info() {
+            out.push(info.content);
+        }
+        for child in element.children_builders() {
+            texts(child.as_ref(), out);
+        }
+    }
+
+    fn broken_widget() -> Div {
+        let items: Vec<u32> = Vec::new();
+        div().child(text(format!("{}", items[3])))
+    }
+
+    #[test]
+    fn test_panicking_builder_renders_fallback() {
+        let ui = div()
+            .child(text("header"))
+            .error_boundary(broken_widget, |message| {
+                div().child(text(format!(
+                    "fallback: {}",
+                    message.contains("index out of bounds")
+                )))
+            })
+            .child(text("footer"));
+
+        let mut out = Vec::new();
+        texts(&ui, &mut out);
+        assert_eq!(out, ["header", "fallback: true", "footer"]);
+
+        // A panicking fallback falls back to the built-in notice
+        let ui = div().error_boundary(broken_widget, |_| panic!("fallback failed"));
+        let mut out = Vec::new();
+        texts(&ui, &mut out);
+        assert_eq!(out, ["Something went wrong"]);
+
+        // Builders that don't panic are untouched
+        let ui = error_boundary(|| text("ok"), default_error_fallback);
+        let mut out = Vec::new();
+        texts(&ui, &mut out);
+        assert_eq!(out, ["ok"]);
+    }
+}
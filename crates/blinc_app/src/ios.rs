@@ -356,14 +356,30 @@ impl IOSRenderContext {
 
     /// Tick scroll physics - must be called every frame for scroll to work
     ///
-    /// Returns true if scroll is animating and needs another frame.
-    /// Call this before `build_ui` or `render_frame`.
+    /// Also ticks press-and-hold gestures (long press, press repeat) and
+    /// advances canvas frame timing. Returns true if scroll is animating, a
+    /// press is held or a canvas asked to keep animating, and another frame
+    /// is needed. Call this before `build_ui` or `render_frame`.
     pub fn tick_scroll(&mut self) -> bool {
         if let Some(ref mut tree) = self.render_tree {
             let current_time = blinc_layout::prelude::elapsed_ms();
             let animating = tree.tick_scroll_physics(current_time);
             tree.process_pending_scroll_refs();
-            animating
+
+            let canvas_animating = tree.canvas_animation_requested();
+            tree.begin_canvas_frame(current_time);
+
+            let router = &mut self.windowed_ctx.event_router;
+            let holding = router.press_hold_ms().is_some();
+            if holding {
+                let (mouse_x, mouse_y) = router.mouse_position();
+                let hold_events = router.on_press_hold(current_time);
+                let hold_ms = router.press_hold_ms().unwrap_or(0);
+                for (node, _) in hold_events {
+                    tree.dispatch_press_hold(node, hold_ms, mouse_x, mouse_y);
+                }
+            }
+            animating || holding || canvas_animating
         } else {
             false
         }
@@ -405,9 +421,11 @@ impl IOSRenderContext {
             tree.compute_layout(self.windowed_ctx.width, self.windowed_ctx.height);
             self.render_tree = Some(tree);
         } else if let Some(ref mut tree) = self.render_tree {
-            // Full rebuild
+            // Full rebuild (canvas frame timing carries over)
             tree.clear_dirty();
-            *tree = RenderTree::from_element(&element);
+            let mut new_tree = RenderTree::from_element(&element);
+            new_tree.transfer_canvas_frame_from(tree);
+            *tree = new_tree;
             tree.set_scale_factor(self.windowed_ctx.scale_factor as f32);
             tree.compute_layout(self.windowed_ctx.width, self.windowed_ctx.height);
         }
@@ -508,6 +526,9 @@ impl IOSRenderContext {
         // Track scroll info for dispatch after regular event handling
         let mut scroll_info: Option<(f32, f32, f32, f32)> = None;
         let mut touch_ended = false;
+        // A Pencil stroke stays with the element it started on
+        let router = &self.windowed_ctx.event_router;
+        let stroke_target = router.pointer_capture().or(router.pressed_target());
 
         // Route touch event through event router
         match touch.phase {
@@ -574,6 +595,29 @@ impl IOSRenderContext {
         // Clear callback
         self.windowed_ctx.event_router.clear_event_callback();
 
+        // Pencil touches also carry pressure and tilt to on_pen handlers
+        let pen = blinc_platform_ios::pen_event(&touch).and_then(|pen| {
+            use blinc_core::events::event_types;
+            let event_type = match pen.phase {
+                blinc_platform::PenPhase::Down => event_types::PEN_DOWN,
+                blinc_platform::PenPhase::Moved => event_types::PEN_MOVE,
+                blinc_platform::PenPhase::Up => event_types::PEN_UP,
+                blinc_platform::PenPhase::Cancelled => return None,
+            };
+            let router = &self.windowed_ctx.event_router;
+            let node = stroke_target
+                .or(router.pressed_target())
+                .or_else(|| router.hit_test(tree, lx, ly).map(|hit| hit.node))?;
+            let state = blinc_core::events::PenState {
+                pressure: pen.pressure,
+                tilt_x: pen.tilt_x,
+                tilt_y: pen.tilt_y,
+                barrel_button: pen.barrel_button,
+                eraser: pen.eraser,
+            };
+            Some((node, event_type, state))
+        });
+
         tracing::trace!(
             "[Blinc] iOS Touch: collected {} pending events",
             pending_events.len()
@@ -585,6 +629,7 @@ impl IOSRenderContext {
 
             if let Some(ref mut tree) = self.render_tree {
                 let router = &self.windowed_ctx.event_router;
+                tree.set_click_count(router.click_count());
                 for event in pending_events {
                     // Get bounds for local coordinate calculation
                     let (bounds_x, bounds_y, bounds_width, bounds_height) = router
@@ -613,6 +658,10 @@ impl IOSRenderContext {
             // The needs_render() check will pick this up for the next frame
         }
 
+        if let (Some((node, event_type, state)), Some(tree)) = (pen, &mut self.render_tree) {
+            tree.dispatch_pen_event(node, event_type, lx, ly, state);
+        }
+
         // Dispatch scroll events (touch scrolling)
         // NOTE: Do NOT set ref_dirty_flag here - that triggers full UI rebuild!
         // Scroll just updates internal offset and needs redraw, not rebuild.
@@ -695,7 +744,11 @@ where
     E: ElementBuilder + 'static,
 {
     let boxed_builder: RustUIBuilder = Box::new(move |ctx, _existing_tree| {
-        let element = builder(ctx);
+        // Render a fallback instead of crashing if the builder panics
+        let element = crate::error_boundary::error_boundary(
+            || builder(ctx),
+            crate::error_boundary::default_error_fallback,
+        );
         let mut tree = RenderTree::from_element(&element);
         tree.set_scale_factor(ctx.scale_factor as f32);
         tree.compute_layout(ctx.width, ctx.height);
@@ -934,6 +987,47 @@ pub extern "C" fn blinc_handle_touch(
     tracing::trace!("[Blinc FFI] blinc_handle_touch completed");
 }
 
+/// Handle an Apple Pencil touch (C FFI for Swift)
+///
+/// Like `blinc_handle_touch`, plus the Pencil's state for `on_pen` handlers.
+///
+/// # Arguments
+/// * `force` - `UITouch.force / maximumPossibleForce` (0.0 - 1.0)
+/// * `altitude` - `UITouch.altitudeAngle` in radians (π/2 = upright)
+/// * `azimuth` - `UITouch.azimuthAngle(in: view)` in radians
+///
+/// # Safety
+/// `ctx` must be a valid pointer returned by `blinc_create_context`.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub extern "C" fn blinc_handle_pencil(
+    ctx: *mut IOSRenderContext,
+    touch_id: u64,
+    x: f32,
+    y: f32,
+    phase: i32,
+    force: f32,
+    altitude: f32,
+    azimuth: f32,
+) {
+    if ctx.is_null() {
+        return;
+    }
+
+    let touch_phase = match phase {
+        0 => TouchPhase::Began,
+        1 => TouchPhase::Moved,
+        2 => TouchPhase::Ended,
+        _ => TouchPhase::Cancelled,
+    };
+
+    let touch = blinc_platform_ios::Touch::with_force(touch_id, x, y, touch_phase, force)
+        .with_pencil(altitude, azimuth);
+    unsafe {
+        (*ctx).handle_touch(touch);
+    }
+}
+
 /// Set the focus state (C FFI for Swift)
 ///
 /// # Safety
@@ -1136,7 +1230,7 @@ pub extern "C" fn blinc_init_gpu(
     width: u32,
     height: u32,
 ) -> *mut IOSGpuRenderer {
-    use blinc_gpu::{GpuRenderer, RendererConfig, TextRenderingContext};
+    use blinc_gpu::{ColorSpace, GpuRenderer, RendererConfig, TextRenderingContext};
 
     if ctx.is_null() || metal_layer.is_null() {
         tracing::error!("blinc_init_gpu: null context or metal_layer");
@@ -1152,6 +1246,9 @@ pub extern "C" fn blinc_init_gpu(
         sample_count: 1,
         texture_format: None,
         unified_text_rendering: true,
+        temporal_aa: false,
+        glyph_atlas: None,
+        color_space: ColorSpace::Srgb,
     };
 
     // Create wgpu instance with Metal backend
@@ -124,6 +124,8 @@ pub fn system_font_paths() -> &'static [&'static str] {
 mod app;
 mod context;
 mod error;
+pub mod error_boundary;
+pub mod persist;
 mod text_measurer;
 
 // Windowed module is compiled for desktop (windowed feature), Android, iOS, Fuchsia, and HarmonyOS
@@ -156,6 +158,7 @@ mod tests;
 pub use app::{BlincApp, BlincConfig};
 pub use context::{DebugMode, RenderContext};
 pub use error::{BlincError, Result};
+pub use error_boundary::{default_error_fallback, error_boundary, ErrorBoundaryExt};
 pub use text_measurer::{init_text_measurer, init_text_measurer_with_registry, FontTextMeasurer};
 
 // Re-export layout API for convenience
@@ -173,6 +176,7 @@ pub mod prelude {
     pub use crate::app::{BlincApp, BlincConfig};
     pub use crate::context::{DebugMode, RenderContext};
     pub use crate::error::{BlincError, Result};
+    pub use crate::error_boundary::{default_error_fallback, error_boundary, ErrorBoundaryExt};
     pub use crate::text_measurer::{init_text_measurer, init_text_measurer_with_registry};
 
     // Layout builders
@@ -0,0 +1,291 @@
+//! Persisted state
+//!
+//! A small key/value store saved as JSON in the app's config directory, so
+//! selected state (window size, last-opened file, preferences) survives
+//! restarts. `WindowedContext::use_persisted_state_keyed` builds on it.
+//!
+//! Values are read back with serde; a value that no longer deserializes
+//! (e.g. after its type changed) is treated as missing, so the caller's
+//! default is used instead. Writes are debounced: the file is written once
+//! values have stopped changing for [`WRITE_DEBOUNCE`], and on shutdown.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// How long values must be unchanged before they're written to disk
+pub const WRITE_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Reads the current value of a watched key, serialized
+type Watcher = Box<dyn Fn() -> Option<Value> + Send>;
+
+/// Key/value store backed by a JSON file
+pub struct PersistedStore {
+    /// File the store loads from and saves to (None = in memory only)
+    path: Option<PathBuf>,
+    /// Current values by key
+    values: Map<String, Value>,
+    /// Live values to copy into `values` on each poll
+    watchers: HashMap<String, Watcher>,
+    /// When values last changed, if they haven't been written since
+    changed_at: Option<Instant>,
+}
+
+/// Shared handle to a persisted store
+pub type SharedPersistedStore = Arc<Mutex<PersistedStore>>;
+
+impl PersistedStore {
+    /// Open the store saved at `path`
+    ///
+    /// A missing or unreadable file gives an empty store; the file is
+    /// (re)created on the first write.
+    pub fn open(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let values = match std::fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+                tracing::warn!("Ignoring unreadable persisted state {:?}: {}", path, e);
+                Map::new()
+            }),
+            Err(_) => Map::new(),
+        };
+        Self {
+            path: Some(path),
+            values,
+            watchers: HashMap::new(),
+            changed_at: None,
+        }
+    }
+
+    /// Create a store that is never written to disk
+    pub fn in_memory() -> Self {
+        Self {
+            path: None,
+            values: Map::new(),
+            watchers: HashMap::new(),
+            changed_at: None,
+        }
+    }
+
+    /// File this store saves to
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Get a value, or `None` if it is missing or no longer deserializes as `T`
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let value = self.values.get(key)?;
+        match T::deserialize(value) {
+            Ok(v) => Some(v),
+            Err(e) => {
+                tracing::warn!(
+                    "Persisted value {:?} has changed shape ({}), using default",
+                    key,
+                    e
+                );
+                None
+            }
+        }
+    }
+
+    /// Set a value; it's written out after the debounce period
+    pub fn set<T: Serialize>(&mut self, key: &str, value: &T) {
+        match serde_json::to_value(value) {
+            Ok(value) => self.update(key, value, Instant::now()),
+            Err(e) => tracing::warn!("Can't persist {:?}: {}", key, e),
+        }
+    }
+
+    /// Whether a key has a watcher registered
+    pub(crate) fn is_watched(&self, key: &str) -> bool {
+        self.watchers.contains_key(key)
+    }
+
+    /// Copy the value returned by `read` into the store on every poll
+    pub(crate) fn watch(&mut self, key: &str, read: impl Fn() -> Option<Value> + Send + 'static) {
+        self.watchers.insert(key.to_string(), Box::new(read));
+    }
+
+    /// Pick up changes from watched values and write them out once settled
+    pub fn poll(&mut self) {
+        self.poll_at(Instant::now());
+    }
+
+    fn poll_at(&mut self, now: Instant) {
+        let changed: Vec<(String, Value)> = self
+            .watchers
+            .iter()
+            .filter_map(|(key, read)| Some((key.clone(), read()?)))
+            .collect();
+        for (key, value) in changed {
+            self.update(&key, value, now);
+        }
+
+        if self
+            .changed_at
+            .is_some_and(|at| now.duration_since(at) >= WRITE_DEBOUNCE)
+        {
+            self.flush();
+        }
+    }
+
+    /// Write pending changes now
+    pub fn flush(&mut self) {
+        if self.changed_at.take().is_none() {
+            return;
+        }
+        let Some(path) = &self.path else {
+            return;
+        };
+        if let Err(e) = write_atomically(path, &self.values) {
+            tracing::warn!("Failed to save persisted state to {:?}: {}", path, e);
+        }
+    }
+
+    fn update(&mut self, key: &str, value: Value, now: Instant) {
+        if self.values.get(key) != Some(&value) {
+            self.values.insert(key.to_string(), value);
+            self.changed_at = Some(now);
+        }
+    }
+}
+
+impl Drop for PersistedStore {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+/// Write JSON through a temp file so a crash never leaves a truncated file
+fn write_atomically(path: &Path, values: &Map<String, Value>) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let json = serde_json::to_vec_pretty(values).map_err(std::io::Error::other)?;
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, json)?;
+    std::fs::rename(&tmp, path)
+}
+
+static STORE: OnceLock<SharedPersistedStore> = OnceLock::new();
+
+/// The app-wide persisted store
+///
+/// Opened on first use at [`default_store_path`], or in memory if there is
+/// no config directory.
+pub fn persisted_store() -> SharedPersistedStore {
+    STORE
+        .get_or_init(|| {
+            let store = match default_store_path() {
+                Some(path) => PersistedStore::open(path),
+                None => PersistedStore::in_memory(),
+            };
+            Arc::new(Mutex::new(store))
+        })
+        .clone()
+}
+
+/// Use the store at `path` for persisted state
+///
+/// Must be called before any persisted state is used; returns `false` if
+/// the app-wide store was already opened.
+pub fn set_persisted_store_path(path: impl Into<PathBuf>) -> bool {
+    STORE
+        .set(Arc::new(Mutex::new(PersistedStore::open(path))))
+        .is_ok()
+}
+
+/// Per-app state file in the platform config directory
+///
+/// `<config dir>/<executable name>/state.json`; see [`blinc_platform::dirs`].
+pub fn default_store_path() -> Option<PathBuf> {
+    let app = std::env::current_exe()
+        .ok()?
+        .file_stem()?
+        .to_str()?
+        .to_owned();
+    Some(blinc_platform::dirs::app_config_dir(&app)?.join("state.json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct WindowState {
+        width: u32,
+        height: u32,
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("blinc-persist-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir.join("state.json")
+    }
+
+    #[test]
+    fn test_value_survives_restart() {
+        let path = temp_path("restart");
+        let size = Arc::new(Mutex::new(WindowState {
+            width: 800,
+            height: 600,
+        }));
+
+        {
+            let mut store = PersistedStore::open(&path);
+            let watched = Arc::clone(&size);
+            store.watch("window", move || {
+                serde_json::to_value(&*watched.lock().unwrap()).ok()
+            });
+
+            *size.lock().unwrap() = WindowState {
+                width: 1280,
+                height: 720,
+            };
+            let start = Instant::now();
+            store.poll_at(start);
+            // Still settling: nothing written yet
+            assert!(!path.exists());
+            store.poll_at(start + WRITE_DEBOUNCE);
+            assert!(path.exists());
+        }
+
+        let restarted = PersistedStore::open(&path);
+        assert_eq!(
+            restarted.get::<WindowState>("window"),
+            Some(WindowState {
+                width: 1280,
+                height: 720
+            })
+        );
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_schema_change_falls_back_to_default() {
+        let mut store = PersistedStore::in_memory();
+        store.set("window", &"not a window");
+        assert_eq!(store.get::<WindowState>("window"), None);
+        assert_eq!(
+            store.get::<String>("window").as_deref(),
+            Some("not a window")
+        );
+    }
+
+    #[test]
+    fn test_unreadable_file_opens_empty() {
+        let path = temp_path("corrupt");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, b"{ not json").unwrap();
+
+        let store = PersistedStore::open(&path);
+        assert_eq!(store.get::<u32>("anything"), None);
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+}
@@ -582,3 +582,140 @@ fn test_render_tree_reuse() {
     save_to_png(app.device(), app.queue(), &texture, 200, 200, &path);
     println!("Saved: {:?}", path);
 }
+
+#[test]
+fn test_damage_rect_redraws_only_changed_node() {
+    require_gpu!(app);
+
+    let ui = div()
+        .w(200.0)
+        .h(100.0)
+        .flex_row()
+        .bg(Color::WHITE)
+        .child(div().w(100.0).h(100.0).bg(Color::RED))
+        .child(div().w(100.0).h(100.0).bg(Color::GREEN));
+
+    let mut tree = RenderTree::from_element(&ui);
+    tree.compute_layout(200.0, 100.0);
+    let children = tree.layout().children(tree.root().unwrap());
+    let (changed, untouched) = (children[0], children[1]);
+
+    let animations = std::sync::Arc::new(std::sync::Mutex::new(
+        blinc_animation::AnimationScheduler::new(),
+    ));
+    let render_state = blinc_layout::RenderState::new(animations);
+
+    // Stands in for the retained frame: a partial redraw keeps whatever is
+    // outside the damage
+    let texture = app.device().create_texture(&wgpu::TextureDescriptor {
+        label: Some("Retained Test Texture"),
+        size: wgpu::Extent3d {
+            width: 200,
+            height: 100,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Bgra8UnormSrgb,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+            | wgpu::TextureUsages::COPY_SRC
+            | wgpu::TextureUsages::COPY_DST,
+        view_formats: &[],
+    });
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+    app.render_tree_with_motion(&tree, &render_state, &view, 200, 100)
+        .expect("Render failed");
+
+    // Recolor one node through a prop update, and the other behind the
+    // damage tracking's back so only a scissored redraw leaves it stale
+    let mut props = tree.get_render_node(changed).unwrap().props.clone();
+    props.background = Some(Color::BLUE.into());
+    let damage = tree.apply_prop_updates(&[(changed, props)]);
+    assert_eq!(damage, Some(Rect::new(0.0, 0.0, 100.0, 100.0)));
+    tree.update_render_props(untouched, |props| {
+        props.background = Some(Color::BLUE.into());
+    });
+
+    app.context().set_damage_rect(damage);
+    app.render_tree_with_motion(&tree, &render_state, &view, 200, 100)
+        .expect("Render failed");
+
+    let frame = app.capture_frame(&texture).expect("Capture failed");
+    let changed_pixel = frame.get_pixel(50, 50).0;
+    let untouched_pixel = frame.get_pixel(150, 50).0;
+    assert!(
+        changed_pixel[2] > 200 && changed_pixel[0] < 50,
+        "damaged node should be redrawn blue, got {changed_pixel:?}"
+    );
+    assert!(
+        untouched_pixel[1] > 100 && untouched_pixel[2] < 50,
+        "node outside the damage should keep its old green, got {untouched_pixel:?}"
+    );
+}
+
+#[test]
+fn test_cache_layer_rasterizes_subtree_once() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    require_gpu!(app);
+
+    let draws = std::sync::Arc::new(AtomicUsize::new(0));
+    let counter = draws.clone();
+    let ui = div().w(200.0).h(100.0).bg(Color::WHITE).child(
+        div().w(100.0).h(100.0).cache_layer().child(
+            blinc_layout::canvas::canvas(move |ctx, bounds| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                ctx.fill_rect(
+                    Rect::new(0.0, 0.0, bounds.width, bounds.height),
+                    blinc_core::CornerRadius::default(),
+                    blinc_core::Brush::Solid(Color::RED),
+                );
+            })
+            .w(100.0)
+            .h(100.0),
+        ),
+    );
+
+    let mut tree = RenderTree::from_element(&ui);
+    tree.compute_layout(200.0, 100.0);
+    let animations = std::sync::Arc::new(std::sync::Mutex::new(
+        blinc_animation::AnimationScheduler::new(),
+    ));
+    let render_state = blinc_layout::RenderState::new(animations);
+
+    let texture = app.device().create_texture(&wgpu::TextureDescriptor {
+        label: Some("Cache Layer Test Texture"),
+        size: wgpu::Extent3d {
+            width: 200,
+            height: 100,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Bgra8UnormSrgb,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    // Idle frames composite the rasterized layer without drawing the subtree
+    for _ in 0..3 {
+        app.render_tree_with_motion(&tree, &render_state, &view, 200, 100)
+            .expect("Render failed");
+    }
+    assert_eq!(draws.load(Ordering::SeqCst), 1);
+
+    let frame = app.capture_frame(&texture).expect("Capture failed");
+    let cached_pixel = frame.get_pixel(50, 50).0;
+    let background_pixel = frame.get_pixel(150, 50).0;
+    assert!(
+        cached_pixel[0] > 200 && cached_pixel[1] < 50 && cached_pixel[2] < 50,
+        "cached layer should composite red, got {cached_pixel:?}"
+    );
+    assert!(
+        background_pixel.iter().take(3).all(|&c| c > 200),
+        "outside the layer should stay white, got {background_pixel:?}"
+    );
+}
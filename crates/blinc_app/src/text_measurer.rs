@@ -81,6 +81,7 @@ impl FontTextMeasurer {
     pub fn load_font_data(&self, data: Vec<u8>) -> Result<(), blinc_text::TextError> {
         let font = FontFace::from_data(data)?;
         *self.font.lock().unwrap() = Some(font);
+        blinc_layout::invalidate_text_measure_cache();
         Ok(())
     }
 
@@ -168,6 +169,9 @@ impl TextMeasurer for FontTextMeasurer {
         let mut layout_opts = LayoutOptions::default();
         layout_opts.line_height = options.line_height;
         layout_opts.letter_spacing = options.letter_spacing;
+        layout_opts.preformatted = options.preformatted;
+        layout_opts.ligatures = options.ligatures;
+        layout_opts.kerning = options.kerning;
         if let Some(max_width) = options.max_width {
             layout_opts.max_width = Some(max_width);
         } else {
@@ -220,3 +224,35 @@ pub fn init_text_measurer_with_registry(font_registry: Arc<Mutex<FontRegistry>>)
     let measurer = Arc::new(FontTextMeasurer::with_shared_registry(font_registry));
     blinc_layout::set_text_measurer(measurer);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ligatures_and_kerning_change_measured_width() {
+        let mut registry = FontRegistry::new();
+        if registry.load_generic(GenericFont::System).is_err() {
+            // No system font to shape with
+            return;
+        }
+        let measurer = FontTextMeasurer::with_shared_registry(Arc::new(Mutex::new(registry)));
+        let width = |text: &str, options: TextLayoutOptions| {
+            measurer.measure_with_options(text, 48.0, &options).width
+        };
+
+        // A ligature is one glyph, so letter spacing only lands between "f"
+        // and "i" once they are shaped separately
+        let tracked = TextLayoutOptions::new().with_letter_spacing(4.0);
+        let fi = width("fi", tracked.clone());
+        let fi_separate = width("fi", tracked.with_ligatures(false));
+        assert!(
+            (fi_separate - fi - 4.0).abs() < 0.01,
+            "fi: {fi} vs {fi_separate}"
+        );
+
+        let av = width("AV", TextLayoutOptions::new());
+        let av_unkerned = width("AV", TextLayoutOptions::new().with_kerning(false));
+        assert!(av < av_unkerned, "AV: {av} vs {av_unkerned}");
+    }
+}
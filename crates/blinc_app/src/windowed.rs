@@ -23,6 +23,7 @@
 //! ```
 
 use std::hash::Hash;
+use std::path::PathBuf;
 use std::sync::{
     atomic::{AtomicBool, Ordering},
     Arc, Mutex,
@@ -38,16 +39,23 @@ use blinc_layout::overlay_state::{get_overlay_manager, OverlayContext};
 use blinc_layout::prelude::*;
 use blinc_layout::widgets::overlay::{overlay_manager, OverlayManager, OverlayManagerExt};
 use blinc_platform::{
-    ControlFlow, Event, EventLoop, InputEvent, Key, KeyState, LifecycleEvent, MouseEvent, Platform,
-    TouchEvent, Window, WindowConfig, WindowEvent,
+    CompositionEvent, ControlFlow, Event, EventLoop, InputEvent, Key, KeyState, LifecycleEvent,
+    MouseEvent, PenPhase, Platform, TouchEvent, Window, WindowConfig, WindowEvent,
 };
+use serde::{de::DeserializeOwned, Serialize};
 
 use crate::app::BlincApp;
 use crate::error::{BlincError, Result};
+use crate::persist;
 
 /// Shared animation scheduler for the application (thread-safe)
 pub type SharedAnimationScheduler = Arc<Mutex<AnimationScheduler>>;
 
+/// Animation frame rate when plugged in; lowered on battery (see
+/// [`PowerState::target_fps`](blinc_platform::PowerState::target_fps))
+#[cfg(all(feature = "windowed", not(target_os = "android")))]
+const FULL_POWER_FPS: u32 = 120;
+
 // SharedAnimatedValue and SharedAnimatedTimeline are re-exported from blinc_animation
 
 #[cfg(all(feature = "windowed", not(target_os = "android")))]
@@ -109,6 +117,8 @@ pub struct WindowedContext {
     element_registry: SharedElementRegistry,
     /// Callbacks to run after UI is ready (motion bindings registered)
     ready_callbacks: SharedReadyCallbacks,
+    /// Where to save the next rendered frame (see `screenshot`)
+    screenshot_request: Arc<Mutex<Option<PathBuf>>>,
 }
 
 impl WindowedContext {
@@ -150,6 +160,7 @@ impl WindowedContext {
             had_visible_overlays: false,
             element_registry,
             ready_callbacks,
+            screenshot_request: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -189,6 +200,7 @@ impl WindowedContext {
             had_visible_overlays: false,
             element_registry,
             ready_callbacks,
+            screenshot_request: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -228,6 +240,7 @@ impl WindowedContext {
             had_visible_overlays: false,
             element_registry,
             ready_callbacks,
+            screenshot_request: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -267,6 +280,7 @@ impl WindowedContext {
             had_visible_overlays: false,
             element_registry,
             ready_callbacks,
+            screenshot_request: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -379,6 +393,41 @@ impl WindowedContext {
         }
     }
 
+    /// Save the next rendered frame to `path`
+    ///
+    /// The frame is read back from the GPU just before it is presented and
+    /// encoded based on the file extension (e.g. `.png`). Failures are
+    /// logged. Supported by the desktop and Android runners, on surfaces that
+    /// allow copying from the swapchain.
+    pub fn screenshot(&self, path: impl Into<PathBuf>) {
+        if let Ok(mut request) = self.screenshot_request.lock() {
+            *request = Some(path.into());
+        }
+        // Make sure another frame is rendered
+        self.ref_dirty_flag.store(true, Ordering::SeqCst);
+    }
+
+    /// Save the frame in `texture` if a screenshot was requested
+    pub(crate) fn save_requested_screenshot(&self, app: &BlincApp, texture: &wgpu::Texture) {
+        let Some(path) = self
+            .screenshot_request
+            .lock()
+            .ok()
+            .and_then(|mut request| request.take())
+        else {
+            return;
+        };
+        let saved = app.capture_frame(texture).and_then(|image| {
+            image
+                .save(&path)
+                .map_err(|e| BlincError::Other(e.to_string()))
+        });
+        match saved {
+            Ok(()) => tracing::info!("Saved screenshot to {}", path.display()),
+            Err(e) => tracing::error!("Screenshot to {} failed: {}", path.display(), e),
+        }
+    }
+
     // =========================================================================
     // Reactive Signal API
     // =========================================================================
@@ -446,6 +495,40 @@ impl WindowedContext {
         )
     }
 
+    /// Create keyed state that is also saved to disk and restored on the next run
+    ///
+    /// Works like `use_state_keyed()`, but the value is read from the app's
+    /// persisted store (see [`crate::persist`]) on first use, and written back
+    /// shortly after it changes and when the window closes. If the saved value
+    /// is missing or no longer deserializes as `T`, `default` is used.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let sidebar_open = ctx.use_persisted_state_keyed("sidebar_open", true);
+    /// ```
+    pub fn use_persisted_state_keyed<T>(&self, key: &str, default: T) -> State<T>
+    where
+        T: Clone + Send + Serialize + DeserializeOwned + 'static,
+    {
+        let store = persist::persisted_store();
+        let state = self.use_state_keyed(&format!("persisted:{}", key), || {
+            store.lock().unwrap().get(key).unwrap_or(default)
+        });
+
+        let mut store = store.lock().unwrap();
+        if !store.is_watched(key) {
+            let signal = state.signal();
+            let reactive = Arc::clone(&self.reactive);
+            store.watch(key, move || {
+                let value = reactive.lock().unwrap().get(signal)?;
+                serde_json::to_value(value).ok()
+            });
+        }
+
+        state
+    }
+
     /// Create a persistent signal that survives across UI rebuilds (keyed)
     ///
     /// Unlike `use_signal()` which creates a new signal each call, this method
@@ -1301,6 +1384,26 @@ impl WindowedApp {
         let _ = set_global_asset_loader(Box::new(loader));
     }
 
+    /// Route text widget copy/cut/paste through the system clipboard
+    #[cfg(all(feature = "windowed", not(target_os = "android")))]
+    fn init_clipboard() {
+        struct SystemClipboard(blinc_platform_desktop::DesktopClipboard);
+
+        impl blinc_layout::ClipboardProvider for SystemClipboard {
+            fn get_text(&self) -> Option<String> {
+                self.0.get_text()
+            }
+
+            fn set_text(&self, text: &str) {
+                self.0.set_text(text);
+            }
+        }
+
+        blinc_layout::set_clipboard_provider(Box::new(SystemClipboard(
+            blinc_platform_desktop::DesktopClipboard::new(),
+        )));
+    }
+
     /// Initialize the theme system with platform detection
     ///
     /// This sets up the global ThemeState with:
@@ -1310,7 +1413,8 @@ impl WindowedApp {
     #[cfg(all(feature = "windowed", not(target_os = "android")))]
     fn init_theme() {
         use blinc_theme::{
-            detect_system_color_scheme, platform_theme_bundle, set_redraw_callback, ThemeState,
+            detect_system_color_scheme, detect_system_text_scale, platform_theme_bundle,
+            set_redraw_callback, set_system_text_scale, ThemeState,
         };
 
         // Only initialize if not already initialized
@@ -1320,6 +1424,11 @@ impl WindowedApp {
             ThemeState::init(bundle, scheme);
         }
 
+        // Respect the OS "larger text" accessibility setting
+        if let Some(scale) = detect_system_text_scale() {
+            set_system_text_scale(scale);
+        }
+
         // Set up the redraw callback to trigger full UI rebuilds when theme changes
         // We use request_full_rebuild() to trigger all three phases:
         // 1. Tree rebuild - reconstruct UI with new theme values
@@ -1373,6 +1482,9 @@ impl WindowedApp {
         // Initialize the platform asset loader for cross-platform asset loading
         Self::init_asset_loader();
 
+        // Use the system clipboard for text widget copy/paste
+        Self::init_clipboard();
+
         // Initialize the text measurer for accurate text layout
         crate::text_measurer::init_text_measurer();
 
@@ -1396,14 +1508,41 @@ impl WindowedApp {
         let mut ctx: Option<WindowedContext> = None;
         // Persistent render tree for hit testing and dirty tracking
         let mut render_tree: Option<RenderTree> = None;
+        // Tab order and keyboard focus visibility (focus rings)
+        let mut focus = InteractiveContext::new();
+        // Copy of the last frame, for redrawing only what changed
+        let mut retained_frame: Option<RetainedFrame> = None;
         // Track if we need to rebuild UI (e.g., after resize)
         let mut needs_rebuild = true;
+        // Whether spring/keyframe animations were running at the end of the
+        // last frame (animated values can move anything, so redraw in full)
+        let mut animating_last_frame = false;
         // Track if we need to relayout (e.g., after resize even if tree unchanged)
         let mut needs_relayout = false;
+        // Asset hot-reload channel when launched by `blinc dev`
+        let mut reload_client = match blinc_recorder::ReloadClient::from_env() {
+            Some(Ok(client)) => {
+                tracing::info!("Connected to blinc dev server for asset hot-reload");
+                Some(client)
+            }
+            Some(Err(e)) => {
+                tracing::warn!("Failed to connect to blinc dev server: {}", e);
+                None
+            }
+            None => None,
+        };
         // Shared dirty flag for element refs
         let ref_dirty_flag: RefDirtyFlag = Arc::new(AtomicBool::new(false));
         // Shared reactive graph for signal-based state management
         let reactive: SharedReactiveGraph = Arc::new(Mutex::new(ReactiveGraph::new()));
+        // Wake the event loop when a resource fetch finishes
+        {
+            let resource_wake = wake_proxy.clone();
+            reactive
+                .lock()
+                .unwrap()
+                .set_resource_waker(move || resource_wake.wake());
+        }
         // Shared hook state for use_state persistence
         let hooks: SharedHookState = Arc::new(Mutex::new(HookState::new()));
 
@@ -1523,7 +1662,7 @@ impl WindowedApp {
                                     // Use the same texture format that the renderer's pipelines use
                                     let format = blinc_app.texture_format();
                                     let config = wgpu::SurfaceConfiguration {
-                                        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+                                        usage: blinc_app.surface_usage(&surf),
                                         format,
                                         width,
                                         height,
@@ -1636,7 +1775,21 @@ impl WindowedApp {
                         }
                     }
 
+                    Event::Lifecycle(LifecycleEvent::PowerStateChanged(power)) => {
+                        // Animations drive redraws, so ticking them less often on
+                        // battery or in power-saving mode lowers the frame rate
+                        let fps = power.target_fps(FULL_POWER_FPS);
+                        tracing::debug!(
+                            "Power state changed (on_battery={}, low_power_mode={}), animating at {}fps",
+                            power.on_battery,
+                            power.low_power_mode,
+                            fps
+                        );
+                        animations.lock().unwrap().set_target_fps(fps);
+                    }
+
                     Event::Window(WindowEvent::CloseRequested) => {
+                        persist::persisted_store().lock().unwrap().flush();
                         return ControlFlow::Exit;
                     }
 
@@ -1699,7 +1852,7 @@ impl WindowedApp {
                         }
 
                         // First phase: collect events using immutable borrow
-                        let (pending_events, keyboard_events, scroll_ended, gesture_ended, scroll_info) = if let (Some(ref mut windowed_ctx), Some(ref tree)) =
+                        let (pending_events, keyboard_events, scroll_ended, gesture_ended, scroll_info, pen_event) = if let (Some(ref mut windowed_ctx), Some(ref tree)) =
                             (&mut ctx, &render_tree)
                         {
                             let router = &mut windowed_ctx.event_router;
@@ -1714,6 +1867,8 @@ impl WindowedApp {
                             let mut gesture_ended = false;
                             // Track scroll info for nested scroll dispatch (mouse_x, mouse_y, delta_x, delta_y)
                             let mut scroll_info: Option<(f32, f32, f32, f32)> = None;
+                            // Pen event to dispatch with stylus state (node, type, x, y, pen)
+                            let mut pen_event: Option<(LayoutNodeId, u32, f32, f32, blinc_core::events::PenState)> = None;
 
                             // Set up callback to collect events
                             router.set_event_callback({
@@ -1895,58 +2050,15 @@ impl WindowedApp {
                                 InputEvent::Keyboard(kb_event) => {
                                     let mods = &kb_event.modifiers;
 
-                                    // Extract character from key if applicable
-                                    let key_char = match &kb_event.key {
-                                        Key::Char(c) => Some(*c),
-                                        Key::Space => Some(' '),
-                                        Key::A => Some(if mods.shift { 'A' } else { 'a' }),
-                                        Key::B => Some(if mods.shift { 'B' } else { 'b' }),
-                                        Key::C => Some(if mods.shift { 'C' } else { 'c' }),
-                                        Key::D => Some(if mods.shift { 'D' } else { 'd' }),
-                                        Key::E => Some(if mods.shift { 'E' } else { 'e' }),
-                                        Key::F => Some(if mods.shift { 'F' } else { 'f' }),
-                                        Key::G => Some(if mods.shift { 'G' } else { 'g' }),
-                                        Key::H => Some(if mods.shift { 'H' } else { 'h' }),
-                                        Key::I => Some(if mods.shift { 'I' } else { 'i' }),
-                                        Key::J => Some(if mods.shift { 'J' } else { 'j' }),
-                                        Key::K => Some(if mods.shift { 'K' } else { 'k' }),
-                                        Key::L => Some(if mods.shift { 'L' } else { 'l' }),
-                                        Key::M => Some(if mods.shift { 'M' } else { 'm' }),
-                                        Key::N => Some(if mods.shift { 'N' } else { 'n' }),
-                                        Key::O => Some(if mods.shift { 'O' } else { 'o' }),
-                                        Key::P => Some(if mods.shift { 'P' } else { 'p' }),
-                                        Key::Q => Some(if mods.shift { 'Q' } else { 'q' }),
-                                        Key::R => Some(if mods.shift { 'R' } else { 'r' }),
-                                        Key::S => Some(if mods.shift { 'S' } else { 's' }),
-                                        Key::T => Some(if mods.shift { 'T' } else { 't' }),
-                                        Key::U => Some(if mods.shift { 'U' } else { 'u' }),
-                                        Key::V => Some(if mods.shift { 'V' } else { 'v' }),
-                                        Key::W => Some(if mods.shift { 'W' } else { 'w' }),
-                                        Key::X => Some(if mods.shift { 'X' } else { 'x' }),
-                                        Key::Y => Some(if mods.shift { 'Y' } else { 'y' }),
-                                        Key::Z => Some(if mods.shift { 'Z' } else { 'z' }),
-                                        Key::Num0 => Some(if mods.shift { ')' } else { '0' }),
-                                        Key::Num1 => Some(if mods.shift { '!' } else { '1' }),
-                                        Key::Num2 => Some(if mods.shift { '@' } else { '2' }),
-                                        Key::Num3 => Some(if mods.shift { '#' } else { '3' }),
-                                        Key::Num4 => Some(if mods.shift { '$' } else { '4' }),
-                                        Key::Num5 => Some(if mods.shift { '%' } else { '5' }),
-                                        Key::Num6 => Some(if mods.shift { '^' } else { '6' }),
-                                        Key::Num7 => Some(if mods.shift { '&' } else { '7' }),
-                                        Key::Num8 => Some(if mods.shift { '*' } else { '8' }),
-                                        Key::Num9 => Some(if mods.shift { '(' } else { '9' }),
-                                        Key::Minus => Some(if mods.shift { '_' } else { '-' }),
-                                        Key::Equals => Some(if mods.shift { '+' } else { '=' }),
-                                        Key::LeftBracket => Some(if mods.shift { '{' } else { '[' }),
-                                        Key::RightBracket => Some(if mods.shift { '}' } else { ']' }),
-                                        Key::Backslash => Some(if mods.shift { '|' } else { '\\' }),
-                                        Key::Semicolon => Some(if mods.shift { ':' } else { ';' }),
-                                        Key::Quote => Some(if mods.shift { '"' } else { '\'' }),
-                                        Key::Comma => Some(if mods.shift { '<' } else { ',' }),
-                                        Key::Period => Some(if mods.shift { '>' } else { '.' }),
-                                        Key::Slash => Some(if mods.shift { '?' } else { '/' }),
-                                        Key::Grave => Some(if mods.shift { '~' } else { '`' }),
-                                        _ => None,
+                                    // Text the press produced under the active keyboard layout.
+                                    // Backends that don't report it fall back to a US layout.
+                                    let key_chars: Vec<char> = match &kb_event.text {
+                                        Some(text) => {
+                                            text.chars().filter(|c| !c.is_control()).collect()
+                                        }
+                                        None => us_layout_char(&kb_event.key, mods.shift)
+                                            .into_iter()
+                                            .collect(),
                                     };
 
                                     // Key code for special key handling (backspace, arrows, etc)
@@ -1966,6 +2078,23 @@ impl WindowedApp {
                                     };
 
                                     match kb_event.state {
+                                        // Tab moves focus between focusable elements
+                                        // instead of reaching the focused one
+                                        KeyState::Pressed
+                                            if kb_event.key == Key::Tab
+                                                && !(mods.ctrl || mods.alt || mods.meta)
+                                                && !focus.tab_sequence().is_empty() =>
+                                        {
+                                            focus.sync_focus(router.focused());
+                                            let next = if mods.shift {
+                                                focus.focus_prev()
+                                            } else {
+                                                focus.focus_next()
+                                            };
+                                            router.set_focus(next);
+                                            // Draw the focus ring
+                                            window.request_redraw();
+                                        }
                                         KeyState::Pressed => {
                                             // Handle Escape key for overlays first
                                             // If an overlay handles it, don't propagate further
@@ -1981,9 +2110,10 @@ impl WindowedApp {
 
                                             // For character-producing keys, dispatch TEXT_INPUT
                                             // We use broadcast dispatch so any focused text input can receive it
-                                            if let Some(c) = key_char {
-                                                // Don't send text input if ctrl/cmd is held (shortcuts)
-                                                if !mods.ctrl && !mods.meta {
+                                            for c in key_chars {
+                                                // Don't send text input if ctrl/cmd is held (shortcuts).
+                                                // Ctrl+Alt is AltGr on Windows, which types text.
+                                                if (!mods.ctrl || mods.alt) && !mods.meta {
                                                     keyboard_events.push(PendingEvent {
                                                         event_type: blinc_core::events::event_types::TEXT_INPUT,
                                                         key_char: Some(c),
@@ -2098,12 +2228,86 @@ impl WindowedApp {
                                     // Scroll momentum ended - full stop
                                     scroll_ended = true;
                                 }
+                                InputEvent::Composition(composition) => {
+                                    // Text inputs don't draw the pending composition yet;
+                                    // the composed text arrives as ordinary text input
+                                    if let CompositionEvent::Commit(text) = composition {
+                                        for c in text.chars().filter(|c| !c.is_control()) {
+                                            keyboard_events.push(PendingEvent {
+                                                event_type: blinc_core::events::event_types::TEXT_INPUT,
+                                                key_char: Some(c),
+                                                ..Default::default()
+                                            });
+                                        }
+                                    }
+                                }
+                                InputEvent::Pen(pen) => {
+                                    use blinc_core::events::event_types;
+
+                                    // Pen input also drives the pointer (like touch), so
+                                    // clicks and drags work on elements without on_pen
+                                    let lx = pen.x / scale;
+                                    let ly = pen.y / scale;
+                                    // A stroke stays with the element it started on
+                                    let stroke_target = router.pointer_capture().or(router.pressed_target());
+                                    let event_type = match pen.phase {
+                                        PenPhase::Down => {
+                                            router.on_mouse_down(tree, lx, ly, MouseButton::Left);
+                                            let (local_x, local_y) = router.last_hit_local();
+                                            let (bounds_x, bounds_y) = router.last_hit_bounds_pos();
+                                            let (bounds_width, bounds_height) = router.last_hit_bounds();
+                                            for event in pending_events.iter_mut() {
+                                                event.mouse_x = lx;
+                                                event.mouse_y = ly;
+                                                event.local_x = local_x;
+                                                event.local_y = local_y;
+                                                event.bounds_x = bounds_x;
+                                                event.bounds_y = bounds_y;
+                                                event.bounds_width = bounds_width;
+                                                event.bounds_height = bounds_height;
+                                            }
+                                            Some(event_types::PEN_DOWN)
+                                        }
+                                        PenPhase::Moved => {
+                                            router.on_mouse_move(tree, lx, ly);
+                                            Some(event_types::PEN_MOVE)
+                                        }
+                                        PenPhase::Up => {
+                                            router.on_mouse_up(tree, lx, ly, MouseButton::Left);
+                                            Some(event_types::PEN_UP)
+                                        }
+                                        PenPhase::Cancelled => {
+                                            router.on_mouse_leave();
+                                            None
+                                        }
+                                    };
+                                    if !matches!(pen.phase, PenPhase::Down | PenPhase::Cancelled) {
+                                        for event in pending_events.iter_mut() {
+                                            event.mouse_x = lx;
+                                            event.mouse_y = ly;
+                                        }
+                                    }
+
+                                    let target = stroke_target
+                                        .or(router.pressed_target())
+                                        .or_else(|| router.hit_test(tree, lx, ly).map(|hit| hit.node));
+                                    if let (Some(event_type), Some(node)) = (event_type, target) {
+                                        let state = blinc_core::events::PenState {
+                                            pressure: pen.pressure,
+                                            tilt_x: pen.tilt_x,
+                                            tilt_y: pen.tilt_y,
+                                            barrel_button: pen.barrel_button,
+                                            eraser: pen.eraser,
+                                        };
+                                        pen_event = Some((node, event_type, lx, ly, state));
+                                    }
+                                }
                             }
 
                             router.clear_event_callback();
-                            (pending_events, keyboard_events, scroll_ended, gesture_ended, scroll_info)
+                            (pending_events, keyboard_events, scroll_ended, gesture_ended, scroll_info, pen_event)
                         } else {
-                            (Vec::new(), Vec::new(), false, false, None)
+                            (Vec::new(), Vec::new(), false, false, None, None)
                         };
 
                         // Second phase: dispatch events with mutable borrow
@@ -2187,6 +2391,7 @@ impl WindowedApp {
                             // Dispatch mouse/touch events (scroll is handled above with nested support)
                             if let Some(ref mut windowed_ctx) = ctx {
                                 let router = &windowed_ctx.event_router;
+                                tree.set_click_count(router.click_count());
                                 for event in pending_events {
                                     // Skip scroll events - already handled with nested scroll support
                                     if event.event_type == blinc_core::events::event_types::SCROLL {
@@ -2221,6 +2426,11 @@ impl WindowedApp {
                                 }
                             }
 
+                            // Pen events carry pressure and tilt to on_pen handlers
+                            if let Some((node, event_type, x, y, pen)) = pen_event {
+                                tree.dispatch_pen_event(node, event_type, x, y, pen);
+                            }
+
                             // Note: Overlay events are now dispatched through the main tree
                             // since overlays are composed into the main tree via build_overlay_layer()
 
@@ -2260,10 +2470,20 @@ impl WindowedApp {
                                 // Request redraw to animate bounce-back
                                 window.request_redraw();
                             }
+
+                            // Restyle containers whose descendants gained or lost hover/focus
+                            if let Some(ref windowed_ctx) = ctx {
+                                if tree.apply_within_styles(&windowed_ctx.event_router) {
+                                    window.request_redraw();
+                                }
+                            }
                         }
                     }
 
                     Event::Frame => {
+                        // Save persisted state once it has settled
+                        persist::persisted_store().lock().unwrap().poll();
+
                         if let (
                             Some(ref mut blinc_app),
                             Some(ref surf),
@@ -2318,6 +2538,35 @@ impl WindowedApp {
                                 false
                             };
 
+                            // Screen region changed by incremental prop updates. Any
+                            // other change this frame forces a full redraw.
+                            let mut frame_damage: Option<blinc_core::Rect> = None;
+                            let mut full_redraw = scroll_animating;
+
+                            // Mount/unmount deferred subtrees that scrolling
+                            // moved near to or far from the viewport
+                            if let Some(ref mut tree) = render_tree {
+                                if tree.update_lazy_mounts() {
+                                    tree.compute_layout(windowed_ctx.width, windowed_ctx.height);
+                                    full_redraw = true;
+                                }
+                            }
+
+                            // Tick press-and-hold gestures (long press, press repeat)
+                            // every frame while the pointer is held still
+                            if windowed_ctx.event_router.press_hold_ms().is_some() {
+                                if let Some(ref mut tree) = render_tree {
+                                    let router = &mut windowed_ctx.event_router;
+                                    let (mouse_x, mouse_y) = router.mouse_position();
+                                    let hold_events = router.on_press_hold(current_time);
+                                    let hold_ms = router.press_hold_ms().unwrap_or(0);
+                                    for (node, _) in hold_events {
+                                        tree.dispatch_press_hold(node, hold_ms, mouse_x, mouse_y);
+                                    }
+                                }
+                                window.request_redraw();
+                            }
+
                             // =========================================================
                             // PHASE 1: Check if tree structure needs rebuild
                             // Only structural changes require tree rebuild
@@ -2337,12 +2586,28 @@ impl WindowedApp {
                                 needs_rebuild = true;
                             }
 
+                            // Apply finished resource fetches
+                            if reactive.lock().unwrap().poll_resources() {
+                                tracing::debug!("Rebuild triggered by: resource fetch");
+                                needs_rebuild = true;
+                            }
+
                             // Check if text widgets requested a rebuild (focus/text changes)
                             if blinc_layout::widgets::take_needs_rebuild() {
                                 tracing::debug!("Rebuild triggered by: text widget state change");
                                 needs_rebuild = true;
                             }
 
+                            // Check if `blinc dev` reported changed asset files
+                            if let Some(ref mut client) = reload_client {
+                                let changed = client.poll();
+                                if !changed.is_empty() {
+                                    tracing::info!("Reloading assets: {}", changed.join(", "));
+                                    blinc_app.context().clear_asset_caches();
+                                    needs_rebuild = true;
+                                }
+                            }
+
                             // Check if a full relayout was requested (e.g., theme changes)
                             if blinc_layout::widgets::take_needs_relayout() {
                                 tracing::debug!("Relayout triggered by: theme or global state change");
@@ -2416,12 +2681,10 @@ impl WindowedApp {
                                 let prop_updates = blinc_layout::take_pending_prop_updates();
                                 let had_prop_updates = !prop_updates.is_empty();
 
-                                // Apply prop updates to the main tree
+                                // Apply prop updates to the main tree, noting where they paint
                                 // (Overlays are now part of the main tree, so all nodes are here)
                                 if let Some(ref mut tree) = render_tree {
-                                    for (node_id, props) in &prop_updates {
-                                        tree.update_render_props(*node_id, |p| *p = props.clone());
-                                    }
+                                    frame_damage = tree.apply_prop_updates(&prop_updates);
                                 }
 
                                 // Process subtree rebuilds (from stateful changes OR overlay changes)
@@ -2431,6 +2694,7 @@ impl WindowedApp {
                                 }
 
                                 if needs_layout {
+                                    full_redraw = true;
                                     if let Some(ref mut tree) = render_tree {
                                         tracing::debug!("Subtree rebuilds processed, recomputing layout");
                                         tree.compute_layout(windowed_ctx.width, windowed_ctx.height);
@@ -2454,11 +2718,16 @@ impl WindowedApp {
                             // This must happen BEFORE tick() so motion animations are available
                             // =========================================================
 
+                            // Time the build/layout/render phases while a recorder is recording
+                            let mut frame_timer = blinc_recorder::start_frame_timer();
+
                             // Begin stable motion frame tracking
                             // This clears the "used" set so we can detect which motions are no longer in the tree
                             rs.begin_stable_motion_frame();
 
                             if needs_rebuild || render_tree.is_none() {
+                                full_redraw = true;
+
                                 // Reset call counters for stable key generation
                                 reset_call_counters();
 
@@ -2469,8 +2738,12 @@ impl WindowedApp {
                                 // Note: Viewport and overlay state are already updated in PHASE 1
                                 // so build_overlay_layer() has correct dimensions
 
-                                // Build UI element tree
-                                let user_ui = ui_builder(windowed_ctx);
+                                // Build UI element tree, rendering a fallback instead of
+                                // crashing if the builder panics
+                                let user_ui = crate::error_boundary::error_boundary(
+                                    || ui_builder(windowed_ctx),
+                                    crate::error_boundary::default_error_fallback,
+                                );
 
                                 // Compose user UI with overlay layer using a regular Div container
                                 // We use position:relative with the overlay absolutely positioned on top.
@@ -2481,6 +2754,9 @@ impl WindowedApp {
                                     .relative() // positioning context for overlay
                                     .child(user_ui)
                                     .child(overlay_layer);
+                                if let Some(ref mut timer) = frame_timer {
+                                    timer.span(blinc_recorder::FramePhase::Build, "ui_builder");
+                                }
 
                                 // Use incremental update if we have an existing tree
                                 // BUT: Skip incremental update during resize - do full rebuild instead
@@ -2517,6 +2793,9 @@ impl WindowedApp {
                                         // Process any motion replay requests queued during tree building
                                         rs.process_global_motion_replays();
 
+                                        // Keep canvas frame timing continuous
+                                        tree.transfer_canvas_frame_from(existing_tree);
+
                                         // Replace existing tree with fresh one
                                         *existing_tree = tree;
 
@@ -2586,6 +2865,19 @@ impl WindowedApp {
                                 }
 
                                 needs_rebuild = false;
+
+                                // Refresh Tab order from the new tree. Focus on a
+                                // removed element moves to its neighbor.
+                                if let Some(ref tree) = render_tree {
+                                    let router = &mut windowed_ctx.event_router;
+                                    focus.sync_focus(router.focused());
+                                    let before = focus.focused();
+                                    focus.set_focus_order(tree.focus_order());
+                                    if focus.focused() != before {
+                                        router.set_focus(focus.focused());
+                                    }
+                                }
+
                                 let was_first_rebuild = windowed_ctx.rebuild_count == 0;
                                 windowed_ctx.rebuild_count = windowed_ctx.rebuild_count.saturating_add(1);
 
@@ -2611,6 +2903,10 @@ impl WindowedApp {
                             // after the first rebuild are executed immediately since the UI
                             // is already ready at that point.
 
+                            if let Some(ref mut timer) = frame_timer {
+                                timer.span(blinc_recorder::FramePhase::Layout, "tree_update");
+                            }
+
                             // =========================================================
                             // PHASE 3: Tick animations and dynamic render state
                             // This must happen AFTER tree rebuild so motions are initialized
@@ -2639,24 +2935,92 @@ impl WindowedApp {
                             // Note: scroll physics tick moved to before PHASE 1 (before any rebuilds)
                             // so that ScrollRef has up-to-date values when stateful components rebuild
 
+                            if let Some(ref mut timer) = frame_timer {
+                                timer.span(blinc_recorder::FramePhase::Render, "tick");
+                            }
+
                             // =========================================================
                             // PHASE 4: Render
                             // Combines stable tree structure with dynamic render state
                             // =========================================================
 
+                            // The focus ring is an overlay, so drawing, moving or
+                            // removing it needs a full redraw
+                            let mut focus_ring = false;
+                            if let Some(ref mut tree) = render_tree {
+                                // Advance frame timing for canvas render callbacks
+                                tree.begin_canvas_frame(current_time);
+
+                                // Clicks hide the focus ring; Tab scrolls the newly
+                                // focused element into view
+                                focus.sync_focus(windowed_ctx.event_router.focused());
+                                if tree.process_scroll_into_view(&mut focus) {
+                                    window.request_redraw();
+                                }
+                                focus_ring = tree.add_focus_ring(&focus, rs);
+                                focus_ring |= !focus.take_dirty().is_empty();
+
+                                // The layout inspector describes the element under the pointer
+                                if tree.is_debug_overlay_enabled()
+                                    || crate::context::DebugMode::from_env().inspect
+                                {
+                                    let router = &windowed_ctx.event_router;
+                                    let (mx, my) = router.mouse_position();
+                                    let hovered = router.hit_test(tree, mx, my).map(|hit| hit.node);
+                                    tree.set_debug_inspect_target(hovered);
+                                }
+                            }
+
                             if let Some(ref tree) = render_tree {
+                                // Anything animating or drawn over the tree can change
+                                // outside the damaged nodes
+                                full_redraw |= theme_animating
+                                    || animating_last_frame
+                                    || focus_ring
+                                    || rs.has_active_motions()
+                                    || windowed_ctx.overlay_manager.has_visible_overlays()
+                                    || blinc_layout::widgets::has_focused_text_input()
+                                    || tree.canvas_animation_requested()
+                                    || tree.is_debug_overlay_enabled()
+                                    || crate::context::DebugMode::from_env().inspect;
+
+                                // Render into the retained frame when the surface can be
+                                // copied into, so partial redraws keep the rest of the
+                                // previous frame
+                                let retained = RetainedFrame::prepare(
+                                    &mut retained_frame,
+                                    blinc_app.device(),
+                                    config,
+                                );
+                                let damage = match retained {
+                                    Some(ref retained) if retained.valid && !full_redraw => {
+                                        frame_damage
+                                    }
+                                    _ => None,
+                                };
+                                blinc_app.context().set_damage_rect(damage);
+                                let target = match retained {
+                                    Some(ref retained) => retained.buffer.write_target(),
+                                    None => &view,
+                                };
+
                                 // Render with motion animations
                                 // Use physical pixel dimensions for the render surface
                                 let result = blinc_app.render_tree_with_motion(
                                     tree,
                                     rs,
-                                    &view,
+                                    target,
                                     windowed_ctx.physical_width as u32,
                                     windowed_ctx.physical_height as u32,
                                 );
-                                if let Err(e) = result {
+                                if let Err(ref e) = result {
                                     tracing::error!("Render error: {}", e);
                                 }
+                                if let Some(retained) = retained {
+                                    // A frame that fails partway can't seed a partial redraw
+                                    retained.valid = result.is_ok();
+                                    retained.present(blinc_app, &frame.texture);
+                                }
                             }
 
                             // =========================================================
@@ -2673,8 +3037,14 @@ impl WindowedApp {
                             let has_visible_overlays = windowed_ctx.overlay_manager.has_visible_overlays();
                             windowed_ctx.had_visible_overlays = has_visible_overlays;
 
+                            windowed_ctx.save_requested_screenshot(blinc_app, &frame.texture);
                             frame.present();
 
+                            if let Some(mut timer) = frame_timer {
+                                timer.span(blinc_recorder::FramePhase::Render, "draw");
+                                blinc_recorder::record_frame_timing(timer.finish());
+                            }
+
                             // =========================================================
                             // PHASE 5: Request next frame if animations are active
                             // This ensures smooth animation without waiting for events
@@ -2686,6 +3056,7 @@ impl WindowedApp {
                             let scheduler = windowed_ctx.animations.lock().unwrap();
                             let needs_animation_redraw = scheduler.take_needs_redraw();
                             drop(scheduler); // Release lock before request_redraw
+                            animating_last_frame = needs_animation_redraw;
 
                             // Check if stateful elements have active spring animations
                             // If so, re-run their callbacks to get updated animation values
@@ -2709,7 +3080,12 @@ impl WindowedApp {
                                 mgr.take_dirty() || mgr.has_visible_overlays()
                             };
 
-                            if needs_animation_redraw || needs_cursor_redraw || needs_motion_redraw || scroll_animating || needs_overlay_redraw || theme_animating {
+                            // Check if a canvas asked to keep animating
+                            let needs_canvas_redraw = render_tree
+                                .as_ref()
+                                .is_some_and(|tree| tree.canvas_animation_requested());
+
+                            if needs_animation_redraw || needs_cursor_redraw || needs_motion_redraw || scroll_animating || needs_overlay_redraw || theme_animating || needs_canvas_redraw {
                                 // Request another frame to render updated animation values
                                 // For cursor blink, also re-request continuous redraw for next frame
                                 if needs_cursor_redraw {
@@ -2746,6 +3122,120 @@ impl WindowedApp {
     }
 }
 
+/// Character a key types on a US layout, for backends that don't report
+/// layout-resolved key text
+#[cfg(all(feature = "windowed", not(target_os = "android")))]
+/// Copy of the last frame, so frames where only a few elements changed can
+/// redraw just their damage region and copy the result to the surface
+struct RetainedFrame {
+    buffer: blinc_gpu::Backbuffer,
+    /// Whether the buffer holds a complete frame to draw a damage region over
+    valid: bool,
+}
+
+impl RetainedFrame {
+    /// Create or resize the retained frame to match the surface
+    ///
+    /// Returns `None` if the surface can't be copied into, in which case
+    /// every frame renders straight to the surface in full.
+    fn prepare<'a>(
+        slot: &'a mut Option<Self>,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+    ) -> Option<&'a mut Self> {
+        if !config.usage.contains(wgpu::TextureUsages::COPY_DST) {
+            *slot = None;
+            return None;
+        }
+
+        let retained = slot.get_or_insert_with(|| Self {
+            buffer: blinc_gpu::Backbuffer::new(
+                device,
+                config.width,
+                config.height,
+                blinc_gpu::BackbufferConfig {
+                    // A single buffer keeps its content between frames
+                    buffer_count: 1,
+                    format: config.format,
+                    ..Default::default()
+                },
+            ),
+            valid: false,
+        });
+        if retained.buffer.dimensions() != (config.width, config.height) {
+            retained.buffer.resize(device, config.width, config.height);
+            retained.valid = false;
+        }
+        Some(retained)
+    }
+
+    /// Copy the frame into the surface texture
+    fn present(&self, app: &BlincApp, surface_texture: &wgpu::Texture) {
+        let mut encoder = app
+            .device()
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Blinc Retained Frame Copy"),
+            });
+        self.buffer.copy_to_surface(&mut encoder, surface_texture);
+        app.queue().submit(std::iter::once(encoder.finish()));
+    }
+}
+
+fn us_layout_char(key: &Key, shift: bool) -> Option<char> {
+    match key {
+        Key::Char(c) => Some(*c),
+        Key::Space => Some(' '),
+        Key::A => Some(if shift { 'A' } else { 'a' }),
+        Key::B => Some(if shift { 'B' } else { 'b' }),
+        Key::C => Some(if shift { 'C' } else { 'c' }),
+        Key::D => Some(if shift { 'D' } else { 'd' }),
+        Key::E => Some(if shift { 'E' } else { 'e' }),
+        Key::F => Some(if shift { 'F' } else { 'f' }),
+        Key::G => Some(if shift { 'G' } else { 'g' }),
+        Key::H => Some(if shift { 'H' } else { 'h' }),
+        Key::I => Some(if shift { 'I' } else { 'i' }),
+        Key::J => Some(if shift { 'J' } else { 'j' }),
+        Key::K => Some(if shift { 'K' } else { 'k' }),
+        Key::L => Some(if shift { 'L' } else { 'l' }),
+        Key::M => Some(if shift { 'M' } else { 'm' }),
+        Key::N => Some(if shift { 'N' } else { 'n' }),
+        Key::O => Some(if shift { 'O' } else { 'o' }),
+        Key::P => Some(if shift { 'P' } else { 'p' }),
+        Key::Q => Some(if shift { 'Q' } else { 'q' }),
+        Key::R => Some(if shift { 'R' } else { 'r' }),
+        Key::S => Some(if shift { 'S' } else { 's' }),
+        Key::T => Some(if shift { 'T' } else { 't' }),
+        Key::U => Some(if shift { 'U' } else { 'u' }),
+        Key::V => Some(if shift { 'V' } else { 'v' }),
+        Key::W => Some(if shift { 'W' } else { 'w' }),
+        Key::X => Some(if shift { 'X' } else { 'x' }),
+        Key::Y => Some(if shift { 'Y' } else { 'y' }),
+        Key::Z => Some(if shift { 'Z' } else { 'z' }),
+        Key::Num0 => Some(if shift { ')' } else { '0' }),
+        Key::Num1 => Some(if shift { '!' } else { '1' }),
+        Key::Num2 => Some(if shift { '@' } else { '2' }),
+        Key::Num3 => Some(if shift { '#' } else { '3' }),
+        Key::Num4 => Some(if shift { '$' } else { '4' }),
+        Key::Num5 => Some(if shift { '%' } else { '5' }),
+        Key::Num6 => Some(if shift { '^' } else { '6' }),
+        Key::Num7 => Some(if shift { '&' } else { '7' }),
+        Key::Num8 => Some(if shift { '*' } else { '8' }),
+        Key::Num9 => Some(if shift { '(' } else { '9' }),
+        Key::Minus => Some(if shift { '_' } else { '-' }),
+        Key::Equals => Some(if shift { '+' } else { '=' }),
+        Key::LeftBracket => Some(if shift { '{' } else { '[' }),
+        Key::RightBracket => Some(if shift { '}' } else { ']' }),
+        Key::Backslash => Some(if shift { '|' } else { '\\' }),
+        Key::Semicolon => Some(if shift { ':' } else { ';' }),
+        Key::Quote => Some(if shift { '"' } else { '\'' }),
+        Key::Comma => Some(if shift { '<' } else { ',' }),
+        Key::Period => Some(if shift { '>' } else { '.' }),
+        Key::Slash => Some(if shift { '?' } else { '/' }),
+        Key::Grave => Some(if shift { '~' } else { '`' }),
+        _ => None,
+    }
+}
+
 /// Convert platform mouse button to layout mouse button
 #[cfg(all(feature = "windowed", not(target_os = "android")))]
 fn convert_mouse_button(button: blinc_platform::MouseButton) -> MouseButton {
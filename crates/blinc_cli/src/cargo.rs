@@ -0,0 +1,326 @@
+//! Native builds via Cargo
+//!
+//! Rust-first Blinc projects are plain Cargo packages, so `blinc build` for
+//! native targets maps the target name to a triple, takes the feature set and
+//! output directory from `blinc.toml` and runs `cargo build`, then copies the
+//! produced executable to the requested output.
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use tracing::info;
+
+use crate::config::BlincConfig;
+use crate::doctor::get_installed_targets;
+
+/// Native targets `blinc build` can produce with cargo
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NativeTarget {
+    /// Whatever the host is
+    Desktop,
+    Macos,
+    Windows,
+    Linux,
+}
+
+impl NativeTarget {
+    /// Parse a CLI target name; `None` for non-native targets
+    pub fn parse(target: &str) -> Option<Self> {
+        match target {
+            "desktop" => Some(Self::Desktop),
+            "macos" => Some(Self::Macos),
+            "windows" => Some(Self::Windows),
+            "linux" => Some(Self::Linux),
+            _ => None,
+        }
+    }
+
+    /// Target triple to pass to cargo, or `None` to build for the host
+    pub fn triple(self, host: &str) -> Option<String> {
+        let arch = host.split('-').next().unwrap_or("x86_64");
+        let (os_marker, triple) = match self {
+            Self::Desktop => return None,
+            Self::Macos => ("apple-darwin", format!("{}-apple-darwin", arch)),
+            Self::Windows => ("windows", format!("{}-pc-windows-msvc", arch)),
+            Self::Linux => ("linux", format!("{}-unknown-linux-gnu", arch)),
+        };
+        // Building for the host OS doesn't need an explicit triple
+        if host.contains(os_marker) {
+            None
+        } else {
+            Some(triple)
+        }
+    }
+}
+
+/// Everything needed to invoke cargo for a native build
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildPlan {
+    pub triple: Option<String>,
+    pub release: bool,
+    pub features: Vec<String>,
+    /// Cargo target directory (`build.output`)
+    pub target_dir: PathBuf,
+}
+
+impl BuildPlan {
+    pub fn new(
+        target: NativeTarget,
+        host: &str,
+        release: bool,
+        project_dir: &Path,
+        config: &BlincConfig,
+    ) -> Self {
+        Self {
+            triple: target.triple(host),
+            release,
+            features: config.native_features(),
+            target_dir: project_dir.join(&config.build.output),
+        }
+    }
+
+    /// Arguments for `cargo`
+    pub fn cargo_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "build".to_string(),
+            "--message-format=json-render-diagnostics".to_string(),
+        ];
+        if self.release {
+            args.push("--release".to_string());
+        }
+        if let Some(triple) = &self.triple {
+            args.push("--target".to_string());
+            args.push(triple.clone());
+        }
+        if !self.features.is_empty() {
+            args.push("--features".to_string());
+            args.push(self.features.join(","));
+        }
+        args.push("--target-dir".to_string());
+        args.push(self.target_dir.display().to_string());
+        args
+    }
+}
+
+/// Host triple as reported by rustc
+fn host_triple() -> Result<String> {
+    let output = Command::new("rustc")
+        .arg("-vV")
+        .output()
+        .context("Failed to run rustc. Run `blinc doctor` to check your Rust toolchain.")?;
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| line.strip_prefix("host: "))
+        .map(|host| host.trim().to_string())
+        .context("Could not determine host target from `rustc -vV`")
+}
+
+/// Build a Cargo-based project for a native target
+///
+/// Returns the path of the final executable.
+pub fn build_native(
+    project_dir: &Path,
+    config: &BlincConfig,
+    target: NativeTarget,
+    release: bool,
+    output: Option<&Path>,
+) -> Result<PathBuf> {
+    let host = host_triple()?;
+    let plan = BuildPlan::new(target, &host, release, project_dir, config);
+
+    // Cross builds need the std library for the target installed
+    if let Some(triple) = &plan.triple {
+        if let Some(installed) = get_installed_targets() {
+            if !installed.iter().any(|t| t == triple) {
+                anyhow::bail!(
+                    "Rust target '{}' is not installed. Install it with `rustup target add {}` \
+                     (cross-compiling may also need a linker for that platform). \
+                     Run `blinc doctor` to check your setup.",
+                    triple,
+                    triple
+                );
+            }
+        }
+    }
+
+    info!("Running cargo {}", plan.cargo_args().join(" "));
+
+    let mut child = Command::new("cargo")
+        .args(plan.cargo_args())
+        .current_dir(project_dir)
+        .stdout(Stdio::piped())
+        .spawn()
+        .context("Failed to run cargo. Run `blinc doctor` to check your setup.")?;
+
+    // Diagnostics go to stderr; stdout carries JSON messages with artifact paths
+    let mut executable = None;
+    if let Some(stdout) = child.stdout.take() {
+        for line in BufReader::new(stdout).lines() {
+            let line = line?;
+            if let Some(path) = executable_from_message(&line) {
+                executable = Some(path);
+            }
+        }
+    }
+
+    let status = child.wait()?;
+    if !status.success() {
+        anyhow::bail!("cargo build failed ({})", status);
+    }
+
+    let executable = executable.with_context(|| {
+        format!(
+            "cargo build produced no executable. Does the project have a binary target \
+             enabled by the features [{}]?",
+            plan.features.join(", ")
+        )
+    })?;
+
+    match output {
+        Some(out) => {
+            let dest = if out.is_dir() {
+                out.join(executable.file_name().unwrap_or_default())
+            } else {
+                out.to_path_buf()
+            };
+            if let Some(parent) = dest.parent().filter(|p| !p.as_os_str().is_empty()) {
+                fs::create_dir_all(parent)?;
+            }
+            fs::copy(&executable, &dest).with_context(|| {
+                format!(
+                    "Failed to copy {} to {}",
+                    executable.display(),
+                    dest.display()
+                )
+            })?;
+            Ok(dest)
+        }
+        None => Ok(executable),
+    }
+}
+
+/// Extract the executable path from a cargo `compiler-artifact` JSON message
+fn executable_from_message(line: &str) -> Option<PathBuf> {
+    let message: serde_json::Value = serde_json::from_str(line).ok()?;
+    if message.get("reason")?.as_str()? != "compiler-artifact" {
+        return None;
+    }
+    message.get("executable")?.as_str().map(PathBuf::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_native_target_triples() {
+        let host = "aarch64-apple-darwin";
+        assert_eq!(NativeTarget::Desktop.triple(host), None);
+        assert_eq!(NativeTarget::Macos.triple(host), None);
+        assert_eq!(
+            NativeTarget::Windows.triple(host).as_deref(),
+            Some("aarch64-pc-windows-msvc")
+        );
+        assert_eq!(
+            NativeTarget::Linux
+                .triple("x86_64-pc-windows-msvc")
+                .as_deref(),
+            Some("x86_64-unknown-linux-gnu")
+        );
+        assert_eq!(NativeTarget::parse("android"), None);
+    }
+
+    #[test]
+    fn test_build_plan_args() {
+        let config: BlincConfig = toml::from_str(
+            r#"
+[project]
+name = "app"
+
+[build]
+output = "out"
+
+[targets.desktop]
+features = ["desktop", "tracing"]
+"#,
+        )
+        .unwrap();
+        let plan = BuildPlan::new(
+            NativeTarget::Windows,
+            "x86_64-unknown-linux-gnu",
+            true,
+            Path::new("/tmp/app"),
+            &config,
+        );
+        assert_eq!(
+            plan.cargo_args(),
+            vec![
+                "build",
+                "--message-format=json-render-diagnostics",
+                "--release",
+                "--target",
+                "x86_64-pc-windows-msvc",
+                "--features",
+                "desktop,tracing",
+                "--target-dir",
+                "/tmp/app/out",
+            ]
+        );
+
+        // Projects without a desktop target section still get the scaffold's feature
+        let config = BlincConfig::new("app");
+        let plan = BuildPlan::new(
+            NativeTarget::Desktop,
+            "x86_64-unknown-linux-gnu",
+            false,
+            Path::new("/tmp/app"),
+            &config,
+        );
+        assert_eq!(plan.features, vec!["desktop"]);
+        assert_eq!(plan.target_dir, PathBuf::from("/tmp/app/target"));
+    }
+
+    #[test]
+    fn test_executable_from_message() {
+        let artifact =
+            r#"{"reason":"compiler-artifact","executable":"/tmp/app/target/debug/app_desktop"}"#;
+        assert_eq!(
+            executable_from_message(artifact),
+            Some(PathBuf::from("/tmp/app/target/debug/app_desktop"))
+        );
+        let lib = r#"{"reason":"compiler-artifact","executable":null}"#;
+        assert_eq!(executable_from_message(lib), None);
+        assert_eq!(executable_from_message("not json"), None);
+    }
+
+    #[test]
+    #[ignore = "compiles the whole blinc workspace into a scaffolded project"]
+    fn test_build_scaffolded_project() {
+        let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        std::env::set_var("BLINC_PATH", workspace.canonicalize().unwrap());
+
+        let dir = std::env::temp_dir().join(format!("blinc-build-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        crate::project::create_rust_project(&dir, "build-test", "com.example").unwrap();
+
+        let config = BlincConfig::load_from_dir(&dir).unwrap();
+        let output = dir.join("dist");
+        fs::create_dir_all(&output).unwrap();
+        let binary =
+            build_native(&dir, &config, NativeTarget::Desktop, false, Some(&output)).unwrap();
+
+        assert!(binary.starts_with(&output));
+        assert!(dir.join(&config.build.output).is_dir());
+        let metadata = fs::metadata(&binary).unwrap();
+        assert!(metadata.is_file() && metadata.len() > 0);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_ne!(metadata.permissions().mode() & 0o111, 0);
+        }
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -314,7 +314,7 @@ pub struct ProjectConfig {
 }
 
 /// Build configuration
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct BuildConfig {
     /// Entry point file (relative to project root)
     #[serde(default = "default_entry")]
@@ -338,6 +338,17 @@ fn default_output() -> String {
     "target".to_string()
 }
 
+impl Default for BuildConfig {
+    fn default() -> Self {
+        Self {
+            entry: default_entry(),
+            output: default_output(),
+            include: Vec::new(),
+            exclude: Vec::new(),
+        }
+    }
+}
+
 /// Development server configuration
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DevConfig {
@@ -391,6 +402,14 @@ pub struct DesktopConfig {
     pub height: u32,
     #[serde(default)]
     pub resizable: bool,
+    /// Cargo features enabled for native builds
+    #[serde(default = "default_desktop_features")]
+    pub features: Vec<String>,
+}
+
+/// Scaffolded projects gate the desktop binary behind this feature
+pub fn default_desktop_features() -> Vec<String> {
+    vec!["desktop".to_string()]
 }
 
 fn default_width() -> u32 {
@@ -488,6 +507,14 @@ impl BlincConfig {
         }
     }
 
+    /// Cargo features for native (desktop) builds
+    pub fn native_features(&self) -> Vec<String> {
+        match &self.targets.desktop {
+            Some(desktop) => desktop.features.clone(),
+            None => default_desktop_features(),
+        }
+    }
+
     /// Serialize to TOML string
     pub fn to_toml(&self) -> Result<String> {
         toml::to_string_pretty(self).context("Failed to serialize config")
@@ -0,0 +1,190 @@
+//! Dev mode with asset hot-reload
+//!
+//! `blinc dev` on a Cargo project starts a `blinc_recorder` debug server,
+//! launches the app with `BLINC_DEV_SOCKET` pointing at it, and watches the
+//! asset directories. Bursts of file events are debounced and pushed to the
+//! app as `assets_changed` messages so it re-reads images, SVGs, fonts and
+//! themes without restarting.
+
+use anyhow::{Context, Result};
+use blinc_recorder::{
+    DebugServer, DebugServerConfig, RecordingConfig, ServerMessage, SharedRecordingSession,
+    DEV_SOCKET_ENV,
+};
+use notify::{RecursiveMode, Watcher};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+use crate::config::BlincConfig;
+
+/// Quiet period after the last file event before a reload is pushed
+const DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// File extensions that trigger an asset reload
+const ASSET_EXTENSIONS: &[&str] = &[
+    "png", "jpg", "jpeg", "gif", "webp", "svg", "ttf", "otf", "woff", "woff2", "css", "toml",
+    "json",
+];
+
+/// Whether a changed file is an asset the running app can reload
+pub fn is_asset(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| ASSET_EXTENSIONS.iter().any(|a| a.eq_ignore_ascii_case(e)))
+}
+
+/// Collects changed paths until no event has arrived for `window`
+pub struct Debouncer {
+    pending: BTreeSet<PathBuf>,
+    last_event: Option<Instant>,
+    window: Duration,
+}
+
+impl Debouncer {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            pending: BTreeSet::new(),
+            last_event: None,
+            window,
+        }
+    }
+
+    /// Record a changed path
+    pub fn push(&mut self, path: PathBuf, now: Instant) {
+        self.pending.insert(path);
+        self.last_event = Some(now);
+    }
+
+    /// Take the pending paths once the burst has settled
+    pub fn take_ready(&mut self, now: Instant) -> Option<Vec<PathBuf>> {
+        let last = self.last_event?;
+        if now.duration_since(last) < self.window {
+            return None;
+        }
+        self.last_event = None;
+        Some(std::mem::take(&mut self.pending).into_iter().collect())
+    }
+}
+
+/// Run a Cargo-based project with asset hot-reload
+pub fn run_dev(project_dir: &Path, config: &BlincConfig) -> Result<()> {
+    let mut server_config = DebugServerConfig::new(format!("{}-dev", config.project.name));
+    // The server is only used as a reload channel
+    server_config.auto_start_recording = false;
+    let socket_path = server_config.socket_path();
+    let session = Arc::new(SharedRecordingSession::new(RecordingConfig::minimal()));
+    let server = DebugServer::new(server_config, session)
+        .start()
+        .context("Failed to start dev server")?;
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        if let Ok(event) = event {
+            if !event.kind.is_access() {
+                for path in event.paths {
+                    let _ = tx.send(path);
+                }
+            }
+        }
+    })
+    .context("Failed to create file watcher")?;
+
+    let watch_dirs = std::iter::once("assets".to_string()).chain(config.dev.watch.iter().cloned());
+    for dir in watch_dirs {
+        let dir = project_dir.join(dir);
+        if dir.is_dir() {
+            watcher.watch(&dir, RecursiveMode::Recursive)?;
+            info!("Watching {}", dir.display());
+        } else {
+            debug!("Skipping missing watch directory {}", dir.display());
+        }
+    }
+
+    info!(
+        "Launching {} (cargo run --features desktop)",
+        config.project.name
+    );
+    let mut child = Command::new("cargo")
+        .args(["run", "--features", "desktop"])
+        .current_dir(project_dir)
+        .env(DEV_SOCKET_ENV, &socket_path)
+        .spawn()
+        .context("Failed to run cargo. Run `blinc doctor` to check your setup.")?;
+
+    let mut debouncer = Debouncer::new(DEBOUNCE);
+    loop {
+        if let Some(status) = child.try_wait()? {
+            info!("App exited ({})", status);
+            break;
+        }
+
+        match rx.recv_timeout(DEBOUNCE) {
+            Ok(path) if is_asset(&path) => debouncer.push(path, Instant::now()),
+            Ok(_) | Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                warn!("File watcher stopped");
+                break;
+            }
+        }
+
+        if let Some(changed) = debouncer.take_ready(Instant::now()) {
+            let paths: Vec<String> = changed
+                .iter()
+                .map(|p| {
+                    p.strip_prefix(project_dir)
+                        .unwrap_or(p)
+                        .display()
+                        .to_string()
+                })
+                .collect();
+            info!("Reloading {} asset(s): {}", paths.len(), paths.join(", "));
+            if server.broadcast(ServerMessage::AssetsChanged { paths }) == 0 {
+                debug!("No app connected to receive the reload");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_asset() {
+        assert!(is_asset(Path::new("assets/logo.PNG")));
+        assert!(is_asset(Path::new("themes/dark.css")));
+        assert!(!is_asset(Path::new("src/main.rs")));
+        assert!(!is_asset(Path::new("assets/.DS_Store")));
+    }
+
+    #[test]
+    fn test_debouncer_coalesces_bursts() {
+        let start = Instant::now();
+        let mut debouncer = Debouncer::new(Duration::from_millis(100));
+        assert_eq!(debouncer.take_ready(start), None);
+
+        debouncer.push(PathBuf::from("a.svg"), start);
+        debouncer.push(PathBuf::from("a.svg"), start + Duration::from_millis(40));
+        debouncer.push(PathBuf::from("b.png"), start + Duration::from_millis(80));
+
+        // Still inside the quiet window of the last event
+        assert_eq!(
+            debouncer.take_ready(start + Duration::from_millis(150)),
+            None
+        );
+
+        let ready = debouncer.take_ready(start + Duration::from_millis(180));
+        assert_eq!(
+            ready,
+            Some(vec![PathBuf::from("a.svg"), PathBuf::from("b.png")])
+        );
+        assert_eq!(debouncer.take_ready(start + Duration::from_secs(1)), None);
+    }
+}
@@ -620,7 +620,7 @@ fn get_command_version(cmd: &str, args: &[&str]) -> Option<String> {
         })
 }
 
-fn get_installed_targets() -> Option<Vec<String>> {
+pub(crate) fn get_installed_targets() -> Option<Vec<String>> {
     Command::new("rustup")
         .args(["target", "list", "--installed"])
         .output()
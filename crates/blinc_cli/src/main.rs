@@ -5,11 +5,13 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tracing::{info, warn};
 use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 
+mod cargo;
 mod config;
+mod dev;
 mod doctor;
 mod project;
 
@@ -221,6 +223,20 @@ fn cmd_build(source: &str, target: &str, release: bool, output: Option<&str>) ->
         );
     }
 
+    // Rust-first projects are Cargo packages: build native targets directly
+    if path.join("Cargo.toml").exists() {
+        let Some(native) = cargo::NativeTarget::parse(target) else {
+            anyhow::bail!(
+                "`blinc build` does not support the '{}' target for Cargo projects yet. \
+                 Use the platform tooling (cargo-ndk/Gradle, Xcode, wasm-pack) directly.",
+                target
+            );
+        };
+        let binary = cargo::build_native(&path, &config, native, release, output.map(Path::new))?;
+        info!("Built {}", binary.display());
+        return Ok(());
+    }
+
     // TODO: When Zyntax Grammar2 is ready:
     // 1. Parse .blinc files
     // 2. Generate Rust code
@@ -248,6 +264,11 @@ fn cmd_dev(source: &str, target: &str, port: u16, device: Option<&str>) -> Resul
         info!("Running on device: {}", dev);
     }
 
+    // Rust-first projects run via cargo with asset hot-reload
+    if path.join("Cargo.toml").exists() {
+        return dev::run_dev(&path, &config);
+    }
+
     // TODO: When Zyntax Grammar2 is ready:
     // 1. Start file watcher
     // 2. Compile on change (using JIT)
@@ -1537,13 +1537,16 @@ supported = ["desktop", "android", "ios"]
 [targets.desktop]
 enabled = true
 command = "cargo run --features desktop"
+features = ["desktop"]
 
 [targets.android]
 enabled = true
+package = "{org}.{package_name}"
 platform_dir = "platforms/android"
 
 [targets.ios]
 enabled = true
+bundle_id = "{org}.{package_name}"
 platform_dir = "platforms/ios"
 
 [build]
@@ -17,6 +17,12 @@
 //! cn::avatar()
 //!     .fallback("JD")
 //!
+//! // Initials and color derived from a name, shown until (or if) the
+//! // image fails to load
+//! cn::avatar()
+//!     .src("https://example.com/avatar.jpg")
+//!     .name("Jane Doe")
+//!
 //! // Avatar with status indicator
 //! cn::avatar()
 //!     .src("https://example.com/avatar.jpg")
@@ -37,8 +43,8 @@ use std::cell::{OnceCell, RefCell};
 
 use blinc_core::Color;
 use blinc_layout::element::RenderProps;
+use blinc_layout::image::Placeholder;
 use blinc_layout::prelude::*;
-use blinc_layout::text::Text as TextElement;
 use blinc_layout::tree::{LayoutNodeId, LayoutTree};
 use blinc_theme::{ColorToken, RadiusToken, ThemeState};
 
@@ -149,6 +155,35 @@ impl AvatarStatus {
     }
 }
 
+/// Background colors for name-derived initials
+const NAME_COLORS: [u32; 8] = [
+    0xDC2626, 0xEA580C, 0xCA8A04, 0x16A34A, 0x0891B2, 0x2563EB, 0x7C3AED, 0xDB2777,
+];
+
+/// Initials for a name: the first letter of a one-word name, or the first
+/// letters of its first and last words
+fn initials(name: &str) -> String {
+    let words: Vec<&str> = name.split_whitespace().collect();
+    let picked = match words.as_slice() {
+        [] => return String::new(),
+        [only] => vec![*only],
+        [first, .., last] => vec![*first, *last],
+    };
+    picked
+        .iter()
+        .filter_map(|word| word.chars().next())
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// Stable background color for a name, so a user keeps their color
+fn name_color(name: &str) -> Color {
+    let hash = name.trim().bytes().fold(0u32, |hash, byte| {
+        hash.wrapping_mul(31).wrapping_add(byte as u32)
+    });
+    Color::from_hex(NAME_COLORS[hash as usize % NAME_COLORS.len()])
+}
+
 /// Configuration for avatar component
 struct AvatarConfig {
     /// Image source URL
@@ -157,6 +192,8 @@ struct AvatarConfig {
     alt: Option<String>,
     /// Fallback text (initials) when no image
     fallback: Option<String>,
+    /// User name, for derived initials and fallback color
+    name: Option<String>,
     /// Avatar size
     size: AvatarSize,
     /// Avatar shape
@@ -175,6 +212,7 @@ impl Default for AvatarConfig {
             src: None,
             alt: None,
             fallback: None,
+            name: None,
             size: AvatarSize::default(),
             shape: AvatarShape::default(),
             status: None,
@@ -195,70 +233,77 @@ impl BuiltAvatar {
         let size_px = config.size.pixels();
         let radius = config.shape.border_radius(size_px, &theme);
 
-        // Determine background and content
-        let (background, content) = if let Some(ref src) = config.src {
-            // Image avatar
-            let image = img(src).size(size_px, size_px).cover().rounded(radius);
-            (None, AvatarContent::Image(image))
-        } else if let Some(ref fallback_text) = config.fallback {
-            // Fallback initials
-            let bg = config
-                .fallback_bg
-                .unwrap_or_else(|| theme.color(ColorToken::Surface));
-            let fg = config
-                .fallback_color
-                .unwrap_or_else(|| theme.color(ColorToken::TextPrimary));
-
-            let initials = text(fallback_text)
-                .size(config.size.font_size())
-                .weight(FontWeight::Medium)
-                .color(fg)
-                .no_wrap();
-
-            (Some(bg), AvatarContent::Initials(initials))
-        } else {
-            // Empty fallback - show placeholder
-            let bg = config
-                .fallback_bg
-                .unwrap_or_else(|| theme.color(ColorToken::Surface));
-            let fg = config
-                .fallback_color
-                .unwrap_or_else(|| theme.color(ColorToken::TextTertiary));
-
+        // Explicit fallback text wins over initials derived from the name
+        let fallback_text = config
+            .fallback
+            .clone()
+            .or_else(|| config.name.as_deref().map(initials))
+            .filter(|text| !text.is_empty());
+        let name_bg = config.name.as_deref().map(name_color);
+        let bg = config
+            .fallback_bg
+            .or(name_bg)
+            .unwrap_or_else(|| theme.color(ColorToken::Surface));
+        let fg = config.fallback_color.unwrap_or_else(|| match name_bg {
+            Some(_) => Color::WHITE,
+            None if fallback_text.is_some() => theme.color(ColorToken::TextPrimary),
             // Default user icon placeholder
-            let placeholder = text("?")
-                .size(config.size.font_size())
-                .weight(FontWeight::Medium)
-                .color(fg)
-                .no_wrap();
-
-            (Some(bg), AvatarContent::Initials(placeholder))
+            None => theme.color(ColorToken::TextTertiary),
+        });
+        let initials = |text_content: &str| {
+            div()
+                .w(size_px)
+                .h(size_px)
+                .flex_row()
+                .items_center()
+                .justify_center()
+                .child(
+                    text(text_content)
+                        .size(config.size.font_size())
+                        .weight(FontWeight::Medium)
+                        .color(fg)
+                        .no_wrap(),
+                )
         };
 
-        // Build the inner avatar container (with clipping for image/initials)
-        let mut inner = div()
-            .w(size_px)
-            .h(size_px)
-            .rounded(radius)
-            .overflow_clip()
-            .flex_row()
-            .items_center()
-            .justify_center();
-
-        // Apply background if needed (for fallback)
-        if let Some(bg) = background {
-            inner = inner.bg(bg);
-        }
-
-        // Add content
-        match content {
-            AvatarContent::Image(image) => {
-                inner = inner.child(image);
-            }
-            AvatarContent::Initials(text_el) => {
-                inner = inner.child(text_el);
-            }
-        }
+        // Build the inner avatar (with clipping for image/initials)
+        let inner: Box<dyn ElementBuilder> = match (&config.src, &fallback_text) {
+            // Initials sit under the image and show through while it loads,
+            // or for good if it fails to load
+            (Some(src), Some(fallback_text)) => Box::new(
+                stack()
+                    .w(size_px)
+                    .h(size_px)
+                    .rounded(radius)
+                    .overflow_clip()
+                    .bg(bg)
+                    .child(initials(fallback_text))
+                    .child(
+                        img(src)
+                            .size(size_px, size_px)
+                            .cover()
+                            .rounded(radius)
+                            .placeholder(Placeholder::None),
+                    ),
+            ),
+            (Some(src), None) => Box::new(
+                div()
+                    .w(size_px)
+                    .h(size_px)
+                    .rounded(radius)
+                    .overflow_clip()
+                    .child(img(src).size(size_px, size_px).cover().rounded(radius)),
+            ),
+            (None, fallback_text) => Box::new(
+                div()
+                    .w(size_px)
+                    .h(size_px)
+                    .rounded(radius)
+                    .overflow_clip()
+                    .bg(bg)
+                    .child(initials(fallback_text.as_deref().unwrap_or("?"))),
+            ),
+        };
 
         // If we have a status indicator, use foreground layer to render on top of images
         let container = if let Some(status) = config.status {
@@ -284,23 +329,17 @@ impl BuiltAvatar {
                     .w(size_px)
                     .h(size_px)
                     .overflow_visible()
-                    .child(inner)
+                    .child(div().w(size_px).h(size_px).child_box(inner))
                     .child(status_indicator),
             ) as Box<dyn ElementBuilder>
         } else {
-            Box::new(inner) as Box<dyn ElementBuilder>
+            inner
         };
 
         Self { inner: container }
     }
 }
 
-/// Avatar content type
-enum AvatarContent {
-    Image(Image),
-    Initials(TextElement),
-}
-
 /// Avatar component
 pub struct Avatar {
     inner: Box<dyn ElementBuilder>,
@@ -361,6 +400,16 @@ impl AvatarBuilder {
         self
     }
 
+    /// Set the user's name
+    ///
+    /// Without an explicit [`fallback`](Self::fallback), the avatar shows the
+    /// name's initials ("Jane Doe" → "JD", "Cher" → "C") on a color picked
+    /// from the name whenever there is no image, or it fails to load.
+    pub fn name(self, name: impl Into<String>) -> Self {
+        self.config.borrow_mut().name = Some(name.into());
+        self
+    }
+
     /// Set avatar size
     pub fn size(self, size: AvatarSize) -> Self {
         self.config.borrow_mut().size = size;
@@ -771,6 +820,50 @@ mod tests {
         assert_eq!(config.status, Some(AvatarStatus::Busy));
     }
 
+    /// Text content anywhere in an element tree
+    fn texts(element: &dyn ElementBuilder, out: &mut Vec<String>) {
+        if let Some(info) = element.text_render_info() {
+            out.push(info.content);
+        }
+        for child in element.children_builders() {
+            texts(child.as_ref(), out);
+        }
+    }
+
+    #[test]
+    fn test_initials_from_name() {
+        assert_eq!(initials("Jane Doe"), "JD");
+        assert_eq!(initials("cher"), "C");
+        assert_eq!(initials("  Mary Ann Smith "), "MS");
+        assert_eq!(initials(""), "");
+        assert_eq!(
+            name_color("Jane Doe").to_array(),
+            name_color("Jane Doe").to_array()
+        );
+    }
+
+    #[test]
+    fn test_avatar_name_renders_initials() {
+        init_theme();
+
+        let built = avatar().name("Jane Doe");
+        let mut found = Vec::new();
+        texts(&built, &mut found);
+        assert_eq!(found, vec!["JD".to_string()]);
+
+        // Initials stay under the image in case it fails to load
+        let built = avatar().src("missing.png").name("Jane Doe").online();
+        let mut found = Vec::new();
+        texts(&built, &mut found);
+        assert_eq!(found, vec!["JD".to_string()]);
+
+        // Explicit fallback wins
+        let built = avatar().name("Jane Doe").fallback("J");
+        let mut found = Vec::new();
+        texts(&built, &mut found);
+        assert_eq!(found, vec!["J".to_string()]);
+    }
+
     #[test]
     fn test_avatar_group_config() {
         init_theme();
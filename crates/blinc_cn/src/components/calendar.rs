@@ -0,0 +1,812 @@
+//! Calendar component with month, week and day views
+//!
+//! Renders a scheduler-style grid with event chips per day, navigation
+//! between periods and today highlighting. Days from adjacent months at the
+//! edges of the month grid are muted; clicking one navigates to its month.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use blinc_cn::prelude::*;
+//!
+//! fn build_ui(ctx: &WindowedContext) -> impl ElementBuilder {
+//!     let today = CalendarDate::new(2026, 3, 14);
+//!     let calendar_state = ctx.use_state_keyed("calendar", || CalendarState::new(today));
+//!
+//!     cn::calendar(&calendar_state)
+//!         .events([
+//!             (today, "Standup", Color::from_hex(0x3b82f6)),
+//!             (today, "Design review", Color::from_hex(0x22c55e)),
+//!             (today.add_days(3), "Release", Color::from_hex(0xef4444)),
+//!         ])
+//!         .on_day_click(|date| println!("Clicked {:?}", date))
+//! }
+//! ```
+
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+
+use blinc_core::{Color, State};
+use blinc_layout::div::{Div, ElementBuilder, ElementTypeId};
+use blinc_layout::element::CursorStyle;
+use blinc_layout::prelude::*;
+use blinc_layout::stateful::{stateful_with_key, ButtonState, NoState};
+use blinc_layout::InstanceKey;
+use blinc_theme::{ColorToken, RadiusToken, ThemeState};
+
+/// Chevron left SVG
+const CHEVRON_LEFT_SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="m15 18-6-6 6-6"/></svg>"#;
+
+/// Chevron right SVG
+const CHEVRON_RIGHT_SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="m9 18 6-6-6-6"/></svg>"#;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAY_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+/// Weeks shown in the month grid; always six so the grid height is stable
+const MONTH_GRID_WEEKS: usize = 6;
+
+/// A calendar date in the proleptic Gregorian calendar
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+    pub year: i32,
+    /// Month of the year, 1-12
+    pub month: u32,
+    /// Day of the month, starting at 1
+    pub day: u32,
+}
+
+impl CalendarDate {
+    /// Create a date, clamping month and day into range
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        let month = month.clamp(1, 12);
+        let day = day.clamp(1, days_in_month(year, month));
+        Self { year, month, day }
+    }
+
+    /// Day of the week, 0 = Sunday through 6 = Saturday
+    pub fn weekday(&self) -> u32 {
+        // 1970-01-01 was a Thursday
+        (self.days_since_epoch() + 4).rem_euclid(7) as u32
+    }
+
+    /// The date `days` days later (or earlier, if negative)
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_days_since_epoch(self.days_since_epoch() + days)
+    }
+
+    /// The same day `months` months later, clamped to the target month's length
+    pub fn add_months(&self, months: i32) -> Self {
+        let index = self.year * 12 + self.month as i32 - 1 + months;
+        Self::new(
+            index.div_euclid(12),
+            index.rem_euclid(12) as u32 + 1,
+            self.day,
+        )
+    }
+
+    /// The first day of this date's month
+    pub fn first_of_month(&self) -> Self {
+        Self::new(self.year, self.month, 1)
+    }
+
+    /// The Sunday starting this date's week
+    pub fn start_of_week(&self) -> Self {
+        self.add_days(-(self.weekday() as i64))
+    }
+
+    /// Whether both dates fall in the same month
+    pub fn same_month(&self, other: &CalendarDate) -> bool {
+        self.year == other.year && self.month == other.month
+    }
+
+    fn month_name(&self) -> &'static str {
+        MONTH_NAMES[self.month as usize - 1]
+    }
+
+    /// Days since 1970-01-01 (Howard Hinnant's `days_from_civil`)
+    fn days_since_epoch(&self) -> i64 {
+        let y = self.year as i64 - if self.month <= 2 { 1 } else { 0 };
+        let era = y.div_euclid(400);
+        let yoe = y - era * 400;
+        let m = self.month as i64;
+        let doy = (153 * (m + if m > 2 { -3 } else { 9 }) + 2) / 5 + self.day as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146097 + doe - 719468
+    }
+
+    /// Inverse of [`days_since_epoch`](Self::days_since_epoch)
+    fn from_days_since_epoch(days: i64) -> Self {
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (yoe + era * 400 + if month <= 2 { 1 } else { 0 }) as i32;
+        Self { year, month, day }
+    }
+}
+
+impl Default for CalendarDate {
+    fn default() -> Self {
+        Self::from_days_since_epoch(0)
+    }
+}
+
+/// Number of days in a month (1-12)
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Which period the calendar shows
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CalendarView {
+    #[default]
+    Month,
+    Week,
+    Day,
+}
+
+/// Navigation state of a calendar: the focused date, today, and the view
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CalendarState {
+    /// Date the visible period is built around
+    pub focused: CalendarDate,
+    /// Date highlighted as today
+    pub today: CalendarDate,
+    pub view: CalendarView,
+}
+
+impl CalendarState {
+    /// Month view focused on today
+    pub fn new(today: CalendarDate) -> Self {
+        Self {
+            focused: today,
+            today,
+            view: CalendarView::Month,
+        }
+    }
+
+    /// Set the view
+    pub fn with_view(mut self, view: CalendarView) -> Self {
+        self.view = view;
+        self
+    }
+
+    /// Focus a date, keeping the view
+    pub fn focus(mut self, date: CalendarDate) -> Self {
+        self.focused = date;
+        self
+    }
+
+    /// The next month, week or day
+    pub fn next(self) -> Self {
+        self.step(1)
+    }
+
+    /// The previous month, week or day
+    pub fn prev(self) -> Self {
+        self.step(-1)
+    }
+
+    fn step(mut self, direction: i32) -> Self {
+        self.focused = match self.view {
+            CalendarView::Month => self.focused.add_months(direction),
+            CalendarView::Week => self.focused.add_days(7 * direction as i64),
+            CalendarView::Day => self.focused.add_days(direction as i64),
+        };
+        self
+    }
+
+    /// Days shown by the current view, in display order
+    ///
+    /// Month view always spans six full weeks starting on a Sunday, so it
+    /// includes trailing days of the previous month and leading days of the
+    /// next one.
+    pub fn visible_days(&self) -> Vec<CalendarDate> {
+        let (start, count) = match self.view {
+            CalendarView::Month => (
+                self.focused.first_of_month().start_of_week(),
+                MONTH_GRID_WEEKS * 7,
+            ),
+            CalendarView::Week => (self.focused.start_of_week(), 7),
+            CalendarView::Day => (self.focused, 1),
+        };
+        (0..count as i64).map(|i| start.add_days(i)).collect()
+    }
+
+    /// Whether a visible day belongs to the focused period rather than an
+    /// adjacent month
+    pub fn in_period(&self, date: &CalendarDate) -> bool {
+        match self.view {
+            CalendarView::Month => date.same_month(&self.focused),
+            CalendarView::Week | CalendarView::Day => true,
+        }
+    }
+
+    /// Header title for the current view
+    pub fn title(&self) -> String {
+        let date = self.focused;
+        match self.view {
+            CalendarView::Month => format!("{} {}", date.month_name(), date.year),
+            CalendarView::Week => {
+                let start = date.start_of_week();
+                format!(
+                    "Week of {} {}, {}",
+                    start.month_name(),
+                    start.day,
+                    start.year
+                )
+            }
+            CalendarView::Day => format!(
+                "{}, {} {}, {}",
+                WEEKDAY_NAMES[date.weekday() as usize],
+                date.month_name(),
+                date.day,
+                date.year
+            ),
+        }
+    }
+}
+
+/// An event shown as a chip on its day
+#[derive(Clone, Debug, PartialEq)]
+pub struct CalendarEvent {
+    pub date: CalendarDate,
+    pub label: String,
+    pub color: Color,
+}
+
+impl<S: Into<String>> From<(CalendarDate, S, Color)> for CalendarEvent {
+    fn from((date, label, color): (CalendarDate, S, Color)) -> Self {
+        Self {
+            date,
+            label: label.into(),
+            color,
+        }
+    }
+}
+
+/// Colors shared by every day cell
+#[derive(Clone, Copy)]
+struct DayCellColors {
+    border: Color,
+    text: Color,
+    muted_text: Color,
+    today_bg: Color,
+    today_text: Color,
+    hover_bg: Color,
+}
+
+impl DayCellColors {
+    fn themed(theme: &ThemeState) -> Self {
+        Self {
+            border: theme.color(ColorToken::Border),
+            text: theme.color(ColorToken::TextPrimary),
+            muted_text: theme.color(ColorToken::TextTertiary),
+            today_bg: theme.color(ColorToken::Primary),
+            today_text: theme.color(ColorToken::TextInverse),
+            hover_bg: theme.color(ColorToken::SurfaceElevated),
+        }
+    }
+}
+
+/// Build one day cell: the day number followed by up to `max_events` chips,
+/// then a "+N more" line for the rest
+fn day_cell(
+    date: CalendarDate,
+    events: &[&CalendarEvent],
+    max_events: usize,
+    muted: bool,
+    is_today: bool,
+    colors: DayCellColors,
+) -> Div {
+    let alpha = if muted { 0.5 } else { 1.0 };
+
+    let number = if is_today {
+        div()
+            .w(24.0)
+            .h(24.0)
+            .rounded(12.0)
+            .items_center()
+            .justify_center()
+            .bg(colors.today_bg)
+            .child(
+                text(date.day.to_string())
+                    .size(12.0)
+                    .semibold()
+                    .color(colors.today_text),
+            )
+    } else {
+        let color = if muted {
+            colors.muted_text
+        } else {
+            colors.text
+        };
+        div()
+            .h(24.0)
+            .items_center()
+            .child(text(date.day.to_string()).size(12.0).color(color))
+    };
+
+    let mut cell = div()
+        .flex_col()
+        .flex_1()
+        .min_w(0.0)
+        .gap(2.0)
+        .p(1.0)
+        .border(0.5, colors.border)
+        .cursor(CursorStyle::Pointer)
+        .on_hover_within(move |s| s.bg(colors.hover_bg))
+        .child(number);
+
+    for event in events.iter().take(max_events) {
+        cell = cell.child(
+            div()
+                .flex_row()
+                .items_center()
+                .gap(4.0)
+                .px(1.0)
+                .h(18.0)
+                .rounded(4.0)
+                .overflow_clip()
+                .bg(event.color.with_alpha(0.18 * alpha))
+                .child(
+                    div()
+                        .w(6.0)
+                        .h(6.0)
+                        .rounded(3.0)
+                        .flex_shrink_0()
+                        .bg(event.color.with_alpha(alpha)),
+                )
+                .child(
+                    text(&event.label)
+                        .size(11.0)
+                        .no_wrap()
+                        .color(colors.text.with_alpha(alpha)),
+                ),
+        );
+    }
+
+    if events.len() > max_events {
+        cell = cell.child(
+            text(format!("+{} more", events.len() - max_events))
+                .size(11.0)
+                .color(colors.muted_text),
+        );
+    }
+
+    cell
+}
+
+/// Calendar component
+pub struct Calendar {
+    inner: Div,
+}
+
+impl Calendar {
+    fn from_builder(builder: &CalendarBuilder) -> Self {
+        let theme = ThemeState::get();
+        let colors = DayCellColors::themed(theme);
+        let radius = theme.radius(RadiusToken::Md);
+        let surface = theme.color(ColorToken::Surface);
+        let text_secondary = theme.color(ColorToken::TextSecondary);
+
+        let key = builder.key.get().to_string();
+        let calendar_state = builder.state.clone();
+        let events = builder.events.clone();
+        let max_events = builder.max_events_per_day;
+        let on_day_click = builder.on_day_click.clone();
+
+        let container_key = format!("{}_container", key);
+        let state_for_container = calendar_state.clone();
+
+        let stateful_container = stateful_with_key::<NoState>(&container_key)
+            .deps([calendar_state.signal_id()])
+            .on_state(move |_ctx| {
+                let current = state_for_container.get();
+
+                // Header: title, navigation and view switcher
+                let nav =
+                    |suffix: &str, icon: &'static str, step: fn(CalendarState) -> CalendarState| {
+                        let state = state_for_container.clone();
+                        build_header_button(&format!("{}_{}", key, suffix), radius, move |_| {
+                            icon_content(icon, text_secondary)
+                        })
+                        .on_click(move |_| state.set(step(state.get())))
+                    };
+
+                let state_for_today = state_for_container.clone();
+                let today_button =
+                    build_header_button(&format!("{}_today", key), radius, move |_| {
+                        text("Today").size(13.0).color(text_secondary)
+                    })
+                    .on_click(move |_| {
+                        let state = state_for_today.get();
+                        state_for_today.set(state.focus(state.today));
+                    });
+
+                let mut views = div().flex_row().gap(2.0);
+                for (view, label) in [
+                    (CalendarView::Month, "Month"),
+                    (CalendarView::Week, "Week"),
+                    (CalendarView::Day, "Day"),
+                ] {
+                    let state = state_for_container.clone();
+                    let selected = current.view == view;
+                    views = views.child(
+                        build_header_button(
+                            &format!("{}_view_{}", key, label),
+                            radius,
+                            move |hovered| {
+                                let color = if selected || hovered {
+                                    colors.text
+                                } else {
+                                    text_secondary
+                                };
+                                text(label).size(13.0).color(color)
+                            },
+                        )
+                        .on_click(move |_| state.set(state.get().with_view(view))),
+                    );
+                }
+
+                let header = div()
+                    .flex_row()
+                    .items_center()
+                    .justify_between()
+                    .gap(8.0)
+                    .child(
+                        div()
+                            .flex_row()
+                            .items_center()
+                            .gap(4.0)
+                            .child(nav("prev", CHEVRON_LEFT_SVG, CalendarState::prev))
+                            .child(today_button)
+                            .child(nav("next", CHEVRON_RIGHT_SVG, CalendarState::next))
+                            .child(
+                                text(current.title())
+                                    .size(16.0)
+                                    .semibold()
+                                    .color(colors.text),
+                            ),
+                    )
+                    .child(views);
+
+                // Weekday labels (a single column in day view)
+                let days = current.visible_days();
+                let columns = days.len().min(7);
+                let mut weekdays = div().flex_row();
+                for date in days.iter().take(columns) {
+                    weekdays = weekdays.child(
+                        div().flex_1().min_w(0.0).py(1.0).justify_center().child(
+                            text(WEEKDAY_NAMES[date.weekday() as usize])
+                                .size(12.0)
+                                .color(text_secondary),
+                        ),
+                    );
+                }
+
+                // Day grid
+                let cell_max_events = match current.view {
+                    CalendarView::Day => usize::MAX,
+                    CalendarView::Month | CalendarView::Week => max_events,
+                };
+                let mut grid = div().flex_col().flex_1();
+                for week in days.chunks(columns) {
+                    let mut row = div().flex_row().flex_1();
+                    for &date in week {
+                        let day_events: Vec<&CalendarEvent> =
+                            events.iter().filter(|e| e.date == date).collect();
+                        let muted = !current.in_period(&date);
+                        let state = state_for_container.clone();
+                        let on_click = on_day_click.clone();
+
+                        row = row.child(
+                            day_cell(
+                                date,
+                                &day_events,
+                                cell_max_events,
+                                muted,
+                                date == current.today,
+                                colors,
+                            )
+                            .on_click(move |_| {
+                                // Adjacent-month days navigate to their month
+                                if muted {
+                                    state.set(state.get().focus(date));
+                                }
+                                if let Some(ref cb) = on_click {
+                                    cb(date);
+                                }
+                            }),
+                        );
+                    }
+                    grid = grid.child(row);
+                }
+
+                div()
+                    .flex_col()
+                    .w_full()
+                    .h_full()
+                    .gap(8.0)
+                    .p(3.0)
+                    .rounded(radius)
+                    .bg(surface)
+                    .child(header)
+                    .child(weekdays)
+                    .child(grid)
+            });
+
+        Self {
+            inner: div().w_full().h_full().child(stateful_container),
+        }
+    }
+}
+
+/// Icon content for a header button
+fn icon_content(icon: &'static str, color: Color) -> Div {
+    div().child(svg(icon).size(16.0, 16.0).color(color))
+}
+
+/// Build a ghost header button whose content depends on hover
+fn build_header_button<F, C>(key: &str, radius: f32, content: F) -> Stateful<ButtonState>
+where
+    F: Fn(bool) -> C + Send + Sync + 'static,
+    C: ElementBuilder + 'static,
+{
+    stateful_with_key::<ButtonState>(key).on_state(move |ctx| {
+        let theme = ThemeState::get();
+        let hovered = matches!(ctx.state(), ButtonState::Hovered | ButtonState::Pressed);
+        let bg = if hovered {
+            theme.color(ColorToken::SurfaceElevated)
+        } else {
+            Color::TRANSPARENT
+        };
+
+        div()
+            .h(28.0)
+            .min_w(28.0)
+            .px(2.0)
+            .rounded(radius)
+            .items_center()
+            .justify_center()
+            .bg(bg)
+            .cursor(CursorStyle::Pointer)
+            .child(content(hovered))
+    })
+}
+
+impl Deref for Calendar {
+    type Target = Div;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl DerefMut for Calendar {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl ElementBuilder for Calendar {
+    fn build(&self, tree: &mut blinc_layout::tree::LayoutTree) -> blinc_layout::tree::LayoutNodeId {
+        self.inner.build(tree)
+    }
+
+    fn render_props(&self) -> blinc_layout::element::RenderProps {
+        self.inner.render_props()
+    }
+
+    fn children_builders(&self) -> &[Box<dyn ElementBuilder>] {
+        self.inner.children_builders()
+    }
+
+    fn layout_style(&self) -> Option<&taffy::Style> {
+        self.inner.layout_style()
+    }
+
+    fn element_type_id(&self) -> ElementTypeId {
+        ElementBuilder::element_type_id(&self.inner)
+    }
+}
+
+/// Builder for calendar component
+pub struct CalendarBuilder {
+    key: InstanceKey,
+    state: State<CalendarState>,
+    events: Arc<Vec<CalendarEvent>>,
+    max_events_per_day: usize,
+    on_day_click: Option<Arc<dyn Fn(CalendarDate) + Send + Sync>>,
+    built: std::cell::OnceCell<Calendar>,
+}
+
+impl CalendarBuilder {
+    /// Create a new calendar builder
+    #[track_caller]
+    pub fn new(state: &State<CalendarState>) -> Self {
+        Self {
+            key: InstanceKey::new("calendar"),
+            state: state.clone(),
+            events: Arc::new(Vec::new()),
+            max_events_per_day: 3,
+            on_day_click: None,
+            built: std::cell::OnceCell::new(),
+        }
+    }
+
+    /// Get or build the calendar
+    fn get_or_build(&self) -> &Calendar {
+        self.built.get_or_init(|| Calendar::from_builder(self))
+    }
+
+    /// Add events, e.g. `(date, "Label", color)` tuples
+    pub fn events<I, E>(mut self, events: I) -> Self
+    where
+        I: IntoIterator<Item = E>,
+        E: Into<CalendarEvent>,
+    {
+        Arc::make_mut(&mut self.events).extend(events.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add a single event
+    pub fn event(self, date: CalendarDate, label: impl Into<String>, color: Color) -> Self {
+        self.events([(date, label.into(), color)])
+    }
+
+    /// Set how many chips a month or week cell shows before "+N more"
+    pub fn max_events_per_day(mut self, count: usize) -> Self {
+        self.max_events_per_day = count;
+        self
+    }
+
+    /// Set day click callback
+    pub fn on_day_click<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(CalendarDate) + Send + Sync + 'static,
+    {
+        self.on_day_click = Some(Arc::new(handler));
+        self
+    }
+}
+
+impl ElementBuilder for CalendarBuilder {
+    fn build(&self, tree: &mut blinc_layout::tree::LayoutTree) -> blinc_layout::tree::LayoutNodeId {
+        self.get_or_build().build(tree)
+    }
+
+    fn render_props(&self) -> blinc_layout::element::RenderProps {
+        self.get_or_build().render_props()
+    }
+
+    fn children_builders(&self) -> &[Box<dyn ElementBuilder>] {
+        self.get_or_build().children_builders()
+    }
+
+    fn event_handlers(&self) -> Option<&blinc_layout::event_handler::EventHandlers> {
+        self.get_or_build().event_handlers()
+    }
+
+    fn layout_style(&self) -> Option<&taffy::Style> {
+        self.get_or_build().layout_style()
+    }
+
+    fn element_type_id(&self) -> ElementTypeId {
+        self.get_or_build().element_type_id()
+    }
+}
+
+/// Create a calendar component
+///
+/// # Example
+///
+/// ```ignore
+/// use blinc_cn::prelude::*;
+///
+/// let state = ctx.use_state_keyed("calendar", || CalendarState::new(today));
+///
+/// cn::calendar(&state)
+///     .event(today, "Standup", Color::from_hex(0x3b82f6))
+///     .on_day_click(|date| println!("{:?}", date))
+/// ```
+#[track_caller]
+pub fn calendar(state: &State<CalendarState>) -> CalendarBuilder {
+    CalendarBuilder::new(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Text content anywhere in an element tree
+    fn texts(element: &dyn ElementBuilder, out: &mut Vec<String>) {
+        if let Some(info) = element.text_render_info() {
+            out.push(info.content);
+        }
+        for child in element.children_builders() {
+            texts(child.as_ref(), out);
+        }
+    }
+
+    fn colors() -> DayCellColors {
+        DayCellColors {
+            border: Color::GRAY,
+            text: Color::BLACK,
+            muted_text: Color::GRAY,
+            today_bg: Color::BLUE,
+            today_text: Color::WHITE,
+            hover_bg: Color::WHITE,
+        }
+    }
+
+    #[test]
+    fn test_date_arithmetic() {
+        let date = CalendarDate::new(2024, 1, 31);
+        assert_eq!(date.weekday(), 3); // Wednesday
+        assert_eq!(date.add_days(29), CalendarDate::new(2024, 2, 29));
+        assert_eq!(date.add_months(1), CalendarDate::new(2024, 2, 29));
+        assert_eq!(date.add_months(-1), CalendarDate::new(2023, 12, 31));
+        assert_eq!(CalendarDate::new(2023, 2, 30).day, 28);
+    }
+
+    #[test]
+    fn test_month_grid_includes_adjacent_days() {
+        // April 2026 starts on a Wednesday, so the grid opens on March 29
+        let state = CalendarState::new(CalendarDate::new(2026, 4, 15));
+        let days = state.visible_days();
+
+        assert_eq!(days.len(), 42);
+        assert_eq!(days[0], CalendarDate::new(2026, 3, 29));
+        assert_eq!(days[0].weekday(), 0);
+        assert!(!state.in_period(&days[0]));
+        assert!(state.in_period(&CalendarDate::new(2026, 4, 1)));
+        assert_eq!(days[41], CalendarDate::new(2026, 5, 9));
+
+        let week = state.with_view(CalendarView::Week);
+        assert_eq!(week.visible_days()[0], CalendarDate::new(2026, 4, 12));
+        assert_eq!(week.next().focused, CalendarDate::new(2026, 4, 22));
+    }
+
+    #[test]
+    fn test_day_cell_shows_event_chips() {
+        let date = CalendarDate::new(2026, 4, 15);
+        let events: Vec<CalendarEvent> = ["Standup", "Review", "Retro"]
+            .into_iter()
+            .map(|label| (date, label, Color::BLUE).into())
+            .collect();
+        let refs: Vec<&CalendarEvent> = events.iter().collect();
+
+        let mut out = Vec::new();
+        texts(&day_cell(date, &refs, 3, false, false, colors()), &mut out);
+        assert_eq!(out, ["15", "Standup", "Review", "Retro"]);
+
+        let mut out = Vec::new();
+        texts(&day_cell(date, &refs, 2, false, false, colors()), &mut out);
+        assert_eq!(out, ["15", "Standup", "Review", "+1 more"]);
+    }
+}
@@ -47,6 +47,33 @@ const CHECKMARK_SVG: &str = r#"<svg viewBox="0 0 16 16" fill="none" xmlns="http:
     <path d="M3 8L6.5 11.5L13 4.5" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"/>
 </svg>"#;
 
+/// SVG dash for the indeterminate state, in the same 16x16 viewBox
+const DASH_SVG: &str = r#"<svg viewBox="0 0 16 16" fill="none" xmlns="http://www.w3.org/2000/svg">
+    <path d="M4 8H12" stroke="currentColor" stroke-width="2" stroke-linecap="round"/>
+</svg>"#;
+
+/// Checked state of a checkbox that can also summarize a group
+///
+/// `Indeterminate` shows a dash, e.g. on a "select all" checkbox when only
+/// some of its items are selected.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CheckState {
+    #[default]
+    Unchecked,
+    Checked,
+    Indeterminate,
+}
+
+impl From<bool> for CheckState {
+    fn from(checked: bool) -> Self {
+        if checked {
+            CheckState::Checked
+        } else {
+            CheckState::Unchecked
+        }
+    }
+}
+
 /// Checkbox size variants
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum CheckboxSize {
@@ -93,6 +120,99 @@ impl CheckboxSize {
     }
 }
 
+/// Resolved colors for drawing a checkbox box
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct CheckboxPalette {
+    checked_bg: Color,
+    unchecked_bg: Color,
+    border: Color,
+    hover_border: Color,
+    check: Color,
+}
+
+impl CheckboxPalette {
+    /// Theme colors
+    pub(crate) fn themed(theme: &ThemeState) -> Self {
+        Self {
+            checked_bg: theme.color(ColorToken::Primary),
+            unchecked_bg: theme.color(ColorToken::InputBg),
+            border: theme.color(ColorToken::Border),
+            hover_border: theme.color(ColorToken::BorderHover),
+            check: theme.color(ColorToken::TextInverse),
+        }
+    }
+
+    /// Theme colors with a config's overrides
+    fn from_config(config: &CheckboxConfig, theme: &ThemeState) -> Self {
+        let themed = Self::themed(theme);
+        Self {
+            checked_bg: config.checked_color.unwrap_or(themed.checked_bg),
+            unchecked_bg: config.unchecked_bg.unwrap_or(themed.unchecked_bg),
+            border: config.border_color.unwrap_or(themed.border),
+            hover_border: config.hover_border_color.unwrap_or(themed.hover_border),
+            check: config.check_color.unwrap_or(themed.check),
+        }
+    }
+
+    /// The checkbox box for a state, with hover feedback unless disabled
+    pub(crate) fn visual(
+        &self,
+        size: CheckboxSize,
+        state: CheckState,
+        hovered: bool,
+        disabled: bool,
+    ) -> Div {
+        let theme = ThemeState::get();
+        let box_size = size.size();
+        let checkmark_size = size.checkmark_size();
+        let hovered = hovered && !disabled;
+
+        // Background and border with smooth color transitions
+        let bg = match state {
+            CheckState::Unchecked => self.unchecked_bg,
+            CheckState::Checked | CheckState::Indeterminate => self.checked_bg,
+        };
+        let current_border = if hovered {
+            self.hover_border
+        } else {
+            self.border
+        };
+
+        // Apply scale effect on hover for subtle motion feedback
+        let scale = if hovered { 1.05 } else { 1.0 };
+
+        let mut visual = div()
+            .w(box_size)
+            .h(box_size)
+            .rounded(size.corner_radius(theme))
+            .cursor_pointer()
+            .items_center()
+            .justify_center()
+            .bg(bg)
+            .border(size.border_width(), current_border)
+            .transform(blinc_core::Transform::scale(scale, scale));
+
+        if disabled {
+            visual = visual.opacity(0.5);
+        }
+
+        let mark = match state {
+            CheckState::Unchecked => None,
+            CheckState::Checked => Some(CHECKMARK_SVG),
+            CheckState::Indeterminate => Some(DASH_SVG),
+        };
+        if let Some(mark) = mark {
+            visual = visual.child(
+                svg(mark)
+                    .size(checkmark_size, checkmark_size)
+                    .tint(self.check),
+            );
+        }
+
+        visual
+    }
+}
+
 /// Checkbox component
 ///
 /// A toggle checkbox with hover and press feedback.
@@ -117,27 +237,8 @@ impl Checkbox {
     /// Create from a full configuration
     fn with_config(config: CheckboxConfig) -> Self {
         let theme = ThemeState::get();
-        let box_size = config.size.size();
-        let border_width = config.size.border_width();
-        let checkmark_size = config.size.checkmark_size();
-        let radius = config.size.corner_radius(&theme);
-
-        // Get colors
-        let checked_bg = config
-            .checked_color
-            .unwrap_or_else(|| theme.color(ColorToken::Primary));
-        let unchecked_bg = config
-            .unchecked_bg
-            .unwrap_or_else(|| theme.color(ColorToken::InputBg));
-        let border = config
-            .border_color
-            .unwrap_or_else(|| theme.color(ColorToken::Border));
-        let hover_border = config
-            .hover_border_color
-            .unwrap_or_else(|| theme.color(ColorToken::BorderHover));
-        let check_mark_color = config
-            .check_color
-            .unwrap_or_else(|| theme.color(ColorToken::TextInverse));
+        let palette = CheckboxPalette::from_config(&config, theme);
+        let size = config.size;
 
         let disabled = config.disabled;
         let on_change = config.on_change.clone();
@@ -147,47 +248,8 @@ impl Checkbox {
         let mut checkbox = stateful::<ButtonState>()
             .deps([checked_state.signal_id()])
             .on_state(move |ctx| {
-                let state = ctx.state();
-                let is_checked = checked_state.get();
-                let is_hovered = matches!(state, ButtonState::Hovered | ButtonState::Pressed);
-
-                // Background and border with smooth color transitions
-                let bg = if is_checked { checked_bg } else { unchecked_bg };
-                let current_border = if is_hovered && !disabled {
-                    hover_border
-                } else {
-                    border
-                };
-
-                // Apply scale effect on hover for subtle motion feedback
-                let scale = if is_hovered && !disabled { 1.05 } else { 1.0 };
-
-                // Build visual
-                let mut visual = div()
-                    .w(box_size)
-                    .h(box_size)
-                    .rounded(radius)
-                    .cursor_pointer()
-                    .items_center()
-                    .justify_center()
-                    .bg(bg)
-                    .border(border_width, current_border)
-                    .transform(blinc_core::Transform::scale(scale, scale));
-
-                if disabled {
-                    visual = visual.opacity(0.5);
-                }
-
-                // Add checkmark if checked using SVG
-                if is_checked {
-                    visual = visual.child(
-                        svg(CHECKMARK_SVG)
-                            .size(checkmark_size, checkmark_size)
-                            .tint(check_mark_color),
-                    );
-                }
-
-                visual
+                let is_hovered = matches!(ctx.state(), ButtonState::Hovered | ButtonState::Pressed);
+                palette.visual(size, checked_state.get().into(), is_hovered, disabled)
             });
 
         // Add click handler to toggle the state (only if not disabled)
@@ -453,6 +515,12 @@ mod tests {
         assert_eq!(CheckboxSize::Large.size(), 22.0);
     }
 
+    #[test]
+    fn test_check_state_from_bool() {
+        assert_eq!(CheckState::from(true), CheckState::Checked);
+        assert_eq!(CheckState::from(false), CheckState::Unchecked);
+    }
+
     #[test]
     fn test_checkbox_checkmark_sizes() {
         assert_eq!(CheckboxSize::Small.checkmark_size(), 10.0);
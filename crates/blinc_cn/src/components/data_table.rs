@@ -0,0 +1,521 @@
+//! Data table component with row selection and row actions
+//!
+//! Composes the layout table primitives with cn checkboxes and dropdown
+//! menus. Selection lives in a `State<HashSet<RowId>>` owned by the caller,
+//! so it survives rebuilds and can drive toolbars elsewhere in the UI.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use blinc_cn::prelude::*;
+//! use std::collections::HashSet;
+//!
+//! fn build_ui(ctx: &WindowedContext) -> impl ElementBuilder {
+//!     let selected = ctx.use_state_keyed("users_selected", HashSet::<RowId>::new);
+//!
+//!     cn::data_table(
+//!         vec![
+//!             DataTableColumn::new("Name"),
+//!             DataTableColumn::new("Email").width(240.0),
+//!             DataTableColumn::new("Age").align_end(),
+//!         ],
+//!         vec![
+//!             DataTableRow::new("u1", ["Ada", "ada@example.com", "36"]),
+//!             DataTableRow::new("u2", ["Linus", "linus@example.com", "54"]),
+//!         ],
+//!     )
+//!     .selection(&selected)
+//!     .row_action("Edit", |id| println!("edit {id}"))
+//!     .row_action("Delete", |id| println!("delete {id}"))
+//!     .max_height(320.0)
+//! }
+//! ```
+
+use std::cell::OnceCell;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use blinc_core::State;
+use blinc_layout::div::ElementTypeId;
+use blinc_layout::element::RenderProps;
+use blinc_layout::prelude::*;
+use blinc_layout::tree::{LayoutNodeId, LayoutTree};
+use blinc_theme::{ColorToken, ThemeState};
+
+use super::checkbox::{CheckState, CheckboxPalette, CheckboxSize};
+use super::dropdown_menu::dropdown_menu_custom;
+
+/// Identifier of a data table row, used as the selection key
+pub type RowId = String;
+
+/// Width of the selection checkbox column
+const SELECT_COLUMN_WIDTH: f32 = 44.0;
+
+/// Width of the row action menu column
+const ACTION_COLUMN_WIDTH: f32 = 48.0;
+
+/// Three horizontal dots for the row action trigger
+const MORE_SVG: &str = r#"<svg viewBox="0 0 16 16" fill="currentColor" xmlns="http://www.w3.org/2000/svg">
+    <circle cx="3" cy="8" r="1.5"/>
+    <circle cx="8" cy="8" r="1.5"/>
+    <circle cx="13" cy="8" r="1.5"/>
+</svg>"#;
+
+/// Horizontal alignment of a column's header and cells
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColumnAlign {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+/// A data table column definition
+#[derive(Clone, Debug)]
+pub struct DataTableColumn {
+    header: String,
+    width: Option<f32>,
+    align: ColumnAlign,
+}
+
+impl DataTableColumn {
+    /// Create a column that shares the remaining width with other flexible columns
+    pub fn new(header: impl Into<String>) -> Self {
+        Self {
+            header: header.into(),
+            width: None,
+            align: ColumnAlign::Start,
+        }
+    }
+
+    /// Fix the column width in pixels
+    pub fn width(mut self, px: f32) -> Self {
+        self.width = Some(px);
+        self
+    }
+
+    /// Set the column alignment
+    pub fn align(mut self, align: ColumnAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Right-align the column (useful for numbers)
+    pub fn align_end(self) -> Self {
+        self.align(ColumnAlign::End)
+    }
+
+    /// Apply this column's width and alignment to a cell
+    fn shape(&self, mut cell: TableCell) -> TableCell {
+        if let Some(width) = self.width {
+            cell = cell.w(width);
+        }
+        match self.align {
+            ColumnAlign::Start => cell,
+            ColumnAlign::Center => cell.justify_center(),
+            ColumnAlign::End => cell.justify_end(),
+        }
+    }
+}
+
+impl From<&str> for DataTableColumn {
+    fn from(header: &str) -> Self {
+        Self::new(header)
+    }
+}
+
+impl From<String> for DataTableColumn {
+    fn from(header: String) -> Self {
+        Self::new(header)
+    }
+}
+
+/// A data table row: a stable id plus one text value per column
+#[derive(Clone, Debug)]
+pub struct DataTableRow {
+    id: RowId,
+    cells: Vec<String>,
+}
+
+impl DataTableRow {
+    /// Create a row from its id and cell values
+    pub fn new<I, S>(id: impl Into<RowId>, cells: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            id: id.into(),
+            cells: cells.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// The row id
+    pub fn id(&self) -> &RowId {
+        &self.id
+    }
+}
+
+/// State of the "select all" checkbox for the visible rows
+///
+/// Checked only when every visible row is selected, indeterminate when some
+/// are. Selected ids that aren't visible don't count either way.
+pub fn header_check_state(selected: &HashSet<RowId>, visible: &[RowId]) -> CheckState {
+    let count = visible.iter().filter(|id| selected.contains(*id)).count();
+    if count == 0 {
+        CheckState::Unchecked
+    } else if count == visible.len() {
+        CheckState::Checked
+    } else {
+        CheckState::Indeterminate
+    }
+}
+
+/// Toggle "select all": deselect the visible rows if all of them are
+/// selected, otherwise select every visible row
+///
+/// Selections outside `visible` are left untouched.
+pub fn toggle_all(mut selected: HashSet<RowId>, visible: &[RowId]) -> HashSet<RowId> {
+    if header_check_state(&selected, visible) == CheckState::Checked {
+        for id in visible {
+            selected.remove(id);
+        }
+    } else {
+        selected.extend(visible.iter().cloned());
+    }
+    selected
+}
+
+/// Toggle a single row's selection
+pub fn toggle_row(mut selected: HashSet<RowId>, id: &RowId) -> HashSet<RowId> {
+    if !selected.remove(id) {
+        selected.insert(id.clone());
+    }
+    selected
+}
+
+/// A row action callback, receiving the id of the row it was opened on
+type RowActionFn = Arc<dyn Fn(&RowId) + Send + Sync>;
+
+/// Data table component
+pub struct DataTable {
+    inner: Div,
+}
+
+impl DataTable {
+    fn with_config(config: DataTableConfig) -> Self {
+        let theme = ThemeState::get();
+        let palette = CheckboxPalette::themed(theme);
+        let border = theme.color(ColorToken::Border);
+        let row_hover = theme.color(ColorToken::SurfaceElevated);
+        let visible: Arc<[RowId]> = config.rows.iter().map(|row| row.id.clone()).collect();
+        let has_actions = !config.actions.is_empty();
+
+        // Header row
+        let mut header = tr().border_bottom(1.0, border);
+        if let Some(ref selection) = config.selection {
+            header = header.child(cell().w(SELECT_COLUMN_WIDTH).child(select_all_checkbox(
+                selection,
+                visible.clone(),
+                palette,
+            )));
+        }
+        for column in &config.columns {
+            header = header.child(column.shape(th(column.header.clone())));
+        }
+        if has_actions {
+            header = header.child(cell().w(ACTION_COLUMN_WIDTH));
+        }
+
+        // Body rows
+        let mut body = tbody();
+        for row in &config.rows {
+            let mut tr_row = tr()
+                .border_bottom(1.0, border)
+                .on_hover_within(move |s| s.bg(row_hover));
+
+            if let Some(ref selection) = config.selection {
+                tr_row = tr_row.child(cell().w(SELECT_COLUMN_WIDTH).child(row_checkbox(
+                    selection,
+                    row.id.clone(),
+                    palette,
+                )));
+            }
+            for (index, column) in config.columns.iter().enumerate() {
+                let value = row.cells.get(index).cloned().unwrap_or_default();
+                tr_row = tr_row.child(column.shape(td(value)));
+            }
+            if has_actions {
+                tr_row = tr_row.child(
+                    cell()
+                        .w(ACTION_COLUMN_WIDTH)
+                        .justify_center()
+                        .child(row_action_menu(&config.actions, &row.id)),
+                );
+            }
+            body = body.child(tr_row);
+        }
+
+        // Keeping the header outside the scroll container pins it in place
+        let head = thead().child(header);
+        let inner = match config.max_height {
+            Some(max_height) => table()
+                .w_full()
+                .child(head)
+                .child(scroll().h(max_height).w_full().child(body)),
+            None => table().w_full().child(head).child(body),
+        };
+
+        Self { inner }
+    }
+}
+
+/// Header checkbox that selects or clears every visible row
+fn select_all_checkbox(
+    selection: &State<HashSet<RowId>>,
+    visible: Arc<[RowId]>,
+    palette: CheckboxPalette,
+) -> impl ElementBuilder {
+    let selection_for_state = selection.clone();
+    let selection_for_click = selection.clone();
+    let visible_for_state = visible.clone();
+
+    stateful::<ButtonState>()
+        .deps([selection.signal_id()])
+        .on_state(move |ctx| {
+            let hovered = matches!(ctx.state(), ButtonState::Hovered | ButtonState::Pressed);
+            let state = header_check_state(&selection_for_state.get(), &visible_for_state);
+            palette.visual(
+                CheckboxSize::Medium,
+                state,
+                hovered,
+                visible_for_state.is_empty(),
+            )
+        })
+        .on_click(move |_| {
+            if visible.is_empty() {
+                return;
+            }
+            selection_for_click.update(|selected| toggle_all(selected, &visible));
+        })
+}
+
+/// Per-row selection checkbox
+fn row_checkbox(
+    selection: &State<HashSet<RowId>>,
+    id: RowId,
+    palette: CheckboxPalette,
+) -> impl ElementBuilder {
+    let selection_for_state = selection.clone();
+    let selection_for_click = selection.clone();
+    let id_for_state = id.clone();
+
+    stateful::<ButtonState>()
+        .deps([selection.signal_id()])
+        .on_state(move |ctx| {
+            let hovered = matches!(ctx.state(), ButtonState::Hovered | ButtonState::Pressed);
+            let state = selection_for_state.get().contains(&id_for_state).into();
+            palette.visual(CheckboxSize::Medium, state, hovered, false)
+        })
+        .on_click(move |_| {
+            selection_for_click.update(|selected| toggle_row(selected, &id));
+        })
+}
+
+/// Action menu opened from the row's trailing "more" button
+fn row_action_menu(actions: &[(String, RowActionFn)], id: &RowId) -> impl ElementBuilder {
+    let mut menu = dropdown_menu_custom(|open| {
+        let theme = ThemeState::get();
+        let (bg, tint) = if open {
+            (
+                theme.color(ColorToken::SurfaceElevated),
+                theme.color(ColorToken::TextPrimary),
+            )
+        } else {
+            (
+                blinc_core::Color::TRANSPARENT,
+                theme.color(ColorToken::TextSecondary),
+            )
+        };
+        div()
+            .w(28.0)
+            .h(28.0)
+            .rounded(6.0)
+            .items_center()
+            .justify_center()
+            .cursor_pointer()
+            .bg(bg)
+            .child(svg(MORE_SVG).size(16.0, 16.0).tint(tint))
+    });
+
+    for (label, action) in actions {
+        let action = action.clone();
+        let id = id.clone();
+        menu = menu.item(label.clone(), move || action(&id));
+    }
+    menu
+}
+
+impl ElementBuilder for DataTable {
+    fn build(&self, tree: &mut LayoutTree) -> LayoutNodeId {
+        self.inner.build(tree)
+    }
+
+    fn render_props(&self) -> RenderProps {
+        self.inner.render_props()
+    }
+
+    fn children_builders(&self) -> &[Box<dyn ElementBuilder>] {
+        self.inner.children_builders()
+    }
+
+    fn element_type_id(&self) -> ElementTypeId {
+        self.inner.element_type_id()
+    }
+
+    fn layout_style(&self) -> Option<&taffy::Style> {
+        self.inner.layout_style()
+    }
+}
+
+/// Internal configuration for building a DataTable
+#[derive(Clone)]
+struct DataTableConfig {
+    columns: Vec<DataTableColumn>,
+    rows: Vec<DataTableRow>,
+    selection: Option<State<HashSet<RowId>>>,
+    actions: Vec<(String, RowActionFn)>,
+    max_height: Option<f32>,
+}
+
+/// Builder for creating DataTable components with fluent API
+pub struct DataTableBuilder {
+    config: DataTableConfig,
+    /// Cached built DataTable - built lazily on first access
+    built: OnceCell<DataTable>,
+}
+
+impl DataTableBuilder {
+    /// Create a new data table builder
+    pub fn new(columns: Vec<DataTableColumn>, rows: Vec<DataTableRow>) -> Self {
+        Self {
+            config: DataTableConfig {
+                columns,
+                rows,
+                selection: None,
+                actions: Vec::new(),
+                max_height: None,
+            },
+            built: OnceCell::new(),
+        }
+    }
+
+    /// Get or build the inner DataTable
+    fn get_or_build(&self) -> &DataTable {
+        self.built
+            .get_or_init(|| DataTable::with_config(self.config.clone()))
+    }
+
+    /// Enable row selection backed by the given state
+    ///
+    /// Adds a checkbox column, with a "select all" checkbox in the header.
+    pub fn selection(mut self, selection: &State<HashSet<RowId>>) -> Self {
+        self.config.selection = Some(selection.clone());
+        self
+    }
+
+    /// Add an entry to each row's action menu
+    pub fn row_action<F>(mut self, label: impl Into<String>, action: F) -> Self
+    where
+        F: Fn(&RowId) + Send + Sync + 'static,
+    {
+        self.config.actions.push((label.into(), Arc::new(action)));
+        self
+    }
+
+    /// Limit the body height; rows scroll beneath a sticky header
+    pub fn max_height(mut self, px: f32) -> Self {
+        self.config.max_height = Some(px);
+        self
+    }
+}
+
+impl ElementBuilder for DataTableBuilder {
+    fn build(&self, tree: &mut LayoutTree) -> LayoutNodeId {
+        self.get_or_build().build(tree)
+    }
+
+    fn render_props(&self) -> RenderProps {
+        self.get_or_build().render_props()
+    }
+
+    fn children_builders(&self) -> &[Box<dyn ElementBuilder>] {
+        self.get_or_build().children_builders()
+    }
+
+    fn element_type_id(&self) -> ElementTypeId {
+        self.get_or_build().element_type_id()
+    }
+
+    fn layout_style(&self) -> Option<&taffy::Style> {
+        self.get_or_build().layout_style()
+    }
+}
+
+/// Create a data table from column definitions and rows
+///
+/// # Example
+///
+/// ```ignore
+/// cn::data_table(
+///     vec!["Name".into(), "Role".into()],
+///     vec![DataTableRow::new("1", ["Ada", "Engineer"])],
+/// )
+/// .selection(&selected)
+/// ```
+pub fn data_table(columns: Vec<DataTableColumn>, rows: Vec<DataTableRow>) -> DataTableBuilder {
+    DataTableBuilder::new(columns, rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(values: &[&str]) -> Vec<RowId> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_toggle_all_selects_visible_rows() {
+        let visible = ids(&["a", "b", "c"]);
+        let selected = toggle_all(HashSet::new(), &visible);
+        assert_eq!(selected, visible.iter().cloned().collect());
+        assert_eq!(header_check_state(&selected, &visible), CheckState::Checked);
+
+        // Toggling again clears only the visible rows
+        let mut with_hidden = selected;
+        with_hidden.insert("hidden".to_string());
+        let cleared = toggle_all(with_hidden, &visible);
+        assert_eq!(cleared, ids(&["hidden"]).into_iter().collect());
+    }
+
+    #[test]
+    fn test_deselecting_one_row_makes_header_indeterminate() {
+        let visible = ids(&["a", "b", "c"]);
+        let selected = toggle_all(HashSet::new(), &visible);
+        let selected = toggle_row(selected, &"b".to_string());
+
+        assert_eq!(
+            header_check_state(&selected, &visible),
+            CheckState::Indeterminate
+        );
+        assert_eq!(
+            header_check_state(&HashSet::new(), &visible),
+            CheckState::Unchecked
+        );
+
+        // Toggling from indeterminate selects everything
+        let selected = toggle_all(selected, &visible);
+        assert_eq!(header_check_state(&selected, &visible), CheckState::Checked);
+    }
+}
@@ -0,0 +1,882 @@
+//! Floating window component for tool and inspector panels
+//!
+//! Draggable, resizable panels inside the app canvas (not OS windows). Drag
+//! the title bar to move a window and its edges or corners to resize it.
+//! The title bar also has minimize and close buttons. Windows stay within
+//! the area's bounds, which default to the viewport.
+//!
+//! Windows live in a floating window area that owns their shared z-order.
+//! Pressing anywhere on a window brings it to the front.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use blinc_cn::prelude::*;
+//!
+//! div()
+//!     .relative()
+//!     .child(main_content())
+//!     .child(
+//!         cn::floating_window_area()
+//!             .window(
+//!                 cn::floating_window("Inspector", inspector)
+//!                     .position(24.0, 24.0)
+//!                     .size(280.0, 360.0),
+//!             )
+//!             .window(
+//!                 cn::floating_window("Layers", layers)
+//!                     .position(320.0, 24.0)
+//!                     .min_size(160.0, 120.0)
+//!                     .on_close(|| println!("Layers closed")),
+//!             ),
+//!     )
+//! ```
+
+use std::cell::OnceCell;
+use std::sync::{Arc, Mutex};
+
+use blinc_core::context_state::BlincContextState;
+use blinc_core::State;
+use blinc_layout::div::ElementTypeId;
+use blinc_layout::element::{CursorStyle, RenderProps};
+use blinc_layout::prelude::*;
+use blinc_layout::stateful::{stateful_with_key, NoState};
+use blinc_layout::tree::{LayoutNodeId, LayoutTree};
+use blinc_layout::InstanceKey;
+use blinc_theme::{ColorToken, RadiusToken, ThemeState};
+
+use super::resizable::PanelConstraints;
+
+const CLOSE_SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="14" height="14" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><line x1="18" x2="6" y1="6" y2="18"/><line x1="6" x2="18" y1="6" y2="18"/></svg>"#;
+
+const MINIMIZE_SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="14" height="14" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M5 12h14"/></svg>"#;
+
+const RESTORE_SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="14" height="14" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><rect width="14" height="14" x="5" y="5" rx="2"/></svg>"#;
+
+/// Height of the title bar, and of a minimized window
+const TITLE_BAR_HEIGHT: f32 = 32.0;
+
+/// Width of the resize zone along each edge
+const RESIZE_HANDLE_SIZE: f32 = 6.0;
+
+/// Position and size of a floating window, relative to its area
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct WindowFrame {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl WindowFrame {
+    /// Create a frame
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Move by `(dx, dy)`, keeping the frame within `bounds` (width, height)
+    pub fn moved(self, dx: f32, dy: f32, bounds: (f32, f32)) -> Self {
+        Self {
+            x: (self.x + dx).clamp(0.0, (bounds.0 - self.width).max(0.0)),
+            y: (self.y + dy).clamp(0.0, (bounds.1 - self.height).max(0.0)),
+            ..self
+        }
+    }
+
+    /// Drag `edge` by `(dx, dy)`, keeping at least `min_size` and staying
+    /// within `bounds`
+    ///
+    /// The opposite edge stays put.
+    pub fn resized(
+        self,
+        edge: ResizeEdge,
+        dx: f32,
+        dy: f32,
+        min_size: (f32, f32),
+        bounds: (f32, f32),
+    ) -> Self {
+        let mut frame = self;
+        let (left, right, top, bottom) = edge.sides();
+        if right {
+            frame.width = constraints(min_size.0, bounds.0 - self.x).clamp(self.width + dx);
+        } else if left {
+            let right_edge = self.x + self.width;
+            frame.width = constraints(min_size.0, right_edge).clamp(self.width - dx);
+            frame.x = right_edge - frame.width;
+        }
+        if bottom {
+            frame.height = constraints(min_size.1, bounds.1 - self.y).clamp(self.height + dy);
+        } else if top {
+            let bottom_edge = self.y + self.height;
+            frame.height = constraints(min_size.1, bottom_edge).clamp(self.height - dy);
+            frame.y = bottom_edge - frame.height;
+        }
+        frame
+    }
+}
+
+/// Size constraints for one axis of a resize, shared with resizable panels
+fn constraints(min_size: f32, max_size: f32) -> PanelConstraints {
+    PanelConstraints {
+        min_size,
+        max_size: Some(max_size),
+    }
+}
+
+/// Edge or corner of a window being resized
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResizeEdge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl ResizeEdge {
+    /// Edge under a point at `(x, y)` within a window of `size`, if any
+    fn at(x: f32, y: f32, size: (f32, f32)) -> Option<Self> {
+        let left = x < RESIZE_HANDLE_SIZE;
+        let right = x > size.0 - RESIZE_HANDLE_SIZE;
+        let top = y < RESIZE_HANDLE_SIZE;
+        let bottom = y > size.1 - RESIZE_HANDLE_SIZE;
+        match (left, right, top, bottom) {
+            (true, _, true, _) => Some(ResizeEdge::TopLeft),
+            (_, true, true, _) => Some(ResizeEdge::TopRight),
+            (true, _, _, true) => Some(ResizeEdge::BottomLeft),
+            (_, true, _, true) => Some(ResizeEdge::BottomRight),
+            (true, ..) => Some(ResizeEdge::Left),
+            (_, true, ..) => Some(ResizeEdge::Right),
+            (_, _, true, _) => Some(ResizeEdge::Top),
+            (.., true) => Some(ResizeEdge::Bottom),
+            _ => None,
+        }
+    }
+
+    /// Which sides move: (left, right, top, bottom)
+    fn sides(self) -> (bool, bool, bool, bool) {
+        match self {
+            ResizeEdge::Top => (false, false, true, false),
+            ResizeEdge::Bottom => (false, false, false, true),
+            ResizeEdge::Left => (true, false, false, false),
+            ResizeEdge::Right => (false, true, false, false),
+            ResizeEdge::TopLeft => (true, false, true, false),
+            ResizeEdge::TopRight => (false, true, true, false),
+            ResizeEdge::BottomLeft => (true, false, false, true),
+            ResizeEdge::BottomRight => (false, true, false, true),
+        }
+    }
+
+    fn cursor(self) -> CursorStyle {
+        match self {
+            ResizeEdge::Top | ResizeEdge::Bottom => CursorStyle::ResizeNS,
+            ResizeEdge::Left | ResizeEdge::Right => CursorStyle::ResizeEW,
+            ResizeEdge::TopLeft | ResizeEdge::BottomRight => CursorStyle::ResizeNWSE,
+            ResizeEdge::TopRight | ResizeEdge::BottomLeft => CursorStyle::ResizeNESW,
+        }
+    }
+}
+
+/// What a press on a window started dragging
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DragMode {
+    Move,
+    Resize(ResizeEdge),
+}
+
+/// Internal configuration for a floating window
+#[derive(Clone)]
+struct FloatingWindowConfig {
+    title: String,
+    content: Arc<dyn Fn() -> Div + Send + Sync>,
+    frame: WindowFrame,
+    min_size: (f32, f32),
+    resizable: bool,
+    minimizable: bool,
+    closable: bool,
+    on_close: Option<Arc<dyn Fn() + Send + Sync>>,
+}
+
+/// Runtime state of one window in an area
+#[derive(Clone)]
+struct WindowController {
+    key: String,
+    config: FloatingWindowConfig,
+    frame: State<WindowFrame>,
+    minimized: State<bool>,
+    open: State<bool>,
+    /// Window keys of the area, back to front
+    order: State<Vec<String>>,
+    bounds: Option<(f32, f32)>,
+    /// Active drag and the frame it started from
+    drag: Arc<Mutex<Option<(DragMode, WindowFrame)>>>,
+}
+
+impl WindowController {
+    /// Area bounds, falling back to the viewport
+    fn bounds(&self) -> (f32, f32) {
+        self.bounds
+            .or_else(|| BlincContextState::try_get().map(|ctx| ctx.viewport_size()))
+            .filter(|(width, height)| *width > 0.0 && *height > 0.0)
+            .unwrap_or((f32::INFINITY, f32::INFINITY))
+    }
+
+    /// Bring this window to the front of its area
+    fn focus(&self) {
+        if self.order.get().last() == Some(&self.key) {
+            return;
+        }
+        let key = self.key.clone();
+        self.order.update(move |mut order| {
+            order.retain(|k| *k != key);
+            order.push(key);
+            order
+        });
+    }
+
+    /// Handle a press at `(local_x, local_y)` within the window
+    ///
+    /// Returns whether the press starts a move or resize.
+    fn press(&self, local_x: f32, local_y: f32) -> bool {
+        self.focus();
+        let frame = self.frame.get();
+        let minimized = self.minimized.get();
+        let edge = ResizeEdge::at(local_x, local_y, (frame.width, frame.height))
+            .filter(|_| self.config.resizable && !minimized);
+        let mode = match edge {
+            Some(edge) => Some(DragMode::Resize(edge)),
+            None if local_y < TITLE_BAR_HEIGHT => Some(DragMode::Move),
+            None => None,
+        };
+        *self.drag.lock().unwrap() = mode.map(|mode| (mode, frame));
+        mode.is_some()
+    }
+
+    /// Apply a drag offset `(dx, dy)` from the press
+    fn drag(&self, dx: f32, dy: f32) {
+        let Some((mode, start)) = *self.drag.lock().unwrap() else {
+            return;
+        };
+        let bounds = self.bounds();
+        let frame = match mode {
+            DragMode::Move => start.moved(dx, dy, bounds),
+            DragMode::Resize(edge) => start.resized(edge, dx, dy, self.config.min_size, bounds),
+        };
+        if frame != self.frame.get() {
+            self.frame.set(frame);
+        }
+    }
+
+    fn end_drag(&self) {
+        *self.drag.lock().unwrap() = None;
+    }
+
+    fn toggle_minimized(&self) {
+        self.minimized.set(!self.minimized.get());
+    }
+
+    fn close(&self) {
+        self.open.set(false);
+        if let Some(ref callback) = self.config.on_close {
+            callback();
+        }
+    }
+}
+
+/// Windows in stacking order, back to front
+///
+/// Windows that were never raised stay at the back in declaration order.
+fn stacking_order(windows: &[WindowController], order: &[String]) -> Vec<WindowController> {
+    let mut stacked = windows.to_vec();
+    stacked.sort_by_key(|window| {
+        order
+            .iter()
+            .position(|key| *key == window.key)
+            .map_or(0, |position| position + 1)
+    });
+    stacked
+}
+
+/// Floating window area component
+pub struct FloatingWindowArea {
+    inner: Div,
+}
+
+impl FloatingWindowArea {
+    fn with_config(
+        key: &str,
+        windows: Vec<(String, FloatingWindowConfig)>,
+        bounds: Option<(f32, f32)>,
+    ) -> Self {
+        let ctx = BlincContextState::get();
+        let order = ctx.use_state_keyed(&format!("{}_order", key), Vec::<String>::new);
+        let controllers: Vec<WindowController> = windows
+            .into_iter()
+            .map(|(window_key, config)| {
+                let initial = config.frame;
+                WindowController {
+                    frame: ctx.use_state_keyed(&format!("{}_frame", window_key), || initial),
+                    minimized: ctx.use_state_keyed(&format!("{}_minimized", window_key), || false),
+                    open: ctx.use_state_keyed(&format!("{}_open", window_key), || true),
+                    order: order.clone(),
+                    bounds,
+                    drag: Arc::new(Mutex::new(None)),
+                    key: window_key,
+                    config,
+                }
+            })
+            .collect();
+
+        let order_for_state = order.clone();
+        let area = stateful_with_key::<NoState>(key)
+            .deps([order.signal_id()])
+            .on_state(move |_ctx| {
+                let mut area = div()
+                    .absolute()
+                    .left(0.0)
+                    .top(0.0)
+                    .right(0.0)
+                    .bottom(0.0)
+                    .pointer_events_none();
+                for window in stacking_order(&controllers, &order_for_state.get()) {
+                    area = area.child(floating_window_element(window));
+                }
+                area
+            });
+
+        Self {
+            inner: div()
+                .absolute()
+                .left(0.0)
+                .top(0.0)
+                .right(0.0)
+                .bottom(0.0)
+                .pointer_events_none()
+                .child(area),
+        }
+    }
+}
+
+/// A single window, rebuilt when it moves, resizes, minimizes or closes
+fn floating_window_element(window: WindowController) -> Div {
+    let deps = [
+        window.frame.signal_id(),
+        window.minimized.signal_id(),
+        window.open.signal_id(),
+    ];
+    let render_window = window.clone();
+    let press_window = window.clone();
+    let drag_window = window.clone();
+    let end_window = window.clone();
+
+    let element = stateful_with_key::<NoState>(&window.key)
+        .deps(deps)
+        .on_state(move |_ctx| window_body(&render_window))
+        .on_mouse_down(move |event| {
+            // Follow a move or resize past the window's edges
+            if press_window.press(event.local_x, event.local_y) {
+                event.capture_pointer();
+            }
+        })
+        .on_drag(move |event| drag_window.drag(event.drag_delta_x, event.drag_delta_y))
+        .on_drag_end(move |_event| end_window.end_drag());
+
+    div().child(element)
+}
+
+/// Frame, title bar, content and resize zones of an open window
+fn window_body(window: &WindowController) -> Div {
+    if !window.open.get() {
+        return div().w(0.0).h(0.0);
+    }
+
+    let theme = ThemeState::get();
+    let bg = theme.color(ColorToken::SurfaceElevated);
+    let border = theme.color(ColorToken::Border);
+    let radius = theme.radius(RadiusToken::Lg);
+
+    let frame = window.frame.get();
+    let minimized = window.minimized.get();
+    let height = if minimized {
+        TITLE_BAR_HEIGHT
+    } else {
+        frame.height
+    };
+
+    let mut body = div()
+        .absolute()
+        .left(frame.x)
+        .top(frame.y)
+        .w(frame.width)
+        .h(height)
+        .flex_col()
+        .bg(bg)
+        .border(1.0, border)
+        .rounded(radius)
+        .shadow_lg()
+        .overflow_clip()
+        .child(title_bar(window, minimized));
+
+    if !minimized {
+        body = body.child(div().w_full().h(1.0).bg(border)).child(
+            div()
+                .flex_1()
+                .w_full()
+                .overflow_clip()
+                .child((window.config.content)()),
+        );
+        if window.config.resizable {
+            body = resize_zones(body);
+        }
+    }
+    body
+}
+
+/// Title with minimize and close buttons; dragging it moves the window
+fn title_bar(window: &WindowController, minimized: bool) -> Div {
+    let theme = ThemeState::get();
+    let spacing = theme.spacing();
+    let text_primary = theme.color(ColorToken::TextPrimary);
+
+    let mut bar = div()
+        .flex_row()
+        .items_center()
+        .w_full()
+        .h(TITLE_BAR_HEIGHT)
+        .px(spacing.space_3)
+        .gap_px(spacing.space_1)
+        .cursor(CursorStyle::Move)
+        .child(
+            div().flex_1().overflow_clip().child(
+                text(&window.config.title)
+                    .size(13.0)
+                    .medium()
+                    .color(text_primary)
+                    .no_wrap(),
+            ),
+        );
+
+    if window.config.minimizable {
+        let toggle = window.clone();
+        let icon = if minimized { RESTORE_SVG } else { MINIMIZE_SVG };
+        bar = bar.child(title_button(icon).on_click(move |_| toggle.toggle_minimized()));
+    }
+    if window.config.closable {
+        let close = window.clone();
+        bar = bar.child(title_button(CLOSE_SVG).on_click(move |_| close.close()));
+    }
+    bar
+}
+
+fn title_button(icon: &str) -> Div {
+    let theme = ThemeState::get();
+    let text_secondary = theme.color(ColorToken::TextSecondary);
+    div()
+        .w(22.0)
+        .h(22.0)
+        .items_center()
+        .justify_center()
+        .rounded(theme.radius(RadiusToken::Sm))
+        .cursor_pointer()
+        .child(svg(icon).size(14.0, 14.0).color(text_secondary))
+}
+
+/// Invisible edge and corner zones showing resize cursors
+///
+/// The window itself decides what a press drags, from where it landed.
+fn resize_zones(body: Div) -> Div {
+    let size = RESIZE_HANDLE_SIZE;
+    let zone = |edge: ResizeEdge| div().absolute().cursor(edge.cursor());
+    body.child(
+        zone(ResizeEdge::Top)
+            .top(0.0)
+            .left(size)
+            .right(size)
+            .h(size),
+    )
+    .child(
+        zone(ResizeEdge::Bottom)
+            .bottom(0.0)
+            .left(size)
+            .right(size)
+            .h(size),
+    )
+    .child(
+        zone(ResizeEdge::Left)
+            .left(0.0)
+            .top(size)
+            .bottom(size)
+            .w(size),
+    )
+    .child(
+        zone(ResizeEdge::Right)
+            .right(0.0)
+            .top(size)
+            .bottom(size)
+            .w(size),
+    )
+    .child(zone(ResizeEdge::TopLeft).top(0.0).left(0.0).w(size).h(size))
+    .child(
+        zone(ResizeEdge::TopRight)
+            .top(0.0)
+            .right(0.0)
+            .w(size)
+            .h(size),
+    )
+    .child(
+        zone(ResizeEdge::BottomLeft)
+            .bottom(0.0)
+            .left(0.0)
+            .w(size)
+            .h(size),
+    )
+    .child(
+        zone(ResizeEdge::BottomRight)
+            .bottom(0.0)
+            .right(0.0)
+            .w(size)
+            .h(size),
+    )
+}
+
+impl ElementBuilder for FloatingWindowArea {
+    fn build(&self, tree: &mut LayoutTree) -> LayoutNodeId {
+        self.inner.build(tree)
+    }
+
+    fn render_props(&self) -> RenderProps {
+        self.inner.render_props()
+    }
+
+    fn children_builders(&self) -> &[Box<dyn ElementBuilder>] {
+        self.inner.children_builders()
+    }
+
+    fn element_type_id(&self) -> ElementTypeId {
+        self.inner.element_type_id()
+    }
+
+    fn layout_style(&self) -> Option<&taffy::Style> {
+        self.inner.layout_style()
+    }
+}
+
+/// Builder for a floating window, added to an area with
+/// [`FloatingWindowAreaBuilder::window`]
+pub struct FloatingWindowBuilder {
+    key: InstanceKey,
+    config: FloatingWindowConfig,
+}
+
+impl FloatingWindowBuilder {
+    /// Create a floating window with a title and a content builder
+    #[track_caller]
+    pub fn new<F>(title: impl Into<String>, content: F) -> Self
+    where
+        F: Fn() -> Div + Send + Sync + 'static,
+    {
+        Self {
+            key: InstanceKey::new("floating_window"),
+            config: FloatingWindowConfig {
+                title: title.into(),
+                content: Arc::new(content),
+                frame: WindowFrame::new(32.0, 32.0, 320.0, 240.0),
+                min_size: (160.0, 96.0),
+                resizable: true,
+                minimizable: true,
+                closable: true,
+                on_close: None,
+            },
+        }
+    }
+
+    /// Set an explicit key, so the window keeps its frame across rebuilds
+    /// wherever it's created
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = InstanceKey::explicit(key);
+        self
+    }
+
+    /// Set the initial position within the area
+    pub fn position(mut self, x: f32, y: f32) -> Self {
+        self.config.frame.x = x;
+        self.config.frame.y = y;
+        self
+    }
+
+    /// Set the initial size
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.config.frame.width = width;
+        self.config.frame.height = height;
+        self
+    }
+
+    /// Set the smallest size resizing allows
+    pub fn min_size(mut self, width: f32, height: f32) -> Self {
+        self.config.min_size = (width, height);
+        self
+    }
+
+    /// Allow or prevent resizing from the edges (default: allowed)
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.config.resizable = resizable;
+        self
+    }
+
+    /// Show or hide the minimize button
+    pub fn minimizable(mut self, minimizable: bool) -> Self {
+        self.config.minimizable = minimizable;
+        self
+    }
+
+    /// Show or hide the close button
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.config.closable = closable;
+        self
+    }
+
+    /// Set the callback for the close button
+    pub fn on_close<F>(mut self, callback: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.config.on_close = Some(Arc::new(callback));
+        self
+    }
+}
+
+/// Builder for an area hosting floating windows
+pub struct FloatingWindowAreaBuilder {
+    key: InstanceKey,
+    windows: Vec<FloatingWindowBuilder>,
+    bounds: Option<(f32, f32)>,
+    /// Cached built area - built lazily on first access
+    built: OnceCell<FloatingWindowArea>,
+}
+
+impl FloatingWindowAreaBuilder {
+    /// Create an empty area covering its positioned parent
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            key: InstanceKey::new("floating_window_area"),
+            windows: Vec::new(),
+            bounds: None,
+            built: OnceCell::new(),
+        }
+    }
+
+    /// Create an area with an explicit key
+    pub fn with_key(key: impl Into<String>) -> Self {
+        Self {
+            key: InstanceKey::explicit(key),
+            ..Self::new()
+        }
+    }
+
+    /// Add a window above the ones added before it
+    pub fn window(mut self, window: FloatingWindowBuilder) -> Self {
+        self.windows.push(window);
+        self
+    }
+
+    /// Keep windows within `(width, height)` instead of the viewport
+    pub fn bounds(mut self, width: f32, height: f32) -> Self {
+        self.bounds = Some((width, height));
+        self
+    }
+
+    /// Get or build the inner area
+    fn get_or_build(&self) -> &FloatingWindowArea {
+        self.built.get_or_init(|| {
+            let windows = self
+                .windows
+                .iter()
+                .map(|window| (window.key.get().to_string(), window.config.clone()))
+                .collect();
+            FloatingWindowArea::with_config(self.key.get(), windows, self.bounds)
+        })
+    }
+}
+
+impl Default for FloatingWindowAreaBuilder {
+    #[track_caller]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ElementBuilder for FloatingWindowAreaBuilder {
+    fn build(&self, tree: &mut LayoutTree) -> LayoutNodeId {
+        self.get_or_build().build(tree)
+    }
+
+    fn render_props(&self) -> RenderProps {
+        self.get_or_build().render_props()
+    }
+
+    fn children_builders(&self) -> &[Box<dyn ElementBuilder>] {
+        self.get_or_build().children_builders()
+    }
+
+    fn element_type_id(&self) -> ElementTypeId {
+        self.get_or_build().element_type_id()
+    }
+
+    fn layout_style(&self) -> Option<&taffy::Style> {
+        self.get_or_build().layout_style()
+    }
+}
+
+/// Create a floating window with a title and a content builder
+///
+/// # Example
+///
+/// ```ignore
+/// cn::floating_window("Inspector", || inspector_panel())
+///     .position(24.0, 24.0)
+///     .size(280.0, 360.0)
+/// ```
+#[track_caller]
+pub fn floating_window<F>(title: impl Into<String>, content: F) -> FloatingWindowBuilder
+where
+    F: Fn() -> Div + Send + Sync + 'static,
+{
+    FloatingWindowBuilder::new(title, content)
+}
+
+/// Create an area for floating windows, sharing one z-order
+///
+/// Place it inside a positioned container; it covers the container without
+/// blocking clicks outside its windows.
+#[track_caller]
+pub fn floating_window_area() -> FloatingWindowAreaBuilder {
+    FloatingWindowAreaBuilder::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+
+    use blinc_core::reactive::ReactiveGraph;
+
+    use super::*;
+
+    fn state<T: Clone + Send + 'static>(initial: T) -> State<T> {
+        let mut graph = ReactiveGraph::new();
+        let signal = graph.create_signal(initial);
+        State::new(
+            signal,
+            Arc::new(Mutex::new(graph)),
+            Arc::new(AtomicBool::new(false)),
+        )
+    }
+
+    fn controller(key: &str, frame: WindowFrame, order: &State<Vec<String>>) -> WindowController {
+        WindowController {
+            key: key.to_string(),
+            config: FloatingWindowBuilder::new(key, div)
+                .position(frame.x, frame.y)
+                .size(frame.width, frame.height)
+                .min_size(100.0, 80.0)
+                .config,
+            frame: state(frame),
+            minimized: state(false),
+            open: state(true),
+            order: order.clone(),
+            bounds: Some((800.0, 600.0)),
+            drag: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    #[test]
+    fn test_title_bar_drag_moves_by_delta() {
+        let order = state(Vec::new());
+        let window = controller(
+            "inspector",
+            WindowFrame::new(100.0, 50.0, 300.0, 200.0),
+            &order,
+        );
+
+        assert!(window.press(150.0, TITLE_BAR_HEIGHT / 2.0));
+        window.drag(40.0, 25.0);
+        assert_eq!(
+            window.frame.get(),
+            WindowFrame::new(140.0, 75.0, 300.0, 200.0)
+        );
+
+        // Offsets are from the press, and the window stays in bounds
+        window.drag(-500.0, 1000.0);
+        assert_eq!(
+            window.frame.get(),
+            WindowFrame::new(0.0, 400.0, 300.0, 200.0)
+        );
+        window.end_drag();
+
+        // Pressing the content doesn't drag
+        assert!(!window.press(150.0, 120.0));
+        window.drag(10.0, 10.0);
+        assert_eq!(
+            window.frame.get(),
+            WindowFrame::new(0.0, 400.0, 300.0, 200.0)
+        );
+    }
+
+    #[test]
+    fn test_edges_resize_with_min_size() {
+        let order = state(Vec::new());
+        let window = controller(
+            "layers",
+            WindowFrame::new(100.0, 100.0, 300.0, 200.0),
+            &order,
+        );
+
+        // Bottom-right corner grows the window
+        window.press(298.0, 198.0);
+        window.drag(50.0, 20.0);
+        assert_eq!(
+            window.frame.get(),
+            WindowFrame::new(100.0, 100.0, 350.0, 220.0)
+        );
+        window.end_drag();
+
+        // Left edge keeps the right edge fixed and stops at the minimum width
+        window.press(2.0, 100.0);
+        window.drag(400.0, 0.0);
+        assert_eq!(
+            window.frame.get(),
+            WindowFrame::new(350.0, 100.0, 100.0, 220.0)
+        );
+    }
+
+    #[test]
+    fn test_pressing_lower_window_raises_it() {
+        let order = state(Vec::new());
+        let frame = WindowFrame::new(0.0, 0.0, 200.0, 150.0);
+        let windows = [
+            controller("a", frame, &order),
+            controller("b", frame, &order),
+        ];
+        let keys = |order: &State<Vec<String>>| -> Vec<String> {
+            stacking_order(&windows, &order.get())
+                .into_iter()
+                .map(|window| window.key)
+                .collect()
+        };
+        assert_eq!(keys(&order), ["a", "b"]);
+
+        windows[0].press(100.0, 100.0);
+        assert_eq!(keys(&order), ["b", "a"]);
+
+        windows[1].press(100.0, 10.0);
+        assert_eq!(keys(&order), ["a", "b"]);
+    }
+}
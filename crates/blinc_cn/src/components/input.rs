@@ -39,6 +39,13 @@
 //!     .idle_bg_color(Color::rgba(0.1, 0.1, 0.1, 1.0))
 //!     .text_color(Color::WHITE)
 //!     .placeholder_color(Color::rgba(0.5, 0.5, 0.5, 1.0))
+//!
+//! // Adornments inside the border
+//! cn::input(&search)
+//!     .leading(|| div().child(cn::icon(icons::SEARCH)))
+//!     .clear_button()
+//!
+//! cn::input(&site).prefix("https://").suffix(".com")
 //! ```
 
 use std::sync::Arc;
@@ -47,7 +54,8 @@ use blinc_core::Color;
 use blinc_layout::div::ElementTypeId;
 use blinc_layout::prelude::*;
 use blinc_layout::widgets::text_input::{
-    InputType, OnChangeCallback, SharedTextInputData, TextInput,
+    clear_text_input, InputType, OnChangeCallback, SharedTextInputData, TextInput,
+    TextInputAdornments,
 };
 use blinc_theme::{ColorToken, RadiusToken, SpacingToken, ThemeState, TypographyTokens};
 use std::ops::{Deref, DerefMut};
@@ -263,6 +271,21 @@ impl Input {
             input = input.placeholder(placeholder.clone());
         }
 
+        // Adornments
+        let adornments = config.adornments.clone();
+        if let Some(leading) = adornments.leading {
+            input = input.leading(move || leading());
+        }
+        if let Some(prefix) = adornments.prefix {
+            input = input.prefix(prefix);
+        }
+        if let Some(suffix) = adornments.suffix {
+            input = input.suffix(suffix);
+        }
+        if let Some(trailing) = adornments.trailing {
+            input = input.trailing(move || trailing());
+        }
+
         // Apply width
         if config.full_width {
             input = input.w_full();
@@ -337,6 +360,7 @@ struct InputConfig {
     selection_color: Option<Color>,
     border_width: Option<f32>,
     corner_radius: Option<f32>,
+    adornments: TextInputAdornments,
     on_change: Option<OnChangeCallback>,
 }
 
@@ -363,6 +387,7 @@ impl Default for InputConfig {
             selection_color: None,
             border_width: None,
             corner_radius: None,
+            adornments: TextInputAdornments::default(),
             on_change: None,
         }
     }
@@ -582,6 +607,55 @@ impl InputBuilder {
         self
     }
 
+    // ========== Adornments ==========
+
+    /// Show an element inside the border, before the text (e.g. an icon)
+    ///
+    /// The builder runs whenever the input redraws, so keep it cheap.
+    pub fn leading<F>(mut self, builder: F) -> Self
+    where
+        F: Fn() -> Div + Send + Sync + 'static,
+    {
+        self.config.adornments.leading = Some(Arc::new(builder));
+        self
+    }
+
+    /// Show an element inside the border, after the text (e.g. a button)
+    ///
+    /// The builder runs whenever the input redraws, so keep it cheap.
+    pub fn trailing<F>(mut self, builder: F) -> Self
+    where
+        F: Fn() -> Div + Send + Sync + 'static,
+    {
+        self.config.adornments.trailing = Some(Arc::new(builder));
+        self
+    }
+
+    /// Show muted text before the value, e.g. `https://`
+    pub fn prefix(mut self, text: impl Into<String>) -> Self {
+        self.config.adornments.prefix = Some(text.into());
+        self
+    }
+
+    /// Show muted text after the value, e.g. a unit
+    pub fn suffix(mut self, text: impl Into<String>) -> Self {
+        self.config.adornments.suffix = Some(text.into());
+        self
+    }
+
+    /// Add a trailing button that empties the input and focuses it
+    ///
+    /// Replaces any other trailing element.
+    pub fn clear_button(self) -> Self {
+        let data = self.config.data.clone();
+        let size = match self.config.size {
+            InputSize::Small => 14.0,
+            InputSize::Medium => 16.0,
+            InputSize::Large => 18.0,
+        };
+        self.trailing(move || clear_button(&data, size))
+    }
+
     /// Set the callback to be invoked when the text value changes
     ///
     /// The callback receives the new text value as a string slice.
@@ -632,9 +706,33 @@ pub fn input(data: &SharedTextInputData) -> InputBuilder {
     InputBuilder::new(data)
 }
 
+const CLEAR_ICON: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><line x1="18" x2="6" y1="6" y2="18"/><line x1="6" x2="18" y1="6" y2="18"/></svg>"#;
+
+/// The ✕ button added by [`InputBuilder::clear_button`]
+fn clear_button(data: &SharedTextInputData, size: f32) -> Div {
+    let theme = ThemeState::get();
+    let data = data.clone();
+    div()
+        .items_center()
+        .justify_center()
+        .p_px(2.0)
+        .rounded(theme.radius(RadiusToken::Sm))
+        .cursor_pointer()
+        .on_click(move |_| clear_text_input(&data))
+        .child(
+            svg(CLEAR_ICON)
+                .size(size, size)
+                .color(theme.color(ColorToken::TextTertiary)),
+        )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use blinc_core::events::event_types;
+    use blinc_layout::renderer::RenderTree;
+    use blinc_layout::tree::LayoutNodeId;
+    use blinc_layout::widgets::text_input::{blur_all_text_inputs, text_input_data};
     use blinc_theme::TypographyTokens;
 
     fn init_theme() {
@@ -673,4 +771,65 @@ mod tests {
             .placeholder("Enter username")
             .size(InputSize::Large);
     }
+
+    /// The last node in tree order with a click handler
+    fn last_clickable(tree: &RenderTree, node: LayoutNodeId) -> Option<LayoutNodeId> {
+        let children = tree.layout().children(node);
+        children
+            .iter()
+            .rev()
+            .find_map(|&child| last_clickable(tree, child))
+            .or_else(|| {
+                tree.handler_registry()
+                    .has_handler(node, event_types::POINTER_UP)
+                    .then_some(node)
+            })
+    }
+
+    /// Press and release on `node`, bubbling to its ancestors like the event router
+    fn click(tree: &mut RenderTree, node: LayoutNodeId) {
+        for event in [event_types::POINTER_DOWN, event_types::POINTER_UP] {
+            let mut target = Some(node);
+            while let Some(id) = target {
+                tree.dispatch_event(id, event, 0.0, 0.0);
+                target = tree.layout().parent(id);
+            }
+        }
+    }
+
+    #[test]
+    fn test_clear_button_empties_value_and_refocuses() {
+        init_theme();
+        let data = text_input_data();
+        data.lock().unwrap().value = "query".to_string();
+        let changes = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let field = input(&data)
+            .w(240.0)
+            .leading(|| div().id("search-icon").w(16.0).h(16.0))
+            .clear_button()
+            .on_change({
+                let changes = Arc::clone(&changes);
+                move |value| changes.lock().unwrap().push(value.to_string())
+            });
+        let mut tree = RenderTree::from_element(&field);
+        tree.compute_layout(400.0, 100.0);
+
+        // Clicking the leading adornment focuses the field instead of taking focus
+        let icon = tree.query_by_id("search-icon").unwrap();
+        click(&mut tree, icon);
+        assert!(data.lock().unwrap().visual.is_focused());
+        assert_eq!(data.lock().unwrap().value, "query");
+
+        blur_all_text_inputs();
+        assert!(!data.lock().unwrap().visual.is_focused());
+
+        let clear = last_clickable(&tree, tree.root().unwrap()).unwrap();
+        click(&mut tree, clear);
+        let d = data.lock().unwrap();
+        assert_eq!(d.value, "");
+        assert_eq!(d.cursor, 0);
+        assert!(d.visual.is_focused());
+        assert_eq!(*changes.lock().unwrap(), [""]);
+    }
 }
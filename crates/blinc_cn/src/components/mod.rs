@@ -14,15 +14,18 @@ pub mod avatar;
 pub mod badge;
 pub mod breadcrumb;
 pub mod button;
+pub mod calendar;
 pub mod card;
 pub mod chart;
 pub mod checkbox;
 pub mod collapsible;
 pub mod combobox;
 pub mod context_menu;
+pub mod data_table;
 pub mod dialog;
 pub mod drawer;
 pub mod dropdown_menu;
+pub mod floating_window;
 pub mod hover_card;
 pub mod icon;
 pub mod input;
@@ -43,13 +46,16 @@ pub mod sidebar;
 pub mod skeleton;
 pub mod slider;
 pub mod spinner;
+pub mod stepper;
 pub mod switch;
 pub mod tabs;
 pub mod textarea;
+pub mod timeline_scrubber;
 pub mod toast;
 pub mod tooltip;
 pub mod tree;
 pub mod typography;
+pub mod wizard;
 
 // Re-export all components
 pub use accordion::{accordion, Accordion, AccordionBuilder, AccordionMode};
@@ -59,6 +65,9 @@ pub use breadcrumb::{
     breadcrumb, Breadcrumb, BreadcrumbBuilder, BreadcrumbItem, BreadcrumbSeparator, BreadcrumbSize,
 };
 pub use button::{button, Button, ButtonBuilder, ButtonSize, ButtonVariant, IconPosition};
+pub use calendar::{
+    calendar, Calendar, CalendarBuilder, CalendarDate, CalendarEvent, CalendarState, CalendarView,
+};
 pub use collapsible::{
     collapsible, collapsible_section, Collapsible, CollapsibleBuilder, CollapsibleTrigger,
 };
@@ -73,14 +82,21 @@ pub use chart::{
     DataSeries, Histogram, HistogramBuilder, LineChart, LineChartBuilder, SparkLine,
     SparkLineBuilder, ThresholdBand, ThresholdLineChart, ThresholdLineChartBuilder,
 };
-pub use checkbox::{checkbox, Checkbox, CheckboxSize};
+pub use checkbox::{checkbox, CheckState, Checkbox, CheckboxSize};
 pub use combobox::{combobox, Combobox, ComboboxBuilder, ComboboxOption, ComboboxSize};
 pub use context_menu::{context_menu, ContextMenuBuilder, ContextMenuItem, SubmenuBuilder};
+pub use data_table::{
+    data_table, ColumnAlign, DataTable, DataTableBuilder, DataTableColumn, DataTableRow, RowId,
+};
 pub use dialog::{alert_dialog, dialog, AlertDialogBuilder, DialogBuilder, DialogSize};
 pub use drawer::{drawer, drawer_left, drawer_right, DrawerBuilder, DrawerSide, DrawerSize};
 pub use dropdown_menu::{
     dropdown_menu, dropdown_menu_custom, DropdownAlign, DropdownMenuBuilder, DropdownPosition,
 };
+pub use floating_window::{
+    floating_window, floating_window_area, FloatingWindowArea, FloatingWindowAreaBuilder,
+    FloatingWindowBuilder, ResizeEdge, WindowFrame,
+};
 pub use hover_card::{hover_card, HoverCard, HoverCardAlign, HoverCardBuilder, HoverCardSide};
 pub use icon::{icon, Icon, IconBuilder, IconSize};
 pub use input::{input, Input, InputBgColors, InputBorderColors, InputSize};
@@ -111,14 +127,17 @@ pub use sidebar::{sidebar, Sidebar, SidebarBuilder, SidebarItem, SidebarSection}
 pub use skeleton::{skeleton, skeleton_circle, Skeleton};
 pub use slider::{slider, Slider, SliderSize};
 pub use spinner::{spinner, Spinner, SpinnerSize};
+pub use stepper::{stepper, Stepper, StepperBuilder, StepperSize};
 pub use switch::{switch, Switch, SwitchSize};
 pub use tabs::{tab_item, tabs, TabMenuItem, Tabs, TabsBuilder, TabsSize, TabsTransition};
 pub use textarea::{textarea, Textarea, TextareaSize};
+pub use timeline_scrubber::{timeline_scrubber, TimelineScrubber, TimelineScrubberBuilder};
 pub use toast::{
     toast, toast_custom, toast_error, toast_success, toast_warning, ToastBuilder, ToastVariant,
 };
 pub use tooltip::{tooltip, Tooltip, TooltipAlign, TooltipBuilder, TooltipSide};
 pub use tree::{tree_view, TreeNodeConfig, TreeNodeDiff, TreeView, TreeViewBuilder};
+pub use wizard::{wizard, wizard_step, Wizard, WizardBuilder, WizardStep, WizardStepStatus};
 // Typography helpers (label excluded - use Label component instead)
 pub use aspect_ratio::{
     aspect_ratio, aspect_ratio_16_9, aspect_ratio_21_9, aspect_ratio_4_3, aspect_ratio_9_16,
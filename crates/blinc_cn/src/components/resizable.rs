@@ -208,9 +208,21 @@ impl Default for ResizablePanelBuilder {
 
 /// Panel constraints extracted from config
 #[derive(Clone, Copy)]
-struct PanelConstraints {
-    min_size: f32,
-    max_size: Option<f32>,
+pub(crate) struct PanelConstraints {
+    pub(crate) min_size: f32,
+    pub(crate) max_size: Option<f32>,
+}
+
+impl PanelConstraints {
+    /// Clamp a dragged size to the constraints; the maximum wins if they
+    /// conflict
+    pub(crate) fn clamp(&self, size: f32) -> f32 {
+        let size = size.max(self.min_size);
+        match self.max_size {
+            Some(max) => size.min(max),
+            None => size,
+        }
+    }
 }
 
 /// Configuration for the resizable group
@@ -471,9 +483,7 @@ impl ResizableGroup {
 
         // Extract constraints
         let left_min = left_constraints.min_size;
-        let left_max = left_constraints.max_size;
         let right_min = right_constraints.min_size;
-        let right_max = right_constraints.max_size;
 
         let handle_key = format!("{}_handle_{}", key, index);
         let idx = index;
@@ -528,7 +538,8 @@ impl ResizableGroup {
                 hit_area.child(handle_visual)
             })
             .on_mouse_down(move |event| {
-                // Start drag
+                // Start drag, following the pointer off the handle
+                event.capture_pointer();
                 drag_index_for_down.set(idx as i32);
 
                 // Store start position
@@ -565,19 +576,9 @@ impl ResizableGroup {
                 let left_start = start_sizes[idx];
                 let right_start = start_sizes[idx + 1];
 
-                let mut new_left = left_start + delta;
-                let mut new_right = right_start - delta;
-
                 // Apply constraints
-                new_left = new_left.max(left_min);
-                if let Some(max) = left_max {
-                    new_left = new_left.min(max);
-                }
-
-                new_right = new_right.max(right_min);
-                if let Some(max) = right_max {
-                    new_right = new_right.min(max);
-                }
+                let mut new_left = left_constraints.clamp(left_start + delta);
+                let mut new_right = right_constraints.clamp(right_start - delta);
 
                 // Ensure total stays constant
                 let total = left_start + right_start;
@@ -409,6 +409,8 @@ impl Slider {
                 if disabled {
                     return;
                 }
+                // Keep receiving the drag after the pointer leaves the track
+                event.capture_pointer();
                 // Store mouse X position and current thumb offset at drag start
                 drag_start_x_for_down.set(event.mouse_x);
                 let current = thumb_offset_for_down.lock().unwrap().get();
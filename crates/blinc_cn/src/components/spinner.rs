@@ -33,7 +33,7 @@
 
 use blinc_animation::SharedAnimatedTimeline;
 use blinc_core::{Brush, Color, CornerRadius, DrawContext, Rect};
-use blinc_layout::canvas::{CanvasBounds, CanvasRenderFn};
+use blinc_layout::canvas::{CanvasBounds, CanvasFrameInfo, CanvasRenderFn};
 use blinc_layout::div::ElementTypeId;
 use blinc_layout::element::RenderProps;
 use blinc_layout::prelude::*;
@@ -152,82 +152,84 @@ impl Spinner {
 
         let render_timeline = Arc::clone(&timeline);
 
-        Rc::new(move |ctx: &mut dyn DrawContext, bounds: CanvasBounds| {
-            // Get current rotation angle from timeline
-            let angle_deg = render_timeline.lock().unwrap().get(entry_id).unwrap_or(0.0);
-            let angle_rad = angle_deg * PI / 180.0;
-
-            let cx = bounds.width / 2.0;
-            let cy = bounds.height / 2.0;
-            let radius = (diameter - border_width) / 2.0;
-
-            // Draw track circle (background)
-            let track_segments = 32;
-            for i in 0..track_segments {
-                let t1 = i as f32 / track_segments as f32;
-                let t2 = (i + 1) as f32 / track_segments as f32;
-
-                let a1 = t1 * PI * 2.0;
-                let a2 = t2 * PI * 2.0;
-
-                let x1 = cx + radius * a1.cos();
-                let y1 = cy + radius * a1.sin();
-                let x2 = cx + radius * a2.cos();
-                let y2 = cy + radius * a2.sin();
-
-                let dx = x2 - x1;
-                let dy = y2 - y1;
-                let len = (dx * dx + dy * dy).sqrt();
-
-                ctx.fill_rect(
-                    Rect::new(
-                        x1 - border_width / 2.0,
-                        y1 - border_width / 2.0,
-                        len + border_width,
-                        border_width,
-                    ),
-                    CornerRadius::uniform(border_width / 2.0),
-                    Brush::Solid(track_color),
-                );
-            }
-
-            // Draw spinning arc (270 degrees with fade effect)
-            let arc_length = PI * 1.5; // 270 degrees
-            let segments = 24;
-
-            for i in 0..segments {
-                let t1 = i as f32 / segments as f32;
-                let t2 = (i + 1) as f32 / segments as f32;
-
-                let a1 = angle_rad + t1 * arc_length;
-                let a2 = angle_rad + t2 * arc_length;
-
-                let x1 = cx + radius * a1.cos();
-                let y1 = cy + radius * a1.sin();
-                let x2 = cx + radius * a2.cos();
-                let y2 = cy + radius * a2.sin();
-
-                let dx = x2 - x1;
-                let dy = y2 - y1;
-                let len = (dx * dx + dy * dy).sqrt();
-
-                // Fade effect: trail fades out behind the leading edge
-                let alpha = 0.3 + 0.7 * t1;
-                let color_with_alpha =
-                    Color::rgba(spinner_color.r, spinner_color.g, spinner_color.b, alpha);
-
-                ctx.fill_rect(
-                    Rect::new(
-                        x1 - border_width / 2.0,
-                        y1 - border_width / 2.0,
-                        len + border_width,
-                        border_width,
-                    ),
-                    CornerRadius::uniform(border_width / 2.0),
-                    Brush::Solid(color_with_alpha),
-                );
-            }
-        })
+        Rc::new(
+            move |ctx: &mut dyn DrawContext, bounds: CanvasBounds, _: &CanvasFrameInfo| {
+                // Get current rotation angle from timeline
+                let angle_deg = render_timeline.lock().unwrap().get(entry_id).unwrap_or(0.0);
+                let angle_rad = angle_deg * PI / 180.0;
+
+                let cx = bounds.width / 2.0;
+                let cy = bounds.height / 2.0;
+                let radius = (diameter - border_width) / 2.0;
+
+                // Draw track circle (background)
+                let track_segments = 32;
+                for i in 0..track_segments {
+                    let t1 = i as f32 / track_segments as f32;
+                    let t2 = (i + 1) as f32 / track_segments as f32;
+
+                    let a1 = t1 * PI * 2.0;
+                    let a2 = t2 * PI * 2.0;
+
+                    let x1 = cx + radius * a1.cos();
+                    let y1 = cy + radius * a1.sin();
+                    let x2 = cx + radius * a2.cos();
+                    let y2 = cy + radius * a2.sin();
+
+                    let dx = x2 - x1;
+                    let dy = y2 - y1;
+                    let len = (dx * dx + dy * dy).sqrt();
+
+                    ctx.fill_rect(
+                        Rect::new(
+                            x1 - border_width / 2.0,
+                            y1 - border_width / 2.0,
+                            len + border_width,
+                            border_width,
+                        ),
+                        CornerRadius::uniform(border_width / 2.0),
+                        Brush::Solid(track_color),
+                    );
+                }
+
+                // Draw spinning arc (270 degrees with fade effect)
+                let arc_length = PI * 1.5; // 270 degrees
+                let segments = 24;
+
+                for i in 0..segments {
+                    let t1 = i as f32 / segments as f32;
+                    let t2 = (i + 1) as f32 / segments as f32;
+
+                    let a1 = angle_rad + t1 * arc_length;
+                    let a2 = angle_rad + t2 * arc_length;
+
+                    let x1 = cx + radius * a1.cos();
+                    let y1 = cy + radius * a1.sin();
+                    let x2 = cx + radius * a2.cos();
+                    let y2 = cy + radius * a2.sin();
+
+                    let dx = x2 - x1;
+                    let dy = y2 - y1;
+                    let len = (dx * dx + dy * dy).sqrt();
+
+                    // Fade effect: trail fades out behind the leading edge
+                    let alpha = 0.3 + 0.7 * t1;
+                    let color_with_alpha =
+                        Color::rgba(spinner_color.r, spinner_color.g, spinner_color.b, alpha);
+
+                    ctx.fill_rect(
+                        Rect::new(
+                            x1 - border_width / 2.0,
+                            y1 - border_width / 2.0,
+                            len + border_width,
+                            border_width,
+                        ),
+                        CornerRadius::uniform(border_width / 2.0),
+                        Brush::Solid(color_with_alpha),
+                    );
+                }
+            },
+        )
     }
 }
 
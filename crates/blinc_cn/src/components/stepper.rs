@@ -0,0 +1,488 @@
+//! Stepper component for numeric input
+//!
+//! A spinbox showing a number between decrement and increment buttons.
+//! Holding a button repeats the step, speeding up after a moment; the
+//! Up/Down arrow keys step while the stepper is focused. Values are
+//! rounded to the step and clamped to the min/max.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use blinc_cn::prelude::*;
+//!
+//! fn build_ui(ctx: &WindowedContext) -> impl ElementBuilder {
+//!     let quantity = ctx.use_state_keyed("quantity", || 1.0);
+//!
+//!     cn::stepper(&quantity)
+//!         .min(0.0)
+//!         .max(10.0)
+//!         .step(0.5)
+//!         .on_change(|value| println!("Quantity: {}", value))
+//! }
+//! ```
+
+use std::cell::OnceCell;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use blinc_core::events::event_types;
+use blinc_core::State;
+use blinc_layout::div::ElementTypeId;
+use blinc_layout::element::{CursorStyle, RenderProps};
+use blinc_layout::prelude::*;
+use blinc_layout::stateful::{stateful_with_key, NoState};
+use blinc_layout::tree::{LayoutNodeId, LayoutTree};
+use blinc_layout::InstanceKey;
+use blinc_theme::{ColorToken, RadiusToken, ThemeState};
+
+/// Minus icon SVG
+const MINUS_SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M5 12h14"/></svg>"#;
+
+/// Plus icon SVG
+const PLUS_SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M5 12h14"/><path d="M12 5v14"/></svg>"#;
+
+/// Arrow key codes
+const KEY_UP: u32 = 38;
+const KEY_DOWN: u32 = 40;
+
+/// Hold timing for the step buttons: steady repeats after 400ms, then
+/// faster repeats once held for 1.5s
+fn default_repeat() -> ButtonRepeat {
+    ButtonRepeat::new(400, 100).accelerate(1500, 30)
+}
+
+/// Stepper size variants
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StepperSize {
+    /// Small stepper (28px tall)
+    Small,
+    /// Default size (36px tall)
+    #[default]
+    Medium,
+    /// Large stepper (44px tall)
+    Large,
+}
+
+impl StepperSize {
+    fn height(&self) -> f32 {
+        match self {
+            StepperSize::Small => 28.0,
+            StepperSize::Medium => 36.0,
+            StepperSize::Large => 44.0,
+        }
+    }
+
+    fn font_size(&self) -> f32 {
+        match self {
+            StepperSize::Small => 12.0,
+            StepperSize::Medium => 14.0,
+            StepperSize::Large => 16.0,
+        }
+    }
+
+    fn icon_size(&self) -> f32 {
+        match self {
+            StepperSize::Small => 12.0,
+            StepperSize::Medium => 14.0,
+            StepperSize::Large => 16.0,
+        }
+    }
+}
+
+/// Decimal places needed to show multiples of `step` exactly (up to 6)
+fn step_decimals(step: f64) -> usize {
+    (0..6)
+        .find(|&places| {
+            let scaled = step * 10f64.powi(places as i32);
+            (scaled - scaled.round()).abs() < 1e-9
+        })
+        .unwrap_or(6)
+}
+
+/// Move the bound value by `steps` steps and notify, if it changed
+fn apply_step(
+    value: &State<f64>,
+    constraints: NumberConstraints,
+    steps: i32,
+    on_change: &Option<Arc<dyn Fn(f64) + Send + Sync>>,
+) {
+    let current = value.get();
+    let next = constraints.offset(current, steps);
+    if next != current {
+        value.set(next);
+        if let Some(ref callback) = on_change {
+            callback(next);
+        }
+    }
+}
+
+/// Stepper component
+pub struct Stepper {
+    inner: Div,
+}
+
+impl Stepper {
+    fn with_config(key: &str, config: StepperConfig) -> Self {
+        let theme = ThemeState::get();
+        let size = config.size;
+        let height = size.height();
+        let font_size = size.font_size();
+        let radius = theme.radius(RadiusToken::Md);
+        let border = theme.color(ColorToken::Border);
+        let focus_border = theme.color(ColorToken::BorderFocus);
+        let input_bg = theme.color(ColorToken::InputBg);
+        let text_primary = theme.color(ColorToken::TextPrimary);
+        let decimals = config
+            .decimals
+            .unwrap_or_else(|| step_decimals(config.constraints.step));
+
+        // Only the value text rebuilds when the state changes, so a held
+        // button keeps its press across updates
+        let value_state = config.value.clone();
+        let display = stateful_with_key::<NoState>(format!("{}_value", key))
+            .deps([config.value.signal_id()])
+            .on_state(move |_ctx| {
+                div().child(
+                    text(format!("{:.*}", decimals, value_state.get()))
+                        .size(font_size)
+                        .color(text_primary),
+                )
+            });
+
+        let value_for_keys = config.value.clone();
+        let on_change_for_keys = config.on_change.clone();
+        let constraints = config.constraints;
+        let disabled = config.disabled;
+
+        let mut stepper = div()
+            .flex_row()
+            .items_center()
+            .h(height)
+            .w(config.width)
+            .rounded(radius)
+            .border(1.0, border)
+            .bg(input_bg)
+            .overflow_clip()
+            .child(step_button(&config, -1, MINUS_SVG))
+            .child(
+                div()
+                    .flex_1()
+                    .h_full()
+                    .items_center()
+                    .justify_center()
+                    .child(display),
+            )
+            .child(step_button(&config, 1, PLUS_SVG));
+
+        if disabled {
+            stepper = stepper.opacity(0.5);
+        } else {
+            stepper = stepper
+                .focusable()
+                .on_focus_within(move |s| s.shadow_params(0.0, 0.0, 4.0, focus_border))
+                .on_key_down(move |ctx| {
+                    let steps = match ctx.key_code {
+                        KEY_UP => 1,
+                        KEY_DOWN => -1,
+                        _ => return,
+                    };
+                    apply_step(&value_for_keys, constraints, steps, &on_change_for_keys);
+                });
+        }
+
+        Self { inner: stepper }
+    }
+}
+
+/// Build the decrement (`direction = -1`) or increment (`1`) button
+///
+/// A click steps once; holding repeats on the [`ButtonRepeat`] schedule.
+fn step_button(config: &StepperConfig, direction: i32, icon: &'static str) -> Div {
+    let theme = ThemeState::get();
+    let size = config.size;
+    let icon_size = size.icon_size();
+    let hover_bg = theme.color(ColorToken::SurfaceElevated);
+    let icon_color = theme.color(ColorToken::TextSecondary);
+
+    let button = div()
+        .w(size.height())
+        .h_full()
+        .items_center()
+        .justify_center()
+        .child(svg(icon).size(icon_size, icon_size).color(icon_color));
+
+    if config.disabled {
+        return button.cursor(CursorStyle::NotAllowed);
+    }
+
+    let value = config.value.clone();
+    let on_change = config.on_change.clone();
+    let constraints = config.constraints;
+    let click = move || apply_step(&value, constraints, direction, &on_change);
+    let hold_click = click.clone();
+
+    // Repeats fired during the current press, reset on each press
+    let repeat = config.repeat;
+    let fired = Arc::new(AtomicU64::new(0));
+    let reset = Arc::clone(&fired);
+
+    button
+        .cursor(CursorStyle::Pointer)
+        .on_hover_within(move |s| s.bg(hover_bg))
+        .on_click(move |_| click())
+        .on_mouse_down(move |_| reset.store(0, Ordering::Relaxed))
+        .on_event(event_types::PRESS_HOLD, move |ctx| {
+            let due = repeat.due(ctx.hold_ms);
+            if due > fired.load(Ordering::Relaxed) {
+                fired.store(due, Ordering::Relaxed);
+                ctx.consume_click();
+                hold_click();
+            }
+        })
+}
+
+impl ElementBuilder for Stepper {
+    fn build(&self, tree: &mut LayoutTree) -> LayoutNodeId {
+        self.inner.build(tree)
+    }
+
+    fn render_props(&self) -> RenderProps {
+        self.inner.render_props()
+    }
+
+    fn children_builders(&self) -> &[Box<dyn ElementBuilder>] {
+        self.inner.children_builders()
+    }
+
+    fn element_type_id(&self) -> ElementTypeId {
+        self.inner.element_type_id()
+    }
+
+    fn layout_style(&self) -> Option<&taffy::Style> {
+        self.inner.layout_style()
+    }
+}
+
+/// Internal configuration for building a Stepper
+#[derive(Clone)]
+struct StepperConfig {
+    value: State<f64>,
+    constraints: NumberConstraints,
+    repeat: ButtonRepeat,
+    size: StepperSize,
+    width: f32,
+    decimals: Option<usize>,
+    disabled: bool,
+    on_change: Option<Arc<dyn Fn(f64) + Send + Sync>>,
+}
+
+impl StepperConfig {
+    fn new(value: State<f64>) -> Self {
+        Self {
+            value,
+            constraints: NumberConstraints::default(),
+            repeat: default_repeat(),
+            size: StepperSize::default(),
+            width: 128.0,
+            decimals: None,
+            disabled: false,
+            on_change: None,
+        }
+    }
+}
+
+/// Builder for creating Stepper components with fluent API
+pub struct StepperBuilder {
+    key: InstanceKey,
+    config: StepperConfig,
+    /// Cached built Stepper - built lazily on first access
+    built: OnceCell<Stepper>,
+}
+
+impl StepperBuilder {
+    /// Create a new stepper builder bound to a value state
+    #[track_caller]
+    pub fn new(value: &State<f64>) -> Self {
+        Self {
+            key: InstanceKey::new("stepper"),
+            config: StepperConfig::new(value.clone()),
+            built: OnceCell::new(),
+        }
+    }
+
+    /// Create a stepper builder with an explicit key
+    pub fn with_key(key: impl Into<String>, value: &State<f64>) -> Self {
+        Self {
+            key: InstanceKey::explicit(key),
+            config: StepperConfig::new(value.clone()),
+            built: OnceCell::new(),
+        }
+    }
+
+    /// Get or build the inner Stepper
+    fn get_or_build(&self) -> &Stepper {
+        self.built
+            .get_or_init(|| Stepper::with_config(self.key.get(), self.config.clone()))
+    }
+
+    /// Set the minimum value
+    pub fn min(mut self, min: f64) -> Self {
+        self.config.constraints.min = Some(min);
+        self
+    }
+
+    /// Set the maximum value
+    pub fn max(mut self, max: f64) -> Self {
+        self.config.constraints.max = Some(max);
+        self
+    }
+
+    /// Set the step size (default 1.0)
+    pub fn step(mut self, step: f64) -> Self {
+        self.config.constraints.step = step;
+        self
+    }
+
+    /// Set min, max and step at once
+    pub fn constraints(mut self, constraints: NumberConstraints) -> Self {
+        self.config.constraints = constraints;
+        self
+    }
+
+    /// Override the press-and-hold repeat timing
+    pub fn repeat(mut self, repeat: ButtonRepeat) -> Self {
+        self.config.repeat = repeat;
+        self
+    }
+
+    /// Set the stepper size
+    pub fn size(mut self, size: StepperSize) -> Self {
+        self.config.size = size;
+        self
+    }
+
+    /// Set the width in pixels (default 128)
+    pub fn w(mut self, width: f32) -> Self {
+        self.config.width = width;
+        self
+    }
+
+    /// Show a fixed number of decimal places instead of the step's precision
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.config.decimals = Some(decimals);
+        self
+    }
+
+    /// Set disabled state
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.config.disabled = disabled;
+        self
+    }
+
+    /// Set the callback for when the value changes
+    pub fn on_change<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(f64) + Send + Sync + 'static,
+    {
+        self.config.on_change = Some(Arc::new(callback));
+        self
+    }
+}
+
+impl ElementBuilder for StepperBuilder {
+    fn build(&self, tree: &mut LayoutTree) -> LayoutNodeId {
+        self.get_or_build().build(tree)
+    }
+
+    fn render_props(&self) -> RenderProps {
+        self.get_or_build().render_props()
+    }
+
+    fn children_builders(&self) -> &[Box<dyn ElementBuilder>] {
+        self.get_or_build().children_builders()
+    }
+
+    fn element_type_id(&self) -> ElementTypeId {
+        self.get_or_build().element_type_id()
+    }
+
+    fn layout_style(&self) -> Option<&taffy::Style> {
+        self.get_or_build().layout_style()
+    }
+}
+
+/// Create a stepper bound to a numeric state
+///
+/// # Example
+///
+/// ```ignore
+/// let quantity = ctx.use_state_keyed("quantity", || 1.0);
+/// cn::stepper(&quantity).min(1.0).max(99.0)
+/// ```
+#[track_caller]
+pub fn stepper(value: &State<f64>) -> StepperBuilder {
+    StepperBuilder::new(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+    use std::sync::Mutex;
+
+    use blinc_core::reactive::ReactiveGraph;
+    use blinc_layout::renderer::RenderTree;
+
+    use super::*;
+
+    fn init_theme() {
+        let _ = ThemeState::try_get().unwrap_or_else(|| {
+            ThemeState::init_default();
+            ThemeState::get()
+        });
+    }
+
+    fn value_state(initial: f64) -> State<f64> {
+        let mut graph = ReactiveGraph::new();
+        let signal = graph.create_signal(initial);
+        State::new(
+            signal,
+            Arc::new(Mutex::new(graph)),
+            Arc::new(AtomicBool::new(false)),
+        )
+    }
+
+    /// Press the button root, tick PRESS_HOLD every frame for `hold_ms`, release
+    fn hold(tree: &mut RenderTree, hold_ms: u64) {
+        let node = tree.root().unwrap();
+        tree.dispatch_event(node, event_types::POINTER_DOWN, 10.0, 10.0);
+        for t in (0..=hold_ms).step_by(16) {
+            tree.dispatch_press_hold(node, t, 10.0, 10.0);
+        }
+        tree.dispatch_event(node, event_types::POINTER_UP, 10.0, 10.0);
+    }
+
+    #[test]
+    fn test_step_decimals() {
+        assert_eq!(step_decimals(1.0), 0);
+        assert_eq!(step_decimals(0.5), 1);
+        assert_eq!(step_decimals(0.25), 2);
+    }
+
+    #[test]
+    fn test_increment_button_adds_one_step() {
+        init_theme();
+        let value = value_state(1.0);
+        let mut config = StepperConfig::new(value.clone());
+        config.constraints = NumberConstraints::range(0.0, 2.0).step(0.5);
+
+        let mut tree = RenderTree::from_element(&step_button(&config, 1, PLUS_SVG));
+        tree.compute_layout(100.0, 100.0);
+
+        hold(&mut tree, 0);
+        assert_eq!(value.get(), 1.5);
+
+        // Holding repeats, but never past the max
+        hold(&mut tree, 1000);
+        assert_eq!(value.get(), 2.0);
+    }
+}
@@ -0,0 +1,340 @@
+//! Timeline scrubber for animation playback controls
+//!
+//! A play/pause button next to a track with a draggable playhead, bound to
+//! an `AnimatedTimeline`. The track shows the timeline's progress through
+//! the current loop; clicking or dragging on it seeks.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use blinc_cn::prelude::*;
+//! use blinc_animation::AnimationContextExt;
+//!
+//! fn intro_editor(ctx: &impl AnimationContext) -> impl ElementBuilder {
+//!     let timeline = ctx.use_animated_timeline();
+//!     timeline.lock().unwrap().configure(|t| {
+//!         t.add(0, 800, 0.0, 1.0);
+//!         t.add(400, 1600, 0.0, 240.0);
+//!         t.set_loop(-1);
+//!     });
+//!
+//!     cn::timeline_scrubber(timeline).w(320.0)
+//! }
+//! ```
+//!
+//! Scrubbing a looping timeline positions the playhead within one loop.
+//! Playback pauses while the playhead is held and resumes on release.
+
+use std::cell::{Cell, OnceCell};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use blinc_animation::SharedAnimatedTimeline;
+use blinc_core::{Brush, Color, CornerRadius, DrawContext, Path, Point, Rect};
+use blinc_layout::canvas::canvas_animated;
+use blinc_layout::div::ElementTypeId;
+use blinc_layout::element::RenderProps;
+use blinc_layout::prelude::*;
+use blinc_layout::tree::{LayoutNodeId, LayoutTree};
+use blinc_theme::{ColorToken, ThemeState};
+
+/// Height of the scrubber row (and the play button's size)
+const ROW_HEIGHT: f32 = 24.0;
+/// Height of the track bar
+const TRACK_HEIGHT: f32 = 4.0;
+/// Radius of the playhead knob
+const PLAYHEAD_RADIUS: f32 = 6.0;
+
+/// Progress (0.0 to 1.0) for a pointer at `local_x` on a track `width` wide
+///
+/// The playhead is inset by its radius at both ends so it never clips, so
+/// the usable span is `width - 2 * inset`.
+fn scrub_progress(local_x: f32, width: f32, inset: f32) -> f32 {
+    let span = width - inset * 2.0;
+    if span <= 0.0 {
+        return 0.0;
+    }
+    ((local_x - inset) / span).clamp(0.0, 1.0)
+}
+
+#[derive(Clone)]
+struct TimelineScrubberConfig {
+    timeline: SharedAnimatedTimeline,
+    width: Option<f32>,
+    track_color: Option<Color>,
+    fill_color: Option<Color>,
+}
+
+impl TimelineScrubberConfig {
+    fn new(timeline: SharedAnimatedTimeline) -> Self {
+        Self {
+            timeline,
+            width: None,
+            track_color: None,
+            fill_color: None,
+        }
+    }
+}
+
+/// Play/pause button and draggable playhead for an animation timeline
+pub struct TimelineScrubber {
+    inner: Div,
+}
+
+impl TimelineScrubber {
+    fn from_config(config: TimelineScrubberConfig) -> Self {
+        let theme = ThemeState::get();
+        let track_color = config
+            .track_color
+            .unwrap_or_else(|| theme.color(ColorToken::Secondary));
+        let fill_color = config
+            .fill_color
+            .unwrap_or_else(|| theme.color(ColorToken::Primary));
+        let icon_color = theme.color(ColorToken::TextPrimary);
+        let knob_color = theme.color(ColorToken::Background);
+
+        let play_button = {
+            let toggle_timeline = Arc::clone(&config.timeline);
+            let icon_timeline = Arc::clone(&config.timeline);
+            let icon = canvas_animated(move |ctx: &mut dyn DrawContext, bounds, frame| {
+                let playing = icon_timeline.lock().unwrap().is_playing();
+                let (cx, cy) = (bounds.width / 2.0, bounds.height / 2.0);
+                if playing {
+                    for x in [cx - 4.0, cx + 1.0] {
+                        ctx.fill_rect(
+                            Rect::new(x, cy - 5.0, 3.0, 10.0),
+                            CornerRadius::uniform(1.0),
+                            Brush::Solid(icon_color),
+                        );
+                    }
+                    // Keep redrawing so the icon flips back when playback ends
+                    frame.request_animation();
+                } else {
+                    let triangle = Path::new()
+                        .move_to(cx - 3.5, cy - 5.5)
+                        .line_to(cx + 5.5, cy)
+                        .line_to(cx - 3.5, cy + 5.5)
+                        .close();
+                    ctx.fill_path(&triangle, Brush::Solid(icon_color));
+                }
+            })
+            .w(ROW_HEIGHT)
+            .h(ROW_HEIGHT);
+
+            div()
+                .w(ROW_HEIGHT)
+                .h(ROW_HEIGHT)
+                .flex_shrink_0()
+                .rounded(ROW_HEIGHT / 2.0)
+                .bg(track_color)
+                .cursor_pointer()
+                .on_click(move |_| {
+                    let timeline = toggle_timeline.lock().unwrap();
+                    timeline.set_playing(!timeline.is_playing());
+                })
+                .child(icon)
+        };
+
+        let track_timeline = Arc::clone(&config.timeline);
+        let track = canvas_animated(move |ctx: &mut dyn DrawContext, bounds, frame| {
+            let (progress, playing) = {
+                let timeline = track_timeline.lock().unwrap();
+                (timeline.progress().clamp(0.0, 1.0), timeline.is_playing())
+            };
+            let span = (bounds.width - PLAYHEAD_RADIUS * 2.0).max(0.0);
+            let cy = bounds.height / 2.0;
+            let bar_y = cy - TRACK_HEIGHT / 2.0;
+            let radius = CornerRadius::uniform(TRACK_HEIGHT / 2.0);
+
+            ctx.fill_rect(
+                Rect::new(PLAYHEAD_RADIUS, bar_y, span, TRACK_HEIGHT),
+                radius,
+                Brush::Solid(track_color),
+            );
+            ctx.fill_rect(
+                Rect::new(PLAYHEAD_RADIUS, bar_y, span * progress, TRACK_HEIGHT),
+                radius,
+                Brush::Solid(fill_color),
+            );
+            let head = Point::new(PLAYHEAD_RADIUS + span * progress, cy);
+            ctx.fill_circle(head, PLAYHEAD_RADIUS, Brush::Solid(fill_color));
+            ctx.fill_circle(head, PLAYHEAD_RADIUS - 2.0, Brush::Solid(knob_color));
+
+            if playing {
+                frame.request_animation();
+            }
+        })
+        .w_full()
+        .h(ROW_HEIGHT);
+
+        // Pause while the playhead is held, and remember whether to resume
+        let resume = Rc::new(Cell::new(false));
+        let seek = {
+            let timeline = Arc::clone(&config.timeline);
+            move |event: &EventContext| {
+                let progress = scrub_progress(event.local_x, event.bounds_width, PLAYHEAD_RADIUS);
+                timeline.lock().unwrap().set_progress(progress);
+            }
+        };
+        let release = {
+            let timeline = Arc::clone(&config.timeline);
+            let resume = Rc::clone(&resume);
+            move |_: &EventContext| {
+                if resume.take() {
+                    timeline.lock().unwrap().set_playing(true);
+                }
+            }
+        };
+        let press = {
+            let timeline = Arc::clone(&config.timeline);
+            let resume = Rc::clone(&resume);
+            let seek = seek.clone();
+            move |event: &EventContext| {
+                // Keep seeking while the pointer is dragged past the track
+                event.capture_pointer();
+                {
+                    let timeline = timeline.lock().unwrap();
+                    resume.set(timeline.is_playing());
+                    timeline.set_playing(false);
+                }
+                seek(event);
+            }
+        };
+
+        let track_area = div()
+            .flex_1()
+            .h(ROW_HEIGHT)
+            .cursor_pointer()
+            .on_mouse_down(press)
+            .on_drag(seek)
+            .on_mouse_up(release.clone())
+            .on_drag_end(release)
+            .child(track);
+
+        let mut inner = div()
+            .flex_row()
+            .items_center()
+            .gap_px(8.0)
+            .h(ROW_HEIGHT)
+            .child(play_button)
+            .child(track_area);
+        inner = match config.width {
+            Some(width) => inner.w(width),
+            None => inner.w_full(),
+        };
+
+        Self { inner }
+    }
+}
+
+impl ElementBuilder for TimelineScrubber {
+    fn build(&self, tree: &mut LayoutTree) -> LayoutNodeId {
+        self.inner.build(tree)
+    }
+
+    fn render_props(&self) -> RenderProps {
+        self.inner.render_props()
+    }
+
+    fn children_builders(&self) -> &[Box<dyn ElementBuilder>] {
+        self.inner.children_builders()
+    }
+
+    fn element_type_id(&self) -> ElementTypeId {
+        self.inner.element_type_id()
+    }
+
+    fn layout_style(&self) -> Option<&taffy::Style> {
+        self.inner.layout_style()
+    }
+}
+
+/// Builder for a timeline scrubber
+pub struct TimelineScrubberBuilder {
+    config: TimelineScrubberConfig,
+    built: OnceCell<TimelineScrubber>,
+}
+
+impl TimelineScrubberBuilder {
+    /// Create a scrubber for a timeline
+    pub fn new(timeline: SharedAnimatedTimeline) -> Self {
+        Self {
+            config: TimelineScrubberConfig::new(timeline),
+            built: OnceCell::new(),
+        }
+    }
+
+    fn get_or_build(&self) -> &TimelineScrubber {
+        self.built
+            .get_or_init(|| TimelineScrubber::from_config(self.config.clone()))
+    }
+
+    /// Set the width in pixels (default: fill the parent)
+    pub fn w(mut self, width: f32) -> Self {
+        self.config.width = Some(width);
+        self
+    }
+
+    /// Set the track (background) color
+    pub fn track_color(mut self, color: impl Into<Color>) -> Self {
+        self.config.track_color = Some(color.into());
+        self
+    }
+
+    /// Set the color of the played portion and the playhead
+    pub fn fill_color(mut self, color: impl Into<Color>) -> Self {
+        self.config.fill_color = Some(color.into());
+        self
+    }
+}
+
+impl ElementBuilder for TimelineScrubberBuilder {
+    fn build(&self, tree: &mut LayoutTree) -> LayoutNodeId {
+        self.get_or_build().build(tree)
+    }
+
+    fn render_props(&self) -> RenderProps {
+        self.get_or_build().render_props()
+    }
+
+    fn children_builders(&self) -> &[Box<dyn ElementBuilder>] {
+        self.get_or_build().children_builders()
+    }
+
+    fn element_type_id(&self) -> ElementTypeId {
+        self.get_or_build().element_type_id()
+    }
+
+    fn layout_style(&self) -> Option<&taffy::Style> {
+        self.get_or_build().layout_style()
+    }
+}
+
+/// Create play/pause and scrub controls for an animation timeline
+///
+/// # Example
+///
+/// ```ignore
+/// let timeline = ctx.use_animated_timeline();
+/// cn::timeline_scrubber(timeline.clone()).w(240.0)
+/// ```
+pub fn timeline_scrubber(timeline: SharedAnimatedTimeline) -> TimelineScrubberBuilder {
+    TimelineScrubberBuilder::new(timeline)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scrub_progress_maps_inset_span() {
+        // 212px track with a 6px inset leaves a 200px span
+        assert_eq!(scrub_progress(6.0, 212.0, 6.0), 0.0);
+        assert_eq!(scrub_progress(106.0, 212.0, 6.0), 0.5);
+        assert_eq!(scrub_progress(206.0, 212.0, 6.0), 1.0);
+        // Outside the span clamps
+        assert_eq!(scrub_progress(-20.0, 212.0, 6.0), 0.0);
+        assert_eq!(scrub_progress(400.0, 212.0, 6.0), 1.0);
+        assert_eq!(scrub_progress(5.0, 8.0, 6.0), 0.0);
+    }
+}
@@ -0,0 +1,581 @@
+//! Wizard component for multi-step flows
+//!
+//! A row of numbered step markers above the current step's content, with
+//! Back/Next buttons underneath. The current step index lives in a
+//! `State<usize>`. Markers show each step as completed, current or upcoming.
+//!
+//! Steps can carry a validator. Next (and jumping ahead through the markers)
+//! only moves forward while every step being left behind validates, and never
+//! past a step that hasn't been reached yet. Going back is always allowed.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use blinc_cn::prelude::*;
+//!
+//! fn build_ui(ctx: &WindowedContext) -> impl ElementBuilder {
+//!     let step = ctx.use_state_keyed("signup_step", || 0usize);
+//!     let email = ctx.use_state_keyed("email", String::new);
+//!     let email_check = email.clone();
+//!
+//!     cn::wizard(&step, [
+//!         cn::wizard_step("Account", move || account_form(&email))
+//!             .description("Email and password")
+//!             .validate(move || email_check.get().contains('@')),
+//!         cn::wizard_step("Profile", profile_form),
+//!         cn::wizard_step("Done", summary),
+//!     ])
+//!     .on_step_change(|from, to| println!("Step {} -> {}", from, to))
+//!     .on_finish(|| println!("Signed up"))
+//! }
+//! ```
+
+use std::cell::OnceCell;
+use std::sync::Arc;
+
+use blinc_core::State;
+use blinc_layout::div::ElementTypeId;
+use blinc_layout::element::{CursorStyle, RenderProps};
+use blinc_layout::prelude::*;
+use blinc_layout::stateful::{stateful_with_key, NoState};
+use blinc_layout::tree::{LayoutNodeId, LayoutTree};
+use blinc_layout::InstanceKey;
+use blinc_theme::{ColorToken, ThemeState};
+
+use super::button::{button, ButtonVariant};
+
+/// Checkmark shown in completed step markers
+const CHECK_SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="3" stroke-linecap="round" stroke-linejoin="round"><path d="M20 6 9 17l-5-5"/></svg>"#;
+
+/// Diameter of a step marker
+const MARKER_SIZE: f32 = 28.0;
+
+/// Store holding the furthest step each wizard has reached
+const PROGRESS_STORE: &str = "wizard_progress";
+
+/// How a step is shown in the indicator
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WizardStepStatus {
+    /// Already passed
+    Completed,
+    /// The step being shown
+    Current,
+    /// Not yet passed
+    Upcoming,
+}
+
+/// A single wizard step
+#[derive(Clone)]
+pub struct WizardStep {
+    label: String,
+    description: Option<String>,
+    content: Arc<dyn Fn() -> Div + Send + Sync>,
+    validate: Option<Arc<dyn Fn() -> bool + Send + Sync>>,
+}
+
+impl WizardStep {
+    /// Create a step with a label and a content builder
+    pub fn new<F>(label: impl Into<String>, content: F) -> Self
+    where
+        F: Fn() -> Div + Send + Sync + 'static,
+    {
+        Self {
+            label: label.into(),
+            description: None,
+            content: Arc::new(content),
+            validate: None,
+        }
+    }
+
+    /// Set a short description shown under the label
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Only allow moving past this step while `validate` returns true
+    pub fn validate<F>(mut self, validate: F) -> Self
+    where
+        F: Fn() -> bool + Send + Sync + 'static,
+    {
+        self.validate = Some(Arc::new(validate));
+        self
+    }
+
+    fn is_valid(&self) -> bool {
+        self.validate.as_ref().map_or(true, |validate| validate())
+    }
+}
+
+/// Create a wizard step
+pub fn wizard_step<F>(label: impl Into<String>, content: F) -> WizardStep
+where
+    F: Fn() -> Div + Send + Sync + 'static,
+{
+    WizardStep::new(label, content)
+}
+
+/// Internal configuration for building a Wizard
+#[derive(Clone)]
+struct WizardConfig {
+    current: State<usize>,
+    steps: Vec<WizardStep>,
+    on_step_change: Option<Arc<dyn Fn(usize, usize) + Send + Sync>>,
+    on_finish: Option<Arc<dyn Fn() + Send + Sync>>,
+}
+
+impl WizardConfig {
+    /// Current step index, clamped to the steps
+    fn current(&self) -> usize {
+        self.current.get().min(self.steps.len().saturating_sub(1))
+    }
+
+    /// Furthest step this wizard has reached
+    fn furthest(&self, key: &str) -> usize {
+        blinc_core::create_store::<usize>(PROGRESS_STORE)
+            .get(key)
+            .max(self.current())
+    }
+
+    fn status(&self, key: &str, index: usize) -> WizardStepStatus {
+        let current = self.current();
+        if index == current {
+            WizardStepStatus::Current
+        } else if index < current || index < self.furthest(key) {
+            WizardStepStatus::Completed
+        } else {
+            WizardStepStatus::Upcoming
+        }
+    }
+
+    /// Whether `target` can be reached from the current step
+    ///
+    /// Backward moves are always allowed. Forward moves need every step
+    /// being passed to validate, and can't skip ahead of the furthest step
+    /// reached so far.
+    fn can_go_to(&self, key: &str, target: usize) -> bool {
+        let current = self.current();
+        if target >= self.steps.len() {
+            return false;
+        }
+        if target <= current {
+            return true;
+        }
+        target <= self.furthest(key).max(current + 1)
+            && self.steps[current..target].iter().all(WizardStep::is_valid)
+    }
+
+    /// Move to `target` if allowed, returning whether the step changed
+    fn go_to(&self, key: &str, target: usize) -> bool {
+        let current = self.current();
+        if target == current || !self.can_go_to(key, target) {
+            return false;
+        }
+        blinc_core::create_store::<usize>(PROGRESS_STORE)
+            .update(key, |furthest| *furthest = (*furthest).max(target));
+        self.current.set(target);
+        if let Some(ref callback) = self.on_step_change {
+            callback(current, target);
+        }
+        true
+    }
+
+    /// Advance one step, or finish on the last step
+    fn next(&self, key: &str) {
+        let current = self.current();
+        if current + 1 < self.steps.len() {
+            self.go_to(key, current + 1);
+        } else if self.steps.get(current).is_some_and(WizardStep::is_valid) {
+            if let Some(ref callback) = self.on_finish {
+                callback();
+            }
+        }
+    }
+}
+
+/// Wizard component
+pub struct Wizard {
+    inner: Div,
+}
+
+impl Wizard {
+    fn with_config(key: &str, config: WizardConfig) -> Self {
+        let theme = ThemeState::get();
+        let spacing = theme.spacing();
+        let deps = [config.current.signal_id()];
+
+        let indicator_config = config.clone();
+        let indicator_key = key.to_string();
+        let indicator = stateful_with_key::<NoState>(format!("{}_indicator", key))
+            .deps(deps)
+            .on_state(move |_ctx| step_indicator(&indicator_key, &indicator_config));
+
+        let content_config = config.clone();
+        let content = stateful_with_key::<NoState>(format!("{}_content", key))
+            .deps(deps)
+            .on_state(move |_ctx| {
+                let step = &content_config.steps[content_config.current()];
+                div().w_full().child((step.content)())
+            });
+
+        let footer_config = config.clone();
+        let footer_key = key.to_string();
+        let footer = stateful_with_key::<NoState>(format!("{}_footer", key))
+            .deps(deps)
+            .on_state(move |_ctx| footer(&footer_key, &footer_config));
+
+        let mut wizard = div().flex_col().w_full().gap_px(spacing.space_6);
+        if !config.steps.is_empty() {
+            wizard = wizard.child(indicator).child(content).child(footer);
+        }
+
+        Self { inner: wizard }
+    }
+}
+
+/// Row of step markers joined by connector lines
+fn step_indicator(key: &str, config: &WizardConfig) -> Div {
+    let theme = ThemeState::get();
+    let spacing = theme.spacing();
+    let border = theme.color(ColorToken::Border);
+    let primary = theme.color(ColorToken::Primary);
+
+    let mut row = div()
+        .flex_row()
+        .items_center()
+        .w_full()
+        .gap_px(spacing.space_3);
+    for (index, step) in config.steps.iter().enumerate() {
+        let status = config.status(key, index);
+        if index > 0 {
+            let line = if status == WizardStepStatus::Upcoming {
+                border
+            } else {
+                primary
+            };
+            row = row.child(div().flex_1().h(2.0).bg(line));
+        }
+        row = row.child(step_marker(key, config, index, step, status));
+    }
+    row
+}
+
+/// Numbered marker and label for one step; reachable steps jump on click
+fn step_marker(
+    key: &str,
+    config: &WizardConfig,
+    index: usize,
+    step: &WizardStep,
+    status: WizardStepStatus,
+) -> Div {
+    let theme = ThemeState::get();
+    let spacing = theme.spacing();
+    let primary = theme.color(ColorToken::Primary);
+    let on_primary = theme.color(ColorToken::TextInverse);
+    let border = theme.color(ColorToken::Border);
+    let text_primary = theme.color(ColorToken::TextPrimary);
+    let text_secondary = theme.color(ColorToken::TextSecondary);
+    let text_tertiary = theme.color(ColorToken::TextTertiary);
+
+    let circle = div()
+        .w(MARKER_SIZE)
+        .h(MARKER_SIZE)
+        .rounded_full()
+        .items_center()
+        .justify_center();
+    let circle = match status {
+        WizardStepStatus::Completed => circle
+            .bg(primary)
+            .child(svg(CHECK_SVG).size(14.0, 14.0).color(on_primary)),
+        WizardStepStatus::Current => circle.bg(primary).child(
+            text(format!("{}", index + 1))
+                .size(13.0)
+                .medium()
+                .color(on_primary),
+        ),
+        WizardStepStatus::Upcoming => circle.border(2.0, border).child(
+            text(format!("{}", index + 1))
+                .size(13.0)
+                .medium()
+                .color(text_tertiary),
+        ),
+    };
+
+    let label_color = match status {
+        WizardStepStatus::Upcoming => text_secondary,
+        _ => text_primary,
+    };
+    let mut labels = div()
+        .flex_col()
+        .child(text(&step.label).size(14.0).medium().color(label_color));
+    if let Some(ref description) = step.description {
+        labels = labels.child(text(description).size(12.0).color(text_secondary));
+    }
+
+    let marker = div()
+        .flex_row()
+        .items_center()
+        .gap_px(spacing.space_2)
+        .child(circle)
+        .child(labels);
+
+    if status == WizardStepStatus::Current || !config.can_go_to(key, index) {
+        return marker;
+    }
+
+    let config = config.clone();
+    let key = key.to_string();
+    marker.cursor(CursorStyle::Pointer).on_click(move |_| {
+        config.go_to(&key, index);
+    })
+}
+
+/// Back and Next/Finish buttons
+fn footer(key: &str, config: &WizardConfig) -> Div {
+    let current = config.current();
+    let last = current + 1 == config.steps.len();
+
+    let back_config = config.clone();
+    let back_key = key.to_string();
+    let back = button("Back")
+        .variant(ButtonVariant::Outline)
+        .disabled(current == 0)
+        .on_click(move |_| {
+            let current = back_config.current();
+            if current > 0 {
+                back_config.go_to(&back_key, current - 1);
+            }
+        });
+
+    let next_config = config.clone();
+    let next_key = key.to_string();
+    let next =
+        button(if last { "Finish" } else { "Next" }).on_click(move |_| next_config.next(&next_key));
+
+    div()
+        .flex_row()
+        .justify_between()
+        .w_full()
+        .child(back)
+        .child(next)
+}
+
+impl ElementBuilder for Wizard {
+    fn build(&self, tree: &mut LayoutTree) -> LayoutNodeId {
+        self.inner.build(tree)
+    }
+
+    fn render_props(&self) -> RenderProps {
+        self.inner.render_props()
+    }
+
+    fn children_builders(&self) -> &[Box<dyn ElementBuilder>] {
+        self.inner.children_builders()
+    }
+
+    fn element_type_id(&self) -> ElementTypeId {
+        self.inner.element_type_id()
+    }
+
+    fn layout_style(&self) -> Option<&taffy::Style> {
+        self.inner.layout_style()
+    }
+}
+
+/// Builder for creating Wizard components with fluent API
+pub struct WizardBuilder {
+    key: InstanceKey,
+    config: WizardConfig,
+    /// Cached built Wizard - built lazily on first access
+    built: OnceCell<Wizard>,
+}
+
+impl WizardBuilder {
+    /// Create a new wizard builder bound to the current step index
+    #[track_caller]
+    pub fn new(current: &State<usize>, steps: impl IntoIterator<Item = WizardStep>) -> Self {
+        Self {
+            key: InstanceKey::new("wizard"),
+            config: WizardConfig {
+                current: current.clone(),
+                steps: steps.into_iter().collect(),
+                on_step_change: None,
+                on_finish: None,
+            },
+            built: OnceCell::new(),
+        }
+    }
+
+    /// Create a wizard builder with an explicit key
+    pub fn with_key(
+        key: impl Into<String>,
+        current: &State<usize>,
+        steps: impl IntoIterator<Item = WizardStep>,
+    ) -> Self {
+        Self {
+            key: InstanceKey::explicit(key),
+            ..Self::new(current, steps)
+        }
+    }
+
+    /// Get or build the inner Wizard
+    fn get_or_build(&self) -> &Wizard {
+        self.built
+            .get_or_init(|| Wizard::with_config(self.key.get(), self.config.clone()))
+    }
+
+    /// Add a step after the existing ones
+    pub fn step(mut self, step: WizardStep) -> Self {
+        self.config.steps.push(step);
+        self
+    }
+
+    /// Set the callback for step changes, called with `(from, to)`
+    pub fn on_step_change<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(usize, usize) + Send + Sync + 'static,
+    {
+        self.config.on_step_change = Some(Arc::new(callback));
+        self
+    }
+
+    /// Set the callback for Finish on the last step, called once it validates
+    pub fn on_finish<F>(mut self, callback: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.config.on_finish = Some(Arc::new(callback));
+        self
+    }
+}
+
+impl ElementBuilder for WizardBuilder {
+    fn build(&self, tree: &mut LayoutTree) -> LayoutNodeId {
+        self.get_or_build().build(tree)
+    }
+
+    fn render_props(&self) -> RenderProps {
+        self.get_or_build().render_props()
+    }
+
+    fn children_builders(&self) -> &[Box<dyn ElementBuilder>] {
+        self.get_or_build().children_builders()
+    }
+
+    fn element_type_id(&self) -> ElementTypeId {
+        self.get_or_build().element_type_id()
+    }
+
+    fn layout_style(&self) -> Option<&taffy::Style> {
+        self.get_or_build().layout_style()
+    }
+}
+
+/// Create a wizard over `steps`, bound to the current step index
+///
+/// # Example
+///
+/// ```ignore
+/// let step = ctx.use_state_keyed("checkout_step", || 0usize);
+/// cn::wizard(&step, [
+///     cn::wizard_step("Cart", cart),
+///     cn::wizard_step("Payment", payment).validate(card_is_valid),
+/// ])
+/// ```
+#[track_caller]
+pub fn wizard(
+    current: &State<usize>,
+    steps: impl IntoIterator<Item = WizardStep>,
+) -> WizardBuilder {
+    WizardBuilder::new(current, steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Mutex;
+
+    use blinc_core::reactive::ReactiveGraph;
+
+    use super::*;
+
+    fn step_state(initial: usize) -> State<usize> {
+        let mut graph = ReactiveGraph::new();
+        let signal = graph.create_signal(initial);
+        State::new(
+            signal,
+            Arc::new(Mutex::new(graph)),
+            Arc::new(AtomicBool::new(false)),
+        )
+    }
+
+    fn config(current: &State<usize>, steps: Vec<WizardStep>) -> WizardConfig {
+        WizardBuilder::new(current, steps).config
+    }
+
+    #[test]
+    fn test_next_is_blocked_by_failing_validator() {
+        let step = step_state(0);
+        let valid = Arc::new(AtomicBool::new(false));
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let finished = Arc::new(AtomicBool::new(false));
+
+        let valid_check = Arc::clone(&valid);
+        let changes_log = Arc::clone(&changes);
+        let finished_flag = Arc::clone(&finished);
+        let wizard = WizardBuilder::new(
+            &step,
+            [
+                wizard_step("Account", div).validate(move || valid_check.load(Ordering::SeqCst)),
+                wizard_step("Done", div).validate(|| false),
+            ],
+        )
+        .on_step_change(move |from, to| changes_log.lock().unwrap().push((from, to)))
+        .on_finish(move || finished_flag.store(true, Ordering::SeqCst));
+        let key = "test_wizard_blocked";
+
+        // Invalid: Next keeps the step
+        wizard.config.next(key);
+        assert_eq!(step.get(), 0);
+        assert!(changes.lock().unwrap().is_empty());
+
+        valid.store(true, Ordering::SeqCst);
+        wizard.config.next(key);
+        assert_eq!(step.get(), 1);
+        assert_eq!(*changes.lock().unwrap(), vec![(0, 1)]);
+
+        // Finish waits for the last step to validate too
+        wizard.config.next(key);
+        assert!(!finished.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_jumps_back_but_not_past_unreached_steps() {
+        let step = step_state(0);
+        let config = config(
+            &step,
+            vec![
+                wizard_step("One", div),
+                wizard_step("Two", div),
+                wizard_step("Three", div),
+                wizard_step("Four", div),
+            ],
+        );
+        let key = "test_wizard_jumps";
+
+        // Step 2 hasn't been reached, so it can't be skipped to
+        assert!(!config.go_to(key, 2));
+        assert!(config.go_to(key, 1));
+        assert!(config.go_to(key, 2));
+        assert_eq!(config.status(key, 1), WizardStepStatus::Completed);
+
+        // Back to a completed step, then forward again up to the furthest
+        // reached, but not beyond it
+        assert!(config.go_to(key, 0));
+        assert_eq!(config.status(key, 1), WizardStepStatus::Completed);
+        assert!(!config.go_to(key, 3));
+        assert!(config.go_to(key, 2));
+        assert_eq!(step.get(), 2);
+        assert_eq!(config.status(key, 3), WizardStepStatus::Upcoming);
+    }
+}
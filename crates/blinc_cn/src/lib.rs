@@ -53,6 +53,7 @@ pub mod cn {
     pub use crate::components::badge::badge;
     pub use crate::components::breadcrumb::breadcrumb;
     pub use crate::components::button::button;
+    pub use crate::components::calendar::calendar;
     pub use crate::components::card::{card, card_content, card_footer, card_header};
     pub use crate::components::chart::{
         bar_chart, comparison_bar_chart, histogram, line_chart, spark_line, threshold_line_chart,
@@ -61,9 +62,11 @@ pub mod cn {
     pub use crate::components::collapsible::{collapsible, collapsible_section};
     pub use crate::components::combobox::combobox;
     pub use crate::components::context_menu::context_menu;
+    pub use crate::components::data_table::data_table;
     pub use crate::components::dialog::{alert_dialog, dialog};
     pub use crate::components::drawer::{drawer, drawer_left, drawer_right};
     pub use crate::components::dropdown_menu::{dropdown_menu, dropdown_menu_custom};
+    pub use crate::components::floating_window::{floating_window, floating_window_area};
     pub use crate::components::hover_card::hover_card;
     pub use crate::components::icon::{icon, IconSize};
     pub use crate::components::input::input;
@@ -83,14 +86,17 @@ pub mod cn {
     pub use crate::components::skeleton::{skeleton, skeleton_circle};
     pub use crate::components::slider::slider;
     pub use crate::components::spinner::spinner;
+    pub use crate::components::stepper::stepper;
     pub use crate::components::switch::switch;
     pub use crate::components::tabs::{tab_item, tabs, TabsSize, TabsTransition};
     pub use crate::components::textarea::textarea;
+    pub use crate::components::timeline_scrubber::timeline_scrubber;
     pub use crate::components::toast::{
         toast, toast_custom, toast_error, toast_success, toast_warning,
     };
     pub use crate::components::tooltip::tooltip;
     pub use crate::components::tree::tree_view;
+    pub use crate::components::wizard::{wizard, wizard_step};
     // Typography helpers (label excluded - use cn::label component instead)
     pub use crate::components::typography::{
         b, caption, chained_text, h1, h2, h3, h4, h5, h6, heading, inline_code, muted, p, small,
@@ -123,6 +129,10 @@ pub mod prelude {
     pub use crate::components::button::{
         button, Button, ButtonBuilder, ButtonSize, ButtonVariant, IconPosition,
     };
+    pub use crate::components::calendar::{
+        calendar, Calendar, CalendarBuilder, CalendarDate, CalendarEvent, CalendarState,
+        CalendarView,
+    };
     // Re-export ButtonState for use with buttons
     pub use crate::components::card::{
         card, card_content, card_footer, card_header, Card, CardContent, CardFooter, CardHeader,
@@ -133,13 +143,16 @@ pub mod prelude {
         DataPoint, DataSeries, Histogram, HistogramBuilder, LineChart, LineChartBuilder, SparkLine,
         SparkLineBuilder, ThresholdBand, ThresholdLineChart, ThresholdLineChartBuilder,
     };
-    pub use crate::components::checkbox::{checkbox, Checkbox, CheckboxSize};
+    pub use crate::components::checkbox::{checkbox, CheckState, Checkbox, CheckboxSize};
     pub use crate::components::collapsible::{
         collapsible, collapsible_section, Collapsible, CollapsibleBuilder, CollapsibleTrigger,
     };
     pub use crate::components::context_menu::{
         context_menu, ContextMenuBuilder, ContextMenuItem, SubmenuBuilder,
     };
+    pub use crate::components::data_table::{
+        data_table, ColumnAlign, DataTable, DataTableBuilder, DataTableColumn, DataTableRow, RowId,
+    };
     pub use crate::components::dialog::{
         alert_dialog, dialog, AlertDialogBuilder, DialogBuilder, DialogSize,
     };
@@ -149,6 +162,10 @@ pub mod prelude {
     pub use crate::components::dropdown_menu::{
         dropdown_menu, dropdown_menu_custom, DropdownAlign, DropdownMenuBuilder, DropdownPosition,
     };
+    pub use crate::components::floating_window::{
+        floating_window, floating_window_area, FloatingWindowArea, FloatingWindowAreaBuilder,
+        FloatingWindowBuilder, ResizeEdge, WindowFrame,
+    };
     pub use crate::components::hover_card::{
         hover_card, HoverCard, HoverCardAlign, HoverCardBuilder, HoverCardSide,
     };
@@ -191,11 +208,15 @@ pub mod prelude {
     pub use crate::components::skeleton::{skeleton, skeleton_circle, Skeleton};
     pub use crate::components::slider::{slider, Slider, SliderBuilder, SliderSize};
     pub use crate::components::spinner::{spinner, Spinner, SpinnerSize};
+    pub use crate::components::stepper::{stepper, Stepper, StepperBuilder, StepperSize};
     pub use crate::components::switch::{switch, Switch, SwitchSize};
     pub use crate::components::tabs::{
         tab_item, tabs, TabMenuItem, Tabs, TabsBuilder, TabsSize, TabsTransition,
     };
     pub use crate::components::textarea::{textarea, Textarea, TextareaSize};
+    pub use crate::components::timeline_scrubber::{
+        timeline_scrubber, TimelineScrubber, TimelineScrubberBuilder,
+    };
     pub use crate::components::toast::{
         toast, toast_custom, toast_error, toast_success, toast_warning, ToastBuilder, ToastVariant,
     };
@@ -205,6 +226,9 @@ pub mod prelude {
     pub use crate::components::tree::{
         tree_view, TreeNodeConfig, TreeNodeDiff, TreeView, TreeViewBuilder,
     };
+    pub use crate::components::wizard::{
+        wizard, wizard_step, Wizard, WizardBuilder, WizardStep, WizardStepStatus,
+    };
     // Typography helpers (label excluded - use Label component instead)
     pub use crate::components::typography::{
         b, caption, chained_text, h1, h2, h3, h4, h5, h6, heading, inline_code, muted, p, small,
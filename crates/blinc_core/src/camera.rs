@@ -0,0 +1,474 @@
+//! Input-driven camera controllers
+//!
+//! Controllers turn pointer, scroll and key [`Event`]s into camera motion and
+//! write the result into a [`Camera`] once per frame:
+//!
+//! ```ignore
+//! let mut orbit = OrbitController::from_camera(&camera);
+//!
+//! // For each input event
+//! orbit.handle_event(&event);
+//!
+//! // Each frame
+//! orbit.update(dt, &mut camera);
+//! ```
+//!
+//! - [`OrbitController`]: primary drag rotates around a target, scroll zooms,
+//!   any other button drags to pan
+//! - [`FlyController`]: WASD moves, drag to look around
+//!
+//! Both accept a `damping` factor in `0.0..1.0`. At `0.0` input is applied on
+//! the next update; higher values spread it over several frames for a
+//! smoother, eased motion.
+
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use crate::events::{event_types, Event, EventData, KeyCode};
+use crate::layer::{Camera, Vec3};
+
+/// Pointer button that rotates the orbit camera; other buttons pan
+const PRIMARY_BUTTON: u8 = 0;
+
+/// Closest the camera may get to straight up or down, in radians
+///
+/// At the poles the view direction is parallel to the up vector and the
+/// camera's orientation flips.
+const POLE_MARGIN: f32 = 0.001;
+
+/// Split `pending` motion into the part applied this frame and the remainder
+fn damp(pending: f32, damping: f32, dt: f32) -> (f32, f32) {
+    // Expressed per 60Hz frame so the feel doesn't depend on frame rate
+    let retain = damping.clamp(0.0, 0.99).powf(dt * 60.0);
+    let applied = pending * (1.0 - retain);
+    (applied, pending - applied)
+}
+
+fn add(a: Vec3, b: Vec3) -> Vec3 {
+    Vec3::new(a.x + b.x, a.y + b.y, a.z + b.z)
+}
+
+fn sub(a: Vec3, b: Vec3) -> Vec3 {
+    Vec3::new(a.x - b.x, a.y - b.y, a.z - b.z)
+}
+
+fn scale(v: Vec3, s: f32) -> Vec3 {
+    Vec3::new(v.x * s, v.y * s, v.z * s)
+}
+
+/// Unit vector for a y-up spherical direction
+///
+/// `polar` is measured from +Y and `azimuth` around it, starting at +Z.
+fn spherical(azimuth: f32, polar: f32) -> Vec3 {
+    Vec3::new(
+        polar.sin() * azimuth.sin(),
+        polar.cos(),
+        polar.sin() * azimuth.cos(),
+    )
+}
+
+/// Camera controller that orbits around a fixed target
+#[derive(Clone, Debug)]
+pub struct OrbitController {
+    /// Point the camera looks at and orbits around
+    pub target: Vec3,
+    /// Distance from the target
+    pub distance: f32,
+    /// Angle around the up axis, in radians
+    pub azimuth: f32,
+    /// Angle from the up axis, in radians (clamped away from the poles)
+    pub polar: f32,
+    /// Radians of rotation per pixel dragged
+    pub rotate_sensitivity: f32,
+    /// Fraction of the distance zoomed per scroll unit
+    pub zoom_sensitivity: f32,
+    /// Fraction of the distance panned per pixel dragged
+    pub pan_sensitivity: f32,
+    /// Easing of motion over frames (0.0 = none)
+    pub damping: f32,
+    /// Closest zoom distance
+    pub min_distance: f32,
+    /// Furthest zoom distance
+    pub max_distance: f32,
+    /// Button held and last pointer position, while dragging
+    drag: Option<(u8, f32, f32)>,
+    /// Rotation not yet applied (azimuth, polar)
+    pending_rotation: (f32, f32),
+    /// Pan not yet applied, in screen pixels
+    pending_pan: (f32, f32),
+    /// Zoom not yet applied, as a log-scale factor
+    pending_zoom: f32,
+}
+
+impl Default for OrbitController {
+    fn default() -> Self {
+        Self {
+            target: Vec3::ZERO,
+            distance: 5.0,
+            azimuth: 0.0,
+            polar: FRAC_PI_2,
+            rotate_sensitivity: 0.005,
+            zoom_sensitivity: 0.1,
+            pan_sensitivity: 0.002,
+            damping: 0.0,
+            min_distance: 0.1,
+            max_distance: 1000.0,
+            drag: None,
+            pending_rotation: (0.0, 0.0),
+            pending_pan: (0.0, 0.0),
+            pending_zoom: 0.0,
+        }
+    }
+}
+
+impl OrbitController {
+    /// Create a controller matching a camera's current position and target
+    pub fn from_camera(camera: &Camera) -> Self {
+        let offset = sub(camera.position, camera.target);
+        let distance = offset.length();
+        let (azimuth, polar) = if distance > 0.0 {
+            (
+                offset.x.atan2(offset.z),
+                (offset.y / distance).clamp(-1.0, 1.0).acos(),
+            )
+        } else {
+            (0.0, FRAC_PI_2)
+        };
+        Self {
+            target: camera.target,
+            distance: distance.max(Self::default().min_distance),
+            azimuth,
+            polar: polar.clamp(POLE_MARGIN, PI - POLE_MARGIN),
+            ..Self::default()
+        }
+    }
+
+    /// Set the rotation, zoom and pan sensitivities
+    pub fn with_sensitivity(mut self, rotate: f32, zoom: f32, pan: f32) -> Self {
+        self.rotate_sensitivity = rotate;
+        self.zoom_sensitivity = zoom;
+        self.pan_sensitivity = pan;
+        self
+    }
+
+    /// Set the damping factor (0.0 = none)
+    pub fn with_damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Limit how close and far the camera may zoom
+    pub fn with_distance_limits(mut self, min: f32, max: f32) -> Self {
+        self.min_distance = min;
+        self.max_distance = max;
+        self
+    }
+
+    /// Feed an input event to the controller
+    ///
+    /// Returns true if the event was consumed.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        match (event.event_type, &event.data) {
+            (event_types::POINTER_DOWN, EventData::Pointer { x, y, button, .. }) => {
+                self.drag = Some((*button, *x, *y));
+                true
+            }
+            (event_types::POINTER_MOVE | event_types::DRAG, EventData::Pointer { x, y, .. }) => {
+                let Some((button, last_x, last_y)) = self.drag else {
+                    return false;
+                };
+                let (dx, dy) = (x - last_x, y - last_y);
+                if button == PRIMARY_BUTTON {
+                    self.rotate(dx, dy);
+                } else {
+                    self.pan(dx, dy);
+                }
+                self.drag = Some((button, *x, *y));
+                true
+            }
+            (event_types::POINTER_UP | event_types::DRAG_END, _) => self.drag.take().is_some(),
+            (event_types::SCROLL, EventData::Scroll { delta_y, .. }) => {
+                self.zoom(*delta_y);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Rotate by a pointer movement in pixels
+    pub fn rotate(&mut self, dx: f32, dy: f32) {
+        // Dragging right swings the camera left, so the scene follows the pointer
+        self.pending_rotation.0 -= dx * self.rotate_sensitivity;
+        self.pending_rotation.1 -= dy * self.rotate_sensitivity;
+    }
+
+    /// Pan the target by a pointer movement in pixels
+    pub fn pan(&mut self, dx: f32, dy: f32) {
+        self.pending_pan.0 += dx;
+        self.pending_pan.1 += dy;
+    }
+
+    /// Zoom by scroll units; positive values move closer
+    pub fn zoom(&mut self, delta: f32) {
+        self.pending_zoom -= delta * self.zoom_sensitivity;
+    }
+
+    /// Apply pending input and write the result into `camera`
+    pub fn update(&mut self, dt: f32, camera: &mut Camera) {
+        let (d_azimuth, rest_azimuth) = damp(self.pending_rotation.0, self.damping, dt);
+        let (d_polar, rest_polar) = damp(self.pending_rotation.1, self.damping, dt);
+        let (pan_x, rest_pan_x) = damp(self.pending_pan.0, self.damping, dt);
+        let (pan_y, rest_pan_y) = damp(self.pending_pan.1, self.damping, dt);
+        let (zoom, rest_zoom) = damp(self.pending_zoom, self.damping, dt);
+        self.pending_rotation = (rest_azimuth, rest_polar);
+        self.pending_pan = (rest_pan_x, rest_pan_y);
+        self.pending_zoom = rest_zoom;
+
+        self.azimuth = (self.azimuth + d_azimuth).rem_euclid(2.0 * PI);
+        self.polar = (self.polar + d_polar).clamp(POLE_MARGIN, PI - POLE_MARGIN);
+        self.distance = (self.distance * zoom.exp()).clamp(self.min_distance, self.max_distance);
+
+        // Pan in the camera's view plane, scaled so the target tracks the pointer
+        let forward = scale(spherical(self.azimuth, self.polar), -1.0);
+        let right = forward.cross(Vec3::UP).normalize();
+        let up = right.cross(forward);
+        let pan_scale = self.distance * self.pan_sensitivity;
+        self.target = add(
+            self.target,
+            add(
+                scale(right, -pan_x * pan_scale),
+                scale(up, pan_y * pan_scale),
+            ),
+        );
+
+        camera.target = self.target;
+        camera.position = add(
+            self.target,
+            scale(spherical(self.azimuth, self.polar), self.distance),
+        );
+        camera.up = Vec3::UP;
+    }
+}
+
+/// First-person camera controller: WASD to move, drag to look
+#[derive(Clone, Debug)]
+pub struct FlyController {
+    /// Camera position
+    pub position: Vec3,
+    /// Heading around the up axis, in radians (0 looks along -Z)
+    pub yaw: f32,
+    /// Angle above the horizon, in radians (clamped short of straight up/down)
+    pub pitch: f32,
+    /// Movement speed in units per second
+    pub speed: f32,
+    /// Radians of rotation per pixel dragged
+    pub look_sensitivity: f32,
+    /// Easing of motion over frames (0.0 = none)
+    pub damping: f32,
+    /// Movement keys held: forward, back, left, right
+    keys: [bool; 4],
+    /// Last pointer position, while dragging
+    drag: Option<(f32, f32)>,
+    /// Look rotation not yet applied (yaw, pitch)
+    pending_look: (f32, f32),
+    /// Smoothed movement velocity
+    velocity: Vec3,
+}
+
+impl Default for FlyController {
+    fn default() -> Self {
+        Self {
+            position: Vec3::ZERO,
+            yaw: 0.0,
+            pitch: 0.0,
+            speed: 5.0,
+            look_sensitivity: 0.003,
+            damping: 0.0,
+            keys: [false; 4],
+            drag: None,
+            pending_look: (0.0, 0.0),
+            velocity: Vec3::ZERO,
+        }
+    }
+}
+
+impl FlyController {
+    /// Create a controller matching a camera's current position and view direction
+    pub fn from_camera(camera: &Camera) -> Self {
+        let direction = sub(camera.target, camera.position).normalize();
+        let (yaw, pitch) = if direction.length() > 0.0 {
+            (
+                (-direction.x).atan2(-direction.z),
+                direction.y.clamp(-1.0, 1.0).asin(),
+            )
+        } else {
+            (0.0, 0.0)
+        };
+        Self {
+            position: camera.position,
+            yaw,
+            pitch: pitch.clamp(-FRAC_PI_2 + POLE_MARGIN, FRAC_PI_2 - POLE_MARGIN),
+            ..Self::default()
+        }
+    }
+
+    /// Set the movement speed and look sensitivity
+    pub fn with_sensitivity(mut self, speed: f32, look: f32) -> Self {
+        self.speed = speed;
+        self.look_sensitivity = look;
+        self
+    }
+
+    /// Set the damping factor (0.0 = none)
+    pub fn with_damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Feed an input event to the controller
+    ///
+    /// Returns true if the event was consumed.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        match (event.event_type, &event.data) {
+            (event_types::KEY_DOWN | event_types::KEY_UP, EventData::Key { key, .. }) => {
+                let index = match *key {
+                    KeyCode::W => 0,
+                    KeyCode::S => 1,
+                    KeyCode::A => 2,
+                    KeyCode::D => 3,
+                    _ => return false,
+                };
+                self.keys[index] = event.event_type == event_types::KEY_DOWN;
+                true
+            }
+            (event_types::POINTER_DOWN, EventData::Pointer { x, y, .. }) => {
+                self.drag = Some((*x, *y));
+                true
+            }
+            (event_types::POINTER_MOVE | event_types::DRAG, EventData::Pointer { x, y, .. }) => {
+                let Some((last_x, last_y)) = self.drag else {
+                    return false;
+                };
+                self.look(x - last_x, y - last_y);
+                self.drag = Some((*x, *y));
+                true
+            }
+            (event_types::POINTER_UP | event_types::DRAG_END, _) => self.drag.take().is_some(),
+            _ => false,
+        }
+    }
+
+    /// Turn by a pointer movement in pixels
+    pub fn look(&mut self, dx: f32, dy: f32) {
+        self.pending_look.0 -= dx * self.look_sensitivity;
+        self.pending_look.1 -= dy * self.look_sensitivity;
+    }
+
+    /// Direction the camera is looking
+    pub fn forward(&self) -> Vec3 {
+        Vec3::new(
+            -self.yaw.sin() * self.pitch.cos(),
+            self.pitch.sin(),
+            -self.yaw.cos() * self.pitch.cos(),
+        )
+    }
+
+    /// Apply held keys and pending look input, and write the result into `camera`
+    pub fn update(&mut self, dt: f32, camera: &mut Camera) {
+        let (d_yaw, rest_yaw) = damp(self.pending_look.0, self.damping, dt);
+        let (d_pitch, rest_pitch) = damp(self.pending_look.1, self.damping, dt);
+        self.pending_look = (rest_yaw, rest_pitch);
+        self.yaw = (self.yaw + d_yaw).rem_euclid(2.0 * PI);
+        self.pitch =
+            (self.pitch + d_pitch).clamp(-FRAC_PI_2 + POLE_MARGIN, FRAC_PI_2 - POLE_MARGIN);
+
+        let forward = self.forward();
+        let right = forward.cross(Vec3::UP).normalize();
+        let axis = |positive: bool, negative: bool| positive as i32 as f32 - negative as i32 as f32;
+        let [w, s, a, d] = self.keys;
+        let wish = add(scale(forward, axis(w, s)), scale(right, axis(d, a))).normalize();
+        let wish = scale(wish, self.speed);
+
+        // Ease the velocity towards the wished velocity
+        let (dx, _) = damp(wish.x - self.velocity.x, self.damping, dt);
+        let (dy, _) = damp(wish.y - self.velocity.y, self.damping, dt);
+        let (dz, _) = damp(wish.z - self.velocity.z, self.damping, dt);
+        self.velocity = add(self.velocity, Vec3::new(dx, dy, dz));
+        self.position = add(self.position, scale(self.velocity, dt));
+
+        camera.position = self.position;
+        camera.target = add(self.position, forward);
+        camera.up = Vec3::UP;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pointer(event_type: u32, x: f32, y: f32) -> Event {
+        Event {
+            event_type,
+            target: 0,
+            data: EventData::Pointer {
+                x,
+                y,
+                button: PRIMARY_BUTTON,
+                pressure: 1.0,
+            },
+            timestamp: 0,
+            propagation_stopped: false,
+        }
+    }
+
+    #[test]
+    fn test_orbit_horizontal_drag_rotates_azimuth() {
+        let mut camera = Camera::perspective(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, 1.0);
+        let mut orbit = OrbitController::from_camera(&camera);
+        let start = orbit.azimuth;
+
+        orbit.handle_event(&pointer(event_types::POINTER_DOWN, 100.0, 100.0));
+        orbit.handle_event(&pointer(event_types::POINTER_MOVE, 160.0, 100.0));
+        orbit.handle_event(&pointer(event_types::POINTER_UP, 160.0, 100.0));
+        orbit.update(1.0 / 60.0, &mut camera);
+
+        let expected = (start - 60.0 * orbit.rotate_sensitivity).rem_euclid(2.0 * PI);
+        assert!((orbit.azimuth - expected).abs() < 1e-5);
+        assert!((orbit.polar - FRAC_PI_2).abs() < 1e-5);
+        assert_eq!(camera.target, Vec3::ZERO);
+        assert!((camera.position.length() - 5.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_orbit_clamps_at_poles() {
+        let mut camera = Camera::perspective(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, 1.0);
+        let mut orbit = OrbitController::from_camera(&camera);
+
+        orbit.rotate(0.0, 1e6);
+        orbit.update(1.0 / 60.0, &mut camera);
+
+        assert!(orbit.polar > 0.0 && orbit.polar < PI);
+        assert!(camera.position.y.abs() < 5.0);
+    }
+
+    #[test]
+    fn test_fly_moves_forward() {
+        let mut camera = Camera::perspective(Vec3::ZERO, Vec3::new(0.0, 0.0, -1.0), 1.0);
+        let mut fly = FlyController::from_camera(&camera);
+
+        fly.handle_event(&Event {
+            event_type: event_types::KEY_DOWN,
+            target: 0,
+            data: EventData::Key {
+                key: KeyCode::W,
+                modifiers: Default::default(),
+                repeat: false,
+            },
+            timestamp: 0,
+            propagation_stopped: false,
+        });
+        fly.update(1.0, &mut camera);
+
+        assert!((camera.position.z + fly.speed).abs() < 1e-4);
+        assert!(camera.position.x.abs() < 1e-4);
+    }
+}
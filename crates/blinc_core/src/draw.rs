@@ -32,6 +32,10 @@
 //! }
 //! ```
 
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
 use crate::layer::{
     Affine2D, BillboardFacing, BlendMode, Brush, Camera, ClipShape, Color, CornerRadius,
     Environment, LayerId, Light, Mat4, Point, Rect, Shadow, Size, Vec2,
@@ -42,7 +46,7 @@ use crate::layer::{
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// Unified transform that can represent 2D or 3D transformations
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Transform {
     /// 2D affine transformation
     Affine2D(Affine2D),
@@ -160,7 +164,7 @@ impl From<Mat4> for Transform {
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// Line cap style
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LineCap {
     /// Flat cap at the endpoint
     #[default]
@@ -172,7 +176,7 @@ pub enum LineCap {
 }
 
 /// Line join style
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LineJoin {
     /// Miter join (sharp corner)
     #[default]
@@ -184,7 +188,7 @@ pub enum LineJoin {
 }
 
 /// Stroke style configuration
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Stroke {
     /// Line width
     pub width: f32,
@@ -247,7 +251,7 @@ impl Stroke {
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// Text alignment
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TextAlign {
     #[default]
     Left,
@@ -256,7 +260,7 @@ pub enum TextAlign {
 }
 
 /// Text baseline
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TextBaseline {
     Top,
     Middle,
@@ -266,7 +270,7 @@ pub enum TextBaseline {
 }
 
 /// Font weight
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FontWeight {
     Thin,
     Light,
@@ -278,7 +282,7 @@ pub enum FontWeight {
 }
 
 /// Text style configuration
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TextStyle {
     /// Font family name
     pub family: String,
@@ -346,7 +350,7 @@ impl TextStyle {
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// Path command for building vector paths
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum PathCommand {
     /// Move to a point
     MoveTo(Point),
@@ -372,8 +376,108 @@ pub enum PathCommand {
     Close,
 }
 
+/// Most line segments [`Path::flatten`] splits a single curve into
+const MAX_CURVE_SEGMENTS: usize = 256;
+
+/// Segments needed to keep pieces of a curve `length` long under `step`
+fn flatten_segments(length: f32, step: f32) -> usize {
+    let segments = (length / step).ceil();
+    if segments.is_finite() {
+        (segments as usize).clamp(1, MAX_CURVE_SEGMENTS)
+    } else {
+        1
+    }
+}
+
+/// Points along a quadratic or cubic Bézier, excluding its start
+fn curve_points(points: &[Point], step: f32) -> Vec<Point> {
+    let polygon: f32 = points
+        .windows(2)
+        .map(|pair| (pair[1].x - pair[0].x).hypot(pair[1].y - pair[0].y))
+        .sum();
+    let segments = flatten_segments(polygon, step);
+
+    (1..=segments)
+        .map(|i| {
+            let t = i as f32 / segments as f32;
+            let mt = 1.0 - t;
+            let weights: &[f32] = match points.len() {
+                3 => &[mt * mt, 2.0 * mt * t, t * t],
+                _ => &[mt * mt * mt, 3.0 * mt * mt * t, 3.0 * mt * t * t, t * t * t],
+            };
+            let (x, y) = points
+                .iter()
+                .zip(weights)
+                .fold((0.0, 0.0), |(x, y), (p, w)| (x + p.x * w, y + p.y * w));
+            Point::new(x, y)
+        })
+        .collect()
+}
+
+/// Points along an SVG-style endpoint arc, excluding its start
+///
+/// Converts to center form as in the SVG spec (appendix F.6.5). Radii too
+/// small to reach the end point are scaled up; zero radii give a line.
+fn arc_points(
+    from: Point,
+    radii: Vec2,
+    rotation: f32,
+    large_arc: bool,
+    sweep: bool,
+    to: Point,
+    step: f32,
+) -> Vec<Point> {
+    let (mut rx, mut ry) = (radii.x.abs(), radii.y.abs());
+    if rx < f32::EPSILON || ry < f32::EPSILON {
+        return vec![to];
+    }
+
+    let (sin, cos) = rotation.sin_cos();
+    let (hx, hy) = ((from.x - to.x) / 2.0, (from.y - to.y) / 2.0);
+    let x1 = cos * hx + sin * hy;
+    let y1 = -sin * hx + cos * hy;
+
+    let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+    if lambda > 1.0 {
+        rx *= lambda.sqrt();
+        ry *= lambda.sqrt();
+    }
+
+    let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+    let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+    let mut coef = (numerator / denominator).max(0.0).sqrt();
+    if large_arc == sweep {
+        coef = -coef;
+    }
+    let (cx1, cy1) = (coef * rx * y1 / ry, -coef * ry * x1 / rx);
+    let (cx, cy) = (
+        cos * cx1 - sin * cy1 + (from.x + to.x) / 2.0,
+        sin * cx1 + cos * cy1 + (from.y + to.y) / 2.0,
+    );
+
+    let start_angle = ((y1 - cy1) / ry).atan2((x1 - cx1) / rx);
+    let end_angle = ((-y1 - cy1) / ry).atan2((-x1 - cx1) / rx);
+    let mut delta = end_angle - start_angle;
+    if sweep && delta < 0.0 {
+        delta += std::f32::consts::TAU;
+    } else if !sweep && delta > 0.0 {
+        delta -= std::f32::consts::TAU;
+    }
+
+    let segments = flatten_segments(delta.abs() * rx.max(ry), step);
+    let mut points: Vec<Point> = (1..segments)
+        .map(|i| {
+            let (s, c) = (start_angle + delta * i as f32 / segments as f32).sin_cos();
+            let (x, y) = (rx * c, ry * s);
+            Point::new(cx + cos * x - sin * y, cy + sin * x + cos * y)
+        })
+        .collect();
+    points.push(to);
+    points
+}
+
 /// A vector path
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Path {
     commands: Vec<PathCommand>,
 }
@@ -549,6 +653,80 @@ impl Path {
         }
     }
 
+    /// Flatten into one polyline per subpath
+    ///
+    /// Curves and arcs are split into line segments of at most
+    /// `max_segment_length` (up to 256 per curve). Closed subpaths end with
+    /// their first point again.
+    pub fn flatten(&self, max_segment_length: f32) -> Vec<Vec<Point>> {
+        let mut polylines: Vec<Vec<Point>> = Vec::new();
+        let mut current = Point::ZERO;
+        let mut start = Point::ZERO;
+        let mut open = false;
+
+        for command in &self.commands {
+            // Drawing commands continue the open subpath, or start one at the
+            // current point
+            if !matches!(command, PathCommand::MoveTo(_) | PathCommand::Close) && !open {
+                polylines.push(vec![current]);
+                open = true;
+            }
+            match *command {
+                PathCommand::MoveTo(p) => {
+                    current = p;
+                    start = p;
+                    open = false;
+                }
+                PathCommand::LineTo(p) => {
+                    polylines.last_mut().unwrap().push(p);
+                    current = p;
+                }
+                PathCommand::QuadTo { control, end } => {
+                    let points = curve_points(&[current, control, end], max_segment_length);
+                    polylines.last_mut().unwrap().extend(points);
+                    current = end;
+                }
+                PathCommand::CubicTo {
+                    control1,
+                    control2,
+                    end,
+                } => {
+                    let points =
+                        curve_points(&[current, control1, control2, end], max_segment_length);
+                    polylines.last_mut().unwrap().extend(points);
+                    current = end;
+                }
+                PathCommand::ArcTo {
+                    radii,
+                    rotation,
+                    large_arc,
+                    sweep,
+                    end,
+                } => {
+                    let points = arc_points(
+                        current,
+                        radii,
+                        rotation,
+                        large_arc,
+                        sweep,
+                        end,
+                        max_segment_length,
+                    );
+                    polylines.last_mut().unwrap().extend(points);
+                    current = end;
+                }
+                PathCommand::Close => {
+                    if open {
+                        polylines.last_mut().unwrap().push(start);
+                    }
+                    current = start;
+                    open = false;
+                }
+            }
+        }
+        polylines
+    }
+
     /// Create a rounded rectangle path
     pub fn rounded_rect(rect: Rect, corner_radius: impl Into<CornerRadius>) -> Self {
         let r = corner_radius.into();
@@ -623,11 +801,11 @@ impl Path {
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// Handle to a loaded image
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ImageId(pub u64);
 
 /// Image rendering options
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct ImageOptions {
     /// Source rectangle within the image (None = entire image)
     pub source_rect: Option<Rect>,
@@ -662,15 +840,15 @@ impl ImageOptions {
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// Handle to a loaded mesh
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct MeshId(pub u64);
 
 /// Handle to a material
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct MaterialId(pub u64);
 
 /// Mesh instance for instanced rendering
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MeshInstance {
     pub transform: Mat4,
     pub material: Option<MaterialId>,
@@ -681,7 +859,7 @@ pub struct MeshInstance {
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// Post-processing effect quality levels
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BlurQuality {
     /// Single-pass box blur (fastest, lowest quality)
     Low,
@@ -693,7 +871,7 @@ pub enum BlurQuality {
 }
 
 /// Post-processing effects that can be applied to layers
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum LayerEffect {
     /// Gaussian blur effect
     Blur {
@@ -736,6 +914,38 @@ pub enum LayerEffect {
         ///                                       `[1]`
         matrix: [f32; 20],
     },
+    /// Bloom: bright areas bleed light into their surroundings
+    ///
+    /// Layers are rendered to 8-bit textures, so this works on LDR colors:
+    /// values above 1.0 are clamped before the bright pass.
+    Bloom {
+        /// Brightness (0.0 to 1.0) above which pixels start to glow
+        threshold: f32,
+        /// Strength of the added light
+        intensity: f32,
+        /// Blur radius of the glow in pixels
+        radius: f32,
+    },
+    /// Exposure adjustment followed by tone mapping
+    ///
+    /// Like [`LayerEffect::Bloom`], this sees the layer's clamped 8-bit
+    /// colors, not HDR values.
+    ToneMap {
+        /// Curve used to compress bright colors
+        mapping: ToneMapping,
+        /// Exposure multiplier applied before mapping (1.0 = unchanged)
+        exposure: f32,
+    },
+}
+
+/// Tone mapping curves for compressing bright colors into displayable range
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ToneMapping {
+    /// ACES filmic approximation (contrasty, film-like roll-off)
+    #[default]
+    Aces,
+    /// Reinhard `c / (1 + c)` (soft, preserves hue)
+    Reinhard,
 }
 
 impl LayerEffect {
@@ -779,6 +989,25 @@ impl LayerEffect {
         }
     }
 
+    /// Create a bloom effect
+    ///
+    /// ## Parameters
+    /// - `threshold`: Brightness above which pixels glow, typically 0.6-0.9
+    /// - `intensity`: Strength of the added light, typically 0.5-2.0
+    /// - `radius`: Blur radius of the glow in pixels
+    pub fn bloom(threshold: f32, intensity: f32, radius: f32) -> Self {
+        Self::Bloom {
+            threshold,
+            intensity,
+            radius,
+        }
+    }
+
+    /// Create a tone mapping effect with the given exposure
+    pub fn tone_map(mapping: ToneMapping, exposure: f32) -> Self {
+        Self::ToneMap { mapping, exposure }
+    }
+
     /// Create an identity color matrix (no change)
     pub fn color_matrix_identity() -> Self {
         Self::ColorMatrix {
@@ -878,7 +1107,7 @@ impl LayerEffect {
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// Configuration for offscreen layers
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct LayerConfig {
     /// Layer ID (optional)
     pub id: Option<LayerId>,
@@ -894,6 +1123,12 @@ pub struct LayerConfig {
     pub depth: bool,
     /// Post-processing effects to apply when layer is composited
     pub effects: Vec<LayerEffect>,
+    /// Keep the rendered layer under `id`, so later frames can composite it
+    /// with [`DrawContext::sample_layer`] instead of drawing its content again
+    ///
+    /// Layers with `effects` are not cached.
+    #[serde(default)]
+    pub cache: bool,
 }
 
 impl LayerConfig {
@@ -938,6 +1173,12 @@ impl LayerConfig {
         self
     }
 
+    /// Keep the rendered layer for [`DrawContext::sample_layer`]
+    pub fn cached(mut self) -> Self {
+        self.cache = true;
+        self
+    }
+
     /// Add a post-processing effect
     pub fn effect(mut self, effect: LayerEffect) -> Self {
         self.effects.push(effect);
@@ -1254,6 +1495,16 @@ pub trait DrawContext {
     /// Sample from a named layer's output
     fn sample_layer(&mut self, id: LayerId, source_rect: Rect, dest_rect: Rect);
 
+    /// Whether the output of a layer pushed with [`LayerConfig::cache`] is
+    /// still held under `id`, so [`sample_layer`](Self::sample_layer) can
+    /// composite it without drawing the layer's content again
+    ///
+    /// Contexts that don't keep layer output (e.g. ones that only record
+    /// commands) report nothing as held, so the content is drawn every time.
+    fn has_cached_layer(&self, _id: LayerId) -> bool {
+        false
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // State Queries
     // ─────────────────────────────────────────────────────────────────────────
@@ -1331,7 +1582,7 @@ impl<T: DrawContext + ?Sized> DrawContextExt for T {}
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// A draw command that can be recorded and replayed
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum DrawCommand {
     // State
     PushTransform(Transform),
@@ -1342,6 +1593,7 @@ pub enum DrawCommand {
     PopOpacity,
     PushBlendMode(BlendMode),
     PopBlendMode,
+    SetZLayer(u32),
 
     // 2D Drawing
     FillPath {
@@ -1439,6 +1691,7 @@ pub struct RecordingContext {
     blend_mode_stack: Vec<BlendMode>,
     viewport: Size,
     is_3d: bool,
+    z_layer: u32,
 }
 
 impl RecordingContext {
@@ -1451,6 +1704,7 @@ impl RecordingContext {
             blend_mode_stack: vec![BlendMode::Normal],
             viewport,
             is_3d: false,
+            z_layer: 0,
         }
     }
 
@@ -1464,12 +1718,18 @@ impl RecordingContext {
         std::mem::take(&mut self.commands)
     }
 
+    /// Consume the context and return the recorded commands
+    pub fn into_commands(self) -> Vec<DrawCommand> {
+        self.commands
+    }
+
     /// Clear all recorded commands
     pub fn clear(&mut self) {
         self.commands.clear();
         self.transform_stack = vec![Transform::identity()];
         self.opacity_stack = vec![1.0];
         self.blend_mode_stack = vec![BlendMode::Normal];
+        self.z_layer = 0;
     }
 }
 
@@ -1524,6 +1784,17 @@ impl DrawContext for RecordingContext {
         }
     }
 
+    fn set_z_layer(&mut self, layer: u32) {
+        if layer != self.z_layer {
+            self.commands.push(DrawCommand::SetZLayer(layer));
+            self.z_layer = layer;
+        }
+    }
+
+    fn z_layer(&self) -> u32 {
+        self.z_layer
+    }
+
     fn fill_path(&mut self, path: &Path, brush: Brush) {
         self.commands.push(DrawCommand::FillPath {
             path: path.clone(),
@@ -1812,6 +2083,175 @@ impl DrawContext for RecordingContext {
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Command Replay
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Maps resource handles from a recording to handles valid in the replay target
+///
+/// Image, mesh and material ids refer to resources owned by the renderer that
+/// recorded them. When commands are deserialized in another process (or after
+/// the resources were reloaded) the ids must be remapped before replay.
+/// Handles without an entry are passed through unchanged.
+#[derive(Clone, Debug, Default)]
+pub struct ResourceMap {
+    images: HashMap<ImageId, ImageId>,
+    meshes: HashMap<MeshId, MeshId>,
+    materials: HashMap<MaterialId, MaterialId>,
+}
+
+impl ResourceMap {
+    /// Create an empty (identity) map
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolve a recorded image id to `target`
+    pub fn with_image(mut self, recorded: ImageId, target: ImageId) -> Self {
+        self.images.insert(recorded, target);
+        self
+    }
+
+    /// Resolve a recorded mesh id to `target`
+    pub fn with_mesh(mut self, recorded: MeshId, target: MeshId) -> Self {
+        self.meshes.insert(recorded, target);
+        self
+    }
+
+    /// Resolve a recorded material id to `target`
+    pub fn with_material(mut self, recorded: MaterialId, target: MaterialId) -> Self {
+        self.materials.insert(recorded, target);
+        self
+    }
+
+    /// Resolve an image id
+    pub fn image(&self, id: ImageId) -> ImageId {
+        self.images.get(&id).copied().unwrap_or(id)
+    }
+
+    /// Resolve a mesh id
+    pub fn mesh(&self, id: MeshId) -> MeshId {
+        self.meshes.get(&id).copied().unwrap_or(id)
+    }
+
+    /// Resolve a material id
+    pub fn material(&self, id: MaterialId) -> MaterialId {
+        self.materials.get(&id).copied().unwrap_or(id)
+    }
+}
+
+/// Re-issue recorded commands into a draw context
+///
+/// Resource ids are used as recorded; see [`replay_with`] to remap them.
+pub fn replay(commands: &[DrawCommand], ctx: &mut dyn DrawContext) {
+    replay_with(commands, ctx, &ResourceMap::default());
+}
+
+/// Re-issue recorded commands into a draw context, resolving resource ids
+/// through `resources`
+pub fn replay_with(commands: &[DrawCommand], ctx: &mut dyn DrawContext, resources: &ResourceMap) {
+    for command in commands {
+        match command {
+            DrawCommand::PushTransform(transform) => ctx.push_transform(transform.clone()),
+            DrawCommand::PopTransform => ctx.pop_transform(),
+            DrawCommand::PushClip(shape) => ctx.push_clip(shape.clone()),
+            DrawCommand::PopClip => ctx.pop_clip(),
+            DrawCommand::PushOpacity(opacity) => ctx.push_opacity(*opacity),
+            DrawCommand::PopOpacity => ctx.pop_opacity(),
+            DrawCommand::PushBlendMode(mode) => ctx.push_blend_mode(*mode),
+            DrawCommand::PopBlendMode => ctx.pop_blend_mode(),
+            DrawCommand::SetZLayer(layer) => ctx.set_z_layer(*layer),
+            DrawCommand::FillPath { path, brush } => ctx.fill_path(path, brush.clone()),
+            DrawCommand::StrokePath {
+                path,
+                stroke,
+                brush,
+            } => ctx.stroke_path(path, stroke, brush.clone()),
+            DrawCommand::FillRect {
+                rect,
+                corner_radius,
+                brush,
+            } => ctx.fill_rect(*rect, *corner_radius, brush.clone()),
+            DrawCommand::StrokeRect {
+                rect,
+                corner_radius,
+                stroke,
+                brush,
+            } => ctx.stroke_rect(*rect, *corner_radius, stroke, brush.clone()),
+            DrawCommand::FillCircle {
+                center,
+                radius,
+                brush,
+            } => ctx.fill_circle(*center, *radius, brush.clone()),
+            DrawCommand::StrokeCircle {
+                center,
+                radius,
+                stroke,
+                brush,
+            } => ctx.stroke_circle(*center, *radius, stroke, brush.clone()),
+            DrawCommand::DrawText {
+                text,
+                origin,
+                style,
+            } => ctx.draw_text(text, *origin, style),
+            DrawCommand::DrawImage {
+                image,
+                rect,
+                options,
+            } => ctx.draw_image(resources.image(*image), *rect, options),
+            DrawCommand::DrawShadow {
+                rect,
+                corner_radius,
+                shadow,
+            } => ctx.draw_shadow(*rect, *corner_radius, *shadow),
+            DrawCommand::DrawInnerShadow {
+                rect,
+                corner_radius,
+                shadow,
+            } => ctx.draw_inner_shadow(*rect, *corner_radius, *shadow),
+            DrawCommand::DrawCircleShadow {
+                center,
+                radius,
+                shadow,
+            } => ctx.draw_circle_shadow(*center, *radius, *shadow),
+            DrawCommand::DrawCircleInnerShadow {
+                center,
+                radius,
+                shadow,
+            } => ctx.draw_circle_inner_shadow(*center, *radius, *shadow),
+            DrawCommand::SetCamera(camera) => ctx.set_camera(camera),
+            DrawCommand::DrawMesh {
+                mesh,
+                material,
+                transform,
+            } => ctx.draw_mesh(
+                resources.mesh(*mesh),
+                resources.material(*material),
+                *transform,
+            ),
+            DrawCommand::DrawMeshInstanced { mesh, instances } => {
+                let instances: Vec<MeshInstance> = instances
+                    .iter()
+                    .map(|instance| MeshInstance {
+                        transform: instance.transform,
+                        material: instance.material.map(|m| resources.material(m)),
+                    })
+                    .collect();
+                ctx.draw_mesh_instanced(resources.mesh(*mesh), &instances);
+            }
+            DrawCommand::AddLight(light) => ctx.add_light(light.clone()),
+            DrawCommand::SetEnvironment(env) => ctx.set_environment(env),
+            DrawCommand::PushLayer(config) => ctx.push_layer(config.clone()),
+            DrawCommand::PopLayer => ctx.pop_layer(),
+            DrawCommand::SampleLayer {
+                id,
+                source_rect,
+                dest_rect,
+            } => ctx.sample_layer(*id, *source_rect, *dest_rect),
+        }
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Recording SDF Builder
 // ─────────────────────────────────────────────────────────────────────────────
@@ -2037,6 +2477,35 @@ mod tests {
         assert!(!circle.is_empty());
     }
 
+    #[test]
+    fn test_path_flatten() {
+        let path = Path::new()
+            .move_to(0.0, 0.0)
+            .line_to(10.0, 0.0)
+            .close()
+            .move_to(20.0, 0.0)
+            .arc_to(Vec2::new(10.0, 10.0), 0.0, false, true, 40.0, 0.0);
+        let polylines = path.flatten(1.0);
+        assert_eq!(polylines.len(), 2);
+
+        // Closed subpaths end back at their start
+        let closed = &polylines[0];
+        assert_eq!(closed.first(), closed.last());
+        assert_eq!(closed.len(), 3);
+
+        // Arc points lie on the circle and stay within the step
+        let arc = &polylines[1];
+        assert_eq!(arc.first(), Some(&Point::new(20.0, 0.0)));
+        assert_eq!(arc.last(), Some(&Point::new(40.0, 0.0)));
+        for point in arc {
+            let radius = (point.x - 30.0).hypot(point.y);
+            assert!((radius - 10.0).abs() < 1e-3, "{point:?} off the arc");
+        }
+        for pair in arc.windows(2) {
+            assert!((pair[1].x - pair[0].x).hypot(pair[1].y - pair[0].y) <= 1.0);
+        }
+    }
+
     #[test]
     fn test_transform_stack() {
         let mut ctx = RecordingContext::new(Size::new(800.0, 600.0));
@@ -2121,4 +2590,64 @@ mod tests {
 
         assert_eq!(ctx.commands().len(), 2);
     }
+
+    #[test]
+    fn test_serialize_and_replay() {
+        let mut ctx = RecordingContext::new(Size::new(800.0, 600.0));
+        ctx.push_opacity(0.5);
+        ctx.fill_rect(
+            Rect::new(0.0, 0.0, 100.0, 50.0),
+            8.0.into(),
+            Color::BLUE.into(),
+        );
+        ctx.fill_circle(Point::new(40.0, 40.0), 12.0, Color::RED.into());
+        ctx.fill_path(
+            &Path::line(Point::ZERO, Point::new(5.0, 5.0)),
+            Color::GREEN.into(),
+        );
+        ctx.draw_image(
+            ImageId(7),
+            Rect::new(0.0, 0.0, 16.0, 16.0),
+            &ImageOptions::new(),
+        );
+        ctx.pop_opacity();
+        let recorded = ctx.into_commands();
+
+        let json = serde_json::to_string(&recorded).unwrap();
+        let decoded: Vec<DrawCommand> = serde_json::from_str(&json).unwrap();
+
+        let mut target = RecordingContext::new(Size::new(800.0, 600.0));
+        let resources = ResourceMap::new().with_image(ImageId(7), ImageId(42));
+        replay_with(&decoded, &mut target, &resources);
+
+        let replayed = target.commands();
+        assert_eq!(replayed.len(), recorded.len());
+        assert!(matches!(replayed[0], DrawCommand::PushOpacity(o) if o == 0.5));
+        match &replayed[1] {
+            DrawCommand::FillRect {
+                rect,
+                corner_radius,
+                brush: Brush::Solid(color),
+            } => {
+                assert_eq!(*rect, Rect::new(0.0, 0.0, 100.0, 50.0));
+                assert_eq!(*corner_radius, CornerRadius::from(8.0));
+                assert_eq!(*color, Color::BLUE);
+            }
+            other => panic!("expected FillRect, got {:?}", other),
+        }
+        assert!(matches!(
+            replayed[2],
+            DrawCommand::FillCircle { radius, .. } if radius == 12.0
+        ));
+        match &replayed[3] {
+            DrawCommand::FillPath { path, .. } => assert_eq!(path.commands().len(), 2),
+            other => panic!("expected FillPath, got {:?}", other),
+        }
+        // Image handles are resolved through the resource map
+        assert!(matches!(
+            replayed[4],
+            DrawCommand::DrawImage { image, .. } if image == ImageId(42)
+        ));
+        assert!(matches!(replayed[5], DrawCommand::PopOpacity));
+    }
 }
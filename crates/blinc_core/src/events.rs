@@ -2,6 +2,8 @@
 //!
 //! Unified event handling across all platforms.
 
+use std::any::{Any, TypeId};
+
 use rustc_hash::FxHashMap;
 
 /// Event type identifier
@@ -20,6 +22,8 @@ pub mod event_types {
     pub const DRAG: EventType = 6;
     /// Drag ended (mouse up after drag)
     pub const DRAG_END: EventType = 7;
+    /// Pointer held down without moving (sent every frame while held)
+    pub const PRESS_HOLD: EventType = 8;
     pub const FOCUS: EventType = 10;
     pub const BLUR: EventType = 11;
     pub const KEY_DOWN: EventType = 20;
@@ -46,6 +50,14 @@ pub mod event_types {
 
     // Selection events
     pub const SELECT_ALL: EventType = 80;
+
+    // Pen/stylus events (also delivered as pointer events)
+    pub const PEN_DOWN: EventType = 90;
+    pub const PEN_MOVE: EventType = 91;
+    pub const PEN_UP: EventType = 92;
+
+    // Typed application events (`EventContext::emit_custom`)
+    pub const CUSTOM: EventType = 100;
 }
 
 /// A UI event with associated data
@@ -93,9 +105,31 @@ pub enum EventData {
         width: u32,
         height: u32,
     },
+    Pen {
+        x: f32,
+        y: f32,
+        pen: PenState,
+    },
     None,
 }
 
+/// Stylus state reported with pen events
+///
+/// Devices that can't sense tilt report zero tilt; pressure is always set.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PenState {
+    /// Tip pressure (0.0 - 1.0)
+    pub pressure: f32,
+    /// Tilt toward +x in degrees (-90 to 90, 0 = perpendicular to the surface)
+    pub tilt_x: f32,
+    /// Tilt toward +y in degrees (-90 to 90, 0 = perpendicular to the surface)
+    pub tilt_y: f32,
+    /// Whether the barrel (side) button is held
+    pub barrel_button: bool,
+    /// Whether the eraser end is in use
+    pub eraser: bool,
+}
+
 /// Virtual key codes (platform-agnostic)
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 pub struct KeyCode(pub u32);
@@ -248,15 +282,20 @@ impl Event {
 /// Event handler function type
 pub type EventHandler = Box<dyn Fn(&Event) + Send + Sync>;
 
+/// Custom event handler, called with a payload already known to match its type
+type CustomEventHandler = Box<dyn Fn(&dyn Any) + Send + Sync>;
+
 /// Dispatches events to registered handlers
 pub struct EventDispatcher {
     handlers: FxHashMap<(u64, EventType), Vec<EventHandler>>,
+    custom_handlers: FxHashMap<TypeId, Vec<CustomEventHandler>>,
 }
 
 impl EventDispatcher {
     pub fn new() -> Self {
         Self {
             handlers: FxHashMap::default(),
+            custom_handlers: FxHashMap::default(),
         }
     }
 
@@ -282,6 +321,47 @@ impl EventDispatcher {
             }
         }
     }
+
+    /// Register a handler for app-defined events of type `T`
+    ///
+    /// The handler runs for every [`emit_custom`](Self::emit_custom) of a
+    /// `T`, and never for payloads of other types. Element-level events that
+    /// bubble through the layout tree use `EventContext::emit_custom` in
+    /// `blinc_layout` instead.
+    ///
+    /// ```ignore
+    /// struct Submitted { email: String }
+    ///
+    /// dispatcher.on_custom(|event: &Submitted| save(&event.email));
+    /// dispatcher.emit_custom(Submitted { email: "a@b.c".into() });
+    /// ```
+    pub fn on_custom<T, F>(&mut self, handler: F)
+    where
+        T: 'static,
+        F: Fn(&T) + Send + Sync + 'static,
+    {
+        self.custom_handlers
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push(Box::new(move |payload| {
+                if let Some(payload) = payload.downcast_ref::<T>() {
+                    handler(payload);
+                }
+            }));
+    }
+
+    /// Deliver an app-defined event to every handler registered for `T`
+    ///
+    /// Returns the number of handlers that received it.
+    pub fn emit_custom<T: 'static>(&self, payload: T) -> usize {
+        let Some(handlers) = self.custom_handlers.get(&TypeId::of::<T>()) else {
+            return 0;
+        };
+        for handler in handlers {
+            handler(&payload);
+        }
+        handlers.len()
+    }
 }
 
 impl Default for EventDispatcher {
@@ -289,3 +369,42 @@ impl Default for EventDispatcher {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Submitted {
+        form: u64,
+        email: String,
+    }
+
+    struct Cancelled;
+
+    #[test]
+    fn test_custom_event_delivers_typed_payload() {
+        let mut dispatcher = EventDispatcher::new();
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let cancelled = Arc::new(Mutex::new(0));
+
+        let sink = Arc::clone(&received);
+        dispatcher.on_custom(move |event: &Submitted| sink.lock().unwrap().push(event.clone()));
+        let count = Arc::clone(&cancelled);
+        dispatcher.on_custom(move |_: &Cancelled| *count.lock().unwrap() += 1);
+
+        let payload = Submitted {
+            form: 7,
+            email: "ada@example.com".to_string(),
+        };
+        assert_eq!(dispatcher.emit_custom(payload.clone()), 1);
+        assert_eq!(*received.lock().unwrap(), vec![payload]);
+        // Only the Submitted handler ran
+        assert_eq!(*cancelled.lock().unwrap(), 0);
+
+        // No handlers for this type
+        assert_eq!(dispatcher.emit_custom(42u32), 0);
+        assert_eq!(received.lock().unwrap().len(), 1);
+    }
+}
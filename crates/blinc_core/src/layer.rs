@@ -14,12 +14,14 @@
 
 use std::collections::HashMap;
 
+use serde::{Deserialize, Serialize};
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Core Geometry Types
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// 2D point
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Point {
     pub x: f32,
     pub y: f32,
@@ -34,7 +36,7 @@ impl Point {
 }
 
 /// 2D size
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Size {
     pub width: f32,
     pub height: f32,
@@ -70,7 +72,7 @@ impl From<Size> for Rect {
 }
 
 /// 2D rectangle
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Rect {
     pub origin: Point,
     pub size: Size,
@@ -227,7 +229,7 @@ impl Rect {
 }
 
 /// 2D vector
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Vec2 {
     pub x: f32,
     pub y: f32,
@@ -256,7 +258,7 @@ impl Vec2 {
 }
 
 /// 3D vector
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Vec3 {
     pub x: f32,
     pub y: f32,
@@ -316,7 +318,7 @@ impl Vec3 {
 }
 
 /// 4x4 transformation matrix (column-major)
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Mat4 {
     pub cols: [[f32; 4]; 4],
 }
@@ -387,7 +389,7 @@ impl Mat4 {
 }
 
 /// 2D affine transformation
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Affine2D {
     /// Matrix elements [a, b, c, d, tx, ty]
     /// | a  c  tx |
@@ -435,6 +437,20 @@ impl Affine2D {
         )
     }
 
+    /// Inverse transform, or None if the matrix is singular (e.g. zero scale)
+    pub fn inverse(&self) -> Option<Affine2D> {
+        let [a, b, c, d, tx, ty] = self.elements;
+        let det = a * d - b * c;
+        if det.abs() < f32::EPSILON {
+            return None;
+        }
+        let inv_det = 1.0 / det;
+        let (ia, ib, ic, id) = (d * inv_det, -b * inv_det, -c * inv_det, a * inv_det);
+        Some(Affine2D {
+            elements: [ia, ib, ic, id, -(ia * tx + ic * ty), -(ib * tx + id * ty)],
+        })
+    }
+
     /// Concatenate this transform with another (self * other)
     /// The resulting transform first applies `other`, then `self`.
     pub fn then(&self, other: &Affine2D) -> Affine2D {
@@ -463,7 +479,7 @@ impl Affine2D {
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// RGBA color (linear space)
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Color {
     pub r: f32,
     pub g: f32,
@@ -519,6 +535,202 @@ impl Color {
             a: a.a + (b.a - a.a) * t,
         }
     }
+
+    /// WCAG relative luminance (0.0 = black, 1.0 = white)
+    ///
+    /// Channels are treated as sRGB-encoded, matching how theme colors are
+    /// authored (`from_hex`). Alpha is ignored.
+    pub fn relative_luminance(&self) -> f32 {
+        fn channel(c: f32) -> f32 {
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+        0.2126 * channel(self.r) + 0.7152 * channel(self.g) + 0.0722 * channel(self.b)
+    }
+
+    /// WCAG contrast ratio between two opaque colors (1.0 to 21.0)
+    pub fn contrast_ratio(&self, other: &Color) -> f32 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Convert to OKLab `[L, a, b]`, treating channels as sRGB-encoded
+    pub fn to_oklab(&self) -> [f32; 3] {
+        fn linear(c: f32) -> f32 {
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+        let (r, g, b) = (linear(self.r), linear(self.g), linear(self.b));
+        let l = (0.41222147 * r + 0.53633254 * g + 0.051445993 * b).cbrt();
+        let m = (0.2119035 * r + 0.6806995 * g + 0.10739696 * b).cbrt();
+        let s = (0.08830246 * r + 0.28171884 * g + 0.6299787 * b).cbrt();
+        [
+            0.21045426 * l + 0.7936178 * m - 0.004072047 * s,
+            1.9779985 * l - 2.4285922 * m + 0.4505937 * s,
+            0.025904037 * l + 0.78277177 * m - 0.80867577 * s,
+        ]
+    }
+
+    /// Create an sRGB-encoded color from OKLab `[L, a, b]`
+    ///
+    /// Out-of-gamut results are clamped.
+    pub fn from_oklab(lab: [f32; 3], alpha: f32) -> Self {
+        fn encode(c: f32) -> f32 {
+            let c = c.clamp(0.0, 1.0);
+            if c <= 0.0031308 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            }
+        }
+        let l = (lab[0] + 0.39633778 * lab[1] + 0.21580376 * lab[2]).powi(3);
+        let m = (lab[0] - 0.105561346 * lab[1] - 0.06385417 * lab[2]).powi(3);
+        let s = (lab[0] - 0.08948418 * lab[1] - 1.2914855 * lab[2]).powi(3);
+        Self::rgba(
+            encode(4.0767417 * l - 3.3077116 * m + 0.23096993 * s),
+            encode(-1.268438 * l + 2.6097574 * m - 0.34131938 * s),
+            encode(-0.0041960863 * l - 0.7034186 * m + 1.7076147 * s),
+            alpha,
+        )
+    }
+
+    /// Interpolate between two colors in OKLab space
+    pub fn lerp_oklab(a: &Color, b: &Color, t: f32) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let (la, lb) = (a.to_oklab(), b.to_oklab());
+        Color::from_oklab(
+            [
+                la[0] + (lb[0] - la[0]) * t,
+                la[1] + (lb[1] - la[1]) * t,
+                la[2] + (lb[2] - la[2]) * t,
+            ],
+            a.a + (b.a - a.a) * t,
+        )
+    }
+
+    /// Parse a hex color string: `#RGB`, `#RGBA`, `#RRGGBB` or `#RRGGBBAA`
+    ///
+    /// The `#` is optional. This is the string counterpart of
+    /// [`from_hex`](Self::from_hex).
+    pub fn from_hex_str(hex: &str) -> Result<Self, ColorParseError> {
+        let trimmed = hex.trim();
+        let digits = trimmed.strip_prefix('#').unwrap_or(trimmed);
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ColorParseError::InvalidHex(hex.to_string()));
+        }
+        let value = |d: &str| u8::from_str_radix(d, 16).unwrap_or(0) as f32 / 255.0;
+        let channels: Vec<f32> = match digits.len() {
+            // Short forms repeat each digit: #f80 == #ff8800
+            3 | 4 => (0..digits.len())
+                .map(|i| value(digits[i..=i].repeat(2).as_str()))
+                .collect(),
+            6 | 8 => (0..digits.len())
+                .step_by(2)
+                .map(|i| value(&digits[i..i + 2]))
+                .collect(),
+            _ => return Err(ColorParseError::InvalidHex(hex.to_string())),
+        };
+        Ok(Self::rgba(
+            channels[0],
+            channels[1],
+            channels[2],
+            channels.get(3).copied().unwrap_or(1.0),
+        ))
+    }
+
+    /// Parse a CSS color string
+    ///
+    /// Accepts hex (see [`from_hex_str`](Self::from_hex_str)), `rgb()`/`rgba()`,
+    /// `hsl()`/`hsla()` and `transparent`. Function arguments may be separated
+    /// by commas or spaces, with an optional `/ alpha`. RGB channels are 0-255
+    /// or percentages, hue is in degrees, saturation and lightness are
+    /// percentages, and alpha is 0-1 or a percentage. Out-of-range values are
+    /// clamped.
+    pub fn from_css(css: &str) -> Result<Self, ColorParseError> {
+        let trimmed = css.trim();
+        if trimmed.starts_with('#') {
+            return Self::from_hex_str(trimmed);
+        }
+        let lower = trimmed.to_ascii_lowercase();
+        if lower == "transparent" {
+            return Ok(Self::TRANSPARENT);
+        }
+
+        let syntax = || ColorParseError::InvalidSyntax(css.to_string());
+        let (function, args) = lower
+            .strip_suffix(')')
+            .and_then(|f| f.split_once('('))
+            .ok_or_else(syntax)?;
+        let args: Vec<&str> = args
+            .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+            .filter(|arg| !arg.is_empty())
+            .collect();
+        if args.len() != 3 && args.len() != 4 {
+            return Err(syntax());
+        }
+
+        let component = |i: usize, parse: fn(&str) -> Option<f32>| {
+            parse(args[i]).ok_or_else(|| ColorParseError::InvalidComponent {
+                input: css.to_string(),
+                component: args[i].to_string(),
+            })
+        };
+        let alpha = match args.len() {
+            4 => component(3, parse_css_alpha)?,
+            _ => 1.0,
+        };
+        match function.trim() {
+            "rgb" | "rgba" => Ok(Self::rgba(
+                component(0, parse_css_channel)?,
+                component(1, parse_css_channel)?,
+                component(2, parse_css_channel)?,
+                alpha,
+            )),
+            "hsl" | "hsla" => Ok(hsl_to_rgb(
+                component(0, parse_css_hue)?,
+                component(1, parse_css_percent)?,
+                component(2, parse_css_percent)?,
+            )
+            .with_alpha(alpha)),
+            _ => Err(syntax()),
+        }
+    }
+
+    /// Format as `#rrggbb`, or `#rrggbbaa` when not fully opaque
+    ///
+    /// Channels are clamped to 0-1 and rounded to 8 bits.
+    pub fn to_hex(&self) -> String {
+        let [r, g, b, a] = self.to_array().map(to_byte);
+        if a == 255 {
+            format!("#{:02x}{:02x}{:02x}", r, g, b)
+        } else {
+            format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+        }
+    }
+
+    /// Format as CSS `rgb(r, g, b)`, or `rgba(r, g, b, a)` when not fully opaque
+    pub fn to_css(&self) -> String {
+        let [r, g, b] = [self.r, self.g, self.b].map(to_byte);
+        let a = self.a.clamp(0.0, 1.0);
+        if a == 1.0 {
+            format!("rgb({}, {}, {})", r, g, b)
+        } else {
+            format!(
+                "rgba({}, {}, {}, {})",
+                r,
+                g,
+                b,
+                (a * 1000.0).round() / 1000.0
+            )
+        }
+    }
 }
 
 impl Default for Color {
@@ -527,13 +739,102 @@ impl Default for Color {
     }
 }
 
+impl std::str::FromStr for Color {
+    type Err = ColorParseError;
+
+    /// Same as [`Color::from_css`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_css(s)
+    }
+}
+
+/// Error parsing a color string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColorParseError {
+    /// Not 3, 4, 6 or 8 hex digits
+    InvalidHex(String),
+    /// Not a hex color, `rgb()`, `rgba()`, `hsl()`, `hsla()` or `transparent`
+    InvalidSyntax(String),
+    /// A function argument isn't a valid number or percentage
+    InvalidComponent { input: String, component: String },
+}
+
+impl std::fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidHex(input) => write!(f, "invalid hex color '{}'", input),
+            Self::InvalidSyntax(input) => write!(f, "unrecognized color '{}'", input),
+            Self::InvalidComponent { input, component } => {
+                write!(f, "invalid component '{}' in color '{}'", component, input)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ColorParseError {}
+
+fn to_byte(channel: f32) -> u8 {
+    (channel.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+fn parse_css_number(s: &str) -> Option<f32> {
+    s.parse::<f32>().ok().filter(|v| v.is_finite())
+}
+
+/// `0-255` or `0%-100%`, as 0-1
+fn parse_css_channel(s: &str) -> Option<f32> {
+    match s.strip_suffix('%') {
+        Some(pct) => parse_css_number(pct).map(|v| v / 100.0),
+        None => parse_css_number(s).map(|v| v / 255.0),
+    }
+    .map(|v| v.clamp(0.0, 1.0))
+}
+
+/// `0-1` or `0%-100%`, as 0-1
+fn parse_css_alpha(s: &str) -> Option<f32> {
+    match s.strip_suffix('%') {
+        Some(pct) => parse_css_number(pct).map(|v| v / 100.0),
+        None => parse_css_number(s),
+    }
+    .map(|v| v.clamp(0.0, 1.0))
+}
+
+/// Percentage (the `%` is optional), as 0-1
+fn parse_css_percent(s: &str) -> Option<f32> {
+    parse_css_number(s.strip_suffix('%').unwrap_or(s)).map(|v| (v / 100.0).clamp(0.0, 1.0))
+}
+
+/// Hue in degrees (`deg` optional), wrapped to 0-360
+fn parse_css_hue(s: &str) -> Option<f32> {
+    parse_css_number(s.strip_suffix("deg").unwrap_or(s)).map(|v| v.rem_euclid(360.0))
+}
+
+/// HSL (hue in degrees, saturation/lightness 0-1) to an opaque RGB color
+fn hsl_to_rgb(h: f32, s: f32, l: f32) -> Color {
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+    let m = l - c / 2.0;
+    let (r, g, b) = match h {
+        h if h < 60.0 => (c, x, 0.0),
+        h if h < 120.0 => (x, c, 0.0),
+        h if h < 180.0 => (0.0, c, x),
+        h if h < 240.0 => (0.0, x, c),
+        h if h < 300.0 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    Color::rgb(r + m, g + m, b + m)
+}
+
 /// Gradient stop
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct GradientStop {
     /// Position along the gradient (0.0 to 1.0)
     pub offset: f32,
     /// Color at this stop
     pub color: Color,
+    /// Easing of the transition from this stop to the next one
+    #[serde(default)]
+    pub easing: GradientEasing,
 }
 
 impl GradientStop {
@@ -542,12 +843,59 @@ impl GradientStop {
         Self {
             offset: offset.clamp(0.0, 1.0),
             color,
+            easing: GradientEasing::Linear,
         }
     }
+
+    /// Set the easing of the transition from this stop to the next one
+    pub fn with_easing(mut self, easing: GradientEasing) -> Self {
+        self.easing = easing;
+        self
+    }
+}
+
+/// Easing curve for the transition between two gradient stops
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GradientEasing {
+    /// Constant rate of change
+    #[default]
+    Linear,
+    /// Slow start
+    EaseIn,
+    /// Slow end
+    EaseOut,
+    /// Slow start and end
+    EaseInOut,
+}
+
+impl GradientEasing {
+    /// Map a local position between two stops (0.0 to 1.0) through the curve
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            GradientEasing::Linear => t,
+            GradientEasing::EaseIn => t * t,
+            GradientEasing::EaseOut => t * (2.0 - t),
+            GradientEasing::EaseInOut => t * t * (3.0 - 2.0 * t),
+        }
+    }
+}
+
+/// Color space gradient stops are blended in
+///
+/// This is independent of [`GradientSpace`], which describes how gradient
+/// coordinates are interpreted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GradientInterpolation {
+    /// Blend the sRGB-encoded components directly (CSS default)
+    #[default]
+    Srgb,
+    /// Blend in the perceptual OKLab space, avoiding gray midpoints
+    Oklab,
 }
 
 /// Gradient coordinate space
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GradientSpace {
     /// Coordinates are in user/world space (absolute pixels)
     #[default]
@@ -557,7 +905,7 @@ pub enum GradientSpace {
 }
 
 /// Gradient spread method for areas outside the gradient
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GradientSpread {
     /// Pad with the end colors
     #[default]
@@ -569,7 +917,7 @@ pub enum GradientSpread {
 }
 
 /// Gradient type
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Gradient {
     /// Linear gradient between two points
     Linear {
@@ -583,6 +931,12 @@ pub enum Gradient {
         space: GradientSpace,
         /// Spread method
         spread: GradientSpread,
+        /// Color space the stops are blended in
+        #[serde(default)]
+        interpolation: GradientInterpolation,
+        /// Add noise to hide banding on large, low-contrast gradients
+        #[serde(default)]
+        dither: bool,
     },
     /// Radial gradient from center outward
     Radial {
@@ -598,6 +952,12 @@ pub enum Gradient {
         space: GradientSpace,
         /// Spread method
         spread: GradientSpread,
+        /// Color space the stops are blended in
+        #[serde(default)]
+        interpolation: GradientInterpolation,
+        /// Add noise to hide banding on large, low-contrast gradients
+        #[serde(default)]
+        dither: bool,
     },
     /// Conic/angular gradient around a center point
     Conic {
@@ -609,6 +969,12 @@ pub enum Gradient {
         stops: Vec<GradientStop>,
         /// Coordinate space interpretation
         space: GradientSpace,
+        /// Color space the stops are blended in
+        #[serde(default)]
+        interpolation: GradientInterpolation,
+        /// Add noise to hide banding on large, low-contrast gradients
+        #[serde(default)]
+        dither: bool,
     },
 }
 
@@ -621,6 +987,8 @@ impl Gradient {
             stops: vec![GradientStop::new(0.0, from), GradientStop::new(1.0, to)],
             space: GradientSpace::UserSpace,
             spread: GradientSpread::Pad,
+            interpolation: GradientInterpolation::Srgb,
+            dither: false,
         }
     }
 
@@ -632,6 +1000,8 @@ impl Gradient {
             stops,
             space: GradientSpace::UserSpace,
             spread: GradientSpread::Pad,
+            interpolation: GradientInterpolation::Srgb,
+            dither: false,
         }
     }
 
@@ -644,6 +1014,8 @@ impl Gradient {
             stops: vec![GradientStop::new(0.0, from), GradientStop::new(1.0, to)],
             space: GradientSpace::UserSpace,
             spread: GradientSpread::Pad,
+            interpolation: GradientInterpolation::Srgb,
+            dither: false,
         }
     }
 
@@ -656,6 +1028,8 @@ impl Gradient {
             stops,
             space: GradientSpace::UserSpace,
             spread: GradientSpread::Pad,
+            interpolation: GradientInterpolation::Srgb,
+            dither: false,
         }
     }
 
@@ -666,6 +1040,8 @@ impl Gradient {
             start_angle: 0.0,
             stops: vec![GradientStop::new(0.0, from), GradientStop::new(1.0, to)],
             space: GradientSpace::UserSpace,
+            interpolation: GradientInterpolation::Srgb,
+            dither: false,
         }
     }
 
@@ -678,6 +1054,95 @@ impl Gradient {
         }
     }
 
+    /// Get the gradient stops mutably
+    pub fn stops_mut(&mut self) -> &mut Vec<GradientStop> {
+        match self {
+            Gradient::Linear { stops, .. } => stops,
+            Gradient::Radial { stops, .. } => stops,
+            Gradient::Conic { stops, .. } => stops,
+        }
+    }
+
+    /// Blend the stops in the given color space
+    pub fn with_interpolation(mut self, value: GradientInterpolation) -> Self {
+        match &mut self {
+            Gradient::Linear { interpolation, .. }
+            | Gradient::Radial { interpolation, .. }
+            | Gradient::Conic { interpolation, .. } => *interpolation = value,
+        }
+        self
+    }
+
+    /// Enable or disable dithering
+    pub fn with_dither(mut self, value: bool) -> Self {
+        match &mut self {
+            Gradient::Linear { dither, .. }
+            | Gradient::Radial { dither, .. }
+            | Gradient::Conic { dither, .. } => *dither = value,
+        }
+        self
+    }
+
+    /// Get the color space the stops are blended in
+    pub fn interpolation(&self) -> GradientInterpolation {
+        match self {
+            Gradient::Linear { interpolation, .. }
+            | Gradient::Radial { interpolation, .. }
+            | Gradient::Conic { interpolation, .. } => *interpolation,
+        }
+    }
+
+    /// Whether dithering is enabled
+    pub fn dither(&self) -> bool {
+        match self {
+            Gradient::Linear { dither, .. }
+            | Gradient::Radial { dither, .. }
+            | Gradient::Conic { dither, .. } => *dither,
+        }
+    }
+
+    /// Whether the gradient needs more than a plain sRGB blend of its stops
+    pub fn has_custom_interpolation(&self) -> bool {
+        self.interpolation() != GradientInterpolation::Srgb
+            || self
+                .stops()
+                .iter()
+                .any(|s| s.easing != GradientEasing::Linear)
+    }
+
+    /// Compute the color at position `t` (0.0 to 1.0) along the gradient
+    ///
+    /// Applies stop easing and the interpolation space; this is the CPU
+    /// reference for what the GPU shaders produce.
+    pub fn sample(&self, t: f32) -> Color {
+        let stops = self.stops();
+        let (first, last) = match (stops.first(), stops.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Color::BLACK,
+        };
+        if t <= first.offset {
+            return first.color;
+        }
+        if t >= last.offset {
+            return last.color;
+        }
+        for pair in stops.windows(2) {
+            let (s0, s1) = (&pair[0], &pair[1]);
+            if t >= s0.offset && t <= s1.offset {
+                let range = s1.offset - s0.offset;
+                if range < 0.0001 {
+                    return s0.color;
+                }
+                let local = s0.easing.apply((t - s0.offset) / range);
+                return match self.interpolation() {
+                    GradientInterpolation::Srgb => Color::lerp(&s0.color, &s1.color, local),
+                    GradientInterpolation::Oklab => Color::lerp_oklab(&s0.color, &s1.color, local),
+                };
+            }
+        }
+        last.color
+    }
+
     /// Get the first color in the gradient (or BLACK if no stops)
     pub fn first_color(&self) -> Color {
         self.stops()
@@ -693,7 +1158,7 @@ impl Gradient {
 }
 
 /// Image fill mode for background images
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ImageFit {
     /// Scale image to fill container, cropping if necessary (CSS: cover)
     #[default]
@@ -707,7 +1172,7 @@ pub enum ImageFit {
 }
 
 /// Image alignment within container
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct ImagePosition {
     /// Horizontal position (0.0 = left, 0.5 = center, 1.0 = right)
     pub x: f32,
@@ -732,7 +1197,7 @@ impl ImagePosition {
 }
 
 /// Image brush for background fills
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ImageBrush {
     /// Path to the image (relative to assets root or absolute)
     pub source: String,
@@ -808,8 +1273,75 @@ impl ImageBrush {
     }
 }
 
+/// A shape drawn inside one tile of a [`PatternBrush`]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PatternShape {
+    /// Outline in tile-local coordinates
+    pub path: crate::draw::Path,
+    /// Fill of the outline
+    pub brush: Brush,
+}
+
+/// Repeating pattern fill (e.g. SVG `<pattern>`)
+///
+/// One tile of content is repeated across the filled shape. Tile `(i, j)`
+/// covers `rect` offset by `(i * rect.width(), j * rect.height())` in
+/// pattern space, which `transform` maps into the shape's space.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PatternBrush {
+    /// Content of a single tile, relative to the tile origin
+    pub tile: Vec<PatternShape>,
+    /// Origin and size of the first tile in pattern space
+    pub rect: Rect,
+    /// Pattern space to shape space
+    pub transform: Affine2D,
+    /// Opacity (0.0 = transparent, 1.0 = opaque)
+    pub opacity: f32,
+}
+
+impl PatternBrush {
+    /// Create a pattern repeating `tile` every `rect`
+    pub fn new(rect: Rect, tile: Vec<PatternShape>) -> Self {
+        Self {
+            tile,
+            rect,
+            transform: Affine2D::IDENTITY,
+            opacity: 1.0,
+        }
+    }
+
+    /// Set the pattern transform
+    pub fn transform(mut self, transform: Affine2D) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// Set opacity
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Flat color for renderers that can't tile content
+    ///
+    /// Uses the first solid or gradient fill in the tile.
+    pub fn fallback_color(&self) -> Color {
+        let color = self
+            .tile
+            .iter()
+            .find_map(|shape| match &shape.brush {
+                Brush::Solid(color) => Some(*color),
+                Brush::Gradient(gradient) => Some(gradient.first_color()),
+                Brush::Pattern(pattern) => Some(pattern.fallback_color()),
+                _ => None,
+            })
+            .unwrap_or(Color::TRANSPARENT);
+        color.with_alpha(color.a * self.opacity)
+    }
+}
+
 /// Brush for filling shapes
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Brush {
     Solid(Color),
     Gradient(Gradient),
@@ -819,6 +1351,8 @@ pub enum Brush {
     Blur(BlurStyle),
     /// Image fill for backgrounds
     Image(ImageBrush),
+    /// Repeating tiled content
+    Pattern(PatternBrush),
 }
 
 impl From<Color> for Brush {
@@ -839,6 +1373,12 @@ impl From<ImageBrush> for Brush {
     }
 }
 
+impl From<PatternBrush> for Brush {
+    fn from(brush: PatternBrush) -> Self {
+        Brush::Pattern(brush)
+    }
+}
+
 impl From<BlurStyle> for Brush {
     fn from(style: BlurStyle) -> Self {
         Brush::Blur(style)
@@ -846,7 +1386,7 @@ impl From<BlurStyle> for Brush {
 }
 
 /// Blend mode for layer composition
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BlendMode {
     #[default]
     Normal,
@@ -864,7 +1404,7 @@ pub enum BlendMode {
 }
 
 /// Corner radii for rounded rectangles
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct CornerRadius {
     pub top_left: f32,
     pub top_right: f32,
@@ -924,7 +1464,7 @@ impl From<f32> for CornerRadius {
 }
 
 /// Shadow configuration
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
 pub struct Shadow {
     pub offset_x: f32,
     pub offset_y: f32,
@@ -953,7 +1493,7 @@ impl Shadow {
 ///
 /// Creates a backdrop blur effect similar to macOS vibrancy or iOS blur.
 /// Used with `DrawContext::fill_glass()` to render glass panels.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct GlassStyle {
     /// Blur intensity (0-50, default 20)
     pub blur: f32,
@@ -1101,7 +1641,7 @@ impl GlassStyle {
 /// Unlike `GlassStyle`, this provides just blur without tinting, noise, or other
 /// glass-specific effects. Use this when you want a simple blur effect on the
 /// content behind an element.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct BlurStyle {
     /// Blur radius in pixels (0-50, default 10)
     pub radius: f32,
@@ -1208,7 +1748,7 @@ impl From<f32> for BlurStyle {
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// Unique identifier for a layer
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct LayerId(pub u64);
 
 impl LayerId {
@@ -1263,6 +1803,65 @@ pub enum BillboardFacing {
     Fixed,
 }
 
+impl BillboardFacing {
+    /// Rotate a billboard's transform to face a camera at `camera_position`
+    ///
+    /// The billboard's content lies in its local XY plane, so its +Z axis
+    /// (the forward vector) is turned towards the camera. Position and scale
+    /// are kept. `CameraY` only turns around the world Y axis, so the
+    /// content stays upright (trees, signposts); `Fixed` returns `transform`
+    /// unchanged. A camera exactly at the billboard (or straight above it
+    /// for `CameraY`) leaves the rotation as it was.
+    pub fn orient(&self, transform: Mat4, camera_position: Vec3) -> Mat4 {
+        let axis = |i: usize| {
+            let c = transform.cols[i];
+            Vec3::new(c[0], c[1], c[2])
+        };
+        let position = axis(3);
+        let mut to_camera = Vec3::new(
+            camera_position.x - position.x,
+            camera_position.y - position.y,
+            camera_position.z - position.z,
+        );
+        match self {
+            BillboardFacing::Fixed => return transform,
+            BillboardFacing::CameraY => to_camera.y = 0.0,
+            BillboardFacing::Camera => {}
+        }
+        let forward = to_camera.normalize();
+        if forward == Vec3::ZERO {
+            return transform;
+        }
+
+        // Looking straight up or down: keep the billboard's current right axis
+        let mut right = Vec3::UP.cross(forward).normalize();
+        if right == Vec3::ZERO {
+            let current = axis(0);
+            let along = current.dot(forward);
+            right = Vec3::new(
+                current.x - forward.x * along,
+                current.y - forward.y * along,
+                current.z - forward.z * along,
+            )
+            .normalize();
+            if right == Vec3::ZERO {
+                right = Vec3::new(1.0, 0.0, 0.0);
+            }
+        }
+        let up = forward.cross(right);
+
+        let column = |v: Vec3, scale: f32| [v.x * scale, v.y * scale, v.z * scale, 0.0];
+        Mat4 {
+            cols: [
+                column(right, axis(0).length()),
+                column(up, axis(1).length()),
+                column(forward, axis(2).length()),
+                transform.cols[3],
+            ],
+        }
+    }
+}
+
 /// Layer cache policy
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum CachePolicy {
@@ -1348,7 +1947,7 @@ impl LayerProperties {
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// Shape used for clipping
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum ClipShape {
     /// Axis-aligned rectangle clip
     Rect(Rect),
@@ -1415,7 +2014,7 @@ impl ClipShape {
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// Camera projection type
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum CameraProjection {
     Perspective {
         fov_y: f32,
@@ -1445,7 +2044,7 @@ impl Default for CameraProjection {
 }
 
 /// Camera for 3D scenes
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Camera {
     pub position: Vec3,
     pub target: Vec3,
@@ -1486,7 +2085,7 @@ impl Camera {
 }
 
 /// Light type for 3D scenes
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Light {
     Directional {
         direction: Vec3,
@@ -1516,7 +2115,7 @@ pub enum Light {
 }
 
 /// Environment settings for 3D scenes (skybox, IBL)
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Environment {
     /// HDRI texture path (if any)
     pub hdri: Option<String>,
@@ -2166,6 +2765,187 @@ impl SceneGraph {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_billboard_faces_moving_camera() {
+        let near = |a: Vec3, b: Vec3| {
+            (a.x - b.x).abs() < 1e-4 && (a.y - b.y).abs() < 1e-4 && (a.z - b.z).abs() < 1e-4
+        };
+        let forward = |m: &Mat4| Vec3::new(m.cols[2][0], m.cols[2][1], m.cols[2][2]);
+        let transform = Mat4::translation(2.0, 1.0, -3.0).mul(&Mat4::scale(2.0, 2.0, 2.0));
+        let position = Vec3::new(2.0, 1.0, -3.0);
+
+        let mut angle = 0.0f32;
+        for step in 0..8 {
+            // Orbit the camera around the billboard while rising
+            angle += 0.9;
+            let camera = Vec3::new(
+                position.x + 6.0 * angle.cos(),
+                position.y + step as f32 - 3.0,
+                position.z + 6.0 * angle.sin(),
+            );
+            let to_camera = Vec3::new(
+                camera.x - position.x,
+                camera.y - position.y,
+                camera.z - position.z,
+            )
+            .normalize();
+
+            let full = BillboardFacing::Camera.orient(transform, camera);
+            assert!(near(forward(&full).normalize(), to_camera), "step {step}");
+            // Scale and position are kept
+            assert!((forward(&full).length() - 2.0).abs() < 1e-4);
+            assert_eq!(full.cols[3], transform.cols[3]);
+
+            // Upright mode only turns around Y
+            let upright = BillboardFacing::CameraY.orient(transform, camera);
+            let flat = Vec3::new(to_camera.x, 0.0, to_camera.z).normalize();
+            assert!(near(forward(&upright).normalize(), flat), "step {step}");
+            assert!(near(
+                Vec3::new(upright.cols[1][0], upright.cols[1][1], upright.cols[1][2]),
+                Vec3::new(0.0, 2.0, 0.0)
+            ));
+        }
+
+        assert_eq!(
+            BillboardFacing::Fixed.orient(transform, Vec3::new(9.0, 9.0, 9.0)),
+            transform
+        );
+        // Straight above: upright billboards keep their rotation
+        assert_eq!(
+            BillboardFacing::CameraY.orient(transform, Vec3::new(2.0, 10.0, -3.0)),
+            transform
+        );
+        let top_down = BillboardFacing::Camera.orient(transform, Vec3::new(2.0, 10.0, -3.0));
+        assert!(near(forward(&top_down).normalize(), Vec3::UP));
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        assert!((Color::BLACK.contrast_ratio(&Color::WHITE) - 21.0).abs() < 0.01);
+        assert!((Color::WHITE.contrast_ratio(&Color::BLACK) - 21.0).abs() < 0.01);
+        assert_eq!(Color::WHITE.contrast_ratio(&Color::WHITE), 1.0);
+        // #767676 is the classic 4.5:1 gray on white
+        let gray = Color::from_hex(0x767676);
+        assert!((gray.contrast_ratio(&Color::WHITE) - 4.54).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_color_from_hex_str() {
+        let orange = Color::rgb(1.0, 136.0 / 255.0, 0.0);
+        assert_eq!(Color::from_hex_str("#ff8800"), Ok(orange));
+        assert_eq!(Color::from_hex_str("#FF8800").unwrap().to_hex(), "#ff8800");
+        assert_eq!(Color::from_hex_str("f80"), Ok(orange));
+        assert_eq!(
+            Color::from_hex_str("#f808"),
+            Ok(orange.with_alpha(136.0 / 255.0))
+        );
+        assert_eq!(
+            Color::from_hex_str("#ff880080").unwrap().to_hex(),
+            "#ff880080"
+        );
+
+        for bad in ["#ff8800f", "#ff880", "#gg8800", "", "#"] {
+            assert!(matches!(
+                Color::from_hex_str(bad),
+                Err(ColorParseError::InvalidHex(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_color_from_css() {
+        let orange = Color::rgb(1.0, 136.0 / 255.0, 0.0);
+        assert_eq!(Color::from_css("rgb(255, 136, 0)"), Ok(orange));
+        assert_eq!(Color::from_css("RGB(255 136 0)"), Ok(orange));
+        assert_eq!(
+            Color::from_css("rgba(255, 136, 0, 0.5)"),
+            Ok(orange.with_alpha(0.5))
+        );
+        assert_eq!(
+            Color::from_css("rgb(255 136 0 / 50%)"),
+            Ok(orange.with_alpha(0.5))
+        );
+        // Out-of-range values clamp
+        assert_eq!(
+            Color::from_css("rgb(300, -5, 0, 2)"),
+            Ok(Color::rgb(1.0, 0.0, 0.0))
+        );
+
+        let red = Color::from_css("hsl(0, 100%, 50%)").unwrap();
+        assert_eq!(red.to_hex(), "#ff0000");
+        assert_eq!(
+            Color::from_css("hsla(120deg 100% 25% / 0.5)")
+                .unwrap()
+                .to_hex(),
+            "#00800080"
+        );
+        assert_eq!(Color::from_css("transparent"), Ok(Color::TRANSPARENT));
+        assert_eq!("#fff".parse::<Color>(), Ok(Color::WHITE));
+
+        assert!(matches!(
+            Color::from_css("rgb(1, 2)"),
+            Err(ColorParseError::InvalidSyntax(_))
+        ));
+        assert!(matches!(
+            Color::from_css("rgb(1, x, 3)"),
+            Err(ColorParseError::InvalidComponent { .. })
+        ));
+        assert!(Color::from_css("cmyk(0, 0, 0, 0)").is_err());
+        assert!(Color::from_css("rebeccapurple").is_err());
+    }
+
+    #[test]
+    fn test_color_to_css() {
+        assert_eq!(Color::from_hex(0xff8800).to_css(), "rgb(255, 136, 0)");
+        assert_eq!(
+            Color::rgba(1.0, 1.0, 2.0, 0.25).to_css(),
+            "rgba(255, 255, 255, 0.25)"
+        );
+        let round_trip = Color::from_css(&Color::from_hex(0x3366cc).to_css()).unwrap();
+        assert_eq!(round_trip.to_hex(), "#3366cc");
+    }
+
+    #[test]
+    fn test_gradient_oklab_midpoint_more_saturated() {
+        let chroma = |c: Color| c.r.max(c.g).max(c.b) - c.r.min(c.g).min(c.b);
+        let srgb = Gradient::linear(
+            Point::new(0.0, 0.0),
+            Point::new(100.0, 0.0),
+            Color::rgb(0.0, 0.0, 1.0),
+            Color::rgb(1.0, 1.0, 0.0),
+        );
+        let oklab = srgb
+            .clone()
+            .with_interpolation(GradientInterpolation::Oklab);
+
+        // sRGB blue→yellow passes through flat gray
+        let gray = srgb.sample(0.5);
+        assert!(chroma(gray) < 0.01, "{:?}", gray);
+
+        let mid = oklab.sample(0.5);
+        assert!(chroma(mid) > chroma(gray) + 0.1, "{:?}", mid);
+
+        // End points are untouched
+        assert!((oklab.sample(0.0).b - 1.0).abs() < 0.001);
+        assert!((oklab.sample(1.0).r - 1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_gradient_stop_easing() {
+        let gradient = Gradient::linear_with_stops(
+            Point::new(0.0, 0.0),
+            Point::new(100.0, 0.0),
+            vec![
+                GradientStop::new(0.0, Color::BLACK).with_easing(GradientEasing::EaseIn),
+                GradientStop::new(1.0, Color::WHITE),
+            ],
+        );
+        assert!((gradient.sample(0.5).r - 0.25).abs() < 0.001);
+        assert!(gradient.has_custom_interpolation());
+        assert_eq!(GradientEasing::EaseOut.apply(0.5), 0.75);
+        assert_eq!(GradientEasing::EaseInOut.apply(0.5), 0.5);
+    }
+
     #[test]
     fn test_layer_creation() {
         let layer = Layer::empty();
@@ -33,6 +33,7 @@
 //! assert_eq!(graph.get_derived(doubled), Some(10));
 //! ```
 
+pub mod camera;
 pub mod context;
 pub mod context_state;
 pub mod draw;
@@ -40,30 +41,33 @@ pub mod events;
 pub mod fsm;
 pub mod layer;
 pub mod native_bridge;
+pub mod provide;
 pub mod reactive;
 pub mod runtime;
 pub mod store;
 pub mod value;
 
+pub use camera::{FlyController, OrbitController};
 pub use draw::{
     BlurQuality, DrawCommand, DrawContext, DrawContextExt, FontWeight, ImageId, ImageOptions,
     LayerConfig, LayerEffect, LineCap, LineJoin, MaterialId, MeshId, MeshInstance, Path,
-    PathCommand, RecordingContext, SdfBuilder, ShapeId, Stroke, TextAlign, TextBaseline, TextStyle,
-    Transform,
+    PathCommand, RecordingContext, ResourceMap, SdfBuilder, ShapeId, Stroke, TextAlign,
+    TextBaseline, TextStyle, ToneMapping, Transform,
 };
-pub use events::{Event, EventData, EventDispatcher, EventType, KeyCode, Modifiers};
+pub use events::{Event, EventData, EventDispatcher, EventType, KeyCode, Modifiers, PenState};
 pub use fsm::{FsmId, FsmRuntime, StateId, StateMachine, Transition};
 pub use layer::{
     Affine2D, BillboardFacing, BlendMode, BlurStyle, Brush, CachePolicy, Camera, CameraProjection,
-    Canvas2DCommand, Canvas2DCommands, ClipShape, Color, CornerRadius, Environment, GlassStyle,
-    Gradient, GradientSpace, GradientSpread, GradientStop, ImageBrush, ImageFit, ImagePosition,
-    Layer, LayerId, LayerIdGenerator, LayerProperties, Light, Mat4, Point, PointerEvents,
-    PostEffect, Rect, Scene3DCommand, Scene3DCommands, SceneGraph, Shadow, Size, TextureFormat,
-    UiNode, Vec2, Vec3,
+    Canvas2DCommand, Canvas2DCommands, ClipShape, Color, ColorParseError, CornerRadius,
+    Environment, GlassStyle, Gradient, GradientEasing, GradientInterpolation, GradientSpace,
+    GradientSpread, GradientStop, ImageBrush, ImageFit, ImagePosition, Layer, LayerId,
+    LayerIdGenerator, LayerProperties, Light, Mat4, PatternBrush, PatternShape, Point,
+    PointerEvents, PostEffect, Rect, Scene3DCommand, Scene3DCommands, SceneGraph, Shadow, Size,
+    TextureFormat, UiNode, Vec2, Vec3,
 };
 pub use reactive::{
-    Derived, DerivedId, DirtyFlag, Effect, EffectId, ReactiveGraph, SharedReactiveGraph, Signal,
-    SignalId, State, StatefulDepsCallback,
+    Derived, DerivedId, DirtyFlag, Effect, EffectId, ReactiveGraph, ResourceSpawner, ResourceState,
+    SharedReactiveGraph, Signal, SignalId, State, StatefulDepsCallback,
 };
 pub use runtime::BlincReactiveRuntime;
 pub use value::{
@@ -81,6 +85,9 @@ pub use context_state::{
     TreeSnapshotAny, UpdateCategory,
 };
 
+// Re-export context provider types
+pub use provide::{capture_context, provide_context, use_context, ContextGuard, ContextScope};
+
 // Re-export store types
 pub use store::{
     clear_all_stores, create_store, create_store_with, get_store_state, kv_delete, kv_get, kv_set,
@@ -0,0 +1,201 @@
+//! Blinc Context Providers - provide/inject for shared services
+//!
+//! Passes shared values (a theme, an HTTP client, a store handle) down a UI
+//! subtree without threading them through every builder. A component provides
+//! a value while its subtree is built; anything built below it reads the
+//! nearest provided value of that type.
+//!
+//! Builders run eagerly, so "below" means "built while the provider's guard is
+//! alive". Closures that run later (e.g. `on_state` callbacks) should capture
+//! the providers with [`capture_context`] and re-enter them.
+//!
+//! # Example
+//!
+//! ```rust
+//! use blinc_core::provide::{provide_context, use_context};
+//!
+//! #[derive(Clone)]
+//! struct ApiClient {
+//!     base_url: &'static str,
+//! }
+//!
+//! fn user_list() -> Option<&'static str> {
+//!     use_context::<ApiClient>().map(|api| api.base_url)
+//! }
+//!
+//! fn app() -> Option<&'static str> {
+//!     let _api = provide_context(ApiClient {
+//!         base_url: "https://api.example.com",
+//!     });
+//!     user_list()
+//! }
+//!
+//! assert_eq!(app(), Some("https://api.example.com"));
+//! assert!(use_context::<ApiClient>().is_none());
+//! ```
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// A provided value, tagged with the guard that owns it
+#[derive(Clone)]
+struct Provided {
+    id: u64,
+    type_id: TypeId,
+    value: Arc<dyn Any + Send + Sync>,
+}
+
+static NEXT_PROVIDER_ID: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    /// Providers in scope, outermost first
+    static PROVIDERS: RefCell<Vec<Provided>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Keeps a provided value in scope until dropped
+///
+/// Returned by [`provide_context`]. Guards are not `Send`: a provider is only
+/// visible on the thread that built its subtree.
+#[must_use = "the context is only provided while the guard is alive"]
+pub struct ContextGuard {
+    id: u64,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        let id = self.id;
+        PROVIDERS.with(|providers| providers.borrow_mut().retain(|p| p.id != id));
+    }
+}
+
+/// Provide `value` to everything built while the returned guard is alive
+///
+/// A closer provider of the same type shadows this one until its guard drops.
+pub fn provide_context<T: Send + Sync + 'static>(value: T) -> ContextGuard {
+    let id = NEXT_PROVIDER_ID.fetch_add(1, Ordering::Relaxed);
+    PROVIDERS.with(|providers| {
+        providers.borrow_mut().push(Provided {
+            id,
+            type_id: TypeId::of::<T>(),
+            value: Arc::new(value),
+        })
+    });
+    ContextGuard {
+        id,
+        _not_send: PhantomData,
+    }
+}
+
+/// The nearest provided `T`, or None if no provider is in scope
+pub fn use_context<T: Clone + Send + Sync + 'static>() -> Option<T> {
+    PROVIDERS.with(|providers| {
+        providers
+            .borrow()
+            .iter()
+            .rev()
+            .find(|p| p.type_id == TypeId::of::<T>())
+            .and_then(|p| p.value.downcast_ref::<T>().cloned())
+    })
+}
+
+/// The providers in scope right now, for closures that run after the build
+///
+/// ```rust
+/// use blinc_core::provide::{capture_context, provide_context, use_context};
+///
+/// let deferred = {
+///     let _theme = provide_context("dark");
+///     let scope = capture_context();
+///     move || scope.enter(use_context::<&'static str>)
+/// };
+/// assert_eq!(deferred(), Some("dark"));
+/// ```
+pub fn capture_context() -> ContextScope {
+    ContextScope {
+        providers: PROVIDERS.with(|providers| providers.borrow().clone()),
+    }
+}
+
+/// A captured set of providers (see [`capture_context`])
+#[derive(Clone)]
+pub struct ContextScope {
+    providers: Vec<Provided>,
+}
+
+impl ContextScope {
+    /// Run `f` with the captured providers in scope
+    ///
+    /// Providers already in scope stay visible underneath the captured ones.
+    pub fn enter<R>(&self, f: impl FnOnce() -> R) -> R {
+        let guards: Vec<ContextGuard> = self
+            .providers
+            .iter()
+            .map(|provided| {
+                let id = NEXT_PROVIDER_ID.fetch_add(1, Ordering::Relaxed);
+                PROVIDERS.with(|providers| {
+                    providers.borrow_mut().push(Provided {
+                        id,
+                        ..provided.clone()
+                    })
+                });
+                ContextGuard {
+                    id,
+                    _not_send: PhantomData,
+                }
+            })
+            .collect();
+        let result = f();
+        drop(guards);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Theme(&'static str);
+
+    fn grandchild() -> Option<Theme> {
+        use_context::<Theme>()
+    }
+
+    fn child() -> Option<Theme> {
+        grandchild()
+    }
+
+    #[test]
+    fn test_grandchild_reads_nearest_provider() {
+        assert_eq!(grandchild(), None);
+
+        let outer = provide_context(Theme("light"));
+        assert_eq!(child(), Some(Theme("light")));
+
+        {
+            let _inner = provide_context(Theme("dark"));
+            assert_eq!(child(), Some(Theme("dark")));
+            // Other types are unaffected
+            assert_eq!(use_context::<u32>(), None);
+        }
+        assert_eq!(child(), Some(Theme("light")));
+
+        drop(outer);
+        assert_eq!(child(), None);
+    }
+
+    #[test]
+    fn test_captured_scope_outlives_guard() {
+        let scope = {
+            let _theme = provide_context(Theme("dark"));
+            capture_context()
+        };
+        assert_eq!(grandchild(), None);
+        assert_eq!(scope.enter(child), Some(Theme("dark")));
+        assert_eq!(grandchild(), None);
+    }
+}
@@ -27,14 +27,37 @@
 //! // Update the value and rebuild UI tree
 //! counter.set_rebuild(value + 1);
 //! ```
+//!
+//! # Resources
+//!
+//! [`ReactiveGraph::create_resource`] runs an async fetcher for a source
+//! signal's value and re-runs it whenever the source changes, exposing the
+//! outcome as a signal of [`ResourceState`]. Only the latest fetch can
+//! complete: a fetch still in flight when the source changes is cancelled.
+//!
+//! ```ignore
+//! let user_id = graph.create_signal(1u32);
+//! let user = graph.create_resource(user_id, |id| async move { api::fetch_user(id).await });
+//!
+//! // Later, once per frame (the windowed runner does this for you)
+//! graph.poll_resources();
+//! match graph.get(user).unwrap() {
+//!     ResourceState::Loading => { /* spinner */ }
+//!     ResourceState::Ready(user) => { /* show user */ }
+//!     ResourceState::Err(e) => { /* show error */ }
+//! }
+//! ```
 
 use slotmap::{new_key_type, SlotMap};
 use smallvec::SmallVec;
 use std::any::Any;
 use std::cell::{Cell, RefCell};
 use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
 
 new_key_type! {
     /// Unique identifier for a signal
@@ -173,6 +196,20 @@ struct EffectNode {
     depth: u32,
 }
 
+/// Internal resource storage
+struct ResourceNode {
+    /// Signal whose changes re-run the fetcher
+    source: SignalId,
+    /// Starts a fetch for the source's current value
+    start: Box<dyn FnMut(&mut ReactiveGraph) + Send>,
+}
+
+/// A finished fetch, waiting to be written to its resource's signal
+type ResourceResult = Box<dyn FnOnce(&mut ReactiveGraph) + Send>;
+
+/// Runs resource fetches on an executor (see [`ReactiveGraph::set_resource_spawner`])
+pub type ResourceSpawner = Arc<dyn Fn(Pin<Box<dyn Future<Output = ()> + Send>>) + Send + Sync>;
+
 /// The reactive graph that manages all signals, derived values, and effects
 pub struct ReactiveGraph {
     signals: SlotMap<SignalId, SignalNode>,
@@ -186,6 +223,14 @@ pub struct ReactiveGraph {
     tracking: RefCell<Option<Vec<SignalId>>>,
     /// Global version counter
     global_version: Cell<u64>,
+    /// Resources, refetched when their source signal changes
+    resources: Vec<ResourceNode>,
+    /// Finished fetches, applied by `poll_resources`
+    resource_results: Arc<Mutex<Vec<ResourceResult>>>,
+    /// Executor for fetches (a thread per fetch if unset)
+    resource_spawner: Option<ResourceSpawner>,
+    /// Called from the fetching task when a fetch finishes
+    resource_waker: Option<Arc<dyn Fn() + Send + Sync>>,
 }
 
 impl ReactiveGraph {
@@ -199,6 +244,10 @@ impl ReactiveGraph {
             batch_depth: Cell::new(0),
             tracking: RefCell::new(None),
             global_version: Cell::new(0),
+            resources: Vec::new(),
+            resource_results: Arc::new(Mutex::new(Vec::new())),
+            resource_spawner: None,
+            resource_waker: None,
         }
     }
 
@@ -260,6 +309,8 @@ impl ReactiveGraph {
             if self.batch_depth.get() == 0 {
                 self.flush_effects();
             }
+
+            self.refetch_resources(signal.id);
         }
     }
 
@@ -423,6 +474,160 @@ impl ReactiveGraph {
         }
     }
 
+    // =========================================================================
+    // RESOURCES
+    // =========================================================================
+
+    /// Create a resource: an async value fetched from a source signal
+    ///
+    /// `fetcher` runs for the source's current value right away and again
+    /// whenever the source is set. The returned signal is `Loading` while a
+    /// fetch is in flight, then `Ready` or `Err` with its result. A fetch
+    /// still running when the source changes is cancelled (its future is
+    /// dropped), so only the latest source value's result is ever applied.
+    ///
+    /// Results are written to the signal by [`poll_resources`](Self::poll_resources).
+    pub fn create_resource<S, T, E, F, Fut>(
+        &mut self,
+        source: Signal<S>,
+        fetcher: F,
+    ) -> Signal<ResourceState<T, E>>
+    where
+        S: Clone + Send + 'static,
+        T: Send + 'static,
+        E: Send + 'static,
+        F: Fn(S) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<T, E>> + Send + 'static,
+    {
+        let state = self.create_signal(ResourceState::<T, E>::Loading);
+        let slot = Arc::new(Mutex::new(FetchSlot::default()));
+
+        let start = move |graph: &mut ReactiveGraph| {
+            let Some(value) = graph.get_untracked(source) else {
+                return;
+            };
+            let generation = {
+                let mut slot = slot.lock().unwrap();
+                slot.generation += 1;
+                // Wake the superseded fetch so it notices and drops its future
+                if let Some(waker) = slot.waker.take() {
+                    waker.wake();
+                }
+                slot.generation
+            };
+            graph.set(state, ResourceState::Loading);
+
+            let fetch = Cancellable {
+                fetch: Box::pin(fetcher(value)),
+                slot: Arc::clone(&slot),
+                generation,
+            };
+            let slot = Arc::clone(&slot);
+            let results = Arc::clone(&graph.resource_results);
+            let waker = graph.resource_waker.clone();
+            graph.spawn_fetch(Box::pin(async move {
+                let Some(result) = fetch.await else {
+                    return;
+                };
+                results.lock().unwrap().push(Box::new(move |graph| {
+                    // The source may have changed after this fetch finished
+                    if slot.lock().unwrap().generation == generation {
+                        graph.set(state, ResourceState::from(result));
+                    }
+                }));
+                if let Some(wake) = waker {
+                    wake();
+                }
+            }));
+        };
+
+        let mut node = ResourceNode {
+            source: source.id,
+            start: Box::new(start),
+        };
+        (node.start)(self);
+        self.resources.push(node);
+
+        state
+    }
+
+    /// Write finished fetches to their resource signals
+    ///
+    /// Call once per frame (or when the resource waker fires). Returns
+    /// `true` if any resource changed.
+    pub fn poll_resources(&mut self) -> bool {
+        let finished: Vec<ResourceResult> =
+            std::mem::take(&mut *self.resource_results.lock().unwrap());
+        if finished.is_empty() {
+            return false;
+        }
+        let version = self.global_version.get();
+        for apply in finished {
+            apply(self);
+        }
+        self.global_version.get() != version
+    }
+
+    /// Run resource fetches with `spawn` instead of a thread per fetch
+    ///
+    /// Pass your async runtime's spawn function (e.g. `tokio::spawn`, or
+    /// `wasm_bindgen_futures::spawn_local` on the web, where threads
+    /// aren't available).
+    pub fn set_resource_spawner<F>(&mut self, spawn: F)
+    where
+        F: Fn(Pin<Box<dyn Future<Output = ()> + Send>>) + Send + Sync + 'static,
+    {
+        self.resource_spawner = Some(Arc::new(spawn));
+    }
+
+    /// Call `wake` whenever a fetch finishes, e.g. to wake the event loop
+    /// so it calls [`poll_resources`](Self::poll_resources)
+    ///
+    /// `wake` is called from the task that ran the fetch.
+    pub fn set_resource_waker<F>(&mut self, wake: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.resource_waker = Some(Arc::new(wake));
+    }
+
+    /// Restart the fetches of resources sourced from `source`
+    fn refetch_resources(&mut self, source: SignalId) {
+        for i in 0..self.resources.len() {
+            if self.resources[i].source != source {
+                continue;
+            }
+            // Take the start function out so it can borrow the graph mutably
+            let mut start = std::mem::replace(&mut self.resources[i].start, Box::new(|_| {}));
+            start(self);
+            self.resources[i].start = start;
+        }
+    }
+
+    /// Run a fetch on the spawner, or a thread if none is set
+    fn spawn_fetch(&self, task: Pin<Box<dyn Future<Output = ()> + Send>>) {
+        if let Some(ref spawn) = self.resource_spawner {
+            spawn(task);
+            return;
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let spawned = std::thread::Builder::new()
+                .name("blinc-resource".to_string())
+                .spawn(move || block_on(task));
+            if let Err(e) = spawned {
+                tracing::warn!("Failed to start resource fetch: {}", e);
+            }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            drop(task);
+            tracing::warn!("No resource spawner set; call ReactiveGraph::set_resource_spawner");
+        }
+    }
+
     // =========================================================================
     // BATCHING
     // =========================================================================
@@ -573,6 +778,106 @@ impl Default for ReactiveGraph {
     }
 }
 
+// =============================================================================
+// RESOURCE STATE
+// =============================================================================
+
+/// The value of a resource (see [`ReactiveGraph::create_resource`])
+#[derive(Clone, Debug, PartialEq)]
+pub enum ResourceState<T, E> {
+    /// A fetch is in flight
+    Loading,
+    /// The latest fetch succeeded
+    Ready(T),
+    /// The latest fetch failed
+    Err(E),
+}
+
+impl<T, E> ResourceState<T, E> {
+    /// Whether a fetch is in flight
+    pub fn is_loading(&self) -> bool {
+        matches!(self, ResourceState::Loading)
+    }
+
+    /// The fetched value, if the latest fetch succeeded
+    pub fn ready(&self) -> Option<&T> {
+        match self {
+            ResourceState::Ready(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The error, if the latest fetch failed
+    pub fn error(&self) -> Option<&E> {
+        match self {
+            ResourceState::Err(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl<T, E> From<Result<T, E>> for ResourceState<T, E> {
+    fn from(result: Result<T, E>) -> Self {
+        match result {
+            Ok(value) => ResourceState::Ready(value),
+            Err(error) => ResourceState::Err(error),
+        }
+    }
+}
+
+/// The latest fetch of a resource, shared with its in-flight tasks
+#[derive(Default)]
+struct FetchSlot {
+    /// Bumped each time a fetch starts; older fetches are cancelled
+    generation: u64,
+    /// Waker of the in-flight fetch
+    waker: Option<Waker>,
+}
+
+/// A fetch that resolves to None once a newer fetch has started
+struct Cancellable<F> {
+    fetch: Pin<Box<F>>,
+    slot: Arc<Mutex<FetchSlot>>,
+    generation: u64,
+}
+
+impl<F: Future> Future for Cancellable<F> {
+    type Output = Option<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        {
+            let mut slot = self.slot.lock().unwrap();
+            if slot.generation != self.generation {
+                return Poll::Ready(None);
+            }
+            slot.waker = Some(cx.waker().clone());
+        }
+        self.fetch.as_mut().poll(cx).map(Some)
+    }
+}
+
+/// Drive a future to completion on the current thread
+#[cfg(not(target_arch = "wasm32"))]
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(std::thread::Thread);
+
+    impl std::task::Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let mut future = std::pin::pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        std::thread::park();
+    }
+}
+
 /// Statistics about the reactive graph
 #[derive(Debug, Clone)]
 pub struct ReactiveStats {
@@ -730,6 +1035,7 @@ impl<T: Clone + Send + 'static> State<T> {
 mod tests {
     use super::*;
     use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll, Waker};
 
     #[test]
     fn test_signal_create_get_set() {
@@ -903,4 +1209,88 @@ mod tests {
         assert_eq!(stats.signal_count, 2);
         assert_eq!(stats.derived_count, 1);
     }
+
+    /// A fetch that waits until its gate is opened, and records being dropped
+    struct GatedFetch {
+        gate: Arc<Mutex<(bool, Option<Waker>)>>,
+        value: u32,
+        dropped: Arc<Mutex<Vec<u32>>>,
+    }
+
+    impl Future for GatedFetch {
+        type Output = Result<u32, String>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let mut gate = self.gate.lock().unwrap();
+            if gate.0 {
+                Poll::Ready(Ok(self.value * 10))
+            } else {
+                gate.1 = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+
+    impl Drop for GatedFetch {
+        fn drop(&mut self) {
+            self.dropped.lock().unwrap().push(self.value);
+        }
+    }
+
+    #[test]
+    fn test_resource_keeps_latest_fetch() {
+        type Gate = Arc<Mutex<(bool, Option<Waker>)>>;
+        let gates: Vec<Gate> = (0..4)
+            .map(|_| Arc::new(Mutex::new((false, None))))
+            .collect();
+        let open = |gate: &Gate| {
+            let mut gate = gate.lock().unwrap();
+            gate.0 = true;
+            if let Some(waker) = gate.1.take() {
+                waker.wake();
+            }
+        };
+        let dropped = Arc::new(Mutex::new(Vec::new()));
+
+        let mut graph = ReactiveGraph::new();
+        let source = graph.create_signal(1u32);
+        let resource = graph.create_resource(source, {
+            let gates = gates.clone();
+            let dropped = Arc::clone(&dropped);
+            move |value: u32| GatedFetch {
+                gate: Arc::clone(&gates[value as usize]),
+                value,
+                dropped: Arc::clone(&dropped),
+            }
+        });
+        assert!(graph.get(resource).unwrap().is_loading());
+
+        // Change the source twice before the first fetch finishes
+        graph.set(source, 2);
+        graph.set(source, 3);
+        open(&gates[3]);
+
+        let wait_for = |graph: &mut ReactiveGraph, done: &dyn Fn(&ReactiveGraph) -> bool| {
+            let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+            while !done(graph) {
+                assert!(std::time::Instant::now() < deadline, "timed out");
+                graph.poll_resources();
+                std::thread::sleep(std::time::Duration::from_millis(1));
+            }
+        };
+        wait_for(&mut graph, &|g| {
+            g.get(resource) == Some(ResourceState::Ready(30))
+        });
+
+        // The superseded fetches were cancelled, and finishing them late
+        // doesn't overwrite the latest result
+        open(&gates[1]);
+        open(&gates[2]);
+        wait_for(&mut graph, &|_| dropped.lock().unwrap().len() == 3);
+        let mut dropped_values = dropped.lock().unwrap().clone();
+        dropped_values.sort();
+        assert_eq!(dropped_values, [1, 2, 3]);
+        assert!(!graph.poll_resources());
+        assert_eq!(graph.get(resource), Some(ResourceState::Ready(30)));
+    }
 }
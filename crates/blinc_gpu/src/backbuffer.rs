@@ -121,7 +121,8 @@ impl Backbuffer {
                     format: config.format,
                     usage: wgpu::TextureUsages::RENDER_ATTACHMENT
                         | wgpu::TextureUsages::TEXTURE_BINDING
-                        | wgpu::TextureUsages::COPY_SRC,
+                        | wgpu::TextureUsages::COPY_SRC
+                        | wgpu::TextureUsages::COPY_DST,
                     view_formats: &[],
                 });
 
@@ -217,6 +218,38 @@ impl Backbuffer {
         self.write_index = (self.write_index + 1) % buffer_count;
     }
 
+    /// Copy the previous frame into the write buffer
+    ///
+    /// Call before a damaged-region redraw (see
+    /// [`GpuRenderer::set_damage_rect`](crate::GpuRenderer::set_damage_rect))
+    /// so everything outside the damage region shows the last frame. No-op
+    /// with a single buffer, which already retains its content.
+    pub fn carry_over(&self, encoder: &mut wgpu::CommandEncoder) {
+        if self.read_index == self.write_index {
+            return;
+        }
+
+        encoder.copy_texture_to_texture(
+            wgpu::ImageCopyTexture {
+                texture: &self.buffers[self.read_index].texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyTexture {
+                texture: &self.buffers[self.write_index].texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::Extent3d {
+                width: self.width,
+                height: self.height,
+                depth_or_array_layers: 1,
+            },
+        );
+    }
+
     /// Copy the current write buffer to the swapchain
     ///
     /// This is used to present the final rendered frame to the screen.
@@ -0,0 +1,336 @@
+//! Frame capture - read a rendered frame back to the CPU
+//!
+//! [`GpuRenderer::capture_frame`] copies a render target (usually the
+//! swapchain texture, before it is presented) into a readback buffer and
+//! converts it to a straight-alpha RGBA image, for screenshots and visual
+//! tests.
+//!
+//! The texture must have been created with `TextureUsages::COPY_SRC`. On the
+//! web, buffer mapping completes on the browser's event loop, so
+//! `capture_frame` is async there and must be awaited.
+
+use std::fmt;
+
+use image::RgbaImage;
+
+use crate::renderer::GpuRenderer;
+
+/// Error reading a frame back from the GPU
+#[derive(Debug)]
+pub enum CaptureError {
+    /// The texture format can't be converted to RGBA8
+    UnsupportedFormat(wgpu::TextureFormat),
+    /// Mapping the readback buffer failed
+    Map(wgpu::BufferAsyncError),
+}
+
+impl fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CaptureError::UnsupportedFormat(format) => {
+                write!(f, "Cannot capture texture format {:?}", format)
+            }
+            CaptureError::Map(e) => write!(f, "Failed to map readback buffer: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for CaptureError {}
+
+/// Channel order of a capturable texture
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ChannelOrder {
+    Rgba,
+    Bgra,
+}
+
+impl ChannelOrder {
+    fn of(format: wgpu::TextureFormat) -> Result<Self, CaptureError> {
+        match format {
+            wgpu::TextureFormat::Rgba8Unorm | wgpu::TextureFormat::Rgba8UnormSrgb => {
+                Ok(ChannelOrder::Rgba)
+            }
+            wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb => {
+                Ok(ChannelOrder::Bgra)
+            }
+            other => Err(CaptureError::UnsupportedFormat(other)),
+        }
+    }
+}
+
+/// A copy of a texture in flight to a mappable buffer
+struct Readback {
+    buffer: wgpu::Buffer,
+    padded_bytes_per_row: u32,
+    width: u32,
+    height: u32,
+    order: ChannelOrder,
+}
+
+impl Readback {
+    /// Record and submit the texture-to-buffer copy
+    fn submit(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        texture: &wgpu::Texture,
+    ) -> Result<Self, CaptureError> {
+        let order = ChannelOrder::of(texture.format())?;
+        let (width, height) = (texture.width(), texture.height());
+
+        let unpadded_bytes_per_row = width * 4;
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padded_bytes_per_row = unpadded_bytes_per_row.div_ceil(align) * align;
+
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Frame Capture Buffer"),
+            size: (padded_bytes_per_row * height) as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Frame Capture Encoder"),
+        });
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_bytes_per_row),
+                    rows_per_image: Some(height),
+                },
+            },
+            texture.size(),
+        );
+        queue.submit(std::iter::once(encoder.finish()));
+
+        Ok(Self {
+            buffer,
+            padded_bytes_per_row,
+            width,
+            height,
+            order,
+        })
+    }
+
+    /// Convert the mapped buffer to an image and unmap it
+    fn finish(self) -> RgbaImage {
+        let image = {
+            let data = self.buffer.slice(..).get_mapped_range();
+            to_image(
+                &data,
+                self.padded_bytes_per_row,
+                self.width,
+                self.height,
+                self.order,
+            )
+        };
+        self.buffer.unmap();
+        image
+    }
+}
+
+/// Strip row padding, reorder channels and un-premultiply alpha
+fn to_image(
+    data: &[u8],
+    padded_bytes_per_row: u32,
+    width: u32,
+    height: u32,
+    order: ChannelOrder,
+) -> RgbaImage {
+    let mut pixels = Vec::with_capacity((width * height * 4) as usize);
+    for row in data
+        .chunks(padded_bytes_per_row as usize)
+        .take(height as usize)
+    {
+        for px in row[..(width * 4) as usize].chunks_exact(4) {
+            let (r, g, b, a) = match order {
+                ChannelOrder::Rgba => (px[0], px[1], px[2], px[3]),
+                ChannelOrder::Bgra => (px[2], px[1], px[0], px[3]),
+            };
+            pixels.extend_from_slice(&[
+                unpremultiply(r, a),
+                unpremultiply(g, a),
+                unpremultiply(b, a),
+                a,
+            ]);
+        }
+    }
+    RgbaImage::from_raw(width, height, pixels).expect("capture buffer size matches image")
+}
+
+/// Undo premultiplied alpha for one channel
+fn unpremultiply(c: u8, a: u8) -> u8 {
+    match a {
+        0 | 255 => c,
+        a => ((c as u32 * 255 + a as u32 / 2) / a as u32).min(255) as u8,
+    }
+}
+
+impl GpuRenderer {
+    /// Read `texture` back as a straight-alpha RGBA image
+    ///
+    /// Call after rendering the frame and before presenting it. Blocks until
+    /// the GPU has finished the copy.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn capture_frame(&self, texture: &wgpu::Texture) -> Result<RgbaImage, CaptureError> {
+        let readback = Readback::submit(self.device(), self.queue(), texture)?;
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        readback
+            .buffer
+            .slice(..)
+            .map_async(wgpu::MapMode::Read, move |result| {
+                let _ = tx.send(result);
+            });
+        self.device().poll(wgpu::Maintain::Wait);
+        rx.recv()
+            .expect("map_async callback runs during poll")
+            .map_err(CaptureError::Map)?;
+
+        Ok(readback.finish())
+    }
+
+    /// Read `texture` back as a straight-alpha RGBA image
+    ///
+    /// Call after rendering the frame and before presenting it. Resolves once
+    /// the browser has mapped the readback buffer.
+    #[cfg(target_arch = "wasm32")]
+    pub async fn capture_frame(&self, texture: &wgpu::Texture) -> Result<RgbaImage, CaptureError> {
+        let readback = Readback::submit(self.device(), self.queue(), texture)?;
+
+        let mapped = MapFuture::default();
+        let shared = mapped.shared.clone();
+        readback
+            .buffer
+            .slice(..)
+            .map_async(wgpu::MapMode::Read, move |result| {
+                let mut state = shared.lock().unwrap();
+                state.0 = Some(result);
+                if let Some(waker) = state.1.take() {
+                    waker.wake();
+                }
+            });
+        mapped.await.map_err(CaptureError::Map)?;
+
+        Ok(readback.finish())
+    }
+}
+
+/// Resolves with the result of a `map_async` callback
+#[cfg(target_arch = "wasm32")]
+#[derive(Default)]
+struct MapFuture {
+    #[allow(clippy::type_complexity)]
+    shared: std::sync::Arc<
+        std::sync::Mutex<(
+            Option<Result<(), wgpu::BufferAsyncError>>,
+            Option<std::task::Waker>,
+        )>,
+    >,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl std::future::Future for MapFuture {
+    type Output = Result<(), wgpu::BufferAsyncError>;
+
+    fn poll(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        let mut state = self.shared.lock().unwrap();
+        match state.0.take() {
+            Some(result) => std::task::Poll::Ready(result),
+            None => {
+                state.1 = Some(cx.waker().clone());
+                std::task::Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::{GpuPrimitive, PrimitiveBatch};
+    use crate::renderer::RendererConfig;
+
+    #[test]
+    fn test_to_image_strips_padding_and_unpremultiplies() {
+        // 2x2 BGRA with 4 bytes of row padding
+        let data = [
+            0, 0, 255, 255, /* */ 0, 64, 0, 128, /* */ 9, 9, 9, 9, //
+            255, 0, 0, 255, /* */ 0, 0, 0, 0, /* */ 9, 9, 9, 9,
+        ];
+        let image = to_image(&data, 12, 2, 2, ChannelOrder::Bgra);
+        assert_eq!(image.dimensions(), (2, 2));
+        assert_eq!(image.get_pixel(0, 0).0, [255, 0, 0, 255]);
+        assert_eq!(image.get_pixel(1, 0).0, [0, 128, 0, 128]);
+        assert_eq!(image.get_pixel(0, 1).0, [0, 0, 255, 255]);
+        assert_eq!(image.get_pixel(1, 1).0, [0, 0, 0, 0]);
+
+        let image = to_image(&data[..8], 8, 2, 1, ChannelOrder::Rgba);
+        assert_eq!(image.get_pixel(0, 0).0, [0, 0, 255, 255]);
+        assert!(matches!(
+            ChannelOrder::of(wgpu::TextureFormat::Rgba16Float),
+            Err(CaptureError::UnsupportedFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_capture_rendered_frame() {
+        let Ok(mut renderer) = pollster::block_on(GpuRenderer::new(RendererConfig {
+            texture_format: Some(wgpu::TextureFormat::Rgba8Unorm),
+            ..Default::default()
+        })) else {
+            // Skip test if no GPU available
+            return;
+        };
+        let (width, height) = (64, 32);
+        let texture = renderer.device().create_texture(&wgpu::TextureDescriptor {
+            label: Some("Capture Test Target"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: renderer.texture_format(),
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        // Red left half, blue right half
+        let mut batch = PrimitiveBatch::new();
+        batch.push(GpuPrimitive::rect(0.0, 0.0, 32.0, 32.0).with_color(1.0, 0.0, 0.0, 1.0));
+        batch.push(GpuPrimitive::rect(32.0, 0.0, 32.0, 32.0).with_color(0.0, 0.0, 1.0, 1.0));
+        renderer.resize(width, height);
+        renderer.render_with_clear(&view, &batch, [0.0, 0.0, 0.0, 1.0]);
+
+        let image = renderer.capture_frame(&texture).unwrap();
+        assert_eq!(image.dimensions(), (width, height));
+        let near = |(x, y): (u32, u32), expected: [u8; 4]| {
+            let actual = image.get_pixel(x, y).0;
+            assert!(
+                actual
+                    .iter()
+                    .zip(expected)
+                    .all(|(a, e)| (*a as i32 - e as i32).abs() <= 2),
+                "pixel ({x}, {y}) is {actual:?}, expected {expected:?}"
+            );
+        };
+        near((8, 16), [255, 0, 0, 255]);
+        near((24, 4), [255, 0, 0, 255]);
+        near((40, 16), [0, 0, 255, 255]);
+        near((60, 28), [0, 0, 255, 255]);
+    }
+}
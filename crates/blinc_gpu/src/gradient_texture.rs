@@ -8,7 +8,7 @@
 //! - The shader samples from this texture using the gradient parameter t
 //! - A placeholder texture is used for 2-stop gradients (fast path)
 
-use blinc_core::{Color, GradientStop};
+use blinc_core::{Color, GradientInterpolation, GradientStop};
 use lru::LruCache;
 use std::hash::{Hash, Hasher};
 use std::num::NonZeroUsize;
@@ -41,7 +41,13 @@ pub struct RasterizedGradient {
 
 impl RasterizedGradient {
     /// Rasterize a gradient with multiple stops into a 256-wide texture
-    pub fn from_stops(stops: &[GradientStop], spread: SpreadMode) -> Self {
+    ///
+    /// Stop easing and the interpolation space are baked into the texels.
+    pub fn from_stops(
+        stops: &[GradientStop],
+        spread: SpreadMode,
+        interpolation: GradientInterpolation,
+    ) -> Self {
         let mut pixels = [0u8; GRADIENT_TEXTURE_WIDTH as usize * 4];
 
         if stops.is_empty() {
@@ -81,7 +87,7 @@ impl RasterizedGradient {
             let t = apply_spread_mode(t, spread);
 
             // Find the two stops that bracket t
-            let color = sample_gradient(stops, t);
+            let color = sample_gradient(stops, t, interpolation);
 
             pixels[i * 4] = (color.r * 255.0).clamp(0.0, 255.0) as u8;
             pixels[i * 4 + 1] = (color.g * 255.0).clamp(0.0, 255.0) as u8;
@@ -97,17 +103,8 @@ impl RasterizedGradient {
 
     /// Create a simple 2-stop gradient
     pub fn two_stop(start: Color, end: Color) -> Self {
-        let stops = [
-            GradientStop {
-                offset: 0.0,
-                color: start,
-            },
-            GradientStop {
-                offset: 1.0,
-                color: end,
-            },
-        ];
-        Self::from_stops(&stops, SpreadMode::Pad)
+        let stops = [GradientStop::new(0.0, start), GradientStop::new(1.0, end)];
+        Self::from_stops(&stops, SpreadMode::Pad, GradientInterpolation::Srgb)
     }
 }
 
@@ -128,7 +125,7 @@ fn apply_spread_mode(t: f32, spread: SpreadMode) -> f32 {
 }
 
 /// Sample a gradient at parameter t
-fn sample_gradient(stops: &[GradientStop], t: f32) -> Color {
+fn sample_gradient(stops: &[GradientStop], t: f32, interpolation: GradientInterpolation) -> Color {
     if stops.is_empty() {
         return Color::TRANSPARENT;
     }
@@ -153,8 +150,11 @@ fn sample_gradient(stops: &[GradientStop], t: f32) -> Color {
                 return s0.color;
             }
 
-            let local_t = (t - s0.offset) / range;
-            return lerp_color(&s0.color, &s1.color, local_t);
+            let local_t = s0.easing.apply((t - s0.offset) / range);
+            return match interpolation {
+                GradientInterpolation::Srgb => lerp_color(&s0.color, &s1.color, local_t),
+                GradientInterpolation::Oklab => Color::lerp_oklab(&s0.color, &s1.color, local_t),
+            };
         }
     }
 
@@ -172,13 +172,18 @@ fn lerp_color(a: &Color, b: &Color, t: f32) -> Color {
     }
 }
 
-/// Compute a hash for gradient stops (offset + RGBA values)
-fn hash_gradient_stops(stops: &[GradientStop], spread: SpreadMode) -> u64 {
+/// Compute a hash for gradient stops (offset + RGBA values + easing)
+fn hash_gradient_stops(
+    stops: &[GradientStop],
+    spread: SpreadMode,
+    interpolation: GradientInterpolation,
+) -> u64 {
     use std::collections::hash_map::DefaultHasher;
     let mut hasher = DefaultHasher::new();
 
-    // Hash spread mode
+    // Hash spread mode and interpolation space
     (spread as u8).hash(&mut hasher);
+    interpolation.hash(&mut hasher);
 
     // Hash each stop's offset and color components
     for stop in stops {
@@ -188,6 +193,7 @@ fn hash_gradient_stops(stops: &[GradientStop], spread: SpreadMode) -> u64 {
         stop.color.g.to_bits().hash(&mut hasher);
         stop.color.b.to_bits().hash(&mut hasher);
         stop.color.a.to_bits().hash(&mut hasher);
+        stop.easing.hash(&mut hasher);
     }
 
     hasher.finish()
@@ -280,8 +286,9 @@ impl GradientTextureCache {
         queue: &wgpu::Queue,
         stops: &[GradientStop],
         spread: SpreadMode,
+        interpolation: GradientInterpolation,
     ) -> bool {
-        let hash = hash_gradient_stops(stops, spread);
+        let hash = hash_gradient_stops(stops, spread, interpolation);
 
         // Skip upload if this gradient is already on the GPU
         if self.current_hash == Some(hash) {
@@ -294,7 +301,7 @@ impl GradientTextureCache {
                 cached.as_ref()
             } else {
                 // Rasterize and cache
-                let rasterized = RasterizedGradient::from_stops(stops, spread);
+                let rasterized = RasterizedGradient::from_stops(stops, spread, interpolation);
                 self.rasterized_cache.put(hash, Box::new(rasterized.pixels));
                 self.rasterized_cache.get(&hash).unwrap().as_ref()
             };
@@ -384,21 +391,13 @@ mod tests {
     #[test]
     fn test_multi_stop_gradient() {
         let stops = vec![
-            GradientStop {
-                offset: 0.0,
-                color: Color::RED,
-            },
-            GradientStop {
-                offset: 0.5,
-                color: Color::GREEN,
-            },
-            GradientStop {
-                offset: 1.0,
-                color: Color::BLUE,
-            },
+            GradientStop::new(0.0, Color::RED),
+            GradientStop::new(0.5, Color::GREEN),
+            GradientStop::new(1.0, Color::BLUE),
         ];
 
-        let gradient = RasterizedGradient::from_stops(&stops, SpreadMode::Pad);
+        let gradient =
+            RasterizedGradient::from_stops(&stops, SpreadMode::Pad, GradientInterpolation::Srgb);
         assert_eq!(gradient.stop_count, 3);
 
         // First pixel should be red
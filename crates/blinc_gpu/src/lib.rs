@@ -13,8 +13,10 @@
 //! - **Backbuffer**: Double/triple buffering for WASM and glass effects
 //! - **Paint Context**: GPU-backed DrawContext implementation
 //! - **Path Rendering**: Vector path tessellation via lyon
+//! - **Frame Capture**: Read rendered frames back as RGBA images
 
 pub mod backbuffer;
+pub mod capture;
 pub mod gradient_texture;
 pub mod image;
 pub mod paint;
@@ -25,6 +27,7 @@ pub mod shaders;
 pub mod text;
 
 pub use backbuffer::{Backbuffer, BackbufferConfig, FrameContext};
+pub use capture::CaptureError;
 pub use gradient_texture::{GradientTextureCache, RasterizedGradient, GRADIENT_TEXTURE_WIDTH};
 pub use image::{GpuImage, GpuImageInstance, ImageRenderingContext};
 pub use paint::GpuPaintContext;
@@ -33,18 +36,21 @@ pub use path::{
     PathVertex, TessellatedPath,
 };
 pub use primitives::{
-    BlurUniforms, ClipType, ColorMatrixUniforms, CompositeUniforms, DropShadowUniforms, FillType,
-    GlassType, GlassUniforms, GlowUniforms, GpuGlassPrimitive, GpuGlyph, GpuPrimitive,
-    LayerCommand, LayerCommandEntry, LayerCompositeUniforms, PathBatch, PathUniforms,
-    PrimitiveBatch, PrimitiveType, Uniforms,
+    BlurUniforms, CachedLayerBatch, ClipType, ColorMatrixUniforms, CompositeUniforms,
+    DropShadowUniforms, FillType, GlassType, GlassUniforms, GlowUniforms, GpuGlassPrimitive,
+    GpuGlyph, GpuPrimitive, LayerCommand, LayerCommandEntry, LayerCompositeUniforms, PathBatch,
+    PathUniforms, PostProcessMode, PostProcessUniforms, PrimitiveBatch, PrimitiveType, Uniforms,
 };
-pub use renderer::{GpuRenderer, LayerTexture, LayerTextureCache, RendererConfig};
+pub use renderer::{ColorSpace, GpuRenderer, LayerTexture, LayerTextureCache, RendererConfig};
 pub use shaders::{
     BLUR_SHADER, COLOR_MATRIX_SHADER, COMPOSITE_SHADER, DROP_SHADOW_SHADER, GLASS_SHADER,
-    GLOW_SHADER, IMAGE_SHADER, LAYER_COMPOSITE_SHADER, PATH_SHADER, SDF_SHADER,
-    SIMPLE_GLASS_SHADER, TEXT_SHADER,
+    GLOW_SHADER, IMAGE_SHADER, LAYER_COMPOSITE_SHADER, PATH_SHADER, POST_PROCESS_SHADER,
+    SDF_SHADER, SIMPLE_GLASS_SHADER, TEXT_SHADER,
 };
-pub use text::TextRenderingContext;
+pub use text::{SharedGlyphAtlas, TextRenderingContext};
 
 // Re-export text types for convenience
 pub use blinc_text::{ColorSpan, FontRegistry, GenericFont, TextAlignment, TextAnchor};
+
+// Re-export the image type returned by frame capture
+pub use ::image::RgbaImage;
@@ -41,6 +41,8 @@
 //! renderer.render(&target, &batch);
 //! ```
 
+use std::collections::HashSet;
+
 use blinc_core::{
     Affine2D, BillboardFacing, BlendMode, Brush, Camera, ClipShape, Color, CornerRadius,
     DrawCommand, DrawContext, Environment, ImageId, ImageOptions, LayerConfig, LayerId, Light,
@@ -48,9 +50,12 @@ use blinc_core::{
     Stroke, TextStyle, Transform,
 };
 
+use blinc_paint::dash_path;
+
 use crate::path::{extract_brush_info, tessellate_fill, tessellate_stroke};
 use crate::primitives::{
-    ClipType, FillType, GlassType, GpuGlassPrimitive, GpuPrimitive, PrimitiveBatch, PrimitiveType,
+    gradient_fill_flags, CachedLayerBatch, ClipType, FillType, GlassType, GpuGlassPrimitive,
+    GpuPrimitive, PrimitiveBatch, PrimitiveType,
 };
 use crate::text::TextRenderingContext;
 
@@ -102,6 +107,27 @@ struct LayerState {
     foreground_path_start: usize,
     /// Parent state stack indices (transform, opacity, blend, clip)
     parent_state_indices: (usize, usize, usize, usize),
+    /// Whether the layer is drawn into its own batch for caching
+    /// (see [`CachedLayerState`])
+    cached: bool,
+}
+
+/// Parent state set aside while a cached layer is drawn into its own batch
+///
+/// Cached content is drawn without the clips and opacity around it, which
+/// are applied when the cached texture is composited instead, so scrolling
+/// or fading the layer doesn't bake into the texture.
+struct CachedLayerState {
+    id: LayerId,
+    /// Layer rect in screen pixels
+    anchor: Rect,
+    /// Layer size in its own coordinates
+    size: Size,
+    /// Z-layer the layer was pushed at, where it is composited
+    z_layer: u32,
+    parent_batch: PrimitiveBatch,
+    clip_stack: Vec<ClipShape>,
+    opacity_stack: Vec<f32>,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -137,6 +163,10 @@ pub struct GpuPaintContext<'a> {
     z_layer: u32,
     /// Stack of active layers for offscreen rendering
     layer_stack: Vec<LayerState>,
+    /// Parent state of the cached layers being drawn, innermost last
+    cached_layer_stack: Vec<CachedLayerState>,
+    /// Cached layers the renderer holds textures for
+    held_layers: HashSet<LayerId>,
 }
 
 impl<'a> GpuPaintContext<'a> {
@@ -155,6 +185,8 @@ impl<'a> GpuPaintContext<'a> {
             is_foreground: false,
             z_layer: 0,
             layer_stack: Vec::new(),
+            cached_layer_stack: Vec::new(),
+            held_layers: HashSet::new(),
         }
     }
 
@@ -185,9 +217,37 @@ impl<'a> GpuPaintContext<'a> {
             is_foreground: false,
             z_layer: 0,
             layer_stack: Vec::new(),
+            cached_layer_stack: Vec::new(),
+            held_layers: HashSet::new(),
         }
     }
 
+    /// Record a composite of the `source` part of a cached layer (in the
+    /// layer's own coordinates) into `dest` (screen pixels) at `z_layer`,
+    /// with the current opacity and clip
+    fn push_sample(&mut self, id: LayerId, source: Rect, dest: Rect, z_layer: u32) {
+        let (clip_bounds, clip_radius, clip_type) = self.get_clip_data();
+        let clip = (clip_type != ClipType::None).then_some((clip_bounds, clip_radius));
+        self.batch
+            .push_layer_command(crate::primitives::LayerCommand::Sample {
+                id,
+                source,
+                dest,
+                opacity: self.combined_opacity(),
+                clip,
+                z_layer,
+            });
+    }
+
+    /// Set the cached layers the renderer holds textures for
+    ///
+    /// Typically [`GpuRenderer::cached_layer_ids`](crate::GpuRenderer::cached_layer_ids).
+    /// [`DrawContext::has_cached_layer`] reports only these as held, so
+    /// everything else is drawn (and rasterized) again.
+    pub fn set_cached_layers(&mut self, ids: impl IntoIterator<Item = LayerId>) {
+        self.held_layers = ids.into_iter().collect();
+    }
+
     /// Set the text rendering context
     pub fn set_text_context(&mut self, text_ctx: &'a mut TextRenderingContext) {
         self.text_ctx = Some(text_ctx);
@@ -453,6 +513,13 @@ impl<'a> GpuPaintContext<'a> {
                 // Return transparent as a fallback (should never be used)
                 ([0.0; 4], [0.0; 4], [0.0, 0.0, 1.0, 0.0], FillType::Solid)
             }
+            Brush::Pattern(pattern) => {
+                // Tiled content can't be drawn by a single primitive; use
+                // its dominant color
+                let color = pattern.fallback_color();
+                let c = [color.r, color.g, color.b, color.a * opacity];
+                (c, c, [0.0, 0.0, 1.0, 0.0], FillType::Solid)
+            }
             Brush::Gradient(gradient) => {
                 let (stops, fill_type, gradient_params) = match gradient {
                     blinc_core::Gradient::Linear {
@@ -533,40 +600,6 @@ impl<'a> GpuPaintContext<'a> {
         let mut intersect_max_y = f32::INFINITY;
         let mut has_rect_clips = false;
 
-        // Track corner radii with their source bounds
-        // Each corner's radius is only valid if the intersection edge matches the source edge
-        // Format: (radius, source_min_x, source_min_y, source_max_x, source_max_y)
-        let mut corner_sources: [(f32, f32, f32, f32, f32); 4] = [
-            (
-                0.0,
-                f32::NEG_INFINITY,
-                f32::NEG_INFINITY,
-                f32::INFINITY,
-                f32::INFINITY,
-            ), // top_left
-            (
-                0.0,
-                f32::NEG_INFINITY,
-                f32::NEG_INFINITY,
-                f32::INFINITY,
-                f32::INFINITY,
-            ), // top_right
-            (
-                0.0,
-                f32::NEG_INFINITY,
-                f32::NEG_INFINITY,
-                f32::INFINITY,
-                f32::INFINITY,
-            ), // bottom_right
-            (
-                0.0,
-                f32::NEG_INFINITY,
-                f32::NEG_INFINITY,
-                f32::INFINITY,
-                f32::INFINITY,
-            ), // bottom_left
-        ];
-
         // Track whether the topmost clip is a plain Rect (not rounded)
         let mut topmost_is_plain_rect = false;
 
@@ -581,10 +614,7 @@ impl<'a> GpuPaintContext<'a> {
                     has_rect_clips = true;
                     topmost_is_plain_rect = true;
                 }
-                ClipShape::RoundedRect {
-                    rect,
-                    corner_radius,
-                } => {
+                ClipShape::RoundedRect { rect, .. } => {
                     let rx = rect.x();
                     let ry = rect.y();
                     let rmax_x = rect.x() + rect.width();
@@ -596,21 +626,6 @@ impl<'a> GpuPaintContext<'a> {
                     intersect_max_x = intersect_max_x.min(rmax_x);
                     intersect_max_y = intersect_max_y.min(rmax_y);
 
-                    // Track corner radii with their source bounds
-                    // Only update if this corner radius is larger (take max)
-                    if corner_radius.top_left > corner_sources[0].0 {
-                        corner_sources[0] = (corner_radius.top_left, rx, ry, rmax_x, rmax_y);
-                    }
-                    if corner_radius.top_right > corner_sources[1].0 {
-                        corner_sources[1] = (corner_radius.top_right, rx, ry, rmax_x, rmax_y);
-                    }
-                    if corner_radius.bottom_right > corner_sources[2].0 {
-                        corner_sources[2] = (corner_radius.bottom_right, rx, ry, rmax_x, rmax_y);
-                    }
-                    if corner_radius.bottom_left > corner_sources[3].0 {
-                        corner_sources[3] = (corner_radius.bottom_left, rx, ry, rmax_x, rmax_y);
-                    }
-
                     has_rect_clips = true;
                     topmost_is_plain_rect = false;
                 }
@@ -628,54 +643,38 @@ impl<'a> GpuPaintContext<'a> {
             let width = (intersect_max_x - intersect_min_x).max(0.0);
             let height = (intersect_max_y - intersect_min_y).max(0.0);
 
-            // Determine final corner radii based on whether intersection edges are within
-            // the corner radius region of the source. A corner should be rounded if the
-            // intersection boundary is close enough to the source corner that it would
-            // visually clip through the rounded area.
-            //
-            // For each corner, we check if the intersection edge is within (radius + epsilon)
-            // of the source edge. If so, apply the rounded corner to prevent visual clipping.
-
+            // Round each corner of the intersection by every rounded clip
+            // whose arc reaches into it. A source corner of radius `r` whose
+            // edges sit `d` outside the intersection's corner cuts as deep
+            // along the diagonal as a corner of radius `r - d * (2 + √2)`
+            // would; the deepest cut wins. Nested rounded clips (a rounded
+            // scroll inside a rounded card) keep whichever corner actually
+            // bites, and a plain rect clip inset past the arc squares it off.
             let mut radii = [0.0f32; 4];
-
-            // Top-left corner: check if intersection is within corner radius region
-            let (r, src_min_x, src_min_y, _, _) = corner_sources[0];
-            if r > 0.0 {
-                let dist_from_left = intersect_min_x - src_min_x;
-                let dist_from_top = intersect_min_y - src_min_y;
-                // If intersection is within the corner radius region, apply rounding
-                if dist_from_left < r && dist_from_top < r {
-                    radii[0] = (r - dist_from_left.max(0.0)).max(0.0).min(r);
-                }
-            }
-
-            // Top-right corner
-            let (r, _, src_min_y, src_max_x, _) = corner_sources[1];
-            if r > 0.0 {
-                let dist_from_right = src_max_x - intersect_max_x;
-                let dist_from_top = intersect_min_y - src_min_y;
-                if dist_from_right < r && dist_from_top < r {
-                    radii[1] = (r - dist_from_right.max(0.0)).max(0.0).min(r);
-                }
-            }
-
-            // Bottom-right corner
-            let (r, _, _, src_max_x, src_max_y) = corner_sources[2];
-            if r > 0.0 {
-                let dist_from_right = src_max_x - intersect_max_x;
-                let dist_from_bottom = src_max_y - intersect_max_y;
-                if dist_from_right < r && dist_from_bottom < r {
-                    radii[2] = (r - dist_from_right.max(0.0)).max(0.0).min(r);
-                }
-            }
-
-            // Bottom-left corner
-            let (r, src_min_x, _, _, src_max_y) = corner_sources[3];
-            if r > 0.0 {
-                let dist_from_left = intersect_min_x - src_min_x;
-                let dist_from_bottom = src_max_y - intersect_max_y;
-                if dist_from_left < r && dist_from_bottom < r {
-                    radii[3] = (r - dist_from_left.max(0.0)).max(0.0).min(r);
+            for clip in &self.clip_stack {
+                let ClipShape::RoundedRect {
+                    rect,
+                    corner_radius,
+                } = clip
+                else {
+                    continue;
+                };
+                let from_left = intersect_min_x - rect.x();
+                let from_top = intersect_min_y - rect.y();
+                let from_right = rect.x() + rect.width() - intersect_max_x;
+                let from_bottom = rect.y() + rect.height() - intersect_max_y;
+                let corners = [
+                    (corner_radius.top_left, from_left, from_top),
+                    (corner_radius.top_right, from_right, from_top),
+                    (corner_radius.bottom_right, from_right, from_bottom),
+                    (corner_radius.bottom_left, from_left, from_bottom),
+                ];
+                for (radius, (r, dx, dy)) in radii.iter_mut().zip(corners) {
+                    let inset = dx.max(dy).max(0.0);
+                    let r = r - inset * (2.0 + std::f32::consts::SQRT_2);
+                    if r > 0.0 {
+                        *radius = radius.max(r);
+                    }
                 }
             }
 
@@ -751,6 +750,7 @@ impl<'a> GpuPaintContext<'a> {
         self.blend_mode_stack = vec![BlendMode::Normal];
         self.clip_stack.clear();
         self.layer_stack.clear();
+        self.cached_layer_stack.clear();
         self.is_3d = false;
         self.camera = None;
     }
@@ -793,6 +793,7 @@ impl<'a> GpuPaintContext<'a> {
             DrawCommand::PopOpacity => self.pop_opacity(),
             DrawCommand::PushBlendMode(m) => self.push_blend_mode(*m),
             DrawCommand::PopBlendMode => self.pop_blend_mode(),
+            DrawCommand::SetZLayer(layer) => self.set_z_layer(*layer),
             DrawCommand::FillPath { path, brush } => self.fill_path(path, brush.clone()),
             DrawCommand::StrokePath {
                 path,
@@ -995,8 +996,14 @@ impl<'a> DrawContext for GpuPaintContext<'a> {
         // Extract brush info for advanced features (multi-stop gradients, images, glass)
         let brush_info = extract_brush_info(&brush);
 
-        // Tessellate the stroke using lyon
-        let mut tessellated = tessellate_stroke(path, stroke, &brush);
+        // Tessellate the stroke using lyon, which only draws solid lines, so
+        // a dashed stroke is split into its dashes first
+        let mut tessellated = if stroke.dash.is_empty() {
+            tessellate_stroke(path, stroke, &brush)
+        } else {
+            let dashed = dash_path(path, &stroke.dash, stroke.dash_offset);
+            tessellate_stroke(&dashed, stroke, &brush)
+        };
 
         // Transform vertices by current transform stack
         let affine = self.current_affine();
@@ -1217,7 +1224,7 @@ impl<'a> DrawContext for GpuPaintContext<'a> {
             gradient_params: transformed_gradient_params,
             type_info: [
                 PrimitiveType::Rect as u32,
-                fill_type as u32,
+                fill_type as u32 | gradient_fill_flags(&brush),
                 clip_type as u32,
                 self.z_layer,
             ],
@@ -1297,7 +1304,7 @@ impl<'a> DrawContext for GpuPaintContext<'a> {
             gradient_params: transformed_gradient_params,
             type_info: [
                 PrimitiveType::Rect as u32,
-                fill_type as u32,
+                fill_type as u32 | gradient_fill_flags(&brush),
                 clip_type as u32,
                 self.z_layer,
             ],
@@ -1346,7 +1353,7 @@ impl<'a> DrawContext for GpuPaintContext<'a> {
             gradient_params,
             type_info: [
                 PrimitiveType::Rect as u32,
-                fill_type as u32,
+                fill_type as u32 | gradient_fill_flags(&brush),
                 clip_type as u32,
                 self.z_layer,
             ],
@@ -1416,7 +1423,7 @@ impl<'a> DrawContext for GpuPaintContext<'a> {
             gradient_params: transformed_gradient_params,
             type_info: [
                 PrimitiveType::Circle as u32,
-                fill_type as u32,
+                fill_type as u32 | gradient_fill_flags(&brush),
                 clip_type as u32,
                 self.z_layer,
             ],
@@ -1469,7 +1476,7 @@ impl<'a> DrawContext for GpuPaintContext<'a> {
             gradient_params: transformed_gradient_params,
             type_info: [
                 PrimitiveType::Circle as u32,
-                fill_type as u32,
+                fill_type as u32 | gradient_fill_flags(&brush),
                 clip_type as u32,
                 self.z_layer,
             ],
@@ -1797,6 +1804,40 @@ impl<'a> DrawContext for GpuPaintContext<'a> {
     }
 
     fn push_layer(&mut self, config: LayerConfig) {
+        // Cached layers draw into their own batch, rasterized and kept by
+        // the renderer, and are composited with a sample on pop. Layers with
+        // effects are drawn as regular layers so the effects still apply.
+        if let (true, true, Some(id)) = (config.cache, config.effects.is_empty(), config.id) {
+            let position = config.position.unwrap_or(Point::ZERO);
+            let size = config.size.unwrap_or(self.viewport);
+            let anchor =
+                self.transform_rect(Rect::new(position.x, position.y, size.width, size.height));
+            self.layer_stack.push(LayerState {
+                config,
+                primitive_start: 0,
+                foreground_primitive_start: 0,
+                path_start: 0,
+                foreground_path_start: 0,
+                parent_state_indices: (
+                    self.transform_stack.len(),
+                    self.opacity_stack.len(),
+                    self.blend_mode_stack.len(),
+                    self.clip_stack.len(),
+                ),
+                cached: true,
+            });
+            self.cached_layer_stack.push(CachedLayerState {
+                id,
+                anchor,
+                size,
+                z_layer: self.z_layer,
+                parent_batch: std::mem::take(&mut self.batch),
+                clip_stack: std::mem::take(&mut self.clip_stack),
+                opacity_stack: std::mem::replace(&mut self.opacity_stack, vec![1.0]),
+            });
+            return;
+        }
+
         // Record current state indices for restoration on pop
         let state = LayerState {
             config: config.clone(),
@@ -1810,6 +1851,7 @@ impl<'a> DrawContext for GpuPaintContext<'a> {
                 self.blend_mode_stack.len(),
                 self.clip_stack.len(),
             ),
+            cached: false,
         };
         self.layer_stack.push(state);
 
@@ -1831,6 +1873,25 @@ impl<'a> DrawContext for GpuPaintContext<'a> {
     }
 
     fn pop_layer(&mut self) {
+        if let Some(LayerState { cached: true, .. }) = self.layer_stack.last() {
+            self.layer_stack.pop();
+            let Some(parent) = self.cached_layer_stack.pop() else {
+                return;
+            };
+            let content = std::mem::replace(&mut self.batch, parent.parent_batch);
+            self.clip_stack = parent.clip_stack;
+            self.opacity_stack = parent.opacity_stack;
+            self.batch.cached_layers.push(CachedLayerBatch {
+                id: parent.id,
+                anchor: parent.anchor,
+                size: parent.size,
+                batch: content,
+            });
+            let source = Rect::new(0.0, 0.0, parent.size.width, parent.size.height);
+            self.push_sample(parent.id, source, parent.anchor, parent.z_layer);
+            return;
+        }
+
         if let Some(state) = self.layer_stack.pop() {
             // Restore parent state by trimming stacks to their saved indices
             let (transform_idx, opacity_idx, blend_idx, clip_idx) = state.parent_state_indices;
@@ -1857,13 +1918,14 @@ impl<'a> DrawContext for GpuPaintContext<'a> {
     }
 
     fn sample_layer(&mut self, id: LayerId, source_rect: Rect, dest_rect: Rect) {
-        // Record sample command for GPU renderer to process
-        self.batch
-            .push_layer_command(crate::primitives::LayerCommand::Sample {
-                id,
-                source: source_rect,
-                dest: dest_rect,
-            });
+        // The renderer maps `source_rect` of the layer, as drawn, onto the
+        // destination
+        let dest = self.transform_rect(dest_rect);
+        self.push_sample(id, source_rect, dest, self.z_layer);
+    }
+
+    fn has_cached_layer(&self, id: LayerId) -> bool {
+        self.held_layers.contains(&id)
     }
 
     fn viewport_size(&self) -> Size {
@@ -2122,6 +2184,64 @@ mod tests {
         assert_eq!(prim.bounds[1], 20.0);
     }
 
+    #[test]
+    fn test_nested_rounded_clips_intersect() {
+        let mut ctx = GpuPaintContext::new(800.0, 600.0);
+
+        // A 16px rounded card holding a 12px rounded scroll inset by 2px
+        ctx.push_clip(ClipShape::rounded_rect(
+            Rect::new(0.0, 0.0, 200.0, 100.0),
+            CornerRadius::uniform(16.0),
+        ));
+        ctx.push_clip(ClipShape::rounded_rect(
+            Rect::new(2.0, 2.0, 196.0, 96.0),
+            CornerRadius::uniform(12.0),
+        ));
+        // Plain rect clip inset past the radius squares the corners off
+        ctx.push_clip(ClipShape::rect(Rect::new(0.0, 40.0, 200.0, 40.0)));
+        ctx.fill_rect(
+            Rect::new(0.0, 0.0, 200.0, 100.0),
+            0.0.into(),
+            Color::RED.into(),
+        );
+        ctx.pop_clip();
+        ctx.fill_rect(
+            Rect::new(0.0, 0.0, 200.0, 100.0),
+            0.0.into(),
+            Color::RED.into(),
+        );
+
+        let batch = ctx.batch();
+        let squared = &batch.primitives[0];
+        assert_eq!(squared.clip_bounds, [2.0, 40.0, 196.0, 40.0]);
+        assert_eq!(squared.clip_radius, [0.0; 4]);
+
+        // The outer 16px arc sits 2px outside the intersection and cuts
+        // shallower than the inner 12px arc
+        let rounded = &batch.primitives[1];
+        assert_eq!(rounded.clip_bounds, [2.0, 2.0, 196.0, 96.0]);
+        assert_eq!(rounded.clip_radius, [12.0; 4]);
+    }
+
+    #[test]
+    fn test_dashed_stroke_leaves_gaps() {
+        let line = Path::new().move_to(0.0, 10.0).line_to(100.0, 10.0);
+        let mut solid = GpuPaintContext::new(800.0, 600.0);
+        solid.stroke_path(&line, &Stroke::new(2.0), Color::BLACK.into());
+
+        let mut dashed = GpuPaintContext::new(800.0, 600.0);
+        let stroke = Stroke::new(2.0).with_dash(vec![10.0, 10.0], 0.0);
+        dashed.stroke_path(&line, &stroke, Color::BLACK.into());
+
+        let vertices = &dashed.batch().paths.vertices;
+        assert!(vertices.len() > solid.batch().path_vertex_count());
+        // Nothing is drawn inside the gaps (10..20, 30..40, ...)
+        for vertex in vertices {
+            let x = vertex.position[0];
+            assert!(x % 20.0 < 10.5 || x % 20.0 > 19.5, "vertex in gap at {x}");
+        }
+    }
+
     #[test]
     fn test_opacity_stack() {
         let mut ctx = GpuPaintContext::new(800.0, 600.0);
@@ -2172,6 +2292,7 @@ mod tests {
             opacity: 0.5,
             depth: false,
             effects: Vec::new(),
+            cache: false,
         };
         ctx.push_layer(config);
 
@@ -2210,6 +2331,7 @@ mod tests {
             opacity: 0.8,
             depth: false,
             effects: Vec::new(),
+            cache: false,
         };
         ctx.push_layer(config1);
         assert_eq!(ctx.layer_stack.len(), 1);
@@ -2224,6 +2346,7 @@ mod tests {
             opacity: 0.5,
             depth: false,
             effects: Vec::new(),
+            cache: false,
         };
         ctx.push_layer(config2);
         assert_eq!(ctx.layer_stack.len(), 2);
@@ -2241,4 +2364,171 @@ mod tests {
         assert_eq!(ctx.layer_stack.len(), 0);
         assert_eq!(ctx.current_opacity(), 1.0);
     }
+    #[test]
+    fn test_cached_layer_draws_into_own_batch() {
+        let mut ctx = GpuPaintContext::new(800.0, 600.0);
+        let id = LayerId(7);
+        assert!(!ctx.has_cached_layer(id));
+
+        ctx.push_opacity(0.5);
+        ctx.push_clip(ClipShape::rect(Rect::new(0.0, 0.0, 400.0, 300.0)));
+        ctx.push_layer(
+            LayerConfig::new()
+                .id(id)
+                .position(Point::new(10.0, 20.0))
+                .size(Size::new(100.0, 50.0))
+                .cached(),
+        );
+        ctx.fill_rect(
+            Rect::new(10.0, 20.0, 100.0, 50.0),
+            0.0.into(),
+            Color::RED.into(),
+        );
+        ctx.pop_layer();
+
+        // The content is kept aside, unclipped and opaque, and composited
+        // with a sample carrying the clip and opacity
+        let batch = ctx.take_batch();
+        assert!(batch.primitives.is_empty());
+        assert_eq!(batch.cached_layers.len(), 1);
+        let layer = &batch.cached_layers[0];
+        assert_eq!(layer.id, id);
+        assert_eq!(layer.anchor, Rect::new(10.0, 20.0, 100.0, 50.0));
+        assert_eq!(layer.batch.primitives.len(), 1);
+        assert_eq!(layer.batch.primitives[0].color[3], 1.0);
+        assert_eq!(
+            layer.batch.content_bounds(),
+            Some((10.0, 20.0, 100.0, 50.0))
+        );
+        match &batch.layer_commands[..] {
+            [entry] => match &entry.command {
+                crate::primitives::LayerCommand::Sample {
+                    id: sampled,
+                    dest,
+                    opacity,
+                    clip,
+                    ..
+                } => {
+                    assert_eq!(*sampled, id);
+                    assert_eq!(*dest, layer.anchor);
+                    assert_eq!(*opacity, 0.5);
+                    assert_eq!(
+                        clip.map(|(bounds, _)| bounds),
+                        Some([0.0, 0.0, 400.0, 300.0])
+                    );
+                }
+                command => panic!("expected a sample, got {command:?}"),
+            },
+            commands => panic!("expected one sample, got {commands:?}"),
+        }
+
+        ctx.set_cached_layers([id]);
+        assert!(ctx.has_cached_layer(id));
+    }
+
+    #[test]
+    fn test_cached_layer_with_effects_draws_as_regular_layer() {
+        let mut ctx = GpuPaintContext::new(800.0, 600.0);
+        ctx.push_layer(
+            LayerConfig::new()
+                .id(LayerId(7))
+                .size(Size::new(100.0, 50.0))
+                .blur(4.0)
+                .cached(),
+        );
+        ctx.fill_rect(
+            Rect::new(0.0, 0.0, 100.0, 50.0),
+            0.0.into(),
+            Color::RED.into(),
+        );
+        ctx.pop_layer();
+
+        // Not set aside for rasterization, so the blur still applies
+        let batch = ctx.take_batch();
+        assert!(batch.cached_layers.is_empty());
+        assert!(!batch.has_cached_layers());
+        assert!(batch.has_layer_effects());
+        assert_eq!(batch.primitives.len(), 1);
+    }
+
+    #[test]
+    fn test_sample_layer_keeps_source_rect_and_z_layer() {
+        let mut ctx = GpuPaintContext::new(800.0, 600.0);
+        ctx.set_z_layer(3);
+        ctx.sample_layer(
+            LayerId(7),
+            Rect::new(50.0, 0.0, 50.0, 50.0),
+            Rect::new(0.0, 0.0, 200.0, 100.0),
+        );
+
+        let batch = ctx.take_batch();
+        assert_eq!(batch.max_z_layer(), 3);
+        match &batch.layer_commands[..] {
+            [entry] => match &entry.command {
+                crate::primitives::LayerCommand::Sample {
+                    source, z_layer, ..
+                } => {
+                    assert_eq!(*source, Rect::new(50.0, 0.0, 50.0, 50.0));
+                    assert_eq!(*z_layer, 3);
+                }
+                command => panic!("expected a sample, got {command:?}"),
+            },
+            commands => panic!("expected one sample, got {commands:?}"),
+        }
+    }
+
+    #[test]
+    fn test_render_tree_cache_layer_draws_subtree_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::{Arc, Mutex};
+
+        use blinc_layout::prelude::*;
+
+        let draws = Arc::new(AtomicUsize::new(0));
+        let counter = draws.clone();
+        let ui = div().w(200.0).h(200.0).child(
+            div().w(100.0).h(100.0).cache_layer().child(
+                blinc_layout::canvas::canvas(move |ctx, bounds| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    ctx.fill_rect(
+                        Rect::new(0.0, 0.0, bounds.width, bounds.height),
+                        CornerRadius::default(),
+                        Color::RED.into(),
+                    );
+                })
+                .w(50.0)
+                .h(50.0),
+            ),
+        );
+
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(200.0, 200.0);
+        let render_state = RenderState::new(Arc::new(Mutex::new(
+            blinc_animation::AnimationScheduler::new(),
+        )));
+
+        // The first frame draws the subtree into cached layers
+        let mut ctx = GpuPaintContext::new(200.0, 200.0);
+        tree.render_with_motion(&mut ctx, &render_state);
+        let first = ctx.take_batch();
+        let held: Vec<_> = first.cached_layers.iter().map(|layer| layer.id).collect();
+        assert!(!held.is_empty());
+        assert_eq!(draws.load(Ordering::SeqCst), 1);
+
+        // Once the renderer holds them, later frames only composite them
+        for _ in 0..2 {
+            let mut ctx = GpuPaintContext::new(200.0, 200.0);
+            ctx.set_cached_layers(held.iter().copied());
+            tree.render_with_motion(&mut ctx, &render_state);
+            let batch = ctx.take_batch();
+            assert!(batch.cached_layers.is_empty());
+            assert!(batch.has_cached_layers());
+        }
+        assert_eq!(draws.load(Ordering::SeqCst), 1);
+
+        // A context that holds nothing draws the subtree again
+        let mut ctx = GpuPaintContext::new(200.0, 200.0);
+        tree.render_with_motion(&mut ctx, &render_state);
+        assert_eq!(draws.load(Ordering::SeqCst), 2);
+    }
 }
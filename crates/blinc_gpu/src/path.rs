@@ -576,10 +576,12 @@ pub struct PathBrushInfo {
     pub end_color: Color,
     /// Gradient parameters: linear (x1,y1,x2,y2), radial (cx,cy,r,0)
     pub gradient_params: [f32; 4],
-    /// Whether gradient has >2 stops (needs texture lookup)
+    /// Whether gradient has >2 stops, eased stops or OKLab blending (needs texture lookup)
     pub needs_gradient_texture: bool,
     /// Gradient stops for multi-stop gradients (for texture rasterization)
     pub gradient_stops: Option<Vec<blinc_core::GradientStop>>,
+    /// Color space the gradient texture is rasterized in
+    pub gradient_interpolation: blinc_core::GradientInterpolation,
     /// Image source path for image brushes
     pub image_source: Option<String>,
     /// Image tint color
@@ -600,6 +602,7 @@ impl Default for PathBrushInfo {
             gradient_params: [0.0, 0.0, 1.0, 1.0],
             needs_gradient_texture: false,
             gradient_stops: None,
+            gradient_interpolation: blinc_core::GradientInterpolation::Srgb,
             image_source: None,
             image_tint: Color::WHITE,
             glass_params: [20.0, 1.0, 0.5, 0.9],
@@ -640,7 +643,9 @@ pub fn extract_brush_info(brush: &Brush) -> PathBrushInfo {
             let stops = gradient.stops();
             let start_color = gradient.first_color();
             let end_color = gradient.last_color();
-            let needs_texture = stops.len() > 2;
+            // The vertex-color fast path can only blend two stops linearly in sRGB
+            let needs_texture = stops.len() > 2 || gradient.has_custom_interpolation();
+            let gradient_interpolation = gradient.interpolation();
 
             match gradient {
                 Gradient::Linear {
@@ -668,6 +673,7 @@ pub fn extract_brush_info(brush: &Brush) -> PathBrushInfo {
                         } else {
                             None
                         },
+                        gradient_interpolation,
                         ..Default::default()
                     }
                 }
@@ -698,6 +704,7 @@ pub fn extract_brush_info(brush: &Brush) -> PathBrushInfo {
                         } else {
                             None
                         },
+                        gradient_interpolation,
                         ..Default::default()
                     }
                 }
@@ -720,6 +727,7 @@ pub fn extract_brush_info(brush: &Brush) -> PathBrushInfo {
                         } else {
                             None
                         },
+                        gradient_interpolation,
                         ..Default::default()
                     }
                 }
@@ -738,6 +746,18 @@ pub fn extract_brush_info(brush: &Brush) -> PathBrushInfo {
                 ..Default::default()
             }
         }
+        Brush::Pattern(pattern) => {
+            // Tiled content isn't supported on GPU paths; fill with its
+            // dominant color instead (SvgDocument expands patterns itself)
+            let color = pattern.fallback_color();
+            PathBrushInfo {
+                brush_type: PathBrushType::Solid,
+                gradient_type: 0,
+                start_color: color,
+                end_color: color,
+                ..Default::default()
+            }
+        }
     }
 }
 
@@ -925,6 +945,10 @@ fn brush_to_color(brush: &Brush) -> Color {
             // Return the tint color or semi-transparent white
             style.tint.unwrap_or(Color::rgba(1.0, 1.0, 1.0, 0.3))
         }
+        Brush::Pattern(pattern) => {
+            // Patterns are not supported on tessellated paths
+            pattern.fallback_color()
+        }
     }
 }
 
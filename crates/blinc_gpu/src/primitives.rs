@@ -30,6 +30,36 @@ pub enum FillType {
     RadialGradient = 2,
 }
 
+/// Gradient flag: blend the end colors in OKLab (must match shader constants)
+pub const GRADIENT_FLAG_OKLAB: u32 = 0x100;
+/// Gradient flag: add dithering noise (must match shader constants)
+pub const GRADIENT_FLAG_DITHER: u32 = 0x200;
+/// Bit offset of the 2-bit first-stop easing (must match shader constants)
+pub const GRADIENT_EASING_SHIFT: u32 = 10;
+
+/// Pack gradient interpolation options into the bits above the fill type
+///
+/// SDF primitives only carry the first and last stop, so the easing of the
+/// first stop applies to the whole ramp.
+pub fn gradient_fill_flags(brush: &blinc_core::Brush) -> u32 {
+    let blinc_core::Brush::Gradient(gradient) = brush else {
+        return 0;
+    };
+    let mut flags = 0;
+    if gradient.interpolation() == blinc_core::GradientInterpolation::Oklab {
+        flags |= GRADIENT_FLAG_OKLAB;
+    }
+    if gradient.dither() {
+        flags |= GRADIENT_FLAG_DITHER;
+    }
+    let easing = gradient
+        .stops()
+        .first()
+        .map(|s| s.easing)
+        .unwrap_or_default();
+    flags | ((easing as u32) << GRADIENT_EASING_SHIFT)
+}
+
 /// Glass material types (must match shader constants)
 #[repr(u32)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -716,7 +746,8 @@ impl GpuGlyph {
 #[derive(Clone, Copy, Debug, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct Uniforms {
     pub viewport_size: [f32; 2],
-    pub _padding: [f32; 2],
+    /// Sub-pixel offset added to SDF clip positions (temporal AA), zero otherwise
+    pub jitter: [f32; 2],
 }
 
 /// Uniform buffer for glass shader
@@ -1059,6 +1090,53 @@ impl Default for GlowUniforms {
     }
 }
 
+/// Post-process pass selector for `PostProcessUniforms::mode`
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PostProcessMode {
+    /// Keep only the part of each pixel above the bloom threshold
+    BloomExtract = 0,
+    /// Add the blurred bright pixels back onto the input
+    BloomCombine = 1,
+    /// Exposure + Reinhard tone mapping
+    ToneMapReinhard = 2,
+    /// Exposure + ACES filmic tone mapping
+    ToneMapAces = 3,
+}
+
+/// Uniforms for the bloom and tone mapping shader
+///
+/// Memory layout:
+/// - mode: `u32` (4 bytes) - which pass to run (see [`PostProcessMode`])
+/// - threshold: `f32` (4 bytes) - bloom brightness threshold
+/// - intensity: `f32` (4 bytes) - bloom strength
+/// - exposure: `f32` (4 bytes) - exposure multiplier for tone mapping
+///
+/// Total: 16 bytes
+#[repr(C)]
+#[derive(Clone, Copy, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct PostProcessUniforms {
+    /// Pass to run (`PostProcessMode as u32`)
+    pub mode: u32,
+    /// Brightness (0-1) above which pixels bloom
+    pub threshold: f32,
+    /// Strength of the added bloom light
+    pub intensity: f32,
+    /// Exposure multiplier applied before tone mapping
+    pub exposure: f32,
+}
+
+impl Default for PostProcessUniforms {
+    fn default() -> Self {
+        Self {
+            mode: PostProcessMode::BloomExtract as u32,
+            threshold: 0.8,
+            intensity: 1.0,
+            exposure: 1.0,
+        }
+    }
+}
+
 /// A batch of tessellated path geometry
 #[derive(Clone, Default)]
 pub struct PathBatch {
@@ -1076,6 +1154,8 @@ pub struct PathBatch {
     pub use_gradient_texture: bool,
     /// Gradient stops for texture rasterization (when use_gradient_texture is true)
     pub gradient_stops: Option<Vec<blinc_core::GradientStop>>,
+    /// Color space for gradient texture rasterization
+    pub gradient_interpolation: blinc_core::GradientInterpolation,
     /// Whether to use image texture
     pub use_image_texture: bool,
     /// Image source path for image brush (None if not using image)
@@ -1111,9 +1191,34 @@ pub enum LayerCommand {
         source: blinc_core::Rect,
         /// Destination rectangle in the current target (in pixels)
         dest: blinc_core::Rect,
+        /// Opacity to composite with
+        opacity: f32,
+        /// Clip (bounds, corner radii) in effect where the sample was drawn
+        clip: Option<([f32; 4], [f32; 4])>,
+        /// Z-layer the sample is composited at
+        z_layer: u32,
     },
 }
 
+/// Content of a cached layer, drawn into its own batch
+///
+/// The renderer rasterizes it into a texture kept under `id`, and
+/// [`LayerCommand::Sample`] commands composite that texture on this and
+/// later frames.
+pub struct CachedLayerBatch {
+    /// ID the texture is kept under
+    pub id: blinc_core::LayerId,
+    /// Layer position and size in screen pixels when the content was drawn
+    ///
+    /// Samples map this rect onto their destination, so content that
+    /// overflows the layer keeps its offset when the layer moves or scales.
+    pub anchor: blinc_core::Rect,
+    /// Layer size in its own coordinates, which sample source rects use
+    pub size: blinc_core::Size,
+    /// Primitives, paths and glyphs drawn inside the layer, in screen pixels
+    pub batch: PrimitiveBatch,
+}
+
 /// A recorded layer command with its primitive index
 #[derive(Clone, Debug)]
 pub struct LayerCommandEntry {
@@ -1137,6 +1242,8 @@ pub struct PrimitiveBatch {
     pub foreground_paths: PathBatch,
     /// Layer commands for offscreen rendering and composition
     pub layer_commands: Vec<LayerCommandEntry>,
+    /// Cached layers drawn this frame, to rasterize before compositing
+    pub cached_layers: Vec<CachedLayerBatch>,
 }
 
 impl PrimitiveBatch {
@@ -1149,6 +1256,7 @@ impl PrimitiveBatch {
             paths: PathBatch::default(),
             foreground_paths: PathBatch::default(),
             layer_commands: Vec::new(),
+            cached_layers: Vec::new(),
         }
     }
 
@@ -1160,6 +1268,7 @@ impl PrimitiveBatch {
         self.paths = PathBatch::default();
         self.foreground_paths = PathBatch::default();
         self.layer_commands.clear();
+        self.cached_layers.clear();
     }
 
     /// Record a layer command at the current primitive index
@@ -1175,6 +1284,13 @@ impl PrimitiveBatch {
         !self.layer_commands.is_empty()
     }
 
+    /// Check if the batch composites any cached layers
+    pub fn has_cached_layers(&self) -> bool {
+        self.layer_commands
+            .iter()
+            .any(|entry| matches!(entry.command, LayerCommand::Sample { .. }))
+    }
+
     /// Check if there are any layer commands with effects
     pub fn has_layer_effects(&self) -> bool {
         self.layer_commands.iter().any(|entry| {
@@ -1329,6 +1445,7 @@ impl PrimitiveBatch {
         // Update brush metadata
         self.paths.use_gradient_texture = brush_info.needs_gradient_texture;
         self.paths.gradient_stops = brush_info.gradient_stops.clone();
+        self.paths.gradient_interpolation = brush_info.gradient_interpolation;
         self.paths.use_image_texture =
             matches!(brush_info.brush_type, crate::path::PathBrushType::Image);
         self.paths.image_source = brush_info.image_source.clone();
@@ -1372,6 +1489,7 @@ impl PrimitiveBatch {
         // Update brush metadata
         self.foreground_paths.use_gradient_texture = brush_info.needs_gradient_texture;
         self.foreground_paths.gradient_stops = brush_info.gradient_stops.clone();
+        self.foreground_paths.gradient_interpolation = brush_info.gradient_interpolation;
         self.foreground_paths.use_image_texture =
             matches!(brush_info.brush_type, crate::path::PathBrushType::Image);
         self.foreground_paths.image_source = brush_info.image_source.clone();
@@ -1400,6 +1518,7 @@ impl PrimitiveBatch {
             && self.glyphs.is_empty()
             && self.paths.vertices.is_empty()
             && self.foreground_paths.vertices.is_empty()
+            && !self.has_cached_layers()
     }
 
     /// Check if the batch contains any tessellated path geometry
@@ -1441,10 +1560,18 @@ impl PrimitiveBatch {
 
     /// Get the maximum z_layer used by primitives in this batch
     pub fn max_z_layer(&self) -> u32 {
+        let samples = self
+            .layer_commands
+            .iter()
+            .filter_map(|entry| match entry.command {
+                LayerCommand::Sample { z_layer, .. } => Some(z_layer),
+                _ => None,
+            });
         self.primitives
             .iter()
             .chain(self.foreground_primitives.iter())
             .map(|p| p.z_layer())
+            .chain(samples)
             .max()
             .unwrap_or(0)
     }
@@ -1510,6 +1637,57 @@ impl PrimitiveBatch {
             entry.primitive_index += primitive_offset;
             self.layer_commands.push(entry);
         }
+        self.cached_layers.extend(other.cached_layers);
+    }
+
+    /// Screen-space bounds of everything drawn in the batch, including
+    /// shadow blur, as (x, y, width, height)
+    ///
+    /// Returns `None` for an empty batch.
+    pub fn content_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        let mut min = (f32::MAX, f32::MAX);
+        let mut max = (f32::MIN, f32::MIN);
+        let mut add = |x0: f32, y0: f32, x1: f32, y1: f32| {
+            min = (min.0.min(x0), min.1.min(y0));
+            max = (max.0.max(x1), max.1.max(y1));
+        };
+
+        for p in self.primitives.iter().chain(&self.foreground_primitives) {
+            let [x, y, w, h] = p.bounds;
+            // Shadows spread past the shape (matches the shader's quad expansion)
+            let margin = if p.shadow_color[3] > 0.0 {
+                let [offset_x, offset_y, blur, spread] = p.shadow;
+                blur * 3.0 + spread.max(0.0) + offset_x.abs() + offset_y.abs()
+            } else {
+                0.0
+            };
+            add(x - margin, y - margin, x + w + margin, y + h + margin);
+        }
+        for glyph in &self.glyphs {
+            let [x, y, w, h] = glyph.bounds;
+            add(x, y, x + w, y + h);
+        }
+        for vertex in self
+            .paths
+            .vertices
+            .iter()
+            .chain(&self.foreground_paths.vertices)
+        {
+            let [x, y] = vertex.position;
+            add(x, y, x, y);
+        }
+        for entry in &self.layer_commands {
+            if let LayerCommand::Sample { dest, .. } = &entry.command {
+                add(
+                    dest.x(),
+                    dest.y(),
+                    dest.x() + dest.width(),
+                    dest.y() + dest.height(),
+                );
+            }
+        }
+
+        (max.0 > min.0 && max.1 > min.1).then_some((min.0, min.1, max.0 - min.0, max.1 - min.1))
     }
 }
 
@@ -3,6 +3,10 @@
 //! The main renderer that manages wgpu resources and executes render passes
 //! for SDF primitives, glass effects, and text.
 
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
 
 use wgpu::util::DeviceExt;
@@ -11,14 +15,16 @@ use crate::gradient_texture::GradientTextureCache;
 use crate::image::GpuImageInstance;
 use crate::path::PathVertex;
 use crate::primitives::{
-    BlurUniforms, ColorMatrixUniforms, DropShadowUniforms, GlassType, GlassUniforms, GlowUniforms,
-    GpuGlassPrimitive, GpuGlyph, GpuPrimitive, PathUniforms, PrimitiveBatch, Uniforms,
+    BlurUniforms, CachedLayerBatch, ColorMatrixUniforms, CompositeUniforms, DropShadowUniforms,
+    GlassType, GlassUniforms, GlowUniforms, GpuGlassPrimitive, GpuGlyph, GpuPrimitive,
+    PathUniforms, PostProcessMode, PostProcessUniforms, PrimitiveBatch, Uniforms,
 };
 use crate::shaders::{
     BLUR_SHADER, COLOR_MATRIX_SHADER, COMPOSITE_SHADER, DROP_SHADOW_SHADER, GLASS_SHADER,
-    GLOW_SHADER, IMAGE_SHADER, LAYER_COMPOSITE_SHADER, PATH_SHADER, SDF_SHADER,
-    SIMPLE_GLASS_SHADER, TEXT_SHADER,
+    GLOW_SHADER, IMAGE_SHADER, LAYER_COMPOSITE_SHADER, PATH_SHADER, POST_PROCESS_SHADER,
+    SDF_SHADER, SIMPLE_GLASS_SHADER, TEXT_SHADER,
 };
+use crate::text::{SharedGlyphAtlas, TextRenderingContext};
 
 /// Error type for renderer operations
 #[derive(Debug)]
@@ -56,6 +62,9 @@ pub struct RendererConfig {
     /// Maximum number of glyphs per batch
     pub max_glyphs: usize,
     /// Enable MSAA (sample count)
+    ///
+    /// Use [`RendererConfig::msaa`] to pick a supported value. Counts the
+    /// adapter can't render are lowered to the nearest supported count.
     pub sample_count: u32,
     /// Preferred texture format (None = use surface preferred)
     pub texture_format: Option<wgpu::TextureFormat>,
@@ -68,6 +77,109 @@ pub struct RendererConfig {
     ///
     /// Default: true (unified rendering for consistent animations)
     pub unified_text_rendering: bool,
+    /// Accumulate jittered samples while the frame is static
+    ///
+    /// When the same batch is rendered on consecutive frames, each frame adds
+    /// a sub-pixel jittered sample to a history texture (up to
+    /// [`TEMPORAL_AA_MAX_SAMPLES`]), smoothing path and text edges that
+    /// analytic AA doesn't cover. Any change to the batch restarts
+    /// accumulation, so animated content is never blurred.
+    ///
+    /// Default: false
+    pub temporal_aa: bool,
+    /// Glyph atlas shared with other renderers on the same device
+    ///
+    /// Text contexts made with [`GpuRenderer::create_text_context`] cache
+    /// glyphs here, so each glyph is rasterized and stored in VRAM once
+    /// across all renderers given this atlas (e.g. one per window).
+    ///
+    /// Default: None (each text context gets its own atlas)
+    pub glyph_atlas: Option<SharedGlyphAtlas>,
+    /// Color space blending happens in
+    ///
+    /// Picks between an sRGB and a non-sRGB variant of the target format.
+    ///
+    /// Default: [`ColorSpace::Srgb`]
+    pub color_space: ColorSpace,
+}
+
+/// Color space the renderer blends in
+///
+/// Brush colors are always given in sRGB.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorSpace {
+    /// Blend sRGB values directly and write them to a non-sRGB target
+    ///
+    /// Cheapest, and matches how browsers blend, but blends and
+    /// anti-aliased edges come out darker than physically correct.
+    #[default]
+    Srgb,
+    /// Convert colors to linear, blend in linear space, and let an sRGB
+    /// target encode the result (gamma-correct blending)
+    Linear,
+}
+
+impl ColorSpace {
+    /// The variant of `format` to render to in this color space
+    pub fn target_format(self, format: wgpu::TextureFormat) -> wgpu::TextureFormat {
+        match self {
+            ColorSpace::Srgb => format.remove_srgb_suffix(),
+            ColorSpace::Linear => format.add_srgb_suffix(),
+        }
+    }
+}
+
+/// Pipeline constants telling brush shaders how to write colors
+///
+/// sRGB targets encode on store, so colors written to them must be linear;
+/// see `LINEAR_OUTPUT` in the SDF, text and path shaders.
+fn output_color_constants(texture_format: wgpu::TextureFormat) -> HashMap<String, f64> {
+    let linear = if texture_format.is_srgb() { 1.0 } else { 0.0 };
+    HashMap::from([("LINEAR_OUTPUT".to_string(), linear)])
+}
+
+/// Sample counts [`RendererConfig::msaa`] accepts
+pub const MSAA_SAMPLE_COUNTS: [u32; 3] = [1, 2, 4];
+
+/// Maximum number of jittered samples accumulated by temporal AA
+pub const TEMPORAL_AA_MAX_SAMPLES: u32 = 8;
+
+/// Frames a cached layer's texture is kept without being composited
+const CACHED_LAYER_MAX_IDLE_FRAMES: u64 = 120;
+
+impl RendererConfig {
+    /// Set the MSAA sample count
+    ///
+    /// Accepts 1, 2 or 4; other values are rounded down to the nearest of
+    /// those (0 disables MSAA). See [`GpuRenderer::supported_sample_counts`]
+    /// for what the adapter can actually render.
+    pub fn msaa(mut self, samples: u32) -> Self {
+        self.sample_count = MSAA_SAMPLE_COUNTS
+            .iter()
+            .rev()
+            .copied()
+            .find(|&count| count <= samples)
+            .unwrap_or(1);
+        self
+    }
+
+    /// Enable or disable temporal anti-aliasing of static frames
+    pub fn temporal_aa(mut self, enabled: bool) -> Self {
+        self.temporal_aa = enabled;
+        self
+    }
+
+    /// Share a glyph atlas with other renderers on the same device
+    pub fn glyph_atlas(mut self, atlas: SharedGlyphAtlas) -> Self {
+        self.glyph_atlas = Some(atlas);
+        self
+    }
+
+    /// Set the color space blending happens in
+    pub fn color_space(mut self, color_space: ColorSpace) -> Self {
+        self.color_space = color_space;
+        self
+    }
 }
 
 impl Default for RendererConfig {
@@ -81,6 +193,9 @@ impl Default for RendererConfig {
             sample_count: 1,
             texture_format: None,
             unified_text_rendering: true, // Enabled for consistent transforms during animations
+            temporal_aa: false,
+            glyph_atlas: None,
+            color_space: ColorSpace::Srgb,
         }
     }
 }
@@ -119,6 +234,8 @@ struct Pipelines {
     drop_shadow: wgpu::RenderPipeline,
     /// Pipeline for glow effect
     glow: wgpu::RenderPipeline,
+    /// Pipeline for bloom and tone mapping
+    post_process: wgpu::RenderPipeline,
 }
 
 /// Cached MSAA pipelines for dynamic sample counts
@@ -158,6 +275,8 @@ struct Buffers {
     glow_uniforms: wgpu::Buffer,
     /// Cached uniform buffer for color matrix effect
     color_matrix_uniforms: wgpu::Buffer,
+    /// Cached uniform buffer for bloom and tone mapping
+    post_process_uniforms: wgpu::Buffer,
 }
 
 /// Bind groups for shader resources
@@ -662,12 +781,10 @@ impl LayerTextureCache {
 /// - Batches primitives for efficient GPU rendering
 /// - Executes render passes
 pub struct GpuRenderer {
-    /// wgpu instance
-    #[allow(dead_code)]
-    instance: wgpu::Instance,
+    /// wgpu instance (shared by renderers made with [`GpuRenderer::on_same_device`])
+    instance: Arc<wgpu::Instance>,
     /// GPU adapter
-    #[allow(dead_code)]
-    adapter: wgpu::Adapter,
+    adapter: Arc<wgpu::Adapter>,
     /// GPU device
     device: Arc<wgpu::Device>,
     /// Command queue
@@ -714,6 +831,63 @@ pub struct GpuRenderer {
     path_image_sampler: wgpu::Sampler,
     /// Layer texture cache for offscreen rendering and composition
     layer_texture_cache: LayerTextureCache,
+    /// Multisampled color attachments for the main pipelines, keyed by size
+    msaa_targets: HashMap<(u32, u32), MsaaTarget>,
+    /// Temporal AA history (created on first use when enabled)
+    temporal: Option<TemporalAccumulation>,
+    /// Sub-pixel offset applied to SDF and path geometry (temporal AA)
+    jitter: [f32; 2],
+    /// Scissor region (x, y, width, height) for damaged-region redraws
+    damage: Option<[u32; 4]>,
+    /// Draw calls issued since the last `reset_draw_calls`
+    draw_calls: AtomicU32,
+    /// Rasterized cached layers; their textures live in `layer_texture_cache`
+    cached_layers: HashMap<blinc_core::LayerId, CachedLayer>,
+    /// Frames rendered, for evicting idle cached layers
+    frame_index: u64,
+}
+
+/// Placement of a cached layer's texture
+struct CachedLayer {
+    /// Layer rect in screen pixels when the content was drawn
+    anchor: blinc_core::Rect,
+    /// Layer size in its own coordinates, which sample source rects use
+    size: blinc_core::Size,
+    /// Screen position of the texture's top-left corner when drawn
+    origin: (f32, f32),
+    /// Size of the drawn content within the texture (`None` if empty)
+    content_size: Option<(u32, u32)>,
+    /// Frame the layer was last drawn or composited
+    last_used: u64,
+}
+
+/// Multisampled color attachment resolved into a single-sampled target
+struct MsaaTarget {
+    #[allow(dead_code)]
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+}
+
+/// History and scratch textures for temporal anti-aliasing
+struct TemporalAccumulation {
+    size: (u32, u32),
+    /// Running average of the accumulated samples
+    #[allow(dead_code)]
+    history_texture: wgpu::Texture,
+    history_view: wgpu::TextureView,
+    /// Latest jittered sample, blended into the history
+    #[allow(dead_code)]
+    scratch_texture: wgpu::Texture,
+    scratch_view: wgpu::TextureView,
+    /// Composite uniforms for blending the scratch sample (opacity = 1/n)
+    scratch_uniforms: wgpu::Buffer,
+    scratch_bind_group: wgpu::BindGroup,
+    /// Composite bind group for presenting the history (opacity = 1)
+    history_bind_group: wgpu::BindGroup,
+    /// Hash of the batch the history was accumulated for
+    frame_key: u64,
+    /// Number of samples in the history
+    samples: u32,
 }
 
 /// Image rendering pipeline (created lazily on first image render)
@@ -742,6 +916,8 @@ struct BindGroupLayouts {
     drop_shadow: wgpu::BindGroupLayout,
     /// Layout for glow effect shader
     glow: wgpu::BindGroupLayout,
+    /// Layout for bloom and tone mapping shader
+    post_process: wgpu::BindGroupLayout,
 }
 
 impl GpuRenderer {
@@ -777,6 +953,52 @@ impl GpuRenderer {
         }
     }
 
+    /// Device features to request from the adapter
+    ///
+    /// Adapter-specific format features unlock sample counts beyond the
+    /// WebGPU-guaranteed 1 and 4 (e.g. 2x MSAA).
+    fn required_features(adapter: &wgpu::Adapter) -> wgpu::Features {
+        adapter.features() & wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES
+    }
+
+    /// MSAA sample counts (from [`MSAA_SAMPLE_COUNTS`]) usable for `format`
+    fn sample_counts_for(
+        adapter: &wgpu::Adapter,
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+    ) -> Vec<u32> {
+        let adapter_specific = device
+            .features()
+            .contains(wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES);
+        let flags = if adapter_specific {
+            adapter.get_texture_format_features(format).flags
+        } else {
+            format.guaranteed_format_features(device.features()).flags
+        };
+        MSAA_SAMPLE_COUNTS
+            .iter()
+            .copied()
+            .filter(|&count| count == 1 || flags.sample_count_supported(count))
+            .collect()
+    }
+
+    /// Device limits to request from the adapter
+    ///
+    /// Browsers falling back to WebGL2 cannot satisfy the default (WebGPU)
+    /// limits, so on wasm32 we request the WebGL2 downlevel limits raised to
+    /// the adapter's supported texture resolution.
+    fn required_limits(adapter: &wgpu::Adapter) -> wgpu::Limits {
+        #[cfg(target_arch = "wasm32")]
+        {
+            wgpu::Limits::downlevel_webgl2_defaults().using_resolution(adapter.limits())
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = adapter;
+            wgpu::Limits::default()
+        }
+    }
+
     /// Create a new renderer without a surface (for headless rendering)
     pub async fn new(config: RendererConfig) -> Result<Self, RendererError> {
         let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
@@ -797,8 +1019,8 @@ impl GpuRenderer {
             .request_device(
                 &wgpu::DeviceDescriptor {
                     label: Some("Blinc GPU Device"),
-                    required_features: wgpu::Features::empty(),
-                    required_limits: wgpu::Limits::default(),
+                    required_features: Self::required_features(&adapter),
+                    required_limits: Self::required_limits(&adapter),
                     // MemoryUsage hint tells the driver to prefer lower memory over performance.
                     // This helps reduce RSS on integrated GPUs (Apple Silicon) where GPU memory
                     // is shared with CPU and counts against process memory.
@@ -813,13 +1035,15 @@ impl GpuRenderer {
         let queue = Arc::new(queue);
 
         // Default texture format for headless
-        let texture_format = config
-            .texture_format
-            .unwrap_or(wgpu::TextureFormat::Bgra8UnormSrgb);
+        let texture_format = config.color_space.target_format(
+            config
+                .texture_format
+                .unwrap_or(wgpu::TextureFormat::Bgra8UnormSrgb),
+        );
 
         Self::create_renderer(
-            instance,
-            adapter,
+            Arc::new(instance),
+            Arc::new(adapter),
             device,
             queue,
             texture_format,
@@ -862,8 +1086,8 @@ impl GpuRenderer {
             .request_device(
                 &wgpu::DeviceDescriptor {
                     label: Some("Blinc GPU Device"),
-                    required_features: wgpu::Features::empty(),
-                    required_limits: wgpu::Limits::default(),
+                    required_features: Self::required_features(&adapter),
+                    required_limits: Self::required_limits(&adapter),
                     // MemoryUsage hint tells the driver to prefer lower memory over performance.
                     // This helps reduce RSS on integrated GPUs (Apple Silicon) where GPU memory
                     // is shared with CPU and counts against process memory.
@@ -884,35 +1108,18 @@ impl GpuRenderer {
             surface_caps.alpha_modes
         );
 
-        // Select texture format based on platform
-        let texture_format = config.texture_format.unwrap_or_else(|| {
-            // On macOS, prefer non-sRGB format to avoid automatic gamma correction
-            // which causes colors to appear washed out. Other platforms may behave
-            // differently, so we use sRGB there for now.
-            #[cfg(target_os = "macos")]
-            {
-                surface_caps
-                    .formats
-                    .iter()
-                    .find(|f| !f.is_srgb())
-                    .copied()
-                    .unwrap_or(surface_caps.formats[0])
-            }
-            #[cfg(not(target_os = "macos"))]
-            {
-                surface_caps
-                    .formats
-                    .iter()
-                    .find(|f| f.is_srgb())
-                    .copied()
-                    .unwrap_or(surface_caps.formats[0])
-            }
-        });
+        // Pick a surface format matching the color space: sRGB-encoding for
+        // linear blending, plain otherwise (an sRGB surface would encode the
+        // sRGB colors a second time and wash them out)
+        let texture_format = match config.texture_format {
+            Some(format) => config.color_space.target_format(format),
+            None => Self::surface_format(&surface_caps.formats, config.color_space),
+        };
         tracing::debug!("Selected texture format: {:?}", texture_format);
 
         let renderer = Self::create_renderer(
-            instance,
-            adapter,
+            Arc::new(instance),
+            Arc::new(adapter),
             device,
             queue,
             texture_format,
@@ -945,8 +1152,8 @@ impl GpuRenderer {
             .request_device(
                 &wgpu::DeviceDescriptor {
                     label: Some("Blinc GPU Device"),
-                    required_features: wgpu::Features::empty(),
-                    required_limits: wgpu::Limits::default(),
+                    required_features: Self::required_features(&adapter),
+                    required_limits: Self::required_limits(&adapter),
                     memory_hints: wgpu::MemoryHints::MemoryUsage,
                 },
                 None,
@@ -960,24 +1167,16 @@ impl GpuRenderer {
         let surface_caps = surface.get_capabilities(&adapter);
         tracing::debug!("Surface capabilities - formats: {:?}", surface_caps.formats);
 
-        // Select texture format based on platform
-        let texture_format = config.texture_format.unwrap_or_else(|| {
-            // On Android, prefer non-sRGB format to match macOS behavior
-            // Using sRGB causes colors to appear washed out because the GPU
-            // applies automatic gamma correction
-            surface_caps
-                .formats
-                .iter()
-                .find(|f| !f.is_srgb())
-                .copied()
-                .unwrap_or(surface_caps.formats[0])
-        });
+        let texture_format = match config.texture_format {
+            Some(format) => config.color_space.target_format(format),
+            None => Self::surface_format(&surface_caps.formats, config.color_space),
+        };
         tracing::info!("Surface formats available: {:?}", surface_caps.formats);
         tracing::info!("Selected texture format: {:?}", texture_format);
 
         Self::create_renderer(
-            instance,
-            adapter,
+            Arc::new(instance),
+            Arc::new(adapter),
             device,
             queue,
             texture_format,
@@ -986,15 +1185,69 @@ impl GpuRenderer {
         )
     }
 
+    /// Create another headless renderer on this renderer's device
+    ///
+    /// Use for additional windows: renderers on one device can share GPU
+    /// resources such as a [`SharedGlyphAtlas`] given through
+    /// [`RendererConfig::glyph_atlas`].
+    pub fn on_same_device(&self, config: RendererConfig) -> Result<Self, RendererError> {
+        let texture_format = config.color_space.target_format(
+            config
+                .texture_format
+                .unwrap_or(wgpu::TextureFormat::Bgra8UnormSrgb),
+        );
+        Self::create_renderer(
+            self.instance.clone(),
+            self.adapter.clone(),
+            self.device.clone(),
+            self.queue.clone(),
+            texture_format,
+            config,
+            (800, 600),
+        )
+    }
+
+    /// Pick the first surface format whose sRGB-ness matches the color space
+    fn surface_format(
+        formats: &[wgpu::TextureFormat],
+        color_space: ColorSpace,
+    ) -> wgpu::TextureFormat {
+        let want_srgb = color_space == ColorSpace::Linear;
+        formats
+            .iter()
+            .find(|f| f.is_srgb() == want_srgb)
+            .copied()
+            .unwrap_or(formats[0])
+    }
+
     fn create_renderer(
-        instance: wgpu::Instance,
-        adapter: wgpu::Adapter,
+        instance: Arc<wgpu::Instance>,
+        adapter: Arc<wgpu::Adapter>,
         device: Arc<wgpu::Device>,
         queue: Arc<wgpu::Queue>,
         texture_format: wgpu::TextureFormat,
-        config: RendererConfig,
+        mut config: RendererConfig,
         viewport_size: (u32, u32),
     ) -> Result<Self, RendererError> {
+        // Lower the MSAA sample count to one the adapter can render
+        let supported = Self::sample_counts_for(&adapter, &device, texture_format);
+        let sample_count = supported
+            .iter()
+            .rev()
+            .copied()
+            .find(|&count| count <= config.sample_count)
+            .unwrap_or(1);
+        if sample_count != config.sample_count {
+            tracing::warn!(
+                "MSAA x{} not supported for {:?} (supported: {:?}), using x{}",
+                config.sample_count,
+                texture_format,
+                supported,
+                sample_count
+            );
+            config.sample_count = sample_count;
+        }
+
         // Create bind group layouts
         let bind_group_layouts = Self::create_bind_group_layouts(&device);
 
@@ -1055,6 +1308,11 @@ impl GpuRenderer {
             source: wgpu::ShaderSource::Wgsl(GLOW_SHADER.into()),
         });
 
+        let post_process_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Post Process Effect Shader"),
+            source: wgpu::ShaderSource::Wgsl(POST_PROCESS_SHADER.into()),
+        });
+
         // Create pipelines
         let pipelines = Self::create_pipelines(
             &device,
@@ -1070,6 +1328,7 @@ impl GpuRenderer {
             &color_matrix_shader,
             &drop_shadow_shader,
             &glow_shader,
+            &post_process_shader,
             texture_format,
             config.sample_count,
         );
@@ -1217,6 +1476,13 @@ impl GpuRenderer {
             placeholder_path_image_view,
             path_image_sampler,
             layer_texture_cache: LayerTextureCache::new(texture_format),
+            msaa_targets: HashMap::new(),
+            temporal: None,
+            jitter: [0.0; 2],
+            damage: None,
+            draw_calls: AtomicU32::new(0),
+            cached_layers: HashMap::new(),
+            frame_index: 0,
         })
     }
 
@@ -1683,6 +1949,53 @@ impl GpuRenderer {
             ],
         });
 
+        // Bloom and tone mapping bind group layout
+        let post_process = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Post Process Effect Bind Group Layout"),
+            entries: &[
+                // PostProcessUniforms
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Input texture
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                // Input sampler
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+                // Blurred bright pixels (bloom combine)
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
         BindGroupLayouts {
             sdf,
             glass,
@@ -1694,6 +2007,7 @@ impl GpuRenderer {
             color_matrix,
             drop_shadow,
             glow,
+            post_process,
         }
     }
 
@@ -1711,9 +2025,11 @@ impl GpuRenderer {
         color_matrix_shader: &wgpu::ShaderModule,
         drop_shadow_shader: &wgpu::ShaderModule,
         glow_shader: &wgpu::ShaderModule,
+        post_process_shader: &wgpu::ShaderModule,
         texture_format: wgpu::TextureFormat,
         sample_count: u32,
     ) -> Pipelines {
+        let output_constants = output_color_constants(texture_format);
         let blend_state = wgpu::BlendState {
             color: wgpu::BlendComponent {
                 src_factor: wgpu::BlendFactor::SrcAlpha,
@@ -1769,7 +2085,10 @@ impl GpuRenderer {
                 module: sdf_shader,
                 entry_point: Some("fs_main"),
                 targets: color_targets,
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
+                compilation_options: wgpu::PipelineCompilationOptions {
+                    constants: &output_constants,
+                    ..Default::default()
+                },
             }),
             primitive: primitive_state,
             depth_stencil: None,
@@ -1798,7 +2117,10 @@ impl GpuRenderer {
                 module: sdf_shader,
                 entry_point: Some("fs_main"),
                 targets: color_targets,
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
+                compilation_options: wgpu::PipelineCompilationOptions {
+                    constants: &output_constants,
+                    ..Default::default()
+                },
             }),
             primitive: primitive_state,
             depth_stencil: None,
@@ -1887,7 +2209,10 @@ impl GpuRenderer {
                 module: text_shader,
                 entry_point: Some("fs_main"),
                 targets: color_targets,
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
+                compilation_options: wgpu::PipelineCompilationOptions {
+                    constants: &output_constants,
+                    ..Default::default()
+                },
             }),
             primitive: primitive_state,
             depth_stencil: None,
@@ -1910,7 +2235,10 @@ impl GpuRenderer {
                 module: text_shader,
                 entry_point: Some("fs_main"),
                 targets: color_targets,
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
+                compilation_options: wgpu::PipelineCompilationOptions {
+                    constants: &output_constants,
+                    ..Default::default()
+                },
             }),
             primitive: primitive_state,
             depth_stencil: None,
@@ -2050,7 +2378,10 @@ impl GpuRenderer {
                 module: path_shader,
                 entry_point: Some("fs_main"),
                 targets: color_targets,
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
+                compilation_options: wgpu::PipelineCompilationOptions {
+                    constants: &output_constants,
+                    ..Default::default()
+                },
             }),
             primitive: primitive_state,
             depth_stencil: None,
@@ -2073,7 +2404,10 @@ impl GpuRenderer {
                 module: path_shader,
                 entry_point: Some("fs_main"),
                 targets: color_targets,
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
+                compilation_options: wgpu::PipelineCompilationOptions {
+                    constants: &output_constants,
+                    ..Default::default()
+                },
             }),
             primitive: primitive_state,
             depth_stencil: None,
@@ -2299,6 +2633,42 @@ impl GpuRenderer {
             cache: None,
         });
 
+        // Bloom and tone mapping pipeline
+        let post_process_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Post Process Effect Pipeline Layout"),
+            bind_group_layouts: &[&layouts.post_process],
+            push_constant_ranges: &[],
+        });
+
+        // Every pass outputs its final result - no blending needed
+        let post_process_targets = &[Some(wgpu::ColorTargetState {
+            format: texture_format,
+            blend: None,
+            write_mask: wgpu::ColorWrites::ALL,
+        })];
+
+        let post_process = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Post Process Effect Pipeline"),
+            layout: Some(&post_process_layout),
+            vertex: wgpu::VertexState {
+                module: post_process_shader,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: post_process_shader,
+                entry_point: Some("fs_post_process"),
+                targets: post_process_targets,
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: effect_primitive_state,
+            depth_stencil: None,
+            multisample: overlay_multisample_state, // 1x sampled
+            multiview: None,
+            cache: None,
+        });
+
         Pipelines {
             sdf,
             sdf_overlay,
@@ -2315,6 +2685,7 @@ impl GpuRenderer {
             color_matrix,
             drop_shadow,
             glow,
+            post_process,
         }
     }
 
@@ -2390,6 +2761,13 @@ impl GpuRenderer {
             mapped_at_creation: false,
         });
 
+        let post_process_uniforms = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Post Process Uniforms Buffer"),
+            size: std::mem::size_of::<PostProcessUniforms>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
         Buffers {
             uniforms,
             primitives,
@@ -2403,6 +2781,7 @@ impl GpuRenderer {
             drop_shadow_uniforms,
             glow_uniforms,
             color_matrix_uniforms,
+            post_process_uniforms,
         }
     }
 
@@ -2504,6 +2883,7 @@ impl GpuRenderer {
         texture_format: wgpu::TextureFormat,
         sample_count: u32,
     ) -> MsaaPipelines {
+        let output_constants = output_color_constants(texture_format);
         let blend_state = wgpu::BlendState {
             color: wgpu::BlendComponent {
                 src_factor: wgpu::BlendFactor::SrcAlpha,
@@ -2564,7 +2944,10 @@ impl GpuRenderer {
                 module: &sdf_shader,
                 entry_point: Some("fs_main"),
                 targets: color_targets,
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
+                compilation_options: wgpu::PipelineCompilationOptions {
+                    constants: &output_constants,
+                    ..Default::default()
+                },
             }),
             primitive: primitive_state,
             depth_stencil: None,
@@ -2641,7 +3024,10 @@ impl GpuRenderer {
                 module: &path_shader,
                 entry_point: Some("fs_main"),
                 targets: color_targets,
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
+                compilation_options: wgpu::PipelineCompilationOptions {
+                    constants: &output_constants,
+                    ..Default::default()
+                },
             }),
             primitive: primitive_state,
             depth_stencil: None,
@@ -2667,6 +3053,61 @@ impl GpuRenderer {
         self.time = time;
     }
 
+    /// Redraw only a damaged region of the target for the next frame
+    ///
+    /// `rect` is in physical pixels (see `RenderTree::damage_rect`) and is
+    /// clamped to the viewport. While set, [`Self::render_with_clear`] keeps
+    /// the target's existing content, clears just the region and draws the
+    /// batch scissored to it, and the overlay passes (text, images, paths)
+    /// are scissored the same way. The target must therefore still hold the
+    /// previous frame - render into a retained texture such as a
+    /// [`Backbuffer`](crate::Backbuffer) after
+    /// [`carry_over`](crate::Backbuffer::carry_over), not a fresh swapchain
+    /// image.
+    ///
+    /// Frames that can't be redrawn partially fall back to a full redraw and
+    /// reset the damage region: layer effects, temporal AA, MSAA main
+    /// pipelines, a translucent clear color, and glass frames.
+    pub fn set_damage_rect(&mut self, rect: Option<blinc_core::Rect>) {
+        let (vw, vh) = self.viewport_size;
+        self.damage = rect.and_then(|rect| {
+            let x0 = rect.x().floor().clamp(0.0, vw as f32) as u32;
+            let y0 = rect.y().floor().clamp(0.0, vh as f32) as u32;
+            let x1 = (rect.x() + rect.width()).ceil().clamp(0.0, vw as f32) as u32;
+            let y1 = (rect.y() + rect.height()).ceil().clamp(0.0, vh as f32) as u32;
+            (x1 > x0 && y1 > y0).then(|| [x0, y0, x1 - x0, y1 - y0])
+        });
+    }
+
+    /// Active damage region (x, y, width, height), if the frame is partial
+    pub fn damage_rect(&self) -> Option<[u32; 4]> {
+        self.damage
+    }
+
+    /// Restrict a pass drawing into the viewport to the damage region
+    fn apply_damage_scissor(&self, render_pass: &mut wgpu::RenderPass<'_>) {
+        if let Some([x, y, width, height]) = self.damage {
+            render_pass.set_scissor_rect(x, y, width, height);
+        }
+    }
+
+    /// MSAA sample counts the adapter supports for the render target format
+    ///
+    /// Always contains 1; a subset of [`MSAA_SAMPLE_COUNTS`].
+    pub fn supported_sample_counts(&self) -> Vec<u32> {
+        Self::sample_counts_for(&self.adapter, &self.device, self.texture_format)
+    }
+
+    /// MSAA sample count of the main SDF and path pipelines
+    pub fn sample_count(&self) -> u32 {
+        self.config.sample_count
+    }
+
+    /// Get the wgpu adapter the device was created from
+    pub fn adapter(&self) -> &wgpu::Adapter {
+        &self.adapter
+    }
+
     /// Get the wgpu device
     pub fn device(&self) -> &wgpu::Device {
         &self.device
@@ -2687,6 +3128,42 @@ impl GpuRenderer {
         self.queue.clone()
     }
 
+    /// Create a text rendering context on this renderer's device
+    ///
+    /// Uses the configured [`RendererConfig::glyph_atlas`] if there is one,
+    /// otherwise a fresh atlas.
+    pub fn create_text_context(&self) -> TextRenderingContext {
+        match &self.config.glyph_atlas {
+            Some(atlas) => TextRenderingContext::with_shared_atlas(
+                self.device.clone(),
+                self.queue.clone(),
+                atlas.clone(),
+            ),
+            None => TextRenderingContext::new(self.device.clone(), self.queue.clone()),
+        }
+    }
+
+    /// Number of draw calls issued since the last [`reset_draw_calls`]
+    ///
+    /// SDF primitives are drawn instanced, one draw per contiguous run
+    /// between layer boundaries, so this stays small however many
+    /// primitives a frame has.
+    ///
+    /// [`reset_draw_calls`]: Self::reset_draw_calls
+    pub fn draw_calls(&self) -> u32 {
+        self.draw_calls.load(Ordering::Relaxed)
+    }
+
+    /// Reset the draw call counter, e.g. at the start of a frame
+    pub fn reset_draw_calls(&mut self) {
+        *self.draw_calls.get_mut() = 0;
+    }
+
+    /// Count a draw call issued on a render pass
+    fn count_draw(&self) {
+        self.draw_calls.fetch_add(1, Ordering::Relaxed);
+    }
+
     /// Get the texture format used by this renderer's pipelines
     pub fn texture_format(&self) -> wgpu::TextureFormat {
         self.texture_format
@@ -2731,6 +3208,83 @@ impl GpuRenderer {
         self.viewport_size = original_size;
     }
 
+    /// Create the multisampled attachment for `size` if MSAA is enabled
+    ///
+    /// Attachments are cached per size (viewport, half-res backdrop, ...);
+    /// the cache is dropped when it grows past a handful of sizes, which
+    /// only happens across resizes.
+    fn prepare_msaa_target(&mut self, size: (u32, u32)) {
+        if self.config.sample_count <= 1 || self.msaa_targets.contains_key(&size) {
+            return;
+        }
+        if self.msaa_targets.len() >= 4 {
+            self.msaa_targets.clear();
+        }
+
+        let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Blinc MSAA Color Target"),
+            size: wgpu::Extent3d {
+                width: size.0.max(1),
+                height: size.1.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: self.config.sample_count,
+            dimension: wgpu::TextureDimension::D2,
+            format: self.texture_format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        self.msaa_targets.insert(size, MsaaTarget { texture, view });
+    }
+
+    /// Color attachment for the main SDF/path pipelines drawing into `target`
+    ///
+    /// With MSAA the pass draws into the multisampled attachment prepared by
+    /// [`Self::prepare_msaa_target`] and resolves into `target` at the end of
+    /// the pass. Only use with `LoadOp::Clear` - the multisampled contents
+    /// are discarded after resolving.
+    fn main_color_attachment<'a>(
+        &'a self,
+        target: &'a wgpu::TextureView,
+        size: (u32, u32),
+        load: wgpu::LoadOp<wgpu::Color>,
+    ) -> wgpu::RenderPassColorAttachment<'a> {
+        let msaa = self
+            .msaa_targets
+            .get(&size)
+            .filter(|_| self.config.sample_count > 1);
+        match msaa {
+            Some(msaa) => wgpu::RenderPassColorAttachment {
+                view: &msaa.view,
+                resolve_target: Some(target),
+                ops: wgpu::Operations {
+                    load,
+                    store: wgpu::StoreOp::Discard,
+                },
+            },
+            None => wgpu::RenderPassColorAttachment {
+                view: target,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load,
+                    store: wgpu::StoreOp::Store,
+                },
+            },
+        }
+    }
+
+    /// SDF pipeline for passes that draw onto existing single-sampled content
+    /// (foreground over glass, offscreen layer textures)
+    fn single_sample_sdf_pipeline(&self) -> &wgpu::RenderPipeline {
+        if self.config.sample_count > 1 {
+            &self.pipelines.sdf_overlay
+        } else {
+            &self.pipelines.sdf
+        }
+    }
+
     /// Render primitives with a specified clear color
     ///
     /// # Arguments
@@ -2746,6 +3300,7 @@ impl GpuRenderer {
         // Evict oversized textures from the pool at frame start
         // This prevents memory bloat from accumulated large textures
         self.layer_texture_cache.evict_oversized();
+        self.evict_idle_cached_layers();
 
         // Check if we have layer commands with effects that need processing
         let has_layer_effects = batch.layer_commands.iter().any(|entry| {
@@ -2763,39 +3318,281 @@ impl GpuRenderer {
             has_layer_effects
         );
 
+        // Partial redraws need a single-sampled main pass over retained
+        // content and an opaque clear that fully replaces the region
+        if self.damage.is_some()
+            && (has_layer_effects
+                || self.config.temporal_aa
+                || self.config.sample_count > 1
+                || clear_color[3] < 1.0)
+        {
+            tracing::trace!("render_with_clear: damage region unsupported, full redraw");
+            self.damage = None;
+        }
+
         // If we have layer effects, use the layer-aware rendering path
         if has_layer_effects {
             self.render_with_layer_effects(target, batch, clear_color);
-            return;
+        } else if self.config.temporal_aa {
+            self.render_temporal(target, batch, clear_color);
+        } else {
+            self.render_with_clear_simple(target, batch, clear_color);
         }
 
-        // Standard rendering (no layer effects)
-        self.render_with_clear_simple(target, batch, clear_color);
+        // Cached layers composite on top of the batch, like effect layers
+        self.composite_cached_layers(target, batch, (0.0, 0.0), None);
     }
 
-    /// Simple render with clear (no layer effect processing)
-    fn render_with_clear_simple(
+    /// Render through the temporal AA history
+    ///
+    /// A changed batch re-seeds the history with an un-jittered frame; an
+    /// unchanged one blends one more jittered sample into it (running
+    /// average). The history is then composited onto `target`, so a static
+    /// frame converges to a supersampled image over a few frames.
+    fn render_temporal(
         &mut self,
         target: &wgpu::TextureView,
         batch: &PrimitiveBatch,
         clear_color: [f64; 4],
     ) {
-        // Update uniforms
-        let uniforms = Uniforms {
-            viewport_size: [self.viewport_size.0 as f32, self.viewport_size.1 as f32],
-            _padding: [0.0; 2],
+        let size = self.viewport_size;
+        let key = Self::frame_key(batch, clear_color, size);
+        let mut temporal = match self.temporal.take() {
+            Some(temporal) if temporal.size == size => temporal,
+            _ => self.create_temporal_accumulation(size),
+        };
+
+        if temporal.samples == 0 || temporal.frame_key != key {
+            self.render_with_clear_simple(&temporal.history_view, batch, clear_color);
+            temporal.frame_key = key;
+            temporal.samples = 1;
+        } else if temporal.samples < TEMPORAL_AA_MAX_SAMPLES {
+            self.jitter = temporal_jitter(temporal.samples);
+            self.render_with_clear_simple(&temporal.scratch_view, batch, clear_color);
+            self.jitter = [0.0; 2];
+
+            // Running average: the new sample is weighted 1/n
+            temporal.samples += 1;
+            let uniforms = CompositeUniforms {
+                opacity: 1.0 / temporal.samples as f32,
+                blend_mode: 0,
+                _padding: [0.0; 2],
+            };
+            self.queue
+                .write_buffer(&temporal.scratch_uniforms, 0, bytemuck::bytes_of(&uniforms));
+            self.composite_pass(
+                &temporal.history_view,
+                &temporal.scratch_bind_group,
+                wgpu::LoadOp::Load,
+                "Temporal AA Accumulate Pass",
+            );
+        }
+
+        self.composite_pass(
+            target,
+            &temporal.history_bind_group,
+            wgpu::LoadOp::Clear(wgpu::Color {
+                r: clear_color[0],
+                g: clear_color[1],
+                b: clear_color[2],
+                a: clear_color[3],
+            }),
+            "Temporal AA Resolve Pass",
+        );
+        self.temporal = Some(temporal);
+    }
+
+    /// Hash of everything `render_with_clear_simple` draws
+    fn frame_key(batch: &PrimitiveBatch, clear_color: [f64; 4], size: (u32, u32)) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        size.hash(&mut hasher);
+        for channel in clear_color {
+            channel.to_bits().hash(&mut hasher);
+        }
+        bytemuck::cast_slice::<_, u8>(&batch.primitives).hash(&mut hasher);
+        bytemuck::cast_slice::<_, u8>(&batch.paths.vertices).hash(&mut hasher);
+        bytemuck::cast_slice::<_, u8>(&batch.paths.indices).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn create_temporal_accumulation(&self, size: (u32, u32)) -> TemporalAccumulation {
+        let create_texture = |label| {
+            let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+                label: Some(label),
+                size: wgpu::Extent3d {
+                    width: size.0.max(1),
+                    height: size.1.max(1),
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: self.texture_format,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                    | wgpu::TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            });
+            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+            (texture, view)
+        };
+        let (history_texture, history_view) = create_texture("Temporal AA History");
+        let (scratch_texture, scratch_view) = create_texture("Temporal AA Scratch");
+
+        // Pixel-aligned fullscreen copies, so no filtering
+        let sampler = self.device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Temporal AA Sampler"),
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        let opaque = CompositeUniforms {
+            opacity: 1.0,
+            blend_mode: 0,
+            _padding: [0.0; 2],
+        };
+        let history_uniforms = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Temporal AA History Uniforms"),
+                contents: bytemuck::bytes_of(&opaque),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+        let scratch_uniforms = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Temporal AA Scratch Uniforms"),
+                contents: bytemuck::bytes_of(&opaque),
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            });
+
+        let bind_group = |label, uniforms: &wgpu::Buffer, view| {
+            self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some(label),
+                layout: &self.bind_group_layouts.composite,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: uniforms.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::TextureView(view),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: wgpu::BindingResource::Sampler(&sampler),
+                    },
+                ],
+            })
+        };
+        let history_bind_group = bind_group(
+            "Temporal AA History Bind Group",
+            &history_uniforms,
+            &history_view,
+        );
+        let scratch_bind_group = bind_group(
+            "Temporal AA Scratch Bind Group",
+            &scratch_uniforms,
+            &scratch_view,
+        );
+
+        TemporalAccumulation {
+            size,
+            history_texture,
+            history_view,
+            scratch_texture,
+            scratch_view,
+            scratch_uniforms,
+            scratch_bind_group,
+            history_bind_group,
+            frame_key: 0,
+            samples: 0,
+        }
+    }
+
+    /// Draw a fullscreen composite of `bind_group`'s texture onto `target`
+    fn composite_pass(
+        &self,
+        target: &wgpu::TextureView,
+        bind_group: &wgpu::BindGroup,
+        load: wgpu::LoadOp<wgpu::Color>,
+        label: &str,
+    ) {
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: Some(label) });
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some(label),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: target,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load,
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            render_pass.set_pipeline(&self.pipelines.composite_overlay);
+            render_pass.set_bind_group(0, bind_group, &[]);
+            self.count_draw();
+            render_pass.draw(0..3, 0..1); // Fullscreen triangle
+        }
+        self.queue.submit(std::iter::once(encoder.finish()));
+    }
+
+    /// Simple render with clear (no layer effect processing)
+    fn render_with_clear_simple(
+        &mut self,
+        target: &wgpu::TextureView,
+        batch: &PrimitiveBatch,
+        clear_color: [f64; 4],
+    ) {
+        self.prepare_msaa_target(self.viewport_size);
+
+        // Update uniforms
+        let uniforms = Uniforms {
+            viewport_size: [self.viewport_size.0 as f32, self.viewport_size.1 as f32],
+            jitter: self.jitter,
         };
         self.queue
             .write_buffer(&self.buffers.uniforms, 0, bytemuck::bytes_of(&uniforms));
 
-        // Update primitives buffer
+        // Update primitives buffer. A damaged-region redraw keeps the
+        // previous frame, so the region is cleared with an opaque quad drawn
+        // first (LoadOp::Clear would wipe the whole target).
+        let clear_quad = self.damage.map(|[x, y, width, height]| {
+            // 1px bleed so edge antialiasing stays outside the scissor
+            GpuPrimitive::rect(
+                x as f32 - 1.0,
+                y as f32 - 1.0,
+                width as f32 + 2.0,
+                height as f32 + 2.0,
+            )
+            .with_color(
+                clear_color[0] as f32,
+                clear_color[1] as f32,
+                clear_color[2] as f32,
+                clear_color[3] as f32,
+            )
+        });
+        let first_primitive = clear_quad.is_some() as usize;
+        if let Some(clear_quad) = clear_quad {
+            self.queue
+                .write_buffer(&self.buffers.primitives, 0, bytemuck::bytes_of(&clear_quad));
+        }
         if !batch.primitives.is_empty() {
             self.queue.write_buffer(
                 &self.buffers.primitives,
-                0,
+                (first_primitive * std::mem::size_of::<GpuPrimitive>()) as u64,
                 bytemuck::cast_slice(&batch.primitives),
             );
         }
+        let primitive_count = first_primitive + batch.primitives.len();
 
         // Update path buffers if we have path geometry
         let has_paths = !batch.paths.vertices.is_empty() && !batch.paths.indices.is_empty();
@@ -2810,34 +3607,39 @@ impl GpuRenderer {
                 label: Some("Blinc Render Encoder"),
             });
 
+        let load = if clear_quad.is_some() {
+            wgpu::LoadOp::Load
+        } else {
+            wgpu::LoadOp::Clear(wgpu::Color {
+                r: clear_color[0],
+                g: clear_color[1],
+                b: clear_color[2],
+                a: clear_color[3],
+            })
+        };
+
         // Begin render pass
         {
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("Blinc Render Pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: target,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color {
-                            r: clear_color[0],
-                            g: clear_color[1],
-                            b: clear_color[2],
-                            a: clear_color[3],
-                        }),
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
+                color_attachments: &[Some(self.main_color_attachment(
+                    target,
+                    self.viewport_size,
+                    load,
+                ))],
                 depth_stencil_attachment: None,
                 timestamp_writes: None,
                 occlusion_query_set: None,
             });
+            self.apply_damage_scissor(&mut render_pass);
 
             // Render SDF primitives
-            if !batch.primitives.is_empty() {
+            if primitive_count > 0 {
                 render_pass.set_pipeline(&self.pipelines.sdf);
                 render_pass.set_bind_group(0, &self.bind_groups.sdf, &[]);
                 // 6 vertices per quad (2 triangles), one instance per primitive
-                render_pass.draw(0..6, 0..batch.primitives.len() as u32);
+                self.count_draw();
+                render_pass.draw(0..6, 0..primitive_count as u32);
             }
 
             // Render paths
@@ -2849,6 +3651,7 @@ impl GpuRenderer {
                     render_pass.set_bind_group(0, &self.bind_groups.path, &[]);
                     render_pass.set_vertex_buffer(0, vb.slice(..));
                     render_pass.set_index_buffer(ib.slice(..), wgpu::IndexFormat::Uint32);
+                    self.count_draw();
                     render_pass.draw_indexed(0..batch.paths.indices.len() as u32, 0, 0..1);
                 }
             }
@@ -3048,6 +3851,8 @@ impl GpuRenderer {
             return;
         }
 
+        self.prepare_msaa_target(self.viewport_size);
+
         // Build list of primitives to render (excluding those in effect layers)
         let included_primitives: Vec<GpuPrimitive> = batch
             .primitives
@@ -3067,19 +3872,16 @@ impl GpuRenderer {
             {
                 let _render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                     label: Some("Clear Pass"),
-                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                        view: target,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(wgpu::Color {
-                                r: clear_color[0],
-                                g: clear_color[1],
-                                b: clear_color[2],
-                                a: clear_color[3],
-                            }),
-                            store: wgpu::StoreOp::Store,
-                        },
-                    })],
+                    color_attachments: &[Some(self.main_color_attachment(
+                        target,
+                        self.viewport_size,
+                        wgpu::LoadOp::Clear(wgpu::Color {
+                            r: clear_color[0],
+                            g: clear_color[1],
+                            b: clear_color[2],
+                            a: clear_color[3],
+                        }),
+                    ))],
                     depth_stencil_attachment: None,
                     timestamp_writes: None,
                     occlusion_query_set: None,
@@ -3092,7 +3894,7 @@ impl GpuRenderer {
         // Update uniforms
         let uniforms = Uniforms {
             viewport_size: [self.viewport_size.0 as f32, self.viewport_size.1 as f32],
-            _padding: [0.0; 2],
+            jitter: [0.0; 2],
         };
         self.queue
             .write_buffer(&self.buffers.uniforms, 0, bytemuck::bytes_of(&uniforms));
@@ -3123,19 +3925,16 @@ impl GpuRenderer {
         {
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("Filtered Render Pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: target,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color {
-                            r: clear_color[0],
-                            g: clear_color[1],
-                            b: clear_color[2],
-                            a: clear_color[3],
-                        }),
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
+                color_attachments: &[Some(self.main_color_attachment(
+                    target,
+                    self.viewport_size,
+                    wgpu::LoadOp::Clear(wgpu::Color {
+                        r: clear_color[0],
+                        g: clear_color[1],
+                        b: clear_color[2],
+                        a: clear_color[3],
+                    }),
+                ))],
                 depth_stencil_attachment: None,
                 timestamp_writes: None,
                 occlusion_query_set: None,
@@ -3145,6 +3944,7 @@ impl GpuRenderer {
             if !included_primitives.is_empty() {
                 render_pass.set_pipeline(&self.pipelines.sdf);
                 render_pass.set_bind_group(0, &self.bind_groups.sdf, &[]);
+                self.count_draw();
                 render_pass.draw(0..6, 0..included_primitives.len() as u32);
             }
 
@@ -3157,6 +3957,7 @@ impl GpuRenderer {
                     render_pass.set_bind_group(0, &self.bind_groups.path, &[]);
                     render_pass.set_vertex_buffer(0, vb.slice(..));
                     render_pass.set_index_buffer(ib.slice(..), wgpu::IndexFormat::Uint32);
+                    self.count_draw();
                     render_pass.draw_indexed(0..batch.paths.indices.len() as u32, 0, 0..1);
                 }
             }
@@ -3175,12 +3976,13 @@ impl GpuRenderer {
                     &self.queue,
                     stops,
                     crate::gradient_texture::SpreadMode::Pad,
+                    batch.paths.gradient_interpolation,
                 );
             }
         }
 
         // Update path uniforms with clip data and brush metadata from batch
-        let path_uniforms = PathUniforms {
+        let mut path_uniforms = PathUniforms {
             viewport_size: [self.viewport_size.0 as f32, self.viewport_size.1 as f32],
             clip_bounds: batch.paths.clip_bounds,
             clip_radius: batch.paths.clip_radius,
@@ -3197,6 +3999,9 @@ impl GpuRenderer {
             glass_tint: batch.paths.glass_tint,
             ..PathUniforms::default()
         };
+        // Temporal AA jitter moves the tessellated geometry itself
+        path_uniforms.transform[0][2] += self.jitter[0];
+        path_uniforms.transform[1][2] += self.jitter[1];
         self.queue.write_buffer(
             &self.buffers.path_uniforms,
             0,
@@ -3263,7 +4068,7 @@ impl GpuRenderer {
         // Update uniforms
         let uniforms = Uniforms {
             viewport_size: [self.viewport_size.0 as f32, self.viewport_size.1 as f32],
-            _padding: [0.0; 2],
+            jitter: [0.0; 2],
         };
         self.queue
             .write_buffer(&self.buffers.uniforms, 0, bytemuck::bytes_of(&uniforms));
@@ -3316,6 +4121,7 @@ impl GpuRenderer {
             if !batch.primitives.is_empty() {
                 render_pass.set_pipeline(&self.pipelines.sdf);
                 render_pass.set_bind_group(0, &self.bind_groups.sdf, &[]);
+                self.count_draw();
                 render_pass.draw(0..6, 0..batch.primitives.len() as u32);
             }
 
@@ -3328,6 +4134,7 @@ impl GpuRenderer {
                     render_pass.set_bind_group(0, &self.bind_groups.path, &[]);
                     render_pass.set_vertex_buffer(0, vb.slice(..));
                     render_pass.set_index_buffer(ib.slice(..), wgpu::IndexFormat::Uint32);
+                    self.count_draw();
                     render_pass.draw_indexed(0..batch.paths.indices.len() as u32, 0, 0..1);
                 }
             }
@@ -3490,6 +4297,7 @@ impl GpuRenderer {
             if simple_count > 0 {
                 render_pass.set_pipeline(&self.pipelines.simple_glass);
                 render_pass.set_bind_group(0, glass_bind_group, &[]);
+                self.count_draw();
                 render_pass.draw(0..6, 0..simple_count as u32);
             }
 
@@ -3497,6 +4305,7 @@ impl GpuRenderer {
             if liquid_count > 0 {
                 render_pass.set_pipeline(&self.pipelines.glass);
                 render_pass.set_bind_group(0, glass_bind_group, &[]);
+                self.count_draw();
                 render_pass.draw(
                     0..6,
                     simple_count as u32..(simple_count + liquid_count) as u32,
@@ -3515,19 +4324,23 @@ impl GpuRenderer {
     pub fn render_to_backdrop(
         &mut self,
         backdrop: &wgpu::TextureView,
-        _backdrop_size: (u32, u32),
+        backdrop_size: (u32, u32),
         batch: &PrimitiveBatch,
     ) {
         if batch.primitives.is_empty() {
             return;
         }
 
+        // With MSAA, render multisampled and resolve into the backdrop so the
+        // glass blur samples a single-sampled texture
+        self.prepare_msaa_target(backdrop_size);
+
         // Use full viewport size for coordinate mapping, even though texture is smaller.
         // GPU automatically maps NDC space to the texture size, ensuring primitives
         // appear at correct relative positions for glass sampling.
         let main_uniforms = Uniforms {
             viewport_size: [self.viewport_size.0 as f32, self.viewport_size.1 as f32],
-            _padding: [0.0; 2],
+            jitter: [0.0; 2],
         };
         self.queue.write_buffer(
             &self.buffers.uniforms,
@@ -3553,14 +4366,11 @@ impl GpuRenderer {
         {
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("Backdrop Render Pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: backdrop,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
+                color_attachments: &[Some(self.main_color_attachment(
+                    backdrop,
+                    backdrop_size,
+                    wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                ))],
                 depth_stencil_attachment: None,
                 timestamp_writes: None,
                 occlusion_query_set: None,
@@ -3568,6 +4378,7 @@ impl GpuRenderer {
 
             render_pass.set_pipeline(&self.pipelines.sdf);
             render_pass.set_bind_group(0, &self.bind_groups.sdf, &[]);
+            self.count_draw();
             render_pass.draw(0..6, 0..batch.primitives.len() as u32);
         }
 
@@ -3587,14 +4398,23 @@ impl GpuRenderer {
         &mut self,
         target: &wgpu::TextureView,
         backdrop: &wgpu::TextureView,
-        _backdrop_size: (u32, u32), // Not used - we render with full viewport coords
+        backdrop_size: (u32, u32), // Only sizes the MSAA attachment - we render with full viewport coords
         batch: &PrimitiveBatch,
     ) {
+        // Glass samples the backdrop outside any damage region
+        self.damage = None;
+        self.evict_idle_cached_layers();
+
+        // With MSAA, background passes resolve into the backdrop and target
+        // before the glass pass samples them
+        self.prepare_msaa_target(backdrop_size);
+        self.prepare_msaa_target(self.viewport_size);
+
         // Update uniforms for rendering (always use full viewport size)
         // The GPU maps NDC space to actual texture size automatically
         let main_uniforms = Uniforms {
             viewport_size: [self.viewport_size.0 as f32, self.viewport_size.1 as f32],
-            _padding: [0.0; 2],
+            jitter: [0.0; 2],
         };
 
         // Update primitives buffer
@@ -3720,14 +4540,11 @@ impl GpuRenderer {
 
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("Backdrop Render Pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: backdrop,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
+                color_attachments: &[Some(self.main_color_attachment(
+                    backdrop,
+                    backdrop_size,
+                    wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                ))],
                 depth_stencil_attachment: None,
                 timestamp_writes: None,
                 occlusion_query_set: None,
@@ -3736,6 +4553,7 @@ impl GpuRenderer {
             if !batch.primitives.is_empty() {
                 render_pass.set_pipeline(&self.pipelines.sdf);
                 render_pass.set_bind_group(0, &self.bind_groups.sdf, &[]);
+                self.count_draw();
                 render_pass.draw(0..6, 0..batch.primitives.len() as u32);
             }
         }
@@ -3750,14 +4568,11 @@ impl GpuRenderer {
 
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("Target Background Pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: target,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
+                color_attachments: &[Some(self.main_color_attachment(
+                    target,
+                    self.viewport_size,
+                    wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                ))],
                 depth_stencil_attachment: None,
                 timestamp_writes: None,
                 occlusion_query_set: None,
@@ -3766,6 +4581,7 @@ impl GpuRenderer {
             if !batch.primitives.is_empty() {
                 render_pass.set_pipeline(&self.pipelines.sdf);
                 render_pass.set_bind_group(0, &self.bind_groups.sdf, &[]);
+                self.count_draw();
                 render_pass.draw(0..6, 0..batch.primitives.len() as u32);
             }
         }
@@ -3799,6 +4615,7 @@ impl GpuRenderer {
             if simple_count > 0 {
                 render_pass.set_pipeline(&self.pipelines.simple_glass);
                 render_pass.set_bind_group(0, glass_bind_group, &[]);
+                self.count_draw();
                 render_pass.draw(0..6, 0..simple_count as u32);
             }
 
@@ -3806,6 +4623,7 @@ impl GpuRenderer {
             if liquid_count > 0 {
                 render_pass.set_pipeline(&self.pipelines.glass);
                 render_pass.set_bind_group(0, glass_bind_group, &[]);
+                self.count_draw();
                 render_pass.draw(
                     0..6,
                     simple_count as u32..(simple_count + liquid_count) as u32,
@@ -3816,6 +4634,8 @@ impl GpuRenderer {
         // Submit background and glass passes first
         self.queue.submit(std::iter::once(encoder.finish()));
 
+        self.composite_cached_layers(target, batch, (0.0, 0.0), None);
+
         // Pass 4: Render foreground primitives (on top of glass)
         // This requires a separate submission because we need to overwrite the primitives buffer
         if !batch.foreground_primitives.is_empty() {
@@ -3847,8 +4667,9 @@ impl GpuRenderer {
                 occlusion_query_set: None,
             });
 
-            render_pass.set_pipeline(&self.pipelines.sdf);
+            render_pass.set_pipeline(self.single_sample_sdf_pipeline());
             render_pass.set_bind_group(0, &self.bind_groups.sdf, &[]);
+            self.count_draw();
             render_pass.draw(0..6, 0..batch.foreground_primitives.len() as u32);
 
             drop(render_pass);
@@ -3891,6 +4712,7 @@ impl GpuRenderer {
                 render_pass.set_bind_group(0, &self.bind_groups.path, &[]);
                 render_pass.set_vertex_buffer(0, vb.slice(..));
                 render_pass.set_index_buffer(ib.slice(..), wgpu::IndexFormat::Uint32);
+                self.count_draw();
                 render_pass.draw_indexed(0..batch.paths.indices.len() as u32, 0, 0..1);
 
                 drop(render_pass);
@@ -3921,6 +4743,7 @@ impl GpuRenderer {
         // If we have layer effects, use the layer-aware rendering path
         if has_layer_effects {
             self.render_overlay_with_layer_effects(target, batch);
+            self.composite_cached_layers(target, batch, (0.0, 0.0), None);
             return;
         }
 
@@ -3928,7 +4751,7 @@ impl GpuRenderer {
         // Update uniforms
         let uniforms = Uniforms {
             viewport_size: [self.viewport_size.0 as f32, self.viewport_size.1 as f32],
-            _padding: [0.0; 2],
+            jitter: [0.0; 2],
         };
         self.queue
             .write_buffer(&self.buffers.uniforms, 0, bytemuck::bytes_of(&uniforms));
@@ -3971,6 +4794,7 @@ impl GpuRenderer {
                 timestamp_writes: None,
                 occlusion_query_set: None,
             });
+            self.apply_damage_scissor(&mut render_pass);
 
             // Render paths first (they're typically backgrounds)
             if has_paths {
@@ -3981,6 +4805,7 @@ impl GpuRenderer {
                     render_pass.set_bind_group(0, &self.bind_groups.path, &[]);
                     render_pass.set_vertex_buffer(0, vb.slice(..));
                     render_pass.set_index_buffer(ib.slice(..), wgpu::IndexFormat::Uint32);
+                    self.count_draw();
                     render_pass.draw_indexed(0..batch.paths.indices.len() as u32, 0, 0..1);
                 }
             }
@@ -3989,12 +4814,15 @@ impl GpuRenderer {
             if !batch.primitives.is_empty() {
                 render_pass.set_pipeline(&self.pipelines.sdf_overlay);
                 render_pass.set_bind_group(0, &self.bind_groups.sdf, &[]);
+                self.count_draw();
                 render_pass.draw(0..6, 0..batch.primitives.len() as u32);
             }
         }
 
         // Submit commands
         self.queue.submit(std::iter::once(encoder.finish()));
+
+        self.composite_cached_layers(target, batch, (0.0, 0.0), None);
     }
 
     /// Render overlay with layer effect processing
@@ -4089,7 +4917,7 @@ impl GpuRenderer {
         // Update uniforms
         let uniforms = Uniforms {
             viewport_size: [self.viewport_size.0 as f32, self.viewport_size.1 as f32],
-            _padding: [0.0; 2],
+            jitter: [0.0; 2],
         };
         self.queue
             .write_buffer(&self.buffers.uniforms, 0, bytemuck::bytes_of(&uniforms));
@@ -4132,6 +4960,7 @@ impl GpuRenderer {
                 timestamp_writes: None,
                 occlusion_query_set: None,
             });
+            self.apply_damage_scissor(&mut render_pass);
 
             // Render paths first
             if has_paths {
@@ -4142,6 +4971,7 @@ impl GpuRenderer {
                     render_pass.set_bind_group(0, &self.bind_groups.path, &[]);
                     render_pass.set_vertex_buffer(0, vb.slice(..));
                     render_pass.set_index_buffer(ib.slice(..), wgpu::IndexFormat::Uint32);
+                    self.count_draw();
                     render_pass.draw_indexed(0..batch.paths.indices.len() as u32, 0, 0..1);
                 }
             }
@@ -4150,6 +4980,7 @@ impl GpuRenderer {
             if !batch.primitives.is_empty() {
                 render_pass.set_pipeline(&self.pipelines.sdf_overlay);
                 render_pass.set_bind_group(0, &self.bind_groups.sdf, &[]);
+                self.count_draw();
                 render_pass.draw(0..6, 0..batch.primitives.len() as u32);
             }
         }
@@ -4174,7 +5005,7 @@ impl GpuRenderer {
         // Update uniforms
         let uniforms = Uniforms {
             viewport_size: [self.viewport_size.0 as f32, self.viewport_size.1 as f32],
-            _padding: [0.0; 2],
+            jitter: [0.0; 2],
         };
         self.queue
             .write_buffer(&self.buffers.uniforms, 0, bytemuck::bytes_of(&uniforms));
@@ -4209,10 +5040,12 @@ impl GpuRenderer {
                 timestamp_writes: None,
                 occlusion_query_set: None,
             });
+            self.apply_damage_scissor(&mut render_pass);
 
             // Render SDF primitives
             render_pass.set_pipeline(&self.pipelines.sdf_overlay);
             render_pass.set_bind_group(0, &self.bind_groups.sdf, &[]);
+            self.count_draw();
             render_pass.draw(0..6, 0..primitives.len() as u32);
         }
 
@@ -4255,12 +5088,14 @@ impl GpuRenderer {
                 timestamp_writes: None,
                 occlusion_query_set: None,
             });
+            self.apply_damage_scissor(&mut render_pass);
 
             // Use overlay path pipeline (1x sampled)
             render_pass.set_pipeline(&self.pipelines.path_overlay);
             render_pass.set_bind_group(0, &self.bind_groups.path, &[]);
             render_pass.set_vertex_buffer(0, vb.slice(..));
             render_pass.set_index_buffer(ib.slice(..), wgpu::IndexFormat::Uint32);
+            self.count_draw();
             render_pass.draw_indexed(0..batch.paths.indices.len() as u32, 0, 0..1);
 
             drop(render_pass);
@@ -4292,7 +5127,7 @@ impl GpuRenderer {
         // Update uniforms
         let uniforms = Uniforms {
             viewport_size: [self.viewport_size.0 as f32, self.viewport_size.1 as f32],
-            _padding: [0.0; 2],
+            jitter: [0.0; 2],
         };
         self.queue
             .write_buffer(&self.buffers.uniforms, 0, bytemuck::bytes_of(&uniforms));
@@ -4374,10 +5209,12 @@ impl GpuRenderer {
                 timestamp_writes: None,
                 occlusion_query_set: None,
             });
+            self.apply_damage_scissor(&mut render_pass);
 
             // Render SDF primitives (including text glyphs)
             render_pass.set_pipeline(&self.pipelines.sdf_overlay);
             render_pass.set_bind_group(0, sdf_bind_group, &[]);
+            self.count_draw();
             render_pass.draw(0..6, 0..primitives.len() as u32);
         }
 
@@ -4533,7 +5370,7 @@ impl GpuRenderer {
         // Update uniforms
         let uniforms = Uniforms {
             viewport_size: [width as f32, height as f32],
-            _padding: [0.0; 2],
+            jitter: [0.0; 2],
         };
         self.queue
             .write_buffer(&self.buffers.uniforms, 0, bytemuck::bytes_of(&uniforms));
@@ -4601,6 +5438,7 @@ impl GpuRenderer {
                     render_pass.set_bind_group(0, &self.bind_groups.path, &[]);
                     render_pass.set_vertex_buffer(0, vb.slice(..));
                     render_pass.set_index_buffer(ib.slice(..), wgpu::IndexFormat::Uint32);
+                    self.count_draw();
                     render_pass.draw_indexed(0..batch.paths.indices.len() as u32, 0, 0..1);
                 }
             }
@@ -4609,6 +5447,7 @@ impl GpuRenderer {
             if !batch.primitives.is_empty() {
                 render_pass.set_pipeline(sdf_pipeline);
                 render_pass.set_bind_group(0, &self.bind_groups.sdf, &[]);
+                self.count_draw();
                 render_pass.draw(0..6, 0..batch.primitives.len() as u32);
             }
         }
@@ -4629,9 +5468,11 @@ impl GpuRenderer {
                 timestamp_writes: None,
                 occlusion_query_set: None,
             });
+            self.apply_damage_scissor(&mut render_pass);
 
             render_pass.set_pipeline(&self.pipelines.composite_overlay);
             render_pass.set_bind_group(0, &cached.composite_bind_group, &[]);
+            self.count_draw();
             render_pass.draw(0..3, 0..1); // Fullscreen triangle
         }
 
@@ -4780,7 +5621,7 @@ impl GpuRenderer {
         // Update uniforms
         let uniforms = Uniforms {
             viewport_size: [width as f32, height as f32],
-            _padding: [0.0; 2],
+            jitter: [0.0; 2],
         };
         self.queue
             .write_buffer(&self.buffers.uniforms, 0, bytemuck::bytes_of(&uniforms));
@@ -4831,6 +5672,7 @@ impl GpuRenderer {
                 render_pass.set_bind_group(0, &self.bind_groups.path, &[]);
                 render_pass.set_vertex_buffer(0, vb.slice(..));
                 render_pass.set_index_buffer(ib.slice(..), wgpu::IndexFormat::Uint32);
+                self.count_draw();
                 render_pass.draw_indexed(0..batch.paths.indices.len() as u32, 0, 0..1);
             }
         }
@@ -4851,9 +5693,11 @@ impl GpuRenderer {
                 timestamp_writes: None,
                 occlusion_query_set: None,
             });
+            self.apply_damage_scissor(&mut render_pass);
 
             render_pass.set_pipeline(&self.pipelines.composite_overlay);
             render_pass.set_bind_group(0, &cached.composite_bind_group, &[]);
+            self.count_draw();
             render_pass.draw(0..3, 0..1);
         }
 
@@ -4883,7 +5727,7 @@ impl GpuRenderer {
         // Update uniforms
         let uniforms = Uniforms {
             viewport_size: [self.viewport_size.0 as f32, self.viewport_size.1 as f32],
-            _padding: [0.0; 2],
+            jitter: [0.0; 2],
         };
         self.queue
             .write_buffer(&self.buffers.uniforms, 0, bytemuck::bytes_of(&uniforms));
@@ -4963,10 +5807,12 @@ impl GpuRenderer {
                 timestamp_writes: None,
                 occlusion_query_set: None,
             });
+            self.apply_damage_scissor(&mut render_pass);
 
             // Use text_overlay pipeline since we're rendering to 1x sampled texture
             render_pass.set_pipeline(&self.pipelines.text_overlay);
             render_pass.set_bind_group(0, text_bind_group, &[]);
+            self.count_draw();
             render_pass.draw(0..6, 0..glyphs.len() as u32);
         }
 
@@ -5176,7 +6022,7 @@ impl GpuRenderer {
         // Update uniforms
         let uniforms = Uniforms {
             viewport_size: [self.viewport_size.0 as f32, self.viewport_size.1 as f32],
-            _padding: [0.0; 2],
+            jitter: [0.0; 2],
         };
         self.queue
             .write_buffer(&self.buffers.uniforms, 0, bytemuck::bytes_of(&uniforms));
@@ -5230,10 +6076,12 @@ impl GpuRenderer {
                 timestamp_writes: None,
                 occlusion_query_set: None,
             });
+            self.apply_damage_scissor(&mut render_pass);
 
             render_pass.set_pipeline(&image_pipeline.pipeline);
             render_pass.set_bind_group(0, &bind_group, &[]);
             render_pass.set_vertex_buffer(0, image_pipeline.instance_buffer.slice(..));
+            self.count_draw();
             render_pass.draw(0..6, 0..instances.len() as u32);
         }
 
@@ -5269,67 +6117,265 @@ impl GpuRenderer {
     }
 
     // ─────────────────────────────────────────────────────────────────────────────
-    // Layer Composition
+    // Cached Layers
     // ─────────────────────────────────────────────────────────────────────────────
 
-    /// Create a bind group for layer composition
-    fn create_layer_composite_bind_group(
-        &self,
-        uniform_buffer: &wgpu::Buffer,
-        layer_view: &wgpu::TextureView,
-        sampler: &wgpu::Sampler,
-    ) -> wgpu::BindGroup {
-        self.device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("Layer Composite Bind Group"),
-            layout: &self.bind_group_layouts.layer_composite,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: uniform_buffer.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: wgpu::BindingResource::TextureView(layer_view),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 2,
-                    resource: wgpu::BindingResource::Sampler(sampler),
-                },
-            ],
-        })
+    /// IDs of the cached layers whose textures the renderer holds
+    ///
+    /// Pass these to [`GpuPaintContext::set_cached_layers`](crate::GpuPaintContext::set_cached_layers)
+    /// so the next frame composites those layers instead of drawing them.
+    pub fn cached_layer_ids(&self) -> impl Iterator<Item = blinc_core::LayerId> + '_ {
+        self.cached_layers.keys().copied()
     }
 
-    /// Composite a layer texture onto a target
+    /// Rasterize the cached layers drawn this frame into their textures
     ///
-    /// Uses the LAYER_COMPOSITE_SHADER to blend the layer onto the target
-    /// with the specified blend mode and opacity.
-    pub fn composite_layer(
-        &self,
-        encoder: &mut wgpu::CommandEncoder,
-        target: &wgpu::TextureView,
-        layer: &LayerTexture,
-        dest_x: f32,
-        dest_y: f32,
-        opacity: f32,
-        blend_mode: blinc_core::BlendMode,
-    ) {
-        // Create uniform buffer for this composition
-        let uniforms = crate::primitives::LayerCompositeUniforms::new(
-            layer.size,
-            dest_x,
-            dest_y,
-            (self.viewport_size.0 as f32, self.viewport_size.1 as f32),
-            opacity,
-            blend_mode,
-        );
+    /// Call after [`Self::resize`] and before rendering the batch the layers
+    /// were taken from, which composites them through its
+    /// [`LayerCommand::Sample`](crate::primitives::LayerCommand::Sample)
+    /// commands. A texture already held under the
+    /// same ID is replaced. Layer effects inside a cached layer are not
+    /// applied (a warning is logged); keep effects out of cached content.
+    pub fn rasterize_cached_layers(&mut self, layers: Vec<CachedLayerBatch>) {
+        for mut layer in layers {
+            // Nested layers first, so this layer can composite them
+            let nested = std::mem::take(&mut layer.batch.cached_layers);
+            self.rasterize_cached_layers(nested);
+            self.rasterize_cached_layer(layer);
+        }
+    }
 
-        let uniform_buffer = self
-            .device
-            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                label: Some("Layer Composite Uniforms"),
-                contents: bytemuck::bytes_of(&uniforms),
-                usage: wgpu::BufferUsages::UNIFORM,
-            });
+    fn rasterize_cached_layer(&mut self, layer: CachedLayerBatch) {
+        let CachedLayerBatch {
+            id,
+            anchor,
+            size,
+            mut batch,
+        } = layer;
+        if batch.has_layer_effects() {
+            tracing::warn!(
+                "Cached layer {:?} contains layer effects, which are not applied when it is rasterized",
+                id
+            );
+        }
+        if let Some(old) = self.layer_texture_cache.remove(&id) {
+            self.layer_texture_cache.release(old);
+        }
+
+        // Tight texture around the content, which may overflow the layer
+        let max_size = self.device.limits().max_texture_dimension_2d;
+        let bounds = batch.content_bounds();
+        let origin = bounds.map_or((anchor.x(), anchor.y()), |(x, y, _, _)| {
+            (x.floor(), y.floor())
+        });
+        let content_size = bounds.map(|(x, y, width, height)| {
+            (
+                ((x + width - origin.0).ceil() as u32).clamp(1, max_size),
+                ((y + height - origin.1).ceil() as u32).clamp(1, max_size),
+            )
+        });
+        self.cached_layers.insert(
+            id,
+            CachedLayer {
+                anchor,
+                size,
+                origin,
+                content_size,
+                last_used: self.frame_index,
+            },
+        );
+        let Some(content_size) = content_size else {
+            return;
+        };
+
+        // Foreground primitives draw over the rest of the layer
+        let foreground = std::mem::take(&mut batch.foreground_primitives);
+        batch.primitives.extend(foreground);
+
+        // Draw in screen coordinates, shifted so the origin lands at the
+        // texture's top-left corner
+        let texture = self
+            .layer_texture_cache
+            .acquire(&self.device, content_size, false);
+        let viewport_size = std::mem::replace(&mut self.viewport_size, texture.size);
+        let damage = self.damage.take();
+        self.jitter = [-origin.0, -origin.1];
+        self.render_with_clear_simple(&texture.view, &batch, [0.0; 4]);
+        self.jitter = [0.0; 2];
+        self.composite_cached_layers(&texture.view, &batch, origin, None);
+        self.viewport_size = viewport_size;
+        self.damage = damage;
+        self.layer_texture_cache.store(id, texture);
+
+        // The composited frame changed without the main batch changing
+        if let Some(temporal) = self.temporal.as_mut() {
+            temporal.samples = 0;
+        }
+    }
+
+    /// Composite the cached layers sampled by `batch` onto existing content
+    ///
+    /// [`Self::render_with_clear`], [`Self::render_overlay`] and
+    /// [`Self::render_glass_frame`] already do this; use it for batches
+    /// drawn through the primitive-only passes.
+    pub fn render_cached_layers(&mut self, target: &wgpu::TextureView, batch: &PrimitiveBatch) {
+        self.composite_cached_layers(target, batch, (0.0, 0.0), None);
+    }
+
+    /// Composite the cached layers `batch` samples at one z-layer
+    ///
+    /// For batches drawn one z-layer at a time, so cached layers keep their
+    /// stacking order with the primitives around them.
+    pub fn render_cached_layers_for_layer(
+        &mut self,
+        target: &wgpu::TextureView,
+        batch: &PrimitiveBatch,
+        z_layer: u32,
+    ) {
+        self.composite_cached_layers(target, batch, (0.0, 0.0), Some(z_layer));
+    }
+
+    /// Composite the cached layers sampled by `batch` onto `target`
+    ///
+    /// `offset` is the screen position of the target's top-left corner.
+    /// With `z_layer`, only samples recorded at that z-layer are composited.
+    fn composite_cached_layers(
+        &mut self,
+        target: &wgpu::TextureView,
+        batch: &PrimitiveBatch,
+        offset: (f32, f32),
+        z_layer: Option<u32>,
+    ) {
+        use crate::primitives::LayerCommand;
+
+        for entry in &batch.layer_commands {
+            let LayerCommand::Sample {
+                id,
+                source,
+                dest,
+                opacity,
+                clip,
+                z_layer: sample_z,
+            } = &entry.command
+            else {
+                continue;
+            };
+            if z_layer.is_some_and(|z| z != *sample_z) {
+                continue;
+            }
+            let Some(layer) = self.cached_layers.get_mut(id) else {
+                continue;
+            };
+            layer.last_used = self.frame_index;
+            let Some(placement) = place_cached_layer_sample(layer, *source, *dest, *clip, offset)
+            else {
+                continue;
+            };
+
+            let Some(texture) = self.layer_texture_cache.remove(id) else {
+                continue;
+            };
+            // The blit maps `source_size` texels onto `dest_size` pixels;
+            // only the content part of the pooled texture is shown
+            let source_size = (
+                ((texture.size.0 as f32 * placement.scale.0).round() as u32).max(1),
+                ((texture.size.1 as f32 * placement.scale.1).round() as u32).max(1),
+            );
+            self.blit_tight_texture_to_target(
+                &texture.view,
+                source_size,
+                target,
+                placement.dest_pos,
+                placement.dest_size,
+                *opacity,
+                blinc_core::BlendMode::Normal,
+                placement.clip,
+            );
+            self.layer_texture_cache.store(*id, texture);
+        }
+    }
+
+    /// Advance the frame counter and drop cached layers that haven't been
+    /// composited for a while
+    fn evict_idle_cached_layers(&mut self) {
+        self.frame_index += 1;
+        let frame_index = self.frame_index;
+        let idle: Vec<_> = self
+            .cached_layers
+            .iter()
+            .filter(|(_, layer)| frame_index - layer.last_used > CACHED_LAYER_MAX_IDLE_FRAMES)
+            .map(|(id, _)| *id)
+            .collect();
+        for id in idle {
+            self.cached_layers.remove(&id);
+            if let Some(texture) = self.layer_texture_cache.remove(&id) {
+                self.layer_texture_cache.release(texture);
+            }
+        }
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Layer Composition
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    /// Create a bind group for layer composition
+    fn create_layer_composite_bind_group(
+        &self,
+        uniform_buffer: &wgpu::Buffer,
+        layer_view: &wgpu::TextureView,
+        sampler: &wgpu::Sampler,
+    ) -> wgpu::BindGroup {
+        self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Layer Composite Bind Group"),
+            layout: &self.bind_group_layouts.layer_composite,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: uniform_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(layer_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Sampler(sampler),
+                },
+            ],
+        })
+    }
+
+    /// Composite a layer texture onto a target
+    ///
+    /// Uses the LAYER_COMPOSITE_SHADER to blend the layer onto the target
+    /// with the specified blend mode and opacity.
+    pub fn composite_layer(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        layer: &LayerTexture,
+        dest_x: f32,
+        dest_y: f32,
+        opacity: f32,
+        blend_mode: blinc_core::BlendMode,
+    ) {
+        // Create uniform buffer for this composition
+        let uniforms = crate::primitives::LayerCompositeUniforms::new(
+            layer.size,
+            dest_x,
+            dest_y,
+            (self.viewport_size.0 as f32, self.viewport_size.1 as f32),
+            opacity,
+            blend_mode,
+        );
+
+        let uniform_buffer = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Layer Composite Uniforms"),
+                contents: bytemuck::bytes_of(&uniforms),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
 
         // Create sampler
         let sampler = self.device.create_sampler(&wgpu::SamplerDescriptor {
@@ -5365,6 +6411,7 @@ impl GpuRenderer {
 
         render_pass.set_pipeline(&self.pipelines.layer_composite);
         render_pass.set_bind_group(0, &bind_group, &[]);
+        self.count_draw();
         render_pass.draw(0..6, 0..1); // 6 vertices for quad (2 triangles)
     }
 
@@ -5444,6 +6491,7 @@ impl GpuRenderer {
 
         render_pass.set_pipeline(&self.pipelines.layer_composite);
         render_pass.set_bind_group(0, &bind_group, &[]);
+        self.count_draw();
         render_pass.draw(0..6, 0..1);
     }
 
@@ -5527,6 +6575,7 @@ impl GpuRenderer {
 
             render_pass.set_pipeline(&self.pipelines.blur);
             render_pass.set_bind_group(0, &bind_group, &[]);
+            self.count_draw();
             render_pass.draw(0..6, 0..1);
         }
 
@@ -5697,6 +6746,7 @@ impl GpuRenderer {
 
             render_pass.set_pipeline(&self.pipelines.color_matrix);
             render_pass.set_bind_group(0, &bind_group, &[]);
+            self.count_draw();
             render_pass.draw(0..6, 0..1);
         }
 
@@ -5782,6 +6832,7 @@ impl GpuRenderer {
 
             render_pass.set_pipeline(&self.pipelines.drop_shadow);
             render_pass.set_bind_group(0, &bind_group, &[]);
+            self.count_draw();
             render_pass.draw(0..6, 0..1);
         }
 
@@ -5862,6 +6913,155 @@ impl GpuRenderer {
 
             render_pass.set_pipeline(&self.pipelines.glow);
             render_pass.set_bind_group(0, &bind_group, &[]);
+            self.count_draw();
+            render_pass.draw(0..6, 0..1);
+        }
+
+        self.queue.submit(std::iter::once(encoder.finish()));
+    }
+
+    /// Apply bloom effect
+    ///
+    /// Keeps the pixels brighter than `threshold`, blurs them (alpha included, so
+    /// the light spreads past the content's edges) and adds them back onto the
+    /// input scaled by `intensity`.
+    ///
+    /// Layer textures are 8-bit, so the input is already clamped to 0..=1:
+    /// `threshold` selects among displayable colors, not HDR values.
+    pub fn apply_bloom(
+        &mut self,
+        input: &LayerTexture,
+        threshold: f32,
+        intensity: f32,
+        radius: f32,
+    ) -> LayerTexture {
+        let size = input.size;
+        let uniforms = PostProcessUniforms {
+            threshold,
+            intensity,
+            ..Default::default()
+        };
+
+        let bright = self.layer_texture_cache.acquire(&self.device, size, false);
+        self.apply_post_process(
+            &input.view,
+            &input.view,
+            &bright.view,
+            PostProcessUniforms {
+                mode: PostProcessMode::BloomExtract as u32,
+                ..uniforms
+            },
+        );
+
+        let passes = (radius / 2.0).ceil().max(2.0) as u32;
+        let glow = self.apply_shadow_blur(&bright, radius, passes);
+        self.layer_texture_cache.release(bright);
+
+        let output = self.layer_texture_cache.acquire(&self.device, size, false);
+        self.apply_post_process(
+            &input.view,
+            &glow.view,
+            &output.view,
+            PostProcessUniforms {
+                mode: PostProcessMode::BloomCombine as u32,
+                ..uniforms
+            },
+        );
+        self.layer_texture_cache.release(glow);
+
+        output
+    }
+
+    /// Apply exposure and tone mapping
+    ///
+    /// Runs on 8-bit layer textures, so colors are clamped to 0..=1 before the
+    /// curve is applied. Exposure above 1.0 can still push them past white.
+    pub fn apply_tone_map(
+        &mut self,
+        input: &wgpu::TextureView,
+        output: &wgpu::TextureView,
+        mapping: blinc_core::ToneMapping,
+        exposure: f32,
+    ) {
+        let mode = match mapping {
+            blinc_core::ToneMapping::Reinhard => PostProcessMode::ToneMapReinhard,
+            blinc_core::ToneMapping::Aces => PostProcessMode::ToneMapAces,
+        };
+        self.apply_post_process(
+            input,
+            input,
+            output,
+            PostProcessUniforms {
+                mode: mode as u32,
+                exposure,
+                ..Default::default()
+            },
+        );
+    }
+
+    /// Run one pass of the bloom / tone mapping shader
+    fn apply_post_process(
+        &mut self,
+        input: &wgpu::TextureView,
+        bloom: &wgpu::TextureView,
+        output: &wgpu::TextureView,
+        uniforms: PostProcessUniforms,
+    ) {
+        // Use cached buffer instead of creating per-pass
+        self.queue.write_buffer(
+            &self.buffers.post_process_uniforms,
+            0,
+            bytemuck::bytes_of(&uniforms),
+        );
+
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Post Process Effect Bind Group"),
+            layout: &self.bind_group_layouts.post_process,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: self.buffers.post_process_uniforms.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(input),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Sampler(&self.path_image_sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::TextureView(bloom),
+                },
+            ],
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Post Process Pass Encoder"),
+            });
+
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Post Process Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: output,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            render_pass.set_pipeline(&self.pipelines.post_process);
+            render_pass.set_bind_group(0, &bind_group, &[]);
+            self.count_draw();
             render_pass.draw(0..6, 0..1);
         }
 
@@ -5996,8 +7196,16 @@ impl GpuRenderer {
                     right = right.max(expand);
                     bottom = bottom.max(expand);
                 }
-                LayerEffect::ColorMatrix { .. } => {
-                    // Color matrix doesn't expand bounds
+                LayerEffect::ColorMatrix { .. } | LayerEffect::ToneMap { .. } => {
+                    // Per-pixel color changes don't expand bounds
+                }
+                LayerEffect::Bloom { radius, .. } => {
+                    // Light spreads past the edges by about the blur radius
+                    let expand = radius * 2.0;
+                    left = left.max(expand);
+                    top = top.max(expand);
+                    right = right.max(expand);
+                    bottom = bottom.max(expand);
                 }
             }
         }
@@ -6144,6 +7352,23 @@ impl GpuRenderer {
                     self.layer_texture_cache.release(current);
                     current = temp;
                 }
+
+                LayerEffect::Bloom {
+                    threshold,
+                    intensity,
+                    radius,
+                } => {
+                    let bloomed = self.apply_bloom(&current, *threshold, *intensity, *radius);
+                    self.layer_texture_cache.release(current);
+                    current = bloomed;
+                }
+
+                LayerEffect::ToneMap { mapping, exposure } => {
+                    let temp = self.layer_texture_cache.acquire(&self.device, size, false);
+                    self.apply_tone_map(&current.view, &temp.view, *mapping, *exposure);
+                    self.layer_texture_cache.release(current);
+                    current = temp;
+                }
             }
         }
 
@@ -6225,6 +7450,7 @@ impl GpuRenderer {
 
             render_pass.set_pipeline(&self.pipelines.composite);
             render_pass.set_bind_group(0, &bind_group, &[]);
+            self.count_draw();
             render_pass.draw(0..6, 0..1);
         }
 
@@ -6255,7 +7481,7 @@ impl GpuRenderer {
         // Update uniforms
         let uniforms = Uniforms {
             viewport_size: [self.viewport_size.0 as f32, self.viewport_size.1 as f32],
-            _padding: [0.0; 2],
+            jitter: [0.0; 2],
         };
         self.queue
             .write_buffer(&self.buffers.uniforms, 0, bytemuck::bytes_of(&uniforms));
@@ -6296,8 +7522,9 @@ impl GpuRenderer {
                 occlusion_query_set: None,
             });
 
-            render_pass.set_pipeline(&self.pipelines.sdf);
+            render_pass.set_pipeline(self.single_sample_sdf_pipeline());
             render_pass.set_bind_group(0, &self.bind_groups.sdf, &[]);
+            self.count_draw();
             render_pass.draw(0..6, 0..primitive_count as u32);
         }
 
@@ -6377,7 +7604,7 @@ impl GpuRenderer {
         // Update uniforms with content size (the viewport for this tight render)
         let uniforms = Uniforms {
             viewport_size: [content_size.0 as f32, content_size.1 as f32],
-            _padding: [0.0; 2],
+            jitter: [0.0; 2],
         };
         self.queue
             .write_buffer(&self.buffers.uniforms, 0, bytemuck::bytes_of(&uniforms));
@@ -6415,8 +7642,9 @@ impl GpuRenderer {
                 occlusion_query_set: None,
             });
 
-            render_pass.set_pipeline(&self.pipelines.sdf);
+            render_pass.set_pipeline(self.single_sample_sdf_pipeline());
             render_pass.set_bind_group(0, &self.bind_groups.sdf, &[]);
+            self.count_draw();
             render_pass.draw(0..6, 0..primitive_count);
         }
 
@@ -6425,7 +7653,7 @@ impl GpuRenderer {
         // Restore viewport uniforms for subsequent operations
         let restore_uniforms = Uniforms {
             viewport_size: [self.viewport_size.0 as f32, self.viewport_size.1 as f32],
-            _padding: [0.0; 2],
+            jitter: [0.0; 2],
         };
         self.queue.write_buffer(
             &self.buffers.uniforms,
@@ -6573,14 +7801,28 @@ impl GpuRenderer {
             });
 
             // Set scissor rect to the visible region (already intersected with clip bounds)
-            let scissor_x = vis_x0.max(0.0) as u32;
-            let scissor_y = vis_y0.max(0.0) as u32;
-            let scissor_w = vis_w.max(1.0) as u32;
-            let scissor_h = vis_h.max(1.0) as u32;
+            let mut scissor_x = vis_x0.max(0.0) as u32;
+            let mut scissor_y = vis_y0.max(0.0) as u32;
+            let mut scissor_w = vis_w.max(1.0) as u32;
+            let mut scissor_h = vis_h.max(1.0) as u32;
+
+            // Keep partial redraws inside the damage region
+            if let Some([dx, dy, dw, dh]) = self.damage {
+                let x1 = (scissor_x + scissor_w).min(dx + dw);
+                let y1 = (scissor_y + scissor_h).min(dy + dh);
+                scissor_x = scissor_x.max(dx);
+                scissor_y = scissor_y.max(dy);
+                if x1 <= scissor_x || y1 <= scissor_y {
+                    return;
+                }
+                scissor_w = x1 - scissor_x;
+                scissor_h = y1 - scissor_y;
+            }
 
             render_pass.set_scissor_rect(scissor_x, scissor_y, scissor_w, scissor_h);
             render_pass.set_pipeline(&self.pipelines.layer_composite);
             render_pass.set_bind_group(0, &bind_group, &[]);
+            self.count_draw();
             render_pass.draw(0..6, 0..1);
         }
 
@@ -6661,9 +7903,11 @@ impl GpuRenderer {
                 timestamp_writes: None,
                 occlusion_query_set: None,
             });
+            self.apply_damage_scissor(&mut render_pass);
 
             render_pass.set_pipeline(&self.pipelines.layer_composite);
             render_pass.set_bind_group(0, &bind_group, &[]);
+            self.count_draw();
             render_pass.draw(0..6, 0..1);
         }
 
@@ -6793,6 +8037,7 @@ impl GpuRenderer {
 
             render_pass.set_pipeline(&self.pipelines.layer_composite);
             render_pass.set_bind_group(0, &bind_group, &[]);
+            self.count_draw();
             render_pass.draw(0..6, 0..1);
         }
 
@@ -6808,10 +8053,181 @@ impl Default for GpuRenderer {
     }
 }
 
+/// Sub-pixel jitter for temporal AA sample `index` (Halton 2,3 sequence)
+fn temporal_jitter(index: u32) -> [f32; 2] {
+    fn halton(mut index: u32, base: u32) -> f32 {
+        let mut fraction = 1.0;
+        let mut result = 0.0;
+        while index > 0 {
+            fraction /= base as f32;
+            result += fraction * (index % base) as f32;
+            index /= base;
+        }
+        result
+    }
+    [halton(index, 2) - 0.5, halton(index, 3) - 0.5]
+}
+
+/// Where a cached layer's texture lands for one sample
+#[derive(Debug, PartialEq)]
+struct SamplePlacement {
+    /// Texture top-left corner on the target
+    dest_pos: (f32, f32),
+    /// Texture size on the target
+    dest_size: (f32, f32),
+    /// Target pixels per texture texel
+    scale: (f32, f32),
+    /// Clip (bounds, corner radii) on the target
+    clip: Option<([f32; 4], [f32; 4])>,
+}
+
+/// Map the `source` part of a cached layer (in the layer's own coordinates)
+/// onto `dest` (screen pixels)
+///
+/// Sampling the whole layer keeps content that overflows it, like shadows;
+/// a smaller source rect is also clipped to `dest`. `offset` is the screen
+/// position of the target's top-left corner. Returns `None` if the layer
+/// drew nothing.
+fn place_cached_layer_sample(
+    layer: &CachedLayer,
+    source: blinc_core::Rect,
+    dest: blinc_core::Rect,
+    clip: Option<([f32; 4], [f32; 4])>,
+    offset: (f32, f32),
+) -> Option<SamplePlacement> {
+    let content_size = layer.content_size?;
+    let ratio = |a: f32, b: f32| if b > 0.0 { a / b } else { 1.0 };
+
+    // Source rect in screen pixels, relative to the layer rect when drawn
+    let layer_scale = (
+        ratio(layer.anchor.width(), layer.size.width),
+        ratio(layer.anchor.height(), layer.size.height),
+    );
+    let source_px = (
+        source.x() * layer_scale.0,
+        source.y() * layer_scale.1,
+        source.width() * layer_scale.0,
+        source.height() * layer_scale.1,
+    );
+    let scale = (
+        ratio(dest.width(), source_px.2),
+        ratio(dest.height(), source_px.3),
+    );
+    let dest_pos = (
+        dest.x() + (layer.origin.0 - layer.anchor.x() - source_px.0) * scale.0 - offset.0,
+        dest.y() + (layer.origin.1 - layer.anchor.y() - source_px.1) * scale.1 - offset.1,
+    );
+    let dest_size = (
+        content_size.0 as f32 * scale.0,
+        content_size.1 as f32 * scale.1,
+    );
+
+    let mut clip = clip.map(|(mut bounds, radius)| {
+        bounds[0] -= offset.0;
+        bounds[1] -= offset.1;
+        (bounds, radius)
+    });
+    let whole_layer = source.x() <= 0.0
+        && source.y() <= 0.0
+        && source.x() + source.width() >= layer.size.width
+        && source.y() + source.height() >= layer.size.height;
+    if !whole_layer {
+        let dest_bounds = [
+            dest.x() - offset.0,
+            dest.y() - offset.1,
+            dest.width(),
+            dest.height(),
+        ];
+        clip = Some(match clip {
+            Some((bounds, radius)) => {
+                let x0 = bounds[0].max(dest_bounds[0]);
+                let y0 = bounds[1].max(dest_bounds[1]);
+                let x1 = (bounds[0] + bounds[2]).min(dest_bounds[0] + dest_bounds[2]);
+                let y1 = (bounds[1] + bounds[3]).min(dest_bounds[1] + dest_bounds[3]);
+                ([x0, y0, (x1 - x0).max(0.0), (y1 - y0).max(0.0)], radius)
+            }
+            None => (dest_bounds, [0.0; 4]),
+        });
+    }
+
+    Some(SamplePlacement {
+        dest_pos,
+        dest_size,
+        scale,
+        clip,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Cached Layer Tests
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    fn cached_layer() -> CachedLayer {
+        // 100x50 logical layer drawn at 2x, at (20, 40) on screen, with a
+        // shadow overflowing it by 10 pixels on every side
+        CachedLayer {
+            anchor: blinc_core::Rect::new(20.0, 40.0, 200.0, 100.0),
+            size: blinc_core::Size::new(100.0, 50.0),
+            origin: (10.0, 30.0),
+            content_size: Some((220, 120)),
+            last_used: 0,
+        }
+    }
+
+    #[test]
+    fn cached_layer_sample_whole_layer_keeps_overflow() {
+        let layer = cached_layer();
+        let placement = place_cached_layer_sample(
+            &layer,
+            blinc_core::Rect::new(0.0, 0.0, 100.0, 50.0),
+            blinc_core::Rect::new(120.0, 40.0, 200.0, 100.0),
+            None,
+            (0.0, 0.0),
+        )
+        .unwrap();
+        assert_eq!(placement.dest_pos, (110.0, 30.0));
+        assert_eq!(placement.dest_size, (220.0, 120.0));
+        assert_eq!(placement.scale, (1.0, 1.0));
+        assert_eq!(placement.clip, None);
+    }
+
+    #[test]
+    fn cached_layer_sample_honors_source_rect() {
+        let layer = cached_layer();
+        // Right half of the layer, stretched over a 200x100 destination
+        let placement = place_cached_layer_sample(
+            &layer,
+            blinc_core::Rect::new(50.0, 0.0, 50.0, 50.0),
+            blinc_core::Rect::new(0.0, 0.0, 200.0, 100.0),
+            None,
+            (0.0, 0.0),
+        )
+        .unwrap();
+        assert_eq!(placement.scale, (2.0, 1.0));
+        // Layer x = 50 (screen x = 120) lands at the destination's left edge
+        assert_eq!(placement.dest_pos, ((10.0 - 20.0 - 100.0) * 2.0, -10.0));
+        assert_eq!(placement.dest_size, (440.0, 120.0));
+        // Only the destination shows, not the rest of the layer
+        assert_eq!(placement.clip, Some(([0.0, 0.0, 200.0, 100.0], [0.0; 4])));
+    }
+
+    #[test]
+    fn cached_layer_sample_of_empty_layer_is_skipped() {
+        let layer = CachedLayer {
+            content_size: None,
+            ..cached_layer()
+        };
+        let rect = blinc_core::Rect::new(0.0, 0.0, 100.0, 50.0);
+        assert_eq!(
+            place_cached_layer_sample(&layer, rect, rect, None, (0.0, 0.0)),
+            None
+        );
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // LayerTextureCache Tests
     // ─────────────────────────────────────────────────────────────────────────────
@@ -7069,4 +8485,375 @@ mod tests {
         });
         result
     }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Anti-aliasing Tests
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn renderer_config_msaa_rounds_to_supported_counts() {
+        assert_eq!(RendererConfig::default().msaa(0).sample_count, 1);
+        assert_eq!(RendererConfig::default().msaa(2).sample_count, 2);
+        assert_eq!(RendererConfig::default().msaa(3).sample_count, 2);
+        assert_eq!(RendererConfig::default().msaa(4).sample_count, 4);
+        assert_eq!(RendererConfig::default().msaa(16).sample_count, 4);
+        assert!(!RendererConfig::default().temporal_aa);
+        assert!(RendererConfig::default().temporal_aa(true).temporal_aa);
+    }
+
+    #[test]
+    fn temporal_jitter_stays_within_pixel() {
+        let offsets: Vec<[f32; 2]> = (1..=TEMPORAL_AA_MAX_SAMPLES).map(temporal_jitter).collect();
+        for offset in &offsets {
+            assert!(offset[0].abs() <= 0.5 && offset[1].abs() <= 0.5);
+        }
+        // Every sample lands on a distinct sub-pixel position
+        for (i, a) in offsets.iter().enumerate() {
+            assert!(offsets[i + 1..].iter().all(|b| a != b));
+        }
+    }
+
+    /// Render `batch` into a `size`x`size` texture and read back the red channel
+    fn render_red_channel(
+        renderer: &mut GpuRenderer,
+        batch: &PrimitiveBatch,
+        size: u32,
+    ) -> Vec<f32> {
+        let texture = renderer.device().create_texture(&wgpu::TextureDescriptor {
+            label: Some("AA Test Target"),
+            size: wgpu::Extent3d {
+                width: size,
+                height: size,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: renderer.texture_format(),
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        renderer.resize(size, size);
+        renderer.render_with_clear(&view, batch, [0.0, 0.0, 0.0, 1.0]);
+
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let bytes_per_row = (size * 4).div_ceil(align) * align;
+        let buffer = renderer.device().create_buffer(&wgpu::BufferDescriptor {
+            label: Some("AA Test Readback"),
+            size: (bytes_per_row * size) as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+        let mut encoder = renderer
+            .device()
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        encoder.copy_texture_to_buffer(
+            texture.as_image_copy(),
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(bytes_per_row),
+                    rows_per_image: Some(size),
+                },
+            },
+            wgpu::Extent3d {
+                width: size,
+                height: size,
+                depth_or_array_layers: 1,
+            },
+        );
+        renderer.queue().submit(std::iter::once(encoder.finish()));
+
+        let slice = buffer.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |_| {});
+        renderer.device().poll(wgpu::Maintain::Wait);
+        let data = slice.get_mapped_range();
+        (0..size)
+            .flat_map(|y| (0..size).map(move |x| (x, y)))
+            .map(|(x, y)| data[(y * bytes_per_row + x * 4) as usize] as f32 / 255.0)
+            .collect()
+    }
+
+    /// Sum of squared differences between neighbouring pixels
+    ///
+    /// Hard (aliased) edges jump 0 -> 1 in one pixel; smoothed edges spread
+    /// the same coverage over several smaller steps.
+    fn edge_energy(pixels: &[f32], size: u32) -> f32 {
+        let size = size as usize;
+        let mut energy = 0.0;
+        for y in 0..size {
+            for x in 0..size {
+                let v = pixels[y * size + x];
+                if x + 1 < size {
+                    energy += (v - pixels[y * size + x + 1]).powi(2);
+                }
+                if y + 1 < size {
+                    energy += (v - pixels[(y + 1) * size + x]).powi(2);
+                }
+            }
+        }
+        energy
+    }
+
+    #[test]
+    fn shared_glyph_atlas_caches_glyphs_once() {
+        let atlas = SharedGlyphAtlas::new();
+        let config = || {
+            RendererConfig {
+                texture_format: Some(wgpu::TextureFormat::Rgba8Unorm),
+                ..RendererConfig::default()
+            }
+            .glyph_atlas(atlas.clone())
+        };
+        let Ok(mut first) = pollster::block_on(GpuRenderer::new(config())) else {
+            // Skip test if no GPU available
+            return;
+        };
+        // A second window's renderer on the same device
+        let mut second = first.on_same_device(config()).unwrap();
+
+        let target = first.device().create_texture(&wgpu::TextureDescriptor {
+            label: Some("Shared Atlas Test Target"),
+            size: wgpu::Extent3d {
+                width: 64,
+                height: 32,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+        let view = target.create_view(&wgpu::TextureViewDescriptor::default());
+
+        // Prepare and draw "A" through a renderer's own text context
+        let draw = |renderer: &mut GpuRenderer| {
+            let mut text = renderer.create_text_context();
+            let glyphs = text.prepare_text("A", 0.0, 0.0, 16.0, [1.0; 4]).ok()?;
+            renderer.resize(64, 32);
+            renderer.render_text(
+                &view,
+                &glyphs,
+                text.atlas_view()?,
+                text.color_atlas_view()?,
+                text.sampler(),
+            );
+            Some(text)
+        };
+
+        let Some(first_text) = draw(&mut first) else {
+            // Skip test if no system font available
+            return;
+        };
+        let glyphs = atlas.glyph_count();
+        let uploads = atlas.upload_count();
+        assert!(glyphs > 0);
+
+        // The glyph is already rasterized and on the GPU for the second renderer
+        let second_text = draw(&mut second).unwrap();
+        assert_eq!(atlas.glyph_count(), glyphs);
+        assert_eq!(atlas.upload_count(), uploads);
+        assert!(std::ptr::eq(
+            first_text.atlas_view().unwrap(),
+            second_text.atlas_view().unwrap()
+        ));
+    }
+
+    #[test]
+    fn color_space_controls_blending() {
+        use blinc_core::{Brush, Color, CornerRadius, DrawContext, Rect};
+
+        const SIZE: u32 = 8;
+        let mut ctx = crate::GpuPaintContext::new(SIZE as f32, SIZE as f32);
+        ctx.fill_rect(
+            Rect::new(0.0, 0.0, SIZE as f32, SIZE as f32),
+            CornerRadius::default(),
+            Brush::Solid(Color::rgba(1.0, 1.0, 1.0, 0.5)),
+        );
+        let batch = ctx.take_batch();
+
+        // 50% white over black, read back from the middle of the target
+        let render = |color_space: ColorSpace| {
+            pollster::block_on(async {
+                let config = RendererConfig {
+                    texture_format: Some(wgpu::TextureFormat::Rgba8Unorm),
+                    ..RendererConfig::default().color_space(color_space)
+                };
+                let mut renderer = GpuRenderer::new(config).await.ok()?;
+                let pixels = render_red_channel(&mut renderer, &batch, SIZE);
+                Some(pixels[(SIZE / 2 * SIZE + SIZE / 2) as usize] * 255.0)
+            })
+        };
+
+        let (Some(srgb), Some(linear)) = (render(ColorSpace::Srgb), render(ColorSpace::Linear))
+        else {
+            // Skip test if no GPU available
+            return;
+        };
+
+        assert!((srgb - 128.0).abs() <= 2.0, "sRGB blend gave {srgb}");
+        // Linear 0.5 encodes to sRGB 188
+        assert!((linear - 188.0).abs() <= 2.0, "linear blend gave {linear}");
+    }
+
+    #[test]
+    fn bloom_spreads_glow_outside_emissive_quad() {
+        use blinc_core::{Brush, Color, CornerRadius, DrawContext, LayerConfig, LayerEffect, Rect};
+
+        const SIZE: u32 = 32;
+        // A bright 8x8 quad in the middle of a black target
+        let batch_with = |effects: Vec<LayerEffect>| {
+            let mut ctx = crate::GpuPaintContext::new(SIZE as f32, SIZE as f32);
+            let mut config = LayerConfig::new().opacity(1.0);
+            config.effects = effects;
+            ctx.push_layer(config);
+            ctx.fill_rect(
+                Rect::new(12.0, 12.0, 8.0, 8.0),
+                CornerRadius::default(),
+                Brush::Solid(Color::RED),
+            );
+            ctx.pop_layer();
+            ctx.take_batch()
+        };
+        let plain = batch_with(Vec::new());
+        let bloom = batch_with(vec![LayerEffect::bloom(0.5, 2.0, 4.0)]);
+
+        let Some((plain, bloom)) = pollster::block_on(async {
+            let config = RendererConfig {
+                texture_format: Some(wgpu::TextureFormat::Rgba8Unorm),
+                ..RendererConfig::default()
+            };
+            let mut renderer = GpuRenderer::new(config).await.ok()?;
+            Some((
+                render_red_channel(&mut renderer, &plain, SIZE),
+                render_red_channel(&mut renderer, &bloom, SIZE),
+            ))
+        }) else {
+            // Skip test if no GPU available
+            return;
+        };
+
+        // Two pixels left of the quad: dark without bloom, lit by the halo
+        let outside = (16 * SIZE + 10) as usize;
+        assert!(
+            plain[outside] < 0.01,
+            "unbloomed edge is {}",
+            plain[outside]
+        );
+        assert!(bloom[outside] > 0.05, "halo is {}", bloom[outside]);
+        // The quad itself stays fully lit
+        let inside = (16 * SIZE + 16) as usize;
+        assert!(bloom[inside] > 0.99);
+    }
+
+    #[test]
+    fn color_space_picks_target_format() {
+        use wgpu::TextureFormat::{Bgra8Unorm, Bgra8UnormSrgb};
+
+        assert_eq!(ColorSpace::Srgb.target_format(Bgra8UnormSrgb), Bgra8Unorm);
+        assert_eq!(ColorSpace::Linear.target_format(Bgra8Unorm), Bgra8UnormSrgb);
+        assert_eq!(
+            GpuRenderer::surface_format(&[Bgra8UnormSrgb, Bgra8Unorm], ColorSpace::Srgb),
+            Bgra8Unorm
+        );
+    }
+
+    #[test]
+    fn primitives_draw_instanced() {
+        use blinc_core::{Brush, ClipShape, Color, CornerRadius, DrawContext, Rect};
+
+        const SIZE: u32 = 64;
+        let batch_of = |count: usize| {
+            let mut ctx = crate::GpuPaintContext::new(SIZE as f32, SIZE as f32);
+            for i in 0..count {
+                let x = (i % 32) as f32 * 2.0;
+                let y = (i / 32 % 32) as f32 * 2.0;
+                // Clip changes travel with each instance rather than
+                // splitting the draw
+                if i % 3 == 0 {
+                    ctx.push_clip(ClipShape::rect(Rect::new(0.0, 0.0, 32.0, 64.0)));
+                }
+                ctx.fill_rect(
+                    Rect::new(x, y, 4.0, 4.0),
+                    CornerRadius::uniform(1.0),
+                    Brush::Solid(Color::rgba(1.0, 0.0, 0.0, 0.5)),
+                );
+                ctx.fill_circle(
+                    blinc_core::Point::new(x, y),
+                    1.5,
+                    Brush::Solid(Color::WHITE),
+                );
+                if i % 3 == 0 {
+                    ctx.pop_clip();
+                }
+            }
+            ctx.take_batch()
+        };
+        let (few, many) = (batch_of(10), batch_of(1000));
+        assert_eq!(many.primitives.len(), 2000);
+
+        let draw_calls = |batch: &PrimitiveBatch| {
+            pollster::block_on(async {
+                let config = RendererConfig {
+                    texture_format: Some(wgpu::TextureFormat::Rgba8Unorm),
+                    ..Default::default()
+                };
+                let mut renderer = GpuRenderer::new(config).await.ok()?;
+                renderer.reset_draw_calls();
+                render_red_channel(&mut renderer, batch, SIZE);
+                Some(renderer.draw_calls())
+            })
+        };
+        let (Some(few), Some(many)) = (draw_calls(&few), draw_calls(&many)) else {
+            // Skip test if no GPU available
+            return;
+        };
+
+        assert_eq!(few, many);
+        assert!(many <= 4, "{many} draw calls for 2000 primitives");
+    }
+
+    #[test]
+    fn msaa_smooths_rotated_thin_line() {
+        use blinc_core::{Brush, Color, DrawContext, Path, Stroke};
+
+        const SIZE: u32 = 64;
+        let mut ctx = crate::GpuPaintContext::new(SIZE as f32, SIZE as f32);
+        ctx.stroke_path(
+            &Path::new().move_to(6.0, 10.0).line_to(58.0, 41.0),
+            &Stroke::new(1.0),
+            Brush::Solid(Color::WHITE),
+        );
+        let batch = ctx.take_batch();
+        assert!(!batch.paths.indices.is_empty());
+
+        let render = |samples: u32| {
+            pollster::block_on(async {
+                let config = RendererConfig {
+                    texture_format: Some(wgpu::TextureFormat::Rgba8Unorm),
+                    ..RendererConfig::default().msaa(samples)
+                };
+                let mut renderer = GpuRenderer::new(config).await.ok()?;
+                if !renderer.supported_sample_counts().contains(&samples) {
+                    return None;
+                }
+                Some(render_red_channel(&mut renderer, &batch, SIZE))
+            })
+        };
+
+        let (Some(aliased), Some(smoothed)) = (render(1), render(4)) else {
+            // Skip test if no GPU (or no 4x MSAA) available
+            return;
+        };
+
+        // Roughly the same amount of line is drawn either way
+        let coverage = |p: &[f32]| p.iter().sum::<f32>();
+        assert!((coverage(&aliased) - coverage(&smoothed)).abs() < coverage(&aliased) * 0.25);
+        // ...but with softer steps along the edge
+        assert!(edge_energy(&smoothed, SIZE) < edge_energy(&aliased, SIZE));
+    }
 }
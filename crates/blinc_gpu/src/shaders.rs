@@ -27,7 +27,8 @@ struct VertexOutput {
 
 struct Uniforms {
     viewport_size: vec2<f32>,
-    _padding: vec2<f32>,
+    // Sub-pixel offset for temporal AA (shifts rasterization, not the SDF)
+    jitter: vec2<f32>,
 }
 
 // Primitive types
@@ -45,6 +46,12 @@ const FILL_SOLID: u32 = 0u;
 const FILL_LINEAR_GRADIENT: u32 = 1u;
 const FILL_RADIAL_GRADIENT: u32 = 2u;
 
+// Gradient flags packed above the fill type in type_info.y
+const FILL_TYPE_MASK: u32 = 0xFFu;
+const GRADIENT_OKLAB: u32 = 0x100u;
+const GRADIENT_DITHER: u32 = 0x200u;
+const GRADIENT_EASING_SHIFT: u32 = 10u;  // 2 bits: linear, ease-in, ease-out, ease-in-out
+
 // Clip types
 const CLIP_NONE: u32 = 0u;
 const CLIP_RECT: u32 = 1u;
@@ -131,9 +138,10 @@ fn vs_main(
     );
 
     // Convert to clip space (-1 to 1)
+    let raster_pos = pos + uniforms.jitter;
     let clip_pos = vec2<f32>(
-        (pos.x / uniforms.viewport_size.x) * 2.0 - 1.0,
-        1.0 - (pos.y / uniforms.viewport_size.y) * 2.0
+        (raster_pos.x / uniforms.viewport_size.x) * 2.0 - 1.0,
+        1.0 - (raster_pos.y / uniforms.viewport_size.y) * 2.0
     );
 
     out.position = vec4<f32>(clip_pos, 0.0, 1.0);
@@ -143,6 +151,75 @@ fn vs_main(
     return out;
 }
 
+// ============================================================================
+// Gradient Interpolation
+// ============================================================================
+
+fn srgb_to_linear(c: vec3<f32>) -> vec3<f32> {
+    return select(pow((c + 0.055) / 1.055, vec3<f32>(2.4)), c / 12.92, c <= vec3<f32>(0.04045));
+}
+
+fn linear_to_srgb(c: vec3<f32>) -> vec3<f32> {
+    let v = clamp(c, vec3<f32>(0.0), vec3<f32>(1.0));
+    return select(1.055 * pow(v, vec3<f32>(1.0 / 2.4)) - 0.055, v * 12.92, v <= vec3<f32>(0.0031308));
+}
+
+fn srgb_to_oklab(c: vec3<f32>) -> vec3<f32> {
+    let rgb = srgb_to_linear(c);
+    let lms = vec3<f32>(
+        dot(rgb, vec3<f32>(0.4122214708, 0.5363325363, 0.0514459929)),
+        dot(rgb, vec3<f32>(0.2119034982, 0.6806995451, 0.1073969566)),
+        dot(rgb, vec3<f32>(0.0883024619, 0.2817188376, 0.6299787005)),
+    );
+    let l = sign(lms) * pow(abs(lms), vec3<f32>(1.0 / 3.0));
+    return vec3<f32>(
+        dot(l, vec3<f32>(0.2104542553, 0.7936177850, -0.0040720468)),
+        dot(l, vec3<f32>(1.9779984951, -2.4285922050, 0.4505937099)),
+        dot(l, vec3<f32>(0.0259040371, 0.7827717662, -0.8086757660)),
+    );
+}
+
+fn oklab_to_srgb(lab: vec3<f32>) -> vec3<f32> {
+    let l = vec3<f32>(
+        dot(lab, vec3<f32>(1.0, 0.3963377774, 0.2158037573)),
+        dot(lab, vec3<f32>(1.0, -0.1055613458, -0.0638541728)),
+        dot(lab, vec3<f32>(1.0, -0.0894841775, -1.2914855480)),
+    );
+    let lms = l * l * l;
+    return linear_to_srgb(vec3<f32>(
+        dot(lms, vec3<f32>(4.0767416621, -3.3077115913, 0.2309699292)),
+        dot(lms, vec3<f32>(-1.2684380046, 2.6097574011, -0.3413193965)),
+        dot(lms, vec3<f32>(-0.0041960863, -0.7034186147, 1.7076147010)),
+    ));
+}
+
+// Blend the two gradient end colors according to the packed gradient flags
+// (mirrors blinc_core::Gradient::sample)
+fn mix_gradient(a: vec4<f32>, b: vec4<f32>, t_in: f32, flags: u32, p: vec2<f32>) -> vec4<f32> {
+    var t = clamp(t_in, 0.0, 1.0);
+    switch (flags >> GRADIENT_EASING_SHIFT) & 3u {
+        case 1u: { t = t * t; }
+        case 2u: { t = t * (2.0 - t); }
+        case 3u: { t = t * t * (3.0 - 2.0 * t); }
+        default: {}
+    }
+
+    var color: vec4<f32>;
+    if (flags & GRADIENT_OKLAB) != 0u {
+        let lab = mix(srgb_to_oklab(a.rgb), srgb_to_oklab(b.rgb), t);
+        color = vec4<f32>(oklab_to_srgb(lab), mix(a.a, b.a, t));
+    } else {
+        color = mix(a, b, t);
+    }
+
+    if (flags & GRADIENT_DITHER) != 0u {
+        // +-0.5 LSB of 8-bit output breaks up visible bands
+        let noise = fract(sin(dot(p, vec2<f32>(12.9898, 78.233))) * 43758.5453) - 0.5;
+        color = vec4<f32>(color.rgb + noise / 255.0, color.a);
+    }
+    return color;
+}
+
 // ============================================================================
 // SDF Functions
 // ============================================================================
@@ -309,8 +386,21 @@ fn calculate_clip_alpha(p: vec2<f32>, clip_bounds: vec4<f32>, clip_radius: vec4<
 // Fragment Shader
 // ============================================================================
 
+// Set when the target is sRGB-encoded: colors are written in linear space so
+// blending happens in linear space and the GPU encodes on store
+override LINEAR_OUTPUT: bool = false;
+
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let color = shade(in);
+    if LINEAR_OUTPUT {
+        return vec4<f32>(srgb_to_linear(color.rgb), color.a);
+    }
+    return color;
+}
+
+// Shade a primitive, producing a straight-alpha sRGB color
+fn shade(in: VertexOutput) -> vec4<f32> {
     let prim = primitives[in.instance_index];
     let p = in.uv;
 
@@ -477,11 +567,16 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
             if is_color {
                 // Color emoji - sample RGBA directly from color atlas
                 text_result = textureSample(color_glyph_atlas, glyph_sampler, atlas_uv);
+                // The sRGB atlas decodes to linear; fs_main expects sRGB back
+                if LINEAR_OUTPUT {
+                    text_result = vec4<f32>(linear_to_srgb(text_result.rgb), text_result.a);
+                }
             } else {
                 // Grayscale text - sample coverage from R channel, apply color tint
                 let coverage = textureSample(glyph_atlas, glyph_sampler, atlas_uv).r;
-                // Apply gamma correction for crisp text rendering
-                let gamma_coverage = pow(coverage, 0.7);
+                // Sharpen coverage to make up for blending in sRGB space; blending
+                // in linear space is already gamma-correct
+                let gamma_coverage = select(pow(coverage, 0.7), coverage, LINEAR_OUTPUT);
                 text_result = vec4<f32>(prim.color.rgb, prim.color.a * gamma_coverage);
             }
 
@@ -514,7 +609,7 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
 
     // Determine fill color
     var fill_color: vec4<f32>;
-    switch fill_type {
+    switch fill_type & FILL_TYPE_MASK {
         case FILL_SOLID: {
             fill_color = prim.color;
         }
@@ -533,7 +628,7 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
             } else {
                 t = 0.0;
             }
-            fill_color = mix(prim.color, prim.color2, t);
+            fill_color = mix_gradient(prim.color, prim.color2, t, fill_type, p);
         }
         case FILL_RADIAL_GRADIENT: {
             // Radial gradient using gradient_params (cx, cy, radius, 0) in user space
@@ -542,7 +637,7 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
 
             let dist = length(p - g_center);
             let t = clamp(dist / max(g_radius, 0.001), 0.0, 1.0);
-            fill_color = mix(prim.color, prim.color2, t);
+            fill_color = mix_gradient(prim.color, prim.color2, t, fill_type, p);
         }
         default: {
             fill_color = prim.color;
@@ -786,6 +881,14 @@ fn calculate_clip_alpha(p: vec2<f32>, clip_bounds: vec4<f32>) -> f32 {
     return clamp(d + 0.5, 0.0, 1.0);
 }
 
+// Set when the target is sRGB-encoded: the tint is converted to linear so
+// coverage blends in linear space (gamma-correct AA)
+override LINEAR_OUTPUT: bool = false;
+
+fn srgb_to_linear(c: vec3<f32>) -> vec3<f32> {
+    return select(pow((c + 0.055) / 1.055, vec3<f32>(2.4)), c / 12.92, c <= vec3<f32>(0.04045));
+}
+
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     // Calculate clip alpha first - discard if completely outside
@@ -807,7 +910,11 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         // Use coverage directly with slight gamma correction for cleaner edges
         // The rasterizer provides good coverage values - we just need to
         // apply a subtle curve to sharpen without losing anti-aliasing
-        // pow(x, 0.7) brightens mid-tones, making strokes appear crisper
+        // pow(x, 0.7) brightens mid-tones, making strokes appear crisper.
+        // Only needed when blending in sRGB space.
+        if LINEAR_OUTPUT {
+            return vec4<f32>(srgb_to_linear(in.color.rgb), in.color.a * coverage * clip_alpha);
+        }
         let aa_alpha = pow(coverage, 0.7);
 
         // Apply both text alpha and clip alpha
@@ -2009,6 +2116,20 @@ fn adjust_saturation(color: vec3<f32>, saturation: f32) -> vec3<f32> {
     return mix(vec3<f32>(gray), color, saturation);
 }
 
+// Set when the target is sRGB-encoded: brush colors are converted to linear
+// so blending happens in linear space. Backdrop and image samples come from
+// sRGB textures and are already linear.
+override LINEAR_OUTPUT: bool = false;
+
+// Convert an sRGB brush color to the output color space
+fn brush_color(c: vec4<f32>) -> vec4<f32> {
+    if LINEAR_OUTPUT {
+        let rgb = select(pow((c.rgb + 0.055) / 1.055, vec3<f32>(2.4)), c.rgb / 12.92, c.rgb <= vec3<f32>(0.04045));
+        return vec4<f32>(rgb, c.a);
+    }
+    return c;
+}
+
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     // Calculate clip alpha first
@@ -2042,7 +2163,8 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         backdrop = vec4<f32>(adjust_saturation(backdrop.rgb, saturation), backdrop.a);
 
         // Apply tint
-        let tinted = mix(backdrop.rgb, uniforms.glass_tint.rgb, tint_strength * uniforms.glass_tint.a);
+        let tint = brush_color(uniforms.glass_tint);
+        let tinted = mix(backdrop.rgb, tint.rgb, tint_strength * tint.a);
 
         // Final color with glass opacity
         color = vec4<f32>(tinted, glass_opacity);
@@ -2054,10 +2176,11 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         let image_uv = uv_min + in.uv * (uv_max - uv_min);
         color = textureSample(image_texture, image_sampler, image_uv);
         // Apply tint from vertex color (multiply)
-        color = vec4<f32>(color.rgb * in.color.rgb, color.a * in.color.a);
+        let tint = brush_color(in.color);
+        color = vec4<f32>(color.rgb * tint.rgb, color.a * tint.a);
     } else if (in.gradient_type == 0u) {
         // Solid color
-        color = in.color;
+        color = brush_color(in.color);
     } else if (in.gradient_type == 1u) {
         // Linear gradient - use gradient_params for direction
         // params: (x1, y1, x2, y2) in ObjectBoundingBox space (0-1)
@@ -2078,10 +2201,10 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         // Sample from gradient texture or mix vertex colors
         if (uniforms.use_gradient_texture == 1u) {
             // Multi-stop gradient: sample from 1D texture
-            color = textureSample(gradient_texture, gradient_sampler, t);
+            color = brush_color(textureSample(gradient_texture, gradient_sampler, t));
         } else {
             // 2-stop fast path: mix vertex colors
-            color = mix(in.color, in.end_color, t);
+            color = brush_color(mix(in.color, in.end_color, t));
         }
     } else {
         // Radial gradient - params: (cx, cy, r, 0) in ObjectBoundingBox space
@@ -2093,10 +2216,10 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         // Sample from gradient texture or mix vertex colors
         if (uniforms.use_gradient_texture == 1u) {
             // Multi-stop gradient: sample from 1D texture
-            color = textureSample(gradient_texture, gradient_sampler, t);
+            color = brush_color(textureSample(gradient_texture, gradient_sampler, t));
         } else {
             // 2-stop fast path: mix vertex colors
-            color = mix(in.color, in.end_color, t);
+            color = brush_color(mix(in.color, in.end_color, t));
         }
     }
 
@@ -2954,3 +3077,117 @@ fn fs_glow(in: VertexOutput) -> @location(0) vec4<f32> {
     return vec4<f32>(result_rgb, result_a);
 }
 "#;
+
+/// Bloom and tone mapping shader for layer effects
+///
+/// One fullscreen pass with a mode switch:
+/// - bloom extract: keep the part of each pixel above the threshold
+/// - bloom combine: add blurred bright pixels back onto the input
+/// - tone map: exposure followed by a Reinhard or ACES curve
+pub const POST_PROCESS_SHADER: &str = r#"
+// ============================================================================
+// Post Process Shader (Layer Effects)
+// ============================================================================
+//
+// Bloom runs as extract -> shadow blur (alpha included, so light spreads
+// past the edges) -> combine. Colors are straight (non-premultiplied)
+// alpha, like the other effect shaders.
+
+struct PostProcessUniforms {
+    // 0 = bloom extract, 1 = bloom combine, 2 = Reinhard, 3 = ACES
+    mode: u32,
+    // Brightness (0-1) above which pixels bloom
+    threshold: f32,
+    // Strength of the added bloom light
+    intensity: f32,
+    // Exposure multiplier applied before tone mapping
+    exposure: f32,
+}
+
+const MODE_BLOOM_EXTRACT: u32 = 0u;
+const MODE_BLOOM_COMBINE: u32 = 1u;
+const MODE_REINHARD: u32 = 2u;
+
+@group(0) @binding(0) var<uniform> uniforms: PostProcessUniforms;
+@group(0) @binding(1) var input_texture: texture_2d<f32>;
+@group(0) @binding(2) var input_sampler: sampler;
+// Blurred bright pixels (bloom combine only)
+@group(0) @binding(3) var bloom_texture: texture_2d<f32>;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+// Full-screen quad vertices
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    var positions = array<vec2<f32>, 6>(
+        vec2<f32>(-1.0, -1.0),
+        vec2<f32>( 1.0, -1.0),
+        vec2<f32>(-1.0,  1.0),
+        vec2<f32>( 1.0, -1.0),
+        vec2<f32>( 1.0,  1.0),
+        vec2<f32>(-1.0,  1.0),
+    );
+
+    var uvs = array<vec2<f32>, 6>(
+        vec2<f32>(0.0, 1.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(0.0, 0.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(1.0, 0.0),
+        vec2<f32>(0.0, 0.0),
+    );
+
+    var out: VertexOutput;
+    out.position = vec4<f32>(positions[vertex_index], 0.0, 1.0);
+    out.uv = uvs[vertex_index];
+    return out;
+}
+
+// Narkowicz's fit of the ACES filmic curve
+fn aces(x: vec3<f32>) -> vec3<f32> {
+    let a = 2.51;
+    let b = 0.03;
+    let c = 2.43;
+    let d = 0.59;
+    let e = 0.14;
+    return clamp((x * (a * x + b)) / (x * (c * x + d) + e), vec3<f32>(0.0), vec3<f32>(1.0));
+}
+
+@fragment
+fn fs_post_process(in: VertexOutput) -> @location(0) vec4<f32> {
+    let src = textureSample(input_texture, input_sampler, in.uv);
+    // Sampled unconditionally: texture sampling must stay in uniform control flow
+    let bloom = textureSample(bloom_texture, input_sampler, in.uv);
+
+    if (uniforms.mode == MODE_BLOOM_EXTRACT) {
+        // Fade in from the threshold so the glow doesn't start with a hard edge
+        let brightness = max(src.r, max(src.g, src.b));
+        let amount = clamp(
+            (brightness - uniforms.threshold) / max(1.0 - uniforms.threshold, 0.0001),
+            0.0,
+            1.0
+        );
+        return vec4<f32>(src.rgb, src.a * amount);
+    }
+
+    if (uniforms.mode == MODE_BLOOM_COMBINE) {
+        // Add light in premultiplied space, then convert back
+        let light = bloom.rgb * bloom.a * uniforms.intensity;
+        let alpha = clamp(src.a + bloom.a * uniforms.intensity, 0.0, 1.0);
+        if (alpha < 0.001) {
+            return vec4<f32>(0.0);
+        }
+        let rgb = (src.rgb * src.a + light) / alpha;
+        return vec4<f32>(clamp(rgb, vec3<f32>(0.0), vec3<f32>(1.0)), alpha);
+    }
+
+    let exposed = src.rgb * uniforms.exposure;
+    if (uniforms.mode == MODE_REINHARD) {
+        return vec4<f32>(exposed / (vec3<f32>(1.0) + exposed), src.a);
+    }
+    return vec4<f32>(aces(exposed), src.a);
+}
+"#;
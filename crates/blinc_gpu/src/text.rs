@@ -23,29 +23,119 @@ pub struct TextMeasurement {
     pub descender: f32,
 }
 
-/// Text rendering context that manages font, atlas, and glyph preparation
-pub struct TextRenderingContext {
+/// Glyph atlas that several text contexts can share
+///
+/// Holds the glyph cache, the CPU-side atlases and their GPU textures
+/// behind one lock, so contexts rendering on the same wgpu device (e.g. one
+/// per window) rasterize and store each glyph only once. Pass it to every
+/// renderer through [`RendererConfig::glyph_atlas`](crate::RendererConfig)
+/// and create text contexts with
+/// [`GpuRenderer::create_text_context`](crate::GpuRenderer::create_text_context).
+///
+/// All contexts sharing an atlas must use the same device; create extra
+/// renderers with [`GpuRenderer::on_same_device`](crate::GpuRenderer::on_same_device).
+#[derive(Clone)]
+pub struct SharedGlyphAtlas {
+    state: Arc<Mutex<AtlasState>>,
+}
+
+impl SharedGlyphAtlas {
+    /// Create an empty atlas; GPU textures are created on first use
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(AtlasState {
+                renderer: TextRenderer::new(),
+                device: None,
+                atlas_texture: None,
+                atlas_view: None,
+                color_atlas_texture: None,
+                color_atlas_view: None,
+                uploads: 0,
+            })),
+        }
+    }
+
+    /// Number of glyphs cached in the grayscale atlas
+    pub fn glyph_count(&self) -> usize {
+        self.lock().renderer.atlas().glyph_count()
+    }
+
+    /// Number of glyphs cached in the color (emoji) atlas
+    pub fn color_glyph_count(&self) -> usize {
+        self.lock().renderer.color_atlas().glyph_count()
+    }
+
+    /// Number of times atlas pixels have been uploaded to the GPU
+    pub fn upload_count(&self) -> u64 {
+        self.lock().uploads
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, AtlasState> {
+        // A panic mid-insert leaves the atlas usable (at worst one glyph short)
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for SharedGlyphAtlas {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for SharedGlyphAtlas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedGlyphAtlas")
+            .field("glyph_count", &self.glyph_count())
+            .finish()
+    }
+}
+
+/// Shared glyph state guarded by [`SharedGlyphAtlas`]
+struct AtlasState {
     /// The text renderer (font, atlas, rasterizer)
     renderer: TextRenderer,
-    /// GPU device for texture creation
-    device: Arc<wgpu::Device>,
-    /// GPU queue for texture upload
-    queue: Arc<wgpu::Queue>,
-    /// Glyph atlas texture (grayscale, created on demand)
+    /// Device the textures were created on
+    device: Option<Arc<wgpu::Device>>,
+    /// Glyph atlas texture (grayscale)
     atlas_texture: Option<wgpu::Texture>,
     /// Glyph atlas texture view
-    atlas_view: Option<wgpu::TextureView>,
-    /// Color glyph atlas texture (RGBA for emoji, created on demand)
+    atlas_view: Option<Arc<wgpu::TextureView>>,
+    /// Color glyph atlas texture (RGBA for emoji)
     color_atlas_texture: Option<wgpu::Texture>,
     /// Color glyph atlas texture view
-    color_atlas_view: Option<wgpu::TextureView>,
+    color_atlas_view: Option<Arc<wgpu::TextureView>>,
+    /// Atlas texture uploads so far (grayscale and color)
+    uploads: u64,
+}
+
+/// Text rendering context that manages font, atlas, and glyph preparation
+pub struct TextRenderingContext {
+    /// Glyph cache, atlases and atlas textures (possibly shared)
+    atlas: SharedGlyphAtlas,
+    /// GPU device for texture creation
+    device: Arc<wgpu::Device>,
+    /// GPU queue for texture upload
+    queue: Arc<wgpu::Queue>,
+    /// Glyph atlas texture view as of this context's last atlas access
+    atlas_view: Option<Arc<wgpu::TextureView>>,
+    /// Color glyph atlas texture view as of this context's last atlas access
+    color_atlas_view: Option<Arc<wgpu::TextureView>>,
     /// Sampler for the atlas
     sampler: wgpu::Sampler,
 }
 
 impl TextRenderingContext {
-    /// Create a new text rendering context
+    /// Create a new text rendering context with its own glyph atlas
     pub fn new(device: Arc<wgpu::Device>, queue: Arc<wgpu::Queue>) -> Self {
+        Self::with_shared_atlas(device, queue, SharedGlyphAtlas::new())
+    }
+
+    /// Create a text rendering context that caches glyphs in a shared atlas
+    pub fn with_shared_atlas(
+        device: Arc<wgpu::Device>,
+        queue: Arc<wgpu::Queue>,
+        atlas: SharedGlyphAtlas,
+    ) -> Self {
         // Use Nearest filtering for sharp, pixel-perfect text at 1:1 scale
         // Linear filtering causes blur when glyphs are rendered at exact pixel positions
         let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
@@ -59,85 +149,64 @@ impl TextRenderingContext {
             ..Default::default()
         });
 
-        // Create initial empty atlases (will be populated as glyphs are used)
-        let renderer = TextRenderer::new();
-
-        // Grayscale atlas for regular text
-        let (gray_width, gray_height) = renderer.atlas_dimensions();
-        let atlas_texture = device.create_texture(&wgpu::TextureDescriptor {
-            label: Some("Glyph Atlas Texture"),
-            size: wgpu::Extent3d {
-                width: gray_width,
-                height: gray_height,
-                depth_or_array_layers: 1,
-            },
-            mip_level_count: 1,
-            sample_count: 1,
-            dimension: wgpu::TextureDimension::D2,
-            format: wgpu::TextureFormat::R8Unorm,
-            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
-            view_formats: &[],
-        });
-        let atlas_view = atlas_texture.create_view(&wgpu::TextureViewDescriptor::default());
-
-        // RGBA color atlas for emoji
-        let (color_width, color_height) = renderer.color_atlas_dimensions();
-        let color_atlas_texture = device.create_texture(&wgpu::TextureDescriptor {
-            label: Some("Color Glyph Atlas Texture"),
-            size: wgpu::Extent3d {
-                width: color_width,
-                height: color_height,
-                depth_or_array_layers: 1,
-            },
-            mip_level_count: 1,
-            sample_count: 1,
-            dimension: wgpu::TextureDimension::D2,
-            format: wgpu::TextureFormat::Rgba8UnormSrgb,
-            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
-            view_formats: &[],
-        });
-        let color_atlas_view =
-            color_atlas_texture.create_view(&wgpu::TextureViewDescriptor::default());
-
-        Self {
-            renderer,
+        let mut ctx = Self {
+            atlas,
             device,
             queue,
-            atlas_texture: Some(atlas_texture),
-            atlas_view: Some(atlas_view),
-            color_atlas_texture: Some(color_atlas_texture),
-            color_atlas_view: Some(color_atlas_view),
+            atlas_view: None,
+            color_atlas_view: None,
             sampler,
-        }
+        };
+        // Create the atlas textures up front so views exist before any text
+        ctx.with_renderer(|_| ());
+        ctx
+    }
+
+    /// Get the glyph atlas this context caches glyphs in
+    pub fn shared_atlas(&self) -> &SharedGlyphAtlas {
+        &self.atlas
+    }
+
+    /// Run `f` on the shared text renderer, then upload any atlas changes
+    fn with_renderer<R>(&mut self, f: impl FnOnce(&mut TextRenderer) -> R) -> R {
+        let mut state = self.atlas.lock();
+        let result = f(&mut state.renderer);
+        state.sync_textures(&self.device, &self.queue);
+        self.atlas_view = state.atlas_view.clone();
+        self.color_atlas_view = state.color_atlas_view.clone();
+        result
     }
 
     /// Load the default font from a file path
     pub fn load_font(&mut self, path: &std::path::Path) -> Result<(), blinc_text::TextError> {
-        self.renderer.load_default_font(path)
+        let result = self.with_renderer(|r| r.load_default_font(path));
+        blinc_layout::invalidate_text_measure_cache();
+        result
     }
 
     /// Preload fonts by name (call at startup for fonts your app uses)
     /// This ensures fonts are cached before render time.
     pub fn preload_fonts(&mut self, names: &[&str]) {
-        self.renderer.preload_fonts(names);
+        self.with_renderer(|r| r.preload_fonts(names));
     }
 
     /// Preload fonts with specific weights and styles
     /// Each spec is (font_name, weight, italic)
     /// Weight: 400 = normal, 700 = bold
     pub fn preload_fonts_with_styles(&mut self, specs: &[(&str, u16, bool)]) {
-        self.renderer.preload_fonts_with_styles(specs);
+        self.with_renderer(|r| r.preload_fonts_with_styles(specs));
     }
 
     /// Preload generic font variants with specific weights
     pub fn preload_generic_styles(&mut self, generic: GenericFont, weights: &[u16], italic: bool) {
-        self.renderer
-            .preload_generic_styles(generic, weights, italic);
+        self.with_renderer(|r| r.preload_generic_styles(generic, weights, italic));
     }
 
     /// Load the default font from data
     pub fn load_font_data(&mut self, data: Vec<u8>) -> Result<(), blinc_text::TextError> {
-        self.renderer.load_default_font_data(data)
+        let result = self.with_renderer(|r| r.load_default_font_data(data));
+        blinc_layout::invalidate_text_measure_cache();
+        result
     }
 
     /// Load font data into the registry (for use by the rendering system)
@@ -148,12 +217,18 @@ impl TextRenderingContext {
     /// Use this instead of `load_font_data` when you want fonts to be
     /// available for regular text rendering (not just as a default fallback).
     pub fn load_font_data_to_registry(&mut self, data: Vec<u8>) -> usize {
-        self.renderer.load_font_data_to_registry(data)
+        let loaded = self.with_renderer(|r| r.load_font_data_to_registry(data));
+        if loaded > 0 {
+            // New faces can change fallback, so cached layout sizes may be stale
+            blinc_layout::invalidate_text_measure_cache();
+        }
+        loaded
     }
 
     /// Set the default font
     pub fn set_font(&mut self, font: blinc_text::FontFace) {
-        self.renderer.set_default_font(font);
+        self.with_renderer(|r| r.set_default_font(font));
+        blinc_layout::invalidate_text_measure_cache();
     }
 
     /// Prepare text for GPU rendering with default top anchor
@@ -289,7 +364,7 @@ impl TextRenderingContext {
     ) -> Result<Vec<GpuGlyph>, blinc_text::TextError> {
         self.prepare_text_with_style(
             text, x, y, font_size, color, anchor, alignment, width, wrap, font_name, generic, 400,
-            false, None,
+            false, false, true, true, None,
         )
     }
 
@@ -309,6 +384,9 @@ impl TextRenderingContext {
     /// * `generic` - Generic font category for fallback
     /// * `weight` - Font weight (100-900, 400=normal, 700=bold)
     /// * `italic` - Whether to use italic variant
+    /// * `preformatted` - Keep leading whitespace on wrapped lines
+    /// * `ligatures` - Apply font ligatures (e.g. "fi")
+    /// * `kerning` - Apply font kerning (e.g. "AV")
     /// * `layout_height` - Optional layout-assigned height for vertical centering
     #[allow(clippy::too_many_arguments)]
     pub fn prepare_text_with_style(
@@ -326,11 +404,17 @@ impl TextRenderingContext {
         generic: GenericFont,
         weight: u16,
         italic: bool,
+        preformatted: bool,
+        ligatures: bool,
+        kerning: bool,
         layout_height: Option<f32>,
     ) -> Result<Vec<GpuGlyph>, blinc_text::TextError> {
         let mut options = LayoutOptions::default();
         options.anchor = anchor;
         options.alignment = alignment;
+        options.preformatted = preformatted;
+        options.ligatures = ligatures;
+        options.kerning = kerning;
         if let Some(w) = width {
             options.max_width = Some(w);
         }
@@ -339,9 +423,11 @@ impl TextRenderingContext {
             options.line_break = blinc_text::LineBreakMode::None;
         }
 
-        let prepared = self.renderer.prepare_text_with_style(
-            text, font_size, color, &options, font_name, generic, weight, italic,
-        )?;
+        let prepared = self.with_renderer(|r| {
+            r.prepare_text_with_style(
+                text, font_size, color, &options, font_name, generic, weight, italic,
+            )
+        })?;
 
         // Determine the number of lines from the prepared text
         // If there's only 1 line, use glyph_extent for more accurate centering
@@ -417,16 +503,6 @@ impl TextRenderingContext {
             })
             .collect();
 
-        // Update atlas textures if dirty
-        if self.renderer.atlas_is_dirty() {
-            self.update_atlas_texture();
-            self.renderer.mark_atlas_clean();
-        }
-        if self.renderer.color_atlas_is_dirty() {
-            self.update_color_atlas_texture();
-            self.renderer.mark_color_atlas_clean();
-        }
-
         Ok(glyphs)
     }
 
@@ -451,15 +527,17 @@ impl TextRenderingContext {
         options.anchor = anchor;
         options.line_break = blinc_text::LineBreakMode::None;
 
-        let prepared = self.renderer.prepare_styled_text(
-            text,
-            font_size,
-            default_color,
-            color_spans,
-            &options,
-            font_name,
-            generic,
-        )?;
+        let prepared = self.with_renderer(|r| {
+            r.prepare_styled_text(
+                text,
+                font_size,
+                default_color,
+                color_spans,
+                &options,
+                font_name,
+                generic,
+            )
+        })?;
 
         // Determine if this is single-line or multi-line text
         // For single-line, use glyph_extent for more accurate centering
@@ -500,15 +578,6 @@ impl TextRenderingContext {
             })
             .collect();
 
-        if self.renderer.atlas_is_dirty() {
-            self.update_atlas_texture();
-            self.renderer.mark_atlas_clean();
-        }
-        if self.renderer.color_atlas_is_dirty() {
-            self.update_color_atlas_texture();
-            self.renderer.mark_color_atlas_clean();
-        }
-
         Ok(glyphs)
     }
 
@@ -518,10 +587,7 @@ impl TextRenderingContext {
     /// for accurate measurement.
     pub fn measure_text(&mut self, text: &str, font_size: f32) -> (f32, f32) {
         let options = LayoutOptions::default();
-        match self
-            .renderer
-            .prepare_text(text, font_size, [0.0; 4], &options)
-        {
+        match self.with_renderer(|r| r.prepare_text(text, font_size, [0.0; 4], &options)) {
             Ok(prepared) => (prepared.width, prepared.height),
             Err(_) => {
                 // Fallback to estimation if font not loaded
@@ -538,10 +604,7 @@ impl TextRenderingContext {
     /// Returns TextMeasurement with width, height, ascender, and descender.
     pub fn measure_text_full(&mut self, text: &str, font_size: f32) -> TextMeasurement {
         let options = LayoutOptions::default();
-        match self
-            .renderer
-            .prepare_text(text, font_size, [0.0; 4], &options)
-        {
+        match self.with_renderer(|r| r.prepare_text(text, font_size, [0.0; 4], &options)) {
             Ok(prepared) => TextMeasurement {
                 width: prepared.width,
                 height: prepared.height,
@@ -563,12 +626,12 @@ impl TextRenderingContext {
 
     /// Get the atlas texture view (creates it if needed)
     pub fn atlas_view(&self) -> Option<&wgpu::TextureView> {
-        self.atlas_view.as_ref()
+        self.atlas_view.as_deref()
     }
 
     /// Get the color atlas texture view (RGBA for emoji)
     pub fn color_atlas_view(&self) -> Option<&wgpu::TextureView> {
-        self.color_atlas_view.as_ref()
+        self.color_atlas_view.as_deref()
     }
 
     /// Get the sampler
@@ -581,11 +644,35 @@ impl TextRenderingContext {
     /// This can be used to share the font registry with other components
     /// like text measurement, ensuring consistent font loading and metrics.
     pub fn font_registry(&self) -> Arc<Mutex<FontRegistry>> {
-        self.renderer.font_registry()
+        self.atlas.lock().renderer.font_registry()
+    }
+}
+
+impl AtlasState {
+    /// Create the atlas textures if needed and upload dirty atlases
+    fn sync_textures(&mut self, device: &Arc<wgpu::Device>, queue: &wgpu::Queue) {
+        match &self.device {
+            Some(bound) => debug_assert!(
+                Arc::ptr_eq(bound, device),
+                "a shared glyph atlas must only be used with one wgpu device"
+            ),
+            None => self.device = Some(device.clone()),
+        }
+
+        if self.atlas_texture.is_none() || self.renderer.atlas_is_dirty() {
+            self.update_atlas_texture(device, queue);
+            self.renderer.mark_atlas_clean();
+            self.uploads += 1;
+        }
+        if self.color_atlas_texture.is_none() || self.renderer.color_atlas_is_dirty() {
+            self.update_color_atlas_texture(device, queue);
+            self.renderer.mark_color_atlas_clean();
+            self.uploads += 1;
+        }
     }
 
     /// Update the GPU atlas texture from the TextRenderer's atlas
-    fn update_atlas_texture(&mut self) {
+    fn update_atlas_texture(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
         let (width, height) = self.renderer.atlas_dimensions();
         let pixels = self.renderer.atlas_pixels();
 
@@ -596,7 +683,7 @@ impl TextRenderingContext {
         };
 
         if needs_create {
-            let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            let texture = device.create_texture(&wgpu::TextureDescriptor {
                 label: Some("Glyph Atlas Texture"),
                 size: wgpu::Extent3d {
                     width,
@@ -613,12 +700,12 @@ impl TextRenderingContext {
 
             let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
             self.atlas_texture = Some(texture);
-            self.atlas_view = Some(view);
+            self.atlas_view = Some(Arc::new(view));
         }
 
         // Upload pixel data
         if let Some(texture) = &self.atlas_texture {
-            self.queue.write_texture(
+            queue.write_texture(
                 wgpu::ImageCopyTexture {
                     texture,
                     mip_level: 0,
@@ -641,7 +728,7 @@ impl TextRenderingContext {
     }
 
     /// Update the GPU color atlas texture from the TextRenderer's color atlas
-    fn update_color_atlas_texture(&mut self) {
+    fn update_color_atlas_texture(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
         let (width, height) = self.renderer.color_atlas_dimensions();
         let pixels = self.renderer.color_atlas_pixels();
 
@@ -652,7 +739,7 @@ impl TextRenderingContext {
         };
 
         if needs_create {
-            let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            let texture = device.create_texture(&wgpu::TextureDescriptor {
                 label: Some("Color Glyph Atlas Texture"),
                 size: wgpu::Extent3d {
                     width,
@@ -669,12 +756,12 @@ impl TextRenderingContext {
 
             let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
             self.color_atlas_texture = Some(texture);
-            self.color_atlas_view = Some(view);
+            self.color_atlas_view = Some(Arc::new(view));
         }
 
         // Upload pixel data (RGBA = 4 bytes per pixel)
         if let Some(texture) = &self.color_atlas_texture {
-            self.queue.write_texture(
+            queue.write_texture(
                 wgpu::ImageCopyTexture {
                     texture,
                     mip_level: 0,
@@ -4,6 +4,14 @@
 //! (`GpuPaintContext` via the `DrawContext` trait) within the layout system.
 //! Canvas inherits transforms, clipping, and layer settings from parent elements.
 //!
+//! Pointer events can be handled with [`Canvas::on_pointer`], which receives
+//! points in the same local space the render callback draws in, so custom
+//! graphics can do their own hit-testing.
+//!
+//! Canvases created with [`canvas_animated`] also receive [`CanvasFrameInfo`]
+//! (time, delta and frame number) so they can animate without external
+//! timelines.
+//!
 //! # GPU Context
 //!
 //! The callback receives a `GpuPaintContext` (as `&mut dyn DrawContext`) which
@@ -33,13 +41,15 @@
 //! .h(20.0)
 //! ```
 
+use std::cell::Cell;
 use std::rc::Rc;
 
-use blinc_core::DrawContext;
+use blinc_core::{DrawContext, Point};
 use taffy::prelude::*;
 
 use crate::div::{ElementBuilder, ElementTypeId};
 use crate::element::{RenderLayer, RenderProps};
+use crate::event_handler::{EventContext, EventHandlers};
 use crate::tree::{LayoutNodeId, LayoutTree};
 
 /// Bounds passed to canvas render callback
@@ -51,17 +61,70 @@ pub struct CanvasBounds {
     pub height: f32,
 }
 
+/// Frame timing passed to canvas render callbacks
+///
+/// Advanced once per frame by the render loop
+/// (see [`RenderTree::begin_canvas_frame`](crate::renderer::RenderTree::begin_canvas_frame)).
+#[derive(Clone, Debug, Default)]
+pub struct CanvasFrameInfo {
+    /// Seconds since the first frame
+    pub time: f32,
+    /// Seconds since the previous frame (0 on the first frame)
+    pub dt: f32,
+    /// Frame number, starting at 0
+    pub frame: u64,
+    /// Timestamps (ms) of the first and latest frame
+    start_ms: Option<u64>,
+    last_ms: u64,
+    /// Set by `request_animation()` during the current frame
+    animation_requested: Cell<bool>,
+}
+
+impl CanvasFrameInfo {
+    /// Ask for another frame after this one
+    ///
+    /// Call from the render callback every frame the canvas is animating;
+    /// in on-demand redraw mode the loop stops once no canvas asks.
+    pub fn request_animation(&self) {
+        self.animation_requested.set(true);
+    }
+
+    /// Whether a canvas requested another frame since the last `advance`
+    pub fn animation_requested(&self) -> bool {
+        self.animation_requested.get()
+    }
+
+    /// Move to the frame at `time_ms`
+    pub(crate) fn advance(&mut self, time_ms: u64) {
+        match self.start_ms {
+            Some(start) => {
+                let time_ms = time_ms.max(self.last_ms);
+                self.time = (time_ms - start) as f32 / 1000.0;
+                self.dt = (time_ms - self.last_ms) as f32 / 1000.0;
+                self.frame += 1;
+                self.last_ms = time_ms;
+            }
+            None => {
+                self.start_ms = Some(time_ms);
+                self.last_ms = time_ms;
+            }
+        }
+        self.animation_requested.set(false);
+    }
+}
+
 /// Canvas render callback type
 ///
 /// The callback receives:
 /// - `ctx`: The GPU paint context (`GpuPaintContext` as `&mut dyn DrawContext`)
 /// - `bounds`: The computed bounds of the canvas element
+/// - `frame`: Frame timing for the current frame
 ///
 /// The context already has the correct transform applied (canvas position),
 /// so drawing at (0, 0) draws at the canvas origin. The context also inherits
 /// clip regions and opacity from parent elements.
 /// Canvas render function type - uses Rc for single-threaded UI
-pub type CanvasRenderFn = Rc<dyn Fn(&mut dyn DrawContext, CanvasBounds)>;
+pub type CanvasRenderFn = Rc<dyn Fn(&mut dyn DrawContext, CanvasBounds, &CanvasFrameInfo)>;
 
 /// Canvas element for custom GPU drawing
 ///
@@ -86,6 +149,8 @@ pub struct Canvas {
     opacity: f32,
     /// Render layer (background, foreground, glass)
     layer: RenderLayer,
+    /// Pointer event handlers
+    handlers: EventHandlers,
 }
 
 impl Canvas {
@@ -96,6 +161,7 @@ impl Canvas {
             render_fn: None,
             opacity: 1.0,
             layer: RenderLayer::default(),
+            handlers: EventHandlers::new(),
         }
     }
 
@@ -104,18 +170,34 @@ impl Canvas {
     where
         F: Fn(&mut dyn DrawContext, CanvasBounds) + 'static,
     {
-        Self {
-            style: Style::default(),
-            render_fn: Some(Rc::new(render_fn)),
-            opacity: 1.0,
-            layer: RenderLayer::default(),
-        }
+        Self::new().render(render_fn)
+    }
+
+    /// Create a canvas with a render callback that also receives frame timing
+    pub fn with_animated_render<F>(render_fn: F) -> Self
+    where
+        F: Fn(&mut dyn DrawContext, CanvasBounds, &CanvasFrameInfo) + 'static,
+    {
+        Self::new().render_animated(render_fn)
     }
 
     /// Set the render callback
     pub fn render<F>(mut self, render_fn: F) -> Self
     where
         F: Fn(&mut dyn DrawContext, CanvasBounds) + 'static,
+    {
+        self.render_fn = Some(Rc::new(
+            move |ctx: &mut dyn DrawContext, bounds: CanvasBounds, _frame: &CanvasFrameInfo| {
+                render_fn(ctx, bounds)
+            },
+        ));
+        self
+    }
+
+    /// Set a render callback that also receives frame timing
+    pub fn render_animated<F>(mut self, render_fn: F) -> Self
+    where
+        F: Fn(&mut dyn DrawContext, CanvasBounds, &CanvasFrameInfo) + 'static,
     {
         self.render_fn = Some(Rc::new(render_fn));
         self
@@ -200,6 +282,34 @@ impl Canvas {
         self
     }
 
+    /// Handle pointer events inside the canvas
+    ///
+    /// Called for pointer down/up/move and drag events with the pointer in
+    /// canvas-local coordinates: (0, 0) is the canvas origin and any
+    /// transforms on the canvas or its ancestors are undone, matching what
+    /// the render callback draws.
+    pub fn on_pointer<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&EventContext, Point) + 'static,
+    {
+        use blinc_core::events::event_types;
+
+        let handler = Rc::new(handler);
+        for event_type in [
+            event_types::POINTER_DOWN,
+            event_types::POINTER_UP,
+            event_types::POINTER_MOVE,
+            event_types::DRAG,
+            event_types::DRAG_END,
+        ] {
+            let handler = Rc::clone(&handler);
+            self.handlers.on(event_type, move |ctx| {
+                handler(ctx, Point::new(ctx.local_x, ctx.local_y))
+            });
+        }
+        self
+    }
+
     /// Get the render function
     pub fn render_fn(&self) -> Option<&CanvasRenderFn> {
         self.render_fn.as_ref()
@@ -241,6 +351,14 @@ impl ElementBuilder for Canvas {
     fn layout_style(&self) -> Option<&taffy::Style> {
         Some(&self.style)
     }
+
+    fn event_handlers(&self) -> Option<&EventHandlers> {
+        if self.handlers.is_empty() {
+            None
+        } else {
+            Some(&self.handlers)
+        }
+    }
 }
 
 /// Data stored in the render tree for canvas elements
@@ -302,6 +420,35 @@ where
     Canvas::with_render(render_fn)
 }
 
+/// Create a canvas element whose render callback receives frame timing
+///
+/// Like [`canvas`], plus a [`CanvasFrameInfo`] with the time since the first
+/// frame, the delta since the last one and the frame number. Call
+/// `frame.request_animation()` while animating so frames keep coming when
+/// nothing else is changing.
+///
+/// # Example
+///
+/// ```ignore
+/// // Pulsing dot
+/// canvas_animated(|ctx, bounds, frame| {
+///     let r = 10.0 + 4.0 * (frame.time * 3.0).sin();
+///     ctx.fill_circle(
+///         Point::new(bounds.width / 2.0, bounds.height / 2.0),
+///         r,
+///         Brush::Solid(Color::RED),
+///     );
+///     frame.request_animation();
+/// })
+/// .size(40.0, 40.0)
+/// ```
+pub fn canvas_animated<F>(render_fn: F) -> Canvas
+where
+    F: Fn(&mut dyn DrawContext, CanvasBounds, &CanvasFrameInfo) + 'static,
+{
+    Canvas::with_animated_render(render_fn)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -325,6 +472,132 @@ mod tests {
         assert_eq!(c.opacity, 0.5);
     }
 
+    #[test]
+    fn test_on_pointer_receives_canvas_local_point() {
+        use std::cell::RefCell;
+
+        use blinc_core::events::event_types;
+
+        use crate::div::div;
+        use crate::renderer::RenderTree;
+
+        let hits = Rc::new(RefCell::new(Vec::new()));
+        let recorded = Rc::clone(&hits);
+        let ui = div().w(400.0).h(400.0).child(
+            canvas(|_ctx, _bounds| {})
+                .size(100.0, 100.0)
+                .absolute()
+                .left(50.0)
+                .top(50.0)
+                .on_pointer(move |ctx, point| recorded.borrow_mut().push((ctx.event_type, point))),
+        );
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(400.0, 400.0);
+        let canvas_node = tree.layout().children(tree.root().unwrap())[0];
+
+        // Click the center of the canvas
+        tree.dispatch_event_full(
+            canvas_node,
+            event_types::POINTER_DOWN,
+            100.0,
+            100.0,
+            50.0,
+            50.0,
+            50.0,
+            50.0,
+            100.0,
+            100.0,
+            0.0,
+            0.0,
+        );
+        assert_eq!(
+            hits.borrow().as_slice(),
+            &[(event_types::POINTER_DOWN, Point::new(50.0, 50.0))]
+        );
+
+        // Scaled 2x around its center, window (125, 125) lands at local (62.5, 62.5)
+        hits.borrow_mut().clear();
+        let scaled =
+            div().w(400.0).h(400.0).child(
+                div()
+                    .absolute()
+                    .left(50.0)
+                    .top(50.0)
+                    .w(100.0)
+                    .h(100.0)
+                    .scale(2.0)
+                    .child({
+                        let recorded = Rc::clone(&hits);
+                        canvas(|_ctx, _bounds| {}).size(100.0, 100.0).on_pointer(
+                            move |ctx, point| recorded.borrow_mut().push((ctx.event_type, point)),
+                        )
+                    }),
+            );
+        let mut tree = RenderTree::from_element(&scaled);
+        tree.compute_layout(400.0, 400.0);
+        let wrapper = tree.layout().children(tree.root().unwrap())[0];
+        let canvas_node = tree.layout().children(wrapper)[0];
+        tree.dispatch_event_full(
+            canvas_node,
+            event_types::POINTER_MOVE,
+            125.0,
+            125.0,
+            75.0,
+            75.0,
+            50.0,
+            50.0,
+            100.0,
+            100.0,
+            0.0,
+            0.0,
+        );
+        assert_eq!(
+            hits.borrow().as_slice(),
+            &[(event_types::POINTER_MOVE, Point::new(62.5, 62.5))]
+        );
+    }
+
+    #[test]
+    fn test_canvas_animated_frame_time_increases() {
+        use std::cell::RefCell;
+
+        use blinc_core::{RecordingContext, Size};
+
+        use crate::div::div;
+        use crate::renderer::RenderTree;
+
+        let frames = Rc::new(RefCell::new(Vec::new()));
+        let recorded = Rc::clone(&frames);
+        let ui = div().w(100.0).h(100.0).child(
+            canvas_animated(move |_ctx, _bounds, frame| {
+                recorded
+                    .borrow_mut()
+                    .push((frame.time, frame.dt, frame.frame));
+                frame.request_animation();
+            })
+            .size(50.0, 50.0),
+        );
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(100.0, 100.0);
+
+        let mut ctx = RecordingContext::new(Size::new(100.0, 100.0));
+        for time_ms in [5_000, 5_016, 5_033, 5_050] {
+            tree.begin_canvas_frame(time_ms);
+            assert!(!tree.canvas_animation_requested());
+            tree.render(&mut ctx);
+            assert!(tree.canvas_animation_requested());
+        }
+
+        let frames = frames.borrow();
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames[0], (0.0, 0.0, 0));
+        assert!(frames
+            .windows(2)
+            .all(|w| w[1].0 > w[0].0 && w[1].2 == w[0].2 + 1));
+        assert!((frames[3].0 - 0.05).abs() < 1e-6);
+        assert!((frames[3].1 - 0.017).abs() < 1e-6);
+    }
+
     #[test]
     fn test_canvas_absolute_positioning() {
         let c = canvas(|_ctx, _bounds| {}).absolute().left(10.0).top(20.0);
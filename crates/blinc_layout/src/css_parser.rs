@@ -51,7 +51,8 @@
 use std::collections::HashMap;
 
 use blinc_core::{
-    Brush, Color, CornerRadius, Gradient, GradientSpace, GradientStop, Point, Shadow, Transform,
+    Brush, Color, CornerRadius, Gradient, GradientInterpolation, GradientSpace, GradientStop,
+    Point, Shadow, Transform,
 };
 use blinc_theme::{ColorToken, ThemeState};
 use nom::{
@@ -2620,6 +2621,7 @@ fn parse_named_color(name: &str) -> Option<Color> {
 /// - `linear-gradient(to right, red, blue)`
 /// - `linear-gradient(to bottom right, #fff, #000)`
 /// - `linear-gradient(90deg, red 0%, yellow 50%, green 100%)`
+/// - `linear-gradient(to right in oklab, blue, yellow)`
 fn parse_linear_gradient(input: &str) -> Option<Gradient> {
     // Strip the function wrapper
     let inner = input
@@ -2628,7 +2630,7 @@ fn parse_linear_gradient(input: &str) -> Option<Gradient> {
         .trim();
 
     // Split by commas, but be careful with colors that contain commas (rgb, rgba)
-    let parts = split_gradient_parts(inner);
+    let (parts, interpolation) = take_gradient_interpolation(split_gradient_parts(inner));
     if parts.is_empty() {
         return None;
     }
@@ -2651,6 +2653,8 @@ fn parse_linear_gradient(input: &str) -> Option<Gradient> {
         stops,
         space: GradientSpace::ObjectBoundingBox,
         spread: blinc_core::GradientSpread::Pad,
+        interpolation,
+        dither: false,
     })
 }
 
@@ -2666,7 +2670,7 @@ fn parse_radial_gradient(input: &str) -> Option<Gradient> {
         .and_then(|s| s.strip_suffix(')'))?
         .trim();
 
-    let parts = split_gradient_parts(inner);
+    let (parts, interpolation) = take_gradient_interpolation(split_gradient_parts(inner));
     if parts.is_empty() {
         return None;
     }
@@ -2707,6 +2711,8 @@ fn parse_radial_gradient(input: &str) -> Option<Gradient> {
         stops,
         space: GradientSpace::ObjectBoundingBox,
         spread: blinc_core::GradientSpread::Pad,
+        interpolation,
+        dither: false,
     })
 }
 
@@ -2722,7 +2728,7 @@ fn parse_conic_gradient(input: &str) -> Option<Gradient> {
         .and_then(|s| s.strip_suffix(')'))?
         .trim();
 
-    let parts = split_gradient_parts(inner);
+    let (parts, interpolation) = take_gradient_interpolation(split_gradient_parts(inner));
     if parts.is_empty() {
         return None;
     }
@@ -2771,9 +2777,40 @@ fn parse_conic_gradient(input: &str) -> Option<Gradient> {
         start_angle: start_angle * std::f32::consts::PI / 180.0, // Convert to radians
         stops,
         space: GradientSpace::ObjectBoundingBox,
+        interpolation,
+        dither: false,
     })
 }
 
+/// Extract a CSS Color 4 interpolation hint (`in oklab` / `in srgb`) from
+/// the first gradient argument, dropping the argument if nothing else is left
+fn take_gradient_interpolation(mut parts: Vec<String>) -> (Vec<String>, GradientInterpolation) {
+    let Some(first) = parts.first() else {
+        return (parts, GradientInterpolation::Srgb);
+    };
+    let lower = first.trim().to_lowercase();
+    let words: Vec<&str> = lower.split_whitespace().collect();
+    let Some(pos) = words.iter().position(|w| *w == "in") else {
+        return (parts, GradientInterpolation::Srgb);
+    };
+    let interpolation = match words.get(pos + 1) {
+        Some(&"oklab") => GradientInterpolation::Oklab,
+        Some(&"srgb") => GradientInterpolation::Srgb,
+        _ => return (parts, GradientInterpolation::Srgb),
+    };
+    let rest: Vec<&str> = words[..pos]
+        .iter()
+        .chain(words[pos + 2..].iter())
+        .copied()
+        .collect();
+    if rest.is_empty() {
+        parts.remove(0);
+    } else {
+        parts[0] = rest.join(" ");
+    }
+    (parts, interpolation)
+}
+
 /// Split gradient arguments by commas, respecting parentheses for rgb()/rgba()
 fn split_gradient_parts(input: &str) -> Vec<String> {
     let mut parts = Vec::new();
@@ -4464,6 +4501,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_linear_gradient_interpolation_hint() {
+        let css = r#"
+            #a { background: linear-gradient(to right in oklab, blue, yellow); }
+            #b { background: linear-gradient(in oklab, blue, yellow); }
+        "#;
+        let result = Stylesheet::parse_with_errors(css);
+        assert!(!result.has_errors());
+
+        for id in ["a", "b"] {
+            match &result.stylesheet.get(id).unwrap().background {
+                Some(Brush::Gradient(g)) => {
+                    assert_eq!(g.interpolation(), GradientInterpolation::Oklab);
+                    assert_eq!(g.stops().len(), 2);
+                }
+                _ => panic!("Expected gradient for #{}", id),
+            }
+        }
+    }
+
     #[test]
     fn test_linear_gradient_to_bottom() {
         let css = r#"#card { background: linear-gradient(to bottom, #fff, #000); }"#;
@@ -0,0 +1,470 @@
+//! Layout debug overlay
+//!
+//! A devtools-style inspector drawn over the UI: an outline per element,
+//! padding (green) and margin (orange) bands, arrows showing the main axis
+//! of flex containers, and a panel describing the element under the pointer.
+//!
+//! The overlay is drawn in its own pass after the tree and is never part of
+//! it, so it is invisible to hit-testing and pointer capture, and enabling it
+//! doesn't change layout.
+//!
+//! ```ignore
+//! tree.set_debug_overlay(true);
+//! tree.set_debug_inspect_target(router.hit_test(&tree, x, y).map(|hit| hit.node));
+//! tree.render_debug_overlay(&mut ctx);
+//! ```
+//!
+//! `blinc_app` does this automatically for `BLINC_DEBUG=inspect`.
+
+use blinc_core::{
+    Brush, Color, CornerRadius, DrawContext, Path, Rect, Stroke, TextBaseline, TextStyle, Transform,
+};
+use taffy::{Dimension, Display, FlexDirection, LengthPercentageAuto, Style};
+
+use crate::element::ElementBounds;
+use crate::renderer::{ElementType, RenderTree};
+use crate::tree::LayoutNodeId;
+
+/// Outline colors, cycled by tree depth
+const OUTLINE_COLORS: [Color; 6] = [
+    Color::rgba(1.0, 0.3, 0.3, 0.9),
+    Color::rgba(0.3, 0.9, 0.3, 0.9),
+    Color::rgba(0.3, 0.5, 1.0, 0.9),
+    Color::rgba(1.0, 0.9, 0.2, 0.9),
+    Color::rgba(0.2, 0.9, 0.9, 0.9),
+    Color::rgba(0.9, 0.3, 0.9, 0.9),
+];
+const PADDING_COLOR: Color = Color::rgba(0.4, 0.8, 0.3, 0.25);
+const MARGIN_COLOR: Color = Color::rgba(1.0, 0.6, 0.2, 0.25);
+const ARROW_COLOR: Color = Color::rgba(1.0, 0.3, 0.6, 0.8);
+const HIGHLIGHT_COLOR: Color = Color::rgba(0.3, 0.6, 1.0, 0.3);
+const PANEL_COLOR: Color = Color::rgba(0.1, 0.1, 0.12, 0.9);
+
+const PANEL_WIDTH: f32 = 280.0;
+const PANEL_LINE_HEIGHT: f32 = 16.0;
+const PANEL_PADDING: f32 = 8.0;
+
+/// Computed layout and style of one element, as shown by the inspector
+#[derive(Clone, Debug)]
+pub struct NodeInspection {
+    pub node: LayoutNodeId,
+    pub element_type: &'static str,
+    /// Border box in logical pixels, relative to the root
+    pub bounds: ElementBounds,
+    /// Resolved padding as `[top, right, bottom, left]`
+    pub padding: [f32; 4],
+    /// Resolved border widths as `[top, right, bottom, left]`
+    pub border: [f32; 4],
+    /// Resolved margin as `[top, right, bottom, left]` (`auto` resolves to 0)
+    pub margin: [f32; 4],
+    /// Number of layout children
+    pub child_count: usize,
+    /// Depth in the tree (root is 0)
+    pub depth: usize,
+    pub style: Style,
+}
+
+impl NodeInspection {
+    /// Human-readable description, one property per line
+    pub fn lines(&self) -> Vec<String> {
+        let b = &self.bounds;
+        let s = &self.style;
+        let mut lines = vec![
+            format!(
+                "{} {:?}  {:.1} × {:.1} at ({:.1}, {:.1})",
+                self.element_type, self.node, b.width, b.height, b.x, b.y
+            ),
+            match s.display {
+                Display::Flex => format!("display: flex {}", direction_name(s.flex_direction)),
+                other => format!("display: {:?}", other).to_lowercase(),
+            },
+            format!(
+                "size: {} × {}  min {} × {}  max {} × {}",
+                dimension(s.size.width),
+                dimension(s.size.height),
+                dimension(s.min_size.width),
+                dimension(s.min_size.height),
+                dimension(s.max_size.width),
+                dimension(s.max_size.height),
+            ),
+            format!("padding: {}", edges(self.padding)),
+            format!("margin: {}", edges(self.margin)),
+        ];
+        if self.border.iter().any(|w| *w > 0.0) {
+            lines.push(format!("border: {}", edges(self.border)));
+        }
+        lines.push(format!(
+            "flex: {} {} {}",
+            s.flex_grow,
+            s.flex_shrink,
+            dimension(s.flex_basis)
+        ));
+        lines
+    }
+}
+
+impl RenderTree {
+    /// Computed layout and style of `node`, or `None` if it isn't in the tree
+    pub fn inspect_node(&self, node: LayoutNodeId) -> Option<NodeInspection> {
+        self.debug_nodes().into_iter().find(|n| n.node == node)
+    }
+
+    /// Draw the layout debug overlay
+    ///
+    /// Draws regardless of [`is_debug_overlay_enabled`](Self::is_debug_overlay_enabled);
+    /// callers check it. Only elements inside the viewport are drawn. Must be
+    /// called with a context separate from (or after) the tree's own render.
+    pub fn render_debug_overlay(&self, ctx: &mut dyn DrawContext) {
+        let scale = self.scale_factor();
+        let viewport = ctx.viewport_size();
+        let visible = ElementBounds::new(0.0, 0.0, viewport.width / scale, viewport.height / scale);
+        let nodes = self.debug_nodes();
+
+        let has_scale = scale != 1.0;
+        if has_scale {
+            ctx.push_transform(Transform::scale(scale, scale));
+        }
+
+        for node in nodes.iter().filter(|n| is_visible(&n.bounds, &visible)) {
+            draw_bands(ctx, &node.bounds, node.margin, false);
+            draw_bands(ctx, &node.bounds, node.padding, true);
+            ctx.stroke_rect(
+                node.bounds.to_rect(),
+                CornerRadius::default(),
+                &Stroke::new(1.0),
+                Brush::Solid(OUTLINE_COLORS[node.depth % OUTLINE_COLORS.len()]),
+            );
+            if node.style.display == Display::Flex && node.child_count > 1 {
+                draw_flex_arrow(ctx, &node.bounds, node.style.flex_direction);
+            }
+        }
+
+        if let Some(target) = self.debug_inspect_target() {
+            if let Some(node) = nodes.iter().find(|n| n.node == target) {
+                ctx.fill_rect(
+                    node.bounds.to_rect(),
+                    CornerRadius::default(),
+                    Brush::Solid(HIGHLIGHT_COLOR),
+                );
+                draw_panel(ctx, &node.lines(), &visible);
+            }
+        }
+
+        if has_scale {
+            ctx.pop_transform();
+        }
+    }
+
+    /// Every node in tree order with absolute bounds
+    fn debug_nodes(&self) -> Vec<NodeInspection> {
+        let mut nodes = Vec::new();
+        if let Some(root) = self.root() {
+            self.collect_debug_nodes(root, (0.0, 0.0), None, 0, &mut nodes);
+        }
+        nodes
+    }
+
+    fn collect_debug_nodes(
+        &self,
+        node: LayoutNodeId,
+        parent_offset: (f32, f32),
+        parent_width: Option<f32>,
+        depth: usize,
+        out: &mut Vec<NodeInspection>,
+    ) {
+        let layout_tree = self.layout();
+        let (Some(layout), Some(style)) =
+            (layout_tree.get_layout(node), layout_tree.get_style(node))
+        else {
+            return;
+        };
+        let bounds = ElementBounds::from_layout(layout, parent_offset);
+        let children = layout_tree.children(node);
+
+        // Percent margins resolve against the containing block's width
+        let basis = parent_width.unwrap_or(0.0);
+        let margin = [
+            resolve_auto(style.margin.top, basis),
+            resolve_auto(style.margin.right, basis),
+            resolve_auto(style.margin.bottom, basis),
+            resolve_auto(style.margin.left, basis),
+        ];
+        let padding = [
+            layout.padding.top,
+            layout.padding.right,
+            layout.padding.bottom,
+            layout.padding.left,
+        ];
+        let border = [
+            layout.border.top,
+            layout.border.right,
+            layout.border.bottom,
+            layout.border.left,
+        ];
+
+        out.push(NodeInspection {
+            node,
+            element_type: self
+                .get_render_node(node)
+                .map(|n| element_type_name(&n.element_type))
+                .unwrap_or("Unknown"),
+            bounds,
+            padding,
+            border,
+            margin,
+            child_count: children.len(),
+            depth,
+            style,
+        });
+
+        // Scroll containers offset their children
+        let (scroll_x, scroll_y) = self.get_scroll_offset(node);
+        let content_width = bounds.width - padding[1] - padding[3] - border[1] - border[3];
+        for child in children {
+            self.collect_debug_nodes(
+                child,
+                (bounds.x + scroll_x, bounds.y + scroll_y),
+                Some(content_width),
+                depth + 1,
+                out,
+            );
+        }
+    }
+}
+
+fn element_type_name(element_type: &ElementType) -> &'static str {
+    match element_type {
+        ElementType::Div => "Div",
+        ElementType::Text(_) => "Text",
+        ElementType::StyledText(_) => "StyledText",
+        ElementType::Svg(_) => "Svg",
+        ElementType::Image(_) => "Image",
+        ElementType::Canvas(_) => "Canvas",
+    }
+}
+
+fn resolve_auto(value: LengthPercentageAuto, basis: f32) -> f32 {
+    match value {
+        LengthPercentageAuto::Length(px) => px,
+        LengthPercentageAuto::Percent(pct) => pct * basis,
+        LengthPercentageAuto::Auto => 0.0,
+    }
+}
+
+fn is_visible(bounds: &ElementBounds, viewport: &ElementBounds) -> bool {
+    bounds.width > 0.0
+        && bounds.height > 0.0
+        && bounds.x < viewport.x + viewport.width
+        && bounds.y < viewport.y + viewport.height
+        && bounds.x + bounds.width > viewport.x
+        && bounds.y + bounds.height > viewport.y
+}
+
+/// Fill the four bands of `edges` inside (padding) or outside (margin) `bounds`
+fn draw_bands(ctx: &mut dyn DrawContext, bounds: &ElementBounds, edges: [f32; 4], inside: bool) {
+    let [top, right, bottom, left] = edges.map(|e| e.max(0.0));
+    if top + right + bottom + left == 0.0 {
+        return;
+    }
+    let (x, y, w, h) = (bounds.x, bounds.y, bounds.width, bounds.height);
+    let bands = if inside {
+        [
+            Rect::new(x, y, w, top),
+            Rect::new(x, y + h - bottom, w, bottom),
+            Rect::new(x, y + top, left, h - top - bottom),
+            Rect::new(x + w - right, y + top, right, h - top - bottom),
+        ]
+    } else {
+        [
+            Rect::new(x - left, y - top, w + left + right, top),
+            Rect::new(x - left, y + h, w + left + right, bottom),
+            Rect::new(x - left, y, left, h),
+            Rect::new(x + w, y, right, h),
+        ]
+    };
+    let color = if inside { PADDING_COLOR } else { MARGIN_COLOR };
+    for band in bands {
+        if band.width() > 0.0 && band.height() > 0.0 {
+            ctx.fill_rect(band, CornerRadius::default(), Brush::Solid(color));
+        }
+    }
+}
+
+/// Arrow through the center of a flex container pointing along its main axis
+fn draw_flex_arrow(ctx: &mut dyn DrawContext, bounds: &ElementBounds, direction: FlexDirection) {
+    let (cx, cy) = (
+        bounds.x + bounds.width / 2.0,
+        bounds.y + bounds.height / 2.0,
+    );
+    let half = match direction {
+        FlexDirection::Row | FlexDirection::RowReverse => bounds.width,
+        FlexDirection::Column | FlexDirection::ColumnReverse => bounds.height,
+    } * 0.3;
+    let head = half.min(8.0);
+    let (dx, dy) = match direction {
+        FlexDirection::Row => (1.0, 0.0),
+        FlexDirection::RowReverse => (-1.0, 0.0),
+        FlexDirection::Column => (0.0, 1.0),
+        FlexDirection::ColumnReverse => (0.0, -1.0),
+    };
+    let (tip_x, tip_y) = (cx + dx * half, cy + dy * half);
+    // The head's barbs go back along the axis and out to either side
+    let path = Path::new()
+        .move_to(cx - dx * half, cy - dy * half)
+        .line_to(tip_x, tip_y)
+        .move_to(tip_x - dx * head - dy * head, tip_y - dy * head - dx * head)
+        .line_to(tip_x, tip_y)
+        .line_to(tip_x - dx * head + dy * head, tip_y - dy * head + dx * head);
+    ctx.stroke_path(&path, &Stroke::new(1.5), Brush::Solid(ARROW_COLOR));
+}
+
+/// Inspector panel in the bottom-right corner of the viewport
+fn draw_panel(ctx: &mut dyn DrawContext, lines: &[String], viewport: &ElementBounds) {
+    let height = lines.len() as f32 * PANEL_LINE_HEIGHT + PANEL_PADDING * 2.0;
+    let x = (viewport.width - PANEL_WIDTH - PANEL_PADDING).max(0.0);
+    let y = (viewport.height - height - PANEL_PADDING).max(0.0);
+    ctx.fill_rect(
+        Rect::new(x, y, PANEL_WIDTH, height),
+        CornerRadius::uniform(4.0),
+        Brush::Solid(PANEL_COLOR),
+    );
+    let style = TextStyle {
+        size: 11.0,
+        color: Color::WHITE,
+        baseline: TextBaseline::Top,
+        ..Default::default()
+    };
+    for (i, line) in lines.iter().enumerate() {
+        ctx.draw_text(
+            line,
+            blinc_core::Point::new(
+                x + PANEL_PADDING,
+                y + PANEL_PADDING + i as f32 * PANEL_LINE_HEIGHT,
+            ),
+            &style,
+        );
+    }
+}
+
+fn direction_name(direction: FlexDirection) -> &'static str {
+    match direction {
+        FlexDirection::Row => "row",
+        FlexDirection::RowReverse => "row-reverse",
+        FlexDirection::Column => "column",
+        FlexDirection::ColumnReverse => "column-reverse",
+    }
+}
+
+fn dimension(value: Dimension) -> String {
+    match value {
+        Dimension::Length(px) => format!("{}", px),
+        Dimension::Percent(pct) => format!("{}%", pct * 100.0),
+        Dimension::Auto => "auto".to_string(),
+    }
+}
+
+fn edges([top, right, bottom, left]: [f32; 4]) -> String {
+    format!("{} {} {} {}", top, right, bottom, left)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::div::div;
+    use crate::event_router::EventRouter;
+    use blinc_core::{DrawCommand, RecordingContext, Size};
+
+    fn sample_tree() -> RenderTree {
+        let ui = div()
+            .w(400.0)
+            .h(300.0)
+            .flex_row()
+            .p(2.0)
+            .child(div().w(100.0).h(50.0).m(1.0))
+            .child(div().w(100.0).h(50.0).flex_col().child(div().h(10.0)));
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(400.0, 300.0);
+        tree
+    }
+
+    fn all_bounds(tree: &RenderTree) -> Vec<(f32, f32, f32, f32)> {
+        tree.debug_nodes()
+            .iter()
+            .map(|n| (n.bounds.x, n.bounds.y, n.bounds.width, n.bounds.height))
+            .collect()
+    }
+
+    #[test]
+    fn test_overlay_draws_per_visible_element() {
+        let mut tree = sample_tree();
+        let layout_before = all_bounds(&tree);
+        let hit_before = EventRouter::new()
+            .hit_test(&tree, 50.0, 30.0)
+            .map(|h| h.node);
+
+        let mut plain = RecordingContext::new(Size::new(400.0, 300.0));
+        tree.render(&mut plain);
+
+        tree.set_debug_overlay(true);
+        assert!(tree.is_debug_overlay_enabled());
+
+        // The tree's own render is unaffected
+        let mut with_overlay = RecordingContext::new(Size::new(400.0, 300.0));
+        tree.render(&mut with_overlay);
+        assert_eq!(plain.commands().len(), with_overlay.commands().len());
+
+        let mut overlay = RecordingContext::new(Size::new(400.0, 300.0));
+        tree.render_debug_overlay(&mut overlay);
+        let outlines = overlay
+            .commands()
+            .iter()
+            .filter(|c| matches!(c, DrawCommand::StrokeRect { .. }))
+            .count();
+        assert_eq!(outlines, 4);
+
+        // Layout and hit-testing are untouched
+        assert_eq!(all_bounds(&tree), layout_before);
+        let hit_after = EventRouter::new()
+            .hit_test(&tree, 50.0, 30.0)
+            .map(|h| h.node);
+        assert_eq!(hit_after, hit_before);
+    }
+
+    #[test]
+    fn test_overlay_skips_offscreen_elements() {
+        let tree = sample_tree();
+        // Only the root and the first child (at x = 8..108) reach into this viewport
+        let mut overlay = RecordingContext::new(Size::new(20.0, 20.0));
+        tree.render_debug_overlay(&mut overlay);
+        let outlines = overlay
+            .commands()
+            .iter()
+            .filter(|c| matches!(c, DrawCommand::StrokeRect { .. }))
+            .count();
+        assert_eq!(outlines, 2);
+    }
+
+    #[test]
+    fn test_inspect_node() {
+        let mut tree = sample_tree();
+        let root = tree.root().unwrap();
+        let first = tree.layout().children(root)[0];
+
+        let info = tree.inspect_node(first).unwrap();
+        assert_eq!(info.element_type, "Div");
+        assert_eq!(info.margin, [4.0; 4]);
+        assert_eq!(info.bounds.x, 12.0);
+        assert_eq!(tree.inspect_node(root).unwrap().padding, [8.0; 4]);
+        assert!(info.lines()[0].starts_with("Div"));
+
+        tree.set_debug_inspect_target(Some(first));
+        let mut overlay = RecordingContext::new(Size::new(400.0, 300.0));
+        tree.render_debug_overlay(&mut overlay);
+        let text_lines = overlay
+            .commands()
+            .iter()
+            .filter(|c| matches!(c, DrawCommand::DrawText { .. }))
+            .count();
+        assert_eq!(text_lines, info.lines().len());
+    }
+}
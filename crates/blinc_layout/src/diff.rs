@@ -766,6 +766,7 @@ fn hash_glass_style(glass: &GlassStyle, hasher: &mut impl Hasher) {
 fn hash_gradient_stop(stop: &GradientStop, hasher: &mut impl Hasher) {
     hash_f32(stop.offset, hasher);
     hash_color(&stop.color, hasher);
+    stop.easing.hash(hasher);
 }
 
 fn hash_gradient(gradient: &Gradient, hasher: &mut impl Hasher) {
@@ -776,6 +777,8 @@ fn hash_gradient(gradient: &Gradient, hasher: &mut impl Hasher) {
             stops,
             space,
             spread,
+            interpolation,
+            dither,
         } => {
             0u8.hash(hasher);
             hash_f32(start.x, hasher);
@@ -788,6 +791,8 @@ fn hash_gradient(gradient: &Gradient, hasher: &mut impl Hasher) {
             }
             std::mem::discriminant(space).hash(hasher);
             std::mem::discriminant(spread).hash(hasher);
+            interpolation.hash(hasher);
+            dither.hash(hasher);
         }
         Gradient::Radial {
             center,
@@ -796,6 +801,8 @@ fn hash_gradient(gradient: &Gradient, hasher: &mut impl Hasher) {
             stops,
             space,
             spread,
+            interpolation,
+            dither,
         } => {
             1u8.hash(hasher);
             hash_f32(center.x, hasher);
@@ -815,12 +822,16 @@ fn hash_gradient(gradient: &Gradient, hasher: &mut impl Hasher) {
             }
             std::mem::discriminant(space).hash(hasher);
             std::mem::discriminant(spread).hash(hasher);
+            interpolation.hash(hasher);
+            dither.hash(hasher);
         }
         Gradient::Conic {
             center,
             start_angle,
             stops,
             space,
+            interpolation,
+            dither,
         } => {
             2u8.hash(hasher);
             hash_f32(center.x, hasher);
@@ -831,6 +842,8 @@ fn hash_gradient(gradient: &Gradient, hasher: &mut impl Hasher) {
                 hash_gradient_stop(stop, hasher);
             }
             std::mem::discriminant(space).hash(hasher);
+            interpolation.hash(hasher);
+            dither.hash(hasher);
         }
     }
 }
@@ -870,6 +883,27 @@ fn hash_brush(brush: &Brush, hasher: &mut impl Hasher) {
                 hash_color(tint, hasher);
             }
         }
+        Brush::Pattern(pattern) => {
+            5u8.hash(hasher);
+            hash_f32(pattern.rect.x(), hasher);
+            hash_f32(pattern.rect.y(), hasher);
+            hash_f32(pattern.rect.width(), hasher);
+            hash_f32(pattern.rect.height(), hasher);
+            for e in pattern.transform.elements {
+                hash_f32(e, hasher);
+            }
+            hash_f32(pattern.opacity, hasher);
+            pattern.tile.len().hash(hasher);
+            for shape in &pattern.tile {
+                let bounds = shape.path.bounds();
+                hash_f32(bounds.x(), hasher);
+                hash_f32(bounds.y(), hasher);
+                hash_f32(bounds.width(), hasher);
+                hash_f32(bounds.height(), hasher);
+                shape.path.commands().len().hash(hasher);
+                hash_brush(&shape.brush, hasher);
+            }
+        }
     }
 }
 
@@ -1192,8 +1226,9 @@ fn brush_eq(a: &Option<Brush>, b: &Option<Brush>) -> bool {
                 && f32_eq(a.opacity, b.opacity)
                 && color_eq(&a.tint, &b.tint)
         }
-        (Some(Brush::Gradient(_)), Some(Brush::Gradient(_))) => {
-            // For gradients, fall back to hash comparison
+        (Some(Brush::Gradient(_)), Some(Brush::Gradient(_)))
+        | (Some(Brush::Pattern(_)), Some(Brush::Pattern(_))) => {
+            // For gradients and patterns, fall back to hash comparison
             let mut ha = DefaultHasher::new();
             let mut hb = DefaultHasher::new();
             hash_brush(a.as_ref().unwrap(), &mut ha);
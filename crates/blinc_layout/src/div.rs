@@ -19,19 +19,141 @@ use std::sync::{
 };
 
 use blinc_core::{
-    BlurQuality, BlurStyle, Brush, Color, CornerRadius, LayerEffect, Shadow, Transform,
+    BlurQuality, BlurStyle, Brush, CachePolicy, Color, CornerRadius, LayerEffect, Shadow,
+    ToneMapping, Transform,
 };
 use blinc_theme::ThemeState;
 use taffy::prelude::*;
 use taffy::Overflow;
 
 use crate::element::{
-    ElementBounds, GlassMaterial, Material, MetallicMaterial, RenderLayer, RenderProps,
-    WoodMaterial,
+    ElementBounds, FocusRing, GlassMaterial, Material, MetallicMaterial, RenderLayer, RenderProps,
+    StickyInsets, WoodMaterial,
 };
 use crate::element_style::ElementStyle;
 use crate::tree::{LayoutNodeId, LayoutTree};
 
+/// Opacity multiplier for a [`Div::disabled`] element
+const DISABLED_OPACITY: f32 = 0.5;
+
+/// Default distance outside the viewport at which deferred content mounts
+pub const DEFAULT_LAZY_OVERSCAN: f32 = 200.0;
+
+/// Content built only while its element is near the viewport
+///
+/// Created by [`Div::defer_until_visible`]. The renderer builds the content
+/// once the element comes within `overscan` pixels of the visible area and
+/// drops it again once the element is more than twice that far away.
+#[derive(Clone)]
+pub struct LazyContent {
+    pub(crate) build: Arc<dyn Fn() -> Div + Send + Sync>,
+    pub(crate) overscan: f32,
+}
+
+impl LazyContent {
+    /// Build the deferred content
+    pub fn build(&self) -> Div {
+        (self.build)()
+    }
+
+    /// Margin around the viewport, in pixels
+    pub fn overscan(&self) -> f32 {
+        self.overscan
+    }
+}
+
+impl std::fmt::Debug for LazyContent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LazyContent")
+            .field("overscan", &self.overscan)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Breakpoints used by [`responsive`] unless others are set: 640, 768,
+/// 1024 and 1280 pixels
+pub const DEFAULT_BREAKPOINTS: [f32; 4] = [640.0, 768.0, 1024.0, 1280.0];
+
+/// Default distance past a breakpoint the width must move before
+/// responsive content is rebuilt
+pub const DEFAULT_BREAKPOINT_HYSTERESIS: f32 = 16.0;
+
+/// Content rebuilt when its element's width crosses a breakpoint
+///
+/// Created by [`Div::responsive`]. The renderer builds the content with the
+/// element's laid out width, and builds it again whenever the width moves
+/// into a different range between breakpoints. A range is only left once
+/// the width is more than `hysteresis` pixels past its edge, so a width
+/// sitting on a breakpoint doesn't flip between layouts.
+#[derive(Clone)]
+pub struct ResponsiveContent {
+    pub(crate) build: Arc<dyn Fn(f32) -> Div + Send + Sync>,
+    pub(crate) breakpoints: Vec<f32>,
+    pub(crate) hysteresis: f32,
+}
+
+impl ResponsiveContent {
+    /// Build the content for `width`
+    pub fn build(&self, width: f32) -> Div {
+        (self.build)(width)
+    }
+
+    /// Breakpoints in ascending order, in pixels
+    pub fn breakpoints(&self) -> &[f32] {
+        &self.breakpoints
+    }
+
+    /// Index of the range `width` falls in: 0 below the first breakpoint,
+    /// `breakpoints().len()` at or above the last
+    pub fn range(&self, width: f32) -> usize {
+        self.breakpoints
+            .iter()
+            .take_while(|&&bp| width >= bp)
+            .count()
+    }
+
+    /// The range for `width` given the range the content was built for
+    ///
+    /// Stays in `current` until `width` is more than the hysteresis past
+    /// one of its edges.
+    pub fn next_range(&self, current: usize, width: f32) -> usize {
+        let lower = current
+            .checked_sub(1)
+            .and_then(|i| self.breakpoints.get(i))
+            .map_or(f32::NEG_INFINITY, |bp| bp - self.hysteresis);
+        let upper = self
+            .breakpoints
+            .get(current)
+            .map_or(f32::INFINITY, |bp| bp + self.hysteresis);
+        if width < lower || width >= upper {
+            self.range(width)
+        } else {
+            current
+        }
+    }
+}
+
+impl std::fmt::Debug for ResponsiveContent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResponsiveContent")
+            .field("breakpoints", &self.breakpoints)
+            .field("hysteresis", &self.hysteresis)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Styles applied while something inside an element is hovered or focused
+///
+/// Set by [`Div::on_hover_within`] and [`Div::on_focus_within`], like CSS
+/// `:hover` and `:focus-within` on a container.
+#[derive(Clone, Debug, Default)]
+pub struct WithinStyles {
+    /// Applied while the element or a descendant is hovered
+    pub hover: Option<ElementStyle>,
+    /// Applied while the element or a descendant has focus
+    pub focus: Option<ElementStyle>,
+}
+
 // ============================================================================
 // ElementRef - Generic reference binding for external access
 // ============================================================================
@@ -385,6 +507,15 @@ pub struct Div {
     pub(crate) layer_effects: Vec<LayerEffect>,
     /// Marks this as a stack layer for z-ordering (increments z_layer for interleaved rendering)
     pub(crate) is_stack_layer: bool,
+    /// Layer cache policy for this element's subtree
+    pub(crate) cache_policy: CachePolicy,
+    pub(crate) tab_index: Option<i32>,
+    pub(crate) focus_ring: Option<FocusRing>,
+    pub(crate) disabled: bool,
+    /// Hosts portal content (see [`Div::portal`])
+    pub(crate) portal: bool,
+    /// Sticky positioning insets (see [`Div::sticky_top`])
+    pub(crate) sticky: Option<StickyInsets>,
     pub(crate) event_handlers: crate::event_handler::EventHandlers,
     /// Element ID for selector API queries
     pub(crate) element_id: Option<String>,
@@ -397,6 +528,14 @@ pub struct Div {
     /// When set, motion containers and layout animations will use this key
     /// as a prefix for auto-generated stable keys.
     pub(crate) stateful_context_key: Option<String>,
+    /// Storage updated with this element's computed bounds after layout
+    pub(crate) layout_bounds: Option<LayoutBoundsStorage>,
+    /// Content mounted only while this element is near the viewport
+    pub(crate) lazy: Option<LazyContent>,
+    /// Content rebuilt when this element's width crosses a breakpoint
+    pub(crate) responsive: Option<ResponsiveContent>,
+    /// Style overrides while a descendant is hovered or focused
+    pub(crate) within: Option<WithinStyles>,
 }
 
 impl Default for Div {
@@ -425,11 +564,21 @@ impl Div {
             pointer_events_none: false,
             layer_effects: Vec::new(),
             is_stack_layer: false,
+            cache_policy: CachePolicy::None,
+            tab_index: None,
+            focus_ring: None,
+            disabled: false,
+            portal: false,
+            sticky: None,
             event_handlers: crate::event_handler::EventHandlers::new(),
             element_id: None,
             layout_animation: None,
             visual_animation: None,
             stateful_context_key: None,
+            layout_bounds: None,
+            lazy: None,
+            responsive: None,
+            within: None,
         }
     }
 
@@ -455,11 +604,21 @@ impl Div {
             pointer_events_none: false,
             layer_effects: Vec::new(),
             is_stack_layer: false,
+            cache_policy: CachePolicy::None,
+            tab_index: None,
+            focus_ring: None,
+            disabled: false,
+            portal: false,
+            sticky: None,
             event_handlers: crate::event_handler::EventHandlers::new(),
             element_id: None,
             layout_animation: None,
             visual_animation: None,
             stateful_context_key: None,
+            layout_bounds: None,
+            lazy: None,
+            responsive: None,
+            within: None,
         }
     }
 
@@ -499,6 +658,21 @@ impl Div {
         self.stateful_context_key.as_deref()
     }
 
+    /// Report this element's computed layout bounds to an `ElementRef`
+    ///
+    /// After each layout pass, `element_ref.get_layout_bounds()` returns the
+    /// bounds of this div in root coordinates. Used by measurement-driven features such as
+    /// shared-element transitions.
+    ///
+    /// ```rust,ignore
+    /// let card_ref = ElementRef::<Div>::new();
+    /// div().w(200.0).h(120.0).bind_bounds(&card_ref)
+    /// ```
+    pub fn bind_bounds<T>(mut self, element_ref: &ElementRef<T>) -> Self {
+        self.layout_bounds = Some(element_ref.layout_bounds_storage());
+        self
+    }
+
     /// Enable layout animation for this element
     ///
     /// # Deprecated
@@ -893,6 +1067,18 @@ impl Div {
         if other.cursor.is_some() {
             self.cursor = other.cursor;
         }
+        if other.portal {
+            self.portal = true;
+        }
+        if other.sticky.is_some() {
+            self.sticky = other.sticky;
+        }
+        if other.tab_index.is_some() {
+            self.tab_index = other.tab_index;
+        }
+        if other.focus_ring.is_some() {
+            self.focus_ring = other.focus_ring;
+        }
 
         // Merge children - if other has children, replace ours
         if !other.children.is_empty() {
@@ -904,6 +1090,11 @@ impl Div {
             self.stateful_context_key = other.stateful_context_key;
         }
 
+        // Merge bound layout storage - take other's if set
+        if other.layout_bounds.is_some() {
+            self.layout_bounds = other.layout_bounds;
+        }
+
         // Merge visual animation config - take other's if set
         if other.visual_animation.is_some() {
             self.visual_animation = other.visual_animation;
@@ -914,6 +1105,27 @@ impl Div {
             self.layout_animation = other.layout_animation;
         }
 
+        // Merge deferred content - take other's if set
+        if other.lazy.is_some() {
+            self.lazy = other.lazy;
+        }
+
+        // Merge responsive content - take other's if set
+        if other.responsive.is_some() {
+            self.responsive = other.responsive;
+        }
+
+        // Merge hover/focus-within styles - take other's if set
+        if let Some(other_within) = other.within {
+            let within = self.within.get_or_insert_with(WithinStyles::default);
+            if other_within.hover.is_some() {
+                within.hover = other_within.hover;
+            }
+            if other_within.focus.is_some() {
+                within.focus = other_within.focus;
+            }
+        }
+
         // Note: event_handlers are NOT merged - they're set on the base element
     }
 
@@ -1150,6 +1362,18 @@ impl Div {
         self
     }
 
+    /// Set flex-basis in pixels (initial main-axis size before growing/shrinking)
+    pub fn flex_basis(mut self, px: f32) -> Self {
+        self.style.flex_basis = Dimension::Length(px);
+        self
+    }
+
+    /// Set flex-basis as a percentage (0-100) of the parent's main axis
+    pub fn flex_basis_pct(mut self, pct: f32) -> Self {
+        self.style.flex_basis = Dimension::Percent(pct / 100.0);
+        self
+    }
+
     /// Allow wrapping
     pub fn flex_wrap(mut self) -> Self {
         self.style.flex_wrap = FlexWrap::Wrap;
@@ -1409,12 +1633,58 @@ impl Div {
         self
     }
 
+    // =========================================================================
+    // Sizing (percentages of the parent)
+    // =========================================================================
+    //
+    // Percentages are given as 0-100. When min and max conflict, min wins
+    // (as in CSS).
+
+    /// Set width as a percentage of the parent's width
+    pub fn w_pct(mut self, pct: f32) -> Self {
+        self.style.size.width = Dimension::Percent(pct / 100.0);
+        self
+    }
+
+    /// Set height as a percentage of the parent's height
+    pub fn h_pct(mut self, pct: f32) -> Self {
+        self.style.size.height = Dimension::Percent(pct / 100.0);
+        self
+    }
+
+    /// Set min-width as a percentage of the parent's width
+    pub fn min_w_pct(mut self, pct: f32) -> Self {
+        self.style.min_size.width = Dimension::Percent(pct / 100.0);
+        self
+    }
+
+    /// Set min-height as a percentage of the parent's height
+    pub fn min_h_pct(mut self, pct: f32) -> Self {
+        self.style.min_size.height = Dimension::Percent(pct / 100.0);
+        self
+    }
+
+    /// Set max-width as a percentage of the parent's width
+    pub fn max_w_pct(mut self, pct: f32) -> Self {
+        self.style.max_size.width = Dimension::Percent(pct / 100.0);
+        self
+    }
+
+    /// Set max-height as a percentage of the parent's height
+    pub fn max_h_pct(mut self, pct: f32) -> Self {
+        self.style.max_size.height = Dimension::Percent(pct / 100.0);
+        self
+    }
+
     // =========================================================================
     // Spacing (4px base unit like Tailwind)
     // =========================================================================
 
     /// Set gap between children (in 4px units)
     /// gap(4) = 16px
+    ///
+    /// Sets both the column gap (`gap_x`) and the row gap (`gap_y`), like
+    /// CSS `gap`. In a flex row the row gap only appears between wrapped lines.
     pub fn gap(mut self, units: f32) -> Self {
         let px = units * 4.0;
         self.style.gap = taffy::Size {
@@ -1433,15 +1703,31 @@ impl Div {
         self
     }
 
-    /// Set column gap (horizontal spacing between items)
-    pub fn gap_x(mut self, units: f32) -> Self {
-        self.style.gap.width = LengthPercentage::Length(units * 4.0);
+    /// Set column gap (horizontal spacing between items, in 4px units)
+    ///
+    /// Like CSS `column-gap`: between items in a flex row, or between
+    /// wrapped columns in a flex column.
+    pub fn gap_x(self, units: f32) -> Self {
+        self.gap_x_px(units * 4.0)
+    }
+
+    /// Set row gap (vertical spacing between items, in 4px units)
+    ///
+    /// Like CSS `row-gap`: between items in a flex column, or between
+    /// wrapped rows in a flex row. A single-line row has no row gap.
+    pub fn gap_y(self, units: f32) -> Self {
+        self.gap_y_px(units * 4.0)
+    }
+
+    /// Set column gap in pixels
+    pub fn gap_x_px(mut self, px: f32) -> Self {
+        self.style.gap.width = LengthPercentage::Length(px);
         self
     }
 
-    /// Set row gap (vertical spacing between items)
-    pub fn gap_y(mut self, units: f32) -> Self {
-        self.style.gap.height = LengthPercentage::Length(units * 4.0);
+    /// Set row gap in pixels
+    pub fn gap_y_px(mut self, px: f32) -> Self {
+        self.style.gap.height = LengthPercentage::Length(px);
         self
     }
 
@@ -2466,6 +2752,21 @@ impl Div {
         self.layer_effect(LayerEffect::glow(color, blur, range, opacity))
     }
 
+    /// Apply bloom: content brighter than `threshold` glows past its edges
+    ///
+    /// ## Parameters
+    /// - `threshold`: Brightness above which pixels glow, typically 0.6-0.9
+    /// - `intensity`: Strength of the added light, typically 0.5-2.0
+    /// - `radius`: Blur radius of the glow in pixels
+    pub fn bloom(self, threshold: f32, intensity: f32, radius: f32) -> Self {
+        self.layer_effect(LayerEffect::bloom(threshold, intensity, radius))
+    }
+
+    /// Apply exposure and tone mapping to compress bright colors
+    pub fn tone_map(self, mapping: ToneMapping, exposure: f32) -> Self {
+        self.layer_effect(LayerEffect::tone_map(mapping, exposure))
+    }
+
     /// Apply grayscale filter
     pub fn grayscale(self) -> Self {
         self.layer_effect(LayerEffect::grayscale())
@@ -2551,6 +2852,191 @@ impl Div {
         self
     }
 
+    /// Let this element take focus, in tree order for Tab navigation
+    ///
+    /// Equivalent to `tab_index(0)`. Focus is managed by
+    /// [`InteractiveContext`](crate::interactive::InteractiveContext).
+    pub fn focusable(self) -> Self {
+        self.tab_index(0)
+    }
+
+    /// Set this element's tab index
+    ///
+    /// Follows HTML semantics: positive indices are visited first in
+    /// ascending order, then `0` in tree order. Negative indices can be
+    /// focused programmatically but are skipped by Tab.
+    pub fn tab_index(mut self, index: i32) -> Self {
+        self.tab_index = Some(index);
+        self
+    }
+
+    /// Draw a ring around this element while it has keyboard focus
+    ///
+    /// The ring only shows when focus arrived via Tab (see
+    /// [`InteractiveContext::is_focus_visible`](crate::interactive::InteractiveContext::is_focus_visible)),
+    /// not after a click. The element also needs to be [`focusable`](Self::focusable).
+    pub fn focus_ring(mut self, color: impl Into<Color>, width: f32) -> Self {
+        self.focus_ring = Some(FocusRing {
+            color: color.into(),
+            width,
+        });
+        self
+    }
+
+    /// Disable this element and everything inside it
+    ///
+    /// A disabled subtree receives no pointer or keyboard events (clicks
+    /// land on this element and go no further), is skipped by Tab, is drawn
+    /// at reduced opacity and shows a not-allowed cursor. Components can
+    /// check [`RenderTree::is_disabled`](crate::renderer::RenderTree::is_disabled)
+    /// to pick up a disabled ancestor.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Restyle this element while it or anything inside it is hovered
+    ///
+    /// The style is built from an empty [`ElementStyle`] and layered over
+    /// the element's own style, like `.card:hover` in CSS applying when
+    /// the pointer is over any of the card's children.
+    ///
+    /// ```ignore
+    /// div()
+    ///     .bg(Color::WHITE)
+    ///     .on_hover_within(|s| s.bg(Color::rgba(0.95, 0.95, 1.0, 1.0)).shadow_md())
+    ///     .child(list_row())
+    /// ```
+    pub fn on_hover_within<F>(mut self, style: F) -> Self
+    where
+        F: FnOnce(ElementStyle) -> ElementStyle,
+    {
+        self.within.get_or_insert_with(WithinStyles::default).hover =
+            Some(style(ElementStyle::new()));
+        self
+    }
+
+    /// Restyle this element while it or anything inside it has focus
+    ///
+    /// Like CSS `:focus-within`: a form group can highlight itself while
+    /// one of its inputs is focused. Applied on top of
+    /// [`on_hover_within`](Self::on_hover_within) when both match. Nested
+    /// containers each track their own descendants.
+    pub fn on_focus_within<F>(mut self, style: F) -> Self
+    where
+        F: FnOnce(ElementStyle) -> ElementStyle,
+    {
+        self.within.get_or_insert_with(WithinStyles::default).focus =
+            Some(style(ElementStyle::new()));
+        self
+    }
+
+    /// Build this element's content only while it's near the viewport
+    ///
+    /// `content` is called when the element scrolls within the overscan
+    /// margin (see [`lazy_overscan`](Self::lazy_overscan)) of the visible
+    /// area, and its result is added after any other children. When the
+    /// element scrolls far away the content is dropped again, and the
+    /// element keeps the size it had so the scroll position doesn't jump.
+    ///
+    /// Give the element a size (or min size) so it takes up space before
+    /// its content is first built. State inside the content is rebuilt
+    /// from scratch on every mount, so anything that must survive should
+    /// be keyed (`stateful_with_key`, `use_shared_state`, ...).
+    ///
+    /// ```ignore
+    /// scroll().child(div().flex_col().children((0..1000).map(|i| {
+    ///     div().h(48.0).defer_until_visible(move || row(i))
+    /// })))
+    /// ```
+    pub fn defer_until_visible<F>(mut self, content: F) -> Self
+    where
+        F: Fn() -> Div + Send + Sync + 'static,
+    {
+        let overscan = self
+            .lazy
+            .as_ref()
+            .map_or(DEFAULT_LAZY_OVERSCAN, |lazy| lazy.overscan);
+        self.lazy = Some(LazyContent {
+            build: Arc::new(content),
+            overscan,
+        });
+        self
+    }
+
+    /// Set how far outside the viewport deferred content is mounted
+    ///
+    /// Only has an effect after [`defer_until_visible`](Self::defer_until_visible).
+    /// Defaults to [`DEFAULT_LAZY_OVERSCAN`].
+    pub fn lazy_overscan(mut self, px: f32) -> Self {
+        if let Some(lazy) = &mut self.lazy {
+            lazy.overscan = px.max(0.0);
+        }
+        self
+    }
+
+    /// Build this element's content from its laid out width
+    ///
+    /// `content` is called with the element's width after layout, and its
+    /// result is added after any other children. It's called again only
+    /// when the width moves across one of the [`breakpoints`](Self::breakpoints)
+    /// (by more than the [`breakpoint_hysteresis`](Self::breakpoint_hysteresis)),
+    /// so branch on the width rather than sizing things from it exactly.
+    ///
+    /// The element's width must come from its parent (`w_full`, `flex_1`,
+    /// a fixed width), not from the content, or the two would feed back
+    /// into each other. See [`responsive`] for a full-width container.
+    ///
+    /// ```ignore
+    /// div().w_full().responsive(|width| {
+    ///     if width >= 1024.0 {
+    ///         div().flex_row().child(sidebar()).child(main_view())
+    ///     } else {
+    ///         div().flex_col().child(drawer()).child(main_view())
+    ///     }
+    /// })
+    /// ```
+    pub fn responsive<F>(mut self, content: F) -> Self
+    where
+        F: Fn(f32) -> Div + Send + Sync + 'static,
+    {
+        let (breakpoints, hysteresis) = match self.responsive.take() {
+            Some(responsive) => (responsive.breakpoints, responsive.hysteresis),
+            None => (DEFAULT_BREAKPOINTS.to_vec(), DEFAULT_BREAKPOINT_HYSTERESIS),
+        };
+        self.responsive = Some(ResponsiveContent {
+            build: Arc::new(content),
+            breakpoints,
+            hysteresis,
+        });
+        self
+    }
+
+    /// Set the widths at which responsive content is rebuilt
+    ///
+    /// Only has an effect after [`responsive`](Self::responsive). Defaults
+    /// to [`DEFAULT_BREAKPOINTS`].
+    pub fn breakpoints(mut self, breakpoints: impl IntoIterator<Item = f32>) -> Self {
+        if let Some(responsive) = &mut self.responsive {
+            let mut breakpoints: Vec<f32> = breakpoints.into_iter().collect();
+            breakpoints.sort_by(|a, b| a.total_cmp(b));
+            responsive.breakpoints = breakpoints;
+        }
+        self
+    }
+
+    /// Set how far past a breakpoint the width must move before responsive
+    /// content is rebuilt
+    ///
+    /// Only has an effect after [`responsive`](Self::responsive). Defaults
+    /// to [`DEFAULT_BREAKPOINT_HYSTERESIS`].
+    pub fn breakpoint_hysteresis(mut self, px: f32) -> Self {
+        if let Some(responsive) = &mut self.responsive {
+            responsive.hysteresis = px.max(0.0);
+        }
+        self
+    }
+
     /// Mark this element as a stack layer for z-ordering
     ///
     /// When set, entering this element increments the z_layer counter,
@@ -2563,6 +3049,33 @@ impl Div {
         self
     }
 
+    /// Cache this element's rendered subtree across frames
+    ///
+    /// The subtree is rasterized into an offscreen texture once, and the
+    /// texture is composited while the subtree is unchanged, so expensive
+    /// static content (detailed SVG panels, heavy canvas drawing) costs a
+    /// single textured quad on idle frames. The cache is dropped when the
+    /// element is resized or anything in the subtree is rebuilt.
+    ///
+    /// Transforms, motion and opacity on this element apply to the composited
+    /// texture and never re-rasterize it (a rotated element composites over
+    /// its rotated bounding box). Text is still drawn live on top. Canvas
+    /// closures inside the subtree only re-run on invalidation, so content
+    /// that changes without a rebuild should call
+    /// `RenderTree::invalidate_layer_cache`.
+    pub fn cache_layer(self) -> Self {
+        self.cache_policy(CachePolicy::Content)
+    }
+
+    /// Set the layer cache policy for this element's subtree
+    ///
+    /// `CachePolicy::Manual` keeps the cache across subtree rebuilds; it is
+    /// only dropped on resize or via `RenderTree::invalidate_layer_cache`.
+    pub fn cache_policy(mut self, policy: CachePolicy) -> Self {
+        self.cache_policy = policy;
+        self
+    }
+
     // =========================================================================
     // Children
     // =========================================================================
@@ -2591,6 +3104,83 @@ impl Div {
         self
     }
 
+    /// Render `content` above the whole tree, escaping ancestor clipping
+    ///
+    /// The content is laid out in a box covering this element, so it can be
+    /// positioned relative to it (e.g. `.absolute().top(height + 4.0)` for a
+    /// popover below). It is painted and hit-tested after the rest of the
+    /// tree, so `overflow_clip` ancestors and later siblings don't cover it.
+    /// Events still bubble through this element and its ancestors.
+    ///
+    /// Ancestor transforms, motion and opacity don't apply to the content;
+    /// it follows this element's layout position and any scrolling.
+    ///
+    /// ```ignore
+    /// div()
+    ///     .overflow_clip()
+    ///     .child(
+    ///         button("Menu").portal(
+    ///             div().absolute().top(36.0).w(180.0).bg(Color::WHITE).child(menu_items()),
+    ///         ),
+    ///     )
+    /// ```
+    pub fn portal(self, content: impl ElementBuilder + 'static) -> Self {
+        let mut host = div()
+            .absolute()
+            .inset(0.0)
+            .pointer_events_none()
+            .stack_layer()
+            .child(content);
+        host.portal = true;
+        self.child(host)
+    }
+
+    /// Stick to `offset` below the top of the nearest scroll container
+    ///
+    /// Like CSS `position: sticky; top: offset`: the element scrolls normally
+    /// until it reaches the offset, stays pinned there while its parent is
+    /// still in view, then scrolls away with the parent's bottom edge. A
+    /// following sticky sibling pushes it out as it arrives, so a flat list
+    /// of headers and rows behaves like sectioned content. Sticky elements
+    /// paint above their siblings.
+    ///
+    /// ```ignore
+    /// scroll().h(400.0).child(div().flex_col().children(sections.iter().map(|section| {
+    ///     div()
+    ///         .flex_col()
+    ///         .child(div().sticky_top(0.0).bg(Color::WHITE).child(text(&section.title)))
+    ///         .children(section.rows.iter().map(row))
+    /// })))
+    /// ```
+    pub fn sticky_top(mut self, offset: f32) -> Self {
+        self.sticky.get_or_insert_with(StickyInsets::default).top = Some(offset);
+        self
+    }
+
+    /// Stick to `offset` above the bottom of the nearest scroll container
+    ///
+    /// See [`sticky_top`](Self::sticky_top).
+    pub fn sticky_bottom(mut self, offset: f32) -> Self {
+        self.sticky.get_or_insert_with(StickyInsets::default).bottom = Some(offset);
+        self
+    }
+
+    /// Stick to `offset` from the left of the nearest scroll container
+    ///
+    /// See [`sticky_top`](Self::sticky_top).
+    pub fn sticky_left(mut self, offset: f32) -> Self {
+        self.sticky.get_or_insert_with(StickyInsets::default).left = Some(offset);
+        self
+    }
+
+    /// Stick to `offset` from the right of the nearest scroll container
+    ///
+    /// See [`sticky_top`](Self::sticky_top).
+    pub fn sticky_right(mut self, offset: f32) -> Self {
+        self.sticky.get_or_insert_with(StickyInsets::default).right = Some(offset);
+        self
+    }
+
     /// Get direct access to the taffy style for advanced configuration
     pub fn style_mut(&mut self) -> &mut Style {
         &mut self.style
@@ -2630,6 +3220,31 @@ impl Div {
         self
     }
 
+    /// Register a long-press handler
+    ///
+    /// Fires once after the pointer is held on this element for
+    /// `duration_ms` without moving. Releasing earlier fires `on_click`
+    /// instead.
+    pub fn on_long_press<F>(mut self, duration_ms: u64, handler: F) -> Self
+    where
+        F: Fn(&crate::event_handler::EventContext) + 'static,
+    {
+        self.event_handlers.on_long_press(duration_ms, handler);
+        self
+    }
+
+    /// Register a handler that repeats every `interval_ms` while held
+    ///
+    /// Useful for stepper buttons: a quick press is a normal click, holding
+    /// keeps firing this handler until release.
+    pub fn on_press_repeat<F>(mut self, interval_ms: u64, handler: F) -> Self
+    where
+        F: Fn(&crate::event_handler::EventContext) + 'static,
+    {
+        self.event_handlers.on_press_repeat(interval_ms, handler);
+        self
+    }
+
     /// Register a mouse down handler
     pub fn on_mouse_down<F>(mut self, handler: F) -> Self
     where
@@ -2785,6 +3400,19 @@ impl Div {
         self
     }
 
+    /// Register a pen/stylus handler
+    ///
+    /// Receives PEN_DOWN, PEN_MOVE and PEN_UP, with pressure and tilt in
+    /// `ctx.pen`. Pen input also arrives as regular pointer events, so
+    /// clicks and drags work without this.
+    pub fn on_pen<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&crate::event_handler::EventContext) + 'static,
+    {
+        self.event_handlers.on_pen(handler);
+        self
+    }
+
     /// Register a handler for a specific event type
     ///
     /// This is the low-level method for registering handlers for any event type.
@@ -2805,6 +3433,49 @@ impl Div {
         self.event_handlers.on(event_type, handler);
         self
     }
+
+    /// Register a handler for typed custom events
+    ///
+    /// Receives payloads passed to `ctx.emit_custom::<T>(..)` by this
+    /// element's handlers or any descendant's. Call `ctx.stop_propagation()`
+    /// to keep the event from ancestors further up.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// struct Submitted { email: String }
+    ///
+    /// div()
+    ///     .on_custom(|event: &Submitted, _ctx| save(&event.email))
+    ///     .child(div().on_click(|ctx| {
+    ///         ctx.emit_custom(Submitted { email: "a@b.c".into() });
+    ///     }))
+    /// ```
+    pub fn on_custom<T, F>(mut self, handler: F) -> Self
+    where
+        T: 'static,
+        F: Fn(&T, &crate::event_handler::EventContext) + 'static,
+    {
+        self.event_handlers.on_custom(handler);
+        self
+    }
+
+    /// Register a capture-phase handler for a specific event type
+    ///
+    /// Capture handlers see pointer down/up and drag events on their way to
+    /// a descendant, before the descendant's own handlers. Call
+    /// `ctx.stop_propagation()` to keep the event from reaching it.
+    pub fn on_event_capture<F>(
+        mut self,
+        event_type: blinc_core::events::EventType,
+        handler: F,
+    ) -> Self
+    where
+        F: Fn(&crate::event_handler::EventContext) + 'static,
+    {
+        self.event_handlers.on_capture(event_type, handler);
+        self
+    }
 }
 
 /// Element type identifier for downcasting
@@ -2891,7 +3562,7 @@ pub enum TextVerticalAlign {
 }
 
 /// Generic font category for fallback when a named font isn't available
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum GenericFont {
     /// Default system UI font
     #[default]
@@ -2986,6 +3657,12 @@ pub struct TextRenderInfo {
     pub v_align: TextVerticalAlign,
     /// Whether to wrap text at container bounds (default: true for text())
     pub wrap: bool,
+    /// Keep leading whitespace on wrapped lines (pre/code text)
+    pub preformatted: bool,
+    /// Apply font ligatures
+    pub ligatures: bool,
+    /// Apply font kerning
+    pub kerning: bool,
     /// Line height multiplier (default: 1.2)
     pub line_height: f32,
     /// Measured width of the text (before any layout constraints)
@@ -3023,6 +3700,10 @@ pub struct StyledTextSpanInfo {
     pub strikethrough: bool,
     /// Optional link URL
     pub link_url: Option<String>,
+    /// Font family override (None = element font)
+    pub font_family: Option<FontFamily>,
+    /// Font size override (None = element size)
+    pub font_size: Option<f32>,
 }
 
 /// Styled text render data (for rich_text element with inline formatting)
@@ -3268,6 +3949,27 @@ pub trait ElementBuilder {
         None
     }
 
+    /// Get content to build only while this element is near the viewport
+    ///
+    /// See [`Div::defer_until_visible`].
+    fn lazy_content(&self) -> Option<&LazyContent> {
+        None
+    }
+
+    /// Get content rebuilt when this element's width crosses a breakpoint
+    ///
+    /// See [`Div::responsive`].
+    fn responsive_content(&self) -> Option<&ResponsiveContent> {
+        None
+    }
+
+    /// Get styles applied while a descendant is hovered or focused
+    ///
+    /// See [`Div::on_hover_within`] and [`Div::on_focus_within`].
+    fn within_styles(&self) -> Option<&WithinStyles> {
+        None
+    }
+
     /// Get the on_ready callback for this motion container
     ///
     /// Motion containers can register a callback that fires once after the
@@ -3331,7 +4033,11 @@ impl ElementBuilder for Div {
             node_id: None,
             shadow: self.shadow,
             transform: self.transform.clone(),
-            opacity: self.opacity,
+            opacity: if self.disabled {
+                self.opacity * DISABLED_OPACITY
+            } else {
+                self.opacity
+            },
             clips_content,
             motion: None,
             motion_stable_id: None,
@@ -3340,8 +4046,18 @@ impl ElementBuilder for Div {
             motion_on_ready_callback: None,
             is_stack_layer: self.is_stack_layer,
             pointer_events_none: self.pointer_events_none,
-            cursor: self.cursor,
+            cursor: if self.disabled {
+                Some(crate::element::CursorStyle::NotAllowed)
+            } else {
+                self.cursor
+            },
             layer_effects: self.layer_effects.clone(),
+            cache_policy: self.cache_policy,
+            tab_index: self.tab_index,
+            focus_ring: self.focus_ring,
+            disabled: self.disabled,
+            portal: self.portal,
+            sticky: self.sticky,
             motion_is_exiting: false,
         }
     }
@@ -3373,6 +4089,22 @@ impl ElementBuilder for Div {
     fn visual_animation_config(&self) -> Option<crate::visual_animation::VisualAnimationConfig> {
         self.visual_animation.clone()
     }
+
+    fn layout_bounds_storage(&self) -> Option<crate::renderer::LayoutBoundsStorage> {
+        self.layout_bounds.clone()
+    }
+
+    fn lazy_content(&self) -> Option<&LazyContent> {
+        self.lazy.as_ref()
+    }
+
+    fn responsive_content(&self) -> Option<&ResponsiveContent> {
+        self.responsive.as_ref()
+    }
+
+    fn within_styles(&self) -> Option<&WithinStyles> {
+        self.within.as_ref()
+    }
 }
 
 /// Convenience function to create a new div
@@ -3380,6 +4112,22 @@ pub fn div() -> Div {
     Div::new()
 }
 
+/// A full-width container whose content is built from its width
+///
+/// Shorthand for `div().w_full().responsive(content)`; see
+/// [`Div::responsive`].
+///
+/// ```ignore
+/// responsive(|width| if width >= 1024.0 { wide_layout() } else { narrow_layout() })
+///     .breakpoints([1024.0])
+/// ```
+pub fn responsive<F>(content: F) -> Div
+where
+    F: Fn(f32) -> Div + Send + Sync + 'static,
+{
+    div().w_full().responsive(content)
+}
+
 // Stack has been moved to stack.rs
 
 #[cfg(test)]
@@ -3498,6 +4246,62 @@ mod tests {
         assert_eq!(third.height, 40.0);
     }
 
+    #[test]
+    fn test_layout_wrapped_row_gaps_per_axis() {
+        // Three 40px items in a 100px row: two fit on the first line
+        let ui = div()
+            .w(100.0)
+            .flex_row()
+            .flex_wrap()
+            .gap_x_px(10.0)
+            .gap_y_px(20.0)
+            .child(div().w(40.0).h(30.0))
+            .child(div().w(40.0).h(30.0))
+            .child(div().w(40.0).h(30.0));
+
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(100.0, 200.0);
+
+        let root = tree.root().unwrap();
+        let children: Vec<_> = tree.layout_tree.children(root);
+        let bounds = |i: usize| {
+            tree.layout_tree
+                .get_bounds(children[i], (0.0, 0.0))
+                .unwrap()
+        };
+
+        // Column gap between items in a row
+        assert_eq!((bounds(0).x, bounds(0).y), (0.0, 0.0));
+        assert_eq!((bounds(1).x, bounds(1).y), (50.0, 0.0));
+        // Row gap between wrapped rows
+        assert_eq!((bounds(2).x, bounds(2).y), (0.0, 50.0));
+        let container = tree.layout_tree.get_bounds(root, (0.0, 0.0)).unwrap();
+        assert_eq!(container.height, 80.0);
+    }
+
+    #[test]
+    fn test_layout_single_row_has_no_row_gap() {
+        let ui = div()
+            .flex_row()
+            .flex_wrap()
+            .gap(5.0)
+            .child(div().w(40.0).h(30.0))
+            .child(div().w(40.0).h(30.0));
+
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(300.0, 200.0);
+
+        let root = tree.root().unwrap();
+        let children: Vec<_> = tree.layout_tree.children(root);
+        let second = tree
+            .layout_tree
+            .get_bounds(children[1], (0.0, 0.0))
+            .unwrap();
+        assert_eq!((second.x, second.y), (60.0, 0.0));
+        let container = tree.layout_tree.get_bounds(root, (0.0, 0.0)).unwrap();
+        assert_eq!(container.height, 30.0);
+    }
+
     #[test]
     fn test_layout_flex_grow() {
         // One fixed child, one growing child
@@ -3530,6 +4334,64 @@ mod tests {
         assert_eq!(growing.width, 150.0);
     }
 
+    #[test]
+    fn test_layout_max_w_caps_growth() {
+        let ui = div()
+            .w(1000.0)
+            .h(100.0)
+            .flex_row()
+            .child(div().flex_grow().max_w(400.0).h(100.0))
+            .child(div().flex_grow().h(100.0));
+
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(1000.0, 100.0);
+
+        let root = tree.root().unwrap();
+        let children: Vec<_> = tree.layout_tree.children(root);
+
+        let capped = tree
+            .layout_tree
+            .get_bounds(children[0], (0.0, 0.0))
+            .unwrap();
+        assert_eq!(capped.width, 400.0);
+
+        // The rest goes to the unconstrained sibling
+        let other = tree
+            .layout_tree
+            .get_bounds(children[1], (0.0, 0.0))
+            .unwrap();
+        assert_eq!(other.x, 400.0);
+        assert_eq!(other.width, 600.0);
+    }
+
+    #[test]
+    fn test_layout_percentages_and_min_over_max() {
+        let ui = div()
+            .w(1000.0)
+            .h(100.0)
+            .flex_row()
+            .child(div().w_pct(25.0).h_pct(50.0))
+            .child(div().flex_basis_pct(10.0).flex_shrink_0().h(10.0))
+            // Conflicting constraints resolve like CSS: min wins
+            .child(div().min_w(300.0).max_w(200.0).h(10.0));
+
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(1000.0, 100.0);
+
+        let root = tree.root().unwrap();
+        let children: Vec<_> = tree.layout_tree.children(root);
+        let bounds = |i: usize| {
+            tree.layout_tree
+                .get_bounds(children[i], (0.0, 0.0))
+                .unwrap()
+        };
+
+        assert_eq!(bounds(0).width, 250.0);
+        assert_eq!(bounds(0).height, 50.0);
+        assert_eq!(bounds(1).width, 100.0);
+        assert_eq!(bounds(2).width, 300.0);
+    }
+
     #[test]
     fn test_layout_padding() {
         // Container with padding
@@ -4,8 +4,8 @@
 //! rendered via the DrawContext API.
 
 use blinc_core::{
-    BlurQuality, Brush, Color, CornerRadius, DynFloat, DynValue, LayerEffect, Rect, Shadow,
-    Transform, ValueContext,
+    BlurQuality, Brush, CachePolicy, Color, CornerRadius, DynFloat, DynValue, LayerEffect, Rect,
+    Shadow, Transform, ValueContext,
 };
 use taffy::Layout;
 
@@ -940,6 +940,26 @@ impl BorderBuilder {
     }
 }
 
+/// Sticky positioning insets (see [`Div::sticky_top`](crate::div::Div::sticky_top))
+///
+/// Each set edge keeps the element at least that far from the same edge of
+/// the nearest scroll container's viewport, as far as its parent allows.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct StickyInsets {
+    pub top: Option<f32>,
+    pub right: Option<f32>,
+    pub bottom: Option<f32>,
+    pub left: Option<f32>,
+}
+
+/// Ring drawn around a focused element (see [`Div::focus_ring`](crate::div::Div::focus_ring))
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FocusRing {
+    pub color: Color,
+    /// Stroke width in pixels
+    pub width: f32,
+}
+
 /// Visual properties for rendering an element
 #[derive(Clone)]
 pub struct RenderProps {
@@ -994,6 +1014,23 @@ pub struct RenderProps {
     /// Layer effects applied to this element (blur, drop shadow, glow, color matrix)
     /// Effects are applied during layer composition when the element is rendered
     pub layer_effects: Vec<LayerEffect>,
+    /// Whether this element's subtree is recorded once and replayed across frames
+    /// (see [`Div::cache_layer`](crate::div::Div::cache_layer))
+    pub cache_policy: CachePolicy,
+    /// Tab index if this element can take focus (see
+    /// [`Div::tab_index`](crate::div::Div::tab_index))
+    pub tab_index: Option<i32>,
+    /// Ring drawn while this element has keyboard focus
+    pub focus_ring: Option<FocusRing>,
+    /// Whether this element's subtree ignores pointer and keyboard input (see
+    /// [`Div::disabled`](crate::div::Div::disabled))
+    pub disabled: bool,
+    /// Whether this element is a portal host (see
+    /// [`Div::portal`](crate::div::Div::portal)): painted and hit-tested after
+    /// the rest of the tree, outside its ancestors' clips
+    pub portal: bool,
+    /// Sticky positioning within the nearest scroll container
+    pub sticky: Option<StickyInsets>,
     /// DEPRECATED: Whether the motion should start exiting
     ///
     /// This field is deprecated. Motion exit is now triggered explicitly via
@@ -1034,6 +1071,12 @@ impl Default for RenderProps {
             cursor: None,
             pointer_events_none: false,
             layer_effects: Vec::new(),
+            cache_policy: CachePolicy::None,
+            tab_index: None,
+            focus_ring: None,
+            disabled: false,
+            portal: false,
+            sticky: None,
             motion_is_exiting: false,
         }
     }
@@ -31,10 +31,12 @@
 //!     });
 //! ```
 
+use std::any::{Any, TypeId};
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 use std::rc::Rc;
 
-use blinc_core::events::{event_types, EventType};
+use blinc_core::events::{event_types, EventType, PenState};
 
 use crate::tree::LayoutNodeId;
 
@@ -44,6 +46,55 @@ use crate::tree::LayoutNodeId;
 /// Uses Rc since UI is single-threaded.
 pub type EventCallback = Rc<dyn Fn(&EventContext)>;
 
+/// Callback for a typed custom event, called with a payload already known to
+/// match its type
+type CustomEventCallback = Rc<dyn Fn(&dyn Any, &EventContext)>;
+
+/// A custom event emitted by a handler, waiting to bubble from its element
+pub(crate) struct PendingCustomEvent {
+    /// Element whose handler emitted the event
+    pub node_id: LayoutNodeId,
+    pub type_id: TypeId,
+    pub payload: Box<dyn Any>,
+}
+
+thread_local! {
+    /// Custom events emitted through `EventContext`, delivered by the render
+    /// tree once the handler that emitted them returns
+    static CUSTOM_EVENTS: RefCell<Vec<PendingCustomEvent>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Take the custom events emitted since the last call
+pub(crate) fn take_custom_events() -> Vec<PendingCustomEvent> {
+    CUSTOM_EVENTS.with(|events| events.take())
+}
+
+/// Phase of a propagating pointer event
+///
+/// Pointer down/up and drag events travel from the root down to the target
+/// (capturing), reach the target, then travel back up to the root (bubbling).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum EventPhase {
+    /// Root to the target's parent - seen by capture handlers
+    Capturing,
+    /// Delivered to the element under the pointer
+    #[default]
+    AtTarget,
+    /// Target's parent back up to the root
+    Bubbling,
+}
+
+/// Whether events of this type propagate through the target's ancestors
+pub fn event_bubbles(event_type: EventType) -> bool {
+    matches!(
+        event_type,
+        event_types::POINTER_DOWN
+            | event_types::POINTER_UP
+            | event_types::DRAG
+            | event_types::DRAG_END
+    )
+}
+
 /// Context passed to event handlers
 #[derive(Clone, Debug)]
 pub struct EventContext {
@@ -85,6 +136,19 @@ pub struct EventContext {
     pub alt: bool,
     /// Whether meta modifier is held (Cmd on macOS, Win on Windows)
     pub meta: bool,
+    /// Consecutive click count for POINTER_DOWN/POINTER_UP (1 = single,
+    /// 2 = double, 3 = triple, ...); 0 for other events
+    pub click_count: u32,
+    /// How long the pointer has been held, in milliseconds (PRESS_HOLD events)
+    pub hold_ms: u64,
+    /// Pressure, tilt and buttons for PEN_DOWN/PEN_MOVE/PEN_UP events
+    pub pen: Option<PenState>,
+    /// Propagation phase this handler is running in
+    pub phase: EventPhase,
+    /// Set by `stop_propagation()`
+    propagation_stopped: Cell<bool>,
+    /// Set by `consume_click()`
+    click_consumed: Cell<bool>,
 }
 
 impl EventContext {
@@ -112,9 +176,114 @@ impl EventContext {
             ctrl: false,
             alt: false,
             meta: false,
+            click_count: 0,
+            hold_ms: 0,
+            pen: None,
+            phase: EventPhase::AtTarget,
+            propagation_stopped: Cell::new(false),
+            click_consumed: Cell::new(false),
         }
     }
 
+    /// Stop the event from reaching any further elements
+    ///
+    /// During capturing this keeps the event from the target and every
+    /// bubbling ancestor; at the target or while bubbling it stops the
+    /// remaining ancestors. Other handlers on the current element still run.
+    pub fn stop_propagation(&self) {
+        self.propagation_stopped.set(true);
+    }
+
+    /// Whether a handler called `stop_propagation()`
+    pub fn is_propagation_stopped(&self) -> bool {
+        self.propagation_stopped.get()
+    }
+
+    /// Mark the current press as handled by a hold gesture
+    ///
+    /// Called from PRESS_HOLD handlers; the release that ends the press then
+    /// doesn't count as a click.
+    pub fn consume_click(&self) {
+        self.click_consumed.set(true);
+    }
+
+    /// Whether a hold gesture already handled this press
+    pub fn is_click_consumed(&self) -> bool {
+        self.click_consumed.get()
+    }
+
+    /// Keep sending pointer moves and the release to this element until the
+    /// button is released, even after the pointer leaves it
+    ///
+    /// Call from a POINTER_DOWN handler to start a drag (slider thumbs,
+    /// resize handles). Local positions stay relative to this element and
+    /// hover state is frozen while captured.
+    pub fn capture_pointer(&self) {
+        crate::event_router::request_pointer_capture(Some(self.node_id));
+    }
+
+    /// End pointer capture before the button is released
+    pub fn release_pointer_capture(&self) {
+        crate::event_router::request_pointer_capture(None);
+    }
+
+    /// Emit a typed event from this element to itself and its ancestors
+    ///
+    /// After the current handler returns, `on_custom::<T>` handlers run on
+    /// this element and then on each ancestor up to the root, until one
+    /// calls `stop_propagation()`.
+    ///
+    /// ```ignore
+    /// div()
+    ///     .on_custom(|event: &Submitted, _| save(&event.email))
+    ///     .child(button("Save").on_click(|ctx| {
+    ///         ctx.emit_custom(Submitted { email: "a@b.c".into() });
+    ///     }))
+    /// ```
+    pub fn emit_custom<T: 'static>(&self, payload: T) {
+        CUSTOM_EVENTS.with(|events| {
+            events.borrow_mut().push(PendingCustomEvent {
+                node_id: self.node_id,
+                type_id: TypeId::of::<T>(),
+                payload: Box::new(payload),
+            })
+        });
+    }
+
+    /// Copy of this context delivered to another node during propagation
+    ///
+    /// Positions and bounds still describe the original target.
+    pub fn with_node(&self, node_id: LayoutNodeId) -> Self {
+        let mut ctx = self.clone();
+        ctx.node_id = node_id;
+        ctx.propagation_stopped = Cell::new(false);
+        ctx
+    }
+
+    /// Set the click count
+    pub fn with_click_count(mut self, count: u32) -> Self {
+        self.click_count = count;
+        self
+    }
+
+    /// Set how long the pointer has been held (for PRESS_HOLD events)
+    pub fn with_hold_ms(mut self, hold_ms: u64) -> Self {
+        self.hold_ms = hold_ms;
+        self
+    }
+
+    /// Set the stylus state (for pen events)
+    pub fn with_pen(mut self, pen: PenState) -> Self {
+        self.pen = Some(pen);
+        self
+    }
+
+    /// Set the propagation phase
+    pub fn with_phase(mut self, phase: EventPhase) -> Self {
+        self.phase = phase;
+        self
+    }
+
     /// Set mouse position
     pub fn with_mouse_pos(mut self, x: f32, y: f32) -> Self {
         self.mouse_x = x;
@@ -190,6 +359,10 @@ impl EventContext {
 pub struct EventHandlers {
     /// Handlers keyed by event type
     handlers: HashMap<EventType, Vec<EventCallback>>,
+    /// Capture-phase handlers keyed by event type
+    capture_handlers: HashMap<EventType, Vec<EventCallback>>,
+    /// Custom event handlers keyed by payload type
+    custom_handlers: HashMap<TypeId, Vec<CustomEventCallback>>,
 }
 
 impl EventHandlers {
@@ -201,6 +374,8 @@ impl EventHandlers {
     /// Check if there are any handlers registered
     pub fn is_empty(&self) -> bool {
         self.handlers.is_empty()
+            && self.capture_handlers.is_empty()
+            && self.custom_handlers.is_empty()
     }
 
     /// Check if a handler is registered for a specific event type
@@ -219,6 +394,48 @@ impl EventHandlers {
             .push(Rc::new(handler));
     }
 
+    /// Register a capture-phase handler for an event type
+    ///
+    /// Capture handlers run on ancestors before the event reaches its target,
+    /// letting a container intercept events meant for its children.
+    pub fn on_capture<F>(&mut self, event_type: EventType, handler: F)
+    where
+        F: Fn(&EventContext) + 'static,
+    {
+        self.capture_handlers
+            .entry(event_type)
+            .or_default()
+            .push(Rc::new(handler));
+    }
+
+    /// Check if a capture-phase handler is registered for an event type
+    pub fn has_capture_handler(&self, event_type: EventType) -> bool {
+        self.capture_handlers.contains_key(&event_type)
+    }
+
+    /// Register a handler for custom events with a `T` payload
+    ///
+    /// Runs for `emit_custom::<T>` calls on this element or any descendant.
+    pub fn on_custom<T, F>(&mut self, handler: F)
+    where
+        T: 'static,
+        F: Fn(&T, &EventContext) + 'static,
+    {
+        self.custom_handlers
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push(Rc::new(move |payload, ctx| {
+                if let Some(payload) = payload.downcast_ref::<T>() {
+                    handler(payload, ctx);
+                }
+            }));
+    }
+
+    /// Check if a custom event handler is registered for a payload type
+    pub fn has_custom_handler(&self, type_id: TypeId) -> bool {
+        self.custom_handlers.contains_key(&type_id)
+    }
+
     /// Get handlers for an event type
     pub fn get(&self, event_type: EventType) -> Option<&[EventCallback]> {
         self.handlers.get(&event_type).map(|v| v.as_slice())
@@ -238,6 +455,24 @@ impl EventHandlers {
         }
     }
 
+    /// Dispatch an event to the capture-phase handlers for that type
+    pub fn dispatch_capture(&self, ctx: &EventContext) {
+        if let Some(handlers) = self.capture_handlers.get(&ctx.event_type) {
+            for handler in handlers {
+                handler(ctx);
+            }
+        }
+    }
+
+    /// Dispatch a custom event payload to the handlers for its type
+    pub fn dispatch_custom(&self, payload: &dyn Any, ctx: &EventContext) {
+        if let Some(handlers) = self.custom_handlers.get(&payload.type_id()) {
+            for handler in handlers {
+                handler(payload, ctx);
+            }
+        }
+    }
+
     /// Merge another set of handlers into this one
     pub fn merge(&mut self, other: EventHandlers) {
         for (event_type, handlers) in other.handlers {
@@ -246,6 +481,18 @@ impl EventHandlers {
                 .or_default()
                 .extend(handlers);
         }
+        for (event_type, handlers) in other.capture_handlers {
+            self.capture_handlers
+                .entry(event_type)
+                .or_default()
+                .extend(handlers);
+        }
+        for (type_id, handlers) in other.custom_handlers {
+            self.custom_handlers
+                .entry(type_id)
+                .or_default()
+                .extend(handlers);
+        }
     }
 
     // =========================================================================
@@ -255,11 +502,61 @@ impl EventHandlers {
     /// Register a click handler (POINTER_DOWN followed by POINTER_UP on same element)
     ///
     /// Note: This registers for POINTER_UP, which fires after press+release.
+    /// Releases ending a press already handled by `on_long_press` or
+    /// `on_press_repeat` are skipped.
     pub fn on_click<F>(&mut self, handler: F)
     where
         F: Fn(&EventContext) + 'static,
     {
-        self.on(event_types::POINTER_UP, handler);
+        self.on(event_types::POINTER_UP, move |ctx| {
+            if !ctx.is_click_consumed() {
+                handler(ctx);
+            }
+        });
+    }
+
+    /// Register a long-press handler
+    ///
+    /// Fires once when the pointer has been held for `duration_ms` without
+    /// moving past the click distance threshold. The release that follows is
+    /// not delivered to click handlers; releasing earlier is a normal click.
+    pub fn on_long_press<F>(&mut self, duration_ms: u64, handler: F)
+    where
+        F: Fn(&EventContext) + 'static,
+    {
+        let fired = Rc::new(Cell::new(false));
+        let reset = Rc::clone(&fired);
+        self.on(event_types::POINTER_DOWN, move |_| reset.set(false));
+        self.on(event_types::PRESS_HOLD, move |ctx| {
+            if !fired.get() && ctx.hold_ms >= duration_ms {
+                fired.set(true);
+                ctx.consume_click();
+                handler(ctx);
+            }
+        });
+    }
+
+    /// Register a press-and-hold repeat handler (e.g. stepper buttons)
+    ///
+    /// Fires every `interval_ms` while the pointer is held without moving.
+    /// A quick press is still a normal click; once the handler has fired,
+    /// the release is not delivered to click handlers.
+    pub fn on_press_repeat<F>(&mut self, interval_ms: u64, handler: F)
+    where
+        F: Fn(&EventContext) + 'static,
+    {
+        let interval_ms = interval_ms.max(1);
+        let fired = Rc::new(Cell::new(0u64));
+        let reset = Rc::clone(&fired);
+        self.on(event_types::POINTER_DOWN, move |_| reset.set(0));
+        self.on(event_types::PRESS_HOLD, move |ctx| {
+            let due = ctx.hold_ms / interval_ms;
+            if due > fired.get() {
+                fired.set(due);
+                ctx.consume_click();
+                handler(ctx);
+            }
+        });
     }
 
     /// Register a mouse down handler
@@ -386,6 +683,25 @@ impl EventHandlers {
     {
         self.on(event_types::DRAG_END, handler);
     }
+
+    /// Register a pen handler
+    ///
+    /// Called for PEN_DOWN, PEN_MOVE and PEN_UP; check `ctx.event_type` for
+    /// the phase and `ctx.pen` for pressure and tilt.
+    pub fn on_pen<F>(&mut self, handler: F)
+    where
+        F: Fn(&EventContext) + 'static,
+    {
+        let handler = Rc::new(handler);
+        for event_type in [
+            event_types::PEN_DOWN,
+            event_types::PEN_MOVE,
+            event_types::PEN_UP,
+        ] {
+            let handler = Rc::clone(&handler);
+            self.on(event_type, move |ctx| handler(ctx));
+        }
+    }
 }
 
 /// Global handler registry for the render tree
@@ -431,6 +747,34 @@ impl HandlerRegistry {
             .unwrap_or(false)
     }
 
+    /// Check if a node has capture-phase handlers for a specific event type
+    pub fn has_capture_handler(&self, node_id: LayoutNodeId, event_type: EventType) -> bool {
+        self.nodes
+            .get(&node_id)
+            .is_some_and(|h| h.has_capture_handler(event_type))
+    }
+
+    /// Dispatch an event to a node's capture-phase handlers
+    pub fn dispatch_capture(&self, ctx: &EventContext) {
+        if let Some(handlers) = self.nodes.get(&ctx.node_id) {
+            handlers.dispatch_capture(ctx);
+        }
+    }
+
+    /// Check if a node has custom event handlers for a payload type
+    pub fn has_custom_handler(&self, node_id: LayoutNodeId, type_id: TypeId) -> bool {
+        self.nodes
+            .get(&node_id)
+            .is_some_and(|h| h.has_custom_handler(type_id))
+    }
+
+    /// Dispatch a custom event payload to a node's handlers for its type
+    pub fn dispatch_custom(&self, payload: &dyn Any, ctx: &EventContext) {
+        if let Some(handlers) = self.nodes.get(&ctx.node_id) {
+            handlers.dispatch_custom(payload, ctx);
+        }
+    }
+
     /// Remove handlers for a node
     pub fn remove(&mut self, node_id: LayoutNodeId) {
         self.nodes.remove(&node_id);
@@ -447,8 +791,18 @@ impl HandlerRegistry {
     /// when the router's focused node ID may be stale. Each handler can check its own
     /// internal focus state to determine if it should process the event.
     pub fn broadcast(&self, event_type: EventType, base_ctx: &EventContext) {
+        self.broadcast_filtered(event_type, base_ctx, |_| true);
+    }
+
+    /// Broadcast an event to the nodes with handlers for it that pass `filter`
+    pub fn broadcast_filtered(
+        &self,
+        event_type: EventType,
+        base_ctx: &EventContext,
+        filter: impl Fn(LayoutNodeId) -> bool,
+    ) {
         for (node_id, handlers) in &self.nodes {
-            if handlers.get(event_type).is_some() {
+            if handlers.get(event_type).is_some() && filter(*node_id) {
                 let ctx = EventContext {
                     event_type,
                     node_id: *node_id,
@@ -33,6 +33,7 @@
 //! router.on_mouse_up(&tree, 100.0, 200.0, MouseButton::Left);
 //! ```
 
+use std::cell::RefCell;
 use std::collections::HashSet;
 
 use blinc_core::events::event_types;
@@ -44,6 +45,17 @@ use crate::tree::LayoutNodeId;
 #[cfg(feature = "recorder")]
 use crate::recorder_bridge::{self, RecorderEventData, RecorderMouseButton};
 
+thread_local! {
+    /// Capture changes requested by handlers through `EventContext`
+    /// (`None` releases), applied before the next pointer event is routed
+    static CAPTURE_REQUESTS: RefCell<Vec<Option<LayoutNodeId>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Queue a pointer capture change for the router (`None` releases)
+pub(crate) fn request_pointer_capture(node: Option<LayoutNodeId>) {
+    CAPTURE_REQUESTS.with(|requests| requests.borrow_mut().push(node));
+}
+
 /// Mouse button identifier (matches platform)
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum MouseButton {
@@ -55,6 +67,50 @@ pub enum MouseButton {
     Other(u16),
 }
 
+/// Time and distance limits for counting consecutive presses as one
+/// multi-click (double-click, triple-click, ...)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClickThreshold {
+    /// Maximum time between presses in milliseconds
+    pub interval_ms: u64,
+    /// Maximum pointer travel between presses in logical pixels
+    pub distance: f32,
+}
+
+impl Default for ClickThreshold {
+    /// Platform defaults (system double-click time and slop)
+    fn default() -> Self {
+        if cfg!(any(target_os = "ios", target_os = "android")) {
+            // Double-tap timeout, with a finger-sized slop
+            Self {
+                interval_ms: 300,
+                distance: 16.0,
+            }
+        } else if cfg!(any(target_os = "macos", target_os = "windows")) {
+            Self {
+                interval_ms: 500,
+                distance: 4.0,
+            }
+        } else {
+            // GTK defaults
+            Self {
+                interval_ms: 400,
+                distance: 5.0,
+            }
+        }
+    }
+}
+
+/// The last press, for click counting
+#[derive(Clone, Copy, Debug)]
+struct LastClick {
+    node: LayoutNodeId,
+    button: MouseButton,
+    x: f32,
+    y: f32,
+    time_ms: u64,
+}
+
 /// Result of a hit test
 #[derive(Clone, Debug)]
 pub struct HitTestResult {
@@ -89,6 +145,8 @@ pub type EventCallback = Box<dyn FnMut(LayoutNodeId, u32)>;
 /// - Focused element (for keyboard events)
 /// - Last scroll delta (for scroll event dispatch)
 /// - Drag state (for drag gesture detection)
+/// - Pointer capture (for drags that leave the element)
+/// - Click count (for double/triple click detection)
 pub struct EventRouter {
     /// Current mouse position
     mouse_x: f32,
@@ -115,6 +173,11 @@ pub struct EventRouter {
     /// Ancestors of pressed target (for event bubbling on release)
     pressed_ancestors: Vec<LayoutNodeId>,
 
+    /// Element receiving all pointer move/up events regardless of hit testing
+    captured: Option<LayoutNodeId>,
+    /// Bounds of the captured element when capture started
+    captured_bounds: Option<(f32, f32, f32, f32)>,
+
     /// Currently focused element (receives keyboard events)
     focused: Option<LayoutNodeId>,
 
@@ -140,6 +203,19 @@ pub struct EventRouter {
     /// Bounds for each ancestor from the last hit test
     /// Maps node_id.to_raw() to (x, y, width, height)
     last_hit_ancestor_bounds: std::collections::HashMap<u32, (f32, f32, f32, f32)>,
+
+    /// Limits for consecutive presses to count as a multi-click
+    click_threshold: ClickThreshold,
+    /// Previous press (for multi-click detection)
+    last_click: Option<LastClick>,
+    /// Click count of the current press sequence (1 = single, 2 = double, ...)
+    click_count: u32,
+
+    /// When the current press started, while it still counts as a hold
+    /// (cleared on release or when the pointer moves too far)
+    hold_start_ms: Option<u64>,
+    /// How long the current press has been held, as of the last tick
+    hold_ms: u64,
 }
 
 impl Default for EventRouter {
@@ -163,6 +239,8 @@ impl EventRouter {
             hovered: HashSet::new(),
             pressed_target: None,
             pressed_ancestors: Vec::new(),
+            captured: None,
+            captured_bounds: None,
             focused: None,
             focused_ancestors: Vec::new(),
             event_callback: None,
@@ -174,6 +252,11 @@ impl EventRouter {
             drag_delta_x: 0.0,
             drag_delta_y: 0.0,
             last_hit_ancestor_bounds: std::collections::HashMap::new(),
+            click_threshold: ClickThreshold::default(),
+            last_click: None,
+            click_count: 0,
+            hold_start_ms: None,
+            hold_ms: 0,
         }
     }
 
@@ -224,6 +307,87 @@ impl EventRouter {
         self.is_dragging
     }
 
+    /// Click count of the latest press sequence
+    ///
+    /// 1 for a single click, 2 for a double click, 3 for a triple click and
+    /// so on. Stays valid through the matching mouse up.
+    pub fn click_count(&self) -> u32 {
+        self.click_count
+    }
+
+    /// Set the time/distance limits for multi-click detection
+    pub fn set_click_threshold(&mut self, threshold: ClickThreshold) {
+        self.click_threshold = threshold;
+    }
+
+    /// Get the time/distance limits for multi-click detection
+    pub fn click_threshold(&self) -> ClickThreshold {
+        self.click_threshold
+    }
+
+    /// How long the current press has been held still, as of the last
+    /// [`on_press_hold`](Self::on_press_hold) tick
+    ///
+    /// None when no press is held or the pointer moved too far.
+    pub fn press_hold_ms(&self) -> Option<u64> {
+        self.hold_start_ms.map(|_| self.hold_ms)
+    }
+
+    /// Tick press-and-hold tracking
+    ///
+    /// Call once per frame while [`press_hold_ms`](Self::press_hold_ms) is
+    /// Some. Emits PRESS_HOLD to the pressed target and its ancestors; dispatch
+    /// them with [`RenderTree::dispatch_press_hold`].
+    pub fn on_press_hold(&mut self, time_ms: u64) -> Vec<(LayoutNodeId, u32)> {
+        let mut events = Vec::new();
+        let (Some(start), Some(target)) = (self.hold_start_ms, self.pressed_target) else {
+            return events;
+        };
+        self.hold_ms = time_ms.saturating_sub(start);
+
+        self.emit_event(target, event_types::PRESS_HOLD);
+        events.push((target, event_types::PRESS_HOLD));
+        let ancestors: Vec<_> = self
+            .pressed_ancestors
+            .iter()
+            .rev()
+            .skip(1)
+            .copied()
+            .collect();
+        for ancestor in ancestors {
+            self.emit_event(ancestor, event_types::PRESS_HOLD);
+            events.push((ancestor, event_types::PRESS_HOLD));
+        }
+        events
+    }
+
+    /// Count a press on `node`, continuing the sequence if it is close
+    /// enough in time and space to the previous press on the same element
+    fn register_click(
+        &mut self,
+        node: LayoutNodeId,
+        button: MouseButton,
+        x: f32,
+        y: f32,
+        time_ms: u64,
+    ) {
+        let threshold = self.click_threshold;
+        let continues = self.last_click.is_some_and(|last| {
+            last.node == node
+                && last.button == button
+                && time_ms.saturating_sub(last.time_ms) <= threshold.interval_ms
+                && (x - last.x).hypot(y - last.y) <= threshold.distance
+        });
+        self.click_count = if continues { self.click_count + 1 } else { 1 };
+        self.last_click = Some(LastClick {
+            node,
+            button,
+            x,
+            y,
+            time_ms,
+        });
+    }
+
     /// Check if a specific node is currently hovered
     pub fn is_hovered(&self, node_id: LayoutNodeId) -> bool {
         self.hovered.contains(&node_id)
@@ -249,6 +413,59 @@ impl EventRouter {
         self.pressed_target
     }
 
+    /// Route all pointer move/up events to `node` until released
+    ///
+    /// While captured, hit testing no longer decides who receives pointer
+    /// moves, so a slider or resizer keeps tracking the pointer after it
+    /// leaves the element. Hover state is frozen for the duration. Capture
+    /// is released automatically on pointer up.
+    ///
+    /// Handlers request capture with `EventContext::capture_pointer`.
+    pub fn capture_pointer(&mut self, node: LayoutNodeId) {
+        self.captured = Some(node);
+        self.captured_bounds = self.get_node_bounds(node);
+    }
+
+    /// Release pointer capture (no-op if nothing is captured)
+    pub fn release_pointer_capture(&mut self) {
+        self.captured = None;
+        self.captured_bounds = None;
+    }
+
+    /// Get the element currently capturing the pointer, if any
+    pub fn pointer_capture(&self) -> Option<LayoutNodeId> {
+        self.captured
+    }
+
+    /// Apply capture changes requested from event handlers
+    ///
+    /// Capture only starts while a button is held, so a request left over
+    /// from a finished press is dropped.
+    fn apply_capture_requests(&mut self) {
+        let requests = CAPTURE_REQUESTS.with(|requests| requests.take());
+        for request in requests {
+            match request {
+                Some(node) if self.pressed_target.is_some() => self.capture_pointer(node),
+                Some(_) => {}
+                None => self.release_pointer_capture(),
+            }
+        }
+    }
+
+    /// Point the last-hit values at the captured element
+    fn update_captured_hit(&mut self, node: LayoutNodeId, x: f32, y: f32) {
+        if let Some((bx, by, bw, bh)) = self.captured_bounds {
+            self.last_hit_local_x = x - bx;
+            self.last_hit_local_y = y - by;
+            self.last_hit_bounds_x = bx;
+            self.last_hit_bounds_y = by;
+            self.last_hit_bounds_width = bw;
+            self.last_hit_bounds_height = bh;
+            self.last_hit_ancestor_bounds
+                .insert(node.to_raw() as u32, (bx, by, bw, bh));
+        }
+    }
+
     /// Set the event callback for routing events to elements
     ///
     /// The callback receives (node_id, event_type) and should dispatch
@@ -393,6 +610,11 @@ impl EventRouter {
         self.mouse_x = x;
         self.mouse_y = y;
 
+        self.apply_capture_requests();
+        if let Some(node) = self.captured {
+            return self.on_captured_mouse_move(node, x, y);
+        }
+
         let mut events = Vec::new();
 
         // Hit test to find elements under pointer (with optional occlusion filtering)
@@ -485,12 +707,44 @@ impl EventRouter {
 
         self.hovered = current_hovered;
 
+        self.emit_drag(x, y, &mut events);
+
+        events
+    }
+
+    /// Mouse move while the pointer is captured
+    ///
+    /// Only the captured element receives POINTER_MOVE (plus DRAG for the
+    /// pressed target); hover state is left untouched.
+    fn on_captured_mouse_move(
+        &mut self,
+        node: LayoutNodeId,
+        x: f32,
+        y: f32,
+    ) -> Vec<(LayoutNodeId, u32)> {
+        let mut events = Vec::new();
+
+        self.update_captured_hit(node, x, y);
+        self.emit_event(node, event_types::POINTER_MOVE);
+        events.push((node, event_types::POINTER_MOVE));
+
+        self.emit_drag(x, y, &mut events);
+        events
+    }
+
+    /// Update drag tracking and emit DRAG to the pressed target and its ancestors
+    fn emit_drag(&mut self, x: f32, y: f32, events: &mut Vec<(LayoutNodeId, u32)>) {
         // Drag detection: if we have a pressed target and moved, emit DRAG
         if let Some(target) = self.pressed_target {
             // Update drag delta
             self.drag_delta_x = x - self.drag_start_x;
             self.drag_delta_y = y - self.drag_start_y;
 
+            // Moving away cancels press-and-hold gestures
+            if self.drag_delta_x.hypot(self.drag_delta_y) > self.click_threshold.distance {
+                self.hold_start_ms = None;
+            }
+
             // Start dragging if we've moved more than a small threshold
             const DRAG_THRESHOLD: f32 = 3.0;
             let delta_exceeds = self.drag_delta_x.abs() > DRAG_THRESHOLD
@@ -536,24 +790,24 @@ impl EventRouter {
                 }
             }
         }
-
-        events
     }
 
     /// Handle mouse button press
     ///
     /// Emits POINTER_DOWN to the topmost hit element AND bubbles through ancestors.
     /// This allows parent elements to receive click events even when clicking on children.
-    /// Also sets focus to the clicked element and initializes drag tracking.
+    /// Also sets focus to the clicked element, initializes drag tracking and
+    /// updates the click count.
     pub fn on_mouse_down(
         &mut self,
         tree: &RenderTree,
         x: f32,
         y: f32,
-        _button: MouseButton,
+        button: MouseButton,
     ) -> Vec<(LayoutNodeId, u32)> {
         self.mouse_x = x;
         self.mouse_y = y;
+        self.apply_capture_requests();
 
         // Initialize drag tracking
         self.drag_start_x = x;
@@ -573,7 +827,11 @@ impl EventRouter {
                 y,
                 hit.ancestors
             );
+            let now = crate::widgets::elapsed_ms();
             self.pressed_target = Some(hit.node);
+            self.register_click(hit.node, button, x, y, now);
+            self.hold_start_ms = Some(now);
+            self.hold_ms = 0;
             // Store ancestors for bubbling on release
             self.pressed_ancestors = hit.ancestors.clone();
             // Store local coordinates and bounds for event handlers
@@ -592,7 +850,7 @@ impl EventRouter {
                 recorder_bridge::record_event(RecorderEventData::MouseDown {
                     x,
                     y,
-                    button: RecorderMouseButton::from(_button),
+                    button: RecorderMouseButton::from(button),
                     target_element: Some(format!("{:?}", hit.node)),
                 });
             }
@@ -613,6 +871,8 @@ impl EventRouter {
         } else {
             // Clicked outside any element - clear focus
             self.set_focus(None);
+            self.last_click = None;
+            self.click_count = 1;
             self.pressed_target = None;
             self.pressed_ancestors.clear();
         }
@@ -625,6 +885,7 @@ impl EventRouter {
     /// Emits POINTER_UP to the element where the press started AND bubbles through ancestors.
     /// If dragging was in progress, also emits DRAG_END.
     /// (ensures proper button release even if cursor moved).
+    /// Releases any pointer capture.
     pub fn on_mouse_up(
         &mut self,
         _tree: &RenderTree,
@@ -634,20 +895,34 @@ impl EventRouter {
     ) -> Vec<(LayoutNodeId, u32)> {
         self.mouse_x = x;
         self.mouse_y = y;
+        self.apply_capture_requests();
 
         let mut events = Vec::new();
+        self.hold_start_ms = None;
 
         // Check if we were dragging
         let was_dragging = self.is_dragging;
 
         tracing::debug!(
-            "on_mouse_up: pressed_target={:?}, was_dragging={}, pos=({:.1}, {:.1})",
+            "on_mouse_up: pressed_target={:?}, captured={:?}, was_dragging={}, pos=({:.1}, {:.1})",
             self.pressed_target,
+            self.captured,
             was_dragging,
             x,
             y
         );
 
+        // Capture always ends with the button release. A capturing element
+        // other than the pressed target gets its own POINTER_UP.
+        if let Some(node) = self.captured {
+            self.update_captured_hit(node, x, y);
+            self.release_pointer_capture();
+            if self.pressed_target != Some(node) {
+                self.emit_event(node, event_types::POINTER_UP);
+                events.push((node, event_types::POINTER_UP));
+            }
+        }
+
         // Release goes to the element where press started
         if let Some(target) = self.pressed_target.take() {
             // If we were dragging, emit DRAG_END before POINTER_UP
@@ -711,6 +986,8 @@ impl EventRouter {
     /// Also emits POINTER_UP to the pressed target if there is one (mouse left while dragging).
     pub fn on_mouse_leave(&mut self) -> Vec<(LayoutNodeId, u32)> {
         let mut events = Vec::new();
+        self.release_pointer_capture();
+        self.hold_start_ms = None;
 
         // If we were pressing/dragging, emit POINTER_UP to clean up state
         // This handles the case where mouse leaves the window while dragging
@@ -986,6 +1263,10 @@ impl EventRouter {
         self.hovered.remove(&node);
         if self.pressed_target == Some(node) {
             self.pressed_target = None;
+            self.hold_start_ms = None;
+        }
+        if self.captured == Some(node) {
+            self.release_pointer_capture();
         }
         if self.focused == Some(node) {
             self.focused = None;
@@ -1059,9 +1340,15 @@ impl EventRouter {
     /// Hit test to find the topmost element at a point
     ///
     /// Returns the hit result for the frontmost (last in child order) element
-    /// that contains the point.
+    /// that contains the point. Portal content is tested first, topmost portal
+    /// first, since it paints above the rest of the tree.
     pub fn hit_test(&self, tree: &RenderTree, x: f32, y: f32) -> Option<HitTestResult> {
         let root = tree.root()?;
+        for (host, _) in tree.portals().into_iter().rev() {
+            if let Some(result) = self.hit_test_portal(tree, host, x, y) {
+                return Some(result);
+            }
+        }
         self.hit_test_node(
             tree,
             root,
@@ -1090,9 +1377,93 @@ impl EventRouter {
                 &mut results,
             );
         }
+        for (host, _) in tree.portals() {
+            let Some((ancestors, ancestor_bounds, child_offset)) = self.portal_path(tree, host)
+            else {
+                continue;
+            };
+            for child in tree.paint_children(host) {
+                self.hit_test_node_all(
+                    tree,
+                    child,
+                    x,
+                    y,
+                    child_offset,
+                    ancestors.clone(),
+                    ancestor_bounds.clone(),
+                    &mut results,
+                );
+            }
+        }
         results
     }
 
+    /// Hit test the content of a portal host
+    ///
+    /// The host's own bounds (and its ancestors') don't limit the hit, matching
+    /// paint, but the result's ancestor chain still runs through them so events
+    /// bubble to the portal's anchor.
+    fn hit_test_portal(
+        &self,
+        tree: &RenderTree,
+        host: LayoutNodeId,
+        x: f32,
+        y: f32,
+    ) -> Option<HitTestResult> {
+        let (ancestors, ancestor_bounds, child_offset) = self.portal_path(tree, host)?;
+        for child in tree.paint_children(host).into_iter().rev() {
+            if let Some(result) = self.hit_test_node(
+                tree,
+                child,
+                x,
+                y,
+                child_offset,
+                ancestors.clone(),
+                ancestor_bounds.clone(),
+            ) {
+                return Some(result);
+            }
+        }
+        None
+    }
+
+    /// Ancestor chain (root to host, inclusive) and bounds for a portal host,
+    /// plus the offset of its children
+    ///
+    /// Returns None if the host isn't laid out or sits in a disabled subtree.
+    #[allow(clippy::type_complexity)]
+    fn portal_path(
+        &self,
+        tree: &RenderTree,
+        host: LayoutNodeId,
+    ) -> Option<(
+        Vec<LayoutNodeId>,
+        std::collections::HashMap<u32, (f32, f32, f32, f32)>,
+        (f32, f32),
+    )> {
+        let mut ancestors = vec![host];
+        while let Some(parent) = tree.layout().parent(*ancestors.last().unwrap()) {
+            ancestors.push(parent);
+        }
+        ancestors.reverse();
+
+        let mut ancestor_bounds = std::collections::HashMap::new();
+        let mut parent_offset = (0.0, 0.0);
+        for &node in &ancestors {
+            if tree.get_render_node(node).is_some_and(|n| n.props.disabled) {
+                return None;
+            }
+            let bounds = tree.paint_bounds(node, parent_offset)?;
+            ancestor_bounds.insert(
+                node.to_raw() as u32,
+                (bounds.x, bounds.y, bounds.width, bounds.height),
+            );
+            let scroll_offset = tree.get_scroll_offset(node);
+            parent_offset = (bounds.x + scroll_offset.0, bounds.y + scroll_offset.1);
+        }
+        Some((ancestors, ancestor_bounds, parent_offset))
+    }
+
     /// Recursive hit test for a single node
     fn hit_test_node(
         &self,
@@ -1104,7 +1475,7 @@ impl EventRouter {
         mut ancestors: Vec<LayoutNodeId>,
         mut ancestor_bounds: std::collections::HashMap<u32, (f32, f32, f32, f32)>,
     ) -> Option<HitTestResult> {
-        let bounds = tree.layout().get_bounds(node, parent_offset)?;
+        let bounds = tree.paint_bounds(node, parent_offset)?;
 
         // Check if point is within bounds
         if !self.point_in_bounds(x, y, &bounds) {
@@ -1136,8 +1507,15 @@ impl EventRouter {
         let scroll_offset = tree.get_scroll_offset(node);
         let child_offset = (bounds.x + scroll_offset.0, bounds.y + scroll_offset.1);
 
+        // A disabled subtree is hit as a whole: its children never become targets
+        let disabled = tree.get_render_node(node).is_some_and(|n| n.props.disabled);
+
         // Check children in reverse order (last child is on top)
-        let children = tree.layout().children(node);
+        let children = if disabled {
+            Vec::new()
+        } else {
+            tree.paint_children(node)
+        };
         tracing::trace!(
             "hit_test_node: node={:?}, bounds=({:.1}, {:.1}, {:.1}x{:.1}), children={:?}",
             node,
@@ -1147,6 +1525,7 @@ impl EventRouter {
             bounds.height,
             children
         );
+        // Portal content is tested separately, ahead of the tree
         for child in children.into_iter().rev() {
             if let Some(result) = self.hit_test_node(
                 tree,
@@ -1206,7 +1585,7 @@ impl EventRouter {
         mut ancestor_bounds: std::collections::HashMap<u32, (f32, f32, f32, f32)>,
         results: &mut Vec<HitTestResult>,
     ) {
-        let Some(bounds) = tree.layout().get_bounds(node, parent_offset) else {
+        let Some(bounds) = tree.paint_bounds(node, parent_offset) else {
             return;
         };
 
@@ -1252,8 +1631,13 @@ impl EventRouter {
         let scroll_offset = tree.get_scroll_offset(node);
         let child_offset = (bounds.x + scroll_offset.0, bounds.y + scroll_offset.1);
 
+        // Children of a disabled node are never hit
+        if tree.get_render_node(node).is_some_and(|n| n.props.disabled) {
+            return;
+        }
+
         // Check children
-        let children = tree.layout().children(node);
+        let children = tree.paint_children(node);
 
         for child in children {
             self.hit_test_node_all(
@@ -1401,7 +1785,7 @@ impl EventRouter {
 mod tests {
     use super::*;
     use crate::prelude::*;
-    use std::cell::RefCell;
+    use std::cell::{Cell, RefCell};
     use std::rc::Rc;
 
     #[test]
@@ -1481,6 +1865,323 @@ mod tests {
         assert!(captured.contains(&event_types::POINTER_UP));
     }
 
+    #[test]
+    fn test_handler_capture_keeps_drag_off_element() {
+        let ui = div().w(400.0).h(300.0).child(
+            div()
+                .w(100.0)
+                .h(100.0)
+                .on_mouse_down(|ctx| ctx.capture_pointer()),
+        );
+
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(400.0, 300.0);
+
+        let events: Rc<RefCell<Vec<(LayoutNodeId, u32)>>> = Rc::new(RefCell::new(Vec::new()));
+        let events_clone = Rc::clone(&events);
+
+        let mut router = EventRouter::new();
+        router.set_event_callback(move |node, event| {
+            events_clone.borrow_mut().push((node, event));
+        });
+
+        // Handlers run after routing, as in the app
+        let handle = router.hit_test(&tree, 50.0, 50.0).unwrap().node;
+        router.on_mouse_down(&tree, 50.0, 50.0, MouseButton::Left);
+        for (node, event) in events.take() {
+            tree.dispatch_event(node, event, 50.0, 50.0);
+        }
+
+        // Dragged well off the handle: it keeps the moves and isn't left
+        router.on_mouse_move(&tree, 300.0, 250.0);
+        assert_eq!(router.pointer_capture(), Some(handle));
+        let moved = events.take();
+        assert!(moved.contains(&(handle, event_types::POINTER_MOVE)));
+        assert!(moved.contains(&(handle, event_types::DRAG)));
+        assert!(!moved.contains(&(handle, event_types::POINTER_LEAVE)));
+        assert_eq!(router.last_hit_local(), (300.0, 250.0));
+
+        router.on_mouse_up(&tree, 300.0, 250.0, MouseButton::Left);
+        assert_eq!(router.pointer_capture(), None);
+
+        // A request with no button held is dropped
+        EventContext::new(event_types::POINTER_UP, handle).capture_pointer();
+        router.on_mouse_move(&tree, 50.0, 50.0);
+        assert_eq!(router.pointer_capture(), None);
+    }
+
+    #[test]
+    fn test_pointer_capture_follows_drag_off_element() {
+        let ui = div().w(400.0).h(300.0).child(div().w(100.0).h(100.0));
+
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(400.0, 300.0);
+
+        let events: Rc<RefCell<Vec<(LayoutNodeId, u32)>>> = Rc::new(RefCell::new(Vec::new()));
+        let events_clone = Rc::clone(&events);
+
+        let mut router = EventRouter::new();
+        router.set_event_callback(move |node, event| {
+            events_clone.borrow_mut().push((node, event));
+        });
+
+        let handle = router.hit_test(&tree, 50.0, 50.0).unwrap().node;
+        router.on_mouse_down(&tree, 50.0, 50.0, MouseButton::Left);
+        router.capture_pointer(handle);
+        events.borrow_mut().clear();
+
+        // Far outside the handle - still delivered, with local coordinates
+        router.on_mouse_move(&tree, 300.0, 250.0);
+        assert!(events
+            .borrow()
+            .contains(&(handle, event_types::POINTER_MOVE)));
+        assert_eq!(router.last_hit_local(), (300.0, 250.0));
+        assert_eq!(
+            router.get_node_bounds(handle),
+            Some((0.0, 0.0, 100.0, 100.0))
+        );
+
+        // Release off the element ends the capture
+        router.on_mouse_up(&tree, 300.0, 250.0, MouseButton::Left);
+        assert!(events.borrow().contains(&(handle, event_types::POINTER_UP)));
+        assert_eq!(router.pointer_capture(), None);
+
+        // Back to hit testing
+        events.borrow_mut().clear();
+        router.on_mouse_move(&tree, 300.0, 250.0);
+        assert!(!events
+            .borrow()
+            .contains(&(handle, event_types::POINTER_MOVE)));
+    }
+
+    #[test]
+    fn test_hover_between_siblings() {
+        let ui = div()
+            .w(400.0)
+            .h(300.0)
+            .flex_row()
+            .child(div().w(100.0).h(100.0))
+            .child(div().w(100.0).h(100.0));
+
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(400.0, 300.0);
+
+        let mut router = EventRouter::new();
+        let first = router.hit_test(&tree, 50.0, 50.0).unwrap().node;
+        let second = router.hit_test(&tree, 150.0, 50.0).unwrap().node;
+
+        router.on_mouse_move(&tree, 50.0, 50.0);
+        let events = router.on_mouse_move(&tree, 150.0, 50.0);
+
+        // The shared parent stays hovered
+        let of_type = |t| {
+            events
+                .iter()
+                .filter(|(_, e)| *e == t)
+                .map(|(n, _)| *n)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(of_type(event_types::POINTER_LEAVE), vec![first]);
+        assert_eq!(of_type(event_types::POINTER_ENTER), vec![second]);
+    }
+
+    #[test]
+    fn test_capture_and_bubble_with_stop_propagation() {
+        let log: Rc<RefCell<Vec<&'static str>>> = Rc::new(RefCell::new(Vec::new()));
+        let logger = |name: &'static str, stop: bool| {
+            let log = Rc::clone(&log);
+            move |ctx: &crate::event_handler::EventContext| {
+                log.borrow_mut().push(name);
+                if stop {
+                    ctx.stop_propagation();
+                }
+            }
+        };
+
+        let ui = div()
+            .w(400.0)
+            .h(300.0)
+            .on_event_capture(event_types::POINTER_DOWN, logger("root capture", false))
+            .on_mouse_down(logger("root", false))
+            .child(
+                div()
+                    .w(200.0)
+                    .h(200.0)
+                    .on_mouse_down(logger("card", true))
+                    .child(
+                        div()
+                            .w(100.0)
+                            .h(100.0)
+                            .on_mouse_down(logger("button", false)),
+                    ),
+            );
+
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(400.0, 300.0);
+
+        let mut router = EventRouter::new();
+        for _ in 0..2 {
+            log.borrow_mut().clear();
+            for (node, event) in router.on_mouse_down(&tree, 50.0, 50.0, MouseButton::Left) {
+                tree.dispatch_event(node, event, 50.0, 50.0);
+            }
+            // The card stops the event before it bubbles to the root; each
+            // press starts a fresh chain
+            assert_eq!(*log.borrow(), ["root capture", "button", "card"]);
+            router.on_mouse_up(&tree, 50.0, 50.0, MouseButton::Left);
+        }
+    }
+
+    #[test]
+    fn test_custom_event_bubbles_to_ancestors() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Submitted {
+            email: String,
+        }
+        struct Cancelled;
+
+        let received: Rc<RefCell<Vec<(&'static str, Submitted)>>> =
+            Rc::new(RefCell::new(Vec::new()));
+        let cancelled = Rc::new(Cell::new(0));
+        let receiver = |name: &'static str| {
+            let received = Rc::clone(&received);
+            move |event: &Submitted, ctx: &crate::event_handler::EventContext| {
+                assert_eq!(ctx.phase, crate::event_handler::EventPhase::Bubbling);
+                received.borrow_mut().push((name, event.clone()));
+            }
+        };
+        let cancelled_clone = Rc::clone(&cancelled);
+
+        let ui = div()
+            .w(400.0)
+            .h(300.0)
+            .on_custom(receiver("root"))
+            .on_custom(move |_: &Cancelled, _| cancelled_clone.set(cancelled_clone.get() + 1))
+            .child(div().w(200.0).h(200.0).on_custom(receiver("form")).child(
+                div().w(100.0).h(100.0).on_click(|ctx| {
+                    ctx.emit_custom(Submitted {
+                        email: "a@b.c".into(),
+                    });
+                }),
+            ));
+
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(400.0, 300.0);
+
+        let mut router = EventRouter::new();
+        router.on_mouse_down(&tree, 50.0, 50.0, MouseButton::Left);
+        for (node, event) in router.on_mouse_up(&tree, 50.0, 50.0, MouseButton::Left) {
+            tree.dispatch_event(node, event, 50.0, 50.0);
+        }
+
+        let submitted = Submitted {
+            email: "a@b.c".into(),
+        };
+        assert_eq!(
+            *received.borrow(),
+            [("form", submitted.clone()), ("root", submitted)]
+        );
+        assert_eq!(cancelled.get(), 0);
+    }
+
+    #[test]
+    fn test_double_click_count() {
+        let ui = div()
+            .w(400.0)
+            .h(300.0)
+            .flex_row()
+            .child(div().w(100.0).h(100.0))
+            .child(div().w(100.0).h(100.0));
+
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(400.0, 300.0);
+
+        let mut router = EventRouter::new();
+        router.set_click_threshold(ClickThreshold {
+            interval_ms: 5_000,
+            distance: 4.0,
+        });
+
+        // Handlers see the count on the click
+        let counts: Rc<RefCell<Vec<u32>>> = Rc::new(RefCell::new(Vec::new()));
+        let counts_clone = Rc::clone(&counts);
+        let mut handlers = crate::event_handler::EventHandlers::new();
+        handlers.on_click(move |ctx| counts_clone.borrow_mut().push(ctx.click_count));
+        let first = router.hit_test(&tree, 50.0, 50.0).unwrap().node;
+        tree.handler_registry_mut().register(first, handlers);
+        for _ in 0..2 {
+            router.on_mouse_down(&tree, 50.0, 50.0, MouseButton::Left);
+            let events = router.on_mouse_up(&tree, 50.0, 50.0, MouseButton::Left);
+            tree.set_click_count(router.click_count());
+            for (node, event) in events {
+                tree.dispatch_event(node, event, 50.0, 50.0);
+            }
+        }
+        assert_eq!(*counts.borrow(), [1, 2]);
+
+        let click = |router: &mut EventRouter, x: f32, y: f32| {
+            router.on_mouse_down(&tree, x, y, MouseButton::Left);
+            router.on_mouse_up(&tree, x, y, MouseButton::Left);
+            router.click_count()
+        };
+        assert_eq!(click(&mut router, 51.0, 50.0), 3);
+
+        // A different element, or too far away on the same one, starts over
+        assert_eq!(click(&mut router, 150.0, 50.0), 1);
+        assert_eq!(click(&mut router, 180.0, 80.0), 1);
+
+        // Too late
+        let node = router.hit_test(&tree, 50.0, 50.0).unwrap().node;
+        router.register_click(node, MouseButton::Left, 50.0, 50.0, 10_000);
+        router.register_click(node, MouseButton::Left, 50.0, 50.0, 16_000);
+        assert_eq!(router.click_count(), 1);
+    }
+
+    #[test]
+    fn test_long_press_fires_once_and_suppresses_click() {
+        let log: Rc<RefCell<Vec<&'static str>>> = Rc::new(RefCell::new(Vec::new()));
+        let (long_log, click_log) = (Rc::clone(&log), Rc::clone(&log));
+        let ui = div().w(400.0).h(300.0).child(
+            div()
+                .w(100.0)
+                .h(100.0)
+                .on_long_press(500, move |_| long_log.borrow_mut().push("long"))
+                .on_click(move |_| click_log.borrow_mut().push("click")),
+        );
+
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(400.0, 300.0);
+        let mut router = EventRouter::new();
+
+        let mut press = |router: &mut EventRouter, ticks: &[u64], moved_to: (f32, f32)| {
+            for (node, event) in router.on_mouse_down(&tree, 50.0, 50.0, MouseButton::Left) {
+                tree.dispatch_event(node, event, 50.0, 50.0);
+            }
+            router.hold_start_ms = Some(1_000);
+            router.on_mouse_move(&tree, moved_to.0, moved_to.1);
+            for &tick in ticks {
+                for (node, _) in router.on_press_hold(1_000 + tick) {
+                    let hold_ms = router.press_hold_ms().unwrap();
+                    tree.dispatch_press_hold(node, hold_ms, 50.0, 50.0);
+                }
+            }
+            for (node, event) in router.on_mouse_up(&tree, 50.0, 50.0, MouseButton::Left) {
+                tree.dispatch_event(node, event, 50.0, 50.0);
+            }
+            std::mem::take(&mut *log.borrow_mut())
+        };
+
+        // Held past the duration: one long press, no click
+        assert_eq!(
+            press(&mut router, &[100, 499, 500, 600, 900], (51.0, 50.0)),
+            ["long"]
+        );
+        // Released early: a normal click
+        assert_eq!(press(&mut router, &[100, 300], (50.0, 50.0)), ["click"]);
+        // Moved too far during the hold: cancelled
+        assert_eq!(press(&mut router, &[600], (80.0, 50.0)), ["click"]);
+    }
+
     #[test]
     fn test_focus_blur() {
         let ui = div()
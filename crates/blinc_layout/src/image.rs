@@ -18,7 +18,7 @@
 
 use std::time::Duration;
 
-use blinc_core::{Brush, Color, Shadow, Transform};
+use blinc_core::{Brush, CachePolicy, Color, Shadow, Transform};
 use taffy::prelude::*;
 
 use crate::div::{ElementBuilder, ElementTypeId, ImageRenderInfo};
@@ -795,6 +795,12 @@ impl ElementBuilder for Image {
             pointer_events_none: false,
             cursor: None,
             layer_effects: Vec::new(),
+            cache_policy: CachePolicy::None,
+            tab_index: None,
+            focus_ring: None,
+            disabled: false,
+            portal: false,
+            sticky: None,
             motion_is_exiting: false,
         }
     }
@@ -827,6 +833,10 @@ impl ElementBuilder for Image {
                     }
                     Brush::Glass(_) => [0.1, 0.1, 0.1, 0.5], // Semi-transparent for glass
                     Brush::Image(_) => [0.0, 0.0, 0.0, 0.0],
+                    Brush::Pattern(p) => {
+                        let c = p.fallback_color();
+                        [c.r, c.g, c.b, c.a]
+                    }
                     Brush::Blur(blur) => {
                         // Use tint color if present, otherwise transparent
                         if let Some(tint) = &blur.tint {
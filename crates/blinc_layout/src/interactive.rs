@@ -4,6 +4,8 @@
 //! - Node state storage (arbitrary typed state per node)
 //! - Dirty tracking for incremental re-renders
 //! - FSM integration for interaction states
+//! - Keyboard focus with tab order and focus-visible tracking
+//! - Scroll-into-view requests for focused or targeted nodes
 //!
 //! # Architecture
 //!
@@ -35,9 +37,10 @@
 use std::any::Any;
 use std::collections::{HashMap, HashSet};
 
-use blinc_core::events::Event;
+use blinc_core::events::{event_types, Event};
 use blinc_core::fsm::{EventId, StateMachine};
 
+use crate::selector::ScrollBlock;
 use crate::tree::LayoutNodeId;
 
 /// Trait for node state types
@@ -146,6 +149,14 @@ pub struct InteractiveContext {
     nodes: HashMap<u64, NodeData>,
     /// Dirty tracker
     dirty: DirtyTracker,
+    /// Focusable nodes and their tab index, in tree order
+    focusables: Vec<(LayoutNodeId, i32)>,
+    /// Node that currently has focus
+    focused: Option<LayoutNodeId>,
+    /// Whether focus arrived via keyboard navigation
+    focus_visible: bool,
+    /// Nodes waiting to be scrolled into view
+    scroll_requests: Vec<(LayoutNodeId, ScrollBlock)>,
 }
 
 impl Default for InteractiveContext {
@@ -160,6 +171,10 @@ impl InteractiveContext {
         Self {
             nodes: HashMap::new(),
             dirty: DirtyTracker::new(),
+            focusables: Vec::new(),
+            focused: None,
+            focus_visible: false,
+            scroll_requests: Vec::new(),
         }
     }
 
@@ -183,8 +198,14 @@ impl InteractiveContext {
     }
 
     /// Unregister a node
+    ///
+    /// If the node had focus, focus moves to its nearest focusable neighbor.
     pub fn unregister(&mut self, id: LayoutNodeId) {
         self.nodes.remove(&Self::key(id));
+        if self.is_focusable(id) {
+            let remaining = self.focusables.iter().copied().filter(|&(n, _)| n != id);
+            self.set_focus_order(remaining.collect::<Vec<_>>());
+        }
     }
 
     /// Check if a node is registered
@@ -309,6 +330,189 @@ impl InteractiveContext {
         self.dirty.clear_all();
     }
 
+    // =========================================================================
+    // Focus
+    // =========================================================================
+
+    /// Replace the focusable nodes, given in tree order with their tab index
+    ///
+    /// Call after each rebuild with
+    /// [`RenderTree::focus_order`](crate::renderer::RenderTree::focus_order).
+    /// If the focused node is gone, focus moves to the next node that was
+    /// after it in tab order, or failing that the one before it.
+    pub fn set_focus_order(&mut self, nodes: impl IntoIterator<Item = (LayoutNodeId, i32)>) {
+        let old_sequence = self.tab_sequence();
+        let old_tree_order: Vec<_> = self.focusables.iter().map(|&(n, _)| n).collect();
+        self.focusables = nodes.into_iter().collect();
+
+        let Some(focused) = self.focused else {
+            return;
+        };
+        if self.is_focusable(focused) {
+            return;
+        }
+        // Nodes skipped by Tab have no tab-order position; use tree order
+        let old_order = if old_sequence.contains(&focused) {
+            old_sequence
+        } else {
+            old_tree_order
+        };
+        let neighbor = old_order
+            .iter()
+            .position(|&n| n == focused)
+            .and_then(|pos| {
+                let after = old_order[pos + 1..].iter();
+                let before = old_order[..pos].iter().rev();
+                after.chain(before).copied().find(|&n| self.is_focusable(n))
+            });
+        self.set_focused(neighbor, self.focus_visible);
+    }
+
+    /// Nodes reachable by Tab, in visiting order
+    ///
+    /// Positive tab indices come first in ascending order, then `0` in tree
+    /// order. Negative tab indices are skipped.
+    pub fn tab_sequence(&self) -> Vec<LayoutNodeId> {
+        let mut sequence: Vec<_> = self
+            .focusables
+            .iter()
+            .filter(|&&(_, index)| index >= 0)
+            .copied()
+            .collect();
+        // Stable sort keeps tree order within equal indices
+        sequence.sort_by_key(|&(_, index)| if index == 0 { i32::MAX } else { index });
+        sequence.into_iter().map(|(n, _)| n).collect()
+    }
+
+    /// Check if a node can take focus
+    pub fn is_focusable(&self, id: LayoutNodeId) -> bool {
+        self.focusables.iter().any(|&(n, _)| n == id)
+    }
+
+    /// Focus a node (pointer or programmatic focus)
+    ///
+    /// The focus ring is not shown. Returns false if the node isn't focusable.
+    pub fn focus(&mut self, id: LayoutNodeId) -> bool {
+        if !self.is_focusable(id) {
+            return false;
+        }
+        self.set_focused(Some(id), false);
+        true
+    }
+
+    /// Move focus to the next node in tab order (Tab), wrapping around
+    pub fn focus_next(&mut self) -> Option<LayoutNodeId> {
+        let sequence = self.tab_sequence();
+        let next = match self
+            .focused
+            .and_then(|f| sequence.iter().position(|&n| n == f))
+        {
+            Some(pos) => sequence.get((pos + 1) % sequence.len()),
+            None => sequence.first(),
+        };
+        self.focus_from_keyboard(next.copied())
+    }
+
+    /// Move focus to the previous node in tab order (Shift+Tab), wrapping around
+    pub fn focus_prev(&mut self) -> Option<LayoutNodeId> {
+        let sequence = self.tab_sequence();
+        let prev = match self
+            .focused
+            .and_then(|f| sequence.iter().position(|&n| n == f))
+        {
+            Some(pos) => sequence.get((pos + sequence.len() - 1) % sequence.len()),
+            None => sequence.last(),
+        };
+        self.focus_from_keyboard(prev.copied())
+    }
+
+    /// Follow focus moved outside keyboard navigation, such as a click
+    /// routed by the [`EventRouter`](crate::event_router::EventRouter)
+    ///
+    /// Nothing changes while `node` already has focus, so a ring shown after
+    /// Tab stays visible. Focus on a non-focusable node clears focus.
+    pub fn sync_focus(&mut self, node: Option<LayoutNodeId>) {
+        if node == self.focused {
+            return;
+        }
+        match node {
+            Some(node) if self.focus(node) => {}
+            _ => self.blur(),
+        }
+    }
+
+    /// Clear focus
+    pub fn blur(&mut self) {
+        self.set_focused(None, false);
+    }
+
+    /// Get the focused node
+    pub fn focused(&self) -> Option<LayoutNodeId> {
+        self.focused
+    }
+
+    /// Check if a node has focus
+    pub fn is_focused(&self, id: LayoutNodeId) -> bool {
+        self.focused == Some(id)
+    }
+
+    /// Check if a node should draw its focus ring
+    ///
+    /// True only while the node has focus that arrived via keyboard
+    /// navigation. Components opt in by checking this when rendering.
+    pub fn is_focus_visible(&self, id: LayoutNodeId) -> bool {
+        self.focus_visible && self.is_focused(id)
+    }
+
+    fn focus_from_keyboard(&mut self, id: Option<LayoutNodeId>) -> Option<LayoutNodeId> {
+        if let Some(id) = id {
+            self.set_focused(Some(id), true);
+            self.scroll_into_view(id, ScrollBlock::Nearest);
+        }
+        id
+    }
+
+    /// Move focus, sending BLUR/FOCUS to the nodes' FSMs and marking them dirty
+    fn set_focused(&mut self, id: Option<LayoutNodeId>, visible: bool) {
+        let previous = self.focused;
+        let visibility_changed = self.focus_visible != visible;
+        self.focused = id;
+        self.focus_visible = visible;
+
+        if previous == id {
+            if let (Some(id), true) = (id, visibility_changed) {
+                self.dirty.mark(id);
+            }
+            return;
+        }
+        if let Some(previous) = previous {
+            self.send_event(previous, event_types::BLUR);
+            self.dirty.mark(previous);
+        }
+        if let Some(id) = id {
+            self.send_event(id, event_types::FOCUS);
+            self.dirty.mark(id);
+        }
+    }
+
+    // =========================================================================
+    // Scroll Into View
+    // =========================================================================
+
+    /// Request that enclosing scroll containers animate to reveal a node
+    ///
+    /// Applied by [`RenderTree::process_scroll_into_view`](crate::renderer::RenderTree::process_scroll_into_view).
+    /// Keyboard focus changes request [`ScrollBlock::Nearest`] automatically.
+    pub fn scroll_into_view(&mut self, id: LayoutNodeId, alignment: ScrollBlock) {
+        self.scroll_requests.retain(|&(n, _)| n != id);
+        self.scroll_requests.push((id, alignment));
+    }
+
+    /// Take pending scroll-into-view requests
+    pub fn take_scroll_requests(&mut self) -> Vec<(LayoutNodeId, ScrollBlock)> {
+        std::mem::take(&mut self.scroll_requests)
+    }
+
     /// Get the dirty tracker (immutable)
     pub fn dirty_tracker(&self) -> &DirtyTracker {
         &self.dirty
@@ -440,4 +644,47 @@ mod tests {
         assert_eq!(state.scale, 0.95);
         assert!(state.clicked);
     }
+
+    #[test]
+    fn test_tab_order_and_focus_visible() {
+        use crate::div::div;
+        use crate::renderer::RenderTree;
+
+        let ui = div()
+            .child(div().w(10.0).h(10.0).focusable())
+            .child(div().w(10.0).h(10.0).tab_index(2))
+            .child(div().w(10.0).h(10.0).tab_index(1));
+        let tree = RenderTree::from_element(&ui);
+        let root = tree.root().unwrap();
+        let children = tree.layout().children(root);
+        let (plain, second, first) = (children[0], children[1], children[2]);
+
+        let mut ctx = InteractiveContext::new();
+        ctx.set_focus_order(tree.focus_order());
+
+        // Pointer focus doesn't show the ring
+        assert!(ctx.focus(plain));
+        assert!(ctx.is_focused(plain));
+        assert!(!ctx.is_focus_visible(plain));
+
+        // Tab visits positive indices ascending, then tree order
+        assert_eq!(ctx.focus_next(), Some(first));
+        assert!(ctx.is_focus_visible(first));
+        assert_eq!(ctx.focus_next(), Some(second));
+        assert_eq!(ctx.focus_next(), Some(plain));
+        assert!(ctx.is_focus_visible(plain));
+        assert_eq!(ctx.focus_next(), Some(first));
+        assert_eq!(ctx.focus_prev(), Some(plain));
+
+        // Removing the focused node moves focus to its neighbor
+        ctx.focus_prev();
+        assert!(ctx.is_focused(second));
+        ctx.unregister(second);
+        assert_eq!(ctx.focused(), Some(plain));
+        assert!(ctx.is_focus_visible(plain));
+        ctx.unregister(plain);
+        assert_eq!(ctx.focused(), Some(first));
+        ctx.unregister(first);
+        assert_eq!(ctx.focused(), None);
+    }
 }
@@ -0,0 +1,118 @@
+//! Layer caching for expensive static subtrees
+//!
+//! Elements marked with [`Div::cache_layer()`](crate::div::Div::cache_layer)
+//! draw their content and children into a cached layer
+//! ([`LayerConfig::cached`](blinc_core::LayerConfig::cached)) once. The GPU
+//! renderer rasterizes it to an offscreen texture, and later frames composite
+//! that texture with [`DrawContext::sample_layer`](blinc_core::DrawContext::sample_layer)
+//! instead of walking the subtree again (canvas closures, SVG tessellation,
+//! border logic, ...) or drawing its primitives.
+//!
+//! The node's own position, transforms, motion and opacity layer are applied
+//! to the composite, so animating them doesn't re-rasterize the content.
+//!
+//! Subtrees that use layer effects (blur, drop shadow, glow, ...) are not
+//! cached, since effects aren't applied inside cached layers.
+//!
+//! The subtree is drawn again when the context no longer holds the layer
+//! ([`DrawContext::has_cached_layer`](blinc_core::DrawContext::has_cached_layer)),
+//! or when the cache entry is dropped because:
+//! - the cached node's size changes
+//! - the z-layer it starts at changes (siblings before it added stack layers)
+//! - any node in the subtree is rebuilt or has its render props updated
+//!   (skipped for [`CachePolicy::Manual`])
+//! - [`RenderTree::invalidate_layer_cache`](crate::renderer::RenderTree::invalidate_layer_cache)
+//!   is called for a node in the subtree
+
+use std::collections::{HashMap, HashSet};
+
+use blinc_core::CachePolicy;
+
+use crate::element::RenderLayer;
+use crate::tree::LayoutNodeId;
+
+/// What one cached node's layer was drawn from, for one render pass
+#[derive(Debug)]
+struct CachedLayer {
+    policy: CachePolicy,
+    /// Node size when drawn
+    size: (f32, f32),
+    /// Z-layer the layer started at
+    base_z: u32,
+    /// Every node in the subtree (including the cached node itself)
+    nodes: HashSet<LayoutNodeId>,
+}
+
+/// Per-tree record of which cached layers are still valid
+#[derive(Debug, Default)]
+pub(crate) struct LayerCache {
+    layers: HashMap<(LayoutNodeId, RenderLayer), CachedLayer>,
+    /// Cached nodes whose subtree can't be cached (already warned about)
+    uncacheable: HashSet<LayoutNodeId>,
+}
+
+impl LayerCache {
+    /// Whether a node's cached layer is still valid for this size and z-layer
+    pub(crate) fn is_valid(
+        &self,
+        node: LayoutNodeId,
+        pass: RenderLayer,
+        size: (f32, f32),
+        base_z: u32,
+    ) -> bool {
+        self.layers
+            .get(&(node, pass))
+            .is_some_and(|layer| layer.size == size && layer.base_z == base_z)
+    }
+
+    /// Record a freshly drawn cached layer
+    pub(crate) fn insert(
+        &mut self,
+        node: LayoutNodeId,
+        pass: RenderLayer,
+        policy: CachePolicy,
+        size: (f32, f32),
+        base_z: u32,
+        nodes: HashSet<LayoutNodeId>,
+    ) {
+        self.layers.insert(
+            (node, pass),
+            CachedLayer {
+                policy,
+                size,
+                base_z,
+                nodes,
+            },
+        );
+    }
+
+    /// Drop layers containing `node` because its content changed
+    ///
+    /// Layers with [`CachePolicy::Manual`] are kept.
+    pub(crate) fn invalidate_content(&mut self, node: LayoutNodeId) {
+        self.layers
+            .retain(|_, layer| layer.policy == CachePolicy::Manual || !layer.nodes.contains(&node));
+    }
+
+    /// Drop every layer containing `node`, regardless of policy
+    pub(crate) fn invalidate(&mut self, node: LayoutNodeId) {
+        self.layers.retain(|_, layer| !layer.nodes.contains(&node));
+    }
+
+    /// Note that a node's subtree can't be cached
+    ///
+    /// Returns true the first time for each node.
+    pub(crate) fn mark_uncacheable(&mut self, node: LayoutNodeId) -> bool {
+        self.uncacheable.insert(node)
+    }
+
+    /// Drop all layers
+    pub(crate) fn clear(&mut self) {
+        self.layers.clear();
+    }
+
+    /// Number of layers currently valid
+    pub(crate) fn len(&self) -> usize {
+        self.layers.len()
+    }
+}
@@ -30,6 +30,7 @@
 
 pub mod animated;
 pub mod canvas;
+pub mod debug_overlay;
 pub mod diff;
 pub mod div;
 pub mod element;
@@ -41,6 +42,7 @@ pub mod event_handler;
 pub mod event_router;
 pub mod image;
 pub mod interactive;
+mod layer_cache;
 pub mod layout_animation;
 pub mod motion;
 pub mod render_state;
@@ -56,6 +58,7 @@ pub mod syntax;
 pub mod text;
 pub mod text_measure;
 pub mod text_selection;
+pub mod text_transition;
 pub mod tree;
 pub mod typography;
 pub mod units;
@@ -86,8 +89,8 @@ pub use key::{reset_call_counters, InstanceKey};
 
 // Core types
 pub use element::{
-    BorderBuilder, BorderSide, BorderSides, CursorStyle, DynRenderProps, ElementBounds,
-    MotionAnimation, MotionKeyframe, RenderLayer, RenderProps, ResolvedRenderProps,
+    BorderBuilder, BorderSide, BorderSides, CursorStyle, DynRenderProps, ElementBounds, FocusRing,
+    MotionAnimation, MotionKeyframe, RenderLayer, RenderProps, ResolvedRenderProps, StickyInsets,
 };
 
 // Diff and reconciliation
@@ -95,8 +98,8 @@ pub use diff::{
     diff, diff_children, diff_elements, reconcile, ChangeCategory, ChildDiff, DiffResult, DivHash,
     ReconcileActions,
 };
-pub use event_handler::{EventCallback, EventContext, EventHandlers, HandlerRegistry};
-pub use event_router::{EventRouter, HitTestResult, MouseButton};
+pub use event_handler::{EventCallback, EventContext, EventHandlers, EventPhase, HandlerRegistry};
+pub use event_router::{ClickThreshold, EventRouter, HitTestResult, MouseButton};
 pub use interactive::{DirtyTracker, InteractiveContext, NodeState};
 pub use style::LayoutStyle;
 pub use tree::{LayoutNodeId, LayoutTree, TextMeasureContext};
@@ -108,8 +111,10 @@ pub use element::{
 
 // Builder API
 pub use div::{
-    div, Div, ElementBuilder, ElementTypeId, FontFamily, FontWeight, GenericFont, ImageRenderInfo,
-    StyledTextRenderInfo, StyledTextSpanInfo, TextAlign, TextVerticalAlign,
+    div, responsive, Div, ElementBuilder, ElementTypeId, FontFamily, FontWeight, GenericFont,
+    ImageRenderInfo, LazyContent, ResponsiveContent, StyledTextRenderInfo, StyledTextSpanInfo,
+    TextAlign, TextVerticalAlign, WithinStyles, DEFAULT_BREAKPOINTS, DEFAULT_BREAKPOINT_HYSTERESIS,
+    DEFAULT_LAZY_OVERSCAN,
 };
 // Stack container (overlayed children)
 pub use stack::{stack, Stack};
@@ -126,11 +131,17 @@ pub use text::{text, Text};
 // Renderer
 pub use renderer::{
     GlassPanel, ImageData, LayoutRenderer, OnReadyCallback, OnReadyEntry, RenderTree,
-    RenderTreeDebugStats, StyledTextData, StyledTextSpan, SvgData, TextData, UpdateResult,
+    RenderTreeDebugStats, StyledTextData, StyledTextSegment, StyledTextSpan, SvgData, TextData,
+    UpdateResult,
 };
 
+// Layout debug overlay
+pub use debug_overlay::NodeInspection;
+
 // Canvas element
-pub use canvas::{canvas, Canvas, CanvasBounds, CanvasData, CanvasRenderFn};
+pub use canvas::{
+    canvas, canvas_animated, Canvas, CanvasBounds, CanvasData, CanvasFrameInfo, CanvasRenderFn,
+};
 
 // Render state (dynamic properties separate from tree structure)
 pub use render_state::{
@@ -143,10 +154,11 @@ pub use render_state::{
 // Stateful elements
 pub use stateful::{
     check_stateful_animations, check_stateful_deps, has_animating_statefuls,
-    has_pending_subtree_rebuilds, peek_needs_redraw, queue_prop_update, queue_subtree_rebuild,
-    request_redraw, take_needs_redraw, take_pending_prop_updates, take_pending_subtree_rebuilds,
-    use_shared_state, use_shared_state_with, PendingSubtreeRebuild, SharedState, StateTransitions,
-    StatefulInner,
+    has_pending_subtree_rebuilds, peek_needs_redraw, queue_prop_update,
+    queue_scoped_subtree_rebuild, queue_subtree_rebuild, request_redraw, take_needs_redraw,
+    take_pending_prop_updates, take_pending_subtree_rebuilds, use_shared_state,
+    use_shared_state_with, PendingSubtreeRebuild, SharedState, StateTransitions, StatefulInner,
+    VisualTransitions,
 };
 
 // Animation integration
@@ -162,20 +174,22 @@ pub use units::{pct, px, sp, Length, Unit};
 pub use motion::{
     check_and_clear_exiting, check_ready_for_enter, current_motion_key, is_inside_animating_motion,
     is_inside_motion, motion, motion_derived, motion_events, motion_presence_store,
-    query_presence_state, start_exit_for_key, update_presence_state, ElementAnimation,
-    ExitingChild, Motion, MotionBindings, MotionPresenceState, MotionPresenceStore,
-    SharedAnimatedValue, SlideDirection, StaggerConfig, StaggerDirection,
+    query_presence_state, shared_transition, start_exit_for_key, update_presence_state,
+    ElementAnimation, ExitingChild, Motion, MotionBindings, MotionPresenceState,
+    MotionPresenceStore, SharedAnimatedValue, SharedTransition, SlideDirection, StaggerConfig,
+    StaggerDirection,
 };
 
 // Text measurement
 pub use text_measure::{
-    measure_text, measure_text_with_options, set_text_measurer, TextLayoutOptions, TextMeasurer,
-    TextMetrics,
+    invalidate_text_measure_cache, measure_text, measure_text_with_options, set_text_measurer,
+    TextLayoutOptions, TextMeasureCache, TextMeasurer, TextMetrics,
 };
 
 // Text selection (clipboard support)
 pub use text_selection::{
-    clear_selection, get_selected_text, global_selection, set_selection, SelectionSource,
+    clear_selection, clipboard_get_text, clipboard_set_text, get_selected_text, global_selection,
+    set_clipboard_provider, set_selection, ClipboardProvider, MemoryClipboard, SelectionSource,
     SharedTextSelection, TextSelection,
 };
 
@@ -193,9 +207,11 @@ pub mod prelude {
         CursorStyle, DynRenderProps, ElementBounds, RenderLayer, RenderProps, ResolvedRenderProps,
     };
     // Event handlers
-    pub use crate::event_handler::{EventCallback, EventContext, EventHandlers, HandlerRegistry};
+    pub use crate::event_handler::{
+        EventCallback, EventContext, EventHandlers, EventPhase, HandlerRegistry,
+    };
     // Event routing
-    pub use crate::event_router::{EventRouter, HitTestResult, MouseButton};
+    pub use crate::event_router::{ClickThreshold, EventRouter, HitTestResult, MouseButton};
     // Image element
     pub use crate::image::{
         emoji, emoji_sized, image, img, Image, ImageFilter, LoadingStrategy, ObjectFit,
@@ -253,6 +269,7 @@ pub mod prelude {
         TextFieldState,
         Toggle,
         ToggleState,
+        VisualTransitions,
     };
 
     // Ready-to-use widgets (production-ready, work in fluent API without .build())
@@ -276,12 +293,14 @@ pub mod prelude {
         text_input_state_with_placeholder,
         Button,
         ButtonConfig,
+        ButtonRepeat,
         ButtonVisualState,
         Checkbox,
         CheckboxConfig,
         CheckboxState,
         InputConstraints,
         InputType,
+        NumberConstraints,
         SharedCheckboxState,
         SharedTextAreaState,
         SharedTextInputState,
@@ -309,8 +328,8 @@ pub mod prelude {
     pub use crate::text::{text, Text};
     pub use crate::tree::{LayoutNodeId, LayoutTree};
     pub use crate::widgets::{
-        scroll, scroll_no_bounce, Scroll, ScrollConfig, ScrollDirection, ScrollPhysics,
-        ScrollRenderInfo, SharedScrollPhysics,
+        scroll, scroll_no_bounce, OverscrollBehavior, Scroll, ScrollConfig, ScrollDirection,
+        ScrollPhysics, ScrollRenderInfo, SharedScrollPhysics,
     };
 
     // Code block widget with syntax highlighting
@@ -326,7 +345,7 @@ pub mod prelude {
     };
 
     // Canvas element
-    pub use crate::canvas::{canvas, Canvas, CanvasBounds};
+    pub use crate::canvas::{canvas, canvas_animated, Canvas, CanvasBounds, CanvasFrameInfo};
 
     // Notch element (shapes with concave curves or sharp steps)
     pub use crate::notch::{notch, CornerConfig, CornerStyle, CornersConfig, Notch};
@@ -349,13 +368,15 @@ pub mod prelude {
     // Motion container for entry/exit animations
     pub use crate::motion::{
         current_motion_key, is_inside_animating_motion, is_inside_motion, motion, motion_derived,
-        ElementAnimation, Motion, MotionBindings, SlideDirection, StaggerConfig, StaggerDirection,
+        shared_transition, ElementAnimation, Motion, MotionBindings, SharedTransition,
+        SlideDirection, StaggerConfig, StaggerDirection,
     };
 
     // Text selection for clipboard support
     pub use crate::text_selection::{
-        clear_selection, get_selected_text, global_selection, set_selection, SelectionSource,
-        SharedTextSelection, TextSelection,
+        clear_selection, clipboard_get_text, clipboard_set_text, get_selected_text,
+        global_selection, set_selection, ClipboardProvider, SelectionSource, SharedTextSelection,
+        TextSelection,
     };
 
     // Render state (dynamic properties separate from tree structure)
@@ -369,7 +390,7 @@ pub mod prelude {
     // Typography helpers (h1-h6, b, span, etc.)
     pub use crate::typography::{
         b, caption, chained_text, h1, h2, h3, h4, h5, h6, heading, inline_code, label, muted, p,
-        small, span, strong,
+        small, span, strong, text_with_inline, InlineRun,
     };
 
     // Table elements
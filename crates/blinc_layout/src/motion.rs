@@ -119,9 +119,13 @@ pub fn is_inside_animating_motion() -> bool {
 pub fn is_inside_motion() -> bool {
     MOTION_CONTEXT_STACK.with(|stack| !stack.borrow().is_empty())
 }
+use crate::div::ElementRef;
 use crate::tree::{LayoutNodeId, LayoutTree};
-use blinc_animation::{AnimatedValue, AnimationPreset, MultiKeyframeAnimation};
-use blinc_core::Transform;
+use blinc_animation::{
+    AnimatedRect, AnimatedValue, AnimationPreset, MultiKeyframeAnimation, SchedulerHandle,
+    SpringConfig,
+};
+use blinc_core::{Rect, Transform};
 use taffy::{Display, FlexDirection, Style};
 
 /// Animation configuration for element lifecycle
@@ -276,6 +280,8 @@ pub struct MotionBindings {
     pub rotation_timeline: Option<TimelineRotation>,
     /// Animated opacity
     pub opacity: Option<SharedAnimatedValue>,
+    /// Shared-element transition, applied on top of the other bindings
+    pub shared: Option<SharedTransition>,
 }
 
 impl MotionBindings {
@@ -289,6 +295,7 @@ impl MotionBindings {
             && self.rotation.is_none()
             && self.rotation_timeline.is_none()
             && self.opacity.is_none()
+            && self.shared.is_none()
     }
 
     /// Get the current translation from animated values
@@ -306,6 +313,10 @@ impl MotionBindings {
             .as_ref()
             .map(|v| v.lock().unwrap().get())
             .unwrap_or(0.0);
+        let (tx, ty) = match self.shared.as_ref().and_then(|s| s.offset()) {
+            Some((dx, dy, _, _)) => (tx + dx, ty + dy),
+            None => (tx, ty),
+        };
 
         if tx.abs() > 0.001 || ty.abs() > 0.001 {
             Some(Transform::translate(tx, ty))
@@ -323,12 +334,19 @@ impl MotionBindings {
         let scale_x = self.scale_x.as_ref().map(|v| v.lock().unwrap().get());
         let scale_y = self.scale_y.as_ref().map(|v| v.lock().unwrap().get());
 
-        if let Some(s) = scale {
+        let bound = if let Some(s) = scale {
             Some((s, s))
         } else if scale_x.is_some() || scale_y.is_some() {
             Some((scale_x.unwrap_or(1.0), scale_y.unwrap_or(1.0)))
         } else {
             None
+        };
+        match self.shared.as_ref().and_then(|s| s.offset()) {
+            Some((_, _, sx, sy)) => {
+                let (x, y) = bound.unwrap_or((1.0, 1.0));
+                Some((x * sx, y * sy))
+            }
+            None => bound,
         }
     }
 
@@ -349,7 +367,11 @@ impl MotionBindings {
 
     /// Get the current opacity from animated value
     pub fn get_opacity(&self) -> Option<f32> {
-        self.opacity.as_ref().map(|v| v.lock().unwrap().get())
+        let opacity = self.opacity.as_ref().map(|v| v.lock().unwrap().get());
+        match &self.shared {
+            Some(shared) => Some(opacity.unwrap_or(1.0) * shared.current_opacity()),
+            None => opacity,
+        }
     }
 }
 
@@ -433,6 +455,8 @@ pub struct Motion {
     rotation_timeline: Option<TimelineRotation>,
     /// Animated opacity
     opacity: Option<SharedAnimatedValue>,
+    /// Shared-element transition driving translate, scale and opacity
+    shared: Option<SharedTransition>,
     /// DEPRECATED: Whether the overlay was closing when this motion was constructed
     ///
     /// This field is deprecated and always false. Motion exit is now triggered
@@ -522,6 +546,7 @@ pub fn motion() -> Motion {
         rotation: None,
         rotation_timeline: None,
         opacity: None,
+        shared: None,
         // Motion exit is now triggered explicitly via MotionHandle.exit()
         // The is_exiting field is deprecated and always false
         is_exiting: false,
@@ -580,6 +605,7 @@ pub fn motion_derived(parent_key: &str) -> Motion {
         rotation: None,
         rotation_timeline: None,
         opacity: None,
+        shared: None,
         // Motion exit is now triggered explicitly via MotionHandle.exit()
         // The is_exiting field is deprecated and always false
         is_exiting: false,
@@ -912,6 +938,7 @@ impl Motion {
             || self.rotation.is_some()
             || self.rotation_timeline.is_some()
             || self.opacity.is_some()
+            || self.shared.is_some()
     }
 
     /// Get the motion bindings for this element
@@ -932,6 +959,7 @@ impl Motion {
             rotation: self.rotation.clone(),
             rotation_timeline: self.rotation_timeline.clone(),
             opacity: self.opacity.clone(),
+            shared: self.shared.clone(),
         })
     }
 
@@ -1261,6 +1289,193 @@ impl Motion {
 
 // ElementBuilder impl moved after MotionPresence section to keep it together
 
+// =============================================================================
+// Shared-Element Transitions (FLIP)
+// =============================================================================
+
+/// A shared-element transition that morphs one element into another
+///
+/// Uses the FLIP technique: the *first* bounds are captured from the source
+/// element when the transition is created, the *last* bounds are read from the
+/// target element once it has been laid out, and the target is then *inverted*
+/// (translated and scaled so it sits exactly over the source) and *played*
+/// back to its natural layout. The rendered bounds animate as a single
+/// [`AnimatedRect`], so position and size stay in step.
+///
+/// Bind the transition onto the motion wrapping the target with
+/// [`Motion::shared_transition`], and bind the target's bounds with
+/// `Div::bind_bounds`. The motion should be sized to its child, since scale
+/// is applied around the motion's center.
+///
+/// If the source has no laid-out bounds (it was never mounted, or is already
+/// gone) the transition falls back to a plain fade-in.
+///
+/// # Example
+///
+/// ```ignore
+/// let thumb_ref = ElementRef::<Div>::new();
+/// let hero_ref = ElementRef::<Div>::new();
+///
+/// // When navigating: capture the thumbnail's bounds before it unmounts
+/// let transition = shared_transition(&thumb_ref, &hero_ref);
+///
+/// // Build the detail view with the hero bound to the transition
+/// motion()
+///     .shared_transition(&transition)
+///     .on_ready({
+///         let transition = transition.clone();
+///         move |_| { transition.start(); }
+///     })
+///     .child(div().w(400.0).h(300.0).bind_bounds(&hero_ref))
+/// ```
+#[derive(Clone)]
+pub struct SharedTransition {
+    /// Bounds of the source element captured when the transition was created
+    from_bounds: Option<ElementBounds>,
+    /// Layout bounds of the target element (populated after layout)
+    to_bounds: crate::renderer::LayoutBoundsStorage,
+    /// Rendered bounds of the target, created by `start()`
+    bounds: Arc<std::sync::Mutex<Option<AnimatedRect>>>,
+    /// Opacity (animates only in the fade-in fallback)
+    opacity: SharedAnimatedValue,
+    handle: SchedulerHandle,
+    config: SpringConfig,
+}
+
+/// Create a shared-element transition from `from_ref` to `to_ref`
+///
+/// The source bounds are captured immediately. Uses the global animation
+/// scheduler and a gentle spring.
+pub fn shared_transition<A, B>(
+    from_ref: &ElementRef<A>,
+    to_ref: &ElementRef<B>,
+) -> SharedTransition {
+    SharedTransition::new(
+        blinc_animation::get_scheduler(),
+        from_ref,
+        to_ref,
+        SpringConfig::gentle(),
+    )
+}
+
+impl SharedTransition {
+    /// Create a shared-element transition with an explicit scheduler and spring
+    pub fn new<A, B>(
+        handle: SchedulerHandle,
+        from_ref: &ElementRef<A>,
+        to_ref: &ElementRef<B>,
+        config: SpringConfig,
+    ) -> Self {
+        Self {
+            from_bounds: from_ref.get_layout_bounds(),
+            to_bounds: to_ref.layout_bounds_storage(),
+            bounds: Arc::new(std::sync::Mutex::new(None)),
+            opacity: Arc::new(std::sync::Mutex::new(AnimatedValue::new(
+                handle.clone(),
+                1.0,
+                config,
+            ))),
+            handle,
+            config,
+        }
+    }
+
+    /// Whether the source element had bounds when the transition was created
+    ///
+    /// When `false`, `start()` performs a fade-in instead of a morph.
+    pub fn has_source(&self) -> bool {
+        self.from_bounds.is_some()
+    }
+
+    /// Start the transition
+    ///
+    /// Call once the target has been laid out (e.g. from `Motion::on_ready`).
+    /// Returns `false` if the target has no bounds yet, in which case nothing
+    /// is animated.
+    pub fn start(&self) -> bool {
+        let Some(to) = *self.to_bounds.lock().unwrap() else {
+            return false;
+        };
+        let to = Rect::new(to.x, to.y, to.width, to.height);
+
+        let mut bounds = AnimatedRect::new(self.handle.clone(), to, self.config);
+        let mut opacity = self.opacity.lock().unwrap();
+        match self.from_bounds {
+            // Invert onto the source's bounds, then play back to the target's
+            Some(from) => {
+                bounds.animate(Rect::new(from.x, from.y, from.width, from.height), to);
+                opacity.set_immediate(1.0);
+            }
+            // Source is gone: fall back to a plain fade-in in place
+            None => {
+                opacity.set_immediate(0.0);
+                opacity.set_target(1.0);
+            }
+        }
+        *self.bounds.lock().unwrap() = Some(bounds);
+        true
+    }
+
+    /// Whether any part of the transition is still animating
+    pub fn is_animating(&self) -> bool {
+        let bounds = self.bounds.lock().unwrap();
+        bounds.as_ref().is_some_and(|b| b.is_animating())
+            || self.opacity.lock().unwrap().is_animating()
+    }
+
+    /// The bounds at which the target is currently rendered
+    ///
+    /// Returns `None` if the target has not been laid out.
+    pub fn current_bounds(&self) -> Option<ElementBounds> {
+        let to = (*self.to_bounds.lock().unwrap())?;
+        Some(match self.bounds.lock().unwrap().as_ref() {
+            Some(bounds) => {
+                let rect = bounds.get();
+                ElementBounds::new(rect.x(), rect.y(), rect.width(), rect.height())
+            }
+            None => to,
+        })
+    }
+
+    /// Current opacity of the target (animates only in the fade-in fallback)
+    pub fn current_opacity(&self) -> f32 {
+        self.opacity.lock().unwrap().get()
+    }
+
+    /// Translation and scale that move the target's layout bounds onto its
+    /// current bounds, as `(dx, dy, sx, sy)`
+    ///
+    /// Scale is around the center. `None` before `start()` or layout.
+    fn offset(&self) -> Option<(f32, f32, f32, f32)> {
+        let to = (*self.to_bounds.lock().unwrap())?;
+        let current = self.bounds.lock().unwrap().as_ref()?.get();
+        let center = current.center();
+        let dx = center.x - (to.x + to.width / 2.0);
+        let dy = center.y - (to.y + to.height / 2.0);
+        let sx = if to.width > 0.0 {
+            current.width() / to.width
+        } else {
+            1.0
+        };
+        let sy = if to.height > 0.0 {
+            current.height() / to.height
+        } else {
+            1.0
+        };
+        Some((dx, dy, sx, sy))
+    }
+}
+
+impl Motion {
+    /// Drive this motion's translate, scale and opacity from a shared transition
+    ///
+    /// See [`SharedTransition`] for how to wire the source and target refs.
+    pub fn shared_transition(mut self, transition: &SharedTransition) -> Self {
+        self.shared = Some(transition.clone());
+        self
+    }
+}
+
 // =============================================================================
 // MotionPresence - State Machine for Enter/Exit Lifecycle
 // =============================================================================
@@ -1670,3 +1885,221 @@ mod tests {
         assert_eq!(config.delay_for_index(9, 10), 150); // still capped
     }
 }
+
+#[cfg(test)]
+mod tests_shared_transition {
+    use super::*;
+    use crate::div::{div, Div};
+    use crate::renderer::RenderTree;
+    use blinc_animation::AnimationScheduler;
+
+    #[test]
+    fn test_shared_transition_starts_at_source_bounds() {
+        let from_ref = ElementRef::<Div>::new();
+        let to_ref = ElementRef::<Div>::new();
+
+        let ui = div()
+            .flex_row()
+            .child(div().w(40.0).h(40.0).bind_bounds(&from_ref))
+            .child(div().w(120.0).h(80.0).bind_bounds(&to_ref));
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(400.0, 200.0);
+
+        let scheduler = AnimationScheduler::new();
+        let transition = SharedTransition::new(
+            scheduler.handle(),
+            &from_ref,
+            &to_ref,
+            SpringConfig::gentle(),
+        );
+        assert!(transition.has_source());
+        assert!(transition.start());
+
+        let from = from_ref.get_layout_bounds().unwrap();
+        let rendered = transition.current_bounds().unwrap();
+        assert!((rendered.x - from.x).abs() < 0.5);
+        assert!((rendered.y - from.y).abs() < 0.5);
+        assert!((rendered.width - from.width).abs() < 0.5);
+        assert!((rendered.height - from.height).abs() < 0.5);
+        assert!(transition.is_animating());
+    }
+
+    #[test]
+    fn test_shared_transition_missing_source_fades_in() {
+        let from_ref = ElementRef::<Div>::new();
+        let to_ref = ElementRef::<Div>::new();
+
+        let ui = div().child(div().w(120.0).h(80.0).bind_bounds(&to_ref));
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(400.0, 200.0);
+
+        let scheduler = AnimationScheduler::new();
+        let transition = SharedTransition::new(
+            scheduler.handle(),
+            &from_ref,
+            &to_ref,
+            SpringConfig::gentle(),
+        );
+        assert!(!transition.has_source());
+        assert!(transition.start());
+
+        let to = to_ref.get_layout_bounds().unwrap();
+        let rendered = transition.current_bounds().unwrap();
+        assert!((rendered.x - to.x).abs() < 0.01);
+        assert!((rendered.width - to.width).abs() < 0.01);
+        assert!(transition.current_opacity() < 0.01);
+    }
+
+    #[test]
+    fn test_shared_transition_bounds_move_as_one() {
+        let from_ref = ElementRef::<Div>::new();
+        let to_ref = ElementRef::<Div>::new();
+
+        let ui = div()
+            .flex_row()
+            .child(div().w(40.0).h(40.0).bind_bounds(&from_ref))
+            .child(div().w(120.0).h(80.0).bind_bounds(&to_ref));
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(400.0, 200.0);
+
+        let scheduler = AnimationScheduler::new();
+        let transition = SharedTransition::new(
+            scheduler.handle(),
+            &from_ref,
+            &to_ref,
+            SpringConfig::gentle(),
+        );
+        let bindings = motion()
+            .shared_transition(&transition)
+            .get_motion_bindings()
+            .unwrap();
+        // Untouched until started
+        assert!(bindings.get_scale().is_none());
+
+        assert!(transition.start());
+        for _ in 0..5 {
+            scheduler.tick_with_dt(1.0 / 60.0);
+        }
+        assert!(transition.is_animating());
+
+        // Every edge is the same fraction of the way to the target
+        let from = from_ref.get_layout_bounds().unwrap();
+        let to = to_ref.get_layout_bounds().unwrap();
+        let rendered = transition.current_bounds().unwrap();
+        let t = (rendered.x - from.x) / (to.x - from.x);
+        assert!(t > 0.0 && t < 1.0);
+        assert!((from.width + (to.width - from.width) * t - rendered.width).abs() < 0.01);
+        assert!((from.height + (to.height - from.height) * t - rendered.height).abs() < 0.01);
+
+        // The motion renders the target scaled to the current bounds
+        let (sx, sy) = bindings.get_scale().unwrap();
+        assert!((sx - rendered.width / to.width).abs() < 0.001);
+        assert!((sy - rendered.height / to.height).abs() < 0.001);
+    }
+
+    /// Screen-space bounds of each red rect drawn in a frame
+    fn drawn_red_rects(tree: &mut RenderTree) -> Vec<ElementBounds> {
+        use blinc_core::{Affine2D, Brush, Color, DrawCommand, Point, RecordingContext, Size};
+
+        let render_state = crate::render_state::RenderState::new(Arc::new(std::sync::Mutex::new(
+            AnimationScheduler::new(),
+        )));
+        let mut ctx = RecordingContext::new(Size::new(400.0, 200.0));
+        tree.render_with_motion(&mut ctx, &render_state);
+
+        let mut stack = vec![Affine2D::IDENTITY];
+        let mut rects = Vec::new();
+        for command in ctx.into_commands() {
+            match command {
+                DrawCommand::PushTransform(Transform::Affine2D(t)) => {
+                    let top = *stack.last().unwrap();
+                    stack.push(top.then(&t));
+                }
+                DrawCommand::PushTransform(Transform::Mat4(_)) => {
+                    panic!("unexpected 3D transform")
+                }
+                DrawCommand::PopTransform => {
+                    stack.pop();
+                }
+                DrawCommand::FillRect {
+                    rect,
+                    brush: Brush::Solid(color),
+                    ..
+                } if color == Color::RED => {
+                    let top = stack.last().unwrap();
+                    let min = top.transform_point(Point::new(rect.x(), rect.y()));
+                    let max = top.transform_point(Point::new(
+                        rect.x() + rect.width(),
+                        rect.y() + rect.height(),
+                    ));
+                    rects.push(ElementBounds::new(
+                        min.x,
+                        min.y,
+                        max.x - min.x,
+                        max.y - min.y,
+                    ));
+                }
+                _ => {}
+            }
+        }
+        rects
+    }
+
+    fn assert_bounds_near(actual: ElementBounds, expected: ElementBounds) {
+        assert!(
+            (actual.x - expected.x).abs() < 0.5
+                && (actual.y - expected.y).abs() < 0.5
+                && (actual.width - expected.width).abs() < 0.5
+                && (actual.height - expected.height).abs() < 0.5,
+            "drawn at {actual:?}, expected {expected:?}"
+        );
+    }
+
+    #[test]
+    fn test_shared_transition_draws_from_source_to_target() {
+        use blinc_core::Color;
+
+        let from_ref = ElementRef::<Div>::new();
+        let to_ref = ElementRef::<Div>::new();
+
+        // The list view with the thumbnail
+        let list = div()
+            .flex_row()
+            .child(div().w(40.0).h(40.0).bg(Color::BLUE).bind_bounds(&from_ref));
+        RenderTree::from_element(&list).compute_layout(400.0, 200.0);
+        let from = from_ref.get_layout_bounds().unwrap();
+
+        let scheduler = AnimationScheduler::new();
+        let transition = SharedTransition::new(
+            scheduler.handle(),
+            &from_ref,
+            &to_ref,
+            SpringConfig::gentle(),
+        );
+
+        // The detail view, with the hero away from the thumbnail's spot
+        let detail = div().flex_row().child(div().w(200.0).h(40.0)).child(
+            motion()
+                .shared_transition(&transition)
+                .child(div().w(120.0).h(80.0).bg(Color::RED).bind_bounds(&to_ref)),
+        );
+        let mut tree = RenderTree::from_element(&detail);
+        tree.compute_layout(400.0, 200.0);
+        let to = to_ref.get_layout_bounds().unwrap();
+        assert!(transition.start());
+
+        // First frame: the hero is drawn over the thumbnail
+        let first = drawn_red_rects(&mut tree);
+        assert_eq!(first.len(), 1);
+        assert_bounds_near(first[0], from);
+
+        // Settled: the hero is drawn at its own layout bounds
+        for _ in 0..600 {
+            scheduler.tick_with_dt(1.0 / 60.0);
+        }
+        assert!(!transition.is_animating());
+        let last = drawn_red_rects(&mut tree);
+        assert_eq!(last.len(), 1);
+        assert_bounds_near(last[0], to);
+    }
+}
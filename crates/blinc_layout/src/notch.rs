@@ -53,12 +53,10 @@
 
 use std::rc::Rc;
 
-use blinc_core::{
-    Brush, Color, CornerRadius, DrawContext, Gradient, Path, Rect, Shadow, Transform,
-};
+use blinc_core::{Brush, Color, CornerRadius, DrawContext, Path, Rect, Shadow, Transform};
 use taffy::{prelude::*, Overflow};
 
-use crate::canvas::{CanvasBounds, CanvasRenderFn};
+use crate::canvas::{CanvasBounds, CanvasFrameInfo, CanvasRenderFn};
 use crate::div::{ElementBuilder, ElementTypeId};
 use crate::element::{Material, RenderLayer, RenderProps};
 use crate::event_handler::EventHandlers;
@@ -2393,7 +2391,7 @@ impl ElementBuilder for Notch {
         let opacity = self.opacity;
 
         Some(Rc::new(
-            move |ctx: &mut dyn DrawContext, bounds: CanvasBounds| {
+            move |ctx: &mut dyn DrawContext, bounds: CanvasBounds, _frame: &CanvasFrameInfo| {
                 // For concave corners, we need to offset the rect inward so the concave
                 // portions (which extend outward) stay within the canvas bounds
                 let tl_r = corners.top_left.radius;
@@ -2478,61 +2476,12 @@ impl ElementBuilder for Notch {
                             Brush::Solid(color) => {
                                 Brush::Solid(color.with_alpha(color.a * opacity))
                             }
-                            Brush::Gradient(g) => {
+                            Brush::Gradient(mut g) => {
                                 // Apply opacity to all gradient stops
-                                let new_stops: Vec<_> = g
-                                    .stops()
-                                    .iter()
-                                    .map(|stop| {
-                                        blinc_core::GradientStop::new(
-                                            stop.offset,
-                                            stop.color.with_alpha(stop.color.a * opacity),
-                                        )
-                                    })
-                                    .collect();
-                                // Recreate gradient with modified stops
-                                let new_gradient = match g {
-                                    Gradient::Linear {
-                                        start,
-                                        end,
-                                        space,
-                                        spread,
-                                        ..
-                                    } => Gradient::Linear {
-                                        start,
-                                        end,
-                                        stops: new_stops,
-                                        space,
-                                        spread,
-                                    },
-                                    Gradient::Radial {
-                                        center,
-                                        radius,
-                                        focal,
-                                        space,
-                                        spread,
-                                        ..
-                                    } => Gradient::Radial {
-                                        center,
-                                        radius,
-                                        focal,
-                                        stops: new_stops,
-                                        space,
-                                        spread,
-                                    },
-                                    Gradient::Conic {
-                                        center,
-                                        start_angle,
-                                        space,
-                                        ..
-                                    } => Gradient::Conic {
-                                        center,
-                                        start_angle,
-                                        stops: new_stops,
-                                        space,
-                                    },
-                                };
-                                Brush::Gradient(new_gradient)
+                                for stop in g.stops_mut() {
+                                    stop.color = stop.color.with_alpha(stop.color.a * opacity);
+                                }
+                                Brush::Gradient(g)
                             }
                             other => other,
                         }
@@ -247,7 +247,15 @@ pub fn capture_tree_snapshot(
 
     if let Some(root) = tree.root() {
         snapshot.root_id = Some(format!("{:?}", root));
-        capture_node_recursive(tree, root, None, focused_node, hovered_nodes, &mut snapshot);
+        capture_node_recursive(
+            tree,
+            root,
+            None,
+            (0.0, 0.0),
+            focused_node,
+            hovered_nodes,
+            &mut snapshot,
+        );
     }
 
     snapshot.focused_element = focused_node.map(|n| format!("{:?}", n));
@@ -256,20 +264,24 @@ pub fn capture_tree_snapshot(
 }
 
 /// Recursively capture a node and its children.
+///
+/// `parent_offset` is the parent's absolute position, so captured bounds
+/// are in window coordinates and a moved parent shows up on its children.
 fn capture_node_recursive(
     tree: &crate::renderer::RenderTree,
     node: crate::tree::LayoutNodeId,
     parent: Option<crate::tree::LayoutNodeId>,
+    parent_offset: (f32, f32),
     focused_node: Option<crate::tree::LayoutNodeId>,
     hovered_nodes: &std::collections::HashSet<crate::tree::LayoutNodeId>,
     snapshot: &mut TreeSnapshotData,
 ) {
     let node_id_str = format!("{:?}", node);
 
-    // Get bounds
+    // Get absolute bounds
     let bounds = tree
         .layout()
-        .get_bounds(node, (0.0, 0.0))
+        .get_bounds(node, parent_offset)
         .map(|b| SnapshotRect::new(b.x, b.y, b.width, b.height))
         .unwrap_or_else(|| SnapshotRect::new(0.0, 0.0, 0.0, 0.0));
 
@@ -321,6 +333,7 @@ fn capture_node_recursive(
     // Simplified check - a more thorough check would need handler registry access
     let is_interactive = render_node.is_some();
 
+    let child_offset = (bounds.x, bounds.y);
     let elem = ElementSnapshotData {
         id: node_id_str.clone(),
         element_type,
@@ -348,6 +361,7 @@ fn capture_node_recursive(
             tree,
             child,
             Some(node),
+            child_offset,
             focused_node,
             hovered_nodes,
             snapshot,
@@ -5,22 +5,26 @@
 
 use std::any::Any;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, Weak};
 
 use blinc_animation::AnimationScheduler;
 use indexmap::IndexMap;
 
 use blinc_core::{
-    BlendMode, Brush, ClipShape, Color, CornerRadius, DrawContext, GlassStyle, LayerConfig, Rect,
-    Shadow, Stroke, Transform,
+    Affine2D, BlendMode, Brush, CachePolicy, ClipShape, Color, CornerRadius, DrawContext,
+    GlassStyle, LayerConfig, LayerEffect, LayerId, Point, Rect, Shadow, Stroke, Transform,
 };
 use taffy::prelude::*;
 
 use crate::canvas::CanvasData;
 use crate::css_parser::{ElementState, Stylesheet};
 use crate::diff::{render_props_eq, ChangeCategory, DivHash};
-use crate::div::{ElementBuilder, ElementTypeId};
-use crate::element::{ElementBounds, GlassMaterial, Material, RenderLayer, RenderProps};
+use crate::div::{ElementBuilder, ElementTypeId, LazyContent, ResponsiveContent, WithinStyles};
+use crate::element::{
+    BorderSide, ElementBounds, GlassMaterial, Material, RenderLayer, RenderProps, StickyInsets,
+};
+use crate::layer_cache::LayerCache;
 use crate::layout_animation::{LayoutAnimationConfig, LayoutAnimationState};
 use crate::selector::{ElementRegistry, ScrollRef};
 use crate::tree::{LayoutNodeId, LayoutTree};
@@ -67,6 +71,8 @@ pub struct RenderTreeDebugStats {
     pub render_node_count: usize,
     /// Number of scroll physics instances
     pub scroll_physics_count: usize,
+    /// Number of cached layer recordings (one per cached node and render pass)
+    pub cached_layer_count: usize,
 }
 
 /// Stores an element's type for rendering
@@ -99,6 +105,12 @@ pub struct TextData {
     pub v_align: crate::div::TextVerticalAlign,
     /// Whether to wrap text at container bounds
     pub wrap: bool,
+    /// Keep leading whitespace on wrapped lines
+    pub preformatted: bool,
+    /// Apply font ligatures
+    pub ligatures: bool,
+    /// Apply font kerning
+    pub kerning: bool,
     /// Line height multiplier
     pub line_height: f32,
     /// Measured width (before layout constraints)
@@ -134,6 +146,10 @@ pub struct StyledTextSpan {
     pub strikethrough: bool,
     /// Optional link URL (for clickable spans)
     pub link_url: Option<String>,
+    /// Font family override (None = element font)
+    pub font_family: Option<crate::div::FontFamily>,
+    /// Font size override (None = element size)
+    pub font_size: Option<f32>,
 }
 
 impl StyledTextSpan {
@@ -148,6 +164,8 @@ impl StyledTextSpan {
             underline: false,
             strikethrough: false,
             link_url: None,
+            font_family: None,
+            font_size: None,
         }
     }
 
@@ -162,6 +180,8 @@ impl StyledTextSpan {
             underline: span.underline,
             strikethrough: span.strikethrough,
             link_url: span.link_url.clone(),
+            font_family: span.font_family.clone(),
+            font_size: span.font_size,
         }
     }
 }
@@ -193,6 +213,177 @@ pub struct StyledTextData {
     pub ascender: f32,
 }
 
+impl From<crate::div::StyledTextRenderInfo> for StyledTextData {
+    fn from(info: crate::div::StyledTextRenderInfo) -> Self {
+        Self {
+            content: info.content,
+            spans: info
+                .spans
+                .into_iter()
+                .map(|s| StyledTextSpan {
+                    start: s.start,
+                    end: s.end,
+                    color: s.color,
+                    bold: s.bold,
+                    italic: s.italic,
+                    underline: s.underline,
+                    strikethrough: s.strikethrough,
+                    link_url: s.link_url,
+                    font_family: s.font_family,
+                    font_size: s.font_size,
+                })
+                .collect(),
+            default_color: info.default_color,
+            font_size: info.font_size,
+            align: info.align,
+            v_align: info.v_align,
+            font_family: info.font_family,
+            line_height: info.line_height,
+            weight: info.weight,
+            italic: info.italic,
+            ascender: info.ascender,
+        }
+    }
+}
+
+/// A run of styled text with overlapping spans resolved to one style
+#[derive(Clone, Debug, PartialEq)]
+pub struct StyledTextSegment {
+    /// Start byte index in text
+    pub start: usize,
+    /// End byte index in text (exclusive)
+    pub end: usize,
+    /// RGBA color
+    pub color: [f32; 4],
+    /// Whether text is bold
+    pub bold: bool,
+    /// Whether text is italic
+    pub italic: bool,
+    /// Whether text has underline decoration
+    pub underline: bool,
+    /// Whether text has strikethrough decoration
+    pub strikethrough: bool,
+    /// Font size in pixels
+    pub font_size: f32,
+    /// Font family
+    pub font_family: crate::div::FontFamily,
+    /// Offset of the segment from the start of the line
+    pub x: f32,
+    /// Measured width
+    pub width: f32,
+    /// Measured ascender of the segment's font
+    pub ascender: f32,
+}
+
+impl StyledTextData {
+    /// Split the text into non-overlapping, measured segments
+    ///
+    /// Overlapping spans (e.g. `<span color="red"><b>text</b></span>`) are
+    /// merged; where spans disagree on color, size or family the later span
+    /// wins. Segments are laid out left to right on one line.
+    pub fn segments(&self) -> Vec<StyledTextSegment> {
+        let content = &self.content;
+        let content_len = content.len();
+
+        // Collect all boundary positions where style might change
+        let mut boundaries: Vec<usize> = vec![0, content_len];
+        for span in &self.spans {
+            if span.start < content_len {
+                boundaries.push(span.start);
+            }
+            if span.end <= content_len {
+                boundaries.push(span.end);
+            }
+        }
+        boundaries.sort();
+        boundaries.dedup();
+
+        let mut segments = Vec::new();
+        let mut x = 0.0;
+        for window in boundaries.windows(2) {
+            let (start, end) = (window[0], window[1]);
+            if start >= end || !content.is_char_boundary(start) || !content.is_char_boundary(end) {
+                continue;
+            }
+
+            let mut segment = StyledTextSegment {
+                start,
+                end,
+                color: self.default_color,
+                bold: self.weight == crate::div::FontWeight::Bold,
+                italic: self.italic,
+                underline: false,
+                strikethrough: false,
+                font_size: self.font_size,
+                font_family: self.font_family.clone(),
+                x,
+                width: 0.0,
+                ascender: self.ascender,
+            };
+            for span in &self.spans {
+                if span.start <= start && span.end >= end {
+                    segment.bold |= span.bold;
+                    segment.italic |= span.italic;
+                    segment.underline |= span.underline;
+                    segment.strikethrough |= span.strikethrough;
+                    // Transparent means "no explicit color"
+                    if span.color[3] > 0.0 {
+                        segment.color = span.color;
+                    }
+                    if let Some(size) = span.font_size {
+                        segment.font_size = size;
+                    }
+                    if let Some(ref family) = span.font_family {
+                        segment.font_family = family.clone();
+                    }
+                }
+            }
+
+            let mut options = crate::text_measure::TextLayoutOptions::new();
+            options.font_name = segment.font_family.name.clone();
+            options.generic_font = segment.font_family.generic;
+            options.font_weight = if segment.bold { 700 } else { 400 };
+            options.italic = segment.italic;
+            let metrics = crate::text_measure::measure_text_with_options(
+                &content[start..end],
+                segment.font_size,
+                &options,
+            );
+            segment.width = metrics.width;
+            if self.has_font_overrides() {
+                segment.ascender = metrics.ascender;
+            }
+            x += metrics.width;
+            segments.push(segment);
+        }
+        segments
+    }
+
+    /// Whether any span changes the font size or family
+    pub fn has_font_overrides(&self) -> bool {
+        self.spans
+            .iter()
+            .any(|s| s.font_size.is_some() || s.font_family.is_some())
+    }
+
+    /// Line box height and the offset of the shared baseline from its top
+    ///
+    /// The line box fits the tallest segment; the extra line height is split
+    /// above and below it, and every segment sits on the same baseline.
+    pub fn line_metrics(&self, segments: &[StyledTextSegment]) -> (f32, f32) {
+        let tallest = segments
+            .iter()
+            .map(|s| s.font_size)
+            .fold(self.font_size, f32::max);
+        let ascender = segments
+            .iter()
+            .map(|s| s.ascender)
+            .fold(self.ascender, f32::max);
+        let height = tallest * self.line_height;
+        (height, ascender + (height - tallest) / 2.0)
+    }
+}
+
 /// SVG data for rendering
 #[derive(Clone)]
 pub struct SvgData {
@@ -333,6 +524,46 @@ pub struct OnReadyEntry {
     pub triggered: bool,
 }
 
+/// Subtree deferred with `Div::defer_until_visible`
+struct LazyMount {
+    content: LazyContent,
+    /// Root of the built content while it's mounted
+    mounted: Option<LayoutNodeId>,
+    /// Style the element was built with, restored when content mounts
+    style: Style,
+}
+
+/// Hover/focus-within styles registered for a node
+struct WithinState {
+    styles: WithinStyles,
+    /// Props as built, without the within styles
+    base: RenderProps,
+    /// Whether the hover style is currently applied
+    hovered: bool,
+    /// Whether the focus style is currently applied
+    focused: bool,
+}
+
+/// Content built from its element's width with `Div::responsive`
+struct ResponsiveMount {
+    content: ResponsiveContent,
+    /// Root of the built content and the breakpoint range it was built for
+    mounted: Option<(LayoutNodeId, usize)>,
+}
+
+/// Pointer event chain currently being dispatched (see `dispatch_propagating`)
+#[derive(Clone, Copy, Debug)]
+struct Propagation {
+    event_type: blinc_core::events::EventType,
+    /// Last node the chain reached
+    node: LayoutNodeId,
+    /// A handler called `stop_propagation()`
+    stopped: bool,
+}
+
+/// Source of [`RenderTree`] serials, so trees' cached layer IDs never collide
+static NEXT_LAYER_CACHE_SERIAL: AtomicU64 = AtomicU64::new(0);
+
 /// RenderTree - bridges layout computation and rendering
 pub struct RenderTree {
     /// The underlying layout tree
@@ -343,8 +574,18 @@ pub struct RenderTree {
     root: Option<LayoutNodeId>,
     /// Event handlers registry for dispatching events
     handler_registry: crate::event_handler::HandlerRegistry,
+    /// Capture/bubble state of the pointer event being dispatched
+    propagation: Option<Propagation>,
+    /// Click count attached to dispatched POINTER_DOWN/POINTER_UP events
+    click_count: u32,
+    /// Whether a hold gesture handled the current press (no click on release)
+    click_consumed: bool,
     /// Dirty tracker for incremental rebuilds
     dirty_tracker: crate::interactive::DirtyTracker,
+    /// Nodes whose props were patched in place (no rebuild) since last taken
+    patched: crate::interactive::DirtyTracker,
+    /// Frame timing handed to canvas render callbacks
+    canvas_frame: crate::canvas::CanvasFrameInfo,
     /// Per-node state storage (survives across rebuilds if tree is reused)
     node_states: HashMap<LayoutNodeId, NodeStateStorage>,
     /// Scroll offsets for scroll containers (node_id -> (offset_x, offset_y))
@@ -374,6 +615,14 @@ pub struct RenderTree {
     layout_bounds_storages: HashMap<LayoutNodeId, LayoutBoundsEntry>,
     /// Element registry for O(1) lookups by string ID
     element_registry: Arc<ElementRegistry>,
+    /// Deferred subtrees, mounted while near the viewport
+    lazy_mounts: HashMap<LayoutNodeId, LazyMount>,
+    /// Width-dependent subtrees, rebuilt when crossing a breakpoint
+    responsive_mounts: HashMap<LayoutNodeId, ResponsiveMount>,
+    /// Viewport size passed to the last `compute_layout`
+    viewport_size: Option<(f32, f32)>,
+    /// Nodes restyled while a descendant is hovered or focused
+    within_styles: HashMap<LayoutNodeId, WithinState>,
     /// Bound ScrollRefs for programmatic scroll control
     /// Note: NOT cleared on rebuild - ScrollRef inner state persists and node_id is updated
     scroll_refs: HashMap<LayoutNodeId, ScrollRef>,
@@ -425,6 +674,17 @@ pub struct RenderTree {
     /// Pre-computed animated render bounds for this frame
     /// Calculated after layout, used during rendering
     animated_render_bounds: HashMap<LayoutNodeId, AnimatedRenderBounds>,
+
+    /// Validity of `cache_layer()` subtrees drawn into cached layers
+    /// Written during rendering (which only has `&self`), hence the mutex
+    layer_cache: Mutex<LayerCache>,
+    /// Distinguishes this tree's cached layer IDs from other trees'
+    layer_cache_serial: u64,
+
+    /// Whether the layout debug overlay is enabled (see `debug_overlay`)
+    debug_overlay: bool,
+    /// Element described by the debug overlay's inspector panel
+    debug_inspect_target: Option<LayoutNodeId>,
 }
 
 /// Result of an incremental update attempt
@@ -454,7 +714,12 @@ impl RenderTree {
             render_nodes: IndexMap::new(),
             root: None,
             handler_registry: crate::event_handler::HandlerRegistry::new(),
+            propagation: None,
+            click_count: 1,
+            click_consumed: false,
             dirty_tracker: crate::interactive::DirtyTracker::new(),
+            patched: crate::interactive::DirtyTracker::new(),
+            canvas_frame: crate::canvas::CanvasFrameInfo::default(),
             node_states: HashMap::new(),
             scroll_offsets: HashMap::new(),
             scroll_physics: HashMap::new(),
@@ -466,6 +731,10 @@ impl RenderTree {
             node_hashes: HashMap::new(),
             layout_bounds_storages: HashMap::new(),
             element_registry: Arc::new(ElementRegistry::new()),
+            lazy_mounts: HashMap::new(),
+            responsive_mounts: HashMap::new(),
+            within_styles: HashMap::new(),
+            viewport_size: None,
             scroll_refs: HashMap::new(),
             active_scroll_refs: Vec::new(),
             on_ready_callbacks: HashMap::new(),
@@ -483,6 +752,10 @@ impl RenderTree {
             visual_animations: HashMap::new(),
             previous_visual_bounds: HashMap::new(),
             animated_render_bounds: HashMap::new(),
+            layer_cache: Mutex::new(LayerCache::default()),
+            layer_cache_serial: NEXT_LAYER_CACHE_SERIAL.fetch_add(1, Ordering::Relaxed),
+            debug_overlay: false,
+            debug_inspect_target: None,
         }
     }
 
@@ -571,6 +844,9 @@ impl RenderTree {
         // Clear scroll_refs HashMap (node_id keyed) - it will be repopulated during rebuild
         // but active_scroll_refs persists for process_pending_scroll_refs
         self.scroll_refs.clear();
+        self.lazy_mounts.clear();
+        self.responsive_mounts.clear();
+        self.within_styles.clear();
 
         // Preserve node_states, scroll_offsets, scroll_physics, motion_bindings, active_scroll_refs
         // as these should survive rebuilds
@@ -730,6 +1006,8 @@ impl RenderTree {
         parent_id: LayoutNodeId,
         new_children: &[Box<dyn ElementBuilder>],
     ) {
+        self.invalidate_layer_content(parent_id);
+
         // Remove old children
         let old_children = self.layout_tree.children(parent_id);
         for child_id in &old_children {
@@ -896,6 +1174,8 @@ impl RenderTree {
         element: &E,
         node_id: LayoutNodeId,
     ) {
+        self.invalidate_layer_content(node_id);
+
         // Update this node's props
         if let Some(render_node) = self.render_nodes.get_mut(&node_id) {
             let mut new_props = element.render_props();
@@ -955,6 +1235,11 @@ impl RenderTree {
         // Register layout bounds storage if element wants bounds updates
         self.register_element_bounds_storage(node_id, element);
 
+        // Re-apply hover/focus-within styles over the new props
+        if let Some(styles) = element.within_styles() {
+            self.register_within_styles(node_id, styles);
+        }
+
         // Recursively update children
         let child_node_ids = self.layout_tree.children(node_id);
         let child_builders = element.children_builders();
@@ -977,6 +1262,8 @@ impl RenderTree {
         element: &dyn ElementBuilder,
         node_id: LayoutNodeId,
     ) {
+        self.invalidate_layer_content(node_id);
+
         if let Some(render_node) = self.render_nodes.get_mut(&node_id) {
             let mut new_props = element.render_props();
             new_props.node_id = Some(node_id);
@@ -1033,6 +1320,11 @@ impl RenderTree {
         // Register layout bounds storage if element wants bounds updates
         self.register_element_bounds_storage(node_id, element);
 
+        // Re-apply hover/focus-within styles over the new props
+        if let Some(styles) = element.within_styles() {
+            self.register_within_styles(node_id, styles);
+        }
+
         let child_node_ids = self.layout_tree.children(node_id);
         let child_builders = element.children_builders();
 
@@ -1064,6 +1356,33 @@ impl RenderTree {
         self.scale_factor
     }
 
+    /// Enable or disable the layout debug overlay
+    ///
+    /// Only records the setting; renderers draw the overlay with
+    /// [`render_debug_overlay`](Self::render_debug_overlay) when it is enabled.
+    pub fn set_debug_overlay(&mut self, enabled: bool) {
+        self.debug_overlay = enabled;
+        if !enabled {
+            self.debug_inspect_target = None;
+        }
+    }
+
+    /// Whether the layout debug overlay is enabled
+    pub fn is_debug_overlay_enabled(&self) -> bool {
+        self.debug_overlay
+    }
+
+    /// Set the element described by the debug overlay's inspector panel
+    /// (typically the hovered element)
+    pub fn set_debug_inspect_target(&mut self, node: Option<LayoutNodeId>) {
+        self.debug_inspect_target = node;
+    }
+
+    /// Element described by the debug overlay's inspector panel
+    pub fn debug_inspect_target(&self) -> Option<LayoutNodeId> {
+        self.debug_inspect_target
+    }
+
     /// Get debug statistics for the render tree
     ///
     /// Returns counts of active animations and other debug info.
@@ -1076,6 +1395,7 @@ impl RenderTree {
             animated_bounds_count: self.animated_render_bounds.len(),
             render_node_count: self.render_nodes.len(),
             scroll_physics_count: self.scroll_physics.len(),
+            cached_layer_count: self.layer_cache.lock().unwrap().len(),
         }
     }
 
@@ -1182,6 +1502,21 @@ impl RenderTree {
             self.register_scroll_ref(node_id, scroll_ref);
         }
 
+        // Track deferred content (mounted by update_lazy_mounts)
+        if let Some(lazy) = element.lazy_content() {
+            self.register_lazy_content(node_id, lazy);
+        }
+
+        // Track width-dependent content (built by update_responsive_mounts)
+        if let Some(responsive) = element.responsive_content() {
+            self.register_responsive_content(node_id, responsive);
+        }
+
+        // Track hover/focus-within styles (applied by apply_within_styles)
+        if let Some(styles) = element.within_styles() {
+            self.register_within_styles(node_id, styles);
+        }
+
         // Get child node IDs from the layout tree
         let child_node_ids = self.layout_tree.children(node_id);
         let child_builders = element.children_builders();
@@ -1253,6 +1588,9 @@ impl RenderTree {
                         italic: info.italic,
                         v_align: info.v_align,
                         wrap: info.wrap,
+                        preformatted: info.preformatted,
+                        ligatures: info.ligatures,
+                        kerning: info.kerning,
                         line_height: info.line_height,
                         measured_width: info.measured_width,
                         font_family: info.font_family,
@@ -1298,32 +1636,7 @@ impl RenderTree {
             }),
             ElementTypeId::StyledText => {
                 if let Some(info) = element.styled_text_render_info() {
-                    ElementType::StyledText(StyledTextData {
-                        content: info.content,
-                        spans: info
-                            .spans
-                            .into_iter()
-                            .map(|s| StyledTextSpan {
-                                start: s.start,
-                                end: s.end,
-                                color: s.color,
-                                bold: s.bold,
-                                italic: s.italic,
-                                underline: s.underline,
-                                strikethrough: s.strikethrough,
-                                link_url: s.link_url,
-                            })
-                            .collect(),
-                        default_color: info.default_color,
-                        font_size: info.font_size,
-                        align: info.align,
-                        v_align: info.v_align,
-                        font_family: info.font_family,
-                        line_height: info.line_height,
-                        weight: info.weight,
-                        italic: info.italic,
-                        ascender: info.ascender,
-                    })
+                    ElementType::StyledText(StyledTextData::from(info))
                 } else {
                     ElementType::Div
                 }
@@ -1396,6 +1709,21 @@ impl RenderTree {
             self.register_scroll_ref(node_id, scroll_ref);
         }
 
+        // Track deferred content (mounted by update_lazy_mounts)
+        if let Some(lazy) = element.lazy_content() {
+            self.register_lazy_content(node_id, lazy);
+        }
+
+        // Track width-dependent content (built by update_responsive_mounts)
+        if let Some(responsive) = element.responsive_content() {
+            self.register_responsive_content(node_id, responsive);
+        }
+
+        // Track hover/focus-within styles (applied by apply_within_styles)
+        if let Some(styles) = element.within_styles() {
+            self.register_within_styles(node_id, styles);
+        }
+
         // Get child node IDs from the layout tree
         let child_node_ids = self.layout_tree.children(node_id);
         let child_builders = element.children_builders();
@@ -1538,6 +1866,9 @@ impl RenderTree {
                         italic: info.italic,
                         v_align: info.v_align,
                         wrap: info.wrap,
+                        preformatted: info.preformatted,
+                        ligatures: info.ligatures,
+                        kerning: info.kerning,
                         line_height: info.line_height,
                         measured_width: info.measured_width,
                         font_family: info.font_family,
@@ -1583,32 +1914,7 @@ impl RenderTree {
             }),
             ElementTypeId::StyledText => {
                 if let Some(info) = element.styled_text_render_info() {
-                    ElementType::StyledText(StyledTextData {
-                        content: info.content,
-                        spans: info
-                            .spans
-                            .into_iter()
-                            .map(|s| StyledTextSpan {
-                                start: s.start,
-                                end: s.end,
-                                color: s.color,
-                                bold: s.bold,
-                                italic: s.italic,
-                                underline: s.underline,
-                                strikethrough: s.strikethrough,
-                                link_url: s.link_url,
-                            })
-                            .collect(),
-                        default_color: info.default_color,
-                        font_size: info.font_size,
-                        align: info.align,
-                        v_align: info.v_align,
-                        font_family: info.font_family,
-                        line_height: info.line_height,
-                        weight: info.weight,
-                        italic: info.italic,
-                        ascender: info.ascender,
-                    })
+                    ElementType::StyledText(StyledTextData::from(info))
                 } else {
                     ElementType::Div
                 }
@@ -1672,6 +1978,21 @@ impl RenderTree {
             self.register_scroll_ref(node_id, scroll_ref);
         }
 
+        // Track deferred content (mounted by update_lazy_mounts)
+        if let Some(lazy) = element.lazy_content() {
+            self.register_lazy_content(node_id, lazy);
+        }
+
+        // Track width-dependent content (built by update_responsive_mounts)
+        if let Some(responsive) = element.responsive_content() {
+            self.register_responsive_content(node_id, responsive);
+        }
+
+        // Track hover/focus-within styles (applied by apply_within_styles)
+        if let Some(styles) = element.within_styles() {
+            self.register_within_styles(node_id, styles);
+        }
+
         // Recursively process children (without motion - motion only applies to direct children)
         let child_node_ids = self.layout_tree.children(node_id);
         let child_builders = element.children_builders();
@@ -1699,6 +2020,9 @@ impl RenderTree {
                         italic: info.italic,
                         v_align: info.v_align,
                         wrap: info.wrap,
+                        preformatted: info.preformatted,
+                        ligatures: info.ligatures,
+                        kerning: info.kerning,
                         line_height: info.line_height,
                         measured_width: info.measured_width,
                         font_family: info.font_family,
@@ -1744,32 +2068,7 @@ impl RenderTree {
             }),
             ElementTypeId::StyledText => {
                 if let Some(info) = element.styled_text_render_info() {
-                    ElementType::StyledText(StyledTextData {
-                        content: info.content,
-                        spans: info
-                            .spans
-                            .into_iter()
-                            .map(|s| StyledTextSpan {
-                                start: s.start,
-                                end: s.end,
-                                color: s.color,
-                                bold: s.bold,
-                                italic: s.italic,
-                                underline: s.underline,
-                                strikethrough: s.strikethrough,
-                                link_url: s.link_url,
-                            })
-                            .collect(),
-                        default_color: info.default_color,
-                        font_size: info.font_size,
-                        align: info.align,
-                        v_align: info.v_align,
-                        font_family: info.font_family,
-                        line_height: info.line_height,
-                        weight: info.weight,
-                        italic: info.italic,
-                        ascender: info.ascender,
-                    })
+                    ElementType::StyledText(StyledTextData::from(info))
                 } else {
                     ElementType::Div
                 }
@@ -1797,6 +2096,9 @@ impl RenderTree {
                         italic: info.italic,
                         v_align: info.v_align,
                         wrap: info.wrap,
+                        preformatted: info.preformatted,
+                        ligatures: info.ligatures,
+                        kerning: info.kerning,
                         line_height: info.line_height,
                         measured_width: info.measured_width,
                         font_family: info.font_family,
@@ -1811,32 +2113,7 @@ impl RenderTree {
             }
             ElementTypeId::StyledText => {
                 if let Some(info) = element.styled_text_render_info() {
-                    ElementType::StyledText(StyledTextData {
-                        content: info.content,
-                        spans: info
-                            .spans
-                            .into_iter()
-                            .map(|s| StyledTextSpan {
-                                start: s.start,
-                                end: s.end,
-                                color: s.color,
-                                bold: s.bold,
-                                italic: s.italic,
-                                underline: s.underline,
-                                strikethrough: s.strikethrough,
-                                link_url: s.link_url,
-                            })
-                            .collect(),
-                        default_color: info.default_color,
-                        font_size: info.font_size,
-                        align: info.align,
-                        v_align: info.v_align,
-                        font_family: info.font_family,
-                        line_height: info.line_height,
-                        weight: info.weight,
-                        italic: info.italic,
-                        ascender: info.ascender,
-                    })
+                    ElementType::StyledText(StyledTextData::from(info))
                 } else {
                     ElementType::Div
                 }
@@ -1883,7 +2160,29 @@ impl RenderTree {
     }
 
     /// Compute layout for the given viewport size
+    ///
+    /// Deferred subtrees (`Div::defer_until_visible`) that end up near or
+    /// far from the viewport are mounted or unmounted, responsive content
+    /// (`Div::responsive`) whose width crossed a breakpoint is rebuilt, and
+    /// layout is run again for them.
     pub fn compute_layout(&mut self, width: f32, height: f32) {
+        self.viewport_size = Some((width, height));
+        self.compute_layout_pass(width, height);
+
+        // Mounting content can move later lazy elements into view, so
+        // repeat a few times (bounded so content that keeps resizing can't
+        // loop forever; the next frame picks up the rest)
+        for _ in 0..4 {
+            let lazy_changed = self.update_lazy_mounts();
+            let responsive_changed = self.update_responsive_mounts();
+            if !lazy_changed && !responsive_changed {
+                break;
+            }
+            self.compute_layout_pass(width, height);
+        }
+    }
+
+    fn compute_layout_pass(&mut self, width: f32, height: f32) {
         if let Some(root) = self.root {
             // Step 1: Check for existing collapsing animations and apply their constraints
             // This ensures children are laid out at the larger (animated) size during collapse
@@ -2138,10 +2437,11 @@ impl RenderTree {
 
     /// Update all registered layout bounds storages after layout computation
     ///
-    /// When bounds change (width or height differ), the on_change callback is invoked.
+    /// Bounds are stored in absolute (root) coordinates. When bounds change
+    /// (width or height differ), the on_change callback is invoked.
     fn update_layout_bounds_storages(&self) {
         for (&node_id, entry) in &self.layout_bounds_storages {
-            if let Some(bounds) = self.layout_tree.get_bounds(node_id, (0.0, 0.0)) {
+            if let Some(bounds) = self.get_absolute_bounds(node_id) {
                 let should_notify = if let Ok(mut guard) = entry.storage.lock() {
                     // Check if bounds changed (compare width and height)
                     let changed = match guard.as_ref() {
@@ -2377,11 +2677,25 @@ impl RenderTree {
     /// Get bounds for rendering, using animated bounds if available
     ///
     /// This returns the animated bounds if a layout animation is active,
-    /// otherwise returns the layout bounds from taffy.
+    /// otherwise returns the layout bounds from taffy. Sticky positioning is
+    /// applied on top.
     pub fn get_render_bounds(
         &self,
         node_id: LayoutNodeId,
         parent_offset: (f32, f32),
+    ) -> Option<ElementBounds> {
+        let mut bounds = self.animated_bounds(node_id, parent_offset)?;
+        let (dx, dy) = self.sticky_offset(node_id);
+        bounds.x += dx;
+        bounds.y += dy;
+        Some(bounds)
+    }
+
+    /// Layout bounds with any active layout animation applied
+    fn animated_bounds(
+        &self,
+        node_id: LayoutNodeId,
+        parent_offset: (f32, f32),
     ) -> Option<ElementBounds> {
         // Check if this node has an ACTIVE visual animation
         // Apply animation offsets to layout bounds (keeps parent-relative coordinates)
@@ -3036,6 +3350,129 @@ impl RenderTree {
         any_modified
     }
 
+    /// Animate enclosing scroll containers so a node becomes visible
+    ///
+    /// Walks from the node to the root. Each scroll container on the way
+    /// springs to the offset that places the node per `alignment` (applied
+    /// on both axes), so nested containers each scroll as needed. Returns
+    /// true if any container's offset changed.
+    pub fn scroll_into_view(
+        &mut self,
+        node_id: LayoutNodeId,
+        alignment: crate::selector::ScrollBlock,
+    ) -> bool {
+        use crate::selector::ScrollBlock;
+
+        // Aligned offset along one axis for a target at `pos` (unscrolled)
+        fn aligned(
+            alignment: ScrollBlock,
+            pos: f32,
+            size: f32,
+            viewport: f32,
+            current: f32,
+        ) -> f32 {
+            let start = -pos;
+            let end = viewport - (pos + size);
+            match alignment {
+                ScrollBlock::Start => start,
+                ScrollBlock::Center => viewport / 2.0 - (pos + size / 2.0),
+                ScrollBlock::End => end,
+                ScrollBlock::Nearest => {
+                    if pos + current < 0.0 || size > viewport {
+                        start
+                    } else if pos + size + current > viewport {
+                        end
+                    } else {
+                        current
+                    }
+                }
+            }
+        }
+
+        let Some(bounds) = self.get_bounds(node_id) else {
+            return false;
+        };
+        // Target rect in the current ancestor's (unscrolled) content space
+        let (mut x, mut y) = (bounds.x, bounds.y);
+        let (width, height) = (bounds.width, bounds.height);
+        let mut any_scrolled = false;
+
+        let mut current = node_id;
+        while let Some(parent) = self.layout_tree.parent(current) {
+            if let Some(physics) = self.scroll_physics.get(&parent) {
+                let mut physics = physics.lock().unwrap();
+                let direction = physics.config.direction;
+                let scroll_x = matches!(
+                    direction,
+                    crate::scroll::ScrollDirection::Horizontal
+                        | crate::scroll::ScrollDirection::Both
+                );
+                let scroll_y = matches!(
+                    direction,
+                    crate::scroll::ScrollDirection::Vertical | crate::scroll::ScrollDirection::Both
+                );
+                let target_x = if scroll_x {
+                    aligned(
+                        alignment,
+                        x,
+                        width,
+                        physics.viewport_width,
+                        physics.offset_x,
+                    )
+                    .clamp(physics.max_offset_x(), physics.min_offset_x())
+                } else {
+                    physics.offset_x
+                };
+                let target_y = if scroll_y {
+                    aligned(
+                        alignment,
+                        y,
+                        height,
+                        physics.viewport_height,
+                        physics.offset_y,
+                    )
+                    .clamp(physics.max_offset_y(), physics.min_offset_y())
+                } else {
+                    physics.offset_y
+                };
+                if (target_x - physics.offset_x).abs() > 0.5
+                    || (target_y - physics.offset_y).abs() > 0.5
+                {
+                    physics.scroll_to_animated(target_x, target_y);
+                    any_scrolled = true;
+                }
+                // Outer containers see the node where this one settles
+                x += target_x;
+                y += target_y;
+            }
+
+            let Some(parent_bounds) = self.get_bounds(parent) else {
+                break;
+            };
+            x += parent_bounds.x;
+            y += parent_bounds.y;
+            current = parent;
+        }
+
+        any_scrolled
+    }
+
+    /// Apply scroll-into-view requests queued on an [`InteractiveContext`]
+    ///
+    /// Call each frame after layout, before ticking scroll physics.
+    ///
+    /// [`InteractiveContext`]: crate::interactive::InteractiveContext
+    pub fn process_scroll_into_view(
+        &mut self,
+        ctx: &mut crate::interactive::InteractiveContext,
+    ) -> bool {
+        let mut any_scrolled = false;
+        for (node_id, alignment) in ctx.take_scroll_requests() {
+            any_scrolled |= self.scroll_into_view(node_id, alignment);
+        }
+        any_scrolled
+    }
+
     /// Dispatch an event to a node's handlers
     ///
     /// This automatically marks the tree as dirty after dispatching,
@@ -3049,91 +3486,329 @@ impl RenderTree {
     ) {
         let ctx = crate::event_handler::EventContext::new(event_type, node_id)
             .with_mouse_pos(mouse_x, mouse_y);
+        let ctx = self.with_press_state(ctx);
 
-        // Check if this node has handlers for this event type
-        if self.handler_registry.has_handler(node_id, event_type) {
-            self.handler_registry.dispatch(&ctx);
-            // Don't auto-mark dirty - handlers update values in place
-        }
+        // Don't auto-mark dirty - handlers update values in place
+        self.dispatch_propagating(ctx);
+        self.dispatch_custom_events();
     }
 
-    /// Dispatch an event with local coordinates
-    ///
-    /// Dispatches an event to a node's handler.
+    /// Dispatch a pen event (PEN_DOWN, PEN_MOVE or PEN_UP) with stylus state
     ///
-    /// Note: This does NOT automatically mark the tree as dirty.
-    /// Handlers that need a rebuild should use EventContext::request_rebuild().
-    #[allow(clippy::too_many_arguments)]
-    pub fn dispatch_event_with_local(
+    /// Bubbles like pointer events; handlers read pressure and tilt from
+    /// `ctx.pen`.
+    pub fn dispatch_pen_event(
         &mut self,
         node_id: LayoutNodeId,
         event_type: blinc_core::events::EventType,
         mouse_x: f32,
         mouse_y: f32,
-        local_x: f32,
-        local_y: f32,
-        bounds_x: f32,
-        bounds_y: f32,
-        bounds_width: f32,
-        bounds_height: f32,
+        pen: blinc_core::events::PenState,
     ) {
-        self.dispatch_event_full(
-            node_id,
-            event_type,
-            mouse_x,
-            mouse_y,
-            local_x,
-            local_y,
-            bounds_x,
-            bounds_y,
-            bounds_width,
-            bounds_height,
-            0.0,
-            0.0,
-        );
+        let mut ctx = crate::event_handler::EventContext::new(event_type, node_id)
+            .with_mouse_pos(mouse_x, mouse_y)
+            .with_pen(pen);
+        if let Some(local) = self.to_local_point(node_id, mouse_x, mouse_y) {
+            ctx = ctx.with_local_pos(local.x, local.y);
+        }
+        self.dispatch_propagating(ctx);
+        self.dispatch_custom_events();
     }
 
-    /// Dispatch an event with all context data including drag delta
+    /// Set the click count for subsequently dispatched press/release events
     ///
-    /// This is the full dispatch method that includes drag_delta for DRAG events.
-    #[allow(clippy::too_many_arguments)]
-    pub fn dispatch_event_full(
+    /// Pass [`EventRouter::click_count`](crate::event_router::EventRouter::click_count)
+    /// before dispatching the events of a mouse down/up.
+    pub fn set_click_count(&mut self, count: u32) {
+        self.click_count = count;
+    }
+
+    /// Dispatch a PRESS_HOLD event with how long the pointer has been held
+    ///
+    /// Call for each event from
+    /// [`EventRouter::on_press_hold`](crate::event_router::EventRouter::on_press_hold).
+    pub fn dispatch_press_hold(
         &mut self,
         node_id: LayoutNodeId,
-        event_type: blinc_core::events::EventType,
+        hold_ms: u64,
         mouse_x: f32,
         mouse_y: f32,
-        local_x: f32,
-        local_y: f32,
-        bounds_x: f32,
-        bounds_y: f32,
-        bounds_width: f32,
-        bounds_height: f32,
-        drag_delta_x: f32,
-        drag_delta_y: f32,
     ) {
-        let has_handler = self.handler_registry.has_handler(node_id, event_type);
-        tracing::debug!(
-            "dispatch_event_full: node={:?}, event_type={}, has_handler={}, drag_delta=({:.1}, {:.1})",
+        let ctx = crate::event_handler::EventContext::new(
+            blinc_core::events::event_types::PRESS_HOLD,
             node_id,
-            event_type,
-            has_handler,
-            drag_delta_x,
-            drag_delta_y
-        );
+        )
+        .with_mouse_pos(mouse_x, mouse_y)
+        .with_hold_ms(hold_ms);
 
-        let ctx = crate::event_handler::EventContext::new(event_type, node_id)
-            .with_mouse_pos(mouse_x, mouse_y)
-            .with_local_pos(local_x, local_y)
-            .with_bounds_pos(bounds_x, bounds_y)
-            .with_bounds(bounds_width, bounds_height)
-            .with_drag_delta(drag_delta_x, drag_delta_y);
+        if self.is_disabled(node_id) {
+            return;
+        }
+        self.handler_registry.dispatch(&ctx);
+        self.click_consumed |= ctx.is_click_consumed();
+        self.dispatch_custom_events();
+    }
 
-        if has_handler {
+    /// Attach click count and hold-gesture state to press/release events
+    fn with_press_state(
+        &mut self,
+        ctx: crate::event_handler::EventContext,
+    ) -> crate::event_handler::EventContext {
+        use blinc_core::events::event_types;
+        match ctx.event_type {
+            event_types::POINTER_DOWN => {
+                self.click_consumed = false;
+                ctx.with_click_count(self.click_count)
+            }
+            event_types::POINTER_UP => {
+                if self.click_consumed {
+                    ctx.consume_click();
+                }
+                ctx.with_click_count(self.click_count)
+            }
+            _ => ctx,
+        }
+    }
+
+    /// Give canvas targets their local position with transforms undone
+    ///
+    /// Canvas pointer handlers hit-test their own drawing, so the point must
+    /// be in the space the render callback draws in.
+    fn with_canvas_local_pos(
+        &self,
+        ctx: crate::event_handler::EventContext,
+    ) -> crate::event_handler::EventContext {
+        let is_canvas = self
+            .render_nodes
+            .get(&ctx.node_id)
+            .is_some_and(|n| matches!(n.element_type, ElementType::Canvas(_)));
+        if !is_canvas {
+            return ctx;
+        }
+        match self.to_local_point(ctx.node_id, ctx.mouse_x, ctx.mouse_y) {
+            Some(local) => ctx.with_local_pos(local.x, local.y),
+            None => ctx,
+        }
+    }
+
+    /// Whether `node` or one of its ancestors is disabled
+    ///
+    /// Disabled subtrees (see [`Div::disabled`](crate::div::Div::disabled))
+    /// don't receive pointer or keyboard events.
+    pub fn is_disabled(&self, node: LayoutNodeId) -> bool {
+        let mut current = Some(node);
+        while let Some(id) = current {
+            if self.render_nodes.get(&id).is_some_and(|n| n.props.disabled) {
+                return true;
+            }
+            current = self.layout_tree.parent(id);
+        }
+        false
+    }
+
+    /// Whether `ancestor` is a strict ancestor of `node`
+    fn is_ancestor(&self, ancestor: LayoutNodeId, node: LayoutNodeId) -> bool {
+        let mut current = self.layout_tree.parent(node);
+        while let Some(parent) = current {
+            if parent == ancestor {
+                return true;
+            }
+            current = self.layout_tree.parent(parent);
+        }
+        false
+    }
+
+    /// Deliver custom events emitted by handlers with
+    /// [`EventContext::emit_custom`](crate::event_handler::EventContext::emit_custom)
+    ///
+    /// Each event runs the `on_custom` handlers of the emitting element, then
+    /// bubbles through its ancestors to the root until a handler stops
+    /// propagation. Events emitted along the way are delivered too. The
+    /// dispatch methods call this after running handlers.
+    pub fn dispatch_custom_events(&mut self) {
+        use crate::event_handler::EventPhase;
+        use blinc_core::events::event_types;
+
+        loop {
+            let events = crate::event_handler::take_custom_events();
+            if events.is_empty() {
+                return;
+            }
+            for event in events {
+                let mut current = Some(event.node_id);
+                let mut phase = EventPhase::AtTarget;
+                while let Some(node_id) = current {
+                    if self
+                        .handler_registry
+                        .has_custom_handler(node_id, event.type_id)
+                    {
+                        let ctx =
+                            crate::event_handler::EventContext::new(event_types::CUSTOM, node_id)
+                                .with_phase(phase);
+                        self.handler_registry.dispatch_custom(&*event.payload, &ctx);
+                        if ctx.is_propagation_stopped() {
+                            break;
+                        }
+                    }
+                    current = self.layout_tree.parent(node_id);
+                    phase = EventPhase::Bubbling;
+                }
+            }
+        }
+    }
+
+    /// Dispatch an event, running capture and bubble phases for pointer
+    /// down/up and drag events
+    ///
+    /// The router emits a bubbling event to its target and then once per
+    /// ancestor, leaf to root. An event on a strict ancestor of the node the
+    /// current chain last reached continues that chain (bubbling phase);
+    /// anything else starts a new chain, which first runs capture handlers
+    /// from the root down to the target's parent. Once a handler stops
+    /// propagation, the rest of the chain is skipped.
+    fn dispatch_propagating(&mut self, ctx: crate::event_handler::EventContext) {
+        use crate::event_handler::{event_bubbles, EventPhase};
+
+        let node_id = ctx.node_id;
+        let event_type = ctx.event_type;
+        let disabled = self.is_disabled(node_id);
+        if !event_bubbles(event_type) {
+            if !disabled && self.handler_registry.has_handler(node_id, event_type) {
+                self.handler_registry.dispatch(&ctx);
+            }
+            return;
+        }
+
+        let chain = self
+            .propagation
+            .filter(|p| p.event_type == event_type && self.is_ancestor(node_id, p.node));
+
+        if let Some(chain) = chain {
+            // Bubbling phase
+            let ctx = ctx.with_phase(EventPhase::Bubbling);
+            if !chain.stopped && !disabled && self.handler_registry.has_handler(node_id, event_type)
+            {
+                self.handler_registry.dispatch(&ctx);
+            }
+            self.propagation = Some(Propagation {
+                node: node_id,
+                stopped: chain.stopped || ctx.is_propagation_stopped(),
+                ..chain
+            });
+            return;
+        }
+
+        // New chain: capture phase from the root down to the target's parent
+        let mut path = Vec::new();
+        let mut current = self.layout_tree.parent(node_id);
+        while let Some(parent) = current {
+            path.push(parent);
+            current = self.layout_tree.parent(parent);
+        }
+        let mut stopped = false;
+        for &ancestor in path.iter().rev() {
+            if self
+                .handler_registry
+                .has_capture_handler(ancestor, event_type)
+            {
+                let capture_ctx = ctx.with_node(ancestor).with_phase(EventPhase::Capturing);
+                self.handler_registry.dispatch_capture(&capture_ctx);
+                if capture_ctx.is_propagation_stopped() {
+                    stopped = true;
+                    break;
+                }
+            }
+        }
+
+        if !stopped && !disabled && self.handler_registry.has_handler(node_id, event_type) {
             self.handler_registry.dispatch(&ctx);
-            // Don't auto-mark dirty - handlers update values in place
-            // Rebuild only when explicitly requested via State::set() or structural changes
+            stopped = ctx.is_propagation_stopped();
         }
+        self.propagation = Some(Propagation {
+            event_type,
+            node: node_id,
+            stopped,
+        });
+    }
+
+    /// Dispatch an event with local coordinates
+    ///
+    /// Dispatches an event to a node's handler.
+    ///
+    /// Note: This does NOT automatically mark the tree as dirty.
+    /// Handlers that need a rebuild should use EventContext::request_rebuild().
+    #[allow(clippy::too_many_arguments)]
+    pub fn dispatch_event_with_local(
+        &mut self,
+        node_id: LayoutNodeId,
+        event_type: blinc_core::events::EventType,
+        mouse_x: f32,
+        mouse_y: f32,
+        local_x: f32,
+        local_y: f32,
+        bounds_x: f32,
+        bounds_y: f32,
+        bounds_width: f32,
+        bounds_height: f32,
+    ) {
+        self.dispatch_event_full(
+            node_id,
+            event_type,
+            mouse_x,
+            mouse_y,
+            local_x,
+            local_y,
+            bounds_x,
+            bounds_y,
+            bounds_width,
+            bounds_height,
+            0.0,
+            0.0,
+        );
+    }
+
+    /// Dispatch an event with all context data including drag delta
+    ///
+    /// This is the full dispatch method that includes drag_delta for DRAG events.
+    #[allow(clippy::too_many_arguments)]
+    pub fn dispatch_event_full(
+        &mut self,
+        node_id: LayoutNodeId,
+        event_type: blinc_core::events::EventType,
+        mouse_x: f32,
+        mouse_y: f32,
+        local_x: f32,
+        local_y: f32,
+        bounds_x: f32,
+        bounds_y: f32,
+        bounds_width: f32,
+        bounds_height: f32,
+        drag_delta_x: f32,
+        drag_delta_y: f32,
+    ) {
+        let has_handler = self.handler_registry.has_handler(node_id, event_type);
+        tracing::debug!(
+            "dispatch_event_full: node={:?}, event_type={}, has_handler={}, drag_delta=({:.1}, {:.1})",
+            node_id,
+            event_type,
+            has_handler,
+            drag_delta_x,
+            drag_delta_y
+        );
+
+        let ctx = crate::event_handler::EventContext::new(event_type, node_id)
+            .with_mouse_pos(mouse_x, mouse_y)
+            .with_local_pos(local_x, local_y)
+            .with_bounds_pos(bounds_x, bounds_y)
+            .with_bounds(bounds_width, bounds_height)
+            .with_drag_delta(drag_delta_x, drag_delta_y);
+        let ctx = self.with_press_state(ctx);
+        let ctx = self.with_canvas_local_pos(ctx);
+
+        // Don't auto-mark dirty - handlers update values in place
+        // Rebuild only when explicitly requested via State::set() or structural changes
+        self.dispatch_propagating(ctx);
+        self.dispatch_custom_events();
     }
 
     /// Dispatch a text input event with character data
@@ -3155,14 +3830,16 @@ impl RenderTree {
         .with_key_char(key_char)
         .with_modifiers(shift, ctrl, alt, meta);
 
-        if self
-            .handler_registry
-            .has_handler(node_id, blinc_core::events::event_types::TEXT_INPUT)
+        if !self.is_disabled(node_id)
+            && self
+                .handler_registry
+                .has_handler(node_id, blinc_core::events::event_types::TEXT_INPUT)
         {
             self.handler_registry.dispatch(&ctx);
             // Don't auto-mark dirty - text input handler updates values in place
             // and calls State::set() which marks dirty if structural change needed
         }
+        self.dispatch_custom_events();
     }
 
     /// Dispatch a text input event with bubbling through ancestors
@@ -3182,15 +3859,17 @@ impl RenderTree {
 
         // Try each node in reverse order (leaf to root) until we find a handler
         for &node_id in ancestors.iter().rev() {
-            if self.handler_registry.has_handler(node_id, event_type) {
+            if self.handler_registry.has_handler(node_id, event_type) && !self.is_disabled(node_id)
+            {
                 let ctx = crate::event_handler::EventContext::new(event_type, node_id)
                     .with_key_char(key_char)
                     .with_modifiers(shift, ctrl, alt, meta);
                 self.handler_registry.dispatch(&ctx);
                 // Don't auto-mark dirty - handler updates state in place
-                return; // Stop after first handler found
+                break; // Stop after first handler found
             }
         }
+        self.dispatch_custom_events();
     }
 
     /// Dispatch a key event with key code and modifiers
@@ -3210,10 +3889,11 @@ impl RenderTree {
             .with_key_code(key_code)
             .with_modifiers(shift, ctrl, alt, meta);
 
-        if self.handler_registry.has_handler(node_id, event_type) {
+        if !self.is_disabled(node_id) && self.handler_registry.has_handler(node_id, event_type) {
             self.handler_registry.dispatch(&ctx);
             // Don't auto-mark dirty - handler updates state in place
         }
+        self.dispatch_custom_events();
     }
 
     /// Dispatch a key event with bubbling through ancestors
@@ -3232,15 +3912,17 @@ impl RenderTree {
     ) {
         // Try each node in reverse order (leaf to root) until we find a handler
         for &node_id in ancestors.iter().rev() {
-            if self.handler_registry.has_handler(node_id, event_type) {
+            if self.handler_registry.has_handler(node_id, event_type) && !self.is_disabled(node_id)
+            {
                 let ctx = crate::event_handler::EventContext::new(event_type, node_id)
                     .with_key_code(key_code)
                     .with_modifiers(shift, ctrl, alt, meta);
                 self.handler_registry.dispatch(&ctx);
                 // Don't auto-mark dirty - handler updates state in place
-                return; // Stop after first handler found
+                break; // Stop after first handler found
             }
         }
+        self.dispatch_custom_events();
     }
 
     /// Broadcast a text input event to ALL text input handlers
@@ -3263,8 +3945,12 @@ impl RenderTree {
         .with_key_char(key_char)
         .with_modifiers(shift, ctrl, alt, meta);
 
-        self.handler_registry
-            .broadcast(blinc_core::events::event_types::TEXT_INPUT, &ctx);
+        self.handler_registry.broadcast_filtered(
+            blinc_core::events::event_types::TEXT_INPUT,
+            &ctx,
+            |node| !self.is_disabled(node),
+        );
+        self.dispatch_custom_events();
     }
 
     /// Broadcast a key event to ALL key handlers
@@ -3287,7 +3973,9 @@ impl RenderTree {
         .with_key_code(key_code)
         .with_modifiers(shift, ctrl, alt, meta);
 
-        self.handler_registry.broadcast(event_type, &ctx);
+        self.handler_registry
+            .broadcast_filtered(event_type, &ctx, |node| !self.is_disabled(node));
+        self.dispatch_custom_events();
     }
 
     /// Dispatch a scroll event with scroll delta
@@ -3321,17 +4009,61 @@ impl RenderTree {
             self.handler_registry.dispatch(&ctx);
             // Don't mark dirty - scroll doesn't require tree rebuild
         }
+        self.dispatch_custom_events();
     }
 
     /// Dispatch scroll event through ancestor chain with consumption tracking
     ///
     /// For nested scrolls, inner scrolls consume delta for their direction,
-    /// and outer scrolls only receive the remaining delta.
+    /// and outer scrolls only receive the remaining delta. A scroll that
+    /// reaches its edge partway through the delta passes the rest on to the
+    /// nearest scrollable ancestor, unless it has
+    /// [`OverscrollBehavior::Contain`](crate::widgets::OverscrollBehavior::Contain).
     ///
     /// - `hit_node`: The innermost node under the cursor
     /// - `ancestors`: The ancestor chain from root to hit_node
     /// - Returns the remaining delta after all consumption
     pub fn dispatch_scroll_chain(
+        &mut self,
+        hit_node: LayoutNodeId,
+        ancestors: &[LayoutNodeId],
+        mouse_x: f32,
+        mouse_y: f32,
+        delta_x: f32,
+        delta_y: f32,
+    ) -> (f32, f32) {
+        self.dispatch_scroll_chain_inner(
+            hit_node, ancestors, mouse_x, mouse_y, delta_x, delta_y, None,
+        )
+    }
+
+    /// Dispatch scroll with time for touch velocity tracking (mobile)
+    ///
+    /// Same as dispatch_scroll_chain but includes time for momentum scrolling.
+    #[allow(clippy::too_many_arguments)]
+    pub fn dispatch_scroll_chain_with_time(
+        &mut self,
+        hit_node: LayoutNodeId,
+        ancestors: &[LayoutNodeId],
+        mouse_x: f32,
+        mouse_y: f32,
+        delta_x: f32,
+        delta_y: f32,
+        scroll_time: f64,
+    ) -> (f32, f32) {
+        self.dispatch_scroll_chain_inner(
+            hit_node,
+            ancestors,
+            mouse_x,
+            mouse_y,
+            delta_x,
+            delta_y,
+            Some(scroll_time),
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn dispatch_scroll_chain_inner(
         &mut self,
         hit_node: LayoutNodeId,
         ancestors: &[LayoutNodeId],
@@ -3339,6 +4071,7 @@ impl RenderTree {
         mouse_y: f32,
         mut delta_x: f32,
         mut delta_y: f32,
+        scroll_time: Option<f64>,
     ) -> (f32, f32) {
         // Build the chain from leaf to root (hit_node first, then ancestors in reverse)
         // ancestors is root to leaf, so we iterate in reverse and include hit_node
@@ -3357,60 +4090,74 @@ impl RenderTree {
             delta_y
         );
 
+        // Axes each scroll handler in the chain handles (None without a handler)
+        let axes: Vec<Option<(bool, bool)>> = chain
+            .iter()
+            .map(|&node_id| self.scroll_axes(node_id))
+            .collect();
+
         // Dispatch to each node in the chain
-        for node_id in chain {
+        for (index, &node_id) in chain.iter().enumerate() {
             // Skip if no remaining delta
             if delta_x.abs() < 0.001 && delta_y.abs() < 0.001 {
                 break;
             }
 
-            // Check if this node has a scroll handler
-            let has_handler = self
-                .handler_registry
-                .has_handler(node_id, blinc_core::events::event_types::SCROLL);
-
-            if !has_handler {
+            let Some((handles_x, handles_y)) = axes[index] else {
                 continue;
-            }
-
-            // Get direction and check what this scroll can consume
+            };
             let direction = self.get_scroll_direction(node_id);
-            let (can_consume_x, can_consume_y) = self.can_consume_scroll(node_id, delta_x, delta_y);
-
-            // Determine if this scroll handles each axis (based on direction)
-            // If no direction (custom scroll handler like TextArea), dispatch full delta
-            let has_scroll_physics = direction.is_some();
-            let handles_x = direction.map_or(true, |d| {
-                matches!(
-                    d,
-                    crate::scroll::ScrollDirection::Horizontal
-                        | crate::scroll::ScrollDirection::Both
-                )
-            });
-            let handles_y = direction.map_or(true, |d| {
-                matches!(
-                    d,
-                    crate::scroll::ScrollDirection::Vertical | crate::scroll::ScrollDirection::Both
-                )
-            });
 
-            // Dispatch the remaining delta for axes this scroll handles
-            let dispatch_x = if handles_x { delta_x } else { 0.0 };
-            let dispatch_y = if handles_y { delta_y } else { 0.0 };
+            // How much of the delta this scroll takes before reaching its edge.
+            // Custom scroll handlers (no physics) take everything they handle.
+            let (taken_x, taken_y) = match self.scroll_room(node_id, delta_x, delta_y) {
+                Some((room_x, room_y)) => (
+                    if handles_x { room_x } else { 0.0 },
+                    if handles_y { room_y } else { 0.0 },
+                ),
+                None => (
+                    if handles_x { delta_x } else { 0.0 },
+                    if handles_y { delta_y } else { 0.0 },
+                ),
+            };
+
+            // Give the scroll only what it can take, so a partial scroll stops
+            // exactly at the edge. A scroll already at its edge gets the full
+            // delta for its overscroll bounce only if no outer scroll on that
+            // axis will take it, so only the outermost scroll bounces.
+            let contained = self.overscroll_contained(node_id);
+            let outer = &axes[index + 1..];
+            let bounce_x = contained || !outer.iter().flatten().any(|&(x, _)| x);
+            let bounce_y = contained || !outer.iter().flatten().any(|&(_, y)| y);
+            let dispatch_x = match (handles_x, taken_x.abs() > 0.001) {
+                (false, _) => 0.0,
+                (true, true) => taken_x,
+                (true, false) if bounce_x => delta_x,
+                (true, false) => 0.0,
+            };
+            let dispatch_y = match (handles_y, taken_y.abs() > 0.001) {
+                (false, _) => 0.0,
+                (true, true) => taken_y,
+                (true, false) if bounce_y => delta_y,
+                (true, false) => 0.0,
+            };
 
             tracing::trace!(
-                "  node={:?}, direction={:?}, handles=({}, {}), can_consume=({}, {}), dispatch=({:.1}, {:.1})",
-                node_id, direction, handles_x, handles_y, can_consume_x, can_consume_y, dispatch_x, dispatch_y
+                "  node={:?}, direction={:?}, handles=({}, {}), taken=({:.1}, {:.1}), dispatch=({:.1}, {:.1})",
+                node_id, direction, handles_x, handles_y, taken_x, taken_y, dispatch_x, dispatch_y
             );
 
             // Dispatch if there's delta for this scroll's direction
             if dispatch_x.abs() > 0.001 || dispatch_y.abs() > 0.001 {
-                let ctx = crate::event_handler::EventContext::new(
+                let mut ctx = crate::event_handler::EventContext::new(
                     blinc_core::events::event_types::SCROLL,
                     node_id,
                 )
                 .with_mouse_pos(mouse_x, mouse_y)
                 .with_scroll_delta(dispatch_x, dispatch_y);
+                if let Some(time) = scroll_time {
+                    ctx = ctx.with_scroll_time(time);
+                }
 
                 tracing::trace!(
                     "    dispatching to {:?}: delta=({:.1}, {:.1})",
@@ -3419,121 +4166,106 @@ impl RenderTree {
                     dispatch_y
                 );
                 self.handler_registry.dispatch(&ctx);
+            }
 
-                // Consume the delta for axes this scroll CAN consume (has room to scroll)
-                // This prevents bubbling to outer scrolls for that axis
-                // For custom scroll handlers (no physics), consume all dispatched delta
-                if has_scroll_physics {
-                    if can_consume_x && handles_x {
-                        delta_x = 0.0;
-                    }
-                    if can_consume_y && handles_y {
-                        delta_y = 0.0;
-                    }
-                } else {
-                    // Custom scroll handler - consume all delta (it handles its own bounds)
-                    if handles_x {
-                        delta_x = 0.0;
-                    }
-                    if handles_y {
-                        delta_y = 0.0;
-                    }
+            // Only the remainder bubbles on to outer scrolls
+            if contained {
+                if handles_x {
+                    delta_x = 0.0;
+                }
+                if handles_y {
+                    delta_y = 0.0;
                 }
+            } else {
+                delta_x -= taken_x;
+                delta_y -= taken_y;
             }
         }
+        self.dispatch_custom_events();
 
         (delta_x, delta_y)
     }
 
-    /// Dispatch scroll with time for touch velocity tracking (mobile)
+    /// Which axes (x, y) a node's scroll handler handles, or None without one
     ///
-    /// Same as dispatch_scroll_chain but includes time for momentum scrolling.
-    pub fn dispatch_scroll_chain_with_time(
-        &mut self,
-        hit_node: LayoutNodeId,
-        ancestors: &[LayoutNodeId],
-        mouse_x: f32,
-        mouse_y: f32,
-        delta_x: f32,
-        delta_y: f32,
-        scroll_time: f64,
-    ) -> (f32, f32) {
-        // Build the chain from leaf to root
-        let mut chain: Vec<LayoutNodeId> = vec![hit_node];
-        for &ancestor in ancestors.iter().rev() {
-            if ancestor != hit_node {
-                chain.push(ancestor);
-            }
-        }
-
-        let mut remaining_dx = delta_x;
-        let mut remaining_dy = delta_y;
-
-        for node_id in chain {
-            if remaining_dx.abs() < 0.001 && remaining_dy.abs() < 0.001 {
-                break;
-            }
-
-            let has_handler = self
-                .handler_registry
-                .has_handler(node_id, blinc_core::events::event_types::SCROLL);
-
-            if !has_handler {
-                continue;
-            }
-
-            let direction = self.get_scroll_direction(node_id);
-            let (can_consume_x, can_consume_y) =
-                self.can_consume_scroll(node_id, remaining_dx, remaining_dy);
-
-            let has_scroll_physics = direction.is_some();
-            let handles_x = direction.map_or(true, |d| {
-                matches!(
-                    d,
-                    crate::scroll::ScrollDirection::Horizontal
-                        | crate::scroll::ScrollDirection::Both
-                )
-            });
-            let handles_y = direction.map_or(true, |d| {
-                matches!(
-                    d,
-                    crate::scroll::ScrollDirection::Vertical | crate::scroll::ScrollDirection::Both
-                )
-            });
-
-            let dispatch_x = if handles_x { remaining_dx } else { 0.0 };
-            let dispatch_y = if handles_y { remaining_dy } else { 0.0 };
-
-            if dispatch_x.abs() > 0.001 || dispatch_y.abs() > 0.001 {
-                let ctx = crate::event_handler::EventContext::new(
-                    blinc_core::events::event_types::SCROLL,
-                    node_id,
-                )
-                .with_mouse_pos(mouse_x, mouse_y)
-                .with_scroll_delta(dispatch_x, dispatch_y)
-                .with_scroll_time(scroll_time);
+    /// Custom scroll handlers without a direction (e.g. TextArea) handle both.
+    fn scroll_axes(&self, node_id: LayoutNodeId) -> Option<(bool, bool)> {
+        if !self
+            .handler_registry
+            .has_handler(node_id, blinc_core::events::event_types::SCROLL)
+        {
+            return None;
+        }
+        let direction = self.get_scroll_direction(node_id);
+        let handles_x = direction.map_or(true, |d| {
+            matches!(
+                d,
+                crate::scroll::ScrollDirection::Horizontal | crate::scroll::ScrollDirection::Both
+            )
+        });
+        let handles_y = direction.map_or(true, |d| {
+            matches!(
+                d,
+                crate::scroll::ScrollDirection::Vertical | crate::scroll::ScrollDirection::Both
+            )
+        });
+        Some((handles_x, handles_y))
+    }
 
-                self.handler_registry.dispatch(&ctx);
+    /// How much of a scroll delta a scroll container takes before its edge
+    ///
+    /// An axis that is already overscrolling (bouncing) takes the whole
+    /// delta. Returns None for nodes without scroll physics.
+    fn scroll_room(&self, node_id: LayoutNodeId, delta_x: f32, delta_y: f32) -> Option<(f32, f32)> {
+        let physics = self.scroll_physics.get(&node_id)?;
+        let Ok(p) = physics.try_lock() else {
+            return Some((0.0, 0.0));
+        };
 
-                if has_scroll_physics {
-                    if can_consume_x && handles_x {
-                        remaining_dx = 0.0;
-                    }
-                    if can_consume_y && handles_y {
-                        remaining_dy = 0.0;
-                    }
-                } else {
-                    if handles_x {
-                        remaining_dx = 0.0;
-                    }
-                    if handles_y {
-                        remaining_dy = 0.0;
-                    }
-                }
+        // Offsets run from 0 (start edge) down to max_offset (end edge)
+        let room = |delta: f32, offset: f32, end: f32, overscrolling: bool| {
+            if overscrolling {
+                delta
+            } else {
+                (offset + delta).clamp(end, 0.0) - offset
             }
-        }
+        };
+        let room_x = if matches!(
+            p.config.direction,
+            crate::scroll::ScrollDirection::Horizontal | crate::scroll::ScrollDirection::Both
+        ) {
+            room(
+                delta_x,
+                p.offset_x,
+                p.max_offset_x(),
+                p.is_overscrolling_x(),
+            )
+        } else {
+            0.0
+        };
+        let room_y = if matches!(
+            p.config.direction,
+            crate::scroll::ScrollDirection::Vertical | crate::scroll::ScrollDirection::Both
+        ) {
+            room(
+                delta_y,
+                p.offset_y,
+                p.max_offset_y(),
+                p.is_overscrolling_y(),
+            )
+        } else {
+            0.0
+        };
+        Some((room_x, room_y))
+    }
 
-        (remaining_dx, remaining_dy)
+    /// Whether a scroll container keeps leftover scroll input from its ancestors
+    fn overscroll_contained(&self, node_id: LayoutNodeId) -> bool {
+        self.scroll_physics.get(&node_id).is_some_and(|physics| {
+            physics.try_lock().is_ok_and(|p| {
+                p.config.overscroll_behavior == crate::scroll::OverscrollBehavior::Contain
+            })
+        })
     }
 
     // =========================================================================
@@ -4073,11 +4805,48 @@ impl RenderTree {
     where
         F: FnOnce(&mut RenderProps),
     {
+        self.invalidate_layer_content(node_id);
         if let Some(render_node) = self.render_nodes.get_mut(&node_id) {
             f(&mut render_node.props);
+            self.patched.mark(node_id);
         }
     }
 
+    /// Advance the frame timing passed to canvas render callbacks
+    ///
+    /// Call once per frame, before rendering, with the frame's timestamp.
+    pub fn begin_canvas_frame(&mut self, time_ms: u64) {
+        self.canvas_frame.advance(time_ms);
+    }
+
+    /// Frame timing for the current frame
+    pub fn canvas_frame(&self) -> &crate::canvas::CanvasFrameInfo {
+        &self.canvas_frame
+    }
+
+    /// Whether a canvas asked for another frame while rendering this one
+    pub fn canvas_animation_requested(&self) -> bool {
+        self.canvas_frame.animation_requested()
+    }
+
+    /// Continue canvas frame timing from another tree
+    ///
+    /// Call when replacing a tree with a fresh one so canvas time doesn't
+    /// restart at zero.
+    pub fn transfer_canvas_frame_from(&mut self, other: &RenderTree) {
+        self.canvas_frame = other.canvas_frame.clone();
+    }
+
+    /// Take the nodes patched in place since the last call
+    ///
+    /// Stateful prop updates and visual-only subtree updates change render
+    /// props without rebuilding; these are the nodes they touched, e.g. for
+    /// [`damage_rect`](Self::damage_rect). Nodes rebuilt by a structural
+    /// change are reported through their parent.
+    pub fn take_patched_nodes(&mut self) -> Vec<LayoutNodeId> {
+        self.patched.take_dirty()
+    }
+
     // =========================================================================
     // Stylesheet Integration
     // =========================================================================
@@ -4166,7 +4935,7 @@ impl RenderTree {
         };
 
         // Reset to base style first
-        render_node.props = base_props;
+        let previous_props = std::mem::replace(&mut render_node.props, base_props);
 
         // Apply base stylesheet style (if any)
         if let Some(base_style) = stylesheet.get(&element_id) {
@@ -4199,6 +4968,10 @@ impl RenderTree {
             }
         }
 
+        if !render_props_eq(&previous_props, &render_node.props) {
+            self.layer_cache.lock().unwrap().invalidate_content(node_id);
+        }
+
         applied
     }
 
@@ -4324,6 +5097,8 @@ impl RenderTree {
         parent_id: LayoutNodeId,
         new_child: &E,
     ) -> LayoutNodeId {
+        self.invalidate_layer_content(parent_id);
+
         // 1. Remove old children from layout tree and render nodes
         let old_children = self.layout_tree.children(parent_id);
         for child_id in &old_children {
@@ -4340,7 +5115,374 @@ impl RenderTree {
         // 4. Collect render props for the new subtree
         self.collect_render_props(new_child, new_child_id);
 
-        new_child_id
+        new_child_id
+    }
+
+    /// Track hover/focus-within styles for a node
+    ///
+    /// Called once the node's props are built. If the styles are active
+    /// (a rebuild while hovered), they're applied over the new props.
+    fn register_within_styles(&mut self, node_id: LayoutNodeId, styles: &WithinStyles) {
+        let Some(render_node) = self.render_nodes.get_mut(&node_id) else {
+            return;
+        };
+        let (hovered, focused) = self
+            .within_styles
+            .get(&node_id)
+            .map_or((false, false), |state| (state.hovered, state.focused));
+        let state = WithinState {
+            styles: styles.clone(),
+            base: render_node.props.clone(),
+            hovered,
+            focused,
+        };
+        Self::apply_within_state(&mut render_node.props, &state);
+        self.within_styles.insert(node_id, state);
+    }
+
+    /// Clip shape for a `clips_content` node's children, in local coordinates
+    ///
+    /// Clips to the padding box like CSS `overflow: hidden`: the bounds are
+    /// inset by each side's border width, and each corner radius shrinks by
+    /// the wider of its two adjacent borders. Any rounded corner makes the
+    /// clip a rounded rect, so rounded scroll containers keep full-bleed
+    /// children inside their corners.
+    fn content_clip_shape(props: &RenderProps, width: f32, height: f32) -> ClipShape {
+        let sides = &props.border_sides;
+        let side = |border: &Option<BorderSide>| {
+            border
+                .as_ref()
+                .map(|b| b.width)
+                .unwrap_or(props.border_width)
+        };
+        let (left, right) = (side(&sides.left), side(&sides.right));
+        let (top, bottom) = (side(&sides.top), side(&sides.bottom));
+
+        let clip_rect = Rect::new(
+            left,
+            top,
+            (width - left - right).max(0.0),
+            (height - top - bottom).max(0.0),
+        );
+        let radius = props.border_radius;
+        let inset_radius = CornerRadius::new(
+            (radius.top_left - left.max(top)).max(0.0),
+            (radius.top_right - right.max(top)).max(0.0),
+            (radius.bottom_right - right.max(bottom)).max(0.0),
+            (radius.bottom_left - left.max(bottom)).max(0.0),
+        );
+        if inset_radius != CornerRadius::ZERO {
+            ClipShape::rounded_rect(clip_rect, inset_radius)
+        } else {
+            ClipShape::rect(clip_rect)
+        }
+    }
+
+    /// Layer the active within styles over a node's base props
+    fn apply_within_state(props: &mut RenderProps, state: &WithinState) {
+        *props = state.base.clone();
+        if state.hovered {
+            if let Some(style) = &state.styles.hover {
+                Self::apply_element_style_to_props(props, style);
+            }
+        }
+        if state.focused {
+            if let Some(style) = &state.styles.focus {
+                Self::apply_element_style_to_props(props, style);
+            }
+        }
+    }
+
+    /// Apply `on_hover_within` / `on_focus_within` styles from router state
+    ///
+    /// A node's hover style applies while it or any descendant is hovered,
+    /// and its focus style while it or any descendant has focus. Each
+    /// hovered or focused node's ancestors are walked, so nested containers
+    /// update independently. Call after routing pointer events.
+    ///
+    /// Returns `true` if any node was restyled.
+    pub fn apply_within_styles(&mut self, router: &crate::event_router::EventRouter) -> bool {
+        if self.within_styles.is_empty() {
+            return false;
+        }
+
+        let collect_ancestors = |start: &mut dyn Iterator<Item = LayoutNodeId>| {
+            let mut nodes = std::collections::HashSet::new();
+            for node in start {
+                let mut current = Some(node);
+                while let Some(id) = current {
+                    if !nodes.insert(id) {
+                        break;
+                    }
+                    current = self.layout_tree.parent(id);
+                }
+            }
+            nodes
+        };
+        let hover_within = collect_ancestors(&mut router.hovered_nodes());
+        let focus_within = collect_ancestors(&mut router.focused().into_iter());
+
+        let mut changed = Vec::new();
+        for (&node_id, state) in &mut self.within_styles {
+            let hovered = state.styles.hover.is_some() && hover_within.contains(&node_id);
+            let focused = state.styles.focus.is_some() && focus_within.contains(&node_id);
+            if (hovered, focused) == (state.hovered, state.focused) {
+                continue;
+            }
+            state.hovered = hovered;
+            state.focused = focused;
+            if let Some(render_node) = self.render_nodes.get_mut(&node_id) {
+                Self::apply_within_state(&mut render_node.props, state);
+                changed.push(node_id);
+            }
+        }
+
+        for &node_id in &changed {
+            self.invalidate_layer_content(node_id);
+        }
+        !changed.is_empty()
+    }
+
+    /// Track deferred content for a node
+    fn register_lazy_content(&mut self, node_id: LayoutNodeId, content: &LazyContent) {
+        if let Some(lazy) = self.lazy_mounts.get_mut(&node_id) {
+            lazy.content = content.clone();
+            return;
+        }
+        let style = self.layout_tree.get_style(node_id).unwrap_or_default();
+        self.lazy_mounts.insert(
+            node_id,
+            LazyMount {
+                content: content.clone(),
+                mounted: None,
+                style,
+            },
+        );
+    }
+
+    /// Mount deferred subtrees near the viewport and unmount distant ones
+    ///
+    /// Content mounts once its element is within the overscan margin of
+    /// the visible area (the viewport clipped by ancestor scroll
+    /// containers) and unmounts beyond twice that, so an element sitting
+    /// at the edge doesn't flip every frame. Unmounted elements keep their
+    /// last laid out size.
+    ///
+    /// Called by `compute_layout`; call it after scrolling and run
+    /// `compute_layout` again if it returns `true`.
+    pub fn update_lazy_mounts(&mut self) -> bool {
+        if self.lazy_mounts.is_empty() {
+            return false;
+        }
+
+        let mut to_mount = Vec::new();
+        let mut to_unmount = Vec::new();
+        for (&node_id, lazy) in &self.lazy_mounts {
+            // Content dropped by a rebuild of this element counts as unmounted
+            let mounted = lazy
+                .mounted
+                .is_some_and(|child| self.layout_tree.parent(child) == Some(node_id));
+            let Some(distance) = self.distance_from_view(node_id) else {
+                continue;
+            };
+            let overscan = lazy.content.overscan();
+            if !mounted && distance <= overscan {
+                to_mount.push(node_id);
+            } else if mounted && distance > overscan * 2.0 {
+                to_unmount.push(node_id);
+            }
+        }
+
+        for &node_id in &to_mount {
+            self.mount_lazy(node_id);
+        }
+        for &node_id in &to_unmount {
+            self.unmount_lazy(node_id);
+        }
+        !to_mount.is_empty() || !to_unmount.is_empty()
+    }
+
+    /// Whether a node's deferred content is currently built
+    pub fn is_lazy_mounted(&self, node_id: LayoutNodeId) -> bool {
+        self.lazy_mounts
+            .get(&node_id)
+            .and_then(|lazy| lazy.mounted)
+            .is_some_and(|child| self.layout_tree.parent(child) == Some(node_id))
+    }
+
+    /// How far a node is from the area it can be seen through, in pixels
+    ///
+    /// 0 when it overlaps the viewport (clipped by ancestor scroll
+    /// containers); `None` before the first layout.
+    fn distance_from_view(&self, node_id: LayoutNodeId) -> Option<f32> {
+        let (width, height) = self.viewport_size?;
+        let mut path = vec![node_id];
+        while let Some(parent) = self.layout_tree.parent(*path.last().unwrap()) {
+            path.push(parent);
+        }
+        path.reverse();
+
+        let (mut left, mut top, mut right, mut bottom) = (0.0f32, 0.0f32, width, height);
+        let mut offset = (0.0, 0.0);
+        for &ancestor in &path[..path.len() - 1] {
+            let bounds = self.layout_tree.get_bounds(ancestor, offset)?;
+            if self.scroll_physics.contains_key(&ancestor) {
+                left = left.max(bounds.x);
+                top = top.max(bounds.y);
+                right = right.min(bounds.x + bounds.width);
+                bottom = bottom.min(bounds.y + bounds.height);
+            }
+            let scroll = self.get_scroll_offset(ancestor);
+            offset = (bounds.x + scroll.0, bounds.y + scroll.1);
+        }
+
+        let bounds = self.layout_tree.get_bounds(node_id, offset)?;
+        let dx = (left - (bounds.x + bounds.width))
+            .max(bounds.x - right)
+            .max(0.0);
+        let dy = (top - (bounds.y + bounds.height))
+            .max(bounds.y - bottom)
+            .max(0.0);
+        Some(dx.max(dy))
+    }
+
+    /// Build a node's deferred content and add it as its last child
+    fn mount_lazy(&mut self, node_id: LayoutNodeId) {
+        let Some(lazy) = self.lazy_mounts.get(&node_id) else {
+            return;
+        };
+        let content = lazy.content.build();
+        self.layout_tree.set_style(node_id, lazy.style.clone());
+
+        let child_id = content.build(&mut self.layout_tree);
+        self.layout_tree.add_child(node_id, child_id);
+        self.collect_render_props(&content, child_id);
+        self.invalidate_layer_content(node_id);
+
+        if let Some(lazy) = self.lazy_mounts.get_mut(&node_id) {
+            lazy.mounted = Some(child_id);
+        }
+        tracing::trace!("Mounted lazy content {:?} under {:?}", child_id, node_id);
+    }
+
+    /// Drop a node's deferred content, keeping the node at its current size
+    fn unmount_lazy(&mut self, node_id: LayoutNodeId) {
+        let Some(child_id) = self
+            .lazy_mounts
+            .get_mut(&node_id)
+            .and_then(|lazy| lazy.mounted.take())
+        else {
+            return;
+        };
+
+        let size = self
+            .layout_tree
+            .get_layout(node_id)
+            .map(|layout| (layout.size.width, layout.size.height));
+        self.remove_subtree_nodes(child_id);
+        self.layout_tree.remove_subtree(child_id);
+        self.invalidate_layer_content(node_id);
+
+        if let (Some((width, height)), Some(lazy)) = (size, self.lazy_mounts.get(&node_id)) {
+            let mut placeholder = lazy.style.clone();
+            placeholder.size = Size {
+                width: Dimension::Length(width),
+                height: Dimension::Length(height),
+            };
+            self.layout_tree.set_style(node_id, placeholder);
+        }
+        tracing::trace!("Unmounted lazy content {:?} under {:?}", child_id, node_id);
+    }
+
+    /// Track width-dependent content for a node
+    fn register_responsive_content(&mut self, node_id: LayoutNodeId, content: &ResponsiveContent) {
+        match self.responsive_mounts.get_mut(&node_id) {
+            Some(responsive) => responsive.content = content.clone(),
+            None => {
+                self.responsive_mounts.insert(
+                    node_id,
+                    ResponsiveMount {
+                        content: content.clone(),
+                        mounted: None,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Build responsive content whose element's width moved into another
+    /// breakpoint range
+    ///
+    /// Content is first built once its element has been laid out, and is
+    /// rebuilt when the width leaves the range it was built for by more
+    /// than the hysteresis.
+    ///
+    /// Called by `compute_layout`; run `compute_layout` again if it
+    /// returns `true`.
+    pub fn update_responsive_mounts(&mut self) -> bool {
+        let mut to_build = Vec::new();
+        for (&node_id, responsive) in &self.responsive_mounts {
+            let Some(layout) = self.layout_tree.get_layout(node_id) else {
+                continue;
+            };
+            let width = layout.size.width;
+            // Content dropped by a rebuild of this element counts as unbuilt
+            let built = responsive
+                .mounted
+                .filter(|&(child, _)| self.layout_tree.parent(child) == Some(node_id));
+            let range = match built {
+                Some((_, range)) => responsive.content.next_range(range, width),
+                None => responsive.content.range(width),
+            };
+            if built.map(|(_, built_range)| built_range) != Some(range) {
+                to_build.push((node_id, width, range));
+            }
+        }
+
+        for &(node_id, width, range) in &to_build {
+            self.mount_responsive(node_id, width, range);
+        }
+        !to_build.is_empty()
+    }
+
+    /// Breakpoint range a node's responsive content was last built for
+    pub fn responsive_range(&self, node_id: LayoutNodeId) -> Option<usize> {
+        self.responsive_mounts
+            .get(&node_id)?
+            .mounted
+            .filter(|&(child, _)| self.layout_tree.parent(child) == Some(node_id))
+            .map(|(_, range)| range)
+    }
+
+    /// Replace a node's responsive content with content built for `width`
+    fn mount_responsive(&mut self, node_id: LayoutNodeId, width: f32, range: usize) {
+        let Some(responsive) = self.responsive_mounts.get_mut(&node_id) else {
+            return;
+        };
+        let previous = responsive.mounted.take();
+        let content = responsive.content.build(width);
+
+        if let Some((child_id, _)) = previous {
+            if self.layout_tree.parent(child_id) == Some(node_id) {
+                self.remove_subtree_nodes(child_id);
+                self.layout_tree.remove_subtree(child_id);
+            }
+        }
+
+        let child_id = content.build(&mut self.layout_tree);
+        self.layout_tree.add_child(node_id, child_id);
+        self.collect_render_props(&content, child_id);
+        self.invalidate_layer_content(node_id);
+
+        if let Some(responsive) = self.responsive_mounts.get_mut(&node_id) {
+            responsive.mounted = Some((child_id, range));
+        }
+        tracing::trace!(
+            "Built responsive content {:?} under {:?} for width {}",
+            child_id,
+            node_id,
+            width
+        );
     }
 
     /// Remove render nodes for a subtree (but don't touch layout tree)
@@ -4352,12 +5494,16 @@ impl RenderTree {
         }
 
         // Remove this node's render data
+        self.layer_cache.lock().unwrap().invalidate(node_id);
         self.render_nodes.swap_remove(&node_id);
         self.handler_registry.remove(node_id);
         self.node_states.remove(&node_id);
         self.scroll_offsets.remove(&node_id);
         self.scroll_physics.remove(&node_id);
         self.scroll_refs.remove(&node_id);
+        self.lazy_mounts.remove(&node_id);
+        self.responsive_mounts.remove(&node_id);
+        self.within_styles.remove(&node_id);
         // Unregister from element registry (removes by node_id)
         self.element_registry.unregister(node_id);
         // Remove layout animation config (but keep stable-key animations running)
@@ -4403,7 +5549,21 @@ impl RenderTree {
                 rebuild.parent_id,
                 rebuild.needs_layout
             );
-            if rebuild.needs_layout {
+            self.invalidate_layer_content(rebuild.parent_id);
+            if rebuild.scoped {
+                // Diff against the existing subtree and patch it in place
+                needs_layout = true;
+                if let Some(render_node) = self.render_nodes.get_mut(&rebuild.parent_id) {
+                    let mut new_props = rebuild.new_child.render_props();
+                    new_props.node_id = Some(rebuild.parent_id);
+                    new_props.motion = render_node.props.motion.clone();
+                    render_node.props = new_props;
+                }
+                if let Some(style) = rebuild.new_child.layout_style() {
+                    self.layout_tree.set_style(rebuild.parent_id, style.clone());
+                }
+                self.patch_children(rebuild.parent_id, rebuild.new_child.children_builders());
+            } else if rebuild.needs_layout {
                 // Full structural rebuild - remove old children and build new ones
                 needs_layout = true;
 
@@ -4435,10 +5595,19 @@ impl RenderTree {
                     self.layout_tree.add_child(rebuild.parent_id, child_id);
                     self.collect_render_props_boxed(child.as_ref(), child_id);
                 }
-            } else {
+            } else if self.subtree_structure_matches(rebuild.parent_id, &rebuild.new_child) {
                 // Visual-only update - just update render props of existing children
                 // Don't remove/rebuild, just walk the tree and update props
                 self.update_subtree_props_recursive(rebuild.parent_id, &rebuild.new_child);
+            } else {
+                // The state callback added or removed children - patching
+                // would drop them, so rebuild this element's children only
+                needs_layout = true;
+                self.patched.mark(rebuild.parent_id);
+                self.rebuild_children_in_place(
+                    rebuild.parent_id,
+                    rebuild.new_child.children_builders(),
+                );
             }
         }
 
@@ -4450,6 +5619,71 @@ impl RenderTree {
         needs_layout
     }
 
+    /// Patch existing children from new builders, rebuilding only on structural change
+    ///
+    /// If the child count or any child's element type differs, all children of
+    /// `parent_id` are rebuilt. Otherwise each child whose tree hash changed is
+    /// patched in place, keeping its node (and everything attached to it).
+    fn patch_children(
+        &mut self,
+        parent_id: LayoutNodeId,
+        new_children: &[Box<dyn ElementBuilder>],
+    ) {
+        let old_children = self.layout_tree.children(parent_id);
+        let same_structure = old_children.len() == new_children.len()
+            && old_children
+                .iter()
+                .zip(new_children)
+                .all(|(child_id, child)| {
+                    self.render_nodes.get(child_id).is_some_and(|node| {
+                        std::mem::discriminant(&node.element_type)
+                            == std::mem::discriminant(&Self::determine_element_type_boxed(
+                                child.as_ref(),
+                            ))
+                    })
+                });
+        if !same_structure {
+            self.rebuild_children_in_place(parent_id, new_children);
+            return;
+        }
+
+        for (child, &child_id) in new_children.iter().zip(old_children.iter()) {
+            let new_tree_hash = DivHash::compute_element_tree(child.as_ref());
+            if self.node_hashes.get(&child_id).map(|&(_, tree)| tree) == Some(new_tree_hash) {
+                continue;
+            }
+            self.patch_node(child.as_ref(), child_id);
+        }
+    }
+
+    /// Replace one node's render state in place, then patch its children
+    fn patch_node(&mut self, element: &dyn ElementBuilder, node_id: LayoutNodeId) {
+        self.invalidate_layer_content(node_id);
+
+        if let Some(render_node) = self.render_nodes.get_mut(&node_id) {
+            let mut new_props = element.render_props();
+            new_props.node_id = Some(node_id);
+            new_props.motion = render_node.props.motion.clone();
+            render_node.props = new_props;
+            // Text content, SVG source, etc. live in the element type
+            render_node.element_type = Self::determine_element_type_boxed(element);
+            self.patched.mark(node_id);
+        }
+        if let Some(style) = element.layout_style() {
+            self.layout_tree.set_style(node_id, style.clone());
+        }
+
+        let own_hash = DivHash::compute_element(element);
+        let tree_hash = DivHash::compute_element_tree(element);
+        self.node_hashes.insert(node_id, (own_hash, tree_hash));
+
+        if let Some(handlers) = element.event_handlers() {
+            self.handler_registry.register(node_id, handlers.clone());
+        }
+
+        self.patch_children(node_id, element.children_builders());
+    }
+
     /// Recursively update render props for existing children without rebuilding
     ///
     /// This walks the existing layout tree children alongside the new element definition
@@ -4462,12 +5696,36 @@ impl RenderTree {
         self.update_subtree_props_from_builder(parent_id, new_element);
     }
 
+    /// Whether the existing subtree under `node_id` has the same shape as
+    /// `element`'s children (child counts and element types, recursively)
+    fn subtree_structure_matches(
+        &self,
+        node_id: LayoutNodeId,
+        element: &dyn crate::div::ElementBuilder,
+    ) -> bool {
+        let existing_children = self.layout_tree.children(node_id);
+        let new_children = element.children_builders();
+        existing_children.len() == new_children.len()
+            && existing_children
+                .iter()
+                .zip(new_children)
+                .all(|(child_id, child)| {
+                    self.render_nodes.get(child_id).is_some_and(|node| {
+                        std::mem::discriminant(&node.element_type)
+                            == std::mem::discriminant(&Self::determine_element_type_boxed(
+                                child.as_ref(),
+                            ))
+                    }) && self.subtree_structure_matches(*child_id, child.as_ref())
+                })
+    }
+
     /// Update subtree props from a generic ElementBuilder (for recursion)
     fn update_subtree_props_from_builder(
         &mut self,
         parent_id: LayoutNodeId,
         new_element: &dyn crate::div::ElementBuilder,
     ) {
+        self.invalidate_layer_content(parent_id);
         let existing_children = self.layout_tree.children(parent_id);
         let new_children = new_element.children_builders();
 
@@ -4477,6 +5735,7 @@ impl RenderTree {
                 let new_props = new_child.render_props();
                 if let Some(render_node) = self.render_nodes.get_mut(child_id) {
                     render_node.props.merge_from(&new_props);
+                    self.patched.mark(*child_id);
                 }
 
                 // Recursively update grandchildren
@@ -4508,8 +5767,8 @@ impl RenderTree {
             "render: motion_bindings count = {}",
             self.motion_bindings.len()
         );
-        if let Some(root) = self.root {
-            self.render_node(ctx, root, (0.0, 0.0));
+        for (node, offset) in self.paint_roots() {
+            self.render_node(ctx, node, offset);
         }
     }
 
@@ -4520,7 +5779,7 @@ impl RenderTree {
         node: LayoutNodeId,
         parent_offset: (f32, f32),
     ) {
-        let Some(bounds) = self.layout_tree.get_bounds(node, parent_offset) else {
+        let Some(bounds) = self.paint_bounds(node, parent_offset) else {
             return;
         };
 
@@ -4775,56 +6034,28 @@ impl RenderTree {
             }
         }
 
+        // Handle canvas element rendering
+        // Note: No clip applied - canvas elements like notch() may draw outside bounds
+        if let ElementType::Canvas(canvas_data) = &render_node.element_type {
+            if let Some(render_fn) = &canvas_data.render_fn {
+                let canvas_bounds = crate::canvas::CanvasBounds {
+                    width: bounds.width,
+                    height: bounds.height,
+                };
+                render_fn(ctx, canvas_bounds, &self.canvas_frame);
+            }
+        }
+
         // Push clip if this element clips its children (e.g., scroll containers)
         // Clip to content area (inset by border width so children don't render over border)
         // This matches CSS overflow:hidden behavior which clips to the padding box
         let clips_content = render_node.props.clips_content;
         if clips_content {
-            // Calculate border insets from either uniform border or per-side borders
-            let sides = &render_node.props.border_sides;
-            let uniform_border = render_node.props.border_width;
-
-            let left_inset = sides
-                .left
-                .as_ref()
-                .map(|b| b.width)
-                .unwrap_or(uniform_border);
-            let right_inset = sides
-                .right
-                .as_ref()
-                .map(|b| b.width)
-                .unwrap_or(uniform_border);
-            let top_inset = sides
-                .top
-                .as_ref()
-                .map(|b| b.width)
-                .unwrap_or(uniform_border);
-            let bottom_inset = sides
-                .bottom
-                .as_ref()
-                .map(|b| b.width)
-                .unwrap_or(uniform_border);
-
-            // Inset clip by border width to exclude border area from clipping region
-            let clip_rect = Rect::new(
-                left_inset,
-                top_inset,
-                (bounds.width - left_inset - right_inset).max(0.0),
-                (bounds.height - top_inset - bottom_inset).max(0.0),
-            );
-            // Adjust corner radius for inset - use max border width for corner adjustment
-            let max_border = left_inset.max(right_inset).max(top_inset).max(bottom_inset);
-            let inset_radius = if radius.is_uniform() && radius.top_left > max_border {
-                CornerRadius::uniform((radius.top_left - max_border).max(0.0))
-            } else {
-                CornerRadius::default()
-            };
-            let clip_shape = if inset_radius.top_left > 0.0 {
-                ClipShape::rounded_rect(clip_rect, inset_radius)
-            } else {
-                ClipShape::rect(clip_rect)
-            };
-            ctx.push_clip(clip_shape);
+            ctx.push_clip(Self::content_clip_shape(
+                &render_node.props,
+                bounds.width,
+                bounds.height,
+            ));
         }
 
         // Check if this node has scroll and apply the offset
@@ -4838,7 +6069,7 @@ impl RenderTree {
         }
 
         // Render children (relative to this node's transform + scroll offset)
-        for child_id in self.layout_tree.children(node) {
+        for child_id in self.paint_children(node) {
             self.render_node(ctx, child_id, (0.0, 0.0));
         }
 
@@ -4917,19 +6148,25 @@ impl RenderTree {
     /// are rendered as `Brush::Glass` which the GPU renderer handles
     /// by pushing to the glass primitive batch for multi-pass rendering.
     pub fn render_layered_simple(&self, ctx: &mut dyn DrawContext) {
-        if let Some(root) = self.root {
-            // Pass 1: Background (excludes children of glass elements)
-            ctx.set_foreground_layer(false);
-            self.render_layer(ctx, root, (0.0, 0.0), RenderLayer::Background, false, false);
+        let roots = self.paint_roots();
+
+        // Pass 1: Background (excludes children of glass elements)
+        ctx.set_foreground_layer(false);
+        for &(node, offset) in &roots {
+            self.render_layer(ctx, node, offset, RenderLayer::Background, false, false);
+        }
 
-            // Pass 2: Glass - these render as Brush::Glass which becomes glass primitives
-            self.render_layer(ctx, root, (0.0, 0.0), RenderLayer::Glass, false, false);
+        // Pass 2: Glass - these render as Brush::Glass which becomes glass primitives
+        for &(node, offset) in &roots {
+            self.render_layer(ctx, node, offset, RenderLayer::Glass, false, false);
+        }
 
-            // Pass 3: Foreground (includes children of glass elements, rendered after glass)
-            ctx.set_foreground_layer(true);
-            self.render_layer(ctx, root, (0.0, 0.0), RenderLayer::Foreground, false, false);
-            ctx.set_foreground_layer(false);
+        // Pass 3: Foreground (includes children of glass elements, rendered after glass)
+        ctx.set_foreground_layer(true);
+        for &(node, offset) in &roots {
+            self.render_layer(ctx, node, offset, RenderLayer::Foreground, false, false);
         }
+        ctx.set_foreground_layer(false);
     }
 
     /// Render with motion animations from RenderState
@@ -4942,56 +6179,65 @@ impl RenderTree {
         ctx: &mut dyn DrawContext,
         render_state: &crate::render_state::RenderState,
     ) {
-        if let Some(root) = self.root {
-            // Apply DPI scale factor if set (for HiDPI display support)
-            let has_scale = self.scale_factor != 1.0;
-            if has_scale {
-                ctx.push_transform(Transform::scale(self.scale_factor, self.scale_factor));
-            }
+        if self.root.is_none() {
+            return;
+        }
+        let roots = self.paint_roots();
 
-            // Pass 1: Background (primitives go to background batch)
-            ctx.set_foreground_layer(false);
+        // Apply DPI scale factor if set (for HiDPI display support)
+        let has_scale = self.scale_factor != 1.0;
+        if has_scale {
+            ctx.push_transform(Transform::scale(self.scale_factor, self.scale_factor));
+        }
+
+        // Pass 1: Background (primitives go to background batch)
+        ctx.set_foreground_layer(false);
+        for &(node, offset) in &roots {
             self.render_layer_with_motion(
                 ctx,
-                root,
-                (0.0, 0.0),
+                node,
+                offset,
                 RenderLayer::Background,
                 false, // inside_glass
                 false, // inside_foreground
                 render_state,
                 1.0, // Start with full opacity at root
             );
+        }
 
-            // Pass 2: Glass (primitives go to glass batch)
+        // Pass 2: Glass (primitives go to glass batch)
+        for &(node, offset) in &roots {
             self.render_layer_with_motion(
                 ctx,
-                root,
-                (0.0, 0.0),
+                node,
+                offset,
                 RenderLayer::Glass,
                 false, // inside_glass
                 false, // inside_foreground
                 render_state,
                 1.0, // Start with full opacity at root
             );
+        }
 
-            // Pass 3: Foreground (primitives go to foreground batch, rendered after glass)
-            ctx.set_foreground_layer(true);
+        // Pass 3: Foreground (primitives go to foreground batch, rendered after glass)
+        ctx.set_foreground_layer(true);
+        for &(node, offset) in &roots {
             self.render_layer_with_motion(
                 ctx,
-                root,
-                (0.0, 0.0),
+                node,
+                offset,
                 RenderLayer::Foreground,
                 false, // inside_glass
                 false, // inside_foreground
                 render_state,
                 1.0, // Start with full opacity at root
             );
-            ctx.set_foreground_layer(false);
+        }
+        ctx.set_foreground_layer(false);
 
-            // Pop the DPI scale transform
-            if has_scale {
-                ctx.pop_transform();
-            }
+        // Pop the DPI scale transform
+        if has_scale {
+            ctx.pop_transform();
         }
     }
 
@@ -5186,9 +6432,136 @@ impl RenderTree {
                 opacity: node_motion_opacity,
                 depth: false,
                 effects: render_node.props.layer_effects.clone(),
+                cache: false,
             });
         }
 
+        // Node content and children: composited from the cached layer when
+        // this node caches its subtree (transforms and opacity layer above
+        // apply to the composite)
+        let base_z = ctx.z_layer();
+        let caching = render_node.props.cache_policy != CachePolicy::None;
+        let cached = caching && self.sample_cached_layer(ctx, node, target_layer, &bounds, base_z);
+        if !cached {
+            if caching && self.can_cache_layer(node) {
+                let size = blinc_core::Size::new(bounds.width, bounds.height);
+                ctx.push_layer(
+                    LayerConfig {
+                        opacity: 1.0,
+                        ..LayerConfig::default()
+                    }
+                    .id(self.cached_layer_id(node, target_layer))
+                    .position(Point::ZERO)
+                    .size(size)
+                    .cached(),
+                );
+                self.render_node_content_with_motion(
+                    ctx,
+                    node,
+                    render_node,
+                    bounds,
+                    target_layer,
+                    effective_layer,
+                    has_layout_animation,
+                    has_opacity_layer,
+                    motion_opacity,
+                    children_inside_glass,
+                    children_inside_foreground,
+                    render_state,
+                );
+                ctx.pop_layer();
+                self.store_cached_layer(node, target_layer, render_node, &bounds, base_z);
+            } else {
+                self.render_node_content_with_motion(
+                    ctx,
+                    node,
+                    render_node,
+                    bounds,
+                    target_layer,
+                    effective_layer,
+                    has_layout_animation,
+                    has_opacity_layer,
+                    motion_opacity,
+                    children_inside_glass,
+                    children_inside_foreground,
+                    render_state,
+                );
+            }
+        }
+
+        // Pop opacity layer (must be after clips, before transforms)
+        if should_push_layer {
+            ctx.pop_layer();
+        }
+
+        // Pop element transforms
+        if has_element_transform {
+            ctx.pop_transform();
+            ctx.pop_transform();
+            ctx.pop_transform();
+        }
+
+        // Pop motion binding rotation (3 transforms for centering)
+        if has_binding_rotation {
+            ctx.pop_transform();
+            ctx.pop_transform();
+            ctx.pop_transform();
+        }
+
+        // Pop motion binding scale (3 transforms for centering)
+        if has_binding_scale {
+            ctx.pop_transform();
+            ctx.pop_transform();
+            ctx.pop_transform();
+        }
+
+        // Pop motion binding translation (1 transform)
+        if has_binding_transform {
+            ctx.pop_transform();
+        }
+
+        // Pop motion scale transforms (from RenderState motion)
+        if has_motion_scale {
+            ctx.pop_transform();
+            ctx.pop_transform();
+            ctx.pop_transform();
+        }
+
+        // Pop motion translation
+        if motion_values
+            .map(|m| {
+                let (tx, ty) = m.resolved_translate();
+                tx.abs() > 0.001 || ty.abs() > 0.001
+            })
+            .unwrap_or(false)
+        {
+            ctx.pop_transform();
+        }
+
+        // Pop position transform
+        ctx.pop_transform();
+    }
+
+    /// Render a node's own content (background, borders, canvas) and its children
+    ///
+    /// Everything drawn here is relative to the node's origin, so it can be
+    /// recorded into the layer cache and replayed under different transforms.
+    #[allow(clippy::too_many_arguments)]
+    fn render_node_content_with_motion(
+        &self,
+        ctx: &mut dyn DrawContext,
+        node: LayoutNodeId,
+        render_node: &RenderNode,
+        bounds: ElementBounds,
+        target_layer: RenderLayer,
+        effective_layer: RenderLayer,
+        has_layout_animation: bool,
+        has_opacity_layer: bool,
+        motion_opacity: f32,
+        children_inside_glass: bool,
+        children_inside_foreground: bool,
+        render_state: &crate::render_state::RenderState,
+    ) {
         // Draw shadow BEFORE pushing clip (shadows extend beyond element bounds)
         // This must be done before the clip is applied so shadows aren't clipped
         let rect = Rect::new(0.0, 0.0, bounds.width, bounds.height);
@@ -5224,7 +6597,7 @@ impl RenderTree {
         let clips_content = render_node.props.clips_content || has_layout_animation;
         if clips_content {
             let clip_rect = Rect::new(0.0, 0.0, bounds.width, bounds.height);
-            let clip_shape = if radius.is_uniform() && radius.top_left > 0.0 {
+            let clip_shape = if radius != CornerRadius::ZERO {
                 ClipShape::rounded_rect(clip_rect, radius)
             } else {
                 ClipShape::rect(clip_rect)
@@ -5394,7 +6767,7 @@ impl RenderTree {
                         width: bounds.width,
                         height: bounds.height,
                     };
-                    render_fn(ctx, canvas_bounds);
+                    render_fn(ctx, canvas_bounds, &self.canvas_frame);
                 } else {
                     // eprintln!("  >>> WARNING: render_fn is None!");
                 }
@@ -5414,53 +6787,11 @@ impl RenderTree {
             render_node.props.border_width > 0.0 || render_node.props.border_sides.has_any();
         let push_children_clip = clips_content && has_border;
         if push_children_clip {
-            // Calculate border insets from either uniform border or per-side borders
-            let sides = &render_node.props.border_sides;
-            let uniform_border = render_node.props.border_width;
-
-            let left_inset = sides
-                .left
-                .as_ref()
-                .map(|b| b.width)
-                .unwrap_or(uniform_border);
-            let right_inset = sides
-                .right
-                .as_ref()
-                .map(|b| b.width)
-                .unwrap_or(uniform_border);
-            let top_inset = sides
-                .top
-                .as_ref()
-                .map(|b| b.width)
-                .unwrap_or(uniform_border);
-            let bottom_inset = sides
-                .bottom
-                .as_ref()
-                .map(|b| b.width)
-                .unwrap_or(uniform_border);
-
-            let clip_rect = Rect::new(
-                left_inset,
-                top_inset,
-                (bounds.width - left_inset - right_inset).max(0.0),
-                (bounds.height - top_inset - bottom_inset).max(0.0),
-            );
-
-            // Adjust corner radius for inset
-            let radius = render_node.props.border_radius;
-            let max_border = left_inset.max(right_inset).max(top_inset).max(bottom_inset);
-            let inset_radius = if radius.is_uniform() && radius.top_left > max_border {
-                CornerRadius::uniform((radius.top_left - max_border).max(0.0))
-            } else {
-                CornerRadius::default()
-            };
-
-            let clip_shape = if inset_radius.top_left > 0.0 {
-                ClipShape::rounded_rect(clip_rect, inset_radius)
-            } else {
-                ClipShape::rect(clip_rect)
-            };
-            ctx.push_clip(clip_shape);
+            ctx.push_clip(Self::content_clip_shape(
+                &render_node.props,
+                bounds.width,
+                bounds.height,
+            ));
         }
 
         // Render children, passing down the effective opacity and layer inheritance
@@ -5471,7 +6802,7 @@ impl RenderTree {
         } else {
             motion_opacity
         };
-        for child_id in self.layout_tree.children(node) {
+        for child_id in self.paint_children(node) {
             self.render_layer_with_motion(
                 ctx,
                 child_id,
@@ -5494,76 +6825,378 @@ impl RenderTree {
             ctx.pop_transform();
         }
 
-        // Render scrollbar overlay if this is a scroll container
-        // Scrollbar is rendered after scroll transform is popped (in viewport space)
-        // but before clip is popped (clipped within scroll container)
-        if effective_layer == target_layer {
-            if let Some(physics) = self.scroll_physics.get(&node) {
-                if let Ok(p) = physics.try_lock() {
-                    let info = p.scrollbar_render_info();
-                    if info.opacity > 0.01 {
-                        self.render_scrollbar(ctx, bounds.width, bounds.height, &info);
-                    }
+        // Render scrollbar overlay if this is a scroll container
+        // Scrollbar is rendered after scroll transform is popped (in viewport space)
+        // but before clip is popped (clipped within scroll container)
+        if effective_layer == target_layer {
+            if let Some(physics) = self.scroll_physics.get(&node) {
+                if let Ok(p) = physics.try_lock() {
+                    let info = p.scrollbar_render_info();
+                    if info.opacity > 0.01 {
+                        self.render_scrollbar(ctx, bounds.width, bounds.height, &info);
+                    }
+                }
+            }
+        }
+
+        // Pop clip
+        if clips_content {
+            ctx.pop_clip();
+        }
+    }
+
+    // =========================================================================
+    // Layer Cache
+    // =========================================================================
+
+    /// ID of the layer a node's subtree is cached under for a render pass
+    fn cached_layer_id(&self, node: LayoutNodeId, target_layer: RenderLayer) -> LayerId {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        (self.layer_cache_serial, node, target_layer).hash(&mut hasher);
+        LayerId(hasher.finish())
+    }
+
+    /// Composite a node's cached layer if it is still valid
+    ///
+    /// Returns false when the subtree hasn't been drawn into a cached layer
+    /// for this pass, its size or starting z-layer changed since, or the
+    /// context no longer holds the layer (e.g. the renderer evicted it).
+    fn sample_cached_layer(
+        &self,
+        ctx: &mut dyn DrawContext,
+        node: LayoutNodeId,
+        target_layer: RenderLayer,
+        bounds: &ElementBounds,
+        base_z: u32,
+    ) -> bool {
+        let id = self.cached_layer_id(node, target_layer);
+        let valid = self.layer_cache.lock().unwrap().is_valid(
+            node,
+            target_layer,
+            (bounds.width, bounds.height),
+            base_z,
+        );
+        if !valid || !ctx.has_cached_layer(id) {
+            return false;
+        }
+        let rect = Rect::new(0.0, 0.0, bounds.width, bounds.height);
+        ctx.sample_layer(id, rect, rect);
+        true
+    }
+
+    /// Whether a node's subtree can be drawn into a cached layer
+    ///
+    /// Layer effects aren't applied inside cached layers, so subtrees using
+    /// them are drawn every frame instead (with a warning the first time).
+    fn can_cache_layer(&self, node: LayoutNodeId) -> bool {
+        let mut stack = vec![node];
+        while let Some(id) = stack.pop() {
+            let has_effects = self
+                .render_nodes
+                .get(&id)
+                .is_some_and(|n| !n.props.layer_effects.is_empty());
+            if has_effects {
+                if self.layer_cache.lock().unwrap().mark_uncacheable(node) {
+                    tracing::warn!(
+                        "cache_layer() on {:?} ignored: its subtree uses layer effects",
+                        node
+                    );
                 }
+                return false;
             }
+            stack.extend(self.layout_tree.children(id));
         }
+        true
+    }
 
-        // Pop clip
-        if clips_content {
-            ctx.pop_clip();
-        }
+    /// Record that a node's subtree was just drawn into its cached layer
+    fn store_cached_layer(
+        &self,
+        node: LayoutNodeId,
+        target_layer: RenderLayer,
+        render_node: &RenderNode,
+        bounds: &ElementBounds,
+        base_z: u32,
+    ) {
+        let mut nodes = std::collections::HashSet::new();
+        let mut stack = vec![node];
+        while let Some(id) = stack.pop() {
+            nodes.insert(id);
+            stack.extend(self.layout_tree.children(id));
+        }
+        self.layer_cache.lock().unwrap().insert(
+            node,
+            target_layer,
+            render_node.props.cache_policy,
+            (bounds.width, bounds.height),
+            base_z,
+            nodes,
+        );
+    }
 
-        // Pop opacity layer (must be after clips, before transforms)
-        if should_push_layer {
-            ctx.pop_layer();
-        }
+    /// Drop automatic (`CachePolicy::Content`) cached layers that contain `node`
+    fn invalidate_layer_content(&self, node: LayoutNodeId) {
+        self.layer_cache.lock().unwrap().invalidate_content(node);
+    }
 
-        // Pop element transforms
-        if has_element_transform {
-            ctx.pop_transform();
-            ctx.pop_transform();
-            ctx.pop_transform();
+    /// Drop every cached layer that contains `node`
+    ///
+    /// Call this when something drawn inside a `cache_layer()` subtree changes
+    /// without the subtree being rebuilt (e.g. a canvas closure reading external
+    /// state), or to refresh a `CachePolicy::Manual` layer.
+    pub fn invalidate_layer_cache(&self, node: LayoutNodeId) {
+        self.layer_cache.lock().unwrap().invalidate(node);
+    }
+
+    /// Drop all cached layers
+    pub fn clear_layer_cache(&self) {
+        self.layer_cache.lock().unwrap().clear();
+    }
+
+    /// Screen region that must be redrawn after `nodes` changed
+    ///
+    /// Returns the union of the nodes' painted areas in physical pixels
+    /// (scale factor applied), clamped to the root bounds, or `None` if none
+    /// of the nodes are visible. A node's painted area covers its whole
+    /// subtree (children may overflow unless the node clips), expanded by drop
+    /// shadows and layer effects, and is mapped through element transforms
+    /// and scroll offsets as the bounding box of the transformed corners.
+    /// Elements with a 3D transform damage the whole viewport.
+    ///
+    /// Motion animation offsets live in `RenderState` and are not included -
+    /// animating elements should be redrawn in full. When an element moves or
+    /// resizes, union the rect from before and after the change so its old
+    /// position is repainted too.
+    pub fn damage_rect(&self, nodes: &[LayoutNodeId]) -> Option<Rect> {
+        let root = self.root?;
+        let root_bounds = self.get_render_bounds(root, (0.0, 0.0))?;
+        let targets: std::collections::HashSet<LayoutNodeId> = nodes.iter().copied().collect();
+        let viewport = (
+            0.0,
+            0.0,
+            (root_bounds.x + root_bounds.width) * self.scale_factor,
+            (root_bounds.y + root_bounds.height) * self.scale_factor,
+        );
+
+        let mut damage: Option<(f32, f32, f32, f32)> = None;
+        self.collect_damage(
+            root,
+            Affine2D::scale(self.scale_factor, self.scale_factor),
+            &targets,
+            None,
+            viewport,
+            &mut damage,
+        );
+
+        let (min_x, min_y, max_x, max_y) = damage?;
+        let (min_x, min_y) = (min_x.max(viewport.0), min_y.max(viewport.1));
+        let (max_x, max_y) = (max_x.min(viewport.2), max_y.min(viewport.3));
+        if max_x <= min_x || max_y <= min_y {
+            return None;
         }
+        Some(Rect::new(min_x, min_y, max_x - min_x, max_y - min_y))
+    }
 
-        // Pop motion binding rotation (3 transforms for centering)
-        if has_binding_rotation {
-            ctx.pop_transform();
-            ctx.pop_transform();
-            ctx.pop_transform();
+    /// Apply incremental prop updates and return the screen region they change
+    ///
+    /// The region covers each updated node as painted both before and after
+    /// the update (see [`Self::damage_rect`]), so whatever the old props drew -
+    /// a shadow that went away - is repainted too.
+    pub fn apply_prop_updates(&mut self, updates: &[(LayoutNodeId, RenderProps)]) -> Option<Rect> {
+        let nodes: Vec<LayoutNodeId> = updates.iter().map(|(node_id, _)| *node_id).collect();
+        let before = self.damage_rect(&nodes);
+        for (node_id, props) in updates {
+            self.update_render_props(*node_id, |p| *p = props.clone());
+        }
+        match (before, self.damage_rect(&nodes)) {
+            (Some(before), Some(after)) => Some(before.union(&after)),
+            (before, after) => before.or(after),
         }
+    }
 
-        // Pop motion binding scale (3 transforms for centering)
-        if has_binding_scale {
-            ctx.pop_transform();
-            ctx.pop_transform();
-            ctx.pop_transform();
+    /// Walk the tree accumulating transforms, adding the painted area of every
+    /// node inside a damaged subtree
+    ///
+    /// `clip` is the screen-space clip of the nearest clipping ancestor inside
+    /// the damaged subtree (overflow past it is never painted).
+    fn collect_damage(
+        &self,
+        node: LayoutNodeId,
+        parent_transform: Affine2D,
+        targets: &std::collections::HashSet<LayoutNodeId>,
+        clip: Option<(f32, f32, f32, f32)>,
+        viewport: (f32, f32, f32, f32),
+        damage: &mut Option<(f32, f32, f32, f32)>,
+    ) {
+        let Some(bounds) = self.get_render_bounds(node, (0.0, 0.0)) else {
+            return;
+        };
+        let Some(render_node) = self.render_nodes.get(&node) else {
+            return;
+        };
+        let props = &render_node.props;
+
+        // Same transform stack as render_layer_with_motion: translate to the
+        // node origin, then the element transform around its center
+        let mut transform = parent_transform.then(&Affine2D::translation(bounds.x, bounds.y));
+        match props.transform {
+            Some(Transform::Affine2D(ref affine)) => {
+                let (cx, cy) = (bounds.width / 2.0, bounds.height / 2.0);
+                transform = transform
+                    .then(&Affine2D::translation(cx, cy))
+                    .then(affine)
+                    .then(&Affine2D::translation(-cx, -cy));
+            }
+            Some(Transform::Mat4(_)) => {
+                // Perspective can put the subtree anywhere on screen
+                let mut stack = vec![node];
+                let mut contains_target = clip.is_some();
+                while let Some(id) = stack.pop().filter(|_| !contains_target) {
+                    contains_target = targets.contains(&id);
+                    stack.extend(self.layout_tree.children(id));
+                }
+                if contains_target {
+                    *damage = Some(viewport);
+                }
+                return;
+            }
+            None => {}
         }
 
-        // Pop motion binding translation (1 transform)
-        if has_binding_transform {
-            ctx.pop_transform();
+        let damaged = clip.is_some() || targets.contains(&node);
+        let mut child_clip = clip;
+        if damaged {
+            let (left, top, right, bottom) = Self::paint_overflow(props);
+            let (mut min_x, mut min_y, mut max_x, mut max_y) = Self::transformed_aabb(
+                &transform,
+                (-left, -top, bounds.width + right, bounds.height + bottom),
+            );
+            if let Some((cx0, cy0, cx1, cy1)) = clip {
+                min_x = min_x.max(cx0);
+                min_y = min_y.max(cy0);
+                max_x = max_x.min(cx1);
+                max_y = max_y.min(cy1);
+            }
+            if max_x > min_x && max_y > min_y {
+                *damage = Some(match *damage {
+                    Some((x0, y0, x1, y1)) => {
+                        (x0.min(min_x), y0.min(min_y), x1.max(max_x), y1.max(max_y))
+                    }
+                    None => (min_x, min_y, max_x, max_y),
+                });
+            }
+
+            child_clip = if props.clips_content {
+                let own =
+                    Self::transformed_aabb(&transform, (0.0, 0.0, bounds.width, bounds.height));
+                Some(match clip {
+                    Some((cx0, cy0, cx1, cy1)) => (
+                        own.0.max(cx0),
+                        own.1.max(cy0),
+                        own.2.min(cx1),
+                        own.3.min(cy1),
+                    ),
+                    None => own,
+                })
+            } else {
+                // Children may overflow anywhere inside the viewport
+                Some(clip.unwrap_or(viewport))
+            };
         }
 
-        // Pop motion scale transforms (from RenderState motion)
-        if has_motion_scale {
-            ctx.pop_transform();
-            ctx.pop_transform();
-            ctx.pop_transform();
+        let (scroll_x, scroll_y) = self.get_scroll_offset(node);
+        let child_transform = transform.then(&Affine2D::translation(scroll_x, scroll_y));
+        for child in self.layout_tree.children(node) {
+            self.collect_damage(
+                child,
+                child_transform,
+                targets,
+                child_clip,
+                viewport,
+                damage,
+            );
         }
+    }
 
-        // Pop motion translation
-        if motion_values
-            .map(|m| {
-                let (tx, ty) = m.resolved_translate();
-                tx.abs() > 0.001 || ty.abs() > 0.001
-            })
-            .unwrap_or(false)
-        {
-            ctx.pop_transform();
+    /// How far an element paints past its layout bounds (left, top, right, bottom)
+    ///
+    /// Matches the quad expansion used by the GPU shadow primitive and the
+    /// layer effect compositor.
+    fn paint_overflow(props: &RenderProps) -> (f32, f32, f32, f32) {
+        let mut overflow = (0.0f32, 0.0f32, 0.0f32, 0.0f32);
+        let mut expand = |left: f32, top: f32, right: f32, bottom: f32| {
+            overflow.0 = overflow.0.max(left);
+            overflow.1 = overflow.1.max(top);
+            overflow.2 = overflow.2.max(right);
+            overflow.3 = overflow.3.max(bottom);
+        };
+
+        if let Some(ref shadow) = props.shadow {
+            let margin = shadow.blur * 3.0
+                + shadow.spread.max(0.0)
+                + shadow.offset_x.abs()
+                + shadow.offset_y.abs();
+            expand(margin, margin, margin, margin);
+        }
+
+        for effect in &props.layer_effects {
+            match *effect {
+                LayerEffect::DropShadow {
+                    offset_x,
+                    offset_y,
+                    blur,
+                    spread,
+                    ..
+                } => {
+                    let margin = blur * 2.0 + spread.max(0.0);
+                    expand(
+                        margin + (-offset_x).max(0.0),
+                        margin + (-offset_y).max(0.0),
+                        margin + offset_x.max(0.0),
+                        margin + offset_y.max(0.0),
+                    );
+                }
+                LayerEffect::Glow { blur, range, .. } => {
+                    let margin = (blur + range) * 2.0;
+                    expand(margin, margin, margin, margin);
+                }
+                LayerEffect::Bloom { radius, .. } => {
+                    let margin = radius * 2.0;
+                    expand(margin, margin, margin, margin);
+                }
+                LayerEffect::Blur { .. }
+                | LayerEffect::ColorMatrix { .. }
+                | LayerEffect::ToneMap { .. } => {}
+            }
         }
 
-        // Pop position transform
-        ctx.pop_transform();
+        overflow
+    }
+
+    /// Screen-space bounding box of a local rect (x0, y0, x1, y1) under `transform`
+    fn transformed_aabb(
+        transform: &Affine2D,
+        (x0, y0, x1, y1): (f32, f32, f32, f32),
+    ) -> (f32, f32, f32, f32) {
+        let corners = [
+            transform.transform_point(Point::new(x0, y0)),
+            transform.transform_point(Point::new(x1, y0)),
+            transform.transform_point(Point::new(x0, y1)),
+            transform.transform_point(Point::new(x1, y1)),
+        ];
+        corners.iter().fold(
+            (f32::MAX, f32::MAX, f32::MIN, f32::MIN),
+            |(min_x, min_y, max_x, max_y), p| {
+                (
+                    min_x.min(p.x),
+                    min_y.min(p.y),
+                    max_x.max(p.x),
+                    max_y.max(p.y),
+                )
+            },
+        )
     }
 
     /// Render with layer separation and explicit context control
@@ -5582,36 +7215,37 @@ impl RenderTree {
         glass_ctx: &mut dyn DrawContext,
         foreground_ctx: &mut dyn DrawContext,
     ) {
-        if let Some(root) = self.root {
+        if self.root.is_some() {
+            let roots = self.paint_roots();
+
             // Pass 1: Background (excludes children of glass elements)
-            self.render_layer(
-                background_ctx,
-                root,
-                (0.0, 0.0),
-                RenderLayer::Background,
-                false,
-                false,
-            );
+            for &(node, offset) in &roots {
+                self.render_layer(
+                    background_ctx,
+                    node,
+                    offset,
+                    RenderLayer::Background,
+                    false,
+                    false,
+                );
+            }
 
             // Pass 2: Glass - render as Brush::Glass
-            self.render_layer(
-                glass_ctx,
-                root,
-                (0.0, 0.0),
-                RenderLayer::Glass,
-                false,
-                false,
-            );
+            for &(node, offset) in &roots {
+                self.render_layer(glass_ctx, node, offset, RenderLayer::Glass, false, false);
+            }
 
             // Pass 3: Foreground (includes children of glass elements)
-            self.render_layer(
-                foreground_ctx,
-                root,
-                (0.0, 0.0),
-                RenderLayer::Foreground,
-                false,
-                false,
-            );
+            for &(node, offset) in &roots {
+                self.render_layer(
+                    foreground_ctx,
+                    node,
+                    offset,
+                    RenderLayer::Foreground,
+                    false,
+                    false,
+                );
+            }
         }
     }
 
@@ -5623,14 +7257,16 @@ impl RenderTree {
     /// **Important:** Children of glass elements are automatically considered
     /// as foreground - no need to mark them with `.foreground()`.
     pub fn render_to_layer(&self, ctx: &mut dyn DrawContext, target_layer: RenderLayer) {
-        if let Some(root) = self.root {
+        if self.root.is_some() {
             // Apply DPI scale factor if set (for HiDPI display support)
             let has_scale = self.scale_factor != 1.0;
             if has_scale {
                 ctx.push_transform(Transform::scale(self.scale_factor, self.scale_factor));
             }
 
-            self.render_layer(ctx, root, (0.0, 0.0), target_layer, false, false);
+            for (node, offset) in self.paint_roots() {
+                self.render_layer(ctx, node, offset, target_layer, false, false);
+            }
 
             // Pop the DPI scale transform
             if has_scale {
@@ -5655,7 +7291,7 @@ impl RenderTree {
         inside_glass: bool,
         inside_foreground: bool,
     ) {
-        let Some(bounds) = self.layout_tree.get_bounds(node, parent_offset) else {
+        let Some(bounds) = self.paint_bounds(node, parent_offset) else {
             return;
         };
 
@@ -5698,52 +7334,11 @@ impl RenderTree {
         // This matches CSS overflow:hidden behavior which clips to the padding box
         let clips_content = render_node.props.clips_content;
         if clips_content {
-            // Calculate border insets from either uniform border or per-side borders
-            let sides = &render_node.props.border_sides;
-            let uniform_border = render_node.props.border_width;
-            let radius = render_node.props.border_radius;
-
-            let left_inset = sides
-                .left
-                .as_ref()
-                .map(|b| b.width)
-                .unwrap_or(uniform_border);
-            let right_inset = sides
-                .right
-                .as_ref()
-                .map(|b| b.width)
-                .unwrap_or(uniform_border);
-            let top_inset = sides
-                .top
-                .as_ref()
-                .map(|b| b.width)
-                .unwrap_or(uniform_border);
-            let bottom_inset = sides
-                .bottom
-                .as_ref()
-                .map(|b| b.width)
-                .unwrap_or(uniform_border);
-
-            // Inset clip by border width to exclude border area from clipping region
-            let clip_rect = Rect::new(
-                left_inset,
-                top_inset,
-                (bounds.width - left_inset - right_inset).max(0.0),
-                (bounds.height - top_inset - bottom_inset).max(0.0),
-            );
-            // Adjust corner radius for inset - use max border width for corner adjustment
-            let max_border = left_inset.max(right_inset).max(top_inset).max(bottom_inset);
-            let inset_radius = if radius.is_uniform() && radius.top_left > max_border {
-                CornerRadius::uniform((radius.top_left - max_border).max(0.0))
-            } else {
-                CornerRadius::default()
-            };
-            let clip_shape = if inset_radius.top_left > 0.0 {
-                ClipShape::rounded_rect(clip_rect, inset_radius)
-            } else {
-                ClipShape::rect(clip_rect)
-            };
-            ctx.push_clip(clip_shape);
+            ctx.push_clip(Self::content_clip_shape(
+                &render_node.props,
+                bounds.width,
+                bounds.height,
+            ));
         }
 
         // Determine the effective layer for this node:
@@ -5889,7 +7484,7 @@ impl RenderTree {
                         width: bounds.width,
                         height: bounds.height,
                     };
-                    render_fn(ctx, canvas_bounds);
+                    render_fn(ctx, canvas_bounds, &self.canvas_frame);
                 }
             }
         }
@@ -5905,7 +7500,7 @@ impl RenderTree {
         }
 
         // Traverse children (they inherit our transform and layer inheritance)
-        for child_id in self.layout_tree.children(node) {
+        for child_id in self.paint_children(node) {
             self.render_layer(
                 ctx,
                 child_id,
@@ -5916,41 +7511,354 @@ impl RenderTree {
             );
         }
 
-        // Pop scroll transform if we pushed one
-        if has_scroll {
-            ctx.pop_transform();
-        }
+        // Pop scroll transform if we pushed one
+        if has_scroll {
+            ctx.pop_transform();
+        }
+
+        // Pop clip if we pushed one
+        if clips_content {
+            ctx.pop_clip();
+        }
+
+        // Pop element-specific transforms if we pushed them (3 transforms for centering)
+        if has_element_transform {
+            ctx.pop_transform(); // pop translate(-center_x, -center_y)
+            ctx.pop_transform(); // pop the actual transform
+            ctx.pop_transform(); // pop translate(center_x, center_y)
+        }
+
+        ctx.pop_transform();
+    }
+
+    /// Get bounds for a specific node
+    pub fn get_bounds(&self, node: LayoutNodeId) -> Option<ElementBounds> {
+        self.layout_tree.get_bounds(node, (0.0, 0.0))
+    }
+
+    /// Get absolute bounds for a node (traversing up the tree)
+    pub fn get_absolute_bounds(&self, node: LayoutNodeId) -> Option<ElementBounds> {
+        let mut offset = (0.0, 0.0);
+        let mut ancestor = self.layout_tree.parent(node);
+        while let Some(parent) = ancestor {
+            let bounds = self.layout_tree.get_bounds(parent, (0.0, 0.0))?;
+            offset.0 += bounds.x;
+            offset.1 += bounds.y;
+            ancestor = self.layout_tree.parent(parent);
+        }
+        self.layout_tree.get_bounds(node, offset)
+    }
+
+    /// Get render node data
+    pub fn get_render_node(&self, node: LayoutNodeId) -> Option<&RenderNode> {
+        self.render_nodes.get(&node)
+    }
+
+    /// Focusable nodes with their tab index, in tree order
+    ///
+    /// Disabled subtrees are skipped. Feed this to [`InteractiveContext::set_focus_order`](crate::interactive::InteractiveContext::set_focus_order)
+    /// after each rebuild.
+    pub fn focus_order(&self) -> Vec<(LayoutNodeId, i32)> {
+        let mut order = Vec::new();
+        let mut stack: Vec<LayoutNodeId> = self.root.into_iter().collect();
+        while let Some(node) = stack.pop() {
+            let props = self.render_nodes.get(&node).map(|n| &n.props);
+            if props.is_some_and(|p| p.disabled) {
+                continue;
+            }
+            if let Some(index) = props.and_then(|p| p.tab_index) {
+                order.push((node, index));
+            }
+            stack.extend(self.layout_tree.children(node).into_iter().rev());
+        }
+        order
+    }
+
+    /// Queue the focused node's focus ring as an overlay
+    ///
+    /// Drawn only while focus is visible (it arrived via Tab) and the node
+    /// opted in with [`Div::focus_ring`](crate::div::Div::focus_ring). Call
+    /// each frame after overlays are cleared. Returns whether a ring was queued.
+    pub fn add_focus_ring(
+        &self,
+        focus: &crate::interactive::InteractiveContext,
+        render_state: &mut crate::render_state::RenderState,
+    ) -> bool {
+        let Some(node) = focus.focused().filter(|&n| focus.is_focus_visible(n)) else {
+            return false;
+        };
+        let Some(props) = self.render_nodes.get(&node).map(|n| &n.props) else {
+            return false;
+        };
+        let (Some(ring), Some(mut bounds)) =
+            (props.focus_ring, self.paint_bounds(node, (0.0, 0.0)))
+        else {
+            return false;
+        };
+
+        // Ancestor positions and scroll offsets, as applied during paint
+        let mut ancestor = self.layout_tree.parent(node);
+        while let Some(parent) = ancestor {
+            let offset = self.paint_bounds(parent, (0.0, 0.0)).unwrap_or_default();
+            let (scroll_x, scroll_y) = self.get_scroll_offset(parent);
+            bounds.x += offset.x + scroll_x;
+            bounds.y += offset.y + scroll_y;
+            ancestor = self.layout_tree.parent(parent);
+        }
+
+        // Just outside the element, in physical pixels like other overlays
+        let scale = self.scale_factor;
+        render_state.add_focus_ring(
+            (bounds.x - ring.width) * scale,
+            (bounds.y - ring.width) * scale,
+            (bounds.width + ring.width * 2.0) * scale,
+            (bounds.height + ring.width * 2.0) * scale,
+            (props.border_radius.top_left + ring.width) * scale,
+            ring.color,
+            ring.width * scale,
+        );
+        true
+    }
+
+    /// Whether a node hosts portal content (see [`Div::portal`](crate::div::Div::portal))
+    pub fn is_portal(&self, node: LayoutNodeId) -> bool {
+        self.render_nodes.get(&node).is_some_and(|n| n.props.portal)
+    }
+
+    /// Portal hosts in tree order, each with its parent's content offset
+    ///
+    /// Paint and hit testing skip portal hosts where they sit in the tree and
+    /// visit them after the root from these offsets instead, so ancestor clips
+    /// don't apply. The offsets follow ancestor layout positions and scroll
+    /// offsets; ancestor element transforms are not applied.
+    pub fn portals(&self) -> Vec<(LayoutNodeId, (f32, f32))> {
+        let mut portals = Vec::new();
+        let Some(root) = self.root else {
+            return portals;
+        };
+        if !self.render_nodes.values().any(|n| n.props.portal) {
+            return portals;
+        }
+
+        let mut stack = vec![(root, (0.0, 0.0))];
+        while let Some((node, offset)) = stack.pop() {
+            if self.is_portal(node) {
+                portals.push((node, offset));
+            }
+            let Some(bounds) = self.paint_bounds(node, offset) else {
+                continue;
+            };
+            let scroll = self.get_scroll_offset(node);
+            let child_offset = (bounds.x + scroll.0, bounds.y + scroll.1);
+            for child in self.layout_tree.children(node).into_iter().rev() {
+                stack.push((child, child_offset));
+            }
+        }
+        portals
+    }
+
+    /// The root followed by every portal host, with the offset to paint each from
+    fn paint_roots(&self) -> Vec<(LayoutNodeId, (f32, f32))> {
+        let mut roots: Vec<_> = self
+            .root
+            .map(|root| (root, (0.0, 0.0)))
+            .into_iter()
+            .collect();
+        roots.extend(self.portals());
+        roots
+    }
+
+    /// Children in paint order: portal hosts are left out (they're painted
+    /// from [`portals`](Self::portals)) and sticky children come last, so
+    /// they stay above the siblings that scroll beneath them
+    ///
+    /// Hit testing walks this in reverse.
+    pub fn paint_children(&self, node: LayoutNodeId) -> Vec<LayoutNodeId> {
+        let (sticky, mut children): (Vec<_>, Vec<_>) = self
+            .layout_tree
+            .children(node)
+            .into_iter()
+            .filter(|&child| !self.is_portal(child))
+            .partition(|child| self.sticky_insets(*child).is_some());
+        children.extend(sticky);
+        children
+    }
+
+    /// Layout bounds shifted by sticky positioning, i.e. where the node paints
+    /// before element transforms and motion
+    pub fn paint_bounds(
+        &self,
+        node: LayoutNodeId,
+        parent_offset: (f32, f32),
+    ) -> Option<ElementBounds> {
+        let mut bounds = self.layout_tree.get_bounds(node, parent_offset)?;
+        let (dx, dy) = self.sticky_offset(node);
+        bounds.x += dx;
+        bounds.y += dy;
+        Some(bounds)
+    }
+
+    fn sticky_insets(&self, node: LayoutNodeId) -> Option<StickyInsets> {
+        self.render_nodes.get(&node).and_then(|n| n.props.sticky)
+    }
 
-        // Pop clip if we pushed one
-        if clips_content {
-            ctx.pop_clip();
-        }
+    fn is_scroll_container(&self, node: LayoutNodeId) -> bool {
+        self.scroll_physics.contains_key(&node) || self.scroll_offsets.contains_key(&node)
+    }
 
-        // Pop element-specific transforms if we pushed them (3 transforms for centering)
-        if has_element_transform {
-            ctx.pop_transform(); // pop translate(-center_x, -center_y)
-            ctx.pop_transform(); // pop the actual transform
-            ctx.pop_transform(); // pop translate(center_x, center_y)
+    /// How far sticky positioning moves a node from its layout position
+    ///
+    /// Zero unless the node is sticky (see [`Div::sticky_top`](crate::div::Div::sticky_top))
+    /// and has a scroll container ancestor.
+    pub fn sticky_offset(&self, node: LayoutNodeId) -> (f32, f32) {
+        let Some(insets) = self.sticky_insets(node) else {
+            return (0.0, 0.0);
+        };
+        let Some(parent) = self.layout_tree.parent(node) else {
+            return (0.0, 0.0);
+        };
+        let Some(bounds) = self.layout_tree.get_bounds(node, (0.0, 0.0)) else {
+            return (0.0, 0.0);
+        };
+
+        // Position of the parent's origin in the scroll container's space,
+        // and the parent's content box (the sticky constraint rectangle)
+        let mut origin = (0.0, 0.0);
+        let mut ancestor = parent;
+        while !self.is_scroll_container(ancestor) {
+            let Some(ancestor_bounds) = self.layout_tree.get_bounds(ancestor, (0.0, 0.0)) else {
+                return (0.0, 0.0);
+            };
+            origin.0 += ancestor_bounds.x;
+            origin.1 += ancestor_bounds.y;
+            let Some(next) = self.layout_tree.parent(ancestor) else {
+                return (0.0, 0.0);
+            };
+            ancestor = next;
         }
+        let scroller = ancestor;
+        let Some(viewport) = self.layout_tree.get_bounds(scroller, (0.0, 0.0)) else {
+            return (0.0, 0.0);
+        };
+        let containing = if parent == scroller {
+            // Sticky children of the scroll container itself are constrained
+            // by the scrolled content
+            let (width, height) = self
+                .layout_tree
+                .get_content_size(scroller)
+                .unwrap_or((viewport.width, viewport.height));
+            (0.0, 0.0, width, height)
+        } else {
+            let Some(layout) = self.layout_tree.get_layout(parent) else {
+                return (0.0, 0.0);
+            };
+            (
+                layout.border.left + layout.padding.left,
+                layout.border.top + layout.padding.top,
+                layout.size.width - layout.border.right - layout.padding.right,
+                layout.size.height - layout.border.bottom - layout.padding.bottom,
+            )
+        };
 
-        ctx.pop_transform();
-    }
+        // Work in the scroll container's viewport space
+        let scroll = self.get_scroll_offset(scroller);
+        let shift = (origin.0 + scroll.0, origin.1 + scroll.1);
+        let siblings = self.layout_tree.children(parent);
+        let index = siblings.iter().position(|&s| s == node).unwrap_or(0);
+        // Layout start of the next sibling sticking to `start`, and layout
+        // end of the previous sibling sticking to `end`, which push this one
+        let next_start = |edge: fn(&StickyInsets) -> Option<f32>,
+                          pos: fn(&ElementBounds) -> f32| {
+            siblings[index + 1..]
+                .iter()
+                .filter(|&&s| self.sticky_insets(s).as_ref().and_then(edge).is_some())
+                .find_map(|&s| self.layout_tree.get_bounds(s, (0.0, 0.0)))
+                .map(|b| pos(&b))
+        };
+        let prev_end = |edge: fn(&StickyInsets) -> Option<f32>, end: fn(&ElementBounds) -> f32| {
+            siblings[..index]
+                .iter()
+                .rev()
+                .filter(|&&s| self.sticky_insets(s).as_ref().and_then(edge).is_some())
+                .find_map(|&s| self.layout_tree.get_bounds(s, (0.0, 0.0)))
+                .map(|b| end(&b))
+        };
 
-    /// Get bounds for a specific node
-    pub fn get_bounds(&self, node: LayoutNodeId) -> Option<ElementBounds> {
-        self.layout_tree.get_bounds(node, (0.0, 0.0))
+        let dx = sticky_shift(
+            StickyAxis {
+                start: bounds.x + shift.0,
+                size: bounds.width,
+                viewport: viewport.width,
+                containing: (containing.0 + shift.0, containing.2 + shift.0),
+                next_start: next_start(|i| i.left, |b| b.x).map(|x| x + shift.0),
+                prev_end: prev_end(|i| i.right, |b| b.x + b.width).map(|x| x + shift.0),
+            },
+            insets.left,
+            insets.right,
+        );
+        let dy = sticky_shift(
+            StickyAxis {
+                start: bounds.y + shift.1,
+                size: bounds.height,
+                viewport: viewport.height,
+                containing: (containing.1 + shift.1, containing.3 + shift.1),
+                next_start: next_start(|i| i.top, |b| b.y).map(|y| y + shift.1),
+                prev_end: prev_end(|i| i.bottom, |b| b.y + b.height).map(|y| y + shift.1),
+            },
+            insets.top,
+            insets.bottom,
+        );
+        (dx, dy)
     }
 
-    /// Get absolute bounds for a node (traversing up the tree)
-    pub fn get_absolute_bounds(&self, node: LayoutNodeId) -> Option<ElementBounds> {
-        // For now, just return bounds from root (0,0)
-        // A more complete implementation would track parent offsets
-        self.layout_tree.get_bounds(node, (0.0, 0.0))
-    }
+    /// Map a window-space point into a node's local space
+    ///
+    /// Undoes the transform stack paint builds for the node: layout positions,
+    /// ancestor scroll offsets, element transforms and motion
+    /// translate/scale/rotate (centered like paint). 3D transforms are ignored.
+    /// Returns None if the node isn't laid out or a transform is singular.
+    pub fn to_local_point(&self, node: LayoutNodeId, x: f32, y: f32) -> Option<Point> {
+        let mut path = vec![node];
+        while let Some(parent) = self.layout_tree.parent(*path.last().unwrap()) {
+            path.push(parent);
+        }
+
+        let centered = |affine: Affine2D, width: f32, height: f32| {
+            let (cx, cy) = (width / 2.0, height / 2.0);
+            Affine2D::translation(cx, cy)
+                .then(&affine)
+                .then(&Affine2D::translation(-cx, -cy))
+        };
 
-    /// Get render node data
-    pub fn get_render_node(&self, node: LayoutNodeId) -> Option<&RenderNode> {
-        self.render_nodes.get(&node)
+        let mut transform = Affine2D::IDENTITY;
+        for (i, &id) in path.iter().rev().enumerate() {
+            let bounds = self.get_render_bounds(id, (0.0, 0.0))?;
+            transform = transform.then(&Affine2D::translation(bounds.x, bounds.y));
+            if let Some(Transform::Affine2D(affine)) = self
+                .render_nodes
+                .get(&id)
+                .and_then(|n| n.props.transform.clone())
+            {
+                transform = transform.then(&centered(affine, bounds.width, bounds.height));
+            }
+            if let Some(Transform::Affine2D(affine)) = self.get_motion_transform(id) {
+                transform = transform.then(&affine);
+            }
+            if let Some((sx, sy)) = self.get_motion_scale(id) {
+                let scale = Affine2D::scale(sx, sy);
+                transform = transform.then(&centered(scale, bounds.width, bounds.height));
+            }
+            if let Some(deg) = self.get_motion_rotation(id) {
+                let rotation = Affine2D::rotation(deg.to_radians());
+                transform = transform.then(&centered(rotation, bounds.width, bounds.height));
+            }
+            if i + 1 < path.len() {
+                let (scroll_x, scroll_y) = self.get_scroll_offset(id);
+                transform = transform.then(&Affine2D::translation(scroll_x, scroll_y));
+            }
+        }
+
+        Some(transform.inverse()?.transform_point(Point::new(x, y)))
     }
 
     /// Get the cursor style for a node
@@ -6014,35 +7922,43 @@ impl RenderTree {
     /// tree.render_to(&mut my_renderer);
     /// ```
     pub fn render_to<R: LayoutRenderer>(&self, renderer: &mut R) {
-        if let Some(root) = self.root {
+        if self.root.is_some() {
+            let roots = self.paint_roots();
+
             // Pass 1: Background elements
             {
                 let ctx = renderer.background();
-                self.render_layer_with_content(
-                    ctx,
-                    root,
-                    (0.0, 0.0),
-                    RenderLayer::Background,
-                    false,
-                );
+                for &(node, offset) in &roots {
+                    self.render_layer_with_content(
+                        ctx,
+                        node,
+                        offset,
+                        RenderLayer::Background,
+                        false,
+                    );
+                }
             }
 
             // Pass 2: Glass elements (to background context)
             {
                 let ctx = renderer.background();
-                self.render_layer_with_content(ctx, root, (0.0, 0.0), RenderLayer::Glass, false);
+                for &(node, offset) in &roots {
+                    self.render_layer_with_content(ctx, node, offset, RenderLayer::Glass, false);
+                }
             }
 
             // Pass 3: Foreground elements (including glass children)
             {
                 let ctx = renderer.foreground();
-                self.render_layer_with_content(
-                    ctx,
-                    root,
-                    (0.0, 0.0),
-                    RenderLayer::Foreground,
-                    false,
-                );
+                for &(node, offset) in &roots {
+                    self.render_layer_with_content(
+                        ctx,
+                        node,
+                        offset,
+                        RenderLayer::Foreground,
+                        false,
+                    );
+                }
             }
 
             // Pass 4: Render text elements
@@ -6062,7 +7978,7 @@ impl RenderTree {
         target_layer: RenderLayer,
         inside_glass: bool,
     ) {
-        let Some(bounds) = self.layout_tree.get_bounds(node, parent_offset) else {
+        let Some(bounds) = self.paint_bounds(node, parent_offset) else {
             return;
         };
 
@@ -6099,27 +8015,11 @@ impl RenderTree {
         // This matches CSS overflow:hidden behavior which clips to the padding box
         let clips_content = render_node.props.clips_content;
         if clips_content {
-            // Inset clip by border width to exclude border area from clipping region
-            let border_width = render_node.props.border_width;
-            let radius = render_node.props.border_radius;
-            let clip_rect = Rect::new(
-                border_width,
-                border_width,
-                (bounds.width - border_width * 2.0).max(0.0),
-                (bounds.height - border_width * 2.0).max(0.0),
-            );
-            // Adjust corner radius for inset - reduce by border width
-            let inset_radius = if radius.is_uniform() && radius.top_left > border_width {
-                CornerRadius::uniform((radius.top_left - border_width).max(0.0))
-            } else {
-                CornerRadius::default()
-            };
-            let clip_shape = if inset_radius.top_left > 0.0 {
-                ClipShape::rounded_rect(clip_rect, inset_radius)
-            } else {
-                ClipShape::rect(clip_rect)
-            };
-            ctx.push_clip(clip_shape);
+            ctx.push_clip(Self::content_clip_shape(
+                &render_node.props,
+                bounds.width,
+                bounds.height,
+            ));
         }
 
         // Determine the effective layer for this node
@@ -6246,7 +8146,7 @@ impl RenderTree {
                             width: bounds.width,
                             height: bounds.height,
                         };
-                        render_fn(ctx, canvas_bounds);
+                        render_fn(ctx, canvas_bounds, &self.canvas_frame);
                     }
                 }
                 // Text, SVG, Image are handled in separate passes
@@ -6265,7 +8165,7 @@ impl RenderTree {
         }
 
         // Traverse children
-        for child_id in self.layout_tree.children(node) {
+        for child_id in self.paint_children(node) {
             self.render_layer_with_content(
                 ctx,
                 child_id,
@@ -6644,6 +8544,47 @@ impl RenderTree {
     }
 }
 
+/// One axis of a sticky element, in its scroll container's viewport space
+struct StickyAxis {
+    /// Unshifted start edge
+    start: f32,
+    size: f32,
+    viewport: f32,
+    /// Start and end of the parent's content box
+    containing: (f32, f32),
+    /// Start of the next sibling sticking to the start inset
+    next_start: Option<f32>,
+    /// End of the previous sibling sticking to the end inset
+    prev_end: Option<f32>,
+}
+
+/// Shift along one axis that keeps a sticky element `start_inset` from the
+/// viewport start (or `end_inset` from its end) without leaving its
+/// containing block or overlapping the sibling that pushes it out
+fn sticky_shift(axis: StickyAxis, start_inset: Option<f32>, end_inset: Option<f32>) -> f32 {
+    let end = axis.start + axis.size;
+    if let Some(inset) = start_inset {
+        if axis.start < inset {
+            let mut limit = axis.containing.1 - end;
+            if let Some(next) = axis.next_start {
+                limit = limit.min(next - end);
+            }
+            return (inset - axis.start).min(limit).max(0.0);
+        }
+    }
+    if let Some(inset) = end_inset {
+        let edge = axis.viewport - inset;
+        if end > edge {
+            let mut limit = axis.containing.0 - axis.start;
+            if let Some(prev) = axis.prev_end {
+                limit = limit.max(prev - axis.start);
+            }
+            return (edge - end).max(limit).min(0.0);
+        }
+    }
+    0.0
+}
+
 /// Apply opacity to a brush by modifying its alpha component
 fn apply_opacity_to_brush(brush: &Brush, opacity: f32) -> Brush {
     match brush {
@@ -6671,6 +8612,11 @@ fn apply_opacity_to_brush(brush: &Brush, opacity: f32) -> Brush {
             blur_adjusted.opacity *= opacity;
             Brush::Blur(blur_adjusted)
         }
+        Brush::Pattern(pattern) => {
+            let mut pattern = pattern.clone();
+            pattern.opacity *= opacity;
+            Brush::Pattern(pattern)
+        }
     }
 }
 
@@ -6678,6 +8624,7 @@ fn apply_opacity_to_brush(brush: &Brush, opacity: f32) -> Brush {
 mod tests {
     use super::*;
     use crate::div::div;
+    use blinc_core::{DrawCommand, RecordingContext};
 
     #[test]
     fn test_render_tree_from_element() {
@@ -6705,4 +8652,746 @@ mod tests {
         assert_eq!(bounds.width, 200.0);
         assert_eq!(bounds.height, 200.0);
     }
+
+    #[test]
+    fn test_keyboard_focus_scrolls_nested_containers_into_view() {
+        use crate::interactive::InteractiveContext;
+        use crate::widgets::scroll::scroll;
+
+        let inner = scroll().w(200.0).h(100.0).child(
+            div()
+                .flex_col()
+                .child(div().w(200.0).h(250.0))
+                .child(div().id("target").focusable().w(200.0).h(40.0)),
+        );
+        let ui = scroll()
+            .w(200.0)
+            .h(200.0)
+            .child(div().flex_col().child(div().w(200.0).h(300.0)).child(inner));
+
+        let scheduler = Arc::new(Mutex::new(AnimationScheduler::new()));
+        let mut tree = RenderTree::from_element(&ui);
+        tree.set_animations(&scheduler);
+        tree.compute_layout(200.0, 200.0);
+        let target = tree.query_by_id("target").unwrap();
+
+        // Position of the target in the root viewport, after scrolling
+        let visible_y = |tree: &RenderTree| {
+            let mut y = tree.get_bounds(target).unwrap().y;
+            let mut node = target;
+            while let Some(parent) = tree.layout_tree.parent(node) {
+                y += tree.get_bounds(parent).unwrap().y + tree.get_scroll_offset(parent).1;
+                node = parent;
+            }
+            y
+        };
+        assert!(visible_y(&tree) > 200.0);
+
+        let mut ctx = InteractiveContext::new();
+        ctx.set_focus_order(tree.focus_order());
+        assert_eq!(ctx.focus_next(), Some(target));
+        assert!(tree.process_scroll_into_view(&mut ctx));
+
+        // Springs animate rather than jump
+        assert_eq!(tree.get_scroll_offset(tree.root().unwrap()), (0.0, 0.0));
+        let mut time_ms = 0;
+        while tree.tick_scroll_physics(time_ms) {
+            scheduler
+                .lock()
+                .unwrap()
+                .springs_iter_mut()
+                .for_each(|_, spring| spring.step(1.0 / 60.0));
+            time_ms += 16;
+            assert!(time_ms < 10_000, "scroll never settled");
+        }
+
+        let y = visible_y(&tree);
+        assert!((0.0..=160.0).contains(&y), "target at {y}");
+        // Already visible: nothing more to do
+        assert!(!tree.scroll_into_view(target, crate::selector::ScrollBlock::Nearest));
+    }
+
+    #[test]
+    fn test_focus_ring_only_after_keyboard_focus() {
+        use crate::interactive::InteractiveContext;
+        use crate::render_state::{Overlay, RenderState};
+
+        let ui = div().p_px(20.0).child(
+            div()
+                .id("button")
+                .w(60.0)
+                .h(30.0)
+                .rounded(4.0)
+                .focusable()
+                .focus_ring(Color::BLUE, 2.0),
+        );
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(200.0, 200.0);
+        let button = tree.query_by_id("button").unwrap();
+        let scheduler = Arc::new(Mutex::new(AnimationScheduler::new()));
+        let mut render_state = RenderState::new(scheduler);
+
+        let mut ctx = InteractiveContext::new();
+        ctx.set_focus_order(tree.focus_order());
+
+        // Pointer focus: no ring
+        ctx.sync_focus(Some(button));
+        assert!(ctx.is_focused(button));
+        assert!(!tree.add_focus_ring(&ctx, &mut render_state));
+
+        // Keyboard focus: ring just outside the button
+        ctx.blur();
+        assert_eq!(ctx.focus_next(), Some(button));
+        assert!(tree.add_focus_ring(&ctx, &mut render_state));
+        match render_state.overlays() {
+            [Overlay::FocusRing {
+                position,
+                size,
+                radius,
+                ..
+            }] => {
+                assert_eq!(*position, (18.0, 18.0));
+                assert_eq!(*size, (64.0, 34.0));
+                assert_eq!(*radius, 6.0);
+            }
+            other => panic!("expected one focus ring, got {}", other.len()),
+        }
+
+        // The same node reported again by the router keeps the ring
+        ctx.sync_focus(Some(button));
+        assert!(ctx.is_focus_visible(button));
+    }
+
+    #[test]
+    fn test_cache_layer_recordings_keep_subtree() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let draws = Arc::new(AtomicUsize::new(0));
+        let counter = draws.clone();
+        let ui = div().w(200.0).h(200.0).child(
+            div().w(100.0).h(100.0).cache_layer().child(
+                crate::canvas::canvas(move |ctx, bounds| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    ctx.fill_rect(
+                        Rect::new(0.0, 0.0, bounds.width, bounds.height),
+                        CornerRadius::default(),
+                        Brush::Solid(Color::RED),
+                    );
+                })
+                .w(50.0)
+                .h(50.0),
+            ),
+        );
+
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(200.0, 200.0);
+        let render_state =
+            crate::render_state::RenderState::new(Arc::new(Mutex::new(AnimationScheduler::new())));
+
+        // A recording context holds no layers, so every frame records the
+        // cached layer with its content instead of a bare sample
+        for frame in 1..=3 {
+            let mut ctx = RecordingContext::new(blinc_core::Size::new(200.0, 200.0));
+            tree.render_with_motion(&mut ctx, &render_state);
+            let commands = ctx.into_commands();
+            assert!(commands
+                .iter()
+                .any(|command| matches!(command, DrawCommand::PushLayer(config) if config.cache)));
+            assert!(!commands
+                .iter()
+                .any(|command| matches!(command, DrawCommand::SampleLayer { .. })));
+            assert_eq!(draws.load(Ordering::SeqCst), frame);
+        }
+        assert!(tree.debug_stats().cached_layer_count > 0);
+    }
+
+    #[test]
+    fn test_cache_layer_skipped_for_layer_effects() {
+        let ui = div().w(200.0).h(200.0).child(
+            div()
+                .w(100.0)
+                .h(100.0)
+                .cache_layer()
+                .child(div().w(50.0).h(50.0).bg(Color::RED).blur(4.0)),
+        );
+
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(200.0, 200.0);
+        let render_state =
+            crate::render_state::RenderState::new(Arc::new(Mutex::new(AnimationScheduler::new())));
+        let mut ctx = RecordingContext::new(blinc_core::Size::new(200.0, 200.0));
+        tree.render_with_motion(&mut ctx, &render_state);
+
+        // The subtree is drawn normally, keeping its blur layer
+        let commands = ctx.into_commands();
+        assert!(!commands
+            .iter()
+            .any(|command| matches!(command, DrawCommand::PushLayer(config) if config.cache)));
+        assert!(commands.iter().any(
+            |command| matches!(command, DrawCommand::PushLayer(config) if !config.effects.is_empty())
+        ));
+        assert_eq!(tree.debug_stats().cached_layer_count, 0);
+    }
+    #[test]
+    fn test_damage_rect_bounded_to_dirty_element() {
+        let mut ui = div().w(1000.0).h(800.0).flex_col();
+        for _ in 0..20 {
+            let mut row = div().h(40.0).flex_row();
+            for _ in 0..10 {
+                row = row.child(div().w(100.0).h(40.0).bg(Color::BLUE));
+            }
+            ui = ui.child(row);
+        }
+        ui = ui.child(
+            div()
+                .absolute()
+                .left(300.0)
+                .top(200.0)
+                .w(40.0)
+                .h(20.0)
+                .bg(Color::RED)
+                .shadow_params(0.0, 2.0, 4.0, Color::BLACK),
+        );
+
+        let mut tree = RenderTree::from_element(&ui);
+        tree.set_scale_factor(2.0);
+        tree.compute_layout(1000.0, 800.0);
+        let root = tree.root().unwrap();
+        let dirty = *tree.layout_tree.children(root).last().unwrap();
+
+        // Shadow margin: blur * 3 + |offset| = 14 logical px on every side
+        let damage = tree.damage_rect(&[dirty]).unwrap();
+        assert_eq!(damage, Rect::new(572.0, 372.0, 136.0, 96.0));
+
+        // Dirtying the root damages the whole surface
+        let full = tree.damage_rect(&[root]).unwrap();
+        assert_eq!(full, Rect::new(0.0, 0.0, 2000.0, 1600.0));
+    }
+
+    #[test]
+    fn test_prop_update_damages_old_and_new_paint() {
+        let ui = div()
+            .w(400.0)
+            .h(300.0)
+            .flex_col()
+            .child(div().w(100.0).h(100.0).bg(Color::BLUE))
+            .child(
+                div()
+                    .w(40.0)
+                    .h(20.0)
+                    .bg(Color::RED)
+                    .shadow_params(0.0, 2.0, 4.0, Color::BLACK),
+            );
+
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(400.0, 300.0);
+        let root = tree.root().unwrap();
+        let node = tree.layout_tree.children(root)[1];
+
+        // Hover swaps the fill and drops the shadow: the region still covers
+        // the shadow that has to be erased
+        let mut props = tree.render_nodes[&node].props.clone();
+        props.background = Some(Color::GREEN.into());
+        props.shadow = None;
+        let damage = tree.apply_prop_updates(&[(node, props)]).unwrap();
+        assert_eq!(damage, Rect::new(0.0, 86.0, 54.0, 48.0));
+        assert!(tree.render_nodes[&node].props.shadow.is_none());
+    }
+
+    #[test]
+    fn test_pen_event_delivers_pressure_and_tilt() {
+        use blinc_core::events::{event_types, PenState};
+        use std::sync::Mutex;
+
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&received);
+        let ui = div()
+            .w(200.0)
+            .h(200.0)
+            .child(div().w(100.0).h(100.0).on_pen(move |ctx| {
+                log.lock()
+                    .unwrap()
+                    .push((ctx.event_type, ctx.pen, ctx.local_x, ctx.local_y));
+            }));
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(200.0, 200.0);
+        let canvas = tree.layout_tree.children(tree.root().unwrap())[0];
+
+        let stroke = PenState {
+            pressure: 0.6,
+            tilt_x: 30.0,
+            tilt_y: -15.0,
+            barrel_button: true,
+            eraser: false,
+        };
+        tree.dispatch_pen_event(canvas, event_types::PEN_DOWN, 20.0, 40.0, stroke);
+        // A device without tilt sensing still reports pressure
+        let flat = PenState {
+            pressure: 0.4,
+            ..Default::default()
+        };
+        tree.dispatch_pen_event(canvas, event_types::PEN_MOVE, 25.0, 45.0, flat);
+
+        let received = received.lock().unwrap();
+        assert_eq!(
+            *received,
+            vec![
+                (event_types::PEN_DOWN, Some(stroke), 20.0, 40.0),
+                (event_types::PEN_MOVE, Some(flat), 25.0, 45.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_disabled_container_blocks_inner_click() {
+        use crate::event_router::EventRouter;
+        use blinc_core::events::event_types;
+        use std::sync::atomic::{AtomicU32, Ordering};
+        use std::sync::Arc;
+
+        let clicks = Arc::new(AtomicU32::new(0));
+        let build = |disabled: bool| {
+            let clicks = Arc::clone(&clicks);
+            div()
+                .w(200.0)
+                .h(200.0)
+                .child(div().w(100.0).h(100.0).disabled(disabled).child(
+                    div().w(50.0).h(50.0).on_click(move |_| {
+                        clicks.fetch_add(1, Ordering::SeqCst);
+                    }),
+                ))
+        };
+        let click = |tree: &mut RenderTree| {
+            let hit = EventRouter::new().hit_test(tree, 10.0, 10.0).unwrap();
+            let root = tree.root().unwrap();
+            let container = tree.layout_tree.children(root)[0];
+            let button = tree.layout_tree.children(container)[0];
+            // Dispatching straight to the button is blocked too
+            for node in [hit.node, button] {
+                tree.dispatch_event(node, event_types::POINTER_DOWN, 10.0, 10.0);
+                tree.dispatch_event(node, event_types::POINTER_UP, 10.0, 10.0);
+            }
+            (hit.node, container)
+        };
+
+        let mut tree = RenderTree::from_element(&build(true));
+        tree.compute_layout(200.0, 200.0);
+        let (target, container) = click(&mut tree);
+        assert_eq!(target, container);
+        assert_eq!(clicks.load(Ordering::SeqCst), 0);
+        assert!(tree.get_render_node(container).unwrap().props.opacity < 1.0);
+
+        let mut tree = RenderTree::from_element(&build(false));
+        tree.compute_layout(200.0, 200.0);
+        click(&mut tree);
+        assert_eq!(clicks.load(Ordering::SeqCst), 2);
+        assert_eq!(tree.get_render_node(container).unwrap().props.opacity, 1.0);
+    }
+
+    #[test]
+    fn test_content_clip_follows_per_corner_radius() {
+        let mut props = RenderProps {
+            border_radius: CornerRadius::new(16.0, 16.0, 0.0, 4.0),
+            border_width: 2.0,
+            clips_content: true,
+            ..Default::default()
+        };
+        props.border_sides.left = Some(BorderSide::new(6.0, Color::BLACK));
+
+        // Insets per side; each corner loses its wider adjacent border
+        match RenderTree::content_clip_shape(&props, 200.0, 100.0) {
+            ClipShape::RoundedRect {
+                rect,
+                corner_radius,
+            } => {
+                assert_eq!(rect, Rect::new(6.0, 2.0, 192.0, 96.0));
+                assert_eq!(corner_radius, CornerRadius::new(10.0, 14.0, 0.0, 0.0));
+            }
+            other => panic!("expected a rounded clip, got {other:?}"),
+        }
+
+        // Square corners clip to a plain rect
+        props.border_radius = CornerRadius::ZERO;
+        assert!(matches!(
+            RenderTree::content_clip_shape(&props, 200.0, 100.0),
+            ClipShape::Rect(_)
+        ));
+    }
+
+    #[test]
+    fn test_portal_escapes_ancestor_clip() {
+        use crate::event_router::EventRouter;
+
+        let ui = div().w(300.0).h(300.0).child(
+            div()
+                .w(100.0)
+                .h(100.0)
+                .overflow_clip()
+                .portal(div().absolute().top(80.0).w(150.0).h(120.0).bg(Color::RED)),
+        );
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(300.0, 300.0);
+        let root = tree.root().unwrap();
+        let anchor = tree.layout_tree.children(root)[0];
+        let host = tree.layout_tree.children(anchor)[0];
+        let content = tree.layout_tree.children(host)[0];
+        assert_eq!(tree.portals(), vec![(host, (0.0, 0.0))]);
+
+        // The portal paints last, after the anchor's clip is popped
+        let mut ctx = RecordingContext::new(blinc_core::Size::new(300.0, 300.0));
+        tree.render(&mut ctx);
+        let mut depth = 0i32;
+        let mut red_fill_depth = None;
+        for command in ctx.into_commands() {
+            match command {
+                DrawCommand::PushClip(_) => depth += 1,
+                DrawCommand::PopClip => depth -= 1,
+                DrawCommand::FillRect {
+                    brush: Brush::Solid(color),
+                    ..
+                } if color == Color::RED => red_fill_depth = Some(depth),
+                _ => {}
+            }
+        }
+        assert_eq!(red_fill_depth, Some(0));
+
+        // Hittable outside the anchor, still bubbling through it
+        let hit = EventRouter::new().hit_test(&tree, 50.0, 150.0).unwrap();
+        assert_eq!(hit.node, content);
+        assert_eq!(hit.ancestors, vec![root, anchor, host, content]);
+        let hit = EventRouter::new().hit_test(&tree, 50.0, 50.0).unwrap();
+        assert_eq!(hit.node, anchor);
+    }
+
+    #[test]
+    fn test_sticky_header_pinned_until_section_ends() {
+        use crate::event_router::EventRouter;
+        use crate::widgets::scroll::scroll;
+
+        let section = || {
+            div()
+                .w(200.0)
+                .h(300.0)
+                .flex_col()
+                .child(div().w(200.0).h(20.0).sticky_top(0.0))
+                .child(div().w(200.0).h(280.0))
+        };
+        let ui = scroll()
+            .w(200.0)
+            .h(100.0)
+            .child(div().w(200.0).flex_col().child(section()).child(section()));
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(200.0, 100.0);
+        let scroller = tree.root().unwrap();
+        let content = tree.layout_tree.children(scroller)[0];
+        let sections = tree.layout_tree.children(content);
+        let header_a = tree.layout_tree.children(sections[0])[0];
+        let header_b = tree.layout_tree.children(sections[1])[0];
+        let scroll_to = |tree: &RenderTree, y: f32| {
+            tree.scroll_physics[&scroller].lock().unwrap().offset_y = -y;
+        };
+
+        // Not scrolled: headers sit at their layout positions
+        assert_eq!(tree.sticky_offset(header_a), (0.0, 0.0));
+
+        // Pinned to the top while the section is in view
+        scroll_to(&tree, 50.0);
+        assert_eq!(tree.sticky_offset(header_a), (0.0, 50.0));
+        assert_eq!(tree.sticky_offset(header_b), (0.0, 0.0));
+        let hit = EventRouter::new().hit_test(&tree, 10.0, 5.0).unwrap();
+        assert_eq!(hit.node, header_a);
+
+        // Carried away by the section's bottom edge
+        scroll_to(&tree, 290.0);
+        assert_eq!(tree.sticky_offset(header_a), (0.0, 280.0));
+        assert_eq!(tree.sticky_offset(header_b), (0.0, 0.0));
+
+        // The next section's header takes over
+        scroll_to(&tree, 310.0);
+        assert_eq!(tree.sticky_offset(header_a), (0.0, 280.0));
+        assert_eq!(tree.sticky_offset(header_b), (0.0, 10.0));
+    }
+
+    #[test]
+    fn test_sticky_sibling_pushes_pinned_header() {
+        use crate::widgets::scroll::scroll;
+
+        let header = || div().w(200.0).h(20.0).sticky_top(0.0);
+        let row = || div().w(200.0).h(100.0);
+        let ui = scroll().w(200.0).h(100.0).child(
+            div()
+                .w(200.0)
+                .flex_col()
+                .child(header())
+                .child(row())
+                .child(header())
+                .child(row()),
+        );
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(200.0, 100.0);
+        let scroller = tree.root().unwrap();
+        let list = tree.layout_tree.children(scroller)[0];
+        let items = tree.layout_tree.children(list);
+
+        // Headers paint above the rows scrolling beneath them
+        assert_eq!(
+            tree.paint_children(list),
+            vec![items[1], items[3], items[0], items[2]]
+        );
+
+        tree.scroll_physics[&scroller].lock().unwrap().offset_y = -60.0;
+        assert_eq!(tree.sticky_offset(items[0]), (0.0, 60.0));
+
+        // The incoming header displaces the pinned one
+        tree.scroll_physics[&scroller].lock().unwrap().offset_y = -110.0;
+        assert_eq!(tree.sticky_offset(items[0]), (0.0, 100.0));
+        assert_eq!(tree.sticky_offset(items[2]), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_hover_and_focus_within_style_ancestors() {
+        use crate::event_router::EventRouter;
+
+        let group = || {
+            div()
+                .w(100.0)
+                .h(100.0)
+                .on_focus_within(|s| s.opacity(0.25))
+                .child(div().w(50.0).h(50.0).child(div().w(20.0).h(20.0)))
+        };
+        let ui = div()
+            .w(300.0)
+            .h(300.0)
+            .on_hover_within(|s| s.opacity(0.5))
+            .on_focus_within(|s| s.rounded(12.0))
+            .child(group())
+            .child(group());
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(300.0, 300.0);
+
+        let root = tree.root().unwrap();
+        let groups = tree.layout_tree.children(root);
+        let (first, second) = (groups[0], groups[1]);
+        let leaf = |group| {
+            let middle = tree.layout_tree.children(group)[0];
+            tree.layout_tree.children(middle)[0]
+        };
+        let (deep_first, deep_second) = (leaf(first), leaf(second));
+        let props = |tree: &RenderTree, node| tree.get_render_node(node).unwrap().props.clone();
+
+        // Hovering a deep child restyles the ancestor
+        let mut router = EventRouter::new();
+        router.on_mouse_move(&tree, 10.0, 10.0);
+        assert!(tree.apply_within_styles(&router));
+        assert_eq!(props(&tree, root).opacity, 0.5);
+        assert!(!tree.apply_within_styles(&router));
+
+        // Focus in the second group: the root and that group, not the first
+        router.set_focus(Some(deep_second));
+        tree.apply_within_styles(&router);
+        assert_eq!(props(&tree, root).border_radius.top_left, 12.0);
+        assert_eq!(props(&tree, root).opacity, 0.5);
+        assert_eq!(props(&tree, second).opacity, 0.25);
+        assert_eq!(props(&tree, first).opacity, 1.0);
+
+        router.set_focus(Some(deep_first));
+        router.on_mouse_leave();
+        tree.apply_within_styles(&router);
+        assert_eq!(props(&tree, first).opacity, 0.25);
+        assert_eq!(props(&tree, second).opacity, 1.0);
+        assert_eq!(props(&tree, root).opacity, 1.0);
+        assert_eq!(props(&tree, root).border_radius.top_left, 12.0);
+
+        router.set_focus(None);
+        tree.apply_within_styles(&router);
+        assert_eq!(props(&tree, root).border_radius.top_left, 0.0);
+        assert_eq!(props(&tree, first).opacity, 1.0);
+    }
+
+    #[test]
+    fn test_nested_scroll_chains_remaining_delta() {
+        use crate::scroll::{scroll, OverscrollBehavior, ScrollDirection};
+
+        let build = |inner: crate::scroll::Scroll| {
+            scroll().w(200.0).h(200.0).child(
+                div()
+                    .flex_col()
+                    .w(200.0)
+                    .child(inner.w(200.0).h(100.0).child(div().w(400.0).h(300.0)))
+                    .child(div().w(200.0).h(400.0)),
+            )
+        };
+        let offsets = |tree: &RenderTree, node: LayoutNodeId| {
+            let p = tree.scroll_physics[&node].lock().unwrap();
+            (p.offset_x, p.offset_y)
+        };
+        let setup = |inner: crate::scroll::Scroll| {
+            let mut tree = RenderTree::from_element(&build(inner));
+            tree.compute_layout(200.0, 200.0);
+            let outer = tree.root().unwrap();
+            let content = tree.layout_tree.children(outer)[0];
+            let inner = tree.layout_tree.children(content)[0];
+            (tree, vec![outer, content, inner])
+        };
+
+        // Inner scroll has 20px left; the other 30px scroll the parent
+        let (mut tree, chain) = setup(scroll());
+        tree.scroll_physics[&chain[2]].lock().unwrap().offset_y = -180.0;
+        let remaining = tree.dispatch_scroll_chain(chain[2], &chain, 10.0, 10.0, 0.0, -50.0);
+        assert_eq!(remaining, (0.0, 0.0));
+        assert_eq!(offsets(&tree, chain[2]).1, -200.0);
+        assert_eq!(offsets(&tree, chain[0]).1, -30.0);
+
+        // Once the inner scroll sits at its edge, every further event keeps
+        // scrolling the parent instead of bouncing the inner scroll
+        for _ in 0..3 {
+            let remaining = tree.dispatch_scroll_chain(chain[2], &chain, 10.0, 10.0, 0.0, -20.0);
+            assert_eq!(remaining, (0.0, 0.0));
+        }
+        assert_eq!(offsets(&tree, chain[2]).1, -200.0);
+        assert_eq!(offsets(&tree, chain[0]).1, -90.0);
+        assert!(!tree.scroll_physics[&chain[2]]
+            .lock()
+            .unwrap()
+            .is_overscrolling_y());
+
+        // The outermost scroll bounces once it reaches its own edge
+        tree.scroll_physics[&chain[0]].lock().unwrap().offset_y = -300.0;
+        tree.dispatch_scroll_chain(chain[2], &chain, 10.0, 10.0, 0.0, -20.0);
+        assert_eq!(offsets(&tree, chain[2]).1, -200.0);
+        assert!(tree.scroll_physics[&chain[0]]
+            .lock()
+            .unwrap()
+            .is_overscrolling_y());
+
+        // A horizontal inner scroll passes vertical delta straight through
+        let (mut tree, chain) = setup(scroll().direction(ScrollDirection::Horizontal));
+        tree.dispatch_scroll_chain(chain[2], &chain, 10.0, 10.0, -15.0, -40.0);
+        assert_eq!(offsets(&tree, chain[2]), (-15.0, 0.0));
+        assert_eq!(offsets(&tree, chain[0]).1, -40.0);
+
+        // Contain keeps the leftover delta from reaching the parent
+        let (mut tree, chain) = setup(scroll().overscroll_behavior(OverscrollBehavior::Contain));
+        tree.scroll_physics[&chain[2]].lock().unwrap().offset_y = -180.0;
+        let remaining = tree.dispatch_scroll_chain(chain[2], &chain, 10.0, 10.0, 0.0, -50.0);
+        assert_eq!(remaining, (0.0, 0.0));
+        assert_eq!(offsets(&tree, chain[0]).1, 0.0);
+    }
+
+    #[test]
+    fn test_lazy_content_mounts_near_viewport() {
+        use crate::scroll::scroll;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let builds = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&builds);
+        let ui = scroll().w(200.0).h(200.0).child(
+            div()
+                .flex_col()
+                .w(200.0)
+                .children((0..10).map(|_| div().w(200.0).h(100.0)))
+                .child(
+                    div()
+                        .w(200.0)
+                        .defer_until_visible(move || {
+                            counter.fetch_add(1, Ordering::SeqCst);
+                            div().w(200.0).h(100.0)
+                        })
+                        .lazy_overscan(50.0),
+                )
+                .child(div().w(200.0).h(100.0)),
+        );
+
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(200.0, 200.0);
+        let scroller = tree.root().unwrap();
+        let column = tree.layout_tree.children(scroller)[0];
+        let lazy = tree.layout_tree.children(column)[10];
+        let after = tree.layout_tree.children(column)[11];
+        let set_offset = |tree: &mut RenderTree, y: f32| {
+            tree.scroll_physics[&scroller].lock().unwrap().offset_y = y;
+            tree.compute_layout(200.0, 200.0);
+        };
+
+        // 800px below the viewport: not built, takes no space yet
+        assert!(!tree.is_lazy_mounted(lazy));
+        assert_eq!(builds.load(Ordering::SeqCst), 0);
+        assert_eq!(
+            tree.layout_tree.get_bounds(after, (0.0, 0.0)).unwrap().y,
+            1000.0
+        );
+
+        // Scrolled to within the overscan margin: built and laid out
+        set_offset(&mut tree, -760.0);
+        assert!(tree.is_lazy_mounted(lazy));
+        assert_eq!(builds.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            tree.layout_tree.get_bounds(after, (0.0, 0.0)).unwrap().y,
+            1100.0
+        );
+
+        // Scrolled back to the top: dropped, but its space is kept
+        set_offset(&mut tree, 0.0);
+        assert!(!tree.is_lazy_mounted(lazy));
+        assert!(tree.layout_tree.children(lazy).is_empty());
+        assert_eq!(
+            tree.layout_tree.get_bounds(after, (0.0, 0.0)).unwrap().y,
+            1100.0
+        );
+
+        // And rebuilt when it comes back
+        set_offset(&mut tree, -800.0);
+        assert!(tree.is_lazy_mounted(lazy));
+        assert_eq!(builds.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_responsive_content_follows_breakpoints() {
+        let widths = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&widths);
+        let ui = div().w_full().h_full().child(
+            crate::div::responsive(move |width| {
+                seen.lock().unwrap().push(width);
+                if width >= 800.0 {
+                    div()
+                        .id("wide")
+                        .flex_row()
+                        .child(div().id("sidebar").w(240.0))
+                } else {
+                    div().id("narrow").child(div().id("drawer-button"))
+                }
+            })
+            .breakpoints([800.0])
+            .breakpoint_hysteresis(20.0),
+        );
+
+        let mut tree = RenderTree::from_element(&ui);
+        let container = tree.layout_tree.children(tree.root().unwrap())[0];
+        let layout_at = |tree: &mut RenderTree, width: f32| {
+            tree.compute_layout(width, 600.0);
+            (
+                tree.query_by_id("narrow").is_some(),
+                tree.query_by_id("wide").is_some(),
+            )
+        };
+
+        assert_eq!(layout_at(&mut tree, 400.0), (true, false));
+        assert_eq!(tree.responsive_range(container), Some(0));
+        assert_eq!(layout_at(&mut tree, 1200.0), (false, true));
+        assert_eq!(tree.responsive_range(container), Some(1));
+        assert!(tree.query_by_id("sidebar").is_some());
+        assert!(tree.query_by_id("drawer-button").is_none());
+        assert_eq!(*widths.lock().unwrap(), [400.0, 1200.0]);
+
+        // Resizing within a range, or just past its edge, doesn't rebuild
+        assert_eq!(layout_at(&mut tree, 1000.0), (false, true));
+        assert_eq!(layout_at(&mut tree, 790.0), (false, true));
+        assert_eq!(layout_at(&mut tree, 815.0), (false, true));
+        assert_eq!(widths.lock().unwrap().len(), 2);
+
+        // Clearly past it does
+        assert_eq!(layout_at(&mut tree, 700.0), (true, false));
+        assert_eq!(layout_at(&mut tree, 810.0), (true, false));
+        assert_eq!(*widths.lock().unwrap(), [400.0, 1200.0, 700.0]);
+    }
 }
@@ -30,7 +30,7 @@ use std::ops::Range;
 use std::sync::Arc;
 
 use blinc_core::events::event_types;
-use blinc_core::{Color, Shadow, Transform};
+use blinc_core::{CachePolicy, Color, Shadow, Transform};
 use taffy::prelude::*;
 
 use crate::div::{
@@ -182,6 +182,8 @@ impl RichText {
                     strikethrough: span.strikethrough,
                     link_url: span.link_url.clone(),
                     token_type: span.token_type.clone(),
+                    font_family: span.font_family.clone(),
+                    font_size: span.font_size,
                 });
             }
             content.push_str(&line.text);
@@ -267,6 +269,27 @@ impl RichText {
         self
     }
 
+    /// Set the font size of a byte range
+    ///
+    /// The line grows to fit the largest size.
+    pub fn font_size_range(mut self, range: Range<usize>, size: f32) -> Self {
+        self.add_or_update_span(range, |span| span.font_size = Some(size));
+        self.update_size_estimate();
+        self
+    }
+
+    /// Set the font family of a byte range
+    pub fn font_family_range(mut self, range: Range<usize>, family: FontFamily) -> Self {
+        self.add_or_update_span(range, |span| span.font_family = Some(family.clone()));
+        self.update_size_estimate();
+        self
+    }
+
+    /// Use the monospace font for a byte range (e.g. inline code)
+    pub fn monospace_range(self, range: Range<usize>) -> Self {
+        self.font_family_range(range, FontFamily::monospace())
+    }
+
     /// Helper to add or update a span for a range
     fn add_or_update_span(&mut self, range: Range<usize>, mut modifier: impl FnMut(&mut TextSpan)) {
         let range_start = range.start.min(self.content.len());
@@ -487,10 +510,23 @@ impl RichText {
 
         self.measured_width = metrics.width;
         self.ascender = metrics.ascender;
+        let mut height = self.font_size * self.line_height;
+
+        // Spans with their own font or size are measured segment by segment,
+        // and the line grows to fit the tallest one
+        if self
+            .spans
+            .iter()
+            .any(|s| s.font_size.is_some() || s.font_family.is_some())
+        {
+            let data = self.styled_text_data();
+            let segments = data.segments();
+            self.measured_width = segments.iter().map(|s| s.width).sum();
+            height = data.line_metrics(&segments).0;
+        }
 
-        self.style.size.width = Dimension::Length(metrics.width);
-        let standardized_height = self.font_size * self.line_height;
-        self.style.size.height = Dimension::Length(standardized_height);
+        self.style.size.width = Dimension::Length(self.measured_width);
+        self.style.size.height = Dimension::Length(height);
         self.style.max_size.width = Dimension::Percent(1.0);
 
         if !self.wrap {
@@ -498,63 +534,41 @@ impl RichText {
         }
     }
 
-    /// Calculate approximate x positions for link regions based on character widths
+    /// Render data for this element, as the renderer will see it
+    fn styled_text_data(&self) -> crate::renderer::StyledTextData {
+        self.styled_text_render_info()
+            .expect("rich text always has render info")
+            .into()
+    }
+
+    /// Calculate x positions for link regions from the measured segments
     fn calculate_link_regions(&self) -> Vec<LinkRegion> {
-        let mut regions = Vec::new();
         let content_len = self.content.len();
+        let segments = self.styled_text_data().segments();
+        // Span boundaries are segment boundaries, so positions are exact sums
+        let x_at = |pos: usize| -> f32 {
+            segments
+                .iter()
+                .filter(|s| s.end <= pos)
+                .map(|s| s.width)
+                .sum()
+        };
 
-        // Use monospace approximation or measure each segment
-        // For better accuracy, measure character by character up to each boundary
-        let mut options = crate::text_measure::TextLayoutOptions::new();
-        options.font_name = self.font_family.name.clone();
-        options.generic_font = self.font_family.generic;
-        options.font_weight = self.weight.weight();
-        options.italic = self.italic;
-
-        for span in &self.spans {
-            if let Some(ref url) = span.link_url {
+        self.spans
+            .iter()
+            .filter_map(|span| {
+                let url = span.link_url.as_ref()?;
                 let start = span.start.min(content_len);
                 let end = span.end.min(content_len);
-
-                if start >= end {
-                    continue;
-                }
-
-                // Measure width of text before this link
-                let x_start = if start > 0 {
-                    let prefix = &self.content[..start];
-                    let prefix_metrics = crate::text_measure::measure_text_with_options(
-                        prefix,
-                        self.font_size,
-                        &options,
-                    );
-                    prefix_metrics.width
-                } else {
-                    0.0
-                };
-
-                // Measure width up to end of link
-                let x_end = {
-                    let to_end = &self.content[..end];
-                    let to_end_metrics = crate::text_measure::measure_text_with_options(
-                        to_end,
-                        self.font_size,
-                        &options,
-                    );
-                    to_end_metrics.width
-                };
-
-                regions.push(LinkRegion {
+                (start < end).then(|| LinkRegion {
                     start,
                     end,
                     url: url.clone(),
-                    x_start,
-                    x_end,
-                });
-            }
-        }
-
-        regions
+                    x_start: x_at(start),
+                    x_end: x_at(end),
+                })
+            })
+            .collect()
     }
 
     /// Set up click handlers for links
@@ -624,6 +638,12 @@ impl ElementBuilder for RichText {
             pointer_events_none: false,
             cursor: self.cursor,
             layer_effects: Vec::new(),
+            cache_policy: CachePolicy::None,
+            tab_index: None,
+            focus_ring: None,
+            disabled: false,
+            portal: false,
+            sticky: None,
             motion_is_exiting: false,
         }
     }
@@ -659,6 +679,8 @@ impl ElementBuilder for RichText {
                     underline: span.underline,
                     strikethrough: span.strikethrough,
                     link_url: span.link_url.clone(),
+                    font_family: span.font_family.clone(),
+                    font_size: span.font_size,
                 })
                 .collect(),
             font_size: self.font_size,
@@ -693,6 +715,33 @@ pub fn rich_text_styled(styled: crate::styled_text::StyledText) -> RichText {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_span_font_size_and_family() {
+        let rt = rich_text("Call foo() now")
+            .size(14.0)
+            .font_size_range(5..10, 28.0)
+            .monospace_range(5..10);
+        let data = rt.styled_text_data();
+        let segments = data.segments();
+        assert_eq!(segments.len(), 3);
+
+        // The code span is shaped at its own size and family
+        let code = &segments[1];
+        assert_eq!(&rt.content()[code.start..code.end], "foo()");
+        assert_eq!(code.font_size, 28.0);
+        assert_eq!(code.font_family, FontFamily::monospace());
+        assert_eq!(segments[0].font_size, 14.0);
+        assert_eq!(segments[2].x, code.x + code.width);
+
+        // The line box fits the tallest span, and all spans share its baseline
+        let (height, baseline) = data.line_metrics(&segments);
+        assert_eq!(height, 28.0 * 1.2);
+        assert!(baseline >= code.ascender && baseline <= height);
+        assert_eq!(rt.style.size.height, Dimension::Length(height));
+        let width: f32 = segments.iter().map(|s| s.width).sum();
+        assert_eq!(rt.style.size.width, Dimension::Length(width));
+    }
+
     #[test]
     fn test_plain_text() {
         let rt = rich_text("Hello World");
@@ -397,6 +397,8 @@ impl<'a> Parser<'a> {
                     strikethrough: self.current_style.strikethrough,
                     link_url: self.current_style.link_url.clone(),
                     token_type: None,
+                    font_family: None,
+                    font_size: None,
                 };
                 self.spans.push(span);
             }
@@ -422,6 +424,8 @@ impl<'a> Parser<'a> {
                     strikethrough: self.current_style.strikethrough,
                     link_url: self.current_style.link_url.clone(),
                     token_type: None,
+                    font_family: None,
+                    font_size: None,
                 };
                 self.spans.push(span);
             }
@@ -440,13 +444,13 @@ impl<'a> Parser<'a> {
     }
 }
 
-/// Parse a color string (hex, named, or rgba)
+/// Parse a color string (hex, named, rgb(a) or hsl(a))
 fn parse_color(s: &str) -> Option<Color> {
     let s = s.trim();
 
-    // Hex color
+    // Hex color (#RGB, #RGBA, #RRGGBB, #RRGGBBAA)
     if s.starts_with('#') {
-        return parse_hex_color(&s[1..]);
+        return Color::from_hex_str(s).ok();
     }
 
     // RGBA
@@ -459,51 +463,15 @@ fn parse_color(s: &str) -> Option<Color> {
         return parse_rgb(&s[4..s.len() - 1]);
     }
 
+    // HSL / HSLA
+    if s.starts_with("hsl") {
+        return Color::from_css(s).ok();
+    }
+
     // Named colors
     parse_named_color(s)
 }
 
-fn parse_hex_color(hex: &str) -> Option<Color> {
-    let hex = hex.trim_start_matches('#');
-
-    match hex.len() {
-        3 => {
-            // Short form: #RGB -> #RRGGBB
-            let r = u8::from_str_radix(&hex[0..1].repeat(2), 16).ok()?;
-            let g = u8::from_str_radix(&hex[1..2].repeat(2), 16).ok()?;
-            let b = u8::from_str_radix(&hex[2..3].repeat(2), 16).ok()?;
-            Some(Color::rgb(
-                r as f32 / 255.0,
-                g as f32 / 255.0,
-                b as f32 / 255.0,
-            ))
-        }
-        6 => {
-            let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
-            let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
-            let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
-            Some(Color::rgb(
-                r as f32 / 255.0,
-                g as f32 / 255.0,
-                b as f32 / 255.0,
-            ))
-        }
-        8 => {
-            let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
-            let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
-            let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
-            let a = u8::from_str_radix(&hex[6..8], 16).ok()?;
-            Some(Color::rgba(
-                r as f32 / 255.0,
-                g as f32 / 255.0,
-                b as f32 / 255.0,
-                a as f32 / 255.0,
-            ))
-        }
-        _ => None,
-    }
-}
-
 fn parse_rgba(s: &str) -> Option<Color> {
     let parts: Vec<&str> = s.split(',').map(|p| p.trim()).collect();
     if parts.len() != 4 {
@@ -694,9 +662,25 @@ mod tests {
 
     #[test]
     fn test_hex_color_parsing() {
-        assert!(parse_hex_color("FF0000").is_some());
-        assert!(parse_hex_color("F00").is_some());
-        assert!(parse_hex_color("FF0000FF").is_some());
+        assert!(parse_color("#FF0000").is_some());
+        assert!(parse_color("#F00").is_some());
+        assert!(parse_color("#FF0000FF").is_some());
+        assert_eq!(
+            parse_color("#F00A").map(|c| c.to_hex()).as_deref(),
+            Some("#ff0000aa")
+        );
+        // #RGBA shorthand: fully transparent yellow
+        assert_eq!(
+            parse_color("#FF00").map(|c| c.to_hex()).as_deref(),
+            Some("#ffff0000")
+        );
+        assert!(parse_color("#FF000").is_none());
+    }
+
+    #[test]
+    fn test_hsl_color_parsing() {
+        let color = parse_color("hsl(240, 100%, 50%)").unwrap();
+        assert_eq!(color.to_hex(), "#0000ff");
     }
 
     #[test]
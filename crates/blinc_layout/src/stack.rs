@@ -360,6 +360,18 @@ impl Stack {
         self
     }
 
+    /// Set horizontal gap in pixels
+    pub fn gap_x_px(mut self, px: f32) -> Self {
+        self.inner = self.inner.gap_x_px(px);
+        self
+    }
+
+    /// Set vertical gap in pixels
+    pub fn gap_y_px(mut self, px: f32) -> Self {
+        self.inner = self.inner.gap_y_px(px);
+        self
+    }
+
     // =========================================================================
     // Padding
     // =========================================================================
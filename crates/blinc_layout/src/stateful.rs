@@ -327,6 +327,9 @@ pub struct PendingSubtreeRebuild {
     /// Whether this rebuild requires layout recomputation
     /// False for visual-only updates (hover/press state changes)
     pub needs_layout: bool,
+    /// Patch existing nodes in place where the structure is unchanged,
+    /// rebuilding only where child count or element type differs
+    pub scoped: bool,
 }
 
 // Safety: PendingSubtreeRebuild is only accessed from the main thread
@@ -341,6 +344,7 @@ pub fn queue_subtree_rebuild(parent_id: LayoutNodeId, new_child: crate::div::Div
             parent_id,
             new_child,
             needs_layout: true,
+            scoped: false,
         });
 }
 
@@ -356,6 +360,24 @@ pub fn queue_visual_subtree_rebuild(parent_id: LayoutNodeId, new_child: crate::d
             parent_id,
             new_child,
             needs_layout: false,
+            scoped: false,
+        });
+}
+
+/// Queue a scoped subtree rebuild (with layout recomputation)
+///
+/// Unlike [`queue_subtree_rebuild`], the existing subtree is diffed against
+/// the new child and patched in place. Only children whose structure changed
+/// (child count or element type) are removed and rebuilt.
+pub fn queue_scoped_subtree_rebuild(parent_id: LayoutNodeId, new_child: crate::div::Div) {
+    PENDING_SUBTREE_REBUILDS
+        .lock()
+        .unwrap()
+        .push(PendingSubtreeRebuild {
+            parent_id,
+            new_child,
+            needs_layout: true,
+            scoped: true,
         });
 }
 
@@ -675,6 +697,209 @@ pub trait StateId: Clone + Copy + PartialEq + Eq + Hash + Send + Sync + 'static
 /// Wrapped in Arc so it can be cloned for incremental updates
 pub type StateCallback<S> = Arc<dyn Fn(&S, &mut Div) + Send + Sync>;
 
+// =========================================================================
+// Visual Transitions
+// =========================================================================
+
+/// Visual properties that spring toward their new value on state changes
+///
+/// Without transitions, `on_state` snaps straight to each state's look.
+/// With them, the declared properties of the element itself animate from
+/// whatever they currently show, so toggling a state back mid-transition
+/// reverses smoothly. Needs the global animation scheduler; without one
+/// values snap as before.
+///
+/// ```ignore
+/// stateful_button()
+///     .transitions(VisualTransitions::new().background(SpringConfig::stiff()))
+///     .on_state(|state, div| { /* bg per state */ })
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct VisualTransitions {
+    background: Option<SpringConfig>,
+    opacity: Option<SpringConfig>,
+    scale: Option<SpringConfig>,
+}
+
+impl VisualTransitions {
+    /// No animated properties
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Animate background, opacity and scale with the same spring
+    pub fn all(config: SpringConfig) -> Self {
+        Self {
+            background: Some(config),
+            opacity: Some(config),
+            scale: Some(config),
+        }
+    }
+
+    /// Animate the solid background color
+    pub fn background(mut self, config: SpringConfig) -> Self {
+        self.background = Some(config);
+        self
+    }
+
+    /// Animate opacity
+    pub fn opacity(mut self, config: SpringConfig) -> Self {
+        self.opacity = Some(config);
+        self
+    }
+
+    /// Animate scale (set with `Div::scale` / `Div::scale_xy`)
+    pub fn scale(mut self, config: SpringConfig) -> Self {
+        self.scale = Some(config);
+        self
+    }
+}
+
+/// Spring the transitioned properties of `div` from their current values
+///
+/// Targets the callback didn't set fall back to the base props. Keys of
+/// springs still moving are added to the stateful's animation keys so
+/// animation frames keep refreshing it.
+fn apply_visual_transitions<S: StateTransitions>(shared: &SharedState<S>, div: &mut Div) {
+    use blinc_core::{Brush, Color, Transform};
+
+    let mut guard = shared.lock().unwrap();
+    let Some(transitions) = guard.transitions else {
+        return;
+    };
+    let Some(scheduler) = blinc_animation::try_get_scheduler() else {
+        return;
+    };
+    let base = guard.base_render_props.clone().unwrap_or_default();
+    let key_prefix = format!("stateful:{:p}:transition", Arc::as_ptr(shared));
+    let mut animating = Vec::new();
+
+    let mut spring =
+        |name: &str, target: f32, config: SpringConfig| -> f32 {
+            let key = format!("{}:{}", key_prefix, name);
+            let value = Arc::clone(guard.transition_values.entry(key.clone()).or_insert_with(
+                || {
+                    let value = Arc::new(Mutex::new(AnimatedValue::new(
+                        scheduler.clone(),
+                        target,
+                        config,
+                    )));
+                    PERSISTED_ANIMATED_VALUES
+                        .write()
+                        .unwrap()
+                        .insert(key.clone(), Arc::clone(&value));
+                    value
+                },
+            ));
+            let mut value = value.lock().unwrap();
+            value.set_target(target);
+            if value.is_animating() {
+                animating.push(key);
+            }
+            value.get()
+        };
+
+    if let Some(config) = transitions.background {
+        let target = match (&div.background, &base.background) {
+            (Some(Brush::Solid(c)), _) | (None, Some(Brush::Solid(c))) => Some(*c),
+            _ => None,
+        };
+        if let Some(c) = target {
+            div.background = Some(Brush::Solid(Color::rgba(
+                spring("bg.r", c.r, config),
+                spring("bg.g", c.g, config),
+                spring("bg.b", c.b, config),
+                spring("bg.a", c.a, config),
+            )));
+        }
+    }
+
+    if let Some(config) = transitions.opacity {
+        let target = if div.opacity != 1.0 {
+            div.opacity
+        } else {
+            base.opacity
+        };
+        div.opacity = spring("opacity", target, config).clamp(0.0, 1.0);
+    }
+
+    if let Some(config) = transitions.scale {
+        // Only pure scales animate; other transforms are left as declared
+        let scale_of = |t: &Option<Transform>| match t {
+            None => Some((1.0, 1.0)),
+            Some(Transform::Affine2D(a)) => match a.elements {
+                [sx, 0.0, 0.0, sy, 0.0, 0.0] => Some((sx, sy)),
+                _ => None,
+            },
+            Some(_) => None,
+        };
+        let target = match div.transform {
+            Some(_) => scale_of(&div.transform),
+            None => scale_of(&base.transform),
+        };
+        if let Some((sx, sy)) = target {
+            let (sx, sy) = (spring("scale.x", sx, config), spring("scale.y", sy, config));
+            div.transform = Some(Transform::scale(sx, sy));
+        }
+    }
+
+    for key in animating {
+        if !guard.animation_keys.contains(&key) {
+            guard.animation_keys.push(key);
+        }
+    }
+}
+
+/// Spring a persisted value from inside a stateful's callback
+///
+/// The value lives under `key` across rebuilds, starting at `initial`, and
+/// `update` retargets it. While it moves, the stateful owning `shared` is
+/// registered so animation frames keep refreshing it. Returns `None` without a global scheduler.
+pub(crate) fn callback_spring<S: StateTransitions>(
+    shared: &SharedState<S>,
+    key: &str,
+    initial: f32,
+    config: SpringConfig,
+    update: impl FnOnce(&mut AnimatedValue),
+) -> Option<f32> {
+    let scheduler = blinc_animation::try_get_scheduler()?;
+    let value = Arc::clone(
+        PERSISTED_ANIMATED_VALUES
+            .write()
+            .unwrap()
+            .entry(key.to_string())
+            .or_insert_with(|| {
+                Arc::new(Mutex::new(AnimatedValue::new(scheduler, initial, config)))
+            }),
+    );
+    let (current, animating) = {
+        let mut value = value.lock().unwrap();
+        update(&mut value);
+        (value.get(), value.is_animating())
+    };
+
+    let mut guard = shared.lock().unwrap();
+    if !animating {
+        guard.animation_keys.retain(|k| k != key);
+    } else if !guard.animation_keys.iter().any(|k| k == key) {
+        guard.animation_keys.push(key.to_string());
+    }
+    if animating {
+        let refresh = Arc::clone(guard.refresh_callback.get_or_insert_with(|| {
+            let weak = Arc::downgrade(shared);
+            Arc::new(move || {
+                if let Some(shared) = weak.upgrade() {
+                    refresh_stateful(&shared);
+                }
+            })
+        }));
+        let keys = guard.animation_keys.clone();
+        drop(guard);
+        register_stateful_animation(Arc::as_ptr(shared) as u64, keys, refresh);
+    }
+    Some(current)
+}
+
 // =========================================================================
 // Built-in State Types
 // =========================================================================
@@ -1039,6 +1264,9 @@ pub struct StatefulInner<S: StateTransitions> {
     /// Signal dependencies - when any of these change, refresh props
     pub(crate) deps: Vec<SignalId>,
 
+    /// Patch the existing subtree on refresh instead of rebuilding it
+    pub(crate) scoped_rebuild: bool,
+
     /// Ancestor motion key (if inside a motion container)
     ///
     /// Set during tree building when the stateful element is inside a Motion.
@@ -1058,6 +1286,12 @@ pub struct StatefulInner<S: StateTransitions> {
     /// Animation keys used by this stateful (for animation-driven refresh)
     /// Updated after each callback invocation with keys of active animations.
     pub(crate) animation_keys: Vec<String>,
+
+    /// Properties that spring between states (see [`VisualTransitions`])
+    pub(crate) transitions: Option<VisualTransitions>,
+
+    /// Springs driving the transitioned properties, by animation key
+    pub(crate) transition_values: HashMap<String, SharedAnimatedValue>,
 }
 
 impl<S: StateTransitions> StatefulInner<S> {
@@ -1071,10 +1305,13 @@ impl<S: StateTransitions> StatefulInner<S> {
             base_style: None,
             node_id: None,
             deps: Vec::new(),
+            scoped_rebuild: false,
             ancestor_motion_key: None,
             current_event: None,
             refresh_callback: None,
             animation_keys: Vec::new(),
+            transitions: None,
+            transition_values: HashMap::new(),
         }
     }
 }
@@ -1965,10 +2202,14 @@ pub struct StatefulBuilder<S: StateTransitions> {
     key: crate::InstanceKey,
     /// Signal dependencies for refresh
     deps: Vec<blinc_core::reactive::SignalId>,
+    /// Patch the subtree in place on refresh (see [`StatefulBuilder::scoped_rebuild`])
+    scoped_rebuild: bool,
     /// Initial state (if explicitly set)
     initial_state: Option<S>,
     /// Parent context key (for nested statefuls)
     parent_key: Option<Arc<String>>,
+    /// Properties that spring between states
+    transitions: Option<VisualTransitions>,
 }
 
 impl<S: StateTransitions + Default> StatefulBuilder<S> {
@@ -1978,8 +2219,10 @@ impl<S: StateTransitions + Default> StatefulBuilder<S> {
         Self {
             key: crate::InstanceKey::new("stateful"),
             deps: Vec::new(),
+            scoped_rebuild: false,
             initial_state: None,
             parent_key: None,
+            transitions: None,
         }
     }
 
@@ -1991,6 +2234,23 @@ impl<S: StateTransitions + Default> StatefulBuilder<S> {
         self
     }
 
+    /// Only rebuild this element's own subtree when dependencies change
+    ///
+    /// The refreshed content is diffed against the current subtree and
+    /// render state is patched in place wherever the structure is unchanged,
+    /// so untouched siblings keep their nodes. Children whose child count or
+    /// element type changed are rebuilt.
+    pub fn scoped_rebuild(mut self) -> Self {
+        self.scoped_rebuild = true;
+        self
+    }
+
+    /// Spring visual properties between states (see [`VisualTransitions`])
+    pub fn transitions(mut self, transitions: VisualTransitions) -> Self {
+        self.transitions = Some(transitions);
+        self
+    }
+
     /// Set initial state (defaults to `S::default()`)
     pub fn initial(mut self, state: S) -> Self {
         self.initial_state = Some(state);
@@ -2015,6 +2275,8 @@ impl<S: StateTransitions + Default> StatefulBuilder<S> {
         let key_str = self.key.get().to_string();
         let parent_key = self.parent_key;
         let deps = self.deps;
+        let scoped_rebuild = self.scoped_rebuild;
+        let transitions = self.transitions;
 
         // Get or create persistent SharedState using the key
         let shared_state = use_shared_state_with::<S>(&key_str, initial);
@@ -2083,6 +2345,8 @@ impl<S: StateTransitions + Default> StatefulBuilder<S> {
         if !deps.is_empty() {
             stateful.shared_state.lock().unwrap().deps = deps.clone();
         }
+        stateful.shared_state.lock().unwrap().scoped_rebuild = scoped_rebuild;
+        stateful.shared_state.lock().unwrap().transitions = transitions;
 
         // Register state handlers to enable event-driven state transitions
         // This sets up on_mouse_down, on_mouse_up, etc. to trigger StateTransitions::on_event()
@@ -2188,8 +2452,10 @@ pub fn stateful_with_key<S: StateTransitions + Default>(
     StatefulBuilder {
         key: crate::InstanceKey::explicit(key),
         deps: Vec::new(),
+        scoped_rebuild: false,
         initial_state: None,
         parent_key: None,
+        transitions: None,
     }
 }
 
@@ -2224,7 +2490,11 @@ impl<S: StateTransitions> Stateful<S> {
 
             tracing::trace!("Invoking state callback for Stateful");
             // Apply callback to populate children and props
-            callback(&state_copy, &mut *self.inner.borrow_mut());
+            {
+                let mut inner = self.inner.borrow_mut();
+                callback(&state_copy, &mut inner);
+                apply_visual_transitions(&self.shared_state, &mut inner);
+            }
 
             // Mark as updated
             self.shared_state.lock().unwrap().needs_visual_update = false;
@@ -2247,10 +2517,13 @@ impl<S: StateTransitions> Stateful<S> {
                 base_style: None,
                 node_id: None,
                 deps: Vec::new(),
+                scoped_rebuild: false,
                 ancestor_motion_key: None,
                 current_event: None,
                 refresh_callback: None,
                 animation_keys: Vec::new(),
+                transitions: None,
+                transition_values: HashMap::new(),
             })),
             children_cache: RefCell::new(Vec::new()),
             event_handlers_cache: RefCell::new(crate::event_handler::EventHandlers::new()),
@@ -2429,6 +2702,33 @@ impl<S: StateTransitions> Stateful<S> {
         self
     }
 
+    /// Only rebuild this element's own subtree when it refreshes
+    ///
+    /// Instead of replacing all children, the refreshed content is diffed
+    /// against the current subtree and patched in place where the structure
+    /// is unchanged. Children whose child count or element type changed are
+    /// rebuilt.
+    pub fn scoped_rebuild(self) -> Self {
+        self.shared_state.lock().unwrap().scoped_rebuild = true;
+        self
+    }
+
+    /// Spring visual properties between states instead of snapping
+    ///
+    /// See [`VisualTransitions`]. Can be set before or after `on_state`.
+    pub fn transitions(self, transitions: VisualTransitions) -> Self {
+        let shared = Arc::clone(&self.shared_state);
+        {
+            let mut inner = self.shared_state.lock().unwrap();
+            inner.transitions = Some(transitions);
+            // Animation frames re-run the callback through the refresh callback
+            if inner.refresh_callback.is_none() {
+                inner.refresh_callback = Some(Arc::new(move || refresh_stateful(&shared)));
+            }
+        }
+        self
+    }
+
     /// Register event handlers for automatic state transitions
     ///
     /// These handlers are registered on the event_handlers_cache (not the inner Div)
@@ -2590,6 +2890,7 @@ impl<S: StateTransitions> Stateful<S> {
                 Div::new()
             };
             callback(&state_copy, &mut temp_div);
+            apply_visual_transitions(shared, &mut temp_div);
             let callback_props = temp_div.render_props();
 
             // Start from base props and merge callback changes on top
@@ -2643,6 +2944,7 @@ impl<S: StateTransitions> Stateful<S> {
         guard.current_event = None;
 
         // Need node_id and callback to refresh
+        let scoped = guard.scoped_rebuild;
         let (callback, state_copy, cached_node_id, base_props, base_style, refresh_callback) =
             match (guard.state_callback.as_ref(), guard.node_id) {
                 (Some(cb), Some(nid)) => {
@@ -2666,6 +2968,7 @@ impl<S: StateTransitions> Stateful<S> {
             Div::new()
         };
         callback(&state_copy, &mut temp_div);
+        apply_visual_transitions(shared, &mut temp_div);
         let callback_props = temp_div.render_props();
 
         // Start from base props and merge callback changes on top
@@ -2686,7 +2989,9 @@ impl<S: StateTransitions> Stateful<S> {
             style_changed
         );
 
-        if !children.is_empty() || style_changed {
+        if scoped {
+            queue_scoped_subtree_rebuild(cached_node_id, temp_div);
+        } else if !children.is_empty() || style_changed {
             queue_subtree_rebuild(cached_node_id, temp_div);
         }
 
@@ -2746,7 +3051,9 @@ impl<S: StateTransitions> Stateful<S> {
             let state_copy = shared.state;
             shared.needs_visual_update = false;
             drop(shared); // Release lock before calling callback
-            callback(&state_copy, &mut *self.inner.borrow_mut());
+            let mut inner = self.inner.borrow_mut();
+            callback(&state_copy, &mut inner);
+            apply_visual_transitions(&self.shared_state, &mut inner);
         }
     }
 
@@ -3092,6 +3399,18 @@ impl<S: StateTransitions> Stateful<S> {
         self.cursor(crate::element::CursorStyle::Text)
     }
 
+    /// Let this element take focus (builder pattern)
+    pub fn focusable(self) -> Self {
+        self.merge_into_inner(Div::new().focusable());
+        self
+    }
+
+    /// Draw a ring while this element has keyboard focus (builder pattern)
+    pub fn focus_ring(self, color: impl Into<blinc_core::Color>, width: f32) -> Self {
+        self.merge_into_inner(Div::new().focus_ring(color, width));
+        self
+    }
+
     // =========================================================================
     // Position (builder pattern)
     // =========================================================================
@@ -3388,7 +3707,9 @@ impl<S: StateTransitions> Stateful<S> {
     ///     btn.dispatch_state(ButtonState::Pressed);
     /// });
     /// ```
-    pub fn bind(self, element_ref: &ElementRef<Self>) -> BoundStateful<S> {
+    pub fn bind(mut self, element_ref: &ElementRef<Self>) -> BoundStateful<S> {
+        // Share layout bounds so `element_ref.get_layout_bounds()` tracks layout
+        self.layout_bounds = element_ref.layout_bounds_storage();
         // Store self in the ElementRef's shared storage
         element_ref.set(self);
         // Return a wrapper that shares the same storage
@@ -4016,6 +4337,18 @@ pub fn text_field() -> TextField {
     Stateful::new(TextFieldState::Idle)
 }
 
+/// The global animation scheduler, stepped by hand in tests
+#[cfg(test)]
+pub(crate) fn test_scheduler() -> &'static blinc_animation::AnimationScheduler {
+    static SCHEDULER: std::sync::OnceLock<blinc_animation::AnimationScheduler> =
+        std::sync::OnceLock::new();
+    SCHEDULER.get_or_init(|| {
+        let scheduler = blinc_animation::AnimationScheduler::new();
+        blinc_animation::set_global_scheduler(scheduler.handle());
+        scheduler
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4271,4 +4604,192 @@ mod tests {
         // State should still be ()
         assert_eq!(elem.state(), ());
     }
+
+    /// Leaf element that counts how often it is built
+    struct CountingLeaf {
+        builds: Arc<AtomicU32>,
+        inner: Div,
+    }
+
+    impl ElementBuilder for CountingLeaf {
+        fn build(&self, tree: &mut LayoutTree) -> LayoutNodeId {
+            self.builds.fetch_add(1, Ordering::SeqCst);
+            self.inner.build(tree)
+        }
+
+        fn render_props(&self) -> RenderProps {
+            self.inner.render_props()
+        }
+
+        fn children_builders(&self) -> &[Box<dyn ElementBuilder>] {
+            &[]
+        }
+
+        fn layout_style(&self) -> Option<&taffy::Style> {
+            self.inner.layout_style()
+        }
+    }
+
+    #[test]
+    fn test_scoped_rebuild_patches_changed_leaf_only() {
+        use crate::renderer::RenderTree;
+
+        let sibling_builds = Arc::new(AtomicU32::new(0));
+        let builds = Arc::clone(&sibling_builds);
+        let elem = stateful_button()
+            .scoped_rebuild()
+            .on_state(move |state, div| {
+                let sibling = CountingLeaf {
+                    builds: Arc::clone(&builds),
+                    inner: crate::div::div().w(10.0).h(10.0),
+                };
+                let leaf = match state {
+                    ButtonState::Idle => crate::div::div().w(10.0).bg(Color::BLUE),
+                    _ => crate::div::div().w(20.0).bg(Color::RED),
+                };
+                *div = div.swap().child(sibling).child(leaf);
+                if *state == ButtonState::Pressed {
+                    *div = div.swap().child(crate::div::div());
+                }
+            });
+
+        let mut tree = RenderTree::from_element(&elem);
+        let root = tree.root().unwrap();
+        let children = tree.layout().children(root);
+        assert_eq!(sibling_builds.load(Ordering::SeqCst), 1);
+
+        // Leaf change: nodes are patched in place, the sibling is not rebuilt
+        assert!(elem.dispatch_state(ButtonState::Hovered));
+        assert!(tree.process_pending_subtree_rebuilds());
+        assert_eq!(tree.layout().children(root), children);
+        assert_eq!(sibling_builds.load(Ordering::SeqCst), 1);
+        let leaf = tree.get_render_node(children[1]).unwrap();
+        assert!(matches!(leaf.props.background, Some(Brush::Solid(c)) if c == Color::RED));
+
+        // Child count change falls back to rebuilding the subtree
+        assert!(elem.dispatch_state(ButtonState::Pressed));
+        assert!(tree.process_pending_subtree_rebuilds());
+        assert_eq!(tree.layout().children(root).len(), 3);
+        assert_eq!(sibling_builds.load(Ordering::SeqCst), 2);
+    }
+
+    /// Apply queued prop updates for `tree`'s nodes, leaving the rest queued
+    fn apply_prop_updates(tree: &mut crate::renderer::RenderTree) {
+        for (node_id, props) in take_pending_prop_updates() {
+            if tree.layout().node_exists(node_id) {
+                tree.update_render_props(node_id, |p| *p = props);
+            } else {
+                queue_prop_update(node_id, props);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hover_patches_widget_without_rebuilding_siblings() {
+        use crate::renderer::RenderTree;
+
+        let sibling_builds = Arc::new(AtomicU32::new(0));
+        let btn = stateful_button().w(80.0).h(30.0).on_state(|state, div| {
+            let bg = match state {
+                ButtonState::Hovered => Color::RED,
+                _ => Color::BLUE,
+            };
+            *div = div
+                .swap()
+                .bg(bg)
+                .child(crate::div::div().w(10.0).h(10.0).bg(bg));
+        });
+        let ui = crate::div::div().child(btn).child(CountingLeaf {
+            builds: Arc::clone(&sibling_builds),
+            inner: crate::div::div().w(10.0).h(10.0),
+        });
+
+        let mut tree = RenderTree::from_element(&ui);
+        let root = tree.root().unwrap();
+        let [btn_node, sibling] = tree.layout().children(root)[..] else {
+            panic!("expected two children");
+        };
+        let icon = tree.layout().children(btn_node)[0];
+        tree.take_patched_nodes();
+
+        tree.dispatch_event(btn_node, event_types::POINTER_ENTER, 10.0, 10.0);
+        apply_prop_updates(&mut tree);
+        tree.process_pending_subtree_rebuilds();
+
+        // Background patched in place, same nodes, sibling never rebuilt
+        let bg =
+            |tree: &RenderTree, node| tree.get_render_node(node).unwrap().props.background.clone();
+        assert!(matches!(bg(&tree, btn_node), Some(Brush::Solid(c)) if c == Color::RED));
+        assert!(matches!(bg(&tree, icon), Some(Brush::Solid(c)) if c == Color::RED));
+        assert_eq!(tree.layout().children(btn_node), [icon]);
+        assert_eq!(sibling_builds.load(Ordering::SeqCst), 1);
+
+        let patched = tree.take_patched_nodes();
+        assert!(patched.contains(&btn_node) && patched.contains(&icon));
+        assert!(!patched.contains(&sibling) && !patched.contains(&root));
+    }
+
+    #[test]
+    fn test_hover_adding_child_rebuilds_widget() {
+        use crate::renderer::RenderTree;
+
+        let btn = stateful_button().on_state(|state, div| {
+            *div = div.swap().child(crate::div::div().w(10.0).h(10.0));
+            if *state == ButtonState::Hovered {
+                *div = div.swap().child(crate::div::div().w(10.0).h(10.0));
+            }
+        });
+        let mut tree = RenderTree::from_element(&crate::div::div().child(btn));
+        let btn_node = tree.layout().children(tree.root().unwrap())[0];
+        assert_eq!(tree.layout().children(btn_node).len(), 1);
+
+        tree.dispatch_event(btn_node, event_types::POINTER_ENTER, 0.0, 0.0);
+        apply_prop_updates(&mut tree);
+        tree.process_pending_subtree_rebuilds();
+        assert_eq!(tree.layout().children(btn_node).len(), 2);
+    }
+
+    #[test]
+    fn test_hover_transition_springs_background() {
+        use crate::renderer::RenderTree;
+
+        let scheduler = test_scheduler();
+        let btn = stateful_button()
+            .w(80.0)
+            .h(30.0)
+            .transitions(VisualTransitions::new().background(SpringConfig::stiff()))
+            .on_state(|state, div| {
+                let bg = match state {
+                    ButtonState::Hovered => Color::RED,
+                    _ => Color::BLUE,
+                };
+                *div = div.swap().bg(bg);
+            });
+        let mut tree = RenderTree::from_element(&crate::div::div().child(btn));
+        let btn_node = tree.layout().children(tree.root().unwrap())[0];
+        let red = |tree: &RenderTree| match tree.get_render_node(btn_node).unwrap().props.background
+        {
+            Some(Brush::Solid(c)) => c.r,
+            _ => panic!("expected a solid background"),
+        };
+        assert_eq!(red(&tree), Color::BLUE.r);
+
+        // One frame after hovering, the color is on its way to red
+        tree.dispatch_event(btn_node, event_types::POINTER_ENTER, 10.0, 10.0);
+        apply_prop_updates(&mut tree);
+        scheduler.tick_with_dt(1.0 / 60.0);
+        check_stateful_animations();
+        apply_prop_updates(&mut tree);
+        let partway = red(&tree);
+        assert!(partway > Color::BLUE.r && partway < Color::RED.r);
+
+        // Leaving mid-transition reverses from where it is
+        tree.dispatch_event(btn_node, event_types::POINTER_LEAVE, 10.0, 10.0);
+        apply_prop_updates(&mut tree);
+        scheduler.tick_with_dt(1.0 / 60.0);
+        check_stateful_animations();
+        apply_prop_updates(&mut tree);
+        let reversing = red(&tree);
+        assert!(reversing > Color::BLUE.r && reversing < Color::RED.r);
+    }
 }
@@ -23,6 +23,7 @@
 
 use blinc_core::Color;
 
+use crate::div::FontFamily;
 use crate::syntax::TokenType;
 
 /// A span of styled text within a line
@@ -46,6 +47,10 @@ pub struct TextSpan {
     pub link_url: Option<String>,
     /// Token type (for intellisense callbacks)
     pub token_type: Option<TokenType>,
+    /// Font family override (None = the element's font)
+    pub font_family: Option<FontFamily>,
+    /// Font size override in pixels (None = the element's size)
+    pub font_size: Option<f32>,
 }
 
 impl TextSpan {
@@ -61,6 +66,8 @@ impl TextSpan {
             strikethrough: false,
             link_url: None,
             token_type: None,
+            font_family: None,
+            font_size: None,
         }
     }
 
@@ -99,6 +106,18 @@ impl TextSpan {
         self
     }
 
+    /// Set a font family for this span
+    pub fn with_font_family(mut self, family: FontFamily) -> Self {
+        self.font_family = Some(family);
+        self
+    }
+
+    /// Set a font size for this span
+    pub fn with_font_size(mut self, size: f32) -> Self {
+        self.font_size = Some(size);
+        self
+    }
+
     /// Create an italic span
     pub fn italic(start: usize, end: usize, color: Color) -> Self {
         Self::new(start, end, color, false).with_italic(true)
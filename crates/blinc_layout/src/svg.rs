@@ -10,7 +10,7 @@
 //!     .color(Color::WHITE);
 //! ```
 
-use blinc_core::{Color, Shadow, Transform};
+use blinc_core::{CachePolicy, Color, Shadow, Transform};
 use taffy::prelude::*;
 
 use crate::div::{ElementBuilder, ElementTypeId, SvgRenderInfo};
@@ -233,6 +233,12 @@ impl Svg {
         self.transform(Transform::scale(factor, factor))
     }
 
+    /// Set the top margin in pixels
+    pub(crate) fn margin_top_px(mut self, px: f32) -> Self {
+        self.style.margin.top = LengthPercentageAuto::Length(px);
+        self
+    }
+
     /// Rotate this SVG by the given angle in radians
     pub fn rotate(self, angle: f32) -> Self {
         self.transform(Transform::rotate(angle))
@@ -268,6 +274,12 @@ impl ElementBuilder for Svg {
             pointer_events_none: false,
             cursor: None,
             layer_effects: Vec::new(),
+            cache_policy: CachePolicy::None,
+            tab_index: None,
+            focus_ring: None,
+            disabled: false,
+            portal: false,
+            sticky: None,
             motion_is_exiting: false,
         }
     }
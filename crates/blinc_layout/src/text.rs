@@ -19,7 +19,7 @@
 //! let emoji = text("Hello 😀 World 🎉");
 //! ```
 
-use blinc_core::{Color, Shadow, Transform};
+use blinc_core::{CachePolicy, Color, Shadow, Transform};
 use html_escape::decode_html_entities;
 use taffy::prelude::*;
 
@@ -31,6 +31,7 @@ use crate::element::{RenderLayer, RenderProps};
 use crate::tree::{LayoutNodeId, LayoutTree};
 
 /// A text element builder
+#[derive(Clone)]
 pub struct Text {
     /// The text content
     content: String,
@@ -58,6 +59,12 @@ pub struct Text {
     transform: Option<Transform>,
     /// Whether to wrap text at container bounds (default: true)
     wrap: bool,
+    /// Keep leading whitespace on wrapped lines (pre/code text)
+    preformatted: bool,
+    /// Apply font ligatures (default: true)
+    ligatures: bool,
+    /// Apply font kerning (default: true)
+    kerning: bool,
     /// Line height multiplier (default: 1.2)
     line_height: f32,
     /// Measured width of the text (before layout constraints)
@@ -108,6 +115,9 @@ impl Text {
             ascender: 14.0 * 0.8, // will be set by update_size_estimate
             strikethrough: false,
             underline: false,
+            preformatted: false,
+            ligatures: true,
+            kerning: true,
             pointer_events_none: false,
             cursor: Some(crate::element::CursorStyle::Text), // Text cursor by default
         };
@@ -393,11 +403,38 @@ impl Text {
         &self.content
     }
 
+    /// Get the measured font ascender (distance from baseline to top)
+    pub fn ascender(&self) -> f32 {
+        self.ascender
+    }
+
+    /// Copy of this text with different content, re-measured
+    pub(crate) fn with_content(&self, content: impl Into<String>) -> Self {
+        let mut text = self.clone();
+        text.content = content.into();
+        text.update_size_estimate();
+        text
+    }
+
+    /// Set the top margin in pixels
+    pub(crate) fn margin_top_px(mut self, px: f32) -> Self {
+        self.style.margin.top = LengthPercentageAuto::Length(px);
+        self
+    }
+
     /// Get the font size
     pub fn font_size(&self) -> f32 {
         self.font_size
     }
 
+    /// Font size after the global text scale (see [`blinc_theme::text_scale`])
+    ///
+    /// The scale is applied when the text is laid out and rendered, so
+    /// changing it doesn't require rebuilding elements.
+    pub fn rendered_font_size(&self) -> f32 {
+        blinc_theme::scaled_text_size(self.font_size)
+    }
+
     /// Get the text color
     pub fn text_color(&self) -> Color {
         self.color
@@ -411,6 +448,9 @@ impl Text {
         options.generic_font = self.font_family.generic;
         options.font_weight = self.weight.weight();
         options.italic = self.italic;
+        options.preformatted = self.preformatted;
+        options.ligatures = self.ligatures;
+        options.kerning = self.kerning;
 
         let metrics =
             crate::text_measure::measure_text_with_options(&self.content, self.font_size, &options);
@@ -543,6 +583,35 @@ impl Text {
         self
     }
 
+    /// Preserve whitespace as written (for code and preformatted text)
+    ///
+    /// Leading whitespace is kept on every line, including lines created by
+    /// wrapping. Tabs always advance to the next tab stop.
+    pub fn preformatted(mut self) -> Self {
+        self.preformatted = true;
+        self.update_size_estimate();
+        self
+    }
+
+    /// Enable or disable font ligatures
+    ///
+    /// On by default. Turn off where glyphs must stay separate, e.g. code
+    /// fonts that join "->" or "!=".
+    pub fn ligatures(mut self, enabled: bool) -> Self {
+        self.ligatures = enabled;
+        self.update_size_estimate();
+        self
+    }
+
+    /// Enable or disable font kerning
+    ///
+    /// On by default. Turn off for tabular alignment of letter pairs.
+    pub fn kerning(mut self, enabled: bool) -> Self {
+        self.kerning = enabled;
+        self.update_size_estimate();
+        self
+    }
+
     /// Set line height multiplier
     ///
     /// Default is 1.2. Increase for more spacing between lines.
@@ -556,23 +625,37 @@ impl ElementBuilder for Text {
     fn build(&self, tree: &mut LayoutTree) -> LayoutNodeId {
         use crate::tree::TextMeasureContext;
 
+        let scale = blinc_theme::text_scale();
+
         // For wrapping text, use a measure context so Taffy can calculate
         // the correct multi-line height based on available width
         if self.wrap {
             let context = TextMeasureContext {
                 content: self.content.clone(),
-                font_size: self.font_size,
+                font_size: self.font_size * scale,
                 line_height: self.line_height,
                 wrap: true,
                 font_name: self.font_family.name.clone(),
                 generic_font: self.font_family.generic,
                 font_weight: self.weight.weight(),
                 italic: self.italic,
+                preformatted: self.preformatted,
+                ligatures: self.ligatures,
+                kerning: self.kerning,
             };
             tree.create_text_node(self.style.clone(), context)
         } else {
-            // Non-wrapping text can use fixed dimensions
-            tree.create_node(self.style.clone())
+            // Non-wrapping text can use fixed dimensions, measured unscaled
+            let mut style = self.style.clone();
+            if scale != 1.0 {
+                if let Dimension::Length(w) = style.size.width {
+                    style.size.width = Dimension::Length(w * scale);
+                }
+                if let Dimension::Length(h) = style.size.height {
+                    style.size.height = Dimension::Length(h * scale);
+                }
+            }
+            tree.create_node(style)
         }
     }
 
@@ -600,6 +683,12 @@ impl ElementBuilder for Text {
             pointer_events_none: self.pointer_events_none,
             cursor: self.cursor,
             layer_effects: Vec::new(),
+            cache_policy: CachePolicy::None,
+            tab_index: None,
+            focus_ring: None,
+            disabled: false,
+            portal: false,
+            sticky: None,
             motion_is_exiting: false,
         }
     }
@@ -613,20 +702,24 @@ impl ElementBuilder for Text {
     }
 
     fn text_render_info(&self) -> Option<TextRenderInfo> {
+        let scale = blinc_theme::text_scale();
         Some(TextRenderInfo {
             content: self.content.clone(),
-            font_size: self.font_size,
+            font_size: self.font_size * scale,
             color: [self.color.r, self.color.g, self.color.b, self.color.a],
             align: self.align,
             weight: self.weight,
             italic: self.italic,
             v_align: self.v_align,
             wrap: self.wrap,
+            preformatted: self.preformatted,
+            ligatures: self.ligatures,
+            kerning: self.kerning,
             line_height: self.line_height,
-            measured_width: self.measured_width,
+            measured_width: self.measured_width * scale,
             font_family: self.font_family.clone(),
             word_spacing: self.word_spacing,
-            ascender: self.ascender,
+            ascender: self.ascender * scale,
             strikethrough: self.strikethrough,
             underline: self.underline,
         })
@@ -660,7 +753,7 @@ impl Text {
     pub fn render_data(&self) -> TextRenderData {
         TextRenderData {
             content: self.content.clone(),
-            font_size: self.font_size,
+            font_size: self.rendered_font_size(),
             color: [self.color.r, self.color.g, self.color.b, self.color.a],
         }
     }
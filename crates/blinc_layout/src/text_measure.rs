@@ -3,6 +3,10 @@
 //! Provides a trait for measuring text dimensions during layout.
 //! This allows accurate text sizing without estimation.
 
+use std::num::NonZeroUsize;
+
+use lru::LruCache;
+
 /// Text layout options that affect measurement
 #[derive(Debug, Clone, Default)]
 pub struct TextLayoutOptions {
@@ -22,6 +26,12 @@ pub struct TextLayoutOptions {
     pub font_weight: u16,
     /// Whether text is italic
     pub italic: bool,
+    /// Keep leading whitespace on wrapped lines (pre/code text)
+    pub preformatted: bool,
+    /// Apply font ligatures (e.g. "fi")
+    pub ligatures: bool,
+    /// Apply font kerning (e.g. "AV")
+    pub kerning: bool,
 }
 
 impl TextLayoutOptions {
@@ -36,6 +46,9 @@ impl TextLayoutOptions {
             generic_font: crate::div::GenericFont::System,
             font_weight: 400,
             italic: false,
+            preformatted: false,
+            ligatures: true,
+            kerning: true,
         }
     }
 
@@ -104,6 +117,18 @@ impl TextLayoutOptions {
         self.italic = true;
         self
     }
+
+    /// Enable or disable font ligatures
+    pub fn with_ligatures(mut self, enabled: bool) -> Self {
+        self.ligatures = enabled;
+        self
+    }
+
+    /// Enable or disable font kerning
+    pub fn with_kerning(mut self, enabled: bool) -> Self {
+        self.kerning = enabled;
+        self
+    }
 }
 
 /// Text measurement result
@@ -216,13 +241,146 @@ impl TextMeasurer for EstimatedTextMeasurer {
     }
 }
 
+/// Default number of measurements kept by [`TextMeasureCache`]
+pub const TEXT_MEASURE_CACHE_CAPACITY: usize = 4096;
+
+/// Everything that affects a measurement, with floats compared bitwise
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct MeasureKey {
+    text: String,
+    font_size: u32,
+    line_height: u32,
+    letter_spacing: u32,
+    word_spacing: u32,
+    max_width: Option<u32>,
+    font_name: Option<String>,
+    generic_font: crate::div::GenericFont,
+    font_weight: u16,
+    italic: bool,
+    preformatted: bool,
+    ligatures: bool,
+    kerning: bool,
+}
+
+impl MeasureKey {
+    fn new(text: &str, font_size: f32, options: &TextLayoutOptions) -> Self {
+        Self {
+            text: text.to_string(),
+            font_size: font_size.to_bits(),
+            line_height: options.line_height.to_bits(),
+            letter_spacing: options.letter_spacing.to_bits(),
+            word_spacing: options.word_spacing.to_bits(),
+            max_width: options.max_width.map(f32::to_bits),
+            font_name: options.font_name.clone(),
+            generic_font: options.generic_font,
+            font_weight: options.font_weight,
+            italic: options.italic,
+            preformatted: options.preformatted,
+            ligatures: options.ligatures,
+            kerning: options.kerning,
+        }
+    }
+}
+
+/// Bounded LRU cache of text measurements
+///
+/// Repeated strings (table cells, list labels) are measured once and served
+/// from the cache until evicted. Call [`clear`](Self::clear) whenever
+/// measurements could change, e.g. after registering a font that may take
+/// part in fallback or shaping.
+pub struct TextMeasureCache {
+    entries: LruCache<MeasureKey, TextMetrics>,
+    /// Bumped by `clear` so measurements started before it aren't stored
+    generation: u64,
+    hits: u64,
+    misses: u64,
+}
+
+impl TextMeasureCache {
+    /// Create a cache holding up to `capacity` measurements
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: LruCache::new(NonZeroUsize::new(capacity.max(1)).unwrap()),
+            generation: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Measure through the cache, calling `measurer` only on a miss
+    pub fn measure(
+        &mut self,
+        measurer: &dyn TextMeasurer,
+        text: &str,
+        font_size: f32,
+        options: &TextLayoutOptions,
+    ) -> TextMetrics {
+        let key = MeasureKey::new(text, font_size, options);
+        if let Some(metrics) = self.lookup(&key) {
+            return metrics;
+        }
+        let metrics = measurer.measure_with_options(text, font_size, options);
+        self.entries.put(key, metrics);
+        metrics
+    }
+
+    /// Cached measurement for `key`, counting the hit or miss
+    fn lookup(&mut self, key: &MeasureKey) -> Option<TextMetrics> {
+        let metrics = self.entries.get(key).copied();
+        if metrics.is_some() {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+        }
+        metrics
+    }
+
+    /// Drop every cached measurement
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.generation += 1;
+    }
+
+    /// Number of cached measurements
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Lookups served from the cache
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Lookups that had to measure
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+}
+
+impl Default for TextMeasureCache {
+    fn default() -> Self {
+        Self::new(TEXT_MEASURE_CACHE_CAPACITY)
+    }
+}
+
 /// Global text measurer storage
 ///
 /// This allows setting a text measurer that will be used during layout.
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
 
 static TEXT_MEASURER: RwLock<Option<Arc<dyn TextMeasurer>>> = RwLock::new(None);
 
+/// Cache shared by all measurements through the global measurer
+fn measure_cache() -> &'static Mutex<TextMeasureCache> {
+    static MEASURE_CACHE: OnceLock<Mutex<TextMeasureCache>> = OnceLock::new();
+    MEASURE_CACHE.get_or_init(|| Mutex::new(TextMeasureCache::default()))
+}
+
 /// Set the global text measurer
 ///
 /// Call this at app initialization with a real text measurer
@@ -230,12 +388,22 @@ static TEXT_MEASURER: RwLock<Option<Arc<dyn TextMeasurer>>> = RwLock::new(None);
 pub fn set_text_measurer(measurer: Arc<dyn TextMeasurer>) {
     let mut guard = TEXT_MEASURER.write().unwrap();
     *guard = Some(measurer);
+    invalidate_text_measure_cache();
 }
 
 /// Clear the global text measurer
 pub fn clear_text_measurer() {
     let mut guard = TEXT_MEASURER.write().unwrap();
     *guard = None;
+    invalidate_text_measure_cache();
+}
+
+/// Drop all cached text measurements
+///
+/// Call after registering fonts: a new face can change fallback and
+/// shaping, and with it the size of text measured before.
+pub fn invalidate_text_measure_cache() {
+    measure_cache().lock().unwrap().clear();
 }
 
 /// Measure text using the global measurer, or fall back to estimation
@@ -249,15 +417,106 @@ pub fn measure_text(text: &str, font_size: f32) -> TextMetrics {
 }
 
 /// Measure text with options using the global measurer, or fall back to estimation
+///
+/// Measurements are cached by text, size and options; see
+/// [`invalidate_text_measure_cache`].
 pub fn measure_text_with_options(
     text: &str,
     font_size: f32,
     options: &TextLayoutOptions,
 ) -> TextMetrics {
     let guard = TEXT_MEASURER.read().unwrap();
-    if let Some(ref measurer) = *guard {
-        measurer.measure_with_options(text, font_size, options)
-    } else {
-        EstimatedTextMeasurer.measure_with_options(text, font_size, options)
+    let Some(ref measurer) = *guard else {
+        return EstimatedTextMeasurer.measure_with_options(text, font_size, options);
+    };
+
+    let key = MeasureKey::new(text, font_size, options);
+    let generation = {
+        let mut cache = measure_cache().lock().unwrap();
+        if let Some(metrics) = cache.lookup(&key) {
+            return metrics;
+        }
+        cache.generation
+    };
+
+    // Measure without holding the cache lock; shaping can be slow
+    let metrics = measurer.measure_with_options(text, font_size, options);
+    let mut cache = measure_cache().lock().unwrap();
+    if cache.generation == generation {
+        cache.entries.put(key, metrics);
+    }
+    metrics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Counts calls through to the estimator
+    #[derive(Default)]
+    struct CountingMeasurer {
+        calls: AtomicUsize,
+    }
+
+    impl TextMeasurer for CountingMeasurer {
+        fn measure_with_options(
+            &self,
+            text: &str,
+            font_size: f32,
+            options: &TextLayoutOptions,
+        ) -> TextMetrics {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            EstimatedTextMeasurer.measure_with_options(text, font_size, options)
+        }
+    }
+
+    #[test]
+    fn test_identical_strings_hit_cache() {
+        let measurer = CountingMeasurer::default();
+        let mut cache = TextMeasureCache::default();
+        let options = TextLayoutOptions::new();
+
+        let first = cache.measure(&measurer, "Cell label", 14.0, &options);
+        for _ in 1..1000 {
+            let metrics = cache.measure(&measurer, "Cell label", 14.0, &options);
+            assert_eq!(metrics.width, first.width);
+        }
+        assert_eq!(measurer.calls.load(Ordering::SeqCst), 1);
+        assert_eq!((cache.hits(), cache.misses()), (999, 1));
+
+        // Any option change is a different measurement
+        cache.measure(&measurer, "Cell label", 14.0, &options.clone().bold());
+        cache.measure(&measurer, "Cell label", 16.0, &options);
+        cache.measure(
+            &measurer,
+            "Cell label",
+            14.0,
+            &options.clone().with_ligatures(false),
+        );
+        assert_eq!(measurer.calls.load(Ordering::SeqCst), 4);
+
+        // Clearing (e.g. after a font registers) forces a re-measure
+        cache.clear();
+        cache.measure(&measurer, "Cell label", 14.0, &options);
+        assert_eq!(measurer.calls.load(Ordering::SeqCst), 5);
+    }
+
+    #[test]
+    fn test_cache_evicts_least_recently_used() {
+        let measurer = CountingMeasurer::default();
+        let mut cache = TextMeasureCache::new(2);
+        let options = TextLayoutOptions::new();
+
+        cache.measure(&measurer, "a", 14.0, &options);
+        cache.measure(&measurer, "b", 14.0, &options);
+        cache.measure(&measurer, "a", 14.0, &options);
+        // "b" is least recently used and makes room for "c"
+        cache.measure(&measurer, "c", 14.0, &options);
+        assert_eq!(cache.len(), 2);
+        cache.measure(&measurer, "a", 14.0, &options);
+        assert_eq!(measurer.calls.load(Ordering::SeqCst), 3);
+        cache.measure(&measurer, "b", 14.0, &options);
+        assert_eq!(measurer.calls.load(Ordering::SeqCst), 4);
     }
 }
@@ -3,8 +3,12 @@
 //! Provides a centralized location to track what text is currently selected
 //! across all text input widgets. This enables clipboard operations (copy/cut/paste)
 //! to work with any focused text input.
+//!
+//! The clipboard itself is reached through a [`ClipboardProvider`]. Platform
+//! backends install one with [`set_clipboard_provider`]; until then an
+//! in-process [`MemoryClipboard`] is used so copy/paste still works within the app.
 
-use std::sync::{Arc, Mutex, OnceLock};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
 
 /// Source of the selected text
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -105,6 +109,67 @@ pub fn can_cut_selection() -> bool {
     guard.can_cut
 }
 
+// =============================================================================
+// Clipboard
+// =============================================================================
+
+/// Access to the system clipboard
+///
+/// Implemented by platform backends (e.g. the desktop app installs one backed
+/// by the OS clipboard). Implementations must be cheap to call from event handlers.
+pub trait ClipboardProvider: Send + Sync {
+    /// Read plain text from the clipboard, if any
+    fn get_text(&self) -> Option<String>;
+
+    /// Replace the clipboard contents with plain text
+    fn set_text(&self, text: &str);
+}
+
+/// In-process clipboard used when no platform provider is installed
+#[derive(Debug, Default)]
+pub struct MemoryClipboard {
+    text: Mutex<Option<String>>,
+}
+
+impl MemoryClipboard {
+    /// Create an empty clipboard
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ClipboardProvider for MemoryClipboard {
+    fn get_text(&self) -> Option<String> {
+        self.text.lock().unwrap().clone()
+    }
+
+    fn set_text(&self, text: &str) {
+        *self.text.lock().unwrap() = Some(text.to_string());
+    }
+}
+
+fn clipboard_provider() -> &'static RwLock<Box<dyn ClipboardProvider>> {
+    static PROVIDER: OnceLock<RwLock<Box<dyn ClipboardProvider>>> = OnceLock::new();
+    PROVIDER.get_or_init(|| RwLock::new(Box::new(MemoryClipboard::new())))
+}
+
+/// Install the clipboard provider used by text widgets
+///
+/// Replaces any previously installed provider.
+pub fn set_clipboard_provider(provider: Box<dyn ClipboardProvider>) {
+    *clipboard_provider().write().unwrap() = provider;
+}
+
+/// Read plain text from the clipboard
+pub fn clipboard_get_text() -> Option<String> {
+    clipboard_provider().read().unwrap().get_text()
+}
+
+/// Write plain text to the clipboard
+pub fn clipboard_set_text(text: &str) {
+    clipboard_provider().read().unwrap().set_text(text);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -139,4 +204,13 @@ mod tests {
         clear_selection();
         assert!(get_selected_text().is_none());
     }
+
+    #[test]
+    fn test_memory_clipboard_roundtrip() {
+        let clipboard = MemoryClipboard::new();
+        assert!(clipboard.get_text().is_none());
+
+        clipboard.set_text("copied");
+        assert_eq!(clipboard.get_text(), Some("copied".to_string()));
+    }
 }
@@ -0,0 +1,184 @@
+//! Animated text transitions
+//!
+//! [`Text::animate_number`] rolls a number to each new value of a state, and
+//! [`Text::crossfade_on_change`] fades from the old string to the new one when
+//! a text's content changes between rebuilds.
+//!
+//! Both spring through the global animation scheduler. A change arriving
+//! mid-animation retargets the running spring rather than queueing another
+//! one, so rapid updates chase the latest value. Without a scheduler the text
+//! snaps to its new content.
+//!
+//! ```ignore
+//! let count = ctx.use_state_keyed("count", || 0.0);
+//!
+//! div()
+//!     .child(text("").size(32.0).animate_number(&count, |n| format!("{:.0}", n)))
+//!     .child(text(status_label).crossfade_on_change())
+//! ```
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use blinc_animation::SpringConfig;
+use blinc_core::State;
+
+use crate::div::div;
+use crate::key::InstanceKey;
+use crate::stateful::{callback_spring, NoState, SharedState, Stateful, StatefulInner};
+use crate::text::Text;
+
+/// Content last shown by each crossfading text, by instance key
+fn crossfade_content() -> &'static Mutex<HashMap<String, String>> {
+    static CROSSFADE_CONTENT: OnceLock<Mutex<HashMap<String, String>>> = OnceLock::new();
+    CROSSFADE_CONTENT.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+impl Text {
+    /// Show `value` through `format`, counting to each new value instead of
+    /// snapping
+    ///
+    /// The text's own content is replaced by the formatted number.
+    #[track_caller]
+    pub fn animate_number<F>(self, value: &State<f64>, format: F) -> Stateful<NoState>
+    where
+        F: Fn(f64) -> String + Send + Sync + 'static,
+    {
+        let key = format!("{}:number", InstanceKey::new("animated_text").get());
+        let value = value.clone();
+        let shared: SharedState<NoState> = Arc::new(Mutex::new(StatefulInner::new(NoState)));
+        let weak = Arc::downgrade(&shared);
+
+        Stateful::with_shared_state(shared)
+            .deps(&[value.signal_id()])
+            .on_state(move |_, container| {
+                let target = value.get();
+                let spring = weak.upgrade().and_then(|shared| {
+                    callback_spring(
+                        &shared,
+                        &key,
+                        target as f32,
+                        SpringConfig::gentle(),
+                        |spring| spring.set_target(target as f32),
+                    )
+                });
+                // Show the exact value once the spring has landed
+                let shown = match spring {
+                    Some(current) if current != target as f32 => current as f64,
+                    _ => target,
+                };
+                *container = container.swap().child(self.with_content(format(shown)));
+            })
+    }
+
+    /// Fade the previous content out and the new content in whenever this
+    /// text is rebuilt with different content
+    #[track_caller]
+    pub fn crossfade_on_change(self) -> Stateful<NoState> {
+        let key = format!("{}:crossfade", InstanceKey::new("animated_text").get());
+        let previous = crossfade_content()
+            .lock()
+            .unwrap()
+            .insert(key.clone(), self.content().to_string())
+            .filter(|previous| previous != self.content());
+        let restart = AtomicBool::new(previous.is_some());
+        let shared: SharedState<NoState> = Arc::new(Mutex::new(StatefulInner::new(NoState)));
+        let weak = Arc::downgrade(&shared);
+
+        Stateful::with_shared_state(shared).on_state(move |_, container| {
+            let progress = weak
+                .upgrade()
+                .and_then(|shared| {
+                    callback_spring(&shared, &key, 1.0, SpringConfig::stiff(), |spring| {
+                        if restart.swap(false, Ordering::Relaxed) {
+                            spring.set_immediate(0.0);
+                            spring.set_target(1.0);
+                        }
+                    })
+                })
+                .unwrap_or(1.0)
+                .clamp(0.0, 1.0);
+
+            let mut content = container
+                .swap()
+                .relative()
+                .child(div().opacity(progress).child(self.clone()));
+            if let Some(previous) = previous.as_ref().filter(|_| progress < 1.0) {
+                content = content.child(
+                    div()
+                        .absolute()
+                        .top(0.0)
+                        .left(0.0)
+                        .opacity(1.0 - progress)
+                        .child(self.with_content(previous.as_str())),
+                );
+            }
+            *container = content;
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+
+    use blinc_core::reactive::ReactiveGraph;
+
+    use super::*;
+    use crate::renderer::{ElementType, RenderTree};
+    use crate::stateful::{check_stateful_animations, check_stateful_deps, test_scheduler};
+    use crate::text::text;
+    use crate::tree::LayoutNodeId;
+
+    fn number_state(initial: f64) -> State<f64> {
+        let mut graph = ReactiveGraph::new();
+        let signal = graph.create_signal(initial);
+        State::new(
+            signal,
+            Arc::new(Mutex::new(graph)),
+            Arc::new(AtomicBool::new(false)),
+        )
+    }
+
+    /// Content of the first text node under `node`
+    fn shown_text(tree: &RenderTree, node: LayoutNodeId) -> Option<String> {
+        if let ElementType::Text(data) = &tree.get_render_node(node)?.element_type {
+            return Some(data.content.clone());
+        }
+        tree.layout()
+            .children(node)
+            .into_iter()
+            .find_map(|child| shown_text(tree, child))
+    }
+
+    #[test]
+    fn test_animate_number_counts_towards_target() {
+        let scheduler = test_scheduler();
+        let value = number_state(0.0);
+        let counter = text("").animate_number(&value, |n| format!("{:.1}", n));
+        let mut tree = RenderTree::from_element(&div().child(counter));
+        let root = tree.root().unwrap();
+        assert_eq!(shown_text(&tree, root).as_deref(), Some("0.0"));
+
+        value.set(100.0);
+        check_stateful_deps(&[value.signal_id()]);
+        tree.process_pending_subtree_rebuilds();
+        scheduler.tick_with_dt(1.0 / 60.0);
+        check_stateful_animations();
+        tree.process_pending_subtree_rebuilds();
+        let shown: f64 = shown_text(&tree, root).unwrap().parse().unwrap();
+        assert!(shown > 0.0 && shown < 100.0, "shown {shown}");
+
+        // Retargeting mid-animation heads straight for the latest value
+        value.set(50.0);
+        check_stateful_deps(&[value.signal_id()]);
+        for _ in 0..600 {
+            scheduler.tick_with_dt(1.0 / 60.0);
+            check_stateful_animations();
+        }
+        tree.process_pending_subtree_rebuilds();
+        let shown: f64 = shown_text(&tree, root).unwrap().parse().unwrap();
+        assert!((shown - 50.0).abs() < 0.5, "shown {shown}");
+    }
+}
@@ -33,6 +33,12 @@ pub struct TextMeasureContext {
     pub font_weight: u16,
     /// Whether text is italic
     pub italic: bool,
+    /// Keep leading whitespace on wrapped lines
+    pub preformatted: bool,
+    /// Apply font ligatures
+    pub ligatures: bool,
+    /// Apply font kerning
+    pub kerning: bool,
 }
 
 impl LayoutNodeId {
@@ -88,6 +94,9 @@ fn text_measure_function(
         options.generic_font = ctx.generic_font;
         options.font_weight = ctx.font_weight;
         options.italic = ctx.italic;
+        options.preformatted = ctx.preformatted;
+        options.ligatures = ctx.ligatures;
+        options.kerning = ctx.kerning;
         options.line_height = ctx.line_height;
         // No max_width for non-wrapping
 
@@ -114,6 +123,9 @@ fn text_measure_function(
     options.generic_font = ctx.generic_font;
     options.font_weight = ctx.font_weight;
     options.italic = ctx.italic;
+    options.preformatted = ctx.preformatted;
+    options.ligatures = ctx.ligatures;
+    options.kerning = ctx.kerning;
     options.line_height = ctx.line_height;
     options.max_width = max_width;
 
@@ -125,12 +137,29 @@ fn text_measure_function(
     }
 }
 
+/// Number of layout passes run on a tree, for profiling incremental layout
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LayoutPassCounts {
+    /// Passes over the whole tree (`compute_layout`)
+    pub full: u64,
+    /// Passes limited to a subtree (`compute_layout_subtree`)
+    pub subtree: u64,
+}
+
 /// Maps between Blinc node IDs and Taffy node IDs
 pub struct LayoutTree {
     taffy: TaffyTree<TextMeasureContext>,
     node_map: SlotMap<LayoutNodeId, NodeId>,
     /// Reverse mapping from Taffy NodeId to our LayoutNodeId
     reverse_map: HashMap<NodeId, LayoutNodeId>,
+    /// Root and available space of the last full layout pass
+    last_root: Option<(LayoutNodeId, Size<AvailableSpace>)>,
+    /// Layouts of subtree roots re-laid out in isolation
+    ///
+    /// Taffy places the root of a layout pass at the origin, so the
+    /// node's position within its parent is restored here.
+    subtree_layouts: HashMap<LayoutNodeId, Layout>,
+    pass_counts: LayoutPassCounts,
 }
 
 impl LayoutTree {
@@ -139,6 +168,9 @@ impl LayoutTree {
             taffy: TaffyTree::new(),
             node_map: SlotMap::with_key(),
             reverse_map: HashMap::new(),
+            last_root: None,
+            subtree_layouts: HashMap::new(),
+            pass_counts: LayoutPassCounts::default(),
         }
     }
 
@@ -176,6 +208,15 @@ impl LayoutTree {
             .cloned()
     }
 
+    /// Replace the text measure context of a text node (e.g. after its text changed)
+    ///
+    /// Marks the node dirty so the next layout pass re-measures it.
+    pub fn set_text_context(&mut self, id: LayoutNodeId, context: TextMeasureContext) {
+        if let Some(&taffy_node) = self.node_map.get(id) {
+            let _ = self.taffy.set_node_context(taffy_node, Some(context));
+        }
+    }
+
     /// Add a child to a parent node
     pub fn add_child(&mut self, parent: LayoutNodeId, child: LayoutNodeId) {
         if let (Some(&parent_node), Some(&child_node)) =
@@ -193,11 +234,96 @@ impl LayoutTree {
                 available_space,
                 text_measure_function,
             );
+            self.last_root = Some((root, available_space));
+            self.subtree_layouts.clear();
+            self.pass_counts.full += 1;
+        }
+    }
+
+    /// Re-layout only the subtree rooted at `node`
+    ///
+    /// `available_space` is the space the parent gives the node, normally its
+    /// current size. If the node's outer size and content size come out
+    /// unchanged, ancestors and siblings keep their layout. Otherwise the
+    /// change can affect ancestors, so the whole tree is laid out again from
+    /// the root of the last `compute_layout` call.
+    ///
+    /// Returns `true` if the change propagated to a full layout pass.
+    pub fn compute_layout_subtree(
+        &mut self,
+        node: LayoutNodeId,
+        available_space: Size<AvailableSpace>,
+    ) -> bool {
+        let Some(&taffy_node) = self.node_map.get(node) else {
+            return false;
+        };
+        let (old, root) = match (self.get_layout(node).copied(), self.last_root) {
+            (Some(old), Some(root)) if root.0 != node => (old, root),
+            // Never laid out, or it is the root: nothing to preserve
+            _ => {
+                self.compute_layout(node, available_space);
+                return true;
+            }
+        };
+
+        let _ = self.taffy.compute_layout_with_measure(
+            taffy_node,
+            available_space,
+            text_measure_function,
+        );
+        self.pass_counts.subtree += 1;
+
+        // Positions inside the subtree are fresh; drop earlier restorations there
+        let stale: Vec<_> = self
+            .subtree_layouts
+            .keys()
+            .copied()
+            .filter(|&id| self.is_within(id, node))
+            .collect();
+        for id in stale {
+            self.subtree_layouts.remove(&id);
+        }
+
+        let Ok(&new) = self.taffy.layout(taffy_node) else {
+            return false;
+        };
+        if new.size == old.size && new.content_size == old.content_size {
+            self.subtree_layouts.insert(
+                node,
+                Layout {
+                    location: old.location,
+                    ..new
+                },
+            );
+            false
+        } else {
+            self.compute_layout(root.0, root.1);
+            true
         }
     }
 
+    /// Layout passes run so far
+    pub fn layout_pass_counts(&self) -> LayoutPassCounts {
+        self.pass_counts
+    }
+
+    /// Whether `id` is `ancestor` or one of its descendants
+    fn is_within(&self, id: LayoutNodeId, ancestor: LayoutNodeId) -> bool {
+        let mut current = Some(id);
+        while let Some(node) = current {
+            if node == ancestor {
+                return true;
+            }
+            current = self.parent(node);
+        }
+        false
+    }
+
     /// Get the computed layout for a node
     pub fn get_layout(&self, id: LayoutNodeId) -> Option<&Layout> {
+        if let Some(layout) = self.subtree_layouts.get(&id) {
+            return Some(layout);
+        }
         self.node_map
             .get(id)
             .and_then(|&taffy_node| self.taffy.layout(taffy_node).ok())
@@ -212,6 +338,7 @@ impl LayoutTree {
     pub fn remove_node(&mut self, id: LayoutNodeId) {
         if let Some(taffy_node) = self.node_map.remove(id) {
             self.reverse_map.remove(&taffy_node);
+            self.subtree_layouts.remove(&id);
             let _ = self.taffy.remove(taffy_node);
         }
     }
@@ -232,6 +359,13 @@ impl LayoutTree {
             .collect()
     }
 
+    /// Get the parent of a layout node
+    pub fn parent(&self, child: LayoutNodeId) -> Option<LayoutNodeId> {
+        let &taffy_node = self.node_map.get(child)?;
+        let parent = self.taffy.parent(taffy_node)?;
+        self.reverse_map.get(&parent).copied()
+    }
+
     /// Get computed layout as ElementBounds with parent offset
     pub fn get_bounds(&self, id: LayoutNodeId, parent_offset: (f32, f32)) -> Option<ElementBounds> {
         self.get_layout(id)
@@ -324,3 +458,102 @@ impl Default for LayoutTree {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text_context(content: &str) -> TextMeasureContext {
+        TextMeasureContext {
+            content: content.to_string(),
+            font_size: 14.0,
+            line_height: 1.2,
+            wrap: true,
+            font_name: None,
+            generic_font: crate::div::GenericFont::default(),
+            font_weight: 400,
+            italic: false,
+            preformatted: false,
+            ligatures: true,
+            kerning: true,
+        }
+    }
+
+    /// Row of a fixed-size header followed by `container` holding a text leaf
+    fn build(container_style: Style) -> (LayoutTree, LayoutNodeId, LayoutNodeId, LayoutNodeId) {
+        let mut tree = LayoutTree::new();
+        let root = tree.create_node(Style {
+            flex_direction: FlexDirection::Row,
+            align_items: Some(AlignItems::FlexStart),
+            size: Size {
+                width: length(400.0),
+                height: length(300.0),
+            },
+            ..Default::default()
+        });
+        let header = tree.create_node(Style {
+            size: Size {
+                width: length(50.0),
+                height: length(50.0),
+            },
+            ..Default::default()
+        });
+        let container = tree.create_node(container_style);
+        let leaf = tree.create_text_node(Style::default(), text_context("Hello"));
+        tree.add_child(root, header);
+        tree.add_child(root, container);
+        tree.add_child(container, leaf);
+        tree.compute_layout(root, Size::MAX_CONTENT);
+        (tree, root, container, leaf)
+    }
+
+    fn current_space(tree: &LayoutTree, id: LayoutNodeId) -> Size<AvailableSpace> {
+        let size = tree.get_layout(id).unwrap().size;
+        Size {
+            width: AvailableSpace::Definite(size.width),
+            height: AvailableSpace::Definite(size.height),
+        }
+    }
+
+    #[test]
+    fn test_subtree_layout_skips_ancestors_when_size_unchanged() {
+        let (mut tree, root, container, leaf) = build(Style {
+            size: Size {
+                width: length(200.0),
+                height: length(40.0),
+            },
+            ..Default::default()
+        });
+        let before = *tree.get_layout(container).unwrap();
+        assert_eq!(tree.layout_pass_counts().full, 1);
+
+        // Same length text measures the same
+        tree.set_text_context(leaf, text_context("World"));
+        let space = current_space(&tree, container);
+        assert!(!tree.compute_layout_subtree(container, space));
+
+        let counts = tree.layout_pass_counts();
+        assert_eq!(counts.full, 1, "root should not be re-laid out");
+        assert_eq!(counts.subtree, 1);
+        let after = tree.get_layout(container).unwrap();
+        assert_eq!(after.location, before.location);
+        assert_eq!(after.size, before.size);
+        assert!(tree.get_layout(root).is_some());
+    }
+
+    #[test]
+    fn test_subtree_size_change_propagates() {
+        let (mut tree, _root, container, leaf) = build(Style::default());
+        let before = tree.get_layout(container).unwrap().size;
+
+        tree.set_text_context(leaf, text_context("Hello, this text is a lot longer now"));
+        let space = current_space(&tree, container);
+        assert!(tree.compute_layout_subtree(container, space));
+
+        assert_eq!(tree.layout_pass_counts().full, 2);
+        let after = tree.get_layout(container).unwrap();
+        assert!(after.size.width > before.width);
+        // Laid out in place after the header, not at the origin
+        assert_eq!(after.location.x, 50.0);
+    }
+}
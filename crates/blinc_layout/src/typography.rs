@@ -41,6 +41,12 @@
 //! muted("Less important")
 //! ```
 //!
+//! # Scaling
+//!
+//! Heading sizes follow `blinc_theme::set_typography_scale` when a modular
+//! scale is set, and all text honours the global `blinc_theme::set_text_scale`
+//! multiplier at render time.
+//!
 //! # All helpers support the full Text API
 //!
 //! ```ignore
@@ -161,7 +167,10 @@ pub fn h6(content: impl Into<String>) -> Text {
 /// ```
 pub fn heading(level: u8, content: impl Into<String>) -> Text {
     let idx = (level.saturating_sub(1).min(5)) as usize;
-    let (size, weight) = HEADING_CONFIG[idx];
+    let (default_size, weight) = HEADING_CONFIG[idx];
+    let size = blinc_theme::typography_scale()
+        .map(|scale| scale.heading(idx as u8 + 1))
+        .unwrap_or(default_size);
 
     let t = text(content).size(size).no_wrap();
 
@@ -350,6 +359,82 @@ pub fn chained_text<const N: usize>(elements: [Text; N]) -> Div {
     container
 }
 
+// ============================================================================
+// Inline Content
+// ============================================================================
+
+use crate::svg::Svg;
+
+/// A run of content for [`text_with_inline`]
+pub enum InlineRun {
+    /// Styled text, which can wrap between words
+    Text(Text),
+    /// An icon that sits on the text baseline
+    Icon(Svg),
+}
+
+impl From<Text> for InlineRun {
+    fn from(text: Text) -> Self {
+        InlineRun::Text(text)
+    }
+}
+
+impl From<Svg> for InlineRun {
+    fn from(icon: Svg) -> Self {
+        InlineRun::Icon(icon)
+    }
+}
+
+/// Create a paragraph of text with inline icons
+///
+/// Text runs are split into words so text and icons wrap together as one
+/// paragraph. Everything is aligned on a shared baseline: text by its font
+/// ascender, icons by their bottom edge (like an inline image in CSS).
+///
+/// # Example
+///
+/// ```ignore
+/// use blinc_layout::prelude::*;
+///
+/// text_with_inline(vec![
+///     span("Press ").into(),
+///     svg(GEAR_ICON).square(16.0).into(),
+///     span(" to open settings").into(),
+/// ])
+/// ```
+pub fn text_with_inline(runs: impl IntoIterator<Item = InlineRun>) -> Div {
+    // Each word or icon, with its height above the baseline
+    let mut pieces: Vec<(InlineRun, f32)> = Vec::new();
+    for run in runs {
+        match run {
+            InlineRun::Text(text) => {
+                let text = text.v_baseline().no_wrap();
+                for word in text.content().split_inclusive(char::is_whitespace) {
+                    let word = text.with_content(word);
+                    let ascent = word.ascender();
+                    pieces.push((InlineRun::Text(word), ascent));
+                }
+            }
+            InlineRun::Icon(icon) => {
+                let ascent = icon.height();
+                pieces.push((InlineRun::Icon(icon), ascent));
+            }
+        }
+    }
+
+    // Push every piece down so its baseline lands on the tallest ascent
+    let baseline = pieces.iter().map(|(_, ascent)| *ascent).fold(0.0, f32::max);
+    let mut container = div().flex_row().flex_wrap().items_start();
+    for (piece, ascent) in pieces {
+        let offset = baseline - ascent;
+        container = match piece {
+            InlineRun::Text(text) => container.child(text.margin_top_px(offset)),
+            InlineRun::Icon(icon) => container.child(icon.margin_top_px(offset)),
+        };
+    }
+    container
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -402,4 +487,38 @@ mod tests {
 
         assert_eq!(tree.len(), 8);
     }
+
+    #[test]
+    fn test_text_with_inline_icon_sits_on_baseline() {
+        use crate::renderer::RenderTree;
+        use crate::svg::svg;
+
+        init_theme();
+        let ui = div().w(400.0).child(text_with_inline(vec![
+            span("Press ").size(16.0).into(),
+            svg("<svg></svg>").square(12.0).into(),
+            span(" to open").size(16.0).into(),
+        ]));
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(400.0, 200.0);
+
+        let layout = tree.layout();
+        let paragraph = layout.children(tree.root().unwrap())[0];
+        let pieces = layout.children(paragraph);
+        // "Press ", icon, " ", "to ", "open"
+        assert_eq!(pieces.len(), 5);
+        let bounds = |i: usize| layout.get_bounds(pieces[i], (0.0, 0.0)).unwrap();
+        let (press, icon, to) = (bounds(0), bounds(1), bounds(3));
+
+        // Between the words, on the same line
+        assert!(icon.x >= press.x + press.width - 0.01);
+        assert!(to.x >= icon.x + icon.width);
+        assert!(icon.y < press.y + press.height && press.y < icon.y + icon.height);
+
+        // Icon bottom on the text baseline (up to layout pixel rounding),
+        // not its top on the text top
+        let ascender = span("Press ").size(16.0).v_baseline().ascender();
+        assert!((icon.y + icon.height - (press.y + ascender)).abs() <= 0.5);
+        assert!(icon.y > press.y);
+    }
 }
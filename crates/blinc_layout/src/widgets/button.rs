@@ -22,24 +22,82 @@
 //!         .child(text("Save"))
 //! })
 //! .on_click(|_| save())
+//!
+//! // Stepper that keeps firing while held
+//! button(inc_state, "+")
+//!     .repeat(400, 80)
+//!     .on_click(move |_| count.update(|n| n + 1))
+//!
+//! // Sticky toolbar toggle
+//! let bold = ctx.use_state_keyed("bold", || false);
+//! button(bold_btn_state, "B").toggle(bold.clone())
 //! ```
 
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 
 use blinc_core::reactive::SignalId;
-use blinc_core::Color;
+use blinc_core::{Color, State};
 use blinc_theme::{ColorToken, ThemeState};
 
 use crate::div::{div, Div, ElementBuilder};
 use crate::element::RenderProps;
-use crate::stateful::{ButtonState, SharedState, Stateful};
+use crate::stateful::{
+    refresh_stateful, register_stateful_deps, ButtonState, SharedState, Stateful,
+};
 use crate::text::text;
 use crate::tree::{LayoutNodeId, LayoutTree};
 
 /// Button visual states (re-exported from stateful)
 pub use crate::stateful::ButtonState as ButtonVisualState;
 
-/// Button-specific configuration (colors)
+/// Auto-repeat timing for buttons held down
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ButtonRepeat {
+    /// Hold time before the first repeat, in milliseconds
+    pub initial_delay_ms: u64,
+    /// Time between repeats after the first, in milliseconds
+    pub interval_ms: u64,
+    /// Hold time after which repeats speed up, with the faster interval
+    pub acceleration: Option<(u64, u64)>,
+}
+
+impl ButtonRepeat {
+    /// Repeat every `interval_ms`, starting after `initial_delay_ms`
+    pub fn new(initial_delay_ms: u64, interval_ms: u64) -> Self {
+        Self {
+            initial_delay_ms,
+            interval_ms,
+            acceleration: None,
+        }
+    }
+
+    /// Switch to `fast_interval_ms` once the button has been held for `after_ms`
+    pub fn accelerate(mut self, after_ms: u64, fast_interval_ms: u64) -> Self {
+        self.acceleration = Some((after_ms.max(self.initial_delay_ms), fast_interval_ms));
+        self
+    }
+
+    /// Number of repeats due after the button has been held for `hold_ms`
+    pub fn due(&self, hold_ms: u64) -> u64 {
+        match self.acceleration {
+            Some((after_ms, fast_interval_ms)) if hold_ms > after_ms => {
+                self.steady_due(after_ms) + (hold_ms - after_ms) / fast_interval_ms.max(1)
+            }
+            _ => self.steady_due(hold_ms),
+        }
+    }
+
+    fn steady_due(&self, hold_ms: u64) -> u64 {
+        if hold_ms < self.initial_delay_ms {
+            0
+        } else {
+            (hold_ms - self.initial_delay_ms) / self.interval_ms.max(1) + 1
+        }
+    }
+}
+
+/// Button-specific configuration (colors, repeat and toggle behavior)
 #[derive(Clone)]
 pub struct ButtonConfig {
     pub label: Option<String>,
@@ -50,6 +108,34 @@ pub struct ButtonConfig {
     pub pressed_color: Color,
     pub disabled_color: Color,
     pub disabled: bool,
+    /// Fire `on_click` repeatedly while held
+    pub repeat: Option<ButtonRepeat>,
+    /// Sticky on/off state flipped by each click
+    pub toggle: Option<State<bool>>,
+}
+
+impl ButtonConfig {
+    /// Fire `on_click` every `interval_ms` while held, starting after `initial_delay_ms`
+    pub fn repeat(mut self, initial_delay_ms: u64, interval_ms: u64) -> Self {
+        self.repeat = Some(ButtonRepeat::new(initial_delay_ms, interval_ms));
+        self
+    }
+
+    /// Speed repeats up to `fast_interval_ms` after holding for `after_ms`
+    ///
+    /// Has no effect unless [`repeat`](Self::repeat) is set.
+    pub fn accelerate(mut self, after_ms: u64, fast_interval_ms: u64) -> Self {
+        self.repeat = self
+            .repeat
+            .map(|repeat| repeat.accelerate(after_ms, fast_interval_ms));
+        self
+    }
+
+    /// Flip `state` on each click and show the pressed color while it's true
+    pub fn toggle(mut self, state: State<bool>) -> Self {
+        self.toggle = Some(state);
+        self
+    }
 }
 
 impl Default for ButtonConfig {
@@ -64,6 +150,8 @@ impl Default for ButtonConfig {
             pressed_color: theme.color(ColorToken::PrimaryActive),
             disabled_color: theme.color(ColorToken::InputBgDisabled),
             disabled: false,
+            repeat: None,
+            toggle: None,
         }
     }
 }
@@ -81,6 +169,7 @@ pub struct Button {
     click_handler: Option<ClickHandler>,
     custom_state_callback: Option<StateCallback>,
     extra_deps: Vec<SignalId>,
+    repeat_registered: bool,
 }
 
 impl Button {
@@ -104,7 +193,7 @@ impl Button {
 
         // Create the inner Stateful - we'll apply bg color dynamically in build()
         // Don't use on_state callback for content since config changes after construction
-        let inner = Stateful::with_shared_state(state);
+        let inner = Self::focusable_inner(state);
 
         Self {
             inner,
@@ -112,6 +201,7 @@ impl Button {
             click_handler: None,
             custom_state_callback: None,
             extra_deps: Vec::new(),
+            repeat_registered: false,
         }
     }
 
@@ -144,7 +234,7 @@ impl Button {
         let content_builder = Arc::new(content_builder);
 
         // Create the inner Stateful
-        let inner = Stateful::with_shared_state(state);
+        let inner = Self::focusable_inner(state);
 
         Self {
             inner,
@@ -158,9 +248,18 @@ impl Button {
                 }
             })),
             extra_deps: Vec::new(),
+            repeat_registered: false,
         }
     }
 
+    /// Inner Stateful reachable by Tab, with a focus ring for keyboard focus
+    fn focusable_inner(state: SharedState<ButtonState>) -> Stateful<ButtonState> {
+        let ring = ThemeState::get().color(ColorToken::BorderFocus);
+        Stateful::with_shared_state(state)
+            .focusable()
+            .focus_ring(ring, 2.0)
+    }
+
     // Button-specific methods
     pub fn bg_color(self, color: impl Into<Color>) -> Self {
         self.config.lock().unwrap().bg_color = color.into();
@@ -212,6 +311,78 @@ impl Button {
         let handler_clone = Arc::clone(&handler);
         self.inner = self.inner.on_click(move |ctx| handler_clone(ctx));
         self.click_handler = Some(handler);
+        self.register_repeat()
+    }
+
+    /// Keep firing `on_click` while the button is held
+    ///
+    /// The first repeat fires after `initial_delay_ms`, then every
+    /// `interval_ms` until release. A quick press is still a single click;
+    /// once a repeat has fired, the release doesn't click again.
+    pub fn repeat(self, initial_delay_ms: u64, interval_ms: u64) -> Self {
+        self.config.lock().unwrap().repeat = Some(ButtonRepeat::new(initial_delay_ms, interval_ms));
+        self.register_repeat()
+    }
+
+    /// Speed repeats up to `fast_interval_ms` once held for `after_ms`
+    ///
+    /// Call after [`repeat`](Self::repeat); long holds then cover large
+    /// ranges quickly while short holds stay precise.
+    pub fn accelerate(self, after_ms: u64, fast_interval_ms: u64) -> Self {
+        {
+            let mut config = self.config.lock().unwrap();
+            config.repeat = config
+                .repeat
+                .map(|repeat| repeat.accelerate(after_ms, fast_interval_ms));
+        }
+        self
+    }
+
+    /// Make this a sticky toggle button bound to `state`
+    ///
+    /// Each click flips `state`, and the button shows its pressed color
+    /// while it's true. Setting `state` elsewhere updates the button.
+    pub fn toggle(mut self, state: State<bool>) -> Self {
+        let flip = state.clone();
+        self.config.lock().unwrap().toggle = Some(state);
+        self.inner = self.inner.on_click(move |_| flip.update(|on| !on));
+        self
+    }
+
+    /// Drive the click handler from PRESS_HOLD ticks once both it and the
+    /// repeat timing are set
+    fn register_repeat(mut self) -> Self {
+        use blinc_core::events::event_types;
+
+        let Some(handler) = self.click_handler.clone() else {
+            return self;
+        };
+        if self.repeat_registered || self.config.lock().unwrap().repeat.is_none() {
+            return self;
+        }
+        self.repeat_registered = true;
+
+        // Repeats fired during the current press, reset on each press
+        let fired = Arc::new(AtomicU64::new(0));
+        let reset = Arc::clone(&fired);
+        let config = Arc::clone(&self.config);
+        self.inner = self
+            .inner
+            .on_mouse_down(move |_| reset.store(0, Ordering::Relaxed))
+            .on_event(event_types::PRESS_HOLD, move |ctx| {
+                let due = {
+                    let cfg = config.lock().unwrap();
+                    match cfg.repeat {
+                        Some(repeat) if !cfg.disabled => repeat.due(ctx.hold_ms),
+                        _ => return,
+                    }
+                };
+                if due > fired.load(Ordering::Relaxed) {
+                    fired.store(due, Ordering::Relaxed);
+                    ctx.consume_click();
+                    handler(ctx);
+                }
+            });
         self
     }
 
@@ -531,11 +702,13 @@ impl ElementBuilder for Button {
                 Some(Arc::new(move |state: &ButtonState, container: &mut Div| {
                     tracing::debug!("Button on_state callback fired, state={:?}", state);
                     let cfg = config_for_state.lock().unwrap();
+                    let toggled_on = cfg.toggle.as_ref().is_some_and(|on| on.get());
                     let bg = match state {
+                        ButtonState::Disabled => cfg.disabled_color,
+                        _ if toggled_on => cfg.pressed_color,
                         ButtonState::Idle => cfg.bg_color,
                         ButtonState::Hovered => cfg.hover_color,
                         ButtonState::Pressed => cfg.pressed_color,
-                        ButtonState::Disabled => cfg.disabled_color,
                     };
 
                     // Apply background color and content
@@ -565,6 +738,24 @@ impl ElementBuilder for Button {
                 }));
             shared.base_render_props = Some(self.inner.inner_render_props());
             shared.needs_visual_update = true;
+
+            // Refresh the visual when the toggle state is set from elsewhere
+            if let Some(ref toggle) = self.config.lock().unwrap().toggle {
+                let signal_id = toggle.signal_id();
+                if !shared.deps.contains(&signal_id) {
+                    shared.deps.push(signal_id);
+                }
+            }
+            if !shared.deps.is_empty() {
+                let deps = shared.deps.clone();
+                let stateful_key = Arc::as_ptr(&shared_state) as u64;
+                let shared_for_refresh = Arc::clone(&shared_state);
+                register_stateful_deps(
+                    stateful_key,
+                    deps,
+                    Arc::new(move || refresh_stateful(&shared_for_refresh)),
+                );
+            }
         }
 
         // Build the inner Stateful - it will apply the callback we just set
@@ -593,3 +784,90 @@ impl ElementBuilder for Button {
         self.inner.layout_style()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, AtomicU32};
+
+    use blinc_core::events::event_types;
+    use blinc_core::reactive::ReactiveGraph;
+
+    use crate::renderer::RenderTree;
+    use crate::stateful::StatefulInner;
+
+    fn init_theme() {
+        let _ = ThemeState::try_get().unwrap_or_else(|| {
+            ThemeState::init_default();
+            ThemeState::get()
+        });
+    }
+
+    fn button_state() -> SharedState<ButtonState> {
+        Arc::new(Mutex::new(StatefulInner::new(ButtonState::Idle)))
+    }
+
+    /// Press the button root, tick PRESS_HOLD every frame for `hold_ms`, release
+    fn hold(tree: &mut RenderTree, hold_ms: u64) {
+        let node = tree.root().unwrap();
+        tree.dispatch_event(node, event_types::POINTER_DOWN, 10.0, 10.0);
+        for t in (0..=hold_ms).step_by(16) {
+            tree.dispatch_press_hold(node, t, 10.0, 10.0);
+        }
+        tree.dispatch_event(node, event_types::POINTER_UP, 10.0, 10.0);
+    }
+
+    #[test]
+    fn test_repeat_fires_while_held() {
+        init_theme();
+        let clicks = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&clicks);
+        let btn = button(button_state(), "+")
+            .repeat(100, 100)
+            .on_click(move |_| {
+                counter.fetch_add(1, Ordering::Relaxed);
+            });
+        let mut tree = RenderTree::from_element(&btn);
+        tree.compute_layout(200.0, 100.0);
+
+        hold(&mut tree, 500);
+        let fired = clicks.load(Ordering::Relaxed);
+        assert!((4..=5).contains(&fired), "fired {fired} times");
+
+        // A quick press is a single click
+        clicks.store(0, Ordering::Relaxed);
+        hold(&mut tree, 50);
+        assert_eq!(clicks.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_repeat_accelerates_after_threshold() {
+        let repeat = ButtonRepeat::new(100, 100).accelerate(500, 25);
+        assert_eq!(repeat.due(99), 0);
+        assert_eq!(repeat.due(100), 1);
+        assert_eq!(repeat.due(500), 5);
+        // Four fast repeats in the next 100ms instead of one
+        assert_eq!(repeat.due(600), 9);
+        assert_eq!(ButtonRepeat::new(100, 100).due(600), 6);
+    }
+
+    #[test]
+    fn test_toggle_flips_state() {
+        init_theme();
+        let mut graph = ReactiveGraph::new();
+        let signal = graph.create_signal(false);
+        let on = State::new(
+            signal,
+            Arc::new(Mutex::new(graph)),
+            Arc::new(AtomicBool::new(false)),
+        );
+        let btn = button(button_state(), "B").toggle(on.clone());
+        let mut tree = RenderTree::from_element(&btn);
+        tree.compute_layout(200.0, 100.0);
+
+        hold(&mut tree, 0);
+        assert!(on.get());
+        hold(&mut tree, 0);
+        assert!(!on.get());
+    }
+}
@@ -67,6 +67,8 @@ pub struct CodeConfig {
     pub corner_radius: f32,
     /// Whether editing is enabled
     pub editable: bool,
+    /// Columns between tab stops
+    pub tab_size: usize,
     /// Background color
     pub bg_color: Color,
     /// Text color (default, when no syntax highlighting)
@@ -94,6 +96,7 @@ impl Default for CodeConfig {
             padding: 16.0,
             corner_radius: 8.0,
             editable: false,
+            tab_size: 4,
             bg_color: theme.color(ColorToken::Surface),
             text_color: theme.color(ColorToken::TextPrimary),
             line_number_color: theme.color(ColorToken::TextTertiary),
@@ -499,6 +502,13 @@ impl Code {
         self
     }
 
+    /// Set the number of columns between tab stops (default: 4)
+    pub fn tab_size(mut self, columns: usize) -> Self {
+        self.config.tab_size = columns.max(1);
+        self.rebuild_inner();
+        self
+    }
+
     /// Set the padding
     pub fn padding(mut self, padding: f32) -> Self {
         self.config.padding = padding;
@@ -637,13 +647,20 @@ impl Code {
                         .color(self.config.text_color),
                 );
             } else {
-                // Render each span with its color
+                // Render each span with its color. Tabs are expanded against
+                // the column across spans so stops line up for the whole line.
+                let mut column = 0;
                 for span in &styled_line.spans {
-                    let span_text = &styled_line.text[span.start..span.end];
+                    let span_text = expand_tabs(
+                        &styled_line.text[span.start..span.end],
+                        self.config.tab_size,
+                        &mut column,
+                    );
                     let mut txt = text(span_text)
                         .size(self.config.font_size)
                         .color(span.color)
-                        .no_wrap(); // Don't wrap individual spans
+                        .no_wrap() // Don't wrap individual spans
+                        .preformatted();
 
                     if span.bold {
                         txt = txt.bold();
@@ -670,6 +687,7 @@ impl Code {
                 let cursor_x = if cursor_col > 0 && cursor_line < state.lines.len() {
                     let line_text = &state.lines[cursor_line];
                     let text_before: String = line_text.chars().take(cursor_col).collect();
+                    let text_before = expand_tabs(&text_before, self.config.tab_size, &mut 0);
                     crate::text_measure::measure_text(&text_before, self.config.font_size).width
                 } else {
                     0.0
@@ -958,6 +976,24 @@ impl ElementBuilder for Code {
     }
 }
 
+/// Replace tabs with spaces up to the next multiple of `tab_size` columns
+///
+/// `column` is the column `text` starts at and is advanced past it.
+fn expand_tabs(text: &str, tab_size: usize, column: &mut usize) -> String {
+    let mut expanded = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c == '\t' {
+            let spaces = tab_size - *column % tab_size;
+            expanded.push_str(&" ".repeat(spaces));
+            *column += spaces;
+        } else {
+            expanded.push(c);
+            *column += 1;
+        }
+    }
+    expanded
+}
+
 // ============================================================================
 // Convenience Constructors
 // ============================================================================
@@ -1006,6 +1042,16 @@ mod tests {
         assert_eq!(c.config.corner_radius, 12.0);
     }
 
+    #[test]
+    fn test_expand_tabs_uses_line_columns() {
+        let mut column = 0;
+        assert_eq!(expand_tabs("a\tb", 4, &mut column), "a   b");
+        assert_eq!(column, 5);
+        // A later span continues from the line column, not its own start
+        assert_eq!(expand_tabs("\tc", 4, &mut column), "   c");
+        assert_eq!(column, 9);
+    }
+
     #[test]
     fn test_code_state_insert() {
         let mut state = CodeState::new("hello");
@@ -46,7 +46,7 @@ pub mod text_area;
 pub mod text_input;
 
 // Re-export button widget
-pub use button::{button, button_with, Button, ButtonConfig, ButtonVisualState};
+pub use button::{button, button_with, Button, ButtonConfig, ButtonRepeat, ButtonVisualState};
 
 // Re-export checkbox widget
 pub use checkbox::{
@@ -58,8 +58,11 @@ pub use checkbox::{
 pub use text_input::{
     // Blur function for click-outside handling
     blur_all_text_inputs,
+    // Programmatic focus and clearing
+    clear_text_input,
     // Cursor blink timing utilities
     elapsed_ms,
+    focus_text_input,
     has_focused_text_input,
     // Rebuild/relayout request functions
     request_full_rebuild,
@@ -74,8 +77,10 @@ pub use text_input::{
     text_input_state_with_placeholder,
     InputConstraints,
     InputType,
+    NumberConstraints,
     SharedTextInputState,
     TextInput,
+    TextInputAdornments,
     TextInputConfig,
     TextInputState,
     CURSOR_BLINK_INTERVAL_MS,
@@ -89,9 +94,9 @@ pub use text_area::{
 
 // Re-export scroll widget
 pub use scroll::{
-    scroll, scroll_no_bounce, Scroll, ScrollConfig, ScrollDirection, ScrollPhysics,
-    ScrollRenderInfo, ScrollbarConfig, ScrollbarRenderInfo, ScrollbarSize, ScrollbarState,
-    ScrollbarVisibility, SharedScrollPhysics,
+    scroll, scroll_no_bounce, OverscrollBehavior, Scroll, ScrollConfig, ScrollDirection,
+    ScrollPhysics, ScrollRenderInfo, ScrollbarConfig, ScrollbarRenderInfo, ScrollbarSize,
+    ScrollbarState, ScrollbarVisibility, SharedScrollPhysics,
 };
 
 // Re-export cursor widget (canvas-based smooth cursor)
@@ -59,6 +59,18 @@ pub enum ScrollDirection {
     Both,
 }
 
+/// What happens to scroll input once a container reaches its edge
+///
+/// Like CSS `overscroll-behavior`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverscrollBehavior {
+    /// Leftover delta scrolls the nearest scrollable ancestor (default)
+    #[default]
+    Auto,
+    /// Scroll input never reaches ancestors, e.g. for modals
+    Contain,
+}
+
 // ============================================================================
 // Scrollbar Types
 // ============================================================================
@@ -239,6 +251,8 @@ pub struct ScrollConfig {
     pub max_overscroll: f32,
     /// Scroll direction
     pub direction: ScrollDirection,
+    /// Whether leftover scroll input chains to ancestors
+    pub overscroll_behavior: OverscrollBehavior,
     /// Scrollbar configuration
     pub scrollbar: ScrollbarConfig,
 }
@@ -255,6 +269,7 @@ impl Default for ScrollConfig {
             velocity_threshold: 10.0, // Stop when below 10 px/s
             max_overscroll: 0.3,      // 30% of viewport for visible elastic effect
             direction: ScrollDirection::Vertical,
+            overscroll_behavior: OverscrollBehavior::Auto,
             scrollbar: ScrollbarConfig::default(),
         }
     }
@@ -1709,6 +1724,10 @@ impl Scroll {
                 let mut p = physics.lock().unwrap();
                 // Check if click is on scrollbar using local coordinates
                 p.on_scrollbar_pointer_down(ctx.local_x, ctx.local_y);
+                // Keep tracking the thumb after the pointer leaves the area
+                if p.scrollbar_state == ScrollbarState::Dragging {
+                    ctx.capture_pointer();
+                }
             }
         });
 
@@ -1809,6 +1828,16 @@ impl Scroll {
         self
     }
 
+    /// Set whether scroll input left over at an edge reaches ancestors
+    ///
+    /// With [`OverscrollBehavior::Auto`] (the default), scrolling past this
+    /// container's end scrolls the nearest scrollable ancestor by the
+    /// remaining delta. [`OverscrollBehavior::Contain`] keeps it here.
+    pub fn overscroll_behavior(self, behavior: OverscrollBehavior) -> Self {
+        self.physics.lock().unwrap().config.overscroll_behavior = behavior;
+        self
+    }
+
     /// Set to vertical-only scrolling
     pub fn vertical(self) -> Self {
         self.direction(ScrollDirection::Vertical)
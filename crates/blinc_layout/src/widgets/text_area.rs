@@ -140,6 +140,15 @@ impl Default for TextAreaConfig {
 }
 
 impl TextAreaConfig {
+    /// Enable or disable text wrapping
+    ///
+    /// With wrapping disabled, lines keep their full width and the text area
+    /// scrolls horizontally to keep the caret in view (useful for code).
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
     /// Calculate the effective width based on cols or explicit width
     pub fn effective_width(&self) -> f32 {
         if let Some(cols) = self.cols {
@@ -1069,6 +1078,9 @@ impl TextAreaState {
 
         // Update physics offset (negative for scroll physics convention)
         physics.offset_y = -new_offset;
+        drop(physics);
+
+        self.ensure_cursor_visible_x();
     }
 
     /// Get current scroll offset (positive value, 0 = top)
@@ -1076,6 +1088,57 @@ impl TextAreaState {
         -self.scroll_physics.lock().unwrap().offset_y
     }
 
+    /// Get current horizontal scroll offset (positive value, 0 = left edge)
+    ///
+    /// Always 0 while wrapping is enabled.
+    pub fn scroll_offset_x(&self) -> f32 {
+        -self.scroll_physics.lock().unwrap().offset_x
+    }
+
+    /// Switch between wrapped and no-wrap layout
+    ///
+    /// The caret keeps its logical (line, column) position; visual lines are
+    /// recomputed and the scroll offsets adjusted so it stays in view.
+    pub fn set_wrap_enabled(&mut self, wrap: bool) {
+        if self.wrap_enabled == wrap {
+            return;
+        }
+        self.wrap_enabled = wrap;
+        self.compute_visual_lines();
+        let line_height = self.line_height;
+        let viewport_height = self.viewport_height;
+        self.ensure_cursor_visible(line_height, viewport_height);
+    }
+
+    /// Horizontal counterpart of `ensure_cursor_visible` for no-wrap mode
+    fn ensure_cursor_visible_x(&mut self) {
+        let mut physics = self.scroll_physics.lock().unwrap();
+        if self.wrap_enabled || self.available_width <= 0.0 {
+            physics.offset_x = 0.0;
+            return;
+        }
+
+        // Leave room for the 2px caret at the right edge
+        let cursor_x = self.cursor_x_in_visual_line();
+        let cursor_right = cursor_x + 2.0;
+        let content_width = self
+            .visual_lines
+            .iter()
+            .map(|vl| vl.width)
+            .fold(cursor_right, f32::max);
+
+        let mut new_offset = -physics.offset_x;
+        if cursor_x < new_offset {
+            new_offset = cursor_x;
+        }
+        if cursor_right > new_offset + self.available_width {
+            new_offset = cursor_right - self.available_width;
+        }
+
+        let max_scroll = (content_width - self.available_width).max(0.0);
+        physics.offset_x = -new_offset.clamp(0.0, max_scroll);
+    }
+
     /// Calculate cursor position from click coordinates
     ///
     /// Takes x/y coordinates relative to the text content area (after padding).
@@ -1296,7 +1359,7 @@ impl TextArea {
                     data_guard.viewport_height = viewport_height;
                     data_guard.font_size = cfg.font_size;
                     data_guard.available_width = available_width;
-                    data_guard.wrap_enabled = cfg.wrap;
+                    data_guard.set_wrap_enabled(cfg.wrap);
 
                     // Recompute visual lines for proper cursor tracking with wrapped text
                     data_guard.compute_visual_lines();
@@ -1428,7 +1491,7 @@ impl TextArea {
                     d.font_size = font_size;
                     d.line_height = line_height;
                     d.available_width = available_width;
-                    d.wrap_enabled = wrap_enabled;
+                    d.set_wrap_enabled(wrap_enabled);
 
                     // Ensure visual lines are computed before positioning cursor
                     // This is needed because click handler may run before the callback
@@ -1866,8 +1929,14 @@ impl TextArea {
         // This provides proper scroll handling and clipping
         // TextArea scroll doesn't use bounce animation - just hard stops at edges
         // Note: Don't add rounded() to scroll - the outer container handles visual rounding
+        // Without wrapping, long lines scroll horizontally as well
+        let scroll_direction = if config.wrap {
+            ScrollDirection::Vertical
+        } else {
+            ScrollDirection::Both
+        };
         let scrollable_content = Scroll::with_physics(Arc::clone(&data.scroll_physics))
-            .direction(ScrollDirection::Vertical)
+            .direction(scroll_direction)
             .no_bounce()
             .flex_grow() // Take remaining space
             .child(text_content);
@@ -2000,6 +2069,32 @@ impl TextArea {
     /// When disabled, text scrolls horizontally instead.
     pub fn wrap(mut self, wrap: bool) -> Self {
         self.config.lock().unwrap().wrap = wrap;
+        if let Ok(mut s) = self.state.lock() {
+            s.set_wrap_enabled(wrap);
+        }
+        self
+    }
+
+    /// Replace the whole configuration
+    ///
+    /// Usage: `text_area(&state).config(TextAreaConfig::default().wrap(false))`
+    pub fn config(mut self, config: TextAreaConfig) -> Self {
+        let (width, height) = (config.effective_width(), config.effective_height());
+        let (placeholder, disabled, wrap) =
+            (config.placeholder.clone(), config.disabled, config.wrap);
+        *self.config.lock().unwrap() = config;
+        self.inner = std::mem::take(&mut self.inner).w(width).h(height);
+        if let Ok(mut s) = self.state.lock() {
+            if !placeholder.is_empty() {
+                s.placeholder = placeholder;
+            }
+            s.disabled = disabled;
+            if disabled {
+                s.visual = TextFieldState::Disabled;
+            }
+            s.set_wrap_enabled(wrap);
+        }
+        self.update_scroll_dimensions();
         self
     }
 
@@ -2529,4 +2624,31 @@ mod tests {
         assert_eq!(state.value(), "new");
         assert_eq!(state.line_count(), 1);
     }
+
+    #[test]
+    fn test_no_wrap_scrolls_horizontally_to_caret() {
+        let mut state = TextAreaState::new();
+        state.available_width = 100.0;
+        state.set_wrap_enabled(false);
+
+        let line_height = state.line_height;
+        let viewport_height = state.viewport_height;
+        for _ in 0..60 {
+            state.insert("x");
+            state.compute_visual_lines();
+            state.ensure_cursor_visible(line_height, viewport_height);
+        }
+
+        assert_eq!(state.line_count(), 1);
+        let offset = state.scroll_offset_x();
+        assert!(offset > 0.0);
+        let caret_x = state.cursor_x_in_visual_line() - offset;
+        assert!(caret_x >= 0.0 && caret_x <= state.available_width);
+
+        // Switching back to wrapping keeps the caret and resets horizontal scroll
+        state.set_wrap_enabled(true);
+        assert_eq!(state.cursor, TextPosition::new(0, 60));
+        assert_eq!(state.scroll_offset_x(), 0.0);
+        assert!(state.visual_line_count() > 1);
+    }
 }
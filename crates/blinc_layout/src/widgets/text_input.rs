@@ -22,13 +22,15 @@ use blinc_core::Color;
 use blinc_theme::{ColorToken, ThemeState};
 
 use crate::canvas::canvas;
-use crate::div::{div, Div, ElementBuilder};
+use crate::div::{div, Div, ElementBuilder, ElementRef};
 use crate::element::RenderProps;
 use crate::stateful::{
     refresh_stateful, SharedState, StateTransitions, Stateful, StatefulInner, TextFieldState,
 };
 use crate::text::text;
-use crate::text_selection::{clear_selection, set_selection, SelectionSource};
+use crate::text_selection::{
+    clear_selection, clipboard_get_text, clipboard_set_text, set_selection, SelectionSource,
+};
 use crate::tree::{LayoutNodeId, LayoutTree};
 use crate::widgets::cursor::{cursor_state, CursorAnimation, SharedCursorState};
 
@@ -313,6 +315,76 @@ pub fn blur_all_text_inputs() {
     }
 }
 
+/// Focus a text input from code, as if it had been clicked
+///
+/// Blurs whichever text input or text area had focus. The cursor stays where
+/// it was. Does nothing if the input is disabled.
+pub fn focus_text_input(data: &SharedTextInputData) {
+    use blinc_core::events::event_types;
+
+    let stateful = {
+        let mut d = match data.lock() {
+            Ok(d) => d,
+            Err(_) => return,
+        };
+        if d.disabled {
+            return;
+        }
+        if !d.visual.is_focused() {
+            d.visual = TextFieldState::Focused;
+            d.focus_time_ms = elapsed_ms();
+            d.reset_cursor_blink();
+            increment_focus_count();
+            set_focused_text_input(data);
+            request_continuous_redraw();
+        }
+        d.stateful_state.clone()
+    };
+
+    if let Some(stateful) = stateful {
+        {
+            let mut shared = stateful.lock().unwrap();
+            if !shared.state.is_focused() {
+                if let Some(new_state) = shared.state.on_event(event_types::FOCUS) {
+                    shared.state = new_state;
+                    shared.needs_visual_update = true;
+                }
+            }
+        }
+        refresh_stateful(&stateful);
+    }
+}
+
+/// Empty a text input and focus it, for clear buttons
+///
+/// The input's `on_change` callback is called if it had a value. Does
+/// nothing if the input is disabled.
+pub fn clear_text_input(data: &SharedTextInputData) {
+    let callback = {
+        let mut d = match data.lock() {
+            Ok(d) => d,
+            Err(_) => return,
+        };
+        if d.disabled {
+            return;
+        }
+        let had_value = !d.value.is_empty();
+        d.value.clear();
+        d.cursor = 0;
+        d.selection_start = None;
+        d.scroll_offset_x = 0.0;
+        d.validate();
+        d.on_change_callback.clone().filter(|_| had_value)
+    };
+
+    // Refreshes the content even if the input was already focused
+    focus_text_input(data);
+
+    if let Some(callback) = callback {
+        callback("");
+    }
+}
+
 // =============================================================================
 // Input Types and Validation
 // =============================================================================
@@ -364,6 +436,73 @@ impl InputConstraints {
     }
 }
 
+/// Range and step size for numeric values, e.g. a stepper's bound value
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NumberConstraints {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    /// Increment per step; values snap to multiples of it (offset from `min`)
+    pub step: f64,
+}
+
+impl Default for NumberConstraints {
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+            step: 1.0,
+        }
+    }
+}
+
+impl NumberConstraints {
+    pub fn range(min: f64, max: f64) -> Self {
+        Self {
+            min: Some(min),
+            max: Some(max),
+            ..Default::default()
+        }
+    }
+
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Round `value` to the nearest step, then clamp it into range
+    pub fn apply(&self, value: f64) -> f64 {
+        let mut value = value;
+        if self.step > 0.0 {
+            let base = self.min.unwrap_or(0.0);
+            let steps = ((value - base) / self.step).round();
+            // Trim float noise so 0.1 + 0.2 lands on 0.3
+            value = ((base + steps * self.step) * 1e9).round() / 1e9;
+        }
+        if let Some(max) = self.max {
+            value = value.min(max);
+        }
+        if let Some(min) = self.min {
+            value = value.max(min);
+        }
+        value
+    }
+
+    /// `value` moved by `steps` steps (negative to decrement), constrained
+    pub fn offset(&self, value: f64, steps: i32) -> f64 {
+        self.apply(value + self.step * steps as f64)
+    }
+}
+
 // =============================================================================
 // TextInputData - the external state that persists across rebuilds
 // =============================================================================
@@ -398,6 +537,8 @@ pub struct TextInputData {
     /// Layout bounds storage - updated after each layout computation
     /// Used to get the actual computed width for proper scroll behavior
     pub layout_bounds_storage: crate::renderer::LayoutBoundsStorage,
+    /// Bounds of the clipped text area, which excludes padding and adornments
+    pub(crate) text_viewport: ElementRef<()>,
     /// Reference to the Stateful's shared state for triggering incremental updates
     pub(crate) stateful_state: Option<SharedState<TextFieldState>>,
     /// Callback invoked when text value changes
@@ -447,6 +588,7 @@ impl TextInputData {
             scroll_offset_x: 0.0,
             computed_width: None,
             layout_bounds_storage: Arc::new(Mutex::new(None)),
+            text_viewport: ElementRef::new(),
             stateful_state: None,
             on_change_callback: None,
         }
@@ -642,6 +784,48 @@ impl TextInputData {
         })
     }
 
+    /// Copy the selected text to the clipboard
+    ///
+    /// Masked (password) inputs never expose their value, so nothing is copied.
+    /// Returns `true` if text was copied.
+    pub fn copy(&self) -> bool {
+        if self.masked {
+            return false;
+        }
+        match self.selected_text() {
+            Some(text) if !text.is_empty() => {
+                clipboard_set_text(&text);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Copy the selected text to the clipboard and remove it from the value
+    ///
+    /// Returns `true` if the value changed.
+    pub fn cut(&mut self) -> bool {
+        if !self.copy() {
+            return false;
+        }
+        self.delete_backward();
+        true
+    }
+
+    /// Replace the selection (or insert at the cursor) with the clipboard text
+    ///
+    /// Line breaks are flattened to spaces since the input is single-line.
+    /// Returns `true` if the value changed.
+    pub fn paste(&mut self) -> bool {
+        let Some(text) = clipboard_get_text() else {
+            return false;
+        };
+        let flattened = text.replace("\r\n", " ").replace(['\r', '\n'], " ");
+        let before = self.value.clone();
+        self.insert(&flattened);
+        self.value != before
+    }
+
     pub fn validate(&mut self) {
         self.is_valid = match self.input_type {
             InputType::Email => {
@@ -766,10 +950,14 @@ impl TextInputData {
         };
 
         // Calculate available width for text (the visible viewport)
-        // Use computed_width if available (set after layout), otherwise fall back to config.width
-        // Account for padding on both sides and border
+        // Prefer the text area's own layout width, which also excludes adornments.
+        // Before that is known, use computed_width if available (set after layout),
+        // otherwise fall back to config.width, accounting for padding and border
         let base_width = effective_computed_width.unwrap_or(config.width);
-        let available_width = base_width - config.padding_x * 2.0 - config.border_width * 2.0;
+        let available_width = match self.text_viewport.get_layout_bounds() {
+            Some(viewport) => viewport.width,
+            None => base_width - config.padding_x * 2.0 - config.border_width * 2.0,
+        };
 
         // Simple approach: measure if text exceeds viewport
         // If cursor is past the visible right edge, scroll to show cursor
@@ -840,6 +1028,7 @@ pub struct TextInputConfig {
     pub border_width: f32,
     pub padding_x: f32,
     pub placeholder: String,
+    pub adornments: TextInputAdornments,
 }
 
 impl Default for TextInputConfig {
@@ -865,10 +1054,42 @@ impl Default for TextInputConfig {
             border_width: 1.5,
             padding_x: 12.0,
             placeholder: String::new(),
+            adornments: TextInputAdornments::default(),
         }
     }
 }
 
+/// Builds an element shown inside a text input's border
+pub type AdornmentFn = Arc<dyn Fn() -> Div + Send + Sync>;
+
+/// Elements shown inside a text input's border, around the text
+///
+/// The text area shrinks to make room, so text never runs underneath them.
+/// Builders are called again whenever the input redraws its content, so
+/// they should be cheap.
+#[derive(Clone, Default)]
+pub struct TextInputAdornments {
+    /// Element before the text (e.g. a search icon)
+    pub leading: Option<AdornmentFn>,
+    /// Muted text before the value (e.g. `https://`)
+    pub prefix: Option<String>,
+    /// Muted text after the value (e.g. `kg`)
+    pub suffix: Option<String>,
+    /// Element after the text (e.g. a clear button)
+    pub trailing: Option<AdornmentFn>,
+}
+
+impl std::fmt::Debug for TextInputAdornments {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TextInputAdornments")
+            .field("leading", &self.leading.is_some())
+            .field("prefix", &self.prefix)
+            .field("suffix", &self.suffix)
+            .field("trailing", &self.trailing.is_some())
+            .finish()
+    }
+}
+
 // =============================================================================
 // TextInput Widget
 // =============================================================================
@@ -1180,6 +1401,21 @@ impl TextInput {
                         36 => d.move_to_start(ctx.shift), // Home
                         35 => d.move_to_end(ctx.shift),   // End
                         65 if ctx.meta || ctx.ctrl => d.select_all(), // Ctrl/Cmd+A
+                        67 if ctx.meta || ctx.ctrl => {
+                            // Ctrl/Cmd+C - selection and value are unchanged
+                            d.copy();
+                            changed = false;
+                        }
+                        88 if ctx.meta || ctx.ctrl => {
+                            // Ctrl/Cmd+X
+                            value_changed = d.cut();
+                            changed = value_changed;
+                        }
+                        86 if ctx.meta || ctx.ctrl => {
+                            // Ctrl/Cmd+V
+                            value_changed = d.paste();
+                            changed = value_changed;
+                        }
                         27 => {
                             // Escape - blur the input
                             should_blur = true;
@@ -1289,6 +1525,18 @@ impl TextInput {
         main_content =
             main_content.child(div().w(config.padding_x).h(inner_height).flex_shrink_0());
 
+        // Leading adornments, each followed by a gap before the text
+        let adornments = &config.adornments;
+        let gap = || div().w(config.padding_x / 2.0).flex_shrink_0();
+        if let Some(ref leading) = adornments.leading {
+            main_content = main_content.child(leading().flex_shrink_0()).child(gap());
+        }
+        if let Some(ref prefix) = adornments.prefix {
+            main_content = main_content
+                .child(Self::adornment_text(prefix, config))
+                .child(gap());
+        }
+
         // Clip container - use flex_1 to fill available space
         // This works for both full-width and fixed-width cases because:
         // - The parent (main_content) already has the width constraint
@@ -1299,7 +1547,8 @@ impl TextInput {
             .relative()
             .overflow_clip()
             .flex_1()
-            .min_w(0.0);
+            .min_w(0.0)
+            .bind_bounds(&data.text_viewport);
 
         // Text wrapper with absolute positioning
         // Using left() with negative scroll offset to scroll content
@@ -1437,6 +1686,16 @@ impl TextInput {
         // Add clip container to main content
         main_content = main_content.child(clip_container);
 
+        // Trailing adornments, each preceded by a gap after the text
+        if let Some(ref suffix) = adornments.suffix {
+            main_content = main_content
+                .child(gap())
+                .child(Self::adornment_text(suffix, config));
+        }
+        if let Some(ref trailing) = adornments.trailing {
+            main_content = main_content.child(gap()).child(trailing().flex_shrink_0());
+        }
+
         // Right padding spacer
         main_content =
             main_content.child(div().w(config.padding_x).h(inner_height).flex_shrink_0());
@@ -1445,6 +1704,17 @@ impl TextInput {
         main_content
     }
 
+    /// Muted prefix/suffix text, sized like the value
+    fn adornment_text(content: &str, config: &TextInputConfig) -> Div {
+        div().flex_shrink_0().child(
+            text(content)
+                .size(config.font_size)
+                .color(config.placeholder_color)
+                .no_wrap()
+                .v_center(),
+        )
+    }
+
     // Builder methods that forward to inner Stateful
     pub fn w(mut self, px: f32) -> Self {
         {
@@ -1624,6 +1894,43 @@ impl TextInput {
         self
     }
 
+    // ========== Adornments ==========
+
+    /// Show an element inside the border, before the text
+    ///
+    /// Clicking it (or the space around it) focuses the input. The builder
+    /// runs whenever the input redraws, so keep it cheap.
+    pub fn leading<F>(self, builder: F) -> Self
+    where
+        F: Fn() -> Div + Send + Sync + 'static,
+    {
+        self.config.lock().unwrap().adornments.leading = Some(Arc::new(builder));
+        self
+    }
+
+    /// Show an element inside the border, after the text
+    ///
+    /// Use [`clear_text_input`] in its click handler for a clear button.
+    pub fn trailing<F>(self, builder: F) -> Self
+    where
+        F: Fn() -> Div + Send + Sync + 'static,
+    {
+        self.config.lock().unwrap().adornments.trailing = Some(Arc::new(builder));
+        self
+    }
+
+    /// Show muted text before the value, e.g. `https://`
+    pub fn prefix(self, text: impl Into<String>) -> Self {
+        self.config.lock().unwrap().adornments.prefix = Some(text.into());
+        self
+    }
+
+    /// Show muted text after the value, e.g. a unit
+    pub fn suffix(self, text: impl Into<String>) -> Self {
+        self.config.lock().unwrap().adornments.suffix = Some(text.into());
+        self
+    }
+
     // ========== Text Color Configuration ==========
 
     /// Set the text color
@@ -1783,4 +2090,100 @@ mod tests {
         data.insert("abc123");
         assert_eq!(data.value, "123");
     }
+
+    #[test]
+    fn test_number_constraints_round_and_clamp() {
+        let constraints = NumberConstraints::range(0.0, 10.0).step(0.5);
+        assert_eq!(constraints.apply(3.3), 3.5);
+        assert_eq!(constraints.apply(12.0), 10.0);
+        assert_eq!(constraints.offset(9.5, 2), 10.0);
+        assert_eq!(constraints.offset(0.0, -1), 0.0);
+
+        let tenths = NumberConstraints::default().step(0.1);
+        assert_eq!(tenths.offset(0.2, 1), 0.3);
+    }
+
+    #[test]
+    fn test_copy_and_paste_selection() {
+        let mut source = TextInputData::with_value("hello world");
+        source.stateful_state = None;
+        source.selection_start = Some(6);
+        source.cursor = 11;
+        assert!(source.copy());
+        assert_eq!(clipboard_get_text(), Some("world".to_string()));
+
+        let mut target = TextInputData::new();
+        target.stateful_state = None;
+        assert!(target.paste());
+        assert_eq!(target.value, "world");
+        assert_eq!(target.cursor, 5);
+
+        // Cut removes the selection, paste replaces it and flattens newlines
+        assert!(source.cut());
+        assert_eq!(source.value, "hello ");
+        clipboard_set_text("big\nwide\r\nworld");
+        source.select_all();
+        assert!(source.paste());
+        assert_eq!(source.value, "big wide world");
+    }
+
+    #[test]
+    fn test_adornments_shrink_text_area() {
+        ThemeState::init_default();
+        let plain = text_input_data();
+        let adorned = text_input_data();
+
+        let row = div()
+            .w(240.0)
+            .flex_col()
+            .child(text_input(&plain).w_full())
+            .child(
+                text_input(&adorned)
+                    .w_full()
+                    .prefix("https://")
+                    .trailing(|| div().w(20.0).h(20.0)),
+            );
+        let mut tree = crate::renderer::RenderTree::from_element(&row);
+        tree.compute_layout(400.0, 200.0);
+
+        let viewport = |data: &SharedTextInputData| {
+            let d = data.lock().unwrap();
+            d.text_viewport.get_layout_bounds().unwrap().width
+        };
+        let prefix_width = crate::text_measure::measure_text("https://", 16.0).width;
+        // The border is drawn over the padding, so only padding is subtracted
+        assert!((viewport(&plain) - (240.0 - 2.0 * 12.0)).abs() < 0.5);
+        assert!(viewport(&adorned) <= viewport(&plain) - prefix_width - 20.0 - 2.0 * 6.0 + 0.5);
+    }
+
+    #[test]
+    fn test_clear_text_input_empties_and_focuses() {
+        let data = text_input_data();
+        {
+            let mut d = data.lock().unwrap();
+            *d = TextInputData::with_value("hello");
+            d.selection_start = Some(1);
+        }
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        data.lock().unwrap().on_change_callback = Some({
+            let changes = Arc::clone(&changes);
+            Arc::new(move |value: &str| changes.lock().unwrap().push(value.to_string()))
+        });
+
+        clear_text_input(&data);
+        {
+            let d = data.lock().unwrap();
+            assert_eq!(d.value, "");
+            assert_eq!((d.cursor, d.selection_start), (0, None));
+            assert!(d.visual.is_focused());
+        }
+        assert_eq!(*changes.lock().unwrap(), [""]);
+
+        // Clearing an empty input doesn't report a change
+        clear_text_input(&data);
+        assert_eq!(changes.lock().unwrap().len(), 1);
+
+        blur_all_text_inputs();
+        assert!(!data.lock().unwrap().visual.is_focused());
+    }
 }
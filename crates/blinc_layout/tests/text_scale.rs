@@ -0,0 +1,26 @@
+//! Global text scale applies at render time
+//!
+//! Kept as an integration test: the scale is process-global and would race
+//! with text measurement in the unit tests.
+
+use blinc_layout::div::ElementBuilder;
+use blinc_layout::typography::h1;
+
+#[test]
+fn test_text_scale_applies_without_rebuild() {
+    let title = h1("Title");
+    let base = title.text_render_info().unwrap().font_size;
+    assert_eq!(base, title.font_size());
+
+    blinc_theme::set_text_scale(1.5);
+    let scaled = title.text_render_info().unwrap().font_size;
+    assert_eq!(scaled, base * 1.5);
+    assert_eq!(title.rendered_font_size(), base * 1.5);
+
+    // Clamped to readable bounds
+    blinc_theme::set_text_scale(10.0);
+    assert_eq!(blinc_theme::text_scale(), blinc_theme::MAX_TEXT_SCALE);
+
+    blinc_theme::set_text_scale(1.0);
+    assert_eq!(title.text_render_info().unwrap().font_size, base);
+}
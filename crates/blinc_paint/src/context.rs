@@ -6,9 +6,14 @@
 use blinc_core::{
     BillboardFacing, BlendMode, Brush, Camera, ClipShape, CornerRadius, DrawCommand, DrawContext,
     Environment, ImageId, ImageOptions, LayerConfig, LayerId, Light, Mat4, MaterialId, MeshId,
-    MeshInstance, Path, Point, RecordingContext, Rect, SdfBuilder, Shadow, Size, Stroke, TextStyle,
-    Transform,
+    MeshInstance, Path, Point, RecordingContext, Rect, SdfBuilder, Shadow, Size, Stroke, TextAlign,
+    TextBaseline, TextStyle, Transform,
 };
+use std::sync::Arc;
+
+use crate::dash::dash_path;
+use crate::primitives::ArrowStyle;
+use crate::text::{EstimatedTextMeasurer, TextMeasurer, TextMetrics};
 
 // Re-export stroke types for convenience
 pub use blinc_core::{LineCap, LineJoin};
@@ -19,6 +24,7 @@ pub use blinc_core::{LineCap, LineJoin};
 /// while providing a Canvas-like API for convenience.
 pub struct PaintContext {
     recording: RecordingContext,
+    measurer: Arc<dyn TextMeasurer>,
 }
 
 impl PaintContext {
@@ -26,6 +32,7 @@ impl PaintContext {
     pub fn new(width: f32, height: f32) -> Self {
         Self {
             recording: RecordingContext::new(Size::new(width, height)),
+            measurer: Arc::new(EstimatedTextMeasurer),
         }
     }
 
@@ -33,9 +40,16 @@ impl PaintContext {
     pub fn from_size(size: Size) -> Self {
         Self {
             recording: RecordingContext::new(size),
+            measurer: Arc::new(EstimatedTextMeasurer),
         }
     }
 
+    /// Use a text measurer for `measure_text` and aligned text drawing
+    pub fn with_text_measurer(mut self, measurer: Arc<dyn TextMeasurer>) -> Self {
+        self.measurer = measurer;
+        self
+    }
+
     /// Get all recorded commands
     pub fn commands(&self) -> &[DrawCommand] {
         self.recording.commands()
@@ -130,6 +144,94 @@ impl PaintContext {
         );
     }
 
+    /// Measure text as `fill_text` would lay it out
+    pub fn measure_text(&self, text: &str, style: &TextStyle) -> TextMetrics {
+        self.measurer.measure(text, style)
+    }
+
+    /// Draw text anchored at (x, y), honoring the style's align and baseline
+    ///
+    /// `align` picks which part of each line sits at `x`, and `baseline`
+    /// which part of the text block sits at `y`. Lines are split at `\n`
+    /// and spaced by the style's line height.
+    pub fn fill_text(&mut self, text: &str, x: f32, y: f32, style: &TextStyle) {
+        let metrics = self.measure_text(text, style);
+        let line_style = TextStyle {
+            align: TextAlign::Left,
+            baseline: TextBaseline::Alphabetic,
+            ..style.clone()
+        };
+        let first_baseline = y + metrics.first_baseline(style.baseline);
+
+        for (i, (line, width)) in text.split('\n').zip(&metrics.line_widths).enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            let line_x = match style.align {
+                TextAlign::Left => x,
+                TextAlign::Center => x - width / 2.0,
+                TextAlign::Right => x - width,
+            };
+            let line_y = first_baseline + i as f32 * metrics.line_height;
+            self.draw_text(line, Point::new(line_x, line_y), &line_style);
+        }
+    }
+
+    /// Draw an outline around text placed as by `fill_text`
+    ///
+    /// Glyph outlines aren't available here, so the outline is approximated
+    /// by drawing the text in the style's color offset around a ring of
+    /// radius `stroke.width / 2`. Fill the same text on top to get outlined
+    /// text.
+    pub fn stroke_text(&mut self, text: &str, x: f32, y: f32, style: &TextStyle, stroke: &Stroke) {
+        let radius = stroke.width / 2.0;
+        if radius <= 0.0 {
+            return;
+        }
+        for i in 0..8 {
+            let angle = i as f32 * std::f32::consts::FRAC_PI_4;
+            let (dy, dx) = angle.sin_cos();
+            self.fill_text(text, x + dx * radius, y + dy * radius, style);
+        }
+    }
+
+    /// Draw a line from `from` to `to` with an open arrowhead at `to`
+    ///
+    /// The head is shortened to half the arrow's length so short arrows
+    /// don't end up all head. Nothing is drawn when the points coincide.
+    pub fn draw_arrow(&mut self, from: Point, to: Point, style: &ArrowStyle) {
+        let (dx, dy) = (to.x - from.x, to.y - from.y);
+        let length = dx.hypot(dy);
+        if length <= f32::EPSILON {
+            return;
+        }
+
+        let shaft = Path::new().move_to(from.x, from.y).line_to(to.x, to.y);
+        self.stroke_path(&shaft, &style.stroke, style.brush.clone());
+
+        let head_length = style.head_length.min(length / 2.0);
+        if head_length <= 0.0 {
+            return;
+        }
+        // Head segments point back along the shaft, rotated either side
+        let back = dy.atan2(dx) + std::f32::consts::PI;
+        let wing = |angle: f32| {
+            let (sin, cos) = angle.sin_cos();
+            Point::new(to.x + cos * head_length, to.y + sin * head_length)
+        };
+        let (left, right) = (wing(back - style.head_angle), wing(back + style.head_angle));
+        let head = Path::new()
+            .move_to(left.x, left.y)
+            .line_to(to.x, to.y)
+            .line_to(right.x, right.y);
+        let solid = Stroke {
+            dash: Vec::new(),
+            dash_offset: 0.0,
+            ..style.stroke.clone()
+        };
+        self.stroke_path(&head, &solid, style.brush.clone());
+    }
+
     // ═══════════════════════════════════════════════════════════════════════════
     // Transform convenience methods
     // ═══════════════════════════════════════════════════════════════════════════
@@ -202,7 +304,18 @@ impl DrawContext for PaintContext {
     }
 
     fn stroke_path(&mut self, path: &Path, stroke: &Stroke, brush: Brush) {
-        self.recording.stroke_path(path, stroke, brush);
+        if stroke.dash.is_empty() {
+            self.recording.stroke_path(path, stroke, brush);
+            return;
+        }
+        // The GPU stroker is solid-only, so stroke just the dashes
+        let dashed = dash_path(path, &stroke.dash, stroke.dash_offset);
+        let solid = Stroke {
+            dash: Vec::new(),
+            dash_offset: 0.0,
+            ..stroke.clone()
+        };
+        self.recording.stroke_path(&dashed, &solid, brush);
     }
 
     fn fill_rect(&mut self, rect: Rect, corner_radius: CornerRadius, brush: Brush) {
@@ -329,7 +442,7 @@ impl DrawContext for PaintContext {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use blinc_core::Color;
+    use blinc_core::{Color, PathCommand};
 
     #[test]
     fn test_paint_context_creation() {
@@ -353,6 +466,118 @@ mod tests {
         assert_eq!(ctx.commands().len(), 3);
     }
 
+    #[test]
+    fn test_fill_text_centered() {
+        let mut ctx = PaintContext::new(800.0, 600.0);
+        let style = TextStyle {
+            align: TextAlign::Center,
+            ..TextStyle::new(16.0)
+        };
+        let metrics = ctx.measure_text("Hello", &style);
+        ctx.fill_text("Hello", 200.0, 100.0, &style);
+
+        let DrawCommand::DrawText { origin, style, .. } = &ctx.commands()[0] else {
+            panic!("expected DrawText");
+        };
+        assert!((origin.x + metrics.width / 2.0 - 200.0).abs() < 1e-3);
+        assert_eq!(origin.y, 100.0);
+        assert_eq!(style.align, TextAlign::Left);
+    }
+
+    #[test]
+    fn test_fill_text_multiline() {
+        let mut ctx = PaintContext::new(800.0, 600.0);
+        let style = TextStyle {
+            baseline: TextBaseline::Top,
+            line_height: 1.5,
+            ..TextStyle::new(20.0)
+        };
+        ctx.fill_text("one\ntwo\nthree", 0.0, 0.0, &style);
+
+        let baselines: Vec<f32> = ctx
+            .commands()
+            .iter()
+            .filter_map(|cmd| match cmd {
+                DrawCommand::DrawText { origin, .. } => Some(origin.y),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(baselines.len(), 3);
+        // Top baseline sits the ascent plus half-leading below y
+        assert!((baselines[0] - 21.0).abs() < 1e-3);
+        assert!((baselines[1] - baselines[0] - 30.0).abs() < 1e-3);
+        assert!((baselines[2] - baselines[1] - 30.0).abs() < 1e-3);
+    }
+
+    /// Line segments of every StrokePath command, in order
+    fn stroked_segments(ctx: &PaintContext) -> Vec<(Point, Point)> {
+        let mut segments = Vec::new();
+        for cmd in ctx.commands() {
+            let DrawCommand::StrokePath { path, .. } = cmd else {
+                continue;
+            };
+            let mut current = Point::ZERO;
+            for command in path.commands() {
+                match command {
+                    PathCommand::MoveTo(p) => current = *p,
+                    PathCommand::LineTo(p) => {
+                        segments.push((current, *p));
+                        current = *p;
+                    }
+                    other => panic!("unexpected {other:?}"),
+                }
+            }
+        }
+        segments
+    }
+
+    #[test]
+    fn test_draw_arrow() {
+        let mut ctx = PaintContext::new(800.0, 600.0);
+        let end = Point::new(100.0, 0.0);
+        ctx.draw_arrow(Point::ZERO, end, &ArrowStyle::new(Color::BLACK));
+
+        let segments = stroked_segments(&ctx);
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[0], (Point::ZERO, end));
+        // Both head segments end at the tip, one either side of the shaft
+        let (left, right) = (segments[1].0, segments[2].1);
+        assert_eq!((segments[1].1, segments[2].0), (end, end));
+        assert!(left.x < 100.0 && right.x < 100.0);
+        assert!((left.y + right.y).abs() < 1e-3 && left.y != 0.0);
+        assert!(((end.x - left.x).hypot(left.y) - 10.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_short_arrow_clamps_head() {
+        let mut ctx = PaintContext::new(800.0, 600.0);
+        let style = ArrowStyle::new(Color::BLACK).head(20.0, 0.5);
+        ctx.draw_arrow(Point::ZERO, Point::new(0.0, 8.0), &style);
+
+        let (wing, tip) = stroked_segments(&ctx)[1];
+        assert!(((tip.x - wing.x).hypot(tip.y - wing.y) - 4.0).abs() < 1e-3);
+
+        ctx.draw_arrow(Point::ZERO, Point::ZERO, &style);
+        assert_eq!(ctx.commands().len(), 2);
+    }
+
+    #[test]
+    fn test_stroke_path_honors_dash() {
+        let mut ctx = PaintContext::new(800.0, 600.0);
+        let line = Path::new().move_to(0.0, 0.0).line_to(100.0, 0.0);
+        let stroke = Stroke::new(2.0).with_dash(vec![10.0, 5.0], 0.0);
+        ctx.stroke_path(&line, &stroke, Color::BLACK.into());
+
+        // 100px of 15px periods: six full dashes and a 10px one at 90
+        let segments = stroked_segments(&ctx);
+        assert_eq!(segments.len(), 7);
+        assert_eq!(segments[6], (Point::new(90.0, 0.0), Point::new(100.0, 0.0)));
+        let DrawCommand::StrokePath { stroke, .. } = &ctx.commands()[0] else {
+            panic!("expected StrokePath");
+        };
+        assert!(stroke.dash.is_empty());
+    }
+
     #[test]
     fn test_implements_draw_context() {
         fn use_draw_context(ctx: &mut dyn DrawContext) {
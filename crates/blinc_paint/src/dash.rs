@@ -0,0 +1,201 @@
+//! Dashed strokes
+//!
+//! The GPU stroker draws solid lines only, so PaintContext turns a dashed
+//! stroke into a path holding just the "on" pieces of the dash pattern and
+//! strokes that. Curves are flattened to short line segments first.
+
+use blinc_core::{Path, Point};
+
+/// Longest line segment a curve is flattened to, in pixels
+const FLATTEN_STEP: f32 = 2.0;
+
+/// Most pattern repeats along a path before it's drawn solid instead
+const MAX_DASH_PERIODS: f32 = 100_000.0;
+
+/// Split a path into the dashes of `pattern`
+///
+/// `pattern` alternates dash and gap lengths, like canvas `setLineDash`;
+/// an odd-length pattern is repeated to make it even. `offset` shifts the
+/// pattern along each subpath. An empty or invalid pattern (negative
+/// lengths, or all zeros) returns the path unchanged, as does a pattern so
+/// short it would repeat more than 100k times along the path. A non-finite
+/// offset is treated as zero.
+pub fn dash_path(path: &Path, pattern: &[f32], offset: f32) -> Path {
+    let pattern = if pattern.len() % 2 == 1 {
+        pattern.repeat(2)
+    } else {
+        pattern.to_vec()
+    };
+    let total: f32 = pattern.iter().sum();
+    if pattern.is_empty()
+        || !total.is_finite()
+        || total <= 0.0
+        || pattern.iter().any(|&len| len < 0.0)
+    {
+        return path.clone();
+    }
+
+    let polylines = path.flatten(FLATTEN_STEP);
+    let length: f32 = polylines
+        .iter()
+        .flat_map(|polyline| polyline.windows(2))
+        .map(|pair| (pair[1].x - pair[0].x).hypot(pair[1].y - pair[0].y))
+        .sum();
+    if !length.is_finite() || length / total > MAX_DASH_PERIODS {
+        return path.clone();
+    }
+    let offset = if offset.is_finite() { offset } else { 0.0 };
+
+    let mut dashed = Path::new();
+    for polyline in polylines {
+        // Each subpath starts the pattern over, shifted by the offset
+        let mut index = 0;
+        let mut remaining = pattern[0];
+        let mut phase = offset.rem_euclid(total);
+        while phase > remaining {
+            phase -= remaining;
+            index = (index + 1) % pattern.len();
+            remaining = pattern[index];
+        }
+        remaining -= phase;
+        let mut pen_down = false;
+
+        for pair in polyline.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            let length = (b.x - a.x).hypot(b.y - a.y);
+            let point_at = |distance: f32| {
+                let t = distance / length;
+                Point::new(a.x + (b.x - a.x) * t, a.y + (b.y - a.y) * t)
+            };
+
+            let mut along = 0.0;
+            loop {
+                let left = length - along;
+                let step = remaining.min(left);
+                if index % 2 == 0 && step > 0.0 {
+                    if !pen_down {
+                        let start = point_at(along);
+                        dashed = dashed.move_to(start.x, start.y);
+                        pen_down = true;
+                    }
+                    let end = point_at(along + step);
+                    dashed = dashed.line_to(end.x, end.y);
+                }
+                if remaining > left {
+                    remaining -= left;
+                    break;
+                }
+                along += remaining;
+                index = (index + 1) % pattern.len();
+                remaining = pattern[index];
+                pen_down = false;
+            }
+        }
+    }
+    dashed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use blinc_core::{PathCommand, Vec2};
+
+    fn segments(path: &Path) -> Vec<(Point, Point)> {
+        let mut out = Vec::new();
+        let mut current = Point::ZERO;
+        for command in path.commands() {
+            match command {
+                PathCommand::MoveTo(p) => current = *p,
+                PathCommand::LineTo(p) => {
+                    out.push((current, *p));
+                    current = *p;
+                }
+                other => panic!("unexpected {other:?}"),
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn dashes_a_line() {
+        let line = Path::new().move_to(0.0, 0.0).line_to(25.0, 0.0);
+        let dashed = segments(&dash_path(&line, &[5.0, 5.0], 0.0));
+        let xs: Vec<(f32, f32)> = dashed
+            .iter()
+            .map(|(a, b)| (a.x.round(), b.x.round()))
+            .collect();
+        assert_eq!(xs, vec![(0.0, 5.0), (10.0, 15.0), (20.0, 25.0)]);
+    }
+
+    #[test]
+    fn dash_continues_around_corners() {
+        // 8px dash turns the corner at (6, 0)
+        let path = Path::new()
+            .move_to(0.0, 0.0)
+            .line_to(6.0, 0.0)
+            .line_to(6.0, 10.0);
+        let dashed = dash_path(&path, &[8.0, 4.0], 0.0);
+        let first = &dashed.flatten(FLATTEN_STEP)[0];
+        assert_eq!(
+            first,
+            &vec![
+                Point::new(0.0, 0.0),
+                Point::new(6.0, 0.0),
+                Point::new(6.0, 2.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn offset_and_odd_patterns() {
+        let line = Path::new().move_to(0.0, 0.0).line_to(12.0, 0.0);
+        // Offset 3 starts 3px into the first dash
+        let shifted = segments(&dash_path(&line, &[4.0, 4.0], 3.0));
+        assert_eq!((shifted[0].0.x, shifted[0].1.x), (0.0, 1.0));
+        assert_eq!((shifted[1].0.x, shifted[1].1.x), (5.0, 9.0));
+        // [2] means 2 on, 2 off
+        assert_eq!(segments(&dash_path(&line, &[2.0], 0.0)).len(), 3);
+    }
+
+    #[test]
+    fn invalid_pattern_is_solid() {
+        let line = Path::new().move_to(0.0, 0.0).line_to(10.0, 0.0);
+        assert_eq!(dash_path(&line, &[0.0, 0.0], 0.0).commands().len(), 2);
+        assert_eq!(dash_path(&line, &[4.0, -1.0], 0.0).commands().len(), 2);
+    }
+
+    #[test]
+    fn degenerate_patterns_terminate() {
+        let line = Path::new().move_to(0.0, 0.0).line_to(10.0, 0.0);
+        // Zero-length dashes leave only the gaps, which draw nothing
+        assert!(segments(&dash_path(&line, &[0.0, 2.0], 0.0)).is_empty());
+        assert_eq!(segments(&dash_path(&line, &[2.0, 0.0], 0.0)).len(), 5);
+        // A non-finite offset starts the pattern at the beginning
+        assert_eq!(
+            segments(&dash_path(&line, &[4.0, 4.0], f32::NAN)),
+            segments(&dash_path(&line, &[4.0, 4.0], 0.0))
+        );
+        // A vanishingly short pattern draws the path solid
+        let long = Path::new().move_to(0.0, 0.0).line_to(1000.0, 0.0);
+        assert_eq!(dash_path(&long, &[1e-4, 1e-4], 0.0).commands().len(), 2);
+    }
+
+    #[test]
+    fn arc_flattens_onto_circle() {
+        let path = Path::new().move_to(10.0, 0.0).arc_to(
+            Vec2 { x: 10.0, y: 10.0 },
+            0.0,
+            false,
+            true,
+            -10.0,
+            0.0,
+        );
+        let points = &path.flatten(FLATTEN_STEP)[0];
+        assert!(points.len() > 4);
+        for p in points {
+            assert!((p.x.hypot(p.y) - 10.0).abs() < 1e-3, "{p:?}");
+        }
+        // Sweep flag set: clockwise in y-down coordinates, through +y
+        assert!(points.iter().all(|p| p.y >= -1e-3));
+    }
+}
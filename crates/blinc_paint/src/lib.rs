@@ -33,9 +33,11 @@
 //! ```
 
 pub mod context;
+pub mod dash;
 pub mod gradient;
 pub mod path;
 pub mod primitives;
+pub mod text;
 
 // Re-export modules
 pub mod color {
@@ -75,6 +77,8 @@ pub use blinc_core::{
     Size,
     Stroke,
     // Text
+    TextAlign,
+    TextBaseline,
     TextStyle,
     // Transforms
     Transform,
@@ -85,5 +89,7 @@ pub use blinc_core::{
 // ─────────────────────────────────────────────────────────────────────────────
 
 pub use context::PaintContext;
+pub use dash::dash_path;
 pub use path::PathBuilder;
-pub use primitives::{shadow_presets, Circle, Ellipse, RoundedRect};
+pub use primitives::{shadow_presets, ArrowStyle, Circle, Ellipse, RoundedRect};
+pub use text::{EstimatedTextMeasurer, TextMeasurer, TextMetrics};
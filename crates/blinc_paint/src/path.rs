@@ -3,6 +3,8 @@
 //! Core types are re-exported from blinc_core for unified type system.
 //! PathBuilder provides a fluent API for path construction.
 
+use std::f32::consts::{FRAC_PI_2, TAU};
+
 // Re-export core types
 pub use blinc_core::{Path, PathCommand, Point};
 
@@ -41,6 +43,11 @@ impl PathBuilder {
         self
     }
 
+    /// Canvas-style name for [`quad_to`](Self::quad_to)
+    pub fn quadratic_to(self, cx: f32, cy: f32, x: f32, y: f32) -> Self {
+        self.quad_to(cx, cy, x, y)
+    }
+
     pub fn cubic_to(mut self, c1x: f32, c1y: f32, c2x: f32, c2y: f32, x: f32, y: f32) -> Self {
         self.path = self.path.cubic_to(c1x, c1y, c2x, c2y, x, y);
         self.current = Point::new(x, y);
@@ -52,6 +59,154 @@ impl PathBuilder {
         self
     }
 
+    /// Add a circular arc, like canvas `arc()`
+    ///
+    /// Angles are in radians, measured from the +x axis towards +y. A line
+    /// connects the current point to the start of the arc; on an empty path
+    /// the arc starts a new subpath instead. A sweep of 2π or more draws a
+    /// full circle.
+    pub fn arc(
+        self,
+        cx: f32,
+        cy: f32,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        counter_clockwise: bool,
+    ) -> Self {
+        self.ellipse(
+            cx,
+            cy,
+            radius,
+            radius,
+            0.0,
+            start_angle,
+            end_angle,
+            counter_clockwise,
+        )
+    }
+
+    /// Add an elliptical arc, like canvas `ellipse()`
+    ///
+    /// `rotation` rotates the ellipse's axes; angles are measured in the
+    /// ellipse's own (unrotated) frame. Otherwise behaves like [`arc`](Self::arc).
+    #[allow(clippy::too_many_arguments)]
+    pub fn ellipse(
+        mut self,
+        cx: f32,
+        cy: f32,
+        rx: f32,
+        ry: f32,
+        rotation: f32,
+        start_angle: f32,
+        end_angle: f32,
+        counter_clockwise: bool,
+    ) -> Self {
+        // Canvas semantics: the sweep wraps around the 0 angle seam in the
+        // drawing direction, and is capped at one full turn
+        let wrap = |delta: f32| {
+            if delta >= TAU {
+                TAU
+            } else {
+                delta.rem_euclid(TAU)
+            }
+        };
+        let sweep = if counter_clockwise {
+            -wrap(start_angle - end_angle)
+        } else {
+            wrap(end_angle - start_angle)
+        };
+
+        let arc = EllipseArc {
+            center: Point::new(cx, cy),
+            rx,
+            ry,
+            rotation,
+        };
+        let start = arc.point(start_angle);
+        self = if self.path.is_empty() {
+            self.move_to(start.x, start.y)
+        } else {
+            self.line_to(start.x, start.y)
+        };
+        self.append_arc(&arc, start_angle, sweep)
+    }
+
+    /// Add a circular arc tangent to two lines, like canvas `arcTo()`
+    ///
+    /// The lines run from the current point to (`x1`, `y1`) and from there to
+    /// (`x2`, `y2`). The arc is joined to the current point with a straight
+    /// line. Degenerate input (a zero radius or collinear points) draws a line
+    /// to (`x1`, `y1`).
+    pub fn arc_to(self, x1: f32, y1: f32, x2: f32, y2: f32, radius: f32) -> Self {
+        if self.path.is_empty() {
+            return self.move_to(x1, y1);
+        }
+
+        let p0 = self.current;
+        let (v1x, v1y) = (p0.x - x1, p0.y - y1);
+        let (v2x, v2y) = (x2 - x1, y2 - y1);
+        let (len1, len2) = (v1x.hypot(v1y), v2x.hypot(v2y));
+        let cross = v1x * v2y - v1y * v2x;
+        if radius <= 0.0 || len1 == 0.0 || len2 == 0.0 || cross.abs() < f32::EPSILON {
+            return self.line_to(x1, y1);
+        }
+
+        let (u1, u2) = ((v1x / len1, v1y / len1), (v2x / len2, v2y / len2));
+        let angle = (u1.0 * u2.0 + u1.1 * u2.1).clamp(-1.0, 1.0).acos();
+        let tangent = radius / (angle / 2.0).tan();
+        let (bx, by) = (u1.0 + u2.0, u1.1 + u2.1);
+        let bisector = bx.hypot(by);
+        let to_center = radius / (angle / 2.0).sin();
+        let center = Point::new(
+            x1 + bx / bisector * to_center,
+            y1 + by / bisector * to_center,
+        );
+
+        let t1 = Point::new(x1 + u1.0 * tangent, y1 + u1.1 * tangent);
+        let t2 = Point::new(x1 + u2.0 * tangent, y1 + u2.1 * tangent);
+        let start = (t1.y - center.y).atan2(t1.x - center.x);
+        let end = (t2.y - center.y).atan2(t2.x - center.x);
+        // The arc between tangent points always takes the short way round
+        let sweep = (end - start + std::f32::consts::PI).rem_euclid(TAU) - std::f32::consts::PI;
+
+        let arc = EllipseArc {
+            center,
+            rx: radius,
+            ry: radius,
+            rotation: 0.0,
+        };
+        self.line_to(t1.x, t1.y).append_arc(&arc, start, sweep)
+    }
+
+    /// Append cubic segments approximating `sweep` radians of `arc`
+    fn append_arc(mut self, arc: &EllipseArc, start_angle: f32, sweep: f32) -> Self {
+        if sweep == 0.0 {
+            return self;
+        }
+        // Cubics match a circle closely up to a quarter turn per segment
+        let segments = (sweep.abs() / FRAC_PI_2).ceil().max(1.0) as usize;
+        let step = sweep / segments as f32;
+        let k = 4.0 / 3.0 * (step / 4.0).tan();
+
+        let mut angle = start_angle;
+        for _ in 0..segments {
+            let next = angle + step;
+            let (p0, d0) = (arc.point(angle), arc.tangent(angle));
+            let (p1, d1) = (arc.point(next), arc.tangent(next));
+            self = self.cubic_to(
+                p0.x + d0.x * k,
+                p0.y + d0.y * k,
+                p1.x - d1.x * k,
+                p1.y - d1.y * k,
+                p1.x,
+                p1.y,
+            );
+            angle = next;
+        }
+        self
+    }
+
     pub fn build(self) -> Path {
         self.path
     }
@@ -67,3 +222,132 @@ impl Default for PathBuilder {
         Self::new()
     }
 }
+
+/// An ellipse, for sampling points along arcs
+struct EllipseArc {
+    center: Point,
+    rx: f32,
+    ry: f32,
+    rotation: f32,
+}
+
+impl EllipseArc {
+    /// Point on the ellipse at `angle`
+    fn point(&self, angle: f32) -> Point {
+        self.rotate(self.rx * angle.cos(), self.ry * angle.sin(), true)
+    }
+
+    /// Derivative of [`point`](Self::point) with respect to the angle
+    fn tangent(&self, angle: f32) -> Point {
+        self.rotate(-self.rx * angle.sin(), self.ry * angle.cos(), false)
+    }
+
+    fn rotate(&self, x: f32, y: f32, translate: bool) -> Point {
+        let (sin, cos) = self.rotation.sin_cos();
+        let (ox, oy) = if translate {
+            (self.center.x, self.center.y)
+        } else {
+            (0.0, 0.0)
+        };
+        Point::new(ox + x * cos - y * sin, oy + x * sin + y * cos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    /// Sample every cubic in a path at a few points along each curve
+    fn flatten(path: &Path) -> Vec<Point> {
+        let mut points = Vec::new();
+        let mut current = Point::ZERO;
+        for cmd in path.commands() {
+            match cmd {
+                PathCommand::MoveTo(p) | PathCommand::LineTo(p) => {
+                    current = *p;
+                    points.push(*p);
+                }
+                PathCommand::CubicTo {
+                    control1,
+                    control2,
+                    end,
+                } => {
+                    for i in 1..=8 {
+                        let t = i as f32 / 8.0;
+                        let mt = 1.0 - t;
+                        let (a, b, c, d) =
+                            (mt * mt * mt, 3.0 * mt * mt * t, 3.0 * mt * t * t, t * t * t);
+                        points.push(Point::new(
+                            a * current.x + b * control1.x + c * control2.x + d * end.x,
+                            a * current.y + b * control1.y + c * control2.y + d * end.y,
+                        ));
+                    }
+                    current = *end;
+                }
+                _ => {}
+            }
+        }
+        points
+    }
+
+    fn assert_on_circle(points: &[Point], center: Point, radius: f32) {
+        for p in points {
+            let distance = (p.x - center.x).hypot(p.y - center.y);
+            assert!(
+                (distance - radius).abs() < radius * 0.001,
+                "{p:?} is {distance} from center, expected {radius}"
+            );
+        }
+    }
+
+    #[test]
+    fn quarter_arc_lies_on_circle() {
+        let path = PathBuilder::new()
+            .arc(10.0, 20.0, 50.0, 0.0, FRAC_PI_2, false)
+            .build();
+        let points = flatten(&path);
+
+        assert_on_circle(&points, Point::new(10.0, 20.0), 50.0);
+        let end = points.last().unwrap();
+        assert!((end.x - 10.0).abs() < 1e-3 && (end.y - 70.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn full_circle_returns_to_start() {
+        let path = PathBuilder::new()
+            .arc(0.0, 0.0, 10.0, 0.0, TAU, false)
+            .build();
+        let points = flatten(&path);
+
+        assert_on_circle(&points, Point::ZERO, 10.0);
+        let (first, last) = (points[0], *points.last().unwrap());
+        assert!((first.x - last.x).abs() < 1e-3 && (first.y - last.y).abs() < 1e-3);
+    }
+
+    #[test]
+    fn arc_across_seam_takes_short_way() {
+        // 3π/2 -> π/2 clockwise passes through angle 0 (+x), not π
+        let path = PathBuilder::new()
+            .arc(0.0, 0.0, 10.0, 1.5 * PI, 0.5 * PI, false)
+            .build();
+        let points = flatten(&path);
+
+        assert_on_circle(&points, Point::ZERO, 10.0);
+        assert!(points.iter().all(|p| p.x >= -1e-3));
+    }
+
+    #[test]
+    fn arc_to_is_tangent_to_both_lines() {
+        let path = PathBuilder::new()
+            .move_to(0.0, 0.0)
+            .arc_to(100.0, 0.0, 100.0, 100.0, 20.0)
+            .build();
+        let points = flatten(&path);
+
+        // Rounded corner of radius 20 centered at (80, 20)
+        assert_on_circle(&points[2..], Point::new(80.0, 20.0), 20.0);
+        let end = points.last().unwrap();
+        assert!((end.x - 100.0).abs() < 1e-3 && (end.y - 20.0).abs() < 1e-3);
+    }
+}
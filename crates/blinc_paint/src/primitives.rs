@@ -6,6 +6,8 @@
 // Re-export core types
 pub use blinc_core::{CornerRadius, Point, Rect, Shadow, Size};
 
+use blinc_core::{Brush, Stroke};
+
 use crate::Color;
 
 /// A circle
@@ -37,6 +39,53 @@ pub struct Ellipse {
     pub radius_y: f32,
 }
 
+/// How `PaintContext::draw_arrow` draws an arrow
+///
+/// The shaft uses `stroke` as is, dash pattern included; the two head
+/// segments always use it without the dash.
+#[derive(Clone, Debug)]
+pub struct ArrowStyle {
+    /// Stroke for the shaft and head
+    pub stroke: Stroke,
+    /// Brush for the shaft and head
+    pub brush: Brush,
+    /// Length of each head segment in pixels
+    pub head_length: f32,
+    /// Angle between the shaft and each head segment, in radians
+    pub head_angle: f32,
+}
+
+impl ArrowStyle {
+    /// A 1px arrow with a 10px head at 30°
+    pub fn new(brush: impl Into<Brush>) -> Self {
+        Self {
+            stroke: Stroke::new(1.0),
+            brush: brush.into(),
+            head_length: 10.0,
+            head_angle: std::f32::consts::FRAC_PI_6,
+        }
+    }
+
+    /// Set the line width
+    pub fn width(mut self, width: f32) -> Self {
+        self.stroke.width = width;
+        self
+    }
+
+    /// Set the head segment length and angle (radians)
+    pub fn head(mut self, length: f32, angle: f32) -> Self {
+        self.head_length = length;
+        self.head_angle = angle;
+        self
+    }
+
+    /// Dash the shaft
+    pub fn dashed(mut self, pattern: Vec<f32>, offset: f32) -> Self {
+        self.stroke = self.stroke.with_dash(pattern, offset);
+        self
+    }
+}
+
 /// A rounded rectangle (convenience type combining Rect + CornerRadius)
 #[derive(Clone, Copy, Debug, Default)]
 #[repr(C)]
@@ -0,0 +1,90 @@
+//! Text measurement for canvas-style text drawing
+//!
+//! PaintContext resolves `TextAlign` and `TextBaseline` itself, so every
+//! line it emits is a left-aligned, alphabetic-baseline `DrawText` at an
+//! exact position. That needs text dimensions, which come from a
+//! [`TextMeasurer`]. Without a real measurer (one backed by the font
+//! system), widths are estimated from the character count.
+
+use blinc_core::{TextBaseline, TextStyle};
+
+/// Dimensions of a (possibly multi-line) string
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextMetrics {
+    /// Width of the widest line in pixels
+    pub width: f32,
+    /// Height of all line boxes in pixels
+    pub height: f32,
+    /// Distance from baseline to the top of the glyphs
+    pub ascent: f32,
+    /// Distance from baseline to the bottom of the glyphs (positive)
+    pub descent: f32,
+    /// Height of one line box in pixels (font size × line height)
+    pub line_height: f32,
+    /// Width of each line, in order
+    pub line_widths: Vec<f32>,
+}
+
+impl TextMetrics {
+    /// Number of lines
+    pub fn line_count(&self) -> usize {
+        self.line_widths.len()
+    }
+
+    /// Offset from the anchor y to the first line's baseline
+    pub fn first_baseline(&self, baseline: TextBaseline) -> f32 {
+        // Glyphs sit in the middle of each line box, like CSS half-leading
+        let in_box = (self.line_height - (self.ascent + self.descent)) / 2.0 + self.ascent;
+        match baseline {
+            TextBaseline::Top => in_box,
+            TextBaseline::Middle => in_box - self.height / 2.0,
+            TextBaseline::Alphabetic => 0.0,
+            TextBaseline::Bottom => in_box - self.height,
+        }
+    }
+}
+
+/// Measures single lines of text
+///
+/// Implement this with the font system to get exact text placement; the
+/// default [`EstimatedTextMeasurer`] only approximates widths.
+pub trait TextMeasurer: Send + Sync {
+    /// Width of a single line (no newlines) in pixels
+    fn line_width(&self, line: &str, style: &TextStyle) -> f32;
+
+    /// Ascent and descent (both positive) for a style
+    fn vertical_metrics(&self, style: &TextStyle) -> (f32, f32) {
+        (style.size * 0.8, style.size * 0.2)
+    }
+
+    /// Measure a string, splitting it into lines at `\n`
+    fn measure(&self, text: &str, style: &TextStyle) -> TextMetrics {
+        let (ascent, descent) = self.vertical_metrics(style);
+        let line_height = style.size * style.line_height;
+        let line_widths: Vec<f32> = text
+            .split('\n')
+            .map(|line| self.line_width(line, style))
+            .collect();
+
+        TextMetrics {
+            width: line_widths.iter().copied().fold(0.0, f32::max),
+            height: line_height * line_widths.len() as f32,
+            ascent,
+            descent,
+            line_height,
+            line_widths,
+        }
+    }
+}
+
+/// Measurer that estimates widths from the character count
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EstimatedTextMeasurer;
+
+impl TextMeasurer for EstimatedTextMeasurer {
+    fn line_width(&self, line: &str, style: &TextStyle) -> f32 {
+        let chars = line.chars().count() as f32;
+        // ~0.55em per character, same estimate as layout uses
+        chars * style.size * 0.55 + (chars - 1.0).max(0.0) * style.letter_spacing
+    }
+}
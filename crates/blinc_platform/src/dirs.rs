@@ -0,0 +1,30 @@
+//! Per-app directories for saved state
+//!
+//! Resolved from the environment so no platform crate is needed:
+//!
+//! - **Linux/BSD**: `$XDG_CONFIG_HOME`, or `~/.config`
+//! - **macOS**: `~/Library/Application Support`
+//! - **Windows**: `%APPDATA%`
+
+use std::path::PathBuf;
+
+/// The user's config directory, if the environment defines one
+pub fn config_dir() -> Option<PathBuf> {
+    let env_dir = |name: &str| {
+        std::env::var_os(name)
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+    };
+    if cfg!(target_os = "windows") {
+        env_dir("APPDATA")
+    } else if cfg!(target_os = "macos") {
+        Some(env_dir("HOME")?.join("Library/Application Support"))
+    } else {
+        env_dir("XDG_CONFIG_HOME").or_else(|| Some(env_dir("HOME")?.join(".config")))
+    }
+}
+
+/// Directory for one app's saved state: `<config dir>/<app_id>`
+pub fn app_config_dir(app_id: &str) -> Option<PathBuf> {
+    Some(config_dir()?.join(app_id))
+}
@@ -2,6 +2,7 @@
 
 use crate::error::PlatformError;
 use crate::input::InputEvent;
+use crate::power::PowerState;
 use crate::window::Window;
 
 /// Event loop abstraction
@@ -86,6 +87,9 @@ pub enum LifecycleEvent {
     Suspended,
     /// System is low on memory - release caches if possible
     LowMemory,
+    /// Power source or power-saving mode changed (e.g. the charger was
+    /// plugged in)
+    PowerStateChanged(PowerState),
 }
 
 #[cfg(test)]
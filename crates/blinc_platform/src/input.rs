@@ -1,4 +1,4 @@
-//! Input event types for keyboard, mouse, and touch
+//! Input event types for keyboard, mouse, touch, and pen
 
 /// Scroll gesture phase (for trackpad/touchpad scrolling)
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
@@ -23,6 +23,8 @@ pub enum InputEvent {
     Keyboard(KeyboardEvent),
     /// Touch event (mobile/touchscreen)
     Touch(TouchEvent),
+    /// Pen/stylus event (drawing tablets, Apple Pencil)
+    Pen(PenEvent),
     /// Scroll/wheel event
     Scroll {
         /// Horizontal scroll delta
@@ -34,6 +36,8 @@ pub enum InputEvent {
     },
     /// Scroll gesture ended (touchpad momentum finished)
     ScrollEnd,
+    /// Text being composed from dead keys or an input method
+    Composition(CompositionEvent),
 }
 
 // ============================================================================
@@ -98,14 +102,47 @@ pub enum MouseButton {
 /// Keyboard event
 #[derive(Clone, Debug)]
 pub struct KeyboardEvent {
-    /// The key that was pressed or released
+    /// The key that was pressed or released, under the active keyboard layout
     pub key: Key,
+    /// The physical key, independent of the keyboard layout
+    pub code: KeyCode,
+    /// Text the key press produced under the active layout, including AltGr
+    /// combinations and composed dead keys
+    ///
+    /// `None` for releases, keys that don't produce text and dead keys
+    /// themselves (see [`CompositionEvent`]).
+    pub text: Option<String>,
     /// Whether the key was pressed or released
     pub state: KeyState,
     /// Modifier keys held during this event
     pub modifiers: Modifiers,
 }
 
+impl KeyboardEvent {
+    /// Create an event for a key with no known physical code or text
+    pub fn new(key: Key, state: KeyState, modifiers: Modifiers) -> Self {
+        Self {
+            key,
+            code: KeyCode::Unidentified,
+            text: None,
+            state,
+            modifiers,
+        }
+    }
+}
+
+/// Text composition from dead keys or an input method
+///
+/// A dead key (e.g. `´` on a German layout) starts a composition; the next
+/// key commits the composed character (`´` then `e` commits `é`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CompositionEvent {
+    /// The pending composition changed; an empty string cancels it
+    Update(String),
+    /// The composition finished, producing this text
+    Commit(String),
+}
+
 /// Key press/release state
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum KeyState {
@@ -253,10 +290,132 @@ pub enum Key {
     // Character input (for text input)
     Char(char),
 
+    /// Dead key waiting to combine with the next key, with the accent it adds
+    Dead(Option<char>),
+
     // Unknown key
     Unknown,
 }
 
+impl Key {
+    /// Whether this is a modifier key (Shift, Ctrl, Alt or Meta)
+    pub fn is_modifier(&self) -> bool {
+        matches!(self, Key::Shift | Key::Ctrl | Key::Alt | Key::Meta)
+    }
+}
+
+/// Physical key codes
+///
+/// Named after the key at that position on a US QWERTY keyboard, so
+/// `KeyY` is the key right of `KeyT` even on a German layout where it
+/// types "z".
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum KeyCode {
+    // Letter row keys
+    KeyA,
+    KeyB,
+    KeyC,
+    KeyD,
+    KeyE,
+    KeyF,
+    KeyG,
+    KeyH,
+    KeyI,
+    KeyJ,
+    KeyK,
+    KeyL,
+    KeyM,
+    KeyN,
+    KeyO,
+    KeyP,
+    KeyQ,
+    KeyR,
+    KeyS,
+    KeyT,
+    KeyU,
+    KeyV,
+    KeyW,
+    KeyX,
+    KeyY,
+    KeyZ,
+
+    // Number row keys
+    Digit0,
+    Digit1,
+    Digit2,
+    Digit3,
+    Digit4,
+    Digit5,
+    Digit6,
+    Digit7,
+    Digit8,
+    Digit9,
+
+    // Function keys
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+
+    // Special keys
+    Space,
+    Enter,
+    Escape,
+    Backspace,
+    Tab,
+    Delete,
+    Insert,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+
+    // Arrow keys
+    ArrowLeft,
+    ArrowRight,
+    ArrowUp,
+    ArrowDown,
+
+    // Modifier keys
+    ShiftLeft,
+    ShiftRight,
+    ControlLeft,
+    ControlRight,
+    /// Left Alt (Option on macOS)
+    AltLeft,
+    /// Right Alt, which is AltGr on many layouts
+    AltRight,
+    MetaLeft,
+    MetaRight,
+
+    // Punctuation and symbol keys
+    Minus,
+    Equal,
+    BracketLeft,
+    BracketRight,
+    Backslash,
+    Semicolon,
+    Quote,
+    Comma,
+    Period,
+    Slash,
+    Backquote,
+    /// Extra key next to left Shift on ISO keyboards (`<` on German layouts)
+    IntlBackslash,
+
+    /// Key without a known physical code
+    #[default]
+    Unidentified,
+}
+
 // ============================================================================
 // Touch Events
 // ============================================================================
@@ -323,3 +482,74 @@ impl TouchEvent {
         }
     }
 }
+
+// ============================================================================
+// Pen Events
+// ============================================================================
+
+/// Pen contact phase
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PenPhase {
+    /// The pen touched the surface
+    Down,
+    /// The pen moved (touching, or hovering in range)
+    Moved,
+    /// The pen left the surface
+    Up,
+    /// The stroke was cancelled (e.g., by system gesture)
+    Cancelled,
+}
+
+/// Pen/stylus event with pressure and tilt
+///
+/// Platforms without tilt sensing report `tilt_x`/`tilt_y` as 0.0 with a
+/// valid pressure.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PenEvent {
+    /// Contact phase
+    pub phase: PenPhase,
+    /// X position in window coordinates
+    pub x: f32,
+    /// Y position in window coordinates
+    pub y: f32,
+    /// Tip pressure (0.0 - 1.0)
+    pub pressure: f32,
+    /// Tilt toward +x in degrees (-90 to 90, 0 = perpendicular)
+    pub tilt_x: f32,
+    /// Tilt toward +y in degrees (-90 to 90, 0 = perpendicular)
+    pub tilt_y: f32,
+    /// Whether the barrel (side) button is held
+    pub barrel_button: bool,
+    /// Whether the eraser end is in use
+    pub eraser: bool,
+}
+
+impl PenEvent {
+    /// Create a pen event with no tilt and no buttons held
+    pub fn new(phase: PenPhase, x: f32, y: f32, pressure: f32) -> Self {
+        Self {
+            phase,
+            x,
+            y,
+            pressure,
+            tilt_x: 0.0,
+            tilt_y: 0.0,
+            barrel_button: false,
+            eraser: false,
+        }
+    }
+
+    /// Set the tilt in degrees
+    pub fn with_tilt(mut self, tilt_x: f32, tilt_y: f32) -> Self {
+        self.tilt_x = tilt_x;
+        self.tilt_y = tilt_y;
+        self
+    }
+
+    /// Set the barrel button and eraser flags
+    pub fn with_buttons(mut self, barrel_button: bool, eraser: bool) -> Self {
+        self.barrel_button = barrel_button;
+        self.eraser = eraser;
+        self
+    }
+}
@@ -16,6 +16,7 @@
 //! - `blinc_platform_desktop` - Desktop platforms (macOS, Windows, Linux) using winit
 //! - `blinc_platform_android` - Android using NDK
 //! - `blinc_platform_ios` - iOS using UIKit (planned)
+//! - `blinc_platform_web` - Browsers using an HTML canvas (wasm32)
 //!
 //! # Example
 //!
@@ -43,21 +44,26 @@
 //! ```
 
 pub mod assets;
+pub mod dirs;
 mod error;
 mod event;
 mod input;
 mod platform;
+mod power;
 mod window;
+mod window_state;
 
 // Re-export all public types
 pub use error::{PlatformError, Result};
 pub use event::{ControlFlow, Event, EventLoop, LifecycleEvent, WindowEvent};
 pub use input::{
-    InputEvent, Key, KeyState, KeyboardEvent, Modifiers, MouseButton, MouseEvent, ScrollPhase,
-    TouchEvent,
+    CompositionEvent, InputEvent, Key, KeyCode, KeyState, KeyboardEvent, Modifiers, MouseButton,
+    MouseEvent, PenEvent, PenPhase, ScrollPhase, TouchEvent,
 };
 pub use platform::Platform;
-pub use window::{Cursor, Window, WindowConfig};
+pub use power::PowerState;
+pub use window::{Cursor, MonitorInfo, Window, WindowConfig};
+pub use window_state::WindowState;
 
 // Re-export commonly used asset types
 pub use assets::{AssetLoader, AssetPath, FilesystemAssetLoader};
@@ -70,9 +76,11 @@ pub mod prelude {
     pub use crate::error::{PlatformError, Result};
     pub use crate::event::{ControlFlow, Event, EventLoop, LifecycleEvent, WindowEvent};
     pub use crate::input::{
-        InputEvent, Key, KeyState, KeyboardEvent, Modifiers, MouseButton, MouseEvent, ScrollPhase,
-        TouchEvent,
+        CompositionEvent, InputEvent, Key, KeyCode, KeyState, KeyboardEvent, Modifiers,
+        MouseButton, MouseEvent, PenEvent, PenPhase, ScrollPhase, TouchEvent,
     };
     pub use crate::platform::Platform;
-    pub use crate::window::{Cursor, Window, WindowConfig};
+    pub use crate::power::PowerState;
+    pub use crate::window::{Cursor, MonitorInfo, Window, WindowConfig};
+    pub use crate::window_state::WindowState;
 }
@@ -2,7 +2,8 @@
 
 use crate::error::PlatformError;
 use crate::event::EventLoop;
-use crate::window::Window;
+use crate::power::PowerState;
+use crate::window::{MonitorInfo, Window};
 
 /// Platform abstraction trait
 ///
@@ -35,4 +36,20 @@ pub trait Platform: Send + Sync {
     /// This returns the system's default scale factor for DPI scaling.
     /// Individual windows may have different scale factors.
     fn scale_factor(&self) -> f64;
+
+    /// List the connected monitors
+    ///
+    /// Indices into this list are what `WindowConfig::monitor` expects.
+    /// Platforms without multiple displays return an empty list.
+    fn available_monitors(&self) -> Vec<MonitorInfo> {
+        Vec::new()
+    }
+
+    /// Current battery and power-saving state
+    ///
+    /// Platforms without power information report a plugged-in device with
+    /// no battery level.
+    fn power_state(&self) -> PowerState {
+        PowerState::default()
+    }
 }
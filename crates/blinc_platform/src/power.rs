@@ -0,0 +1,134 @@
+//! Battery and power-saving state
+//!
+//! `Platform::power_state()` reports whether the device is running on
+//! battery or in a power-saving mode, so apps (and the windowed runner's
+//! frame pacing) can do less work when energy is scarce.
+
+use std::path::Path;
+
+/// Power source and power-saving state of the device
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PowerState {
+    /// Running on battery (not plugged in)
+    pub on_battery: bool,
+    /// The OS power-saving mode (Low Power Mode, Battery Saver, power-saver
+    /// profile) is on
+    pub low_power_mode: bool,
+    /// Battery charge in `0.0..=1.0`, or None without a battery or when the
+    /// platform can't tell
+    pub level: Option<f32>,
+}
+
+impl PowerState {
+    /// Frame rate cap to run at given the full-power rate
+    ///
+    /// Full rate when plugged in, at most 60fps on battery and at most 30fps
+    /// in power-saving mode.
+    pub fn target_fps(&self, full_fps: u32) -> u32 {
+        if self.low_power_mode {
+            full_fps.min(30)
+        } else if self.on_battery {
+            full_fps.min(60)
+        } else {
+            full_fps
+        }
+    }
+
+    /// Read power state from a Linux-style `power_supply` sysfs directory
+    /// (normally `/sys/class/power_supply`)
+    ///
+    /// Returns None if the directory doesn't exist or lists no supplies.
+    /// `low_power_mode` isn't reported by power supplies and is left false.
+    pub fn from_power_supply_dir(dir: &Path) -> Option<Self> {
+        let read = |path: &Path| std::fs::read_to_string(path).ok();
+        let mut found = false;
+        let mut external_online = false;
+        let mut discharging = false;
+        let (mut energy_now, mut energy_full) = (0.0f32, 0.0f32);
+
+        for entry in std::fs::read_dir(dir).ok()?.flatten() {
+            let supply = entry.path();
+            let Some(kind) = read(&supply.join("type")) else {
+                continue;
+            };
+            found = true;
+            match kind.trim() {
+                "Battery" => {
+                    // Peripheral batteries (mice, headsets) don't power the device
+                    if read(&supply.join("scope")).is_some_and(|s| s.trim() == "Device") {
+                        continue;
+                    }
+                    let status = read(&supply.join("status")).unwrap_or_default();
+                    discharging |= status.trim() == "Discharging";
+                    if let Some(capacity) =
+                        read(&supply.join("capacity")).and_then(|c| c.trim().parse::<f32>().ok())
+                    {
+                        // Weight by a nominal 100 units per battery
+                        energy_now += capacity.clamp(0.0, 100.0);
+                        energy_full += 100.0;
+                    }
+                }
+                _ => {
+                    external_online |=
+                        read(&supply.join("online")).is_some_and(|o| o.trim() == "1");
+                }
+            }
+        }
+
+        found.then(|| PowerState {
+            on_battery: discharging && !external_online,
+            low_power_mode: false,
+            level: (energy_full > 0.0).then(|| energy_now / energy_full),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_supply(dir: &Path, name: &str, files: &[(&str, &str)]) {
+        let supply = dir.join(name);
+        std::fs::create_dir_all(&supply).unwrap();
+        for (file, contents) in files {
+            std::fs::write(supply.join(file), format!("{contents}\n")).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_power_supply_dir() {
+        let dir = std::env::temp_dir().join(format!("blinc_power_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        write_supply(&dir, "AC", &[("type", "Mains"), ("online", "0")]);
+        write_supply(
+            &dir,
+            "BAT0",
+            &[
+                ("type", "Battery"),
+                ("status", "Discharging"),
+                ("capacity", "42"),
+            ],
+        );
+        write_supply(
+            &dir,
+            "hidpp_battery_0",
+            &[("type", "Battery"), ("scope", "Device"), ("capacity", "5")],
+        );
+
+        let state = PowerState::from_power_supply_dir(&dir).unwrap();
+        assert!(state.on_battery);
+        let level = state.level.unwrap();
+        assert!((0.0..=1.0).contains(&level));
+        assert!((level - 0.42).abs() < 1e-6);
+        assert_eq!(state.target_fps(120), 60);
+
+        // Plugging in restores the full rate
+        write_supply(&dir, "AC", &[("online", "1")]);
+        let state = PowerState::from_power_supply_dir(&dir).unwrap();
+        assert!(!state.on_battery);
+        assert_eq!(state.target_fps(120), 120);
+
+        let _ = std::fs::remove_dir_all(&dir);
+        assert_eq!(PowerState::from_power_supply_dir(&dir), None);
+    }
+}
@@ -1,5 +1,9 @@
 //! Window abstraction and configuration
 
+use std::path::PathBuf;
+
+use crate::window_state::WindowState;
+
 /// Window configuration
 #[derive(Clone, Debug)]
 pub struct WindowConfig {
@@ -19,6 +23,22 @@ pub struct WindowConfig {
     pub always_on_top: bool,
     /// Whether to start in fullscreen mode
     pub fullscreen: bool,
+    /// Whether to start maximized
+    pub maximized: bool,
+    /// Index into the platform's monitor list to open the window on
+    ///
+    /// Falls back to the primary monitor if the index no longer exists.
+    pub monitor: Option<usize>,
+    /// Initial window position in logical pixels
+    ///
+    /// Relative to the top-left of the target monitor. When unset, the window
+    /// is centered on the target monitor (or placed by the OS if no monitor is set).
+    pub position: Option<(i32, i32)>,
+    /// File the window's size, position and maximized/fullscreen state are
+    /// saved to on exit (see `remember_state`)
+    pub state_file: Option<PathBuf>,
+    /// State restored from `state_file`, if it had any
+    pub saved_state: Option<WindowState>,
 }
 
 impl Default for WindowConfig {
@@ -32,6 +52,11 @@ impl Default for WindowConfig {
             transparent: false,
             always_on_top: false,
             fullscreen: false,
+            maximized: false,
+            monitor: None,
+            position: None,
+            state_file: None,
+            saved_state: None,
         }
     }
 }
@@ -87,6 +112,124 @@ impl WindowConfig {
         self.fullscreen = fullscreen;
         self
     }
+
+    /// Set whether to start maximized
+    pub fn maximized(mut self, maximized: bool) -> Self {
+        self.maximized = maximized;
+        self
+    }
+
+    /// Open the window on the monitor at `index` (see `Platform::available_monitors`)
+    pub fn monitor(mut self, index: usize) -> Self {
+        self.monitor = Some(index);
+        self
+    }
+
+    /// Set the initial window position in logical pixels
+    pub fn position(mut self, x: i32, y: i32) -> Self {
+        self.position = Some((x, y));
+        self
+    }
+
+    /// Restore the window as it was when `app_id` last exited
+    ///
+    /// Loads the size, position and maximized/fullscreen state saved in the
+    /// user's config directory (overriding what was set so far) and saves
+    /// them again on exit. A saved position that is no longer on any
+    /// monitor is moved onto the primary monitor.
+    pub fn remember_state(self, app_id: &str) -> Self {
+        match WindowState::path(app_id) {
+            Some(path) => self.state_file(path),
+            None => self,
+        }
+    }
+
+    /// Like `remember_state`, but with an explicit state file
+    pub fn state_file(mut self, path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        if let Some(state) = WindowState::load(&path) {
+            if state.width > 0 && state.height > 0 {
+                self.width = state.width;
+                self.height = state.height;
+            }
+            self.maximized = state.maximized;
+            self.fullscreen = state.fullscreen;
+            self.saved_state = Some(state);
+        }
+        self.state_file = Some(path);
+        self
+    }
+
+    /// Pick the monitor this configuration targets
+    ///
+    /// Returns the requested monitor, falling back to the primary (or first)
+    /// monitor when no index is set or the index is out of range.
+    pub fn resolve_monitor<'a>(&self, monitors: &'a [MonitorInfo]) -> Option<&'a MonitorInfo> {
+        self.monitor
+            .and_then(|index| monitors.get(index))
+            .or_else(|| monitors.iter().find(|m| m.is_primary))
+            .or_else(|| monitors.first())
+    }
+
+    /// Compute the initial window position in physical desktop coordinates
+    ///
+    /// Returns `None` when the OS should choose the placement.
+    pub fn initial_position(&self, monitors: &[MonitorInfo]) -> Option<(i32, i32)> {
+        if let Some(position) = self
+            .saved_state
+            .as_ref()
+            .and_then(|state| state.clamp_position(monitors))
+        {
+            return Some(position);
+        }
+        if self.monitor.is_none() && self.position.is_none() {
+            return None;
+        }
+        let Some(monitor) = self.resolve_monitor(monitors) else {
+            return self.position;
+        };
+
+        let scale = monitor.scale_factor;
+        let (mx, my) = monitor.position;
+        let (x, y) = match self.position {
+            Some((x, y)) => (x as f64 * scale, y as f64 * scale),
+            None => {
+                let (mw, mh) = monitor.size;
+                (
+                    (mw as f64 - self.width as f64 * scale).max(0.0) / 2.0,
+                    (mh as f64 - self.height as f64 * scale).max(0.0) / 2.0,
+                )
+            }
+        };
+        Some((mx + x.round() as i32, my + y.round() as i32))
+    }
+}
+
+/// Information about a connected display
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonitorInfo {
+    /// Human-readable monitor name, if the OS provides one
+    pub name: Option<String>,
+    /// Top-left corner in physical desktop coordinates
+    pub position: (i32, i32),
+    /// Size in physical pixels
+    pub size: (u32, u32),
+    /// Display scale factor (DPI scaling)
+    pub scale_factor: f64,
+    /// Refresh rate in Hz, if known
+    pub refresh_rate: Option<f32>,
+    /// Whether this is the primary monitor
+    pub is_primary: bool,
+}
+
+impl MonitorInfo {
+    /// Size in logical pixels
+    pub fn logical_size(&self) -> (f32, f32) {
+        (
+            (self.size.0 as f64 / self.scale_factor) as f32,
+            (self.size.1 as f64 / self.scale_factor) as f32,
+        )
+    }
 }
 
 /// Window abstraction trait
@@ -105,6 +248,34 @@ pub trait Window: Send {
     /// Set the window title
     fn set_title(&self, title: &str);
 
+    /// Enter or leave (borderless) fullscreen
+    ///
+    /// The new size is reported through a `WindowEvent::Resized` event.
+    /// Platforms where windows are always fullscreen ignore this.
+    fn set_fullscreen(&self, _fullscreen: bool) {}
+
+    /// Check if the window is currently fullscreen
+    fn is_fullscreen(&self) -> bool {
+        false
+    }
+
+    /// Check if the window is currently maximized
+    fn is_maximized(&self) -> bool {
+        false
+    }
+
+    /// Outer top-left corner in physical desktop coordinates, if the
+    /// platform exposes window positions
+    fn position(&self) -> Option<(i32, i32)> {
+        None
+    }
+
+    /// Keep the window above all other windows
+    fn set_always_on_top(&self, _always_on_top: bool) {}
+
+    /// Show or hide window decorations (title bar, borders)
+    fn set_decorations(&self, _decorations: bool) {}
+
     /// Set the cursor icon
     fn set_cursor(&self, cursor: Cursor);
 
@@ -153,3 +324,49 @@ pub enum Cursor {
     /// Hidden cursor
     None,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitors() -> Vec<MonitorInfo> {
+        vec![
+            MonitorInfo {
+                name: Some("Primary".to_string()),
+                position: (0, 0),
+                size: (2560, 1440),
+                scale_factor: 2.0,
+                refresh_rate: Some(60.0),
+                is_primary: true,
+            },
+            MonitorInfo {
+                name: Some("Side".to_string()),
+                position: (2560, 0),
+                size: (1920, 1080),
+                scale_factor: 1.0,
+                refresh_rate: Some(144.0),
+                is_primary: false,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_monitor_position_is_relative_to_monitor() {
+        let config = WindowConfig::default().monitor(1).position(10, 20);
+        assert_eq!(config.initial_position(&monitors()), Some((2570, 20)));
+    }
+
+    #[test]
+    fn test_missing_monitor_falls_back_to_primary() {
+        let monitors = monitors();
+        let config = WindowConfig::default().size(800, 600).monitor(5);
+        assert_eq!(config.resolve_monitor(&monitors), Some(&monitors[0]));
+        // Centered on the primary monitor at 2x scale
+        assert_eq!(config.initial_position(&monitors), Some((480, 120)));
+    }
+
+    #[test]
+    fn test_default_placement_is_left_to_os() {
+        assert_eq!(WindowConfig::default().initial_position(&monitors()), None);
+    }
+}
@@ -0,0 +1,196 @@
+//! Saved window geometry
+//!
+//! With `WindowConfig::remember_state(app_id)`, the platform saves the
+//! window's size, position and maximized/fullscreen state when the app
+//! exits and the next launch opens the window the same way.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::window::{MonitorInfo, Window};
+
+/// How much of a restored window (in physical pixels, each axis) must
+/// overlap a monitor for its saved position to be kept
+const MIN_VISIBLE: i32 = 48;
+
+/// Window geometry saved between launches
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WindowState {
+    /// Outer top-left corner in physical desktop coordinates
+    pub position: Option<(i32, i32)>,
+    /// Inner width in logical pixels, when not maximized or fullscreen
+    pub width: u32,
+    /// Inner height in logical pixels, when not maximized or fullscreen
+    pub height: u32,
+    /// Whether the window was maximized
+    pub maximized: bool,
+    /// Whether the window was fullscreen
+    pub fullscreen: bool,
+}
+
+impl WindowState {
+    /// File the state for `app_id` is saved in
+    pub fn path(app_id: &str) -> Option<PathBuf> {
+        Some(crate::dirs::app_config_dir(app_id)?.join("window.json"))
+    }
+
+    /// Load saved state, or `None` if there is none or it can't be read
+    pub fn load(path: &Path) -> Option<Self> {
+        let bytes = std::fs::read(path).ok()?;
+        serde_json::from_slice(&bytes).ok()
+    }
+
+    /// Save this state to `path`, creating its directory if needed
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_vec_pretty(self).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)
+    }
+
+    /// Update from a live window
+    ///
+    /// Size and position are only taken while the window is in its normal
+    /// state, so restoring a maximized window un-maximizes to the old size.
+    pub fn capture(&mut self, window: &dyn Window) {
+        self.maximized = window.is_maximized();
+        self.fullscreen = window.is_fullscreen();
+        if self.maximized || self.fullscreen {
+            return;
+        }
+
+        let (width, height) = window.logical_size();
+        if width >= 1.0 && height >= 1.0 {
+            self.width = width.round() as u32;
+            self.height = height.round() as u32;
+        }
+        if let Some(position) = window.position() {
+            self.position = Some(position);
+        }
+    }
+
+    /// Saved position, moved onto the primary monitor if it's off-screen
+    ///
+    /// A position is kept when the window overlaps some monitor; otherwise
+    /// (e.g. its monitor was disconnected) it is clamped into the primary
+    /// monitor. With no monitor information the position is kept as is.
+    pub fn clamp_position(&self, monitors: &[MonitorInfo]) -> Option<(i32, i32)> {
+        let (x, y) = self.position?;
+        let visible = monitors.iter().any(|m| {
+            let (w, h) = self.physical_size(m);
+            let overlap_x = (x + w).min(m.position.0 + m.size.0 as i32) - x.max(m.position.0);
+            let overlap_y = (y + h).min(m.position.1 + m.size.1 as i32) - y.max(m.position.1);
+            overlap_x >= MIN_VISIBLE.min(w) && overlap_y >= MIN_VISIBLE.min(h)
+        });
+        if visible {
+            return Some((x, y));
+        }
+
+        let Some(primary) = monitors
+            .iter()
+            .find(|m| m.is_primary)
+            .or_else(|| monitors.first())
+        else {
+            return Some((x, y));
+        };
+        let (w, h) = self.physical_size(primary);
+        let (mx, my) = primary.position;
+        let max_x = mx + (primary.size.0 as i32 - w).max(0);
+        let max_y = my + (primary.size.1 as i32 - h).max(0);
+        Some((x.clamp(mx, max_x), y.clamp(my, max_y)))
+    }
+
+    fn physical_size(&self, monitor: &MonitorInfo) -> (i32, i32) {
+        (
+            (self.width as f64 * monitor.scale_factor).round() as i32,
+            (self.height as f64 * monitor.scale_factor).round() as i32,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::window::WindowConfig;
+
+    fn primary() -> MonitorInfo {
+        MonitorInfo {
+            name: Some("Primary".to_string()),
+            position: (0, 0),
+            size: (1920, 1080),
+            scale_factor: 1.0,
+            refresh_rate: Some(60.0),
+            is_primary: true,
+        }
+    }
+
+    fn saved(position: (i32, i32)) -> WindowState {
+        WindowState {
+            position: Some(position),
+            width: 800,
+            height: 600,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_saved_state_is_applied_to_config() {
+        let path = std::env::temp_dir()
+            .join(format!("blinc-window-state-{}", std::process::id()))
+            .join("window.json");
+        WindowState {
+            position: Some((100, 50)),
+            width: 1280,
+            height: 720,
+            maximized: true,
+            fullscreen: false,
+        }
+        .save(&path)
+        .unwrap();
+
+        let config = WindowConfig::default().size(800, 600).state_file(&path);
+        assert_eq!((config.width, config.height), (1280, 720));
+        assert!(config.maximized);
+        assert_eq!(config.initial_position(&[primary()]), Some((100, 50)));
+        assert_eq!(config.state_file.as_deref(), Some(path.as_path()));
+
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_missing_state_keeps_config() {
+        let config = WindowConfig::default()
+            .size(640, 480)
+            .state_file("/nonexistent/blinc/window.json");
+        assert_eq!((config.width, config.height), (640, 480));
+        assert_eq!(config.initial_position(&[primary()]), None);
+    }
+
+    #[test]
+    fn test_visible_position_is_kept() {
+        assert_eq!(
+            saved((300, 200)).clamp_position(&[primary()]),
+            Some((300, 200))
+        );
+        // Hanging mostly off the right edge is still reachable
+        assert_eq!(
+            saved((1800, 200)).clamp_position(&[primary()]),
+            Some((1800, 200))
+        );
+    }
+
+    #[test]
+    fn test_disconnected_monitor_clamps_to_primary() {
+        // Saved on a monitor to the right of the primary that is gone now
+        assert_eq!(
+            saved((2500, 300)).clamp_position(&[primary()]),
+            Some((1120, 300))
+        );
+        assert_eq!(
+            saved((-3000, -900)).clamp_position(&[primary()]),
+            Some((0, 0))
+        );
+    }
+}
@@ -4,11 +4,14 @@
 //! - User interaction events (mouse, keyboard, scroll, etc.)
 //! - Element tree snapshots (for debugging and replay)
 //! - Primitive types (timestamps, points, rectangles)
+//! - Per-frame timings (build, layout and render durations)
 
 mod events;
 mod primitives;
+mod timing;
 mod tree;
 
 pub use events::*;
 pub use primitives::*;
+pub use timing::*;
 pub use tree::*;
@@ -1,6 +1,8 @@
 //! Core primitive types for recording.
 
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 /// A timestamp relative to the recording session start.
@@ -122,34 +124,83 @@ impl From<blinc_core::Rect> for Rect {
     }
 }
 
-/// Clock for generating timestamps relative to session start.
+/// Source of timestamps relative to session start.
+///
+/// The default [`SystemClock`] follows wall-clock time. Inject a
+/// [`ManualClock`] to make captures reproducible.
+pub trait RecordingClock: Send + Sync {
+    /// Get the current timestamp relative to session start.
+    fn now(&self) -> Timestamp;
+
+    /// Restart the clock from zero.
+    fn reset(&mut self);
+}
+
+/// Clock backed by the system's monotonic time.
 #[derive(Debug)]
-pub struct RecordingClock {
+pub struct SystemClock {
     start: Instant,
 }
 
-impl RecordingClock {
+impl SystemClock {
     /// Create a new clock starting now.
     pub fn new() -> Self {
         Self {
             start: Instant::now(),
         }
     }
+}
 
-    /// Get the current timestamp relative to session start.
-    pub fn now(&self) -> Timestamp {
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RecordingClock for SystemClock {
+    fn now(&self) -> Timestamp {
         Timestamp::from_duration(self.start.elapsed())
     }
 
-    /// Reset the clock to start now.
-    pub fn reset(&mut self) {
+    fn reset(&mut self) {
         self.start = Instant::now();
     }
 }
 
-impl Default for RecordingClock {
-    fn default() -> Self {
-        Self::new()
+/// Clock that only advances when told to.
+///
+/// Clones share the same time, so a test can keep one handle to drive the
+/// clock while the session owns another.
+#[derive(Clone, Debug, Default)]
+pub struct ManualClock {
+    micros: Arc<AtomicU64>,
+}
+
+impl ManualClock {
+    /// Create a new clock at time zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move the clock forward.
+    pub fn advance(&self, by: Duration) {
+        self.micros
+            .fetch_add(by.as_micros() as u64, Ordering::SeqCst);
+    }
+
+    /// Set the clock to an exact time.
+    pub fn set(&self, time: Timestamp) {
+        self.micros.store(time.as_micros(), Ordering::SeqCst);
+    }
+}
+
+impl RecordingClock for ManualClock {
+    fn now(&self) -> Timestamp {
+        Timestamp::from_micros(self.micros.load(Ordering::SeqCst))
+    }
+
+    fn reset(&mut self) {
+        self.set(Timestamp::zero());
     }
 }
 
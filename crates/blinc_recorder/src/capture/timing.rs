@@ -0,0 +1,198 @@
+//! Per-frame timing capture.
+//!
+//! A [`FrameTimer`] splits a frame into build, layout and render phases with
+//! one clock read per phase boundary, so it's cheap enough to leave on while
+//! recording. Named spans inside a phase are only kept when the session's
+//! config opts in with `capture_timing_spans`.
+
+use super::Timestamp;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// A phase of a frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FramePhase {
+    /// Running the UI builder.
+    Build,
+    /// Updating the render tree and computing layout.
+    Layout,
+    /// Ticking animations, drawing and presenting.
+    Render,
+}
+
+impl FramePhase {
+    /// Lowercase name, as used by the debug server.
+    pub fn name(&self) -> &'static str {
+        match self {
+            FramePhase::Build => "build",
+            FramePhase::Layout => "layout",
+            FramePhase::Render => "render",
+        }
+    }
+}
+
+/// A named piece of a frame phase.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TimingSpan {
+    /// What ran (e.g. "ui_builder", "present").
+    pub name: String,
+    /// Phase the span belongs to.
+    pub phase: FramePhase,
+    /// How long it took.
+    pub duration: Duration,
+}
+
+/// Timing breakdown of one frame.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FrameTiming {
+    /// Frame number within the session, starting at 0.
+    pub frame: u64,
+    /// When the frame finished.
+    pub timestamp: Timestamp,
+    /// Time spent running the UI builder.
+    pub build: Duration,
+    /// Time spent updating the render tree and computing layout.
+    pub layout: Duration,
+    /// Time spent ticking animations, drawing and presenting.
+    pub render: Duration,
+    /// Events recorded since the previous frame.
+    pub event_count: u32,
+    /// Named spans within the phases, if span capture is enabled.
+    pub spans: Vec<TimingSpan>,
+}
+
+impl FrameTiming {
+    /// Create a timing from phase durations.
+    pub fn new(build: Duration, layout: Duration, render: Duration) -> Self {
+        Self {
+            build,
+            layout,
+            render,
+            ..Default::default()
+        }
+    }
+
+    /// Total time of the three phases.
+    pub fn total(&self) -> Duration {
+        self.build + self.layout + self.render
+    }
+
+    /// Time spent in `phase`.
+    pub fn phase(&self, phase: FramePhase) -> Duration {
+        match phase {
+            FramePhase::Build => self.build,
+            FramePhase::Layout => self.layout,
+            FramePhase::Render => self.render,
+        }
+    }
+
+    fn phase_mut(&mut self, phase: FramePhase) -> &mut Duration {
+        match phase {
+            FramePhase::Build => &mut self.build,
+            FramePhase::Layout => &mut self.layout,
+            FramePhase::Render => &mut self.render,
+        }
+    }
+}
+
+/// Measures the phases of a frame.
+///
+/// Each [`mark`](Self::mark) adds the time since the previous mark (or since
+/// the timer started) to a phase.
+///
+/// ```ignore
+/// let mut timer = FrameTimer::start();
+/// let ui = build_ui();
+/// timer.mark(FramePhase::Build);
+/// tree.compute_layout(width, height);
+/// timer.mark(FramePhase::Layout);
+/// render(&tree);
+/// timer.mark(FramePhase::Render);
+/// blinc_recorder::record_frame_timing(timer.finish());
+/// ```
+#[derive(Debug)]
+pub struct FrameTimer {
+    last: Instant,
+    timing: FrameTiming,
+    spans: bool,
+}
+
+impl FrameTimer {
+    /// Start timing a frame.
+    pub fn start() -> Self {
+        Self {
+            last: Instant::now(),
+            timing: FrameTiming::default(),
+            spans: false,
+        }
+    }
+
+    /// Keep named spans passed to [`span`](Self::span).
+    pub fn with_spans(mut self, spans: bool) -> Self {
+        self.spans = spans;
+        self
+    }
+
+    /// Add the time since the last mark to `phase`.
+    pub fn mark(&mut self, phase: FramePhase) {
+        self.elapsed_into(phase);
+    }
+
+    /// Like [`mark`](Self::mark), also recording the time as a span called
+    /// `name` when spans are kept.
+    pub fn span(&mut self, phase: FramePhase, name: &str) {
+        let duration = self.elapsed_into(phase);
+        if self.spans {
+            self.timing.spans.push(TimingSpan {
+                name: name.to_string(),
+                phase,
+                duration,
+            });
+        }
+    }
+
+    /// Restart the clock without attributing the elapsed time to a phase.
+    pub fn skip(&mut self) {
+        self.last = Instant::now();
+    }
+
+    /// The measured timing, ready for `record_frame_timing`.
+    pub fn finish(self) -> FrameTiming {
+        self.timing
+    }
+
+    fn elapsed_into(&mut self, phase: FramePhase) -> Duration {
+        let now = Instant::now();
+        let duration = now - self.last;
+        self.last = now;
+        *self.timing.phase_mut(phase) += duration;
+        duration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_timer_accumulates_phases() {
+        let mut timer = FrameTimer::start().with_spans(true);
+        std::thread::sleep(Duration::from_millis(2));
+        timer.span(FramePhase::Layout, "tree_update");
+        timer.skip();
+        timer.mark(FramePhase::Layout);
+        let timing = timer.finish();
+
+        assert!(timing.layout >= Duration::from_millis(2));
+        assert_eq!(timing.build, Duration::ZERO);
+        assert_eq!(timing.total(), timing.layout);
+        assert_eq!(timing.spans.len(), 1);
+        assert_eq!(timing.spans[0].name, "tree_update");
+        assert!(timing.spans[0].duration <= timing.layout);
+
+        // Spans are dropped unless kept
+        let mut timer = FrameTimer::start();
+        timer.span(FramePhase::Render, "present");
+        assert!(timer.finish().spans.is_empty());
+    }
+}
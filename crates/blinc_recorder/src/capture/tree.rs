@@ -187,10 +187,25 @@ pub struct ElementDiff {
     pub changes: Vec<PropertyChange>,
 }
 
+impl ElementDiff {
+    /// Check if the element moved or resized.
+    pub fn is_layout_change(&self) -> bool {
+        self.changes
+            .iter()
+            .any(|c| c.category == ChangeCategory::Layout)
+    }
+
+    /// Get the change to a property, if it changed.
+    pub fn change(&self, property: &str) -> Option<&PropertyChange> {
+        self.changes.iter().find(|c| c.property == property)
+    }
+}
+
 /// Categories of element changes.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ChangeCategory {
     /// Visual-only change (color, opacity, etc.).
+    #[default]
     Visual,
     /// Layout change (position, size).
     Layout,
@@ -200,6 +215,19 @@ pub enum ChangeCategory {
     State,
 }
 
+impl ChangeCategory {
+    /// Rank used to pick an element's overall category when several kinds
+    /// of change happen at once (higher wins).
+    fn rank(self) -> u8 {
+        match self {
+            ChangeCategory::Visual => 0,
+            ChangeCategory::State => 1,
+            ChangeCategory::Layout => 2,
+            ChangeCategory::Structural => 3,
+        }
+    }
+}
+
 /// A specific property change.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PropertyChange {
@@ -209,8 +237,36 @@ pub struct PropertyChange {
     pub old_value: Option<String>,
     /// New value (stringified).
     pub new_value: Option<String>,
+    /// Category of this change.
+    #[serde(default)]
+    pub category: ChangeCategory,
+    /// Numeric change for layout properties: `(dx, dy)` for position,
+    /// `(dwidth, dheight)` for size.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delta: Option<(f32, f32)>,
 }
 
+impl PropertyChange {
+    fn new(
+        property: &str,
+        category: ChangeCategory,
+        old_value: impl ToString,
+        new_value: impl ToString,
+    ) -> Self {
+        Self {
+            property: property.to_string(),
+            old_value: Some(old_value.to_string()),
+            new_value: Some(new_value.to_string()),
+            category,
+            delta: None,
+        }
+    }
+}
+
+/// Bounds changes at or below this many pixels are treated as sub-pixel
+/// jitter and not reported.
+pub const LAYOUT_CHANGE_THRESHOLD: f32 = 0.5;
+
 /// Compute the difference between two tree snapshots.
 pub fn diff_trees(old: &TreeSnapshot, new: &TreeSnapshot) -> TreeDiff {
     let mut added = Vec::new();
@@ -252,71 +308,79 @@ pub fn diff_trees(old: &TreeSnapshot, new: &TreeSnapshot) -> TreeDiff {
 /// Compute the difference between two element snapshots.
 fn diff_elements(old: &ElementSnapshot, new: &ElementSnapshot) -> Option<ElementDiff> {
     let mut changes = Vec::new();
-    let mut category = ChangeCategory::Visual;
-
-    // Check bounds changes (layout)
-    if old.bounds != new.bounds {
-        category = ChangeCategory::Layout;
-        if old.bounds.x != new.bounds.x || old.bounds.y != new.bounds.y {
-            changes.push(PropertyChange {
-                property: "position".to_string(),
-                old_value: Some(format!("({}, {})", old.bounds.x, old.bounds.y)),
-                new_value: Some(format!("({}, {})", new.bounds.x, new.bounds.y)),
-            });
-        }
-        if old.bounds.width != new.bounds.width || old.bounds.height != new.bounds.height {
-            changes.push(PropertyChange {
-                property: "size".to_string(),
-                old_value: Some(format!("{}x{}", old.bounds.width, old.bounds.height)),
-                new_value: Some(format!("{}x{}", new.bounds.width, new.bounds.height)),
-            });
-        }
+
+    // Check bounds changes (layout), ignoring sub-pixel jitter
+    let (dx, dy) = (new.bounds.x - old.bounds.x, new.bounds.y - old.bounds.y);
+    if dx.abs() > LAYOUT_CHANGE_THRESHOLD || dy.abs() > LAYOUT_CHANGE_THRESHOLD {
+        changes.push(PropertyChange {
+            delta: Some((dx, dy)),
+            ..PropertyChange::new(
+                "position",
+                ChangeCategory::Layout,
+                format!("({}, {})", old.bounds.x, old.bounds.y),
+                format!("({}, {})", new.bounds.x, new.bounds.y),
+            )
+        });
+    }
+    let (dw, dh) = (
+        new.bounds.width - old.bounds.width,
+        new.bounds.height - old.bounds.height,
+    );
+    if dw.abs() > LAYOUT_CHANGE_THRESHOLD || dh.abs() > LAYOUT_CHANGE_THRESHOLD {
+        changes.push(PropertyChange {
+            delta: Some((dw, dh)),
+            ..PropertyChange::new(
+                "size",
+                ChangeCategory::Layout,
+                format!("{}x{}", old.bounds.width, old.bounds.height),
+                format!("{}x{}", new.bounds.width, new.bounds.height),
+            )
+        });
     }
 
     // Check visibility
     if old.is_visible != new.is_visible {
-        category = ChangeCategory::Visual;
-        changes.push(PropertyChange {
-            property: "visible".to_string(),
-            old_value: Some(old.is_visible.to_string()),
-            new_value: Some(new.is_visible.to_string()),
-        });
+        changes.push(PropertyChange::new(
+            "visible",
+            ChangeCategory::Visual,
+            old.is_visible,
+            new.is_visible,
+        ));
     }
 
     // Check focus/hover state
-    if old.is_focused != new.is_focused || old.is_hovered != new.is_hovered {
-        category = ChangeCategory::State;
-        if old.is_focused != new.is_focused {
-            changes.push(PropertyChange {
-                property: "focused".to_string(),
-                old_value: Some(old.is_focused.to_string()),
-                new_value: Some(new.is_focused.to_string()),
-            });
-        }
-        if old.is_hovered != new.is_hovered {
-            changes.push(PropertyChange {
-                property: "hovered".to_string(),
-                old_value: Some(old.is_hovered.to_string()),
-                new_value: Some(new.is_hovered.to_string()),
-            });
-        }
+    if old.is_focused != new.is_focused {
+        changes.push(PropertyChange::new(
+            "focused",
+            ChangeCategory::State,
+            old.is_focused,
+            new.is_focused,
+        ));
+    }
+    if old.is_hovered != new.is_hovered {
+        changes.push(PropertyChange::new(
+            "hovered",
+            ChangeCategory::State,
+            old.is_hovered,
+            new.is_hovered,
+        ));
     }
 
     // Check children (structural)
     if old.children != new.children {
-        category = ChangeCategory::Structural;
-        changes.push(PropertyChange {
-            property: "children".to_string(),
-            old_value: Some(format!("{} children", old.children.len())),
-            new_value: Some(format!("{} children", new.children.len())),
-        });
+        changes.push(PropertyChange::new(
+            "children",
+            ChangeCategory::Structural,
+            format!("{} children", old.children.len()),
+            format!("{} children", new.children.len()),
+        ));
     }
 
-    if changes.is_empty() {
-        None
-    } else {
-        Some(ElementDiff { category, changes })
-    }
+    let category = changes
+        .iter()
+        .map(|c| c.category)
+        .max_by_key(|c| c.rank())?;
+    Some(ElementDiff { category, changes })
 }
 
 #[cfg(test)]
@@ -358,4 +422,40 @@ mod tests {
         assert!(diff.added.is_empty());
         assert_eq!(diff.removed, vec!["old-elem".to_string()]);
     }
+
+    fn snapshot_with(id: &str, bounds: Rect) -> TreeSnapshot {
+        let mut snapshot = TreeSnapshot::new(Timestamp::zero(), (800, 600), 1.0);
+        snapshot.elements.insert(
+            id.to_string(),
+            ElementSnapshot::new(id.to_string(), "Div".to_string(), bounds),
+        );
+        snapshot
+    }
+
+    #[test]
+    fn test_tree_diff_reports_layout_delta() {
+        let old = snapshot_with("box", Rect::new(10.0, 20.0, 100.0, 50.0));
+        let mut new = snapshot_with("box", Rect::new(20.0, 20.0, 100.0, 50.0));
+        // Hover changing at the same time doesn't hide the move
+        new.elements.get_mut("box").unwrap().is_hovered = true;
+
+        let diff = diff_trees(&old, &new);
+        let elem = &diff.modified["box"];
+        assert_eq!(elem.category, ChangeCategory::Layout);
+        assert!(elem.is_layout_change());
+
+        let position = elem.change("position").unwrap();
+        assert_eq!(position.category, ChangeCategory::Layout);
+        assert_eq!(position.delta, Some((10.0, 0.0)));
+        assert!(elem.change("size").is_none());
+    }
+
+    #[test]
+    fn test_tree_diff_ignores_subpixel_jitter() {
+        let old = snapshot_with("box", Rect::new(10.0, 20.0, 100.0, 50.0));
+        let new = snapshot_with("box", Rect::new(10.3, 19.8, 100.2, 50.0));
+
+        let diff = diff_trees(&old, &new);
+        assert!(diff.is_empty());
+    }
 }
@@ -4,6 +4,7 @@
 //! - Event recording for user interactions
 //! - Tree snapshot capture for debugging UI state
 //! - Session management with start/pause/stop lifecycle
+//! - Per-frame build/layout/render timings
 //!
 //! # Quick Start
 //!
@@ -32,10 +33,11 @@ pub mod session;
 pub mod testing;
 
 pub use capture::{
-    ChangeCategory, CustomEvent, ElementDiff, ElementSnapshot, FocusChangeEvent, HoverEvent, Key,
-    KeyEvent, Modifiers, MouseButton, MouseEvent, MouseMoveEvent, Point, PropertyChange,
-    RecordedEvent, RecordingClock, Rect, ScrollEvent, TextInputEvent, Timestamp, TimestampedEvent,
-    TreeDiff, TreeSnapshot, VisualProps, WindowResizeEvent,
+    ChangeCategory, CustomEvent, ElementDiff, ElementSnapshot, FocusChangeEvent, FramePhase,
+    FrameTimer, FrameTiming, HoverEvent, Key, KeyEvent, ManualClock, Modifiers, MouseButton,
+    MouseEvent, MouseMoveEvent, Point, PropertyChange, RecordedEvent, RecordingClock, Rect,
+    ScrollEvent, SystemClock, TextInputEvent, Timestamp, TimestampedEvent, TimingSpan, TreeDiff,
+    TreeSnapshot, VisualProps, WindowResizeEvent,
 };
 pub use replay::{
     EventSimulator, FrameUpdate, ReplayConfig, ReplayPlayer, ReplayState, SimulatedInput,
@@ -43,7 +45,7 @@ pub use replay::{
 };
 pub use server::{
     start_local_server, start_local_server_named, ClientCommand, DebugServer, DebugServerConfig,
-    ServerHandle, ServerMessage,
+    ReloadClient, ServerHandle, ServerMessage, DEV_SOCKET_ENV,
 };
 pub use session::{
     RecordingConfig, RecordingExport, RecordingSession, SessionState, SessionStats,
@@ -101,6 +103,22 @@ pub fn record_snapshot(snapshot: TreeSnapshot) -> Option<TreeDiff> {
     get_recorder().and_then(|r| r.record_snapshot(snapshot))
 }
 
+/// Start timing a frame if a recorder is installed and recording.
+///
+/// The timer keeps named spans only if the session's config asks for them.
+pub fn start_frame_timer() -> Option<FrameTimer> {
+    let recorder = get_recorder().filter(|r| r.is_recording())?;
+    let spans = recorder.with_session(|s| s.config().capture_timing_spans);
+    Some(FrameTimer::start().with_spans(spans))
+}
+
+/// Record a frame timing if a recorder is installed and recording.
+pub fn record_frame_timing(timing: FrameTiming) {
+    if let Some(recorder) = get_recorder() {
+        recorder.record_frame_timing(timing);
+    }
+}
+
 /// Callback types for integration with BlincContextState.
 pub mod callbacks {
     use super::*;
@@ -470,6 +470,7 @@ mod tests {
             config: RecordingConfig::minimal(),
             events,
             snapshots: Vec::new(),
+            timings: Vec::new(),
             stats: Default::default(),
         }
     }
@@ -3,12 +3,13 @@
 //! Provides a cross-platform server that listens for debugger connections
 //! and streams recording data in real-time.
 
-use crate::{RecordingExport, SharedRecordingSession};
+use crate::{FrameTiming, RecordingExport, SharedRecordingSession};
 use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 use std::io::{self, Read, Write};
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
 use std::sync::Arc;
 use std::thread::{self, JoinHandle};
 
@@ -60,11 +61,15 @@ impl DebugServerConfig {
     }
 }
 
+/// Outgoing message queues for connected clients.
+type ClientQueues = Arc<Mutex<Vec<Sender<ServerMessage>>>>;
+
 /// Handle to a running debug server.
 pub struct ServerHandle {
     shutdown: Arc<AtomicBool>,
     thread: Option<JoinHandle<()>>,
     socket_path: PathBuf,
+    clients: ClientQueues,
 }
 
 impl ServerHandle {
@@ -78,6 +83,21 @@ impl ServerHandle {
         &self.socket_path
     }
 
+    /// Push a message to every connected client.
+    ///
+    /// Returns the number of clients the message was queued for.
+    pub fn broadcast(&self, message: ServerMessage) -> usize {
+        let mut clients = self.clients.lock();
+        // Drop queues whose client thread has exited
+        clients.retain(|tx| tx.send(message.clone()).is_ok());
+        clients.len()
+    }
+
+    /// Number of currently connected clients.
+    pub fn client_count(&self) -> usize {
+        self.clients.lock().len()
+    }
+
     /// Check if the server is still running.
     pub fn is_running(&self) -> bool {
         self.thread
@@ -109,14 +129,7 @@ impl Drop for ServerHandle {
 pub struct DebugServer {
     config: DebugServerConfig,
     session: Arc<SharedRecordingSession>,
-    clients: Arc<Mutex<Vec<ClientConnection>>>,
-}
-
-struct ClientConnection {
-    #[cfg(unix)]
-    stream: std::os::unix::net::UnixStream,
-    #[cfg(windows)]
-    stream: std::net::TcpStream, // Fallback for Windows (TODO: named pipes)
+    clients: ClientQueues,
 }
 
 impl DebugServer {
@@ -149,6 +162,7 @@ impl DebugServer {
         let shutdown = Arc::new(AtomicBool::new(false));
         let shutdown_clone = shutdown.clone();
         let socket_path_clone = socket_path.clone();
+        let clients = self.clients.clone();
 
         let thread = thread::spawn(move || {
             if let Err(e) = self.run_server(&socket_path_clone, shutdown_clone) {
@@ -160,9 +174,17 @@ impl DebugServer {
             shutdown,
             thread: Some(thread),
             socket_path,
+            clients,
         })
     }
 
+    /// Create the outgoing queue for a newly connected client.
+    fn register_client(&self) -> Receiver<ServerMessage> {
+        let (tx, rx) = mpsc::channel();
+        self.clients.lock().push(tx);
+        rx
+    }
+
     #[cfg(unix)]
     fn run_server(&self, socket_path: &PathBuf, shutdown: Arc<AtomicBool>) -> io::Result<()> {
         use std::os::unix::net::UnixListener;
@@ -184,8 +206,9 @@ impl DebugServer {
 
                     // Handle client in a new thread
                     let session = self.session.clone();
+                    let outbox = self.register_client();
                     thread::spawn(move || {
-                        if let Err(e) = handle_client(stream, session) {
+                        if let Err(e) = handle_client(stream, session, outbox) {
                             tracing::debug!("Client disconnected: {}", e);
                         }
                     });
@@ -226,8 +249,9 @@ impl DebugServer {
                     }
 
                     let session = self.session.clone();
+                    let outbox = self.register_client();
                     thread::spawn(move || {
-                        if let Err(e) = handle_client_tcp(stream, session) {
+                        if let Err(e) = handle_client_tcp(stream, session, outbox) {
                             tracing::debug!("Client disconnected: {}", e);
                         }
                     });
@@ -333,6 +357,10 @@ pub enum ServerMessage {
     Error { message: String },
     /// Ping response (pong).
     Pong,
+    /// Watched asset files changed on disk (dev mode hot-reload).
+    AssetsChanged { paths: Vec<String> },
+    /// Frames recorded since the last update, oldest first.
+    FrameTimings(Vec<FrameTiming>),
 }
 
 impl ServerMessage {
@@ -382,6 +410,23 @@ impl ServerMessage {
                 format!(r#"{{"type":"error","message":"{}"}}"#, message)
             }
             ServerMessage::Pong => r#"{"type":"pong"}"#.to_string(),
+            ServerMessage::AssetsChanged { paths } => {
+                let paths: Vec<String> = paths
+                    .iter()
+                    .map(|p| format!("\"{}\"", json_escape(p)))
+                    .collect();
+                format!(
+                    r#"{{"type":"assets_changed","paths":[{}]}}"#,
+                    paths.join(",")
+                )
+            }
+            ServerMessage::FrameTimings(timings) => {
+                let frames: Vec<String> = timings.iter().map(frame_timing_json).collect();
+                format!(
+                    r#"{{"type":"frame_timings","frames":[{}]}}"#,
+                    frames.join(",")
+                )
+            }
         };
 
         let bytes = json.as_bytes();
@@ -393,6 +438,83 @@ impl ServerMessage {
     }
 }
 
+/// Serialize a frame timing for the `frame_timings` message (durations in µs).
+fn frame_timing_json(timing: &FrameTiming) -> String {
+    let spans: Vec<String> = timing
+        .spans
+        .iter()
+        .map(|span| {
+            format!(
+                r#"{{"name":"{}","phase":"{}","us":{}}}"#,
+                json_escape(&span.name),
+                span.phase.name(),
+                span.duration.as_micros()
+            )
+        })
+        .collect();
+    format!(
+        r#"{{"frame":{},"timestamp_us":{},"build_us":{},"layout_us":{},"render_us":{},"total_us":{},"events":{},"spans":[{}]}}"#,
+        timing.frame,
+        timing.timestamp.as_micros(),
+        timing.build.as_micros(),
+        timing.layout.as_micros(),
+        timing.render.as_micros(),
+        timing.total().as_micros(),
+        timing.event_count,
+        spans.join(",")
+    )
+}
+
+/// Escape a string for embedding in a JSON string literal.
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Write any messages queued for this client via `ServerHandle::broadcast`.
+fn flush_outbox(stream: &mut impl Write, outbox: &Receiver<ServerMessage>) -> io::Result<()> {
+    while let Ok(message) = outbox.try_recv() {
+        stream.write_all(&message.to_bytes())?;
+    }
+    Ok(())
+}
+
+/// Stream frame timings recorded since the last call to this client.
+///
+/// `next_frame` is the first frame number not yet sent.
+fn flush_frame_timings(
+    stream: &mut impl Write,
+    session: &SharedRecordingSession,
+    next_frame: &mut u64,
+) -> io::Result<()> {
+    // A reset session numbers frames from 0 again
+    let total = session.stats().total_frames;
+    if total < *next_frame {
+        *next_frame = 0;
+    }
+    if total == *next_frame {
+        return Ok(());
+    }
+
+    let timings = session.frame_timings_since(*next_frame);
+    *next_frame = total;
+    if timings.is_empty() {
+        return Ok(());
+    }
+    stream.write_all(&ServerMessage::FrameTimings(timings).to_bytes())
+}
+
 /// Process a client command and return a response message.
 fn handle_command(cmd: ClientCommand, session: &Arc<SharedRecordingSession>) -> ServerMessage {
     match cmd {
@@ -441,6 +563,7 @@ fn handle_command(cmd: ClientCommand, session: &Arc<SharedRecordingSession>) ->
 fn handle_client(
     mut stream: std::os::unix::net::UnixStream,
     session: Arc<SharedRecordingSession>,
+    outbox: Receiver<ServerMessage>,
 ) -> io::Result<()> {
     use std::time::Duration;
 
@@ -457,6 +580,8 @@ fn handle_client(
     // Track last state to avoid sending redundant updates
     let mut last_recording = session.is_recording();
     let mut last_paused = session.is_paused();
+    // Only stream frames recorded after the client connected
+    let mut next_frame = session.stats().total_frames;
 
     // Main loop: respond to commands and send state updates
     let mut buf = [0u8; 1024];
@@ -482,6 +607,12 @@ fn handle_client(
             }
         }
 
+        // Push broadcast messages
+        flush_outbox(&mut stream, &outbox)?;
+
+        // Stream new frame timings for live profiling
+        flush_frame_timings(&mut stream, &session, &mut next_frame)?;
+
         // Send state change if state has changed
         let is_recording = session.is_recording();
         let is_paused = session.is_paused();
@@ -503,6 +634,7 @@ fn handle_client(
 fn handle_client_tcp(
     mut stream: std::net::TcpStream,
     session: Arc<SharedRecordingSession>,
+    outbox: Receiver<ServerMessage>,
 ) -> io::Result<()> {
     use std::time::Duration;
 
@@ -518,6 +650,8 @@ fn handle_client_tcp(
     // Track last state to avoid sending redundant updates
     let mut last_recording = session.is_recording();
     let mut last_paused = session.is_paused();
+    // Only stream frames recorded after the client connected
+    let mut next_frame = session.stats().total_frames;
 
     let mut buf = [0u8; 1024];
     loop {
@@ -534,6 +668,12 @@ fn handle_client_tcp(
             Err(e) => return Err(e),
         }
 
+        // Push broadcast messages
+        flush_outbox(&mut stream, &outbox)?;
+
+        // Stream new frame timings for live profiling
+        flush_frame_timings(&mut stream, &session, &mut next_frame)?;
+
         // Send state change if state has changed
         let is_recording = session.is_recording();
         let is_paused = session.is_paused();
@@ -648,4 +788,90 @@ mod tests {
             Some(ClientCommand::Start)
         ));
     }
+
+    #[test]
+    fn test_assets_changed_serialization() {
+        let msg = ServerMessage::AssetsChanged {
+            paths: vec![
+                "assets/logo.png".to_string(),
+                "C:\\theme \"dark\".css".to_string(),
+            ],
+        };
+        let bytes = msg.to_bytes();
+        let json = std::str::from_utf8(&bytes[4..]).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"assets_changed","paths":["assets/logo.png","C:\\theme \"dark\".css"]}"#
+        );
+    }
+
+    #[test]
+    fn test_frame_timings_serialization() {
+        use crate::{FramePhase, Timestamp, TimingSpan};
+        use std::time::Duration;
+
+        let mut timing = FrameTiming::new(
+            Duration::from_micros(1200),
+            Duration::from_micros(800),
+            Duration::from_micros(3000),
+        );
+        timing.frame = 7;
+        timing.timestamp = Timestamp::from_micros(50_000);
+        timing.event_count = 2;
+        timing.spans.push(TimingSpan {
+            name: "present".to_string(),
+            phase: FramePhase::Render,
+            duration: Duration::from_micros(500),
+        });
+        let bytes = ServerMessage::FrameTimings(vec![timing]).to_bytes();
+        let json = std::str::from_utf8(&bytes[4..]).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"frame_timings","frames":[{"frame":7,"timestamp_us":50000,"build_us":1200,"layout_us":800,"render_us":3000,"total_us":5000,"events":2,"spans":[{"name":"present","phase":"render","us":500}]}]}"#
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_broadcast_reaches_connected_client() {
+        use crate::RecordingConfig;
+        use std::os::unix::net::UnixStream;
+        use std::time::{Duration, Instant};
+
+        let mut config = DebugServerConfig::new(format!("test_broadcast_{}", std::process::id()));
+        config.auto_start_recording = false;
+        let session = Arc::new(SharedRecordingSession::new(RecordingConfig::minimal()));
+        let handle = DebugServer::new(config, session).start().unwrap();
+
+        // The listener binds on the server thread; retry until it's up
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let mut stream = loop {
+            match UnixStream::connect(handle.socket_path()) {
+                Ok(stream) => break stream,
+                Err(_) if Instant::now() < deadline => thread::sleep(Duration::from_millis(20)),
+                Err(e) => panic!("failed to connect: {}", e),
+            }
+        };
+        while handle.client_count() == 0 {
+            assert!(Instant::now() < deadline, "client never registered");
+            thread::sleep(Duration::from_millis(20));
+        }
+
+        let sent = handle.broadcast(ServerMessage::AssetsChanged {
+            paths: vec!["assets/logo.png".to_string()],
+        });
+        assert_eq!(sent, 1);
+
+        stream
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        let mut received = Vec::new();
+        let mut buf = [0u8; 1024];
+        while !String::from_utf8_lossy(&received).contains("assets_changed") {
+            let n = stream.read(&mut buf).unwrap();
+            assert!(n > 0, "server closed the connection");
+            received.extend_from_slice(&buf[..n]);
+        }
+        assert!(String::from_utf8_lossy(&received).contains("assets/logo.png"));
+    }
 }
@@ -8,8 +8,10 @@
 //! - Windows: Named pipes at `\\.\pipe\blinc\{app_name}`
 
 mod local;
+mod reload;
 
 pub use local::{
     start_local_server, start_local_server_named, ClientCommand, DebugServer, DebugServerConfig,
     ServerHandle, ServerMessage,
 };
+pub use reload::{ReloadClient, DEV_SOCKET_ENV};
@@ -0,0 +1,128 @@
+//! Client side of dev-mode hot-reload.
+//!
+//! `blinc dev` runs a debug server and broadcasts `assets_changed` messages
+//! when watched files change. A running app connects with [`ReloadClient`]
+//! (the socket path is passed in the `BLINC_DEV_SOCKET` environment variable)
+//! and polls it once per frame.
+
+use std::io;
+use std::path::Path;
+
+/// Environment variable `blinc dev` uses to pass the server socket path.
+pub const DEV_SOCKET_ENV: &str = "BLINC_DEV_SOCKET";
+
+/// Non-blocking connection to a `blinc dev` server.
+pub struct ReloadClient {
+    #[cfg(unix)]
+    stream: std::os::unix::net::UnixStream,
+    buffer: Vec<u8>,
+}
+
+impl ReloadClient {
+    /// Connect using the socket path from `BLINC_DEV_SOCKET`, if set.
+    pub fn from_env() -> Option<io::Result<Self>> {
+        let path = std::env::var_os(DEV_SOCKET_ENV)?;
+        Some(Self::connect(Path::new(&path)))
+    }
+
+    /// Connect to the dev server at `path`.
+    #[cfg(unix)]
+    pub fn connect(path: &Path) -> io::Result<Self> {
+        let stream = std::os::unix::net::UnixStream::connect(path)?;
+        stream.set_nonblocking(true)?;
+        Ok(Self {
+            stream,
+            buffer: Vec::new(),
+        })
+    }
+
+    /// Connect to the dev server at `path`.
+    #[cfg(not(unix))]
+    pub fn connect(_path: &Path) -> io::Result<Self> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "hot-reload is only supported on unix platforms",
+        ))
+    }
+
+    /// Read pending messages and return the asset paths that changed.
+    ///
+    /// Never blocks; returns an empty list when nothing has changed.
+    pub fn poll(&mut self) -> Vec<String> {
+        self.read_available();
+
+        let mut changed = Vec::new();
+        while self.buffer.len() >= 4 {
+            let len = u32::from_le_bytes([
+                self.buffer[0],
+                self.buffer[1],
+                self.buffer[2],
+                self.buffer[3],
+            ]) as usize;
+            if self.buffer.len() < 4 + len {
+                break;
+            }
+            let frame: Vec<u8> = self.buffer.drain(..4 + len).skip(4).collect();
+            changed.extend(parse_assets_changed(&frame));
+        }
+        changed
+    }
+
+    #[cfg(unix)]
+    fn read_available(&mut self) {
+        use std::io::Read;
+
+        let mut buf = [0u8; 4096];
+        loop {
+            match self.stream.read(&mut buf) {
+                Ok(0) => break,
+                Ok(n) => self.buffer.extend_from_slice(&buf[..n]),
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                // WouldBlock (nothing pending) or a dropped server
+                Err(_) => break,
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn read_available(&mut self) {}
+}
+
+/// Extract the paths from an `assets_changed` message; other messages yield none.
+fn parse_assets_changed(frame: &[u8]) -> Vec<String> {
+    let Ok(value) = serde_json::from_slice::<serde_json::Value>(frame) else {
+        return Vec::new();
+    };
+    if value.get("type").and_then(|t| t.as_str()) != Some("assets_changed") {
+        return Vec::new();
+    }
+    value
+        .get("paths")
+        .and_then(|p| p.as_array())
+        .map(|paths| {
+            paths
+                .iter()
+                .filter_map(|p| p.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ServerMessage;
+
+    #[test]
+    fn test_parse_assets_changed() {
+        let bytes = ServerMessage::AssetsChanged {
+            paths: vec!["assets/a \"b\".svg".to_string()],
+        }
+        .to_bytes();
+        assert_eq!(
+            parse_assets_changed(&bytes[4..]),
+            vec!["assets/a \"b\".svg"]
+        );
+        assert!(parse_assets_changed(&ServerMessage::Pong.to_bytes()[4..]).is_empty());
+    }
+}
@@ -19,10 +19,20 @@ pub struct RecordingConfig {
     pub capture_visual_props: bool,
     /// Whether to capture text content in snapshots.
     pub capture_text_content: bool,
+    /// Maximum number of frame timings to store.
+    #[serde(default = "default_max_frame_timings")]
+    pub max_frame_timings: usize,
+    /// Whether to keep named spans within frame phases (more overhead).
+    #[serde(default)]
+    pub capture_timing_spans: bool,
     /// Application name (for debug server identification).
     pub app_name: String,
 }
 
+fn default_max_frame_timings() -> usize {
+    1_000
+}
+
 impl Default for RecordingConfig {
     fn default() -> Self {
         Self::standard()
@@ -40,6 +50,8 @@ impl RecordingConfig {
             capture_every_frame: false,
             capture_visual_props: false,
             capture_text_content: false,
+            max_frame_timings: 1_000,
+            capture_timing_spans: false,
             app_name: "blinc_app".to_string(),
         }
     }
@@ -54,6 +66,8 @@ impl RecordingConfig {
             capture_every_frame: true,
             capture_visual_props: true,
             capture_text_content: true,
+            max_frame_timings: 5_000,
+            capture_timing_spans: false,
             app_name: "blinc_app".to_string(),
         }
     }
@@ -68,6 +82,8 @@ impl RecordingConfig {
             capture_every_frame: false,
             capture_visual_props: false,
             capture_text_content: false,
+            max_frame_timings: 100,
+            capture_timing_spans: false,
             app_name: "blinc_app".to_string(),
         }
     }
@@ -82,6 +98,8 @@ impl RecordingConfig {
             capture_every_frame: true,
             capture_visual_props: true,
             capture_text_content: true,
+            max_frame_timings: 10_000,
+            capture_timing_spans: false,
             app_name: "blinc_test".to_string(),
         }
     }
@@ -121,4 +139,16 @@ impl RecordingConfig {
         self.capture_visual_props = capture;
         self
     }
+
+    /// Set the maximum number of frame timings.
+    pub fn with_max_frame_timings(mut self, max: usize) -> Self {
+        self.max_frame_timings = max;
+        self
+    }
+
+    /// Enable or disable named spans in frame timings.
+    pub fn with_timing_spans(mut self, capture: bool) -> Self {
+        self.capture_timing_spans = capture;
+        self
+    }
 }
@@ -2,7 +2,8 @@
 
 use super::config::RecordingConfig;
 use crate::capture::{
-    RecordedEvent, RecordingClock, Timestamp, TimestampedEvent, TreeDiff, TreeSnapshot,
+    FrameTiming, RecordedEvent, RecordingClock, SystemClock, Timestamp, TimestampedEvent, TreeDiff,
+    TreeSnapshot,
 };
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
@@ -28,17 +29,21 @@ pub struct RecordingSession {
     /// Current session state.
     state: SessionState,
     /// Clock for timestamps.
-    clock: RecordingClock,
+    clock: Box<dyn RecordingClock>,
     /// Ring buffer of recorded events.
     events: VecDeque<TimestampedEvent>,
     /// Ring buffer of tree snapshots.
     snapshots: VecDeque<TreeSnapshot>,
     /// Last snapshot for diff computation.
     last_snapshot: Option<TreeSnapshot>,
+    /// Ring buffer of frame timings.
+    frame_timings: VecDeque<FrameTiming>,
+    /// `stats.total_events` when the last frame timing was recorded.
+    events_at_last_frame: u64,
     /// Accumulated pause duration (for accurate timestamps).
     pause_duration: std::time::Duration,
-    /// When the current pause started (if paused).
-    pause_start: Option<std::time::Instant>,
+    /// Clock time when the current pause started (if paused).
+    pause_start: Option<Timestamp>,
     /// Statistics.
     stats: SessionStats,
 }
@@ -58,18 +63,31 @@ pub struct SessionStats {
     pub last_event_time: Option<Timestamp>,
     /// Last snapshot timestamp.
     pub last_snapshot_time: Option<Timestamp>,
+    /// Total frame timings recorded.
+    #[serde(default)]
+    pub total_frames: u64,
+    /// Frame timings dropped due to buffer overflow.
+    #[serde(default)]
+    pub frames_dropped: u64,
 }
 
 impl RecordingSession {
     /// Create a new recording session with the given configuration.
     pub fn new(config: RecordingConfig) -> Self {
+        Self::with_clock(config, SystemClock::new())
+    }
+
+    /// Create a new recording session that takes its timestamps from `clock`.
+    pub fn with_clock(config: RecordingConfig, clock: impl RecordingClock + 'static) -> Self {
         Self {
             config,
             state: SessionState::Idle,
-            clock: RecordingClock::new(),
+            clock: Box::new(clock),
             events: VecDeque::new(),
             snapshots: VecDeque::new(),
             last_snapshot: None,
+            frame_timings: VecDeque::new(),
+            events_at_last_frame: 0,
             pause_duration: std::time::Duration::ZERO,
             pause_start: None,
             stats: SessionStats::default(),
@@ -104,6 +122,8 @@ impl RecordingSession {
                 self.events.clear();
                 self.snapshots.clear();
                 self.last_snapshot = None;
+                self.frame_timings.clear();
+                self.events_at_last_frame = 0;
                 self.pause_duration = std::time::Duration::ZERO;
                 self.stats = SessionStats::default();
                 self.state = SessionState::Recording;
@@ -111,7 +131,7 @@ impl RecordingSession {
             SessionState::Paused => {
                 // Resume from pause
                 if let Some(pause_start) = self.pause_start.take() {
-                    self.pause_duration += pause_start.elapsed();
+                    self.pause_duration += self.clock.now() - pause_start;
                 }
                 self.state = SessionState::Recording;
             }
@@ -122,7 +142,7 @@ impl RecordingSession {
     /// Pause recording (can resume later).
     pub fn pause(&mut self) {
         if self.state == SessionState::Recording {
-            self.pause_start = Some(std::time::Instant::now());
+            self.pause_start = Some(self.clock.now());
             self.state = SessionState::Paused;
         }
     }
@@ -141,6 +161,8 @@ impl RecordingSession {
         self.events.clear();
         self.snapshots.clear();
         self.last_snapshot = None;
+        self.frame_timings.clear();
+        self.events_at_last_frame = 0;
         self.pause_duration = std::time::Duration::ZERO;
         self.pause_start = None;
         self.stats = SessionStats::default();
@@ -202,6 +224,38 @@ impl RecordingSession {
         diff
     }
 
+    /// Record the timing of a frame.
+    ///
+    /// The frame number, timestamp and event count are filled in by the
+    /// session; spans are dropped unless `capture_timing_spans` is set.
+    pub fn record_frame_timing(&mut self, mut timing: FrameTiming) {
+        if self.state != SessionState::Recording {
+            return;
+        }
+
+        timing.frame = self.stats.total_frames;
+        timing.timestamp = self.current_timestamp();
+        timing.event_count = (self.stats.total_events - self.events_at_last_frame) as u32;
+        self.events_at_last_frame = self.stats.total_events;
+        if !self.config.capture_timing_spans {
+            timing.spans.clear();
+        }
+
+        // Ring buffer: remove oldest if at capacity
+        if self.frame_timings.len() >= self.config.max_frame_timings {
+            self.frame_timings.pop_front();
+            self.stats.frames_dropped += 1;
+        }
+
+        self.frame_timings.push_back(timing);
+        self.stats.total_frames += 1;
+    }
+
+    /// Get recorded frame timings, oldest first.
+    pub fn frame_timings(&self) -> &VecDeque<FrameTiming> {
+        &self.frame_timings
+    }
+
     /// Get recorded events.
     pub fn events(&self) -> &VecDeque<TimestampedEvent> {
         &self.events
@@ -247,6 +301,7 @@ impl RecordingSession {
             config: self.config.clone(),
             events: self.events.iter().cloned().collect(),
             snapshots: self.snapshots.iter().cloned().collect(),
+            timings: self.frame_timings.iter().cloned().collect(),
             stats: self.stats.clone(),
         }
     }
@@ -258,9 +313,18 @@ pub struct RecordingExport {
     pub config: RecordingConfig,
     pub events: Vec<TimestampedEvent>,
     pub snapshots: Vec<TreeSnapshot>,
+    #[serde(default)]
+    pub timings: Vec<FrameTiming>,
     pub stats: SessionStats,
 }
 
+impl RecordingExport {
+    /// Frame timings in frame order.
+    pub fn frame_timings(&self) -> Vec<FrameTiming> {
+        self.timings.clone()
+    }
+}
+
 /// Thread-safe wrapper around RecordingSession.
 pub struct SharedRecordingSession {
     inner: RwLock<RecordingSession>,
@@ -273,6 +337,12 @@ impl SharedRecordingSession {
         }
     }
 
+    pub fn with_clock(config: RecordingConfig, clock: impl RecordingClock + 'static) -> Self {
+        Self {
+            inner: RwLock::new(RecordingSession::with_clock(config, clock)),
+        }
+    }
+
     pub fn state(&self) -> SessionState {
         self.inner.read().state()
     }
@@ -309,6 +379,21 @@ impl SharedRecordingSession {
         self.inner.write().record_snapshot(snapshot)
     }
 
+    pub fn record_frame_timing(&self, timing: FrameTiming) {
+        self.inner.write().record_frame_timing(timing);
+    }
+
+    /// Frame timings from frame number `frame` on, oldest first.
+    pub fn frame_timings_since(&self, frame: u64) -> Vec<FrameTiming> {
+        self.inner
+            .read()
+            .frame_timings()
+            .iter()
+            .filter(|timing| timing.frame >= frame)
+            .cloned()
+            .collect()
+    }
+
     pub fn stats(&self) -> SessionStats {
         self.inner.read().stats().clone()
     }
@@ -382,4 +467,85 @@ mod tests {
         assert_eq!(session.stats().events_dropped, 2);
         assert_eq!(session.stats().total_events, 7);
     }
+
+    #[test]
+    fn test_manual_clock_timestamps() {
+        use crate::capture::{ManualClock, Modifiers, MouseButton, MouseEvent, Point};
+        use std::time::Duration;
+
+        let clock = ManualClock::new();
+        let session = SharedRecordingSession::with_clock(RecordingConfig::minimal(), clock.clone());
+        session.start();
+
+        for _ in 0..3 {
+            session.record_event(RecordedEvent::Click(MouseEvent {
+                position: Point::new(0.0, 0.0),
+                button: MouseButton::Left,
+                modifiers: Modifiers::none(),
+                target_element: None,
+            }));
+            clock.advance(Duration::from_millis(100));
+        }
+
+        let millis: Vec<_> = session
+            .export()
+            .events
+            .iter()
+            .map(|e| e.timestamp.as_millis())
+            .collect();
+        assert_eq!(millis, vec![0, 100, 200]);
+
+        // Paused time is measured on the same clock and left out
+        session.pause();
+        clock.advance(Duration::from_secs(5));
+        session.start();
+        assert_eq!(
+            session.with_session(|s| s.current_timestamp()),
+            Timestamp::from_micros(300_000)
+        );
+    }
+
+    #[test]
+    fn test_frame_timings_in_order() {
+        use crate::capture::{Modifiers, MouseButton, MouseEvent, Point};
+        use std::time::Duration;
+
+        let ms = Duration::from_millis;
+        let mut session =
+            RecordingSession::new(RecordingConfig::minimal().with_max_frame_timings(3));
+        session.record_frame_timing(FrameTiming::new(ms(9), ms(9), ms(9)));
+        assert!(session.frame_timings().is_empty());
+
+        session.start();
+        let injected = [(1, 2, 3), (4, 5, 6), (7, 8, 9), (10, 11, 12)];
+        for (i, &(build, layout, render)) in injected.iter().enumerate() {
+            for _ in 0..i {
+                session.record_event(RecordedEvent::Click(MouseEvent {
+                    position: Point::new(0.0, 0.0),
+                    button: MouseButton::Left,
+                    modifiers: Modifiers::none(),
+                    target_element: None,
+                }));
+            }
+            session.record_frame_timing(FrameTiming::new(ms(build), ms(layout), ms(render)));
+        }
+
+        // The oldest frame fell out of the buffer
+        let timings = session.export().frame_timings();
+        let phases: Vec<_> = timings
+            .iter()
+            .map(|t| (t.frame, t.build, t.layout, t.render, t.event_count))
+            .collect();
+        assert_eq!(
+            phases,
+            [
+                (1, ms(4), ms(5), ms(6), 1),
+                (2, ms(7), ms(8), ms(9), 2),
+                (3, ms(10), ms(11), ms(12), 3),
+            ]
+        );
+        assert_eq!(timings[2].total(), ms(33));
+        assert_eq!(session.stats().total_frames, 4);
+        assert_eq!(session.stats().frames_dropped, 1);
+    }
 }
@@ -1,6 +1,12 @@
 //! Blinc Embedding SDK
 //!
 //! Integrate Blinc UI into Rust applications.
+//!
+//! ```ignore
+//! blinc_runtime::init()?;
+//!
+//! let ui = blinc_runtime::blinc_cn::cn::button("Save");
+//! ```
 
 #[cfg(feature = "blinc_core")]
 pub use blinc_core;
@@ -17,11 +23,135 @@ pub use blinc_gpu;
 #[cfg(feature = "blinc_paint")]
 pub use blinc_paint;
 
-// #[cfg(feature = "blinc_cn")]
-// pub use blinc_cn;
+#[cfg(feature = "blinc_theme")]
+pub use blinc_theme;
+
+#[cfg(feature = "blinc_text")]
+pub use blinc_text;
+
+#[cfg(feature = "blinc_cn")]
+pub use blinc_cn;
+
+use std::sync::Once;
+
+static INIT: Once = Once::new();
 
 /// Initialize the Blinc runtime
+///
+/// Sets up the global state that components expect when Blinc is embedded
+/// outside of `blinc_app`:
+/// - the context state holding the reactive graph and keyed component
+///   state (`blinc_core` feature)
+/// - the global font registry (`blinc_text` feature)
+/// - a background animation scheduler (`blinc_animation` feature)
+/// - the platform theme for the system color scheme (`blinc_theme` feature)
+///
+/// Globals that the host has already set up are left alone, and calling
+/// this more than once is a no-op.
 pub fn init() -> anyhow::Result<()> {
-    // TODO: Initialize Zyntax runtime with Blinc grammar
+    INIT.call_once(|| {
+        #[cfg(feature = "blinc_core")]
+        init_context_state();
+
+        #[cfg(feature = "blinc_text")]
+        {
+            let _ = blinc_text::global_font_registry();
+        }
+
+        #[cfg(feature = "blinc_animation")]
+        init_scheduler();
+
+        #[cfg(feature = "blinc_theme")]
+        init_theme();
+
+        tracing::debug!("Blinc runtime initialized");
+    });
     Ok(())
 }
+
+#[cfg(feature = "blinc_core")]
+fn init_context_state() {
+    use std::sync::atomic::AtomicBool;
+    use std::sync::{Arc, Mutex};
+
+    use blinc_core::context_state::{BlincContextState, HookState};
+    use blinc_core::reactive::ReactiveGraph;
+
+    if BlincContextState::is_initialized() {
+        return;
+    }
+    let reactive = Arc::new(Mutex::new(ReactiveGraph::new()));
+    let hooks = Arc::new(Mutex::new(HookState::new()));
+    let dirty_flag = Arc::new(AtomicBool::new(false));
+
+    // Rebuild stateful elements when the signals they depend on change
+    #[cfg(feature = "blinc_layout")]
+    BlincContextState::init_with_callback(
+        reactive,
+        hooks,
+        dirty_flag,
+        Arc::new(|signal_ids: &[blinc_core::SignalId]| {
+            blinc_layout::check_stateful_deps(signal_ids);
+        }),
+    );
+    #[cfg(not(feature = "blinc_layout"))]
+    BlincContextState::init(reactive, hooks, dirty_flag);
+}
+
+/// The scheduler owned by the runtime (handles only hold a weak reference)
+#[cfg(feature = "blinc_animation")]
+static SCHEDULER: std::sync::OnceLock<
+    std::sync::Arc<std::sync::Mutex<blinc_animation::AnimationScheduler>>,
+> = std::sync::OnceLock::new();
+
+#[cfg(feature = "blinc_animation")]
+fn init_scheduler() {
+    use std::sync::{Arc, Mutex};
+
+    if blinc_animation::is_scheduler_initialized() {
+        return;
+    }
+    let scheduler = SCHEDULER.get_or_init(|| {
+        let mut scheduler = blinc_animation::AnimationScheduler::new();
+        scheduler.start_background();
+        Arc::new(Mutex::new(scheduler))
+    });
+    blinc_animation::set_global_scheduler(scheduler.lock().unwrap().handle());
+}
+
+#[cfg(feature = "blinc_theme")]
+fn init_theme() {
+    use blinc_theme::ThemeState;
+
+    if ThemeState::try_get().is_none() {
+        ThemeState::init_default();
+    }
+    #[cfg(feature = "blinc_animation")]
+    if let Some(scheduler) = SCHEDULER.get() {
+        ThemeState::get().set_scheduler(scheduler);
+    }
+}
+
+#[cfg(all(test, feature = "full"))]
+mod tests {
+    use super::*;
+    use blinc_core::{RecordingContext, Size};
+    use blinc_layout::RenderTree;
+
+    #[test]
+    fn test_init_is_idempotent_and_cn_renders() {
+        init().unwrap();
+        init().unwrap();
+        assert!(blinc_animation::is_scheduler_initialized());
+        assert!(blinc_theme::ThemeState::try_get().is_some());
+        assert!(blinc_core::BlincContextState::is_initialized());
+
+        let ui = crate::blinc_cn::cn::button("Save");
+        let mut tree = RenderTree::from_element(&ui);
+        tree.compute_layout(400.0, 300.0);
+
+        let mut ctx = RecordingContext::new(Size::new(400.0, 300.0));
+        tree.render(&mut ctx);
+        assert!(!ctx.commands().is_empty());
+    }
+}
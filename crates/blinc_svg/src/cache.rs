@@ -0,0 +1,201 @@
+//! Memoized SVG draw commands
+
+use std::collections::HashMap;
+
+use blinc_core::{DrawContext, Rect};
+
+use crate::document::{SvgDocument, SvgDrawCommand};
+
+/// Scale changes smaller than this reuse the cached commands
+pub const SCALE_EPSILON: f32 = 0.001;
+
+/// Documents kept by [`SvgCache::new`]
+const DEFAULT_CAPACITY: usize = 64;
+
+/// Cache of draw commands built from SVG documents at a render scale
+///
+/// Static icons redrawn every frame otherwise convert and scale their paths
+/// each time. Entries are keyed by a caller-chosen document id (e.g. a hash
+/// of the source) and rebuilt when the scale drifts by more than
+/// [`SCALE_EPSILON`]. The least recently used entry is evicted past the
+/// capacity.
+///
+/// ```ignore
+/// let mut cache = SvgCache::new();
+/// // Every frame
+/// cache.render_fit(ctx, icon_id, &icon, Rect::new(8.0, 8.0, 24.0, 24.0));
+/// ```
+pub struct SvgCache {
+    entries: HashMap<u64, CacheEntry>,
+    capacity: usize,
+    /// Use counter for least-recently-used eviction
+    clock: u64,
+}
+
+struct CacheEntry {
+    scale: f32,
+    /// Commands with the scale already applied, drawn at scale 1
+    commands: Vec<SvgDrawCommand>,
+    last_used: u64,
+}
+
+impl SvgCache {
+    /// Create a cache holding up to 64 documents
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY)
+    }
+
+    /// Create a cache holding up to `capacity` documents
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            capacity: capacity.max(1),
+            clock: 0,
+        }
+    }
+
+    /// Draw commands for `doc` at `scale`, built on a miss
+    ///
+    /// The commands are pre-scaled: render them with a scale of 1.
+    pub fn commands(&mut self, id: u64, doc: &SvgDocument, scale: f32) -> &[SvgDrawCommand] {
+        self.clock += 1;
+        let stale = self
+            .entries
+            .get(&id)
+            .map_or(true, |entry| (entry.scale - scale).abs() > SCALE_EPSILON);
+        if stale {
+            if !self.entries.contains_key(&id) && self.entries.len() >= self.capacity {
+                self.evict_least_recent();
+            }
+            let commands = doc
+                .commands()
+                .into_iter()
+                .map(|command| command.scaled(scale))
+                .collect();
+            self.entries.insert(
+                id,
+                CacheEntry {
+                    scale,
+                    commands,
+                    last_used: 0,
+                },
+            );
+        }
+
+        let entry = self.entries.get_mut(&id).expect("entry inserted above");
+        entry.last_used = self.clock;
+        &entry.commands
+    }
+
+    /// Render `doc` at the given position and scale, reusing cached commands
+    pub fn render(
+        &mut self,
+        ctx: &mut dyn DrawContext,
+        id: u64,
+        doc: &SvgDocument,
+        x: f32,
+        y: f32,
+        scale: f32,
+    ) {
+        for command in self.commands(id, doc, scale) {
+            command.render(ctx, x, y, 1.0);
+        }
+    }
+
+    /// Render `doc` to fit within `bounds`, maintaining aspect ratio
+    ///
+    /// Cached equivalent of [`SvgDocument::render_fit`].
+    pub fn render_fit(
+        &mut self,
+        ctx: &mut dyn DrawContext,
+        id: u64,
+        doc: &SvgDocument,
+        bounds: Rect,
+    ) {
+        let (x, y, scale) = doc.fit(bounds);
+        self.render(ctx, id, doc, x, y, scale);
+    }
+
+    /// Drop the cached commands for a document
+    pub fn remove(&mut self, id: u64) {
+        self.entries.remove(&id);
+    }
+
+    /// Drop all cached commands
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Number of cached documents
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn evict_least_recent(&mut self) {
+        let oldest = self
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(id, _)| *id);
+        if let Some(id) = oldest {
+            self.entries.remove(&id);
+        }
+    }
+}
+
+impl Default for SvgCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ICON: &str = r#"
+        <svg xmlns="http://www.w3.org/2000/svg" width="10" height="10">
+            <rect width="10" height="10" fill="red"/>
+        </svg>
+    "#;
+
+    #[test]
+    fn test_reuses_commands_until_scale_changes() {
+        let doc = SvgDocument::from_str(ICON).unwrap();
+        let mut cache = SvgCache::new();
+
+        let first = cache.commands(1, &doc, 2.0).as_ptr();
+        assert_eq!(
+            cache.commands(1, &doc, 2.0 + SCALE_EPSILON / 2.0).as_ptr(),
+            first
+        );
+        assert_eq!(cache.len(), 1);
+
+        // Commands come pre-scaled
+        let rescaled = cache.commands(1, &doc, 3.0);
+        let Some(SvgDrawCommand::FillPath { path, .. }) = rescaled.first() else {
+            panic!("expected a fill command");
+        };
+        let bounds = path.bounds();
+        assert_eq!((bounds.width(), bounds.height()), (30.0, 30.0));
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let doc = SvgDocument::from_str(ICON).unwrap();
+        let mut cache = SvgCache::with_capacity(2);
+        cache.commands(1, &doc, 1.0);
+        cache.commands(2, &doc, 1.0);
+        cache.commands(1, &doc, 1.0);
+        cache.commands(3, &doc, 1.0);
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.entries.contains_key(&1));
+        assert!(!cache.entries.contains_key(&2));
+    }
+}
@@ -0,0 +1,209 @@
+//! SVG clip paths and masks
+//!
+//! `clipPath` content becomes a clip pushed on the draw context's clip
+//! stack around the clipped group. Masks draw the group into an offscreen
+//! layer that is clipped to the mask's shapes and composited with the
+//! mask's coverage as opacity, which is exact for the common case of
+//! uniformly filled mask shapes.
+
+use blinc_core::{Brush, ClipShape, Color, Path, PathCommand, Point, Vec2};
+
+use crate::document::SvgDrawCommand;
+use crate::path::collect_group_paths;
+use crate::style::fill_to_brush;
+
+/// Emit the clip and mask commands for a group
+///
+/// Returns the commands that close them, in the order they must be emitted
+/// after the group's content, or `None` if the group is clipped or masked
+/// away entirely.
+pub(crate) fn open_group_effects(
+    group: &usvg::Group,
+    commands: &mut Vec<SvgDrawCommand>,
+) -> Option<Vec<SvgDrawCommand>> {
+    let transform = group.abs_transform();
+    let mut opened = Vec::new();
+
+    // A clip path can itself be clipped; each level pushes another clip and
+    // the clip stack intersects them
+    let mut clip = group.clip_path();
+    while let Some(clip_path) = clip {
+        let path = outline(
+            clip_path.root(),
+            transform.pre_concat(clip_path.transform()),
+        );
+        if path.is_empty() {
+            return None;
+        }
+        opened.push(SvgDrawCommand::PushClip { path });
+        clip = clip_path.clip_path();
+    }
+
+    let mut mask = group.mask();
+    while let Some(m) = mask {
+        let mut paths = Vec::new();
+        collect_group_paths(m.root(), transform, &mut paths);
+
+        let opacity = paths
+            .iter()
+            .filter_map(|(_, p)| fill_to_brush(p.fill()?))
+            .map(|brush| coverage(&brush, m.kind()))
+            .fold(0.0, f32::max);
+        let path = merge(paths.into_iter().map(|(path, _)| path));
+        if path.is_empty() || opacity <= 0.0 {
+            return None;
+        }
+        opened.push(SvgDrawCommand::PushMask { path, opacity });
+        mask = m.mask();
+    }
+
+    let closers = opened
+        .iter()
+        .rev()
+        .map(|cmd| match cmd {
+            SvgDrawCommand::PushMask { .. } => SvgDrawCommand::PopMask,
+            _ => SvgDrawCommand::PopClip,
+        })
+        .collect();
+    commands.extend(opened);
+    Some(closers)
+}
+
+/// Union of all paths under a group, as a single path
+fn outline(group: &usvg::Group, transform: usvg::Transform) -> Path {
+    let mut paths = Vec::new();
+    collect_group_paths(group, transform, &mut paths);
+    merge(paths.into_iter().map(|(path, _)| path))
+}
+
+/// Concatenate paths into one; with nonzero filling this is their union
+fn merge(paths: impl Iterator<Item = Path>) -> Path {
+    Path::from_commands(paths.flat_map(|path| path.commands().to_vec()).collect())
+}
+
+/// How much of the masked content a mask brush lets through (0.0 - 1.0)
+fn coverage(brush: &Brush, kind: usvg::MaskType) -> f32 {
+    let of_color = |c: Color| match kind {
+        usvg::MaskType::Luminance => (0.2125 * c.r + 0.7154 * c.g + 0.0721 * c.b) * c.a,
+        usvg::MaskType::Alpha => c.a,
+    };
+    match brush {
+        Brush::Gradient(gradient) => {
+            let stops = gradient.stops();
+            stops.iter().map(|s| of_color(s.color)).sum::<f32>() / stops.len().max(1) as f32
+        }
+        Brush::Pattern(pattern) => of_color(pattern.fallback_color()),
+        Brush::Solid(color) => of_color(*color),
+        _ => 1.0,
+    }
+}
+
+/// Pick the cheapest clip shape that matches a path exactly
+///
+/// usvg turns every shape into a path, but rectangles and ellipses clip
+/// much better (and on GPU, at all) as dedicated shapes.
+pub(crate) fn clip_shape_for(path: &Path) -> ClipShape {
+    let bounds = path.bounds();
+    if is_rect(path, bounds) {
+        return ClipShape::rect(bounds);
+    }
+    if is_ellipse(path, bounds) {
+        let center = Point::new(
+            bounds.x() + bounds.width() / 2.0,
+            bounds.y() + bounds.height() / 2.0,
+        );
+        let (rx, ry) = (bounds.width() / 2.0, bounds.height() / 2.0);
+        return if (rx - ry).abs() <= TOLERANCE {
+            ClipShape::Circle { center, radius: rx }
+        } else {
+            ClipShape::Ellipse {
+                center,
+                radii: Vec2::new(rx, ry),
+            }
+        };
+    }
+    ClipShape::Path(path.clone())
+}
+
+/// Distance below which points are considered equal, in user units
+const TOLERANCE: f32 = 0.01;
+
+/// Whether a path is a single axis-aligned rectangle
+fn is_rect(path: &Path, bounds: blinc_core::Rect) -> bool {
+    let on_corner = |p: &Point| {
+        let on_x = (p.x - bounds.x()).abs() <= TOLERANCE
+            || (p.x - (bounds.x() + bounds.width())).abs() <= TOLERANCE;
+        let on_y = (p.y - bounds.y()).abs() <= TOLERANCE
+            || (p.y - (bounds.y() + bounds.height())).abs() <= TOLERANCE;
+        on_x && on_y
+    };
+
+    let mut points = Vec::new();
+    for (i, cmd) in path.commands().iter().enumerate() {
+        match cmd {
+            PathCommand::MoveTo(p) if i == 0 => points.push(*p),
+            PathCommand::LineTo(p) => points.push(*p),
+            PathCommand::Close => {}
+            _ => return false,
+        }
+    }
+    points.dedup_by(|a, b| (a.x - b.x).abs() <= TOLERANCE && (a.y - b.y).abs() <= TOLERANCE);
+    let axis_aligned = points
+        .windows(2)
+        .all(|w| (w[0].x - w[1].x).abs() <= TOLERANCE || (w[0].y - w[1].y).abs() <= TOLERANCE);
+    (4..=5).contains(&points.len()) && axis_aligned && points.iter().all(on_corner)
+}
+
+/// Whether a single closed curve lies on the ellipse inscribed in `bounds`
+///
+/// Checks every on-curve point and every curve's midpoint.
+fn is_ellipse(path: &Path, bounds: blinc_core::Rect) -> bool {
+    let (rx, ry) = (bounds.width() / 2.0, bounds.height() / 2.0);
+    if rx <= TOLERANCE || ry <= TOLERANCE {
+        return false;
+    }
+    let (cx, cy) = (bounds.x() + rx, bounds.y() + ry);
+    let on_ellipse = |p: Point| {
+        let d = ((p.x - cx) / rx).powi(2) + ((p.y - cy) / ry).powi(2);
+        (d - 1.0).abs() <= 0.01
+    };
+
+    let mut current = Point::new(0.0, 0.0);
+    let mut curves = 0;
+    for (i, cmd) in path.commands().iter().enumerate() {
+        let ok = match cmd {
+            PathCommand::MoveTo(p) if i == 0 => {
+                current = *p;
+                on_ellipse(*p)
+            }
+            PathCommand::QuadTo { control, end } => {
+                let mid = Point::new(
+                    0.25 * current.x + 0.5 * control.x + 0.25 * end.x,
+                    0.25 * current.y + 0.5 * control.y + 0.25 * end.y,
+                );
+                current = *end;
+                curves += 1;
+                on_ellipse(mid) && on_ellipse(*end)
+            }
+            PathCommand::CubicTo {
+                control1,
+                control2,
+                end,
+            } => {
+                let mid = Point::new(
+                    0.125 * current.x + 0.375 * control1.x + 0.375 * control2.x + 0.125 * end.x,
+                    0.125 * current.y + 0.375 * control1.y + 0.375 * control2.y + 0.125 * end.y,
+                );
+                current = *end;
+                curves += 1;
+                on_ellipse(mid) && on_ellipse(*end)
+            }
+            PathCommand::Close => true,
+            _ => false,
+        };
+        if !ok {
+            return false;
+        }
+    }
+    curves >= 4
+}
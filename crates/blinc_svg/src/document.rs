@@ -3,12 +3,21 @@
 use std::fs;
 use std::path::Path as FilePath;
 
-use blinc_core::{Brush, DrawContext, Path, PathCommand, Point, Rect, Stroke};
+use blinc_core::{
+    Affine2D, BlendMode, Brush, Color, DrawContext, LayerConfig, Path, PathCommand, PatternBrush,
+    Point, Rect, Size, Stroke,
+};
 use usvg::{Options, Tree};
 
+use crate::clip::{clip_shape_for, open_group_effects};
 use crate::error::SvgError;
-use crate::path::usvg_path_to_blinc;
-use crate::style::{fill_to_brush, stroke_to_blinc};
+use crate::path::{transform_path, usvg_path_to_blinc, usvg_transform_to_affine};
+use crate::style::{fill_to_brush, stroke_to_blinc, ColorOverride};
+use crate::text;
+
+/// Most pattern tiles drawn for a single fill before falling back to a
+/// flat color
+const MAX_PATTERN_TILES: i64 = 4096;
 
 /// A loaded and parsed SVG document
 #[derive(Clone)]
@@ -18,9 +27,16 @@ pub struct SvgDocument {
     /// Original viewBox/size of the SVG
     pub width: f32,
     pub height: f32,
+    /// Declared viewBox, or the size when there is none
+    view_box: Rect,
+    /// Recolors paints as commands are built
+    color_override: Option<ColorOverride>,
 }
 
 /// A drawing command extracted from the SVG
+///
+/// Clip and mask commands come in push/pop pairs around the commands they
+/// affect, and nest.
 #[derive(Clone, Debug)]
 pub enum SvgDrawCommand {
     /// Fill a path with a brush
@@ -31,39 +47,209 @@ pub enum SvgDrawCommand {
         stroke: Stroke,
         brush: Brush,
     },
+    /// Clip following commands to a path, intersecting any enclosing clips
+    PushClip { path: Path },
+    /// End the most recent clip
+    PopClip,
+    /// Draw following commands into an offscreen group, composited clipped
+    /// to `path` with the mask's coverage as opacity
+    PushMask { path: Path, opacity: f32 },
+    /// Composite the most recent mask group
+    PopMask,
+}
+
+impl SvgDrawCommand {
+    /// Replace the brush of fill and stroke commands (e.g. to tint an icon)
+    pub fn with_brush(self, brush: Brush) -> Self {
+        match self {
+            SvgDrawCommand::FillPath { path, .. } => SvgDrawCommand::FillPath { path, brush },
+            SvgDrawCommand::StrokePath { path, stroke, .. } => SvgDrawCommand::StrokePath {
+                path,
+                stroke,
+                brush,
+            },
+            other => other,
+        }
+    }
+
+    /// Bake `scale` into this command, so rendering it at scale 1 matches
+    /// rendering the original at `scale`
+    pub fn scaled(self, scale: f32) -> Self {
+        let scale_path = |path: &Path| scale_and_translate_path(path, 0.0, 0.0, scale);
+        match self {
+            SvgDrawCommand::FillPath { path, brush } => SvgDrawCommand::FillPath {
+                path: scale_path(&path),
+                brush: match brush {
+                    Brush::Pattern(mut pattern) => {
+                        pattern.transform = Affine2D::scale(scale, scale).then(&pattern.transform);
+                        Brush::Pattern(pattern)
+                    }
+                    other => other,
+                },
+            },
+            SvgDrawCommand::StrokePath {
+                path,
+                stroke,
+                brush,
+            } => SvgDrawCommand::StrokePath {
+                path: scale_path(&path),
+                stroke: scale_stroke(&stroke, scale),
+                brush,
+            },
+            SvgDrawCommand::PushClip { path } => SvgDrawCommand::PushClip {
+                path: scale_path(&path),
+            },
+            SvgDrawCommand::PushMask { path, opacity } => SvgDrawCommand::PushMask {
+                path: scale_path(&path),
+                opacity,
+            },
+            other => other,
+        }
+    }
+
+    /// Draw this command to a DrawContext at the given position and scale
+    pub fn render(&self, ctx: &mut dyn DrawContext, x: f32, y: f32, scale: f32) {
+        match self {
+            SvgDrawCommand::FillPath { path, brush } => {
+                let scaled = scale_and_translate_path(path, x, y, scale);
+                if let Brush::Pattern(pattern) = brush {
+                    let to_screen = Affine2D {
+                        elements: [scale, 0.0, 0.0, scale, x, y],
+                    };
+                    fill_pattern(ctx, &scaled, pattern, &to_screen);
+                } else {
+                    ctx.fill_path(&scaled, brush.clone());
+                }
+            }
+            SvgDrawCommand::StrokePath {
+                path,
+                stroke,
+                brush,
+            } => {
+                let scaled = scale_and_translate_path(path, x, y, scale);
+                ctx.stroke_path(&scaled, &scale_stroke(stroke, scale), brush.clone());
+            }
+            SvgDrawCommand::PushClip { path } => {
+                let scaled = scale_and_translate_path(path, x, y, scale);
+                ctx.push_clip(clip_shape_for(&scaled));
+            }
+            SvgDrawCommand::PopClip => ctx.pop_clip(),
+            SvgDrawCommand::PushMask { path, opacity } => {
+                let scaled = scale_and_translate_path(path, x, y, scale);
+                let bounds = scaled.bounds();
+                ctx.push_layer(LayerConfig {
+                    id: None,
+                    position: Some(Point::new(bounds.x(), bounds.y())),
+                    size: Some(Size::new(bounds.width(), bounds.height())),
+                    blend_mode: BlendMode::Normal,
+                    opacity: *opacity,
+                    depth: false,
+                    effects: Vec::new(),
+                    cache: false,
+                });
+                ctx.push_clip(clip_shape_for(&scaled));
+            }
+            SvgDrawCommand::PopMask => {
+                ctx.pop_clip();
+                ctx.pop_layer();
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for SvgDocument {
+    type Err = SvgError;
+
+    fn from_str(svg_str: &str) -> Result<Self, SvgError> {
+        Self::from_data(svg_str.as_bytes())
+    }
 }
 
 impl SvgDocument {
     /// Load an SVG document from a file
+    ///
+    /// Relative `<image>` hrefs resolve against the file's directory.
     pub fn from_file(path: impl AsRef<FilePath>) -> Result<Self, SvgError> {
+        let path = path.as_ref();
         let data = fs::read(path)?;
-        Self::from_data(&data)
+        Self::parse(&data, path.parent())
     }
 
     /// Load an SVG document from raw bytes
     pub fn from_data(data: &[u8]) -> Result<Self, SvgError> {
-        let options = Options::default();
+        Self::parse(data, None)
+    }
+
+    /// Load an SVG document from a string
+    ///
+    /// Same as `svg_str.parse::<SvgDocument>()`; kept inherent so callers
+    /// don't need `FromStr` in scope.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(svg_str: &str) -> Result<Self, SvgError> {
+        Self::from_data(svg_str.as_bytes())
+    }
+
+    /// Load an SVG document from a string, resolving relative `<image>`
+    /// hrefs against `base_dir`
+    ///
+    /// Useful for markup generated at runtime that still references assets
+    /// on disk.
+    pub fn from_str_with_base(
+        svg_str: &str,
+        base_dir: impl AsRef<FilePath>,
+    ) -> Result<Self, SvgError> {
+        Self::parse(svg_str.as_bytes(), Some(base_dir.as_ref()))
+    }
+
+    fn parse(data: &[u8], resources_dir: Option<&FilePath>) -> Result<Self, SvgError> {
+        let mut options = Options {
+            resources_dir: resources_dir.map(FilePath::to_path_buf),
+            ..Options::default()
+        };
+        if text::has_text(data) {
+            options.fontdb = text::fontdb();
+        }
         let tree = Tree::from_data(data, &options).map_err(|e| SvgError::Parse(e.to_string()))?;
 
         let size = tree.size();
+        let view_box = parse_view_box(data)
+            .unwrap_or_else(|| Rect::new(0.0, 0.0, size.width(), size.height()));
 
         Ok(Self {
             tree,
             width: size.width(),
             height: size.height(),
+            view_box,
+            color_override: None,
         })
     }
 
-    /// Load an SVG document from a string
-    pub fn from_str(svg_str: &str) -> Result<Self, SvgError> {
-        Self::from_data(svg_str.as_bytes())
+    /// Recolor every solid fill with `color`, keeping each fill's alpha
+    ///
+    /// Equivalent to `with_color_override(ColorOverride::new(color))`; use
+    /// that to recolor strokes or flatten gradients too.
+    pub fn with_fill_override(self, color: Color) -> Self {
+        self.with_color_override(ColorOverride::new(color))
+    }
+
+    /// Recolor paints according to `color_override` when building commands
+    pub fn with_color_override(mut self, color_override: ColorOverride) -> Self {
+        self.color_override = Some(color_override);
+        self
     }
 
-    /// Get the original size of the SVG
+    /// Get the intrinsic size of the SVG, as resolved by usvg
+    ///
+    /// Percentage root dimensions resolve against the viewBox.
     pub fn size(&self) -> (f32, f32) {
         (self.width, self.height)
     }
 
+    /// Get the declared viewBox, or `(0, 0, width, height)` without one
+    pub fn view_box(&self) -> Rect {
+        self.view_box
+    }
+
     /// Get the bounding box of the SVG content
     pub fn bounds(&self) -> Rect {
         Rect::new(0.0, 0.0, self.width, self.height)
@@ -73,6 +259,19 @@ impl SvgDocument {
     pub fn commands(&self) -> Vec<SvgDrawCommand> {
         let mut commands = Vec::new();
         self.extract_commands(self.tree.root(), &mut commands);
+        if let Some(color_override) = &self.color_override {
+            for command in &mut commands {
+                match command {
+                    SvgDrawCommand::FillPath { brush, .. } => {
+                        *brush = color_override.fill(brush.clone());
+                    }
+                    SvgDrawCommand::StrokePath { brush, .. } => {
+                        *brush = color_override.stroke(brush.clone());
+                    }
+                    _ => {}
+                }
+            }
+        }
         commands
     }
 
@@ -81,8 +280,12 @@ impl SvgDocument {
         for child in group.children() {
             match child {
                 usvg::Node::Group(g) => {
-                    // Recurse into groups (transforms are handled per-path via abs_transform)
-                    self.extract_commands(g, commands);
+                    // Recurse into groups (transforms are handled per-path via abs_transform),
+                    // wrapped in the group's clip and mask
+                    if let Some(closers) = open_group_effects(g, commands) {
+                        self.extract_commands(g, commands);
+                        commands.extend(closers);
+                    }
                 }
                 usvg::Node::Path(p) => {
                     // Convert path to Blinc path and apply the absolute transform
@@ -94,7 +297,7 @@ impl SvgDocument {
                         if let Some(brush) = fill_to_brush(fill) {
                             commands.push(SvgDrawCommand::FillPath {
                                 path: transformed_path.clone(),
-                                brush,
+                                brush: in_document_space(brush, &p.abs_transform()),
                             });
                         }
                     }
@@ -105,7 +308,7 @@ impl SvgDocument {
                             commands.push(SvgDrawCommand::StrokePath {
                                 path: transformed_path,
                                 stroke: blinc_stroke,
-                                brush,
+                                brush: in_document_space(brush, &p.abs_transform()),
                             });
                         }
                     }
@@ -113,8 +316,9 @@ impl SvgDocument {
                 usvg::Node::Image(_) => {
                     // TODO: Handle embedded images
                 }
-                usvg::Node::Text(_) => {
-                    // Text is converted to paths by usvg
+                usvg::Node::Text(t) => {
+                    // Shape text through the Blinc font system
+                    text::text_to_commands(t, commands);
                 }
             }
         }
@@ -122,32 +326,20 @@ impl SvgDocument {
 
     /// Render the SVG to a DrawContext at the given position and scale
     pub fn render(&self, ctx: &mut dyn DrawContext, x: f32, y: f32, scale: f32) {
-        let commands = self.commands();
-
-        for cmd in commands {
-            match cmd {
-                SvgDrawCommand::FillPath { path, brush } => {
-                    let scaled = scale_and_translate_path(&path, x, y, scale);
-                    ctx.fill_path(&scaled, brush);
-                }
-                SvgDrawCommand::StrokePath {
-                    path,
-                    stroke,
-                    brush,
-                } => {
-                    let scaled = scale_and_translate_path(&path, x, y, scale);
-                    // Scale stroke width proportionally
-                    let scaled_stroke = Stroke::new(stroke.width * scale)
-                        .with_cap(stroke.cap)
-                        .with_join(stroke.join);
-                    ctx.stroke_path(&scaled, &scaled_stroke, brush);
-                }
-            }
+        for cmd in self.commands() {
+            cmd.render(ctx, x, y, scale);
         }
     }
 
     /// Render the SVG to fit within a given rectangle, maintaining aspect ratio
     pub fn render_fit(&self, ctx: &mut dyn DrawContext, bounds: Rect) {
+        let (x, y, scale) = self.fit(bounds);
+        self.render(ctx, x, y, scale);
+    }
+
+    /// Position and scale that fit the SVG centered within `bounds`,
+    /// maintaining aspect ratio
+    pub fn fit(&self, bounds: Rect) -> (f32, f32, f32) {
         let scale_x = bounds.width() / self.width;
         let scale_y = bounds.height() / self.height;
         let scale = scale_x.min(scale_y);
@@ -157,66 +349,126 @@ impl SvgDocument {
         let scaled_height = self.height * scale;
         let x = bounds.x() + (bounds.width() - scaled_width) / 2.0;
         let y = bounds.y() + (bounds.height() - scaled_height) / 2.0;
+        (x, y, scale)
+    }
+}
 
-        self.render(ctx, x, y, scale);
+/// Move a pattern brush from its element's space into document space
+///
+/// Other brushes are returned unchanged.
+pub(crate) fn in_document_space(brush: Brush, transform: &usvg::Transform) -> Brush {
+    match brush {
+        Brush::Pattern(mut pattern) => {
+            pattern.transform = usvg_transform_to_affine(transform).then(&pattern.transform);
+            Brush::Pattern(pattern)
+        }
+        other => other,
     }
 }
 
-/// Apply a usvg Transform to a Blinc Path
-fn apply_transform(path: &Path, transform: &usvg::Transform) -> Path {
-    if transform.is_identity() {
-        return path.clone();
+/// Fill a path with a repeating pattern by drawing its tiles clipped to it
+///
+/// `path` is in screen space; `to_screen` maps the pattern's shape space
+/// to screen space.
+fn fill_pattern(
+    ctx: &mut dyn DrawContext,
+    path: &Path,
+    pattern: &PatternBrush,
+    to_screen: &Affine2D,
+) {
+    let rect = pattern.rect;
+    let to_screen = to_screen.then(&pattern.transform);
+    let Some(from_screen) = to_screen.inverse() else {
+        return;
+    };
+    if rect.width() <= 0.0 || rect.height() <= 0.0 {
+        return;
     }
 
-    let (sx, ky, kx, sy, tx, ty) = (
-        transform.sx as f32,
-        transform.ky as f32,
-        transform.kx as f32,
-        transform.sy as f32,
-        transform.tx as f32,
-        transform.ty as f32,
-    );
+    // Range of tiles covering the path, in pattern space
+    let bounds = path.bounds();
+    let corners = [
+        Point::new(bounds.x(), bounds.y()),
+        Point::new(bounds.x() + bounds.width(), bounds.y()),
+        Point::new(bounds.x(), bounds.y() + bounds.height()),
+        Point::new(bounds.x() + bounds.width(), bounds.y() + bounds.height()),
+    ]
+    .map(|p| from_screen.transform_point(p));
+    let (mut min, mut max) = (corners[0], corners[0]);
+    for p in &corners[1..] {
+        min = Point::new(min.x.min(p.x), min.y.min(p.y));
+        max = Point::new(max.x.max(p.x), max.y.max(p.y));
+    }
+    let cols = ((min.x - rect.x()) / rect.width()).floor() as i64
+        ..((max.x - rect.x()) / rect.width()).ceil() as i64;
+    let rows = ((min.y - rect.y()) / rect.height()).floor() as i64
+        ..((max.y - rect.y()) / rect.height()).ceil() as i64;
+
+    let tiles = (cols.end - cols.start).saturating_mul(rows.end - rows.start);
+    if tiles > MAX_PATTERN_TILES {
+        ctx.fill_path(path, Brush::Solid(pattern.fallback_color()));
+        return;
+    }
 
-    let transform_point =
-        |p: Point| -> Point { Point::new(sx * p.x + kx * p.y + tx, ky * p.x + sy * p.y + ty) };
+    ctx.push_clip(clip_shape_for(path));
+    ctx.push_opacity(pattern.opacity);
+    for row in rows {
+        for col in cols.clone() {
+            let tile_to_screen = to_screen.then(&Affine2D::translation(
+                rect.x() + col as f32 * rect.width(),
+                rect.y() + row as f32 * rect.height(),
+            ));
+            for shape in &pattern.tile {
+                let tile_path = transform_path(&shape.path, &tile_to_screen);
+                if let Brush::Pattern(nested) = &shape.brush {
+                    fill_pattern(ctx, &tile_path, nested, &tile_to_screen);
+                } else {
+                    ctx.fill_path(&tile_path, shape.brush.clone());
+                }
+            }
+        }
+    }
+    ctx.pop_opacity();
+    ctx.pop_clip();
+}
 
-    let new_commands: Vec<PathCommand> = path
-        .commands()
-        .iter()
-        .map(|cmd| match cmd {
-            PathCommand::MoveTo(p) => PathCommand::MoveTo(transform_point(*p)),
-            PathCommand::LineTo(p) => PathCommand::LineTo(transform_point(*p)),
-            PathCommand::QuadTo { control, end } => PathCommand::QuadTo {
-                control: transform_point(*control),
-                end: transform_point(*end),
-            },
-            PathCommand::CubicTo {
-                control1,
-                control2,
-                end,
-            } => PathCommand::CubicTo {
-                control1: transform_point(*control1),
-                control2: transform_point(*control2),
-                end: transform_point(*end),
-            },
-            PathCommand::ArcTo {
-                radii,
-                rotation,
-                large_arc,
-                sweep,
-                end,
-            } => PathCommand::ArcTo {
-                radii: *radii,
-                rotation: *rotation,
-                large_arc: *large_arc,
-                sweep: *sweep,
-                end: transform_point(*end),
-            },
-            PathCommand::Close => PathCommand::Close,
-        })
-        .collect();
+/// Apply a usvg Transform to a Blinc Path
+pub(crate) fn apply_transform(path: &Path, transform: &usvg::Transform) -> Path {
+    transform_path(path, &usvg_transform_to_affine(transform))
+}
 
-    Path::from_commands(new_commands)
+/// Scale a stroke's lengths (width and dash pattern) proportionally
+///
+/// Caps, joins and the miter limit are ratios and carry over unchanged.
+fn scale_stroke(stroke: &Stroke, scale: f32) -> Stroke {
+    Stroke {
+        width: stroke.width * scale,
+        dash: stroke.dash.iter().map(|d| d * scale).collect(),
+        dash_offset: stroke.dash_offset * scale,
+        ..stroke.clone()
+    }
+}
+
+/// Read the root element's `viewBox` attribute
+fn parse_view_box(data: &[u8]) -> Option<Rect> {
+    let decompressed;
+    let data = if data.starts_with(&[0x1f, 0x8b]) {
+        decompressed = usvg::decompress_svgz(data).ok()?;
+        &decompressed[..]
+    } else {
+        data
+    };
+    let source = std::str::from_utf8(data).ok()?;
+    let doc = usvg::roxmltree::Document::parse(source).ok()?;
+    let mut values = doc
+        .root_element()
+        .attribute("viewBox")?
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|v| !v.is_empty())
+        .map(|v| v.parse::<f32>().ok());
+    let mut next = || values.next().flatten();
+    let (x, y, width, height) = (next()?, next()?, next()?, next()?);
+    (width > 0.0 && height > 0.0).then(|| Rect::new(x, y, width, height))
 }
 
 /// Scale and translate a path for rendering
@@ -282,10 +534,61 @@ mod tests {
         assert_eq!(doc.width, 100.0);
         assert_eq!(doc.height, 100.0);
 
+        let parsed: SvgDocument = svg.parse().unwrap();
+        assert_eq!(parsed.size(), doc.size());
+
         let commands = doc.commands();
         assert!(!commands.is_empty());
     }
 
+    #[test]
+    fn test_from_str_errors_and_base_dir() {
+        assert!(matches!(
+            SvgDocument::from_str("<svg"),
+            Err(SvgError::Parse(_))
+        ));
+
+        let dir = std::env::temp_dir().join(format!("blinc_svg_base_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("dot.svg"),
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="10"><circle cx="5" cy="5" r="5"/></svg>"#,
+        )
+        .unwrap();
+        let svg = r#"
+            <svg xmlns="http://www.w3.org/2000/svg" width="20" height="20">
+                <image href="dot.svg" width="10" height="10"/>
+            </svg>
+        "#;
+        fn has_image(group: &usvg::Group) -> bool {
+            group.children().iter().any(|node| match node {
+                usvg::Node::Image(_) => true,
+                usvg::Node::Group(group) => has_image(group),
+                _ => false,
+            })
+        }
+
+        // The relative href only resolves with a base directory
+        let doc = SvgDocument::from_str_with_base(svg, &dir).unwrap();
+        let unresolved = SvgDocument::from_str(svg).unwrap();
+        fs::remove_dir_all(&dir).ok();
+        assert!(has_image(doc.tree.root()));
+        assert!(!has_image(unresolved.tree.root()));
+    }
+
+    #[test]
+    fn test_size_and_view_box() {
+        let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" width="50%" viewBox="-4, 2 24 12"/>"#;
+        let doc = SvgDocument::from_str(svg).unwrap();
+        // Percentages resolve against the viewBox; the missing height is 100%
+        assert_eq!(doc.size(), (12.0, 12.0));
+        assert_eq!(doc.view_box(), Rect::new(-4.0, 2.0, 24.0, 12.0));
+
+        let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" width="32" height="16"/>"#;
+        let doc = SvgDocument::from_str(svg).unwrap();
+        assert_eq!(doc.view_box(), Rect::new(0.0, 0.0, 32.0, 16.0));
+    }
+
     #[test]
     fn test_parse_path_svg() {
         let svg = r#"
@@ -314,6 +617,57 @@ mod tests {
         assert!(!commands.is_empty());
     }
 
+    #[test]
+    fn test_fill_override_keeps_alpha() {
+        let svg = r#"
+            <svg xmlns="http://www.w3.org/2000/svg" width="24" height="24">
+                <defs>
+                    <linearGradient id="g">
+                        <stop offset="0" stop-color="red" stop-opacity="0.25"/>
+                        <stop offset="1" stop-color="blue"/>
+                    </linearGradient>
+                </defs>
+                <rect width="10" height="10" fill="red" fill-opacity="0.5" stroke="red"/>
+                <rect width="10" height="10" fill="url(#g)"/>
+            </svg>
+        "#;
+        let tint = Color::rgba(0.0, 1.0, 0.0, 0.8);
+        let solid = |brush: &Brush| match brush {
+            Brush::Solid(color) => Some(*color),
+            _ => None,
+        };
+        let brushes = |doc: SvgDocument| -> Vec<Brush> {
+            doc.commands()
+                .into_iter()
+                .filter_map(|c| match c {
+                    SvgDrawCommand::FillPath { brush, .. }
+                    | SvgDrawCommand::StrokePath { brush, .. } => Some(brush),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        // Fill recolored with its opacity kept; stroke and gradient untouched
+        let doc = SvgDocument::from_str(svg).unwrap().with_fill_override(tint);
+        let [fill, stroke, gradient] = &brushes(doc)[..] else {
+            panic!("expected fill, stroke and gradient commands");
+        };
+        assert_eq!(solid(fill), Some(tint.with_alpha(0.4)));
+        assert_eq!(solid(stroke), Some(Color::rgba(1.0, 0.0, 0.0, 1.0)));
+        assert!(matches!(gradient, Brush::Gradient(_)));
+
+        let doc = SvgDocument::from_str(svg).unwrap().with_color_override(
+            ColorOverride::new(tint)
+                .strokes(true)
+                .flatten_gradients(true),
+        );
+        let [_, stroke, gradient] = &brushes(doc)[..] else {
+            panic!("expected fill, stroke and gradient commands");
+        };
+        assert_eq!(solid(stroke), Some(tint));
+        assert_eq!(solid(gradient), Some(tint.with_alpha(0.2)));
+    }
+
     #[test]
     fn test_fill_only_no_stroke() {
         // SVG with fill but no stroke - should NOT have a stroke command
@@ -342,4 +696,184 @@ mod tests {
             "Should have NO stroke commands when stroke is not specified"
         );
     }
+
+    #[test]
+    fn test_text_emits_glyph_commands() {
+        // Needs at least one system font to shape with
+        let has_font = blinc_text::global_font_registry()
+            .lock()
+            .unwrap()
+            .load_generic(blinc_text::GenericFont::SansSerif)
+            .is_ok();
+        if !has_font {
+            return;
+        }
+
+        let svg = r#"
+            <svg xmlns="http://www.w3.org/2000/svg" width="100" height="40">
+                <text x="10" y="30" font-family="NoSuchFont, sans-serif" font-size="20">Hi</text>
+            </svg>
+        "#;
+
+        let doc = SvgDocument::from_str(svg).unwrap();
+        let glyphs = doc
+            .commands()
+            .iter()
+            .filter(|c| matches!(c, SvgDrawCommand::FillPath { .. }))
+            .count();
+        assert_eq!(glyphs, 2, "Should fill one path per character");
+    }
+
+    #[test]
+    fn test_rect_clipped_to_circle() {
+        let svg = r#"
+            <svg xmlns="http://www.w3.org/2000/svg" width="100" height="100">
+                <clipPath id="c"><circle cx="50" cy="50" r="30"/></clipPath>
+                <g clip-path="url(#c)">
+                    <rect x="0" y="0" width="100" height="100" fill="red"/>
+                </g>
+            </svg>
+        "#;
+
+        let doc = SvgDocument::from_str(svg).unwrap();
+        let commands = doc.commands();
+        assert!(matches!(
+            commands.as_slice(),
+            [
+                SvgDrawCommand::PushClip { .. },
+                SvgDrawCommand::FillPath { .. },
+                SvgDrawCommand::PopClip
+            ]
+        ));
+
+        // Rendering turns the clip into a real circle on the clip stack
+        let mut ctx = blinc_core::RecordingContext::new(blinc_core::Size::new(100.0, 100.0));
+        doc.render(&mut ctx, 0.0, 0.0, 1.0);
+        let clip = ctx.commands().iter().find_map(|cmd| match cmd {
+            blinc_core::DrawCommand::PushClip(shape) => Some(shape.clone()),
+            _ => None,
+        });
+        match clip {
+            Some(blinc_core::ClipShape::Circle { center, radius }) => {
+                assert!((center.x - 50.0).abs() < 0.1 && (center.y - 50.0).abs() < 0.1);
+                assert!((radius - 30.0).abs() < 0.1);
+            }
+            other => panic!("expected a circle clip, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_nested_clips_stack() {
+        let svg = r#"
+            <svg xmlns="http://www.w3.org/2000/svg" width="100" height="100">
+                <clipPath id="a"><rect x="0" y="0" width="60" height="100"/></clipPath>
+                <clipPath id="b"><rect x="40" y="0" width="60" height="100"/></clipPath>
+                <g clip-path="url(#a)">
+                    <g clip-path="url(#b)">
+                        <rect x="0" y="0" width="100" height="100" fill="red"/>
+                    </g>
+                </g>
+            </svg>
+        "#;
+
+        let commands = SvgDocument::from_str(svg).unwrap().commands();
+        let pushes = commands
+            .iter()
+            .filter(|c| matches!(c, SvgDrawCommand::PushClip { .. }))
+            .count();
+        let pops = commands
+            .iter()
+            .filter(|c| matches!(c, SvgDrawCommand::PopClip))
+            .count();
+        // Both clips are active around the fill, so the clip stack intersects them
+        assert_eq!((pushes, pops), (2, 2));
+        assert!(matches!(commands.last(), Some(SvgDrawCommand::PopClip)));
+    }
+
+    #[test]
+    fn test_pattern_fill_becomes_pattern_brush() {
+        let svg = r#"
+            <svg xmlns="http://www.w3.org/2000/svg" width="100" height="100">
+                <pattern id="p" width="10" height="10" patternUnits="userSpaceOnUse">
+                    <rect width="5" height="5" fill="blue"/>
+                </pattern>
+                <rect width="100" height="100" fill="url(#p)"/>
+            </svg>
+        "#;
+
+        let commands = SvgDocument::from_str(svg).unwrap().commands();
+        match commands.as_slice() {
+            [SvgDrawCommand::FillPath {
+                brush: Brush::Pattern(pattern),
+                ..
+            }] => {
+                assert_eq!(pattern.tile.len(), 1);
+                assert_eq!(pattern.rect.width(), 10.0);
+            }
+            other => panic!("expected a pattern fill, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_dashed_stroke_carries_style() {
+        let svg = r#"
+            <svg xmlns="http://www.w3.org/2000/svg" width="100" height="100">
+                <path d="M10,50 L90,50 L90,90" fill="none" stroke="black" stroke-width="2"
+                      stroke-dasharray="4 2" stroke-dashoffset="1"
+                      stroke-linecap="round" stroke-linejoin="bevel" stroke-miterlimit="8"/>
+            </svg>
+        "#;
+
+        let doc = SvgDocument::from_str(svg).unwrap();
+        let commands = doc.commands();
+        let stroke = match commands.as_slice() {
+            [SvgDrawCommand::StrokePath { stroke, .. }] => stroke.clone(),
+            other => panic!("expected a single stroke, got {:?}", other),
+        };
+        assert_eq!(stroke.dash, vec![4.0, 2.0]);
+        assert_eq!(stroke.dash_offset, 1.0);
+        assert!(matches!(stroke.cap, blinc_core::LineCap::Round));
+        assert!(matches!(stroke.join, blinc_core::LineJoin::Bevel));
+        assert_eq!(stroke.miter_limit, 8.0);
+
+        // Rendering at 2x scales the dash pattern along with the width
+        let mut ctx = blinc_core::RecordingContext::new(blinc_core::Size::new(200.0, 200.0));
+        doc.render(&mut ctx, 0.0, 0.0, 2.0);
+        let rendered = ctx.commands().iter().find_map(|cmd| match cmd {
+            blinc_core::DrawCommand::StrokePath { stroke, .. } => Some(stroke.clone()),
+            _ => None,
+        });
+        let rendered = rendered.expect("stroke should be rendered");
+        assert_eq!(rendered.width, 4.0);
+        assert_eq!(rendered.dash, vec![8.0, 4.0]);
+        assert_eq!(rendered.dash_offset, 2.0);
+        assert!(matches!(rendered.join, blinc_core::LineJoin::Bevel));
+    }
+
+    #[test]
+    fn test_percentage_and_zero_dash_arrays() {
+        let stroke_of = |dasharray: &str| {
+            let svg = format!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="100">
+                    <path d="M10,50 L90,50" fill="none" stroke="black"
+                          stroke-dasharray="{}"/>
+                </svg>"#,
+                dasharray
+            );
+            let doc = SvgDocument::from_str(&svg).unwrap();
+            match doc.commands().as_slice() {
+                [SvgDrawCommand::StrokePath { stroke, .. }] => stroke.clone(),
+                other => panic!("expected a single stroke, got {:?}", other),
+            }
+        };
+
+        // Percentages resolve against the normalized viewport diagonal (100)
+        let stroke = stroke_of("10% 5%");
+        assert_eq!(stroke.dash.len(), 2);
+        assert!((stroke.dash[0] - 10.0).abs() < 1e-3, "{:?}", stroke.dash);
+        assert!((stroke.dash[1] - 5.0).abs() < 1e-3, "{:?}", stroke.dash);
+
+        // An all-zero pattern draws a solid line
+        assert!(stroke_of("0 0").dash.is_empty());
+    }
 }
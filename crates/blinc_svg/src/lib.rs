@@ -20,19 +20,28 @@
 //!
 //! // Tessellation mode (legacy)
 //! let svg = SvgDocument::from_file("icon.svg")?;
-//! ctx.draw_svg(&svg, Point::new(10.0, 10.0), 1.0);
+//! svg.render(ctx, 10.0, 10.0, 1.0);
+//!
+//! // Static icons redrawn every frame can reuse their commands
+//! let mut cache = SvgCache::new();
+//! cache.render(ctx, icon_id, &svg, 10.0, 10.0, 1.0);
 //!
 //! // Rasterization mode (recommended for icons)
 //! let rasterized = RasterizedSvg::from_str(svg_str, 64, 64)?;
 //! // Upload rasterized.data() to GPU texture
 //! ```
 
+mod cache;
+mod clip;
 mod document;
 mod error;
 mod path;
 mod rasterize;
 mod style;
+mod text;
 
+pub use cache::{SvgCache, SCALE_EPSILON};
 pub use document::{SvgDocument, SvgDrawCommand};
 pub use error::SvgError;
 pub use rasterize::RasterizedSvg;
+pub use style::ColorOverride;
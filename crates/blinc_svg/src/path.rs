@@ -1,6 +1,6 @@
 //! SVG path conversion to Blinc Path
 
-use blinc_core::{Path, PathCommand, Point};
+use blinc_core::{Affine2D, Path, PathCommand, Point};
 
 /// Convert usvg path data to Blinc Path
 pub fn usvg_path_to_blinc(path_data: &usvg::tiny_skia_path::Path) -> Path {
@@ -35,3 +35,91 @@ pub fn usvg_path_to_blinc(path_data: &usvg::tiny_skia_path::Path) -> Path {
 
     Path::from_commands(commands)
 }
+
+/// Convert a usvg transform to a Blinc affine transform
+pub fn usvg_transform_to_affine(transform: &usvg::Transform) -> Affine2D {
+    Affine2D {
+        elements: [
+            transform.sx,
+            transform.ky,
+            transform.kx,
+            transform.sy,
+            transform.tx,
+            transform.ty,
+        ],
+    }
+}
+
+/// Apply an affine transform to every point of a path
+pub fn transform_path(path: &Path, transform: &Affine2D) -> Path {
+    if *transform == Affine2D::IDENTITY {
+        return path.clone();
+    }
+
+    let transform_point = |p: Point| transform.transform_point(p);
+
+    let new_commands: Vec<PathCommand> = path
+        .commands()
+        .iter()
+        .map(|cmd| match cmd {
+            PathCommand::MoveTo(p) => PathCommand::MoveTo(transform_point(*p)),
+            PathCommand::LineTo(p) => PathCommand::LineTo(transform_point(*p)),
+            PathCommand::QuadTo { control, end } => PathCommand::QuadTo {
+                control: transform_point(*control),
+                end: transform_point(*end),
+            },
+            PathCommand::CubicTo {
+                control1,
+                control2,
+                end,
+            } => PathCommand::CubicTo {
+                control1: transform_point(*control1),
+                control2: transform_point(*control2),
+                end: transform_point(*end),
+            },
+            PathCommand::ArcTo {
+                radii,
+                rotation,
+                large_arc,
+                sweep,
+                end,
+            } => PathCommand::ArcTo {
+                radii: *radii,
+                rotation: *rotation,
+                large_arc: *large_arc,
+                sweep: *sweep,
+                end: transform_point(*end),
+            },
+            PathCommand::Close => PathCommand::Close,
+        })
+        .collect();
+
+    Path::from_commands(new_commands)
+}
+
+/// Collect the paths under a group, transformed by `transform`
+///
+/// Unlike the document tree, clip paths, masks and pattern tiles are
+/// positioned relative to the element using them, so their content is
+/// walked with relative group transforms instead of `abs_transform`.
+pub fn collect_group_paths<'a>(
+    group: &'a usvg::Group,
+    transform: usvg::Transform,
+    out: &mut Vec<(Path, &'a usvg::Path)>,
+) {
+    for child in group.children() {
+        match child {
+            usvg::Node::Group(g) => {
+                collect_group_paths(g, transform.pre_concat(g.transform()), out);
+            }
+            usvg::Node::Path(p) => {
+                let path = usvg_path_to_blinc(p.data());
+                out.push((
+                    transform_path(&path, &usvg_transform_to_affine(&transform)),
+                    p,
+                ));
+            }
+            usvg::Node::Image(_) | usvg::Node::Text(_) => {}
+        }
+    }
+}
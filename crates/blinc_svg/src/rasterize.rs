@@ -42,7 +42,10 @@ impl RasterizedSvg {
         };
 
         // Parse SVG
-        let options = Options::default();
+        let mut options = Options::default();
+        if crate::text::has_text(data) {
+            options.fontdb = crate::text::fontdb();
+        }
         let tree = Tree::from_data(data, &options).map_err(|e| SvgError::Parse(e.to_string()))?;
 
         Self::from_tree(&tree, width, height)
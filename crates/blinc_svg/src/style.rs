@@ -1,6 +1,11 @@
 //! SVG style conversion to Blinc types
 
-use blinc_core::{Brush, Color, Gradient, GradientStop, LineCap, LineJoin, Stroke};
+use blinc_core::{
+    Brush, Color, Gradient, GradientStop, LineCap, LineJoin, PatternBrush, PatternShape, Rect,
+    Stroke,
+};
+
+use crate::path::{collect_group_paths, usvg_transform_to_affine};
 
 /// Convert usvg Paint to Blinc Brush
 pub fn paint_to_brush(paint: &usvg::Paint, opacity: f32) -> Option<Brush> {
@@ -15,14 +20,16 @@ pub fn paint_to_brush(paint: &usvg::Paint, opacity: f32) -> Option<Brush> {
             let stops: Vec<GradientStop> = lg
                 .stops()
                 .iter()
-                .map(|s| GradientStop {
-                    offset: s.offset().get(),
-                    color: Color::rgba(
-                        s.color().red as f32 / 255.0,
-                        s.color().green as f32 / 255.0,
-                        s.color().blue as f32 / 255.0,
-                        s.opacity().get() * opacity,
-                    ),
+                .map(|s| {
+                    GradientStop::new(
+                        s.offset().get(),
+                        Color::rgba(
+                            s.color().red as f32 / 255.0,
+                            s.color().green as f32 / 255.0,
+                            s.color().blue as f32 / 255.0,
+                            s.opacity().get() * opacity,
+                        ),
+                    )
                 })
                 .collect();
 
@@ -37,14 +44,16 @@ pub fn paint_to_brush(paint: &usvg::Paint, opacity: f32) -> Option<Brush> {
             let stops: Vec<GradientStop> = rg
                 .stops()
                 .iter()
-                .map(|s| GradientStop {
-                    offset: s.offset().get(),
-                    color: Color::rgba(
-                        s.color().red as f32 / 255.0,
-                        s.color().green as f32 / 255.0,
-                        s.color().blue as f32 / 255.0,
-                        s.opacity().get() * opacity,
-                    ),
+                .map(|s| {
+                    GradientStop::new(
+                        s.offset().get(),
+                        Color::rgba(
+                            s.color().red as f32 / 255.0,
+                            s.color().green as f32 / 255.0,
+                            s.color().blue as f32 / 255.0,
+                            s.opacity().get() * opacity,
+                        ),
+                    )
                 })
                 .collect();
 
@@ -55,13 +64,35 @@ pub fn paint_to_brush(paint: &usvg::Paint, opacity: f32) -> Option<Brush> {
             );
             Some(Brush::Gradient(gradient))
         }
-        usvg::Paint::Pattern(_) => {
-            // Patterns not yet supported - use fallback color
-            Some(Brush::Solid(Color::rgba(0.5, 0.5, 0.5, opacity)))
-        }
+        usvg::Paint::Pattern(pattern) => Some(Brush::Pattern(pattern_to_brush(pattern, opacity))),
     }
 }
 
+/// Convert a usvg pattern to a Blinc pattern brush
+///
+/// The pattern transform is relative to the filled element; callers place
+/// it in document space (see `SvgDocument::commands`).
+fn pattern_to_brush(pattern: &usvg::Pattern, opacity: f32) -> PatternBrush {
+    let mut paths = Vec::new();
+    collect_group_paths(pattern.root(), usvg::Transform::identity(), &mut paths);
+
+    let tile = paths
+        .into_iter()
+        .filter_map(|(path, p)| {
+            let brush = fill_to_brush(p.fill()?)?;
+            Some(PatternShape { path, brush })
+        })
+        .collect();
+
+    let rect = pattern.rect();
+    PatternBrush::new(
+        Rect::new(rect.x(), rect.y(), rect.width(), rect.height()),
+        tile,
+    )
+    .transform(usvg_transform_to_affine(&pattern.transform()))
+    .opacity(opacity)
+}
+
 /// Convert usvg Fill to Blinc Brush
 pub fn fill_to_brush(fill: &usvg::Fill) -> Option<Brush> {
     paint_to_brush(fill.paint(), fill.opacity().get())
@@ -78,6 +109,7 @@ pub fn stroke_to_blinc(stroke: &usvg::Stroke) -> Option<(Stroke, Brush)> {
     };
 
     let join = match stroke.linejoin() {
+        // Blinc has no clipped miter; a plain miter differs only past the limit
         usvg::LineJoin::Miter | usvg::LineJoin::MiterClip => LineJoin::Miter,
         usvg::LineJoin::Round => LineJoin::Round,
         usvg::LineJoin::Bevel => LineJoin::Bevel,
@@ -86,6 +118,7 @@ pub fn stroke_to_blinc(stroke: &usvg::Stroke) -> Option<(Stroke, Brush)> {
     let mut blinc_stroke = Stroke::new(stroke.width().get() as f32)
         .with_cap(cap)
         .with_join(join);
+    blinc_stroke.miter_limit = stroke.miterlimit().get();
 
     // Handle dash pattern
     if let Some(dasharray) = stroke.dasharray() {
@@ -95,3 +128,65 @@ pub fn stroke_to_blinc(stroke: &usvg::Stroke) -> Option<(Stroke, Brush)> {
 
     Some((blinc_stroke, brush))
 }
+
+/// Recolors the solid paints of a document, e.g. to tint a monochrome icon
+/// to the current theme
+///
+/// Each replaced paint keeps its own alpha, so `fill-opacity` and
+/// semi-transparent shapes survive the recolor. Gradients and patterns are
+/// left alone unless [`flatten_gradients`](Self::flatten_gradients) is set.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorOverride {
+    color: Color,
+    strokes: bool,
+    flatten_gradients: bool,
+}
+
+impl ColorOverride {
+    /// Recolor solid fills with `color`
+    pub fn new(color: Color) -> Self {
+        Self {
+            color,
+            strokes: false,
+            flatten_gradients: false,
+        }
+    }
+
+    /// Recolor solid strokes as well as fills
+    pub fn strokes(mut self, strokes: bool) -> Self {
+        self.strokes = strokes;
+        self
+    }
+
+    /// Replace gradients with the override color, using the alpha of their
+    /// first stop
+    pub fn flatten_gradients(mut self, flatten: bool) -> Self {
+        self.flatten_gradients = flatten;
+        self
+    }
+
+    /// Recolor a fill brush
+    pub(crate) fn fill(&self, brush: Brush) -> Brush {
+        match brush {
+            Brush::Solid(color) => Brush::Solid(self.tinted(color.a)),
+            Brush::Gradient(gradient) if self.flatten_gradients => {
+                let alpha = gradient.stops().first().map_or(1.0, |stop| stop.color.a);
+                Brush::Solid(self.tinted(alpha))
+            }
+            other => other,
+        }
+    }
+
+    /// Recolor a stroke brush, if strokes are overridden
+    pub(crate) fn stroke(&self, brush: Brush) -> Brush {
+        if self.strokes {
+            self.fill(brush)
+        } else {
+            brush
+        }
+    }
+
+    fn tinted(&self, alpha: f32) -> Color {
+        self.color.with_alpha(self.color.a * alpha)
+    }
+}
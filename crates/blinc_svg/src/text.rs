@@ -0,0 +1,195 @@
+//! SVG text conversion to glyph paths
+//!
+//! usvg resolves `<text>` elements into chunks of styled spans. Each span is
+//! shaped with `blinc_text` using the font family, size, weight and style
+//! from the SVG, and every glyph outline becomes a fill (and optionally
+//! stroke) command. Families that aren't installed fall back through the
+//! font registry's generic chain instead of dropping the text.
+
+use std::sync::{Arc, OnceLock};
+
+use blinc_core::Path;
+use blinc_text::{global_font_registry, FontFace, FontRegistry, GenericFont, TextShaper};
+use usvg::fontdb;
+
+use crate::document::{apply_transform, in_document_space, SvgDrawCommand};
+use crate::style::{fill_to_brush, stroke_to_blinc};
+
+/// Font database handed to usvg when a document contains text
+///
+/// usvg drops text nodes it can't find any font for, so it needs the system
+/// fonts to keep them in the tree. Scanning is done once and only for
+/// documents that actually contain text, so icon-only SVGs stay cheap.
+pub(crate) fn fontdb() -> Arc<fontdb::Database> {
+    static FONTDB: OnceLock<Arc<fontdb::Database>> = OnceLock::new();
+    FONTDB
+        .get_or_init(|| {
+            let mut db = fontdb::Database::new();
+            db.load_system_fonts();
+            set_generic_families(&mut db);
+            Arc::new(db)
+        })
+        .clone()
+}
+
+/// Point fontdb's generic families at fonts that are actually installed
+///
+/// fontdb maps them to fixed names (Arial, Times New Roman, ...), and usvg
+/// drops text whose families all resolve to missing fonts.
+fn set_generic_families(db: &mut fontdb::Database) {
+    let installed: Vec<String> = db
+        .faces()
+        .filter_map(|face| face.families.first())
+        .map(|(family, _)| family.clone())
+        .collect();
+    let Some(any) = installed.first().cloned() else {
+        return;
+    };
+    let pick = |candidates: &[&str]| {
+        candidates
+            .iter()
+            .find(|c| installed.iter().any(|family| family == *c))
+            .map_or_else(|| any.clone(), |c| c.to_string())
+    };
+
+    let sans = pick(&["Arial", "Helvetica", "Roboto", "Noto Sans", "DejaVu Sans"]);
+    let serif = pick(&["Times New Roman", "Times", "Noto Serif", "DejaVu Serif"]);
+    let mono = pick(&[
+        "Courier New",
+        "Menlo",
+        "Consolas",
+        "Noto Sans Mono",
+        "DejaVu Sans Mono",
+    ]);
+    db.set_sans_serif_family(sans);
+    db.set_serif_family(serif);
+    db.set_monospace_family(mono);
+}
+
+/// Whether raw SVG data contains a `<text>` element
+pub(crate) fn has_text(data: &[u8]) -> bool {
+    data.windows(5).any(|w| w == b"<text")
+}
+
+/// Convert a usvg text node into glyph draw commands
+pub(crate) fn text_to_commands(text: &usvg::Text, commands: &mut Vec<SvgDrawCommand>) {
+    let registry = global_font_registry();
+    let mut registry = registry.lock().unwrap();
+    let shaper = TextShaper::new();
+    let transform = text.abs_transform();
+
+    // Chunks without an explicit position continue where the previous ended
+    let (mut pen_x, mut pen_y) = (0.0, 0.0);
+
+    for chunk in text.chunks() {
+        let runs: Vec<_> = chunk
+            .spans()
+            .iter()
+            .filter_map(|span| {
+                let face = resolve_face(&mut registry, span.font())?;
+                let shaped = shaper.shape(
+                    &chunk.text()[span.start()..span.end()],
+                    &face,
+                    span.font_size().get(),
+                );
+                Some((span, face, shaped))
+            })
+            .collect();
+
+        let width: f32 = runs
+            .iter()
+            .map(|(span, _, shaped)| {
+                shaped.width_px() + span.letter_spacing() * shaped.glyphs.len() as f32
+            })
+            .sum();
+
+        pen_x = chunk.x().unwrap_or(pen_x);
+        pen_y = chunk.y().unwrap_or(pen_y);
+        pen_x -= match chunk.anchor() {
+            usvg::TextAnchor::Start => 0.0,
+            usvg::TextAnchor::Middle => width / 2.0,
+            usvg::TextAnchor::End => width,
+        };
+
+        for (span, face, shaped) in &runs {
+            let fill = span
+                .fill()
+                .and_then(fill_to_brush)
+                .map(|brush| in_document_space(brush, &transform));
+            let stroke = span
+                .stroke()
+                .and_then(stroke_to_blinc)
+                .map(|(stroke, brush)| (stroke, in_document_space(brush, &transform)));
+
+            for glyph in &shaped.glyphs {
+                let outline = face.glyph_outline(glyph.glyph_id, shaped.font_size);
+                if let Some(outline) = outline.filter(|p| !p.is_empty()) {
+                    let glyph_transform = transform.pre_translate(
+                        pen_x + shaped.scale(glyph.x_offset),
+                        pen_y - shaped.scale(glyph.y_offset),
+                    );
+                    let path = apply_transform(&outline, &glyph_transform);
+                    push_glyph(commands, path, fill.as_ref(), stroke.as_ref());
+                }
+                pen_x += shaped.scale(glyph.x_advance) + span.letter_spacing();
+            }
+        }
+    }
+}
+
+/// Emit the fill and stroke commands for one glyph
+fn push_glyph(
+    commands: &mut Vec<SvgDrawCommand>,
+    path: Path,
+    fill: Option<&blinc_core::Brush>,
+    stroke: Option<&(blinc_core::Stroke, blinc_core::Brush)>,
+) {
+    if let Some(brush) = fill {
+        commands.push(SvgDrawCommand::FillPath {
+            path: path.clone(),
+            brush: brush.clone(),
+        });
+    }
+    if let Some((stroke, brush)) = stroke {
+        commands.push(SvgDrawCommand::StrokePath {
+            path,
+            stroke: stroke.clone(),
+            brush: brush.clone(),
+        });
+    }
+}
+
+/// Find a font face for a span, walking its family list in order
+///
+/// Named families are tried first; the first generic family (or sans-serif
+/// if none is listed) is the fallback.
+fn resolve_face(registry: &mut FontRegistry, font: &usvg::Font) -> Option<Arc<FontFace>> {
+    let weight = font.weight();
+    let italic = !matches!(font.style(), usvg::FontStyle::Normal);
+
+    let mut generic = GenericFont::SansSerif;
+    for family in font.families() {
+        match family {
+            usvg::FontFamily::Named(name) => {
+                if let Ok(face) = registry.load_font_with_style(name, weight, italic) {
+                    return Some(face);
+                }
+            }
+            usvg::FontFamily::Serif => {
+                generic = GenericFont::Serif;
+                break;
+            }
+            usvg::FontFamily::Monospace => {
+                generic = GenericFont::Monospace;
+                break;
+            }
+            usvg::FontFamily::SansSerif | usvg::FontFamily::Cursive | usvg::FontFamily::Fantasy => {
+                break;
+            }
+        }
+    }
+
+    registry
+        .load_with_fallback_styled(None, generic, weight, italic)
+        .ok()
+}
@@ -5,11 +5,12 @@
 //! - Offscreen rendering to PNG files
 //! - Reference image comparison
 
+use crate::interaction::Interaction;
 use anyhow::{Context, Result};
 use blinc_core::{Rect, Size};
 use blinc_gpu::{
-    GpuGlassPrimitive, GpuGlyph, GpuPaintContext, GpuRenderer, PrimitiveBatch, RendererConfig,
-    TextRenderingContext,
+    ColorSpace, GpuGlassPrimitive, GpuGlyph, GpuPaintContext, GpuRenderer, PrimitiveBatch,
+    RendererConfig, TextRenderingContext,
 };
 use blinc_layout::div::FontFamily;
 use blinc_layout::prelude::*;
@@ -76,6 +77,8 @@ pub struct TestContext<'a> {
     pub name: String,
     /// Output directory for reference images
     pub output_dir: PathBuf,
+    /// UI mounted for scripted interaction (see [`TestContext::mount`])
+    pub(crate) interaction: Option<Interaction>,
 }
 
 impl<'a> TestContext<'a> {
@@ -88,6 +91,7 @@ impl<'a> TestContext<'a> {
             size: Size::new(width, height),
             name: name.to_string(),
             output_dir: PathBuf::from("test_output"),
+            interaction: None,
         }
     }
 
@@ -371,6 +375,9 @@ impl TestHarness {
             sample_count: config.sample_count,
             texture_format: Some(wgpu::TextureFormat::Rgba8Unorm),
             unified_text_rendering: true,
+            temporal_aa: false,
+            glyph_atlas: None,
+            color_space: ColorSpace::Srgb,
         };
 
         let renderer = pollster::block_on(GpuRenderer::new(renderer_config))
@@ -0,0 +1,311 @@
+//! Scripted interactions for test cases
+//!
+//! Lets a test mount a UI, drive it with synthetic pointer and keyboard
+//! input, step frames with a fixed time step and then assert on (or render)
+//! the resulting frame:
+//!
+//! ```ignore
+//! suite.add("counter_click", |ctx| {
+//!     ctx.mount(move || counter_ui(&count));
+//!     ctx.click_at(50.0, 20.0);
+//!     ctx.advance_frames(1);
+//! });
+//! ```
+//!
+//! Input goes through a real [`EventRouter`], so hit testing, hover tracking
+//! and event bubbling behave as in a window.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::OnceLock;
+
+use blinc_animation::{AnimationScheduler, SchedulerHandle};
+use blinc_layout::prelude::*;
+
+use crate::harness::TestContext;
+
+/// Time step of one frame in [`TestContext::advance_frames`] (60 fps)
+pub const FRAME_DT: f32 = 1.0 / 60.0;
+
+/// Scheduler installed as the global one when nothing else installed one
+///
+/// The global scheduler handle can only be set once per process, so the
+/// scheduler lives for the whole test run.
+static SCHEDULER: OnceLock<Option<AnimationScheduler>> = OnceLock::new();
+
+/// The global animation scheduler, which animations register with
+///
+/// Installs one if there is none yet. Frames tick it by hand, so it must not
+/// also be ticked by a background thread for results to be deterministic.
+///
+/// # Panics
+///
+/// Panics if the installed global scheduler has been dropped.
+fn scheduler() -> SchedulerHandle {
+    SCHEDULER.get_or_init(|| {
+        (!blinc_animation::is_scheduler_initialized()).then(|| {
+            let scheduler = AnimationScheduler::new();
+            blinc_animation::set_global_scheduler(scheduler.handle());
+            scheduler
+        })
+    });
+    let handle = blinc_animation::get_scheduler();
+    assert!(
+        handle.is_alive(),
+        "the global animation scheduler was dropped, so interactive tests can't step animations"
+    );
+    handle
+}
+
+/// A mounted UI driven by synthetic input
+pub(crate) struct Interaction {
+    build: Box<dyn Fn() -> RenderTree>,
+    tree: RenderTree,
+    router: EventRouter,
+    events: Rc<RefCell<Vec<(LayoutNodeId, u32)>>>,
+    /// Virtual clock in milliseconds, advanced by [`FRAME_DT`] per frame
+    time_ms: f64,
+}
+
+impl<'a> TestContext<'a> {
+    /// Mount a UI for interaction
+    ///
+    /// `build` is called again whenever the UI requests a rebuild (e.g. from
+    /// a click handler via `blinc_layout::widgets::request_rebuild()`).
+    pub fn mount<F, E>(&mut self, build: F)
+    where
+        F: Fn() -> E + 'static,
+        E: ElementBuilder,
+    {
+        let build: Box<dyn Fn() -> RenderTree> =
+            Box::new(move || RenderTree::from_element(&build()));
+        let mut tree = build();
+        tree.compute_layout(self.size.width, self.size.height);
+
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let mut router = EventRouter::new();
+        let sink = Rc::clone(&events);
+        router.set_event_callback(move |node, event_type| {
+            sink.borrow_mut().push((node, event_type));
+        });
+
+        self.interaction = Some(Interaction {
+            build,
+            tree,
+            router,
+            events,
+            time_ms: 0.0,
+        });
+        self.render_mounted();
+    }
+
+    /// The render tree of the mounted UI
+    ///
+    /// # Panics
+    ///
+    /// Panics if nothing has been mounted.
+    pub fn tree(&self) -> &RenderTree {
+        &self.interaction().tree
+    }
+
+    /// Move the pointer to (x, y), emitting enter/leave/move events
+    pub fn send_pointer_move(&mut self, x: f32, y: f32) {
+        let state = self.interaction_mut();
+        state.router.on_mouse_move(&state.tree, x, y);
+        state.dispatch_pending(x, y);
+    }
+
+    /// Press and release the left button at (x, y)
+    pub fn click_at(&mut self, x: f32, y: f32) {
+        self.send_pointer_move(x, y);
+        let state = self.interaction_mut();
+        state
+            .router
+            .on_mouse_down(&state.tree, x, y, MouseButton::Left);
+        state.dispatch_pending(x, y);
+        state
+            .router
+            .on_mouse_up(&state.tree, x, y, MouseButton::Left);
+        state.dispatch_pending(x, y);
+    }
+
+    /// Type text into the focused text input, one character at a time
+    pub fn type_text(&mut self, text: &str) {
+        let state = self.interaction_mut();
+        for ch in text.chars() {
+            state
+                .tree
+                .broadcast_text_input_event(ch, ch.is_uppercase(), false, false, false);
+        }
+    }
+
+    /// Step `n` frames of [`FRAME_DT`] each, then re-render the mounted UI
+    ///
+    /// Each frame ticks the global animation scheduler and scroll physics
+    /// with the fixed time step, refreshes animating statefuls, applies
+    /// stateful updates and rebuilds the UI if requested, so
+    /// results don't depend on wall-clock time. The paint contexts are
+    /// cleared and hold the last frame afterwards.
+    pub fn advance_frames(&mut self, n: u32) {
+        let (width, height) = (self.size.width, self.size.height);
+        let state = self.interaction_mut();
+        for _ in 0..n {
+            state.time_ms += FRAME_DT as f64 * 1000.0;
+            scheduler().tick_with_dt(FRAME_DT);
+            blinc_layout::check_stateful_animations();
+            state.tree.tick_scroll_physics(state.time_ms as u64);
+
+            for (node, props) in blinc_layout::take_pending_prop_updates() {
+                state.tree.update_render_props(node, |p| *p = props);
+            }
+            let mut needs_layout = state.tree.process_pending_subtree_rebuilds();
+
+            // Non-short-circuiting so the global flag is always consumed
+            if state.tree.needs_rebuild() | blinc_layout::widgets::take_needs_rebuild() {
+                state.tree = (state.build)();
+                needs_layout = true;
+            }
+            if needs_layout {
+                state.tree.compute_layout(width, height);
+            }
+        }
+        self.render_mounted();
+    }
+
+    fn render_mounted(&mut self) {
+        self.clear();
+        let state = self
+            .interaction
+            .take()
+            .expect("no UI mounted, call mount() first");
+        self.render_layout(&state.tree);
+        self.interaction = Some(state);
+    }
+
+    fn interaction(&self) -> &Interaction {
+        self.interaction
+            .as_ref()
+            .expect("no UI mounted, call mount() first")
+    }
+
+    fn interaction_mut(&mut self) -> &mut Interaction {
+        self.interaction
+            .as_mut()
+            .expect("no UI mounted, call mount() first")
+    }
+}
+
+impl Interaction {
+    /// Deliver the events the router emitted to the tree's handlers
+    fn dispatch_pending(&mut self, x: f32, y: f32) {
+        self.tree.set_click_count(self.router.click_count());
+        let events: Vec<_> = self.events.borrow_mut().drain(..).collect();
+        for (node, event_type) in events {
+            let (bx, by, bw, bh) = self
+                .router
+                .get_node_bounds(node)
+                .unwrap_or((0.0, 0.0, 0.0, 0.0));
+            let (dx, dy) = self.router.drag_delta();
+            self.tree.dispatch_event_full(
+                node,
+                event_type,
+                x,
+                y,
+                x - bx,
+                y - by,
+                bx,
+                by,
+                bw,
+                bh,
+                dx,
+                dy,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use blinc_core::Color;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    fn counter(count: &Arc<AtomicU32>) -> Div {
+        let clicks = Arc::clone(count);
+        div()
+            .w(200.0)
+            .h(100.0)
+            .flex_col()
+            .child(div().w(100.0).h(40.0).bg(Color::BLUE).on_click(move |_| {
+                clicks.fetch_add(1, Ordering::SeqCst);
+                blinc_layout::widgets::request_rebuild();
+            }))
+            .child(text(format!("Count: {}", count.load(Ordering::SeqCst))).size(14.0))
+    }
+
+    fn displayed(ctx: &TestContext) -> Vec<String> {
+        ctx.text_commands.iter().map(|t| t.text.clone()).collect()
+    }
+
+    #[test]
+    fn test_click_counter_increments_after_frame() {
+        let count = Arc::new(AtomicU32::new(0));
+        let mut ctx = TestContext::new("counter", 200.0, 100.0);
+        let ui_count = Arc::clone(&count);
+        ctx.mount(move || counter(&ui_count));
+        assert_eq!(displayed(&ctx), ["Count: 0"]);
+
+        ctx.click_at(50.0, 20.0);
+        ctx.advance_frames(1);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+        assert_eq!(displayed(&ctx), ["Count: 1"]);
+
+        // Clicking outside the button does nothing
+        ctx.click_at(150.0, 20.0);
+        ctx.advance_frames(1);
+        assert_eq!(displayed(&ctx), ["Count: 1"]);
+    }
+
+    #[test]
+    fn test_hover_transition_interpolates_background() {
+        let mut ctx = TestContext::new("hover_transition", 200.0, 100.0);
+        ctx.mount(|| {
+            div().w(200.0).h(100.0).child(
+                stateful_button()
+                    .w(100.0)
+                    .h(40.0)
+                    .transitions(VisualTransitions::new().background(SpringConfig::stiff()))
+                    .on_state(|state, div| {
+                        let bg = match state {
+                            ButtonState::Hovered => Color::RED,
+                            _ => Color::BLUE,
+                        };
+                        *div = div.swap().bg(bg);
+                    }),
+            )
+        });
+        let button_color = |ctx: &TestContext| {
+            ctx.batch()
+                .primitives
+                .iter()
+                .find(|p| p.bounds == [0.0, 0.0, 100.0, 40.0])
+                .map(|p| p.color)
+                .expect("button not drawn")
+        };
+        assert_eq!(button_color(&ctx)[0], Color::BLUE.r);
+
+        // One frame after hovering, the background is between blue and red
+        ctx.send_pointer_move(50.0, 20.0);
+        ctx.advance_frames(1);
+        let [r, _, b, _] = button_color(&ctx);
+        assert!(r > Color::BLUE.r && r < Color::RED.r, "red channel {r}");
+        assert!(b < Color::BLUE.b && b > Color::RED.b, "blue channel {b}");
+
+        // The spring settles on red
+        ctx.advance_frames(120);
+        let [r, _, b, _] = button_color(&ctx);
+        assert!((r - Color::RED.r).abs() < 0.01, "red channel {r}");
+        assert!((b - Color::RED.b).abs() < 0.01, "blue channel {b}");
+    }
+}
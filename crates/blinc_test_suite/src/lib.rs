@@ -10,11 +10,14 @@
 //! # Test Categories
 //!
 //! - **Headless Tests**: Run without display, render to textures
+//! - **Interaction Tests**: Script pointer/keyboard input and step frames
+//!   deterministically before rendering
 //! - **Visual Regression**: Compare rendered output to reference images
 //! - **Interactive Tests**: Manual testing with live windows
 //! - **Benchmarks**: Performance testing of rendering pipeline
 
 pub mod harness;
+pub mod interaction;
 pub mod runner;
 pub mod tests;
 
@@ -4,7 +4,7 @@
 
 use crate::runner::TestSuite;
 use blinc_core::{DrawContext, Rect};
-use blinc_svg::{SvgDocument, SvgDrawCommand};
+use blinc_svg::SvgDocument;
 
 /// Create the SVG test suite
 pub fn suite() -> TestSuite {
@@ -384,21 +384,7 @@ pub fn suite() -> TestSuite {
 fn render_svg(ctx: &mut dyn DrawContext, svg_str: &str) {
     match SvgDocument::from_str(svg_str) {
         Ok(doc) => {
-            let commands = doc.commands();
-            for cmd in commands {
-                match cmd {
-                    SvgDrawCommand::FillPath { path, brush } => {
-                        ctx.fill_path(&path, brush);
-                    }
-                    SvgDrawCommand::StrokePath {
-                        path,
-                        stroke,
-                        brush,
-                    } => {
-                        ctx.stroke_path(&path, &stroke, brush);
-                    }
-                }
-            }
+            doc.render(ctx, 0.0, 0.0, 1.0);
         }
         Err(e) => {
             tracing::error!("Failed to parse SVG: {}", e);
@@ -3,6 +3,7 @@
 //! Provides font parsing via ttf-parser and font metric extraction.
 
 use crate::{Result, TextError};
+use blinc_core::{PathCommand, Point};
 use std::sync::Arc;
 
 /// Font data that can be either owned or memory-mapped.
@@ -304,6 +305,62 @@ impl FontFace {
         self.as_ttf_face()
             .and_then(|face| face.glyph_hor_advance(ttf_parser::GlyphId(glyph_id)))
     }
+
+    /// Get a glyph's outline as a vector path at the given font size
+    ///
+    /// The path is in pixels with the origin on the baseline and y pointing
+    /// down. Returns `None` for glyphs without an outline (e.g. spaces).
+    pub fn glyph_outline(&self, glyph_id: u16, font_size: f32) -> Option<blinc_core::Path> {
+        let face = self.as_ttf_face()?;
+        let mut builder = OutlinePathBuilder {
+            scale: font_size / self.metrics.units_per_em as f32,
+            commands: Vec::new(),
+        };
+        face.outline_glyph(ttf_parser::GlyphId(glyph_id), &mut builder)?;
+        Some(blinc_core::Path::from_commands(builder.commands))
+    }
+}
+
+/// Collects a ttf-parser outline into path commands, scaled to pixels
+struct OutlinePathBuilder {
+    scale: f32,
+    commands: Vec<PathCommand>,
+}
+
+impl OutlinePathBuilder {
+    /// Scale a point from font units to pixels, flipping y to point down
+    fn point(&self, x: f32, y: f32) -> Point {
+        Point::new(x * self.scale, -y * self.scale)
+    }
+}
+
+impl ttf_parser::OutlineBuilder for OutlinePathBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.commands.push(PathCommand::MoveTo(self.point(x, y)));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.commands.push(PathCommand::LineTo(self.point(x, y)));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.commands.push(PathCommand::QuadTo {
+            control: self.point(x1, y1),
+            end: self.point(x, y),
+        });
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.commands.push(PathCommand::CubicTo {
+            control1: self.point(x1, y1),
+            control2: self.point(x2, y2),
+            end: self.point(x, y),
+        });
+    }
+
+    fn close(&mut self) {
+        self.commands.push(PathCommand::Close);
+    }
 }
 
 impl std::fmt::Debug for FontFace {
@@ -2,7 +2,14 @@
 //!
 //! Handles line breaking, text measurement, and multi-line layout.
 
+use std::sync::Arc;
+
+use rustybuzz::ttf_parser::Tag;
+use rustybuzz::Feature;
+use unicode_bidi::{BidiInfo, Level};
+
 use crate::font::FontFace;
+use crate::linebreak::{break_opportunities, BreakDictionary};
 use crate::shaper::{ShapedGlyph, ShapedText, TextShaper};
 
 /// Text alignment options (horizontal)
@@ -29,7 +36,8 @@ pub enum TextAnchor {
 /// Line break mode
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum LineBreakMode {
-    /// Break at word boundaries
+    /// Break at Unicode line break opportunities (between words, between
+    /// CJK ideographs)
     #[default]
     Word,
     /// Break at character boundaries
@@ -38,6 +46,31 @@ pub enum LineBreakMode {
     None,
 }
 
+/// Distance between tab stops
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TabWidth {
+    /// Multiple of the font's space advance
+    Spaces(f32),
+    /// Fixed width in pixels
+    Pixels(f32),
+}
+
+impl Default for TabWidth {
+    fn default() -> Self {
+        TabWidth::Spaces(4.0)
+    }
+}
+
+impl TabWidth {
+    /// Tab stop interval in pixels for a font with the given space advance
+    pub fn to_pixels(self, space_advance: f32) -> f32 {
+        match self {
+            TabWidth::Spaces(spaces) => spaces * space_advance,
+            TabWidth::Pixels(px) => px,
+        }
+    }
+}
+
 /// Options for text layout
 #[derive(Debug, Clone)]
 pub struct LayoutOptions {
@@ -51,8 +84,21 @@ pub struct LayoutOptions {
     pub line_break: LineBreakMode,
     /// Line height multiplier (1.0 = default)
     pub line_height: f32,
-    /// Letter spacing adjustment in pixels
+    /// Letter spacing adjustment in pixels, added between glyphs (not after
+    /// the last glyph of a line)
     pub letter_spacing: f32,
+    /// Apply standard and contextual ligatures (OpenType `liga`, `clig`)
+    pub ligatures: bool,
+    /// Apply font kerning (OpenType `kern`)
+    pub kerning: bool,
+    /// Tab stop interval - tabs advance to the next multiple of this from
+    /// the line start
+    pub tab_width: TabWidth,
+    /// Keep leading whitespace on every line when wrapping (`pre()`/`code()`)
+    pub preformatted: bool,
+    /// Word list for breaking Thai, Lao, Khmer and other scripts written
+    /// without spaces (None = those runs only break where UAX #14 allows)
+    pub break_dictionary: Option<Arc<BreakDictionary>>,
 }
 
 impl Default for LayoutOptions {
@@ -64,7 +110,45 @@ impl Default for LayoutOptions {
             line_break: LineBreakMode::Word,
             line_height: 1.2,
             letter_spacing: 0.0,
+            ligatures: true,
+            kerning: true,
+            tab_width: TabWidth::default(),
+            preformatted: false,
+            break_dictionary: None,
+        }
+    }
+}
+
+impl LayoutOptions {
+    /// Enable or disable ligatures (e.g. "fi", or "->" in some code fonts)
+    pub fn ligatures(mut self, enabled: bool) -> Self {
+        self.ligatures = enabled;
+        self
+    }
+
+    /// Enable or disable font kerning
+    pub fn kerning(mut self, enabled: bool) -> Self {
+        self.kerning = enabled;
+        self
+    }
+
+    /// Set tracking: extra pixels between glyphs (negative tightens)
+    pub fn letter_spacing(mut self, px: f32) -> Self {
+        self.letter_spacing = px;
+        self
+    }
+
+    /// OpenType features overriding the shaper's defaults
+    pub fn shaping_features(&self) -> Vec<Feature> {
+        let mut features = Vec::new();
+        if !self.ligatures {
+            features.push(Feature::new(Tag::from_bytes(b"liga"), 0, ..));
+            features.push(Feature::new(Tag::from_bytes(b"clig"), 0, ..));
         }
+        if !self.kerning {
+            features.push(Feature::new(Tag::from_bytes(b"kern"), 0, ..));
+        }
+        features
     }
 }
 
@@ -79,12 +163,17 @@ pub struct PositionedGlyph {
     pub y: f32,
     /// Character this glyph represents
     pub codepoint: char,
+    /// Byte offset of the glyph's cluster in the source text (logical order,
+    /// for editing and selection)
+    pub logical_index: u32,
+    /// Whether the glyph belongs to a right-to-left run
+    pub rtl: bool,
 }
 
 /// A line of positioned glyphs
 #[derive(Debug, Clone)]
 pub struct LayoutLine {
-    /// Glyphs in this line
+    /// Glyphs in this line, in visual (left-to-right display) order
     pub glyphs: Vec<PositionedGlyph>,
     /// Line width in pixels
     pub width: f32,
@@ -150,18 +239,26 @@ impl TextLayoutEngine {
         }
 
         let ascender = metrics.ascender_px(font_size);
+        let space_advance = font
+            .glyph_id(' ')
+            .and_then(|id| font.glyph_advance(id))
+            .map(|advance| advance as f32 * font_size / metrics.units_per_em as f32)
+            .unwrap_or(font_size * 0.25);
+        let tab_stop = options.tab_width.to_pixels(space_advance).max(1.0);
 
         // Check for explicit newlines - these are always respected regardless of wrap mode
         let has_newlines = text.contains('\n');
 
         // Shape the entire text first
-        let shaped = self.shaper.shape(text, font, font_size);
+        let shaped =
+            self.shaper
+                .shape_with_features(text, font, font_size, &options.shaping_features());
 
         // If no wrapping AND no explicit newlines, return single line
         if (options.max_width.is_none() || options.line_break == LineBreakMode::None)
             && !has_newlines
         {
-            let mut line = self.create_line(&shaped, 0.0, ascender, options);
+            let mut line = self.create_line(&shaped, 0.0, ascender, options, tab_stop);
             let width = line.width;
 
             // Apply alignment if max_width is set
@@ -197,13 +294,14 @@ impl TextLayoutEngine {
                 ascender,
                 line_height,
                 options,
+                tab_stop,
             );
         }
 
         let max_width = options.max_width.unwrap();
 
         // Break into lines
-        let lines = self.break_lines(text, &shaped, font, font_size, max_width, options);
+        let lines = self.break_lines(text, &shaped, max_width, options, tab_stop);
 
         // Position lines
         let mut positioned_lines = Vec::with_capacity(lines.len());
@@ -218,7 +316,7 @@ impl TextLayoutEngine {
                 units_per_em: metrics.units_per_em,
             };
 
-            let line = self.create_line(&shaped_line, 0.0, y, options);
+            let line = self.create_line(&shaped_line, 0.0, y, options, tab_stop);
             max_width_found = max_width_found.max(line.width);
             positioned_lines.push(line);
             y += line_height;
@@ -249,6 +347,7 @@ impl TextLayoutEngine {
     }
 
     /// Layout text that contains explicit newlines but no word wrapping
+    #[allow(clippy::too_many_arguments)]
     fn layout_with_newlines_only(
         &self,
         text: &str,
@@ -258,6 +357,7 @@ impl TextLayoutEngine {
         ascender: f32,
         line_height: f32,
         options: &LayoutOptions,
+        tab_stop: f32,
     ) -> TextLayout {
         let mut lines = Vec::new();
         let mut current_line: Vec<ShapedGlyph> = Vec::new();
@@ -296,7 +396,7 @@ impl TextLayoutEngine {
                 units_per_em: metrics_units_per_em,
             };
 
-            let line = self.create_line(&shaped_line, 0.0, y, options);
+            let line = self.create_line(&shaped_line, 0.0, y, options, tab_stop);
             max_width_found = max_width_found.max(line.width);
             positioned_lines.push(line);
             y += line_height;
@@ -333,34 +433,98 @@ impl TextLayoutEngine {
         }
     }
 
+    /// Advance of a glyph whose pen position is `x` from the line start
+    ///
+    /// Tabs jump to the next absolute tab stop, so text after a tab lines up
+    /// regardless of the proportional glyphs before it.
+    fn glyph_advance(
+        shaped: &ShapedText,
+        glyph: &ShapedGlyph,
+        x: f32,
+        options: &LayoutOptions,
+        tab_stop: f32,
+    ) -> f32 {
+        if glyph.codepoint == '\t' {
+            // Small epsilon so a pen sitting on a stop (up to float error)
+            // still advances a full stop
+            ((x + 0.001) / tab_stop).floor() * tab_stop + tab_stop - x
+        } else {
+            shaped.scale(glyph.x_advance) + Self::letter_spacing_after(glyph, options)
+        }
+    }
+
+    /// Letter spacing included in a glyph's advance
+    ///
+    /// Trailing on the last glyph of a line, where it isn't part of the
+    /// line's width. Zero-advance glyphs (combining marks) stay on their base.
+    fn letter_spacing_after(glyph: &ShapedGlyph, options: &LayoutOptions) -> f32 {
+        if glyph.codepoint == '\t' || glyph.x_advance == 0 {
+            0.0
+        } else {
+            options.letter_spacing
+        }
+    }
+
+    /// Width of a run of glyphs starting at the line start
+    fn run_width(
+        shaped: &ShapedText,
+        glyphs: &[ShapedGlyph],
+        options: &LayoutOptions,
+        tab_stop: f32,
+    ) -> f32 {
+        glyphs.iter().fold(0.0, |x, g| {
+            x + Self::glyph_advance(shaped, g, x, options, tab_stop)
+        })
+    }
+
+    /// Display order of a line's logically ordered glyphs (UBA rule L2)
+    fn visual_order(glyphs: &[ShapedGlyph]) -> Vec<usize> {
+        if glyphs.iter().all(|g| g.bidi_level == 0) {
+            return (0..glyphs.len()).collect();
+        }
+        let levels: Vec<Level> = glyphs
+            .iter()
+            .map(|g| Level::new(g.bidi_level).unwrap_or_else(|_| Level::ltr()))
+            .collect();
+        BidiInfo::reorder_visual(&levels)
+    }
+
     /// Create a layout line from shaped glyphs
+    ///
+    /// Glyphs arrive in logical order and are placed in visual order.
     fn create_line(
         &self,
         shaped: &ShapedText,
         start_x: f32,
         baseline_y: f32,
         options: &LayoutOptions,
+        tab_stop: f32,
     ) -> LayoutLine {
         let mut glyphs = Vec::with_capacity(shaped.glyphs.len());
         let mut x = start_x;
+        let mut trailing_spacing = 0.0;
 
-        for glyph in &shaped.glyphs {
+        for index in Self::visual_order(&shaped.glyphs) {
+            let glyph = &shaped.glyphs[index];
             let x_offset = shaped.scale(glyph.x_offset);
-            let advance = shaped.scale(glyph.x_advance) + options.letter_spacing;
+            let advance = Self::glyph_advance(shaped, glyph, x - start_x, options, tab_stop);
 
             glyphs.push(PositionedGlyph {
                 glyph_id: glyph.glyph_id,
                 x: x + x_offset,
                 y: baseline_y,
                 codepoint: glyph.codepoint,
+                logical_index: glyph.cluster,
+                rtl: glyph.bidi_level % 2 == 1,
             });
 
             x += advance;
+            trailing_spacing = Self::letter_spacing_after(glyph, options);
         }
 
         LayoutLine {
             glyphs,
-            width: x - start_x,
+            width: x - start_x - trailing_spacing,
             baseline_y,
         }
     }
@@ -370,24 +534,22 @@ impl TextLayoutEngine {
         &self,
         text: &str,
         shaped: &ShapedText,
-        _font: &FontFace,
-        _font_size: f32,
         max_width: f32,
         options: &LayoutOptions,
+        tab_stop: f32,
     ) -> Vec<Vec<ShapedGlyph>> {
+        // Leading whitespace is dropped from lines unless preformatted
+        let skip_leading = |line: &[ShapedGlyph], c: char| {
+            !options.preformatted && line.is_empty() && c.is_whitespace()
+        };
         let mut lines = Vec::new();
         let mut current_line: Vec<ShapedGlyph> = Vec::new();
         let mut line_width = 0.0f32;
 
-        // Find word boundaries (whitespace positions)
-        let word_breaks: Vec<usize> = text
-            .char_indices()
-            .filter(|(_, c)| c.is_whitespace())
-            .map(|(i, _)| i)
-            .collect();
+        // Byte offsets where a line may start (Unicode line breaking rules)
+        let break_before = break_opportunities(text, options.break_dictionary.as_deref());
 
         let mut last_word_end = 0;
-        let mut last_word_width = 0.0f32;
 
         for glyph in shaped.glyphs.iter() {
             // Handle explicit newline - always force a line break
@@ -395,17 +557,28 @@ impl TextLayoutEngine {
                 lines.push(std::mem::take(&mut current_line));
                 line_width = 0.0;
                 last_word_end = 0;
-                last_word_width = 0.0;
                 continue; // Don't include the newline glyph itself
             }
 
-            let advance = shaped.scale(glyph.x_advance) + options.letter_spacing;
+            // Mark a break opportunity before this glyph. Glyphs sharing a
+            // cluster (ligature parts, marks) are never split.
+            let starts_cluster = current_line
+                .last()
+                .is_some_and(|prev| prev.cluster != glyph.cluster);
+            if starts_cluster
+                && break_before
+                    .binary_search(&(glyph.cluster as usize))
+                    .is_ok()
+            {
+                last_word_end = current_line.len();
+            }
 
-            // Check if this is a word boundary (at a whitespace character)
-            let is_word_break = word_breaks.contains(&(glyph.cluster as usize));
+            let advance = Self::glyph_advance(shaped, glyph, line_width, options, tab_stop);
 
-            // Check if adding this glyph would overflow the line
-            if line_width + advance > max_width && !current_line.is_empty() {
+            // Check if adding this glyph would overflow the line (its
+            // trailing letter spacing doesn't count at the end of a line)
+            let fitted = line_width + advance - Self::letter_spacing_after(glyph, options);
+            if fitted > max_width && !current_line.is_empty() {
                 let mut broke_line = false;
                 match options.line_break {
                     LineBreakMode::Word => {
@@ -418,26 +591,21 @@ impl TextLayoutEngine {
 
                             // Start new line with remaining glyphs (skip leading whitespace)
                             for g in remaining {
-                                if current_line.is_empty() && g.codepoint.is_whitespace() {
+                                if skip_leading(&current_line, g.codepoint) {
                                     continue; // Skip leading whitespace
                                 }
                                 current_line.push(g);
                             }
 
                             // Recalculate line width
-                            line_width = current_line
-                                .iter()
-                                .map(|g| shaped.scale(g.x_advance) + options.letter_spacing)
-                                .sum();
+                            line_width = Self::run_width(shaped, &current_line, options, tab_stop);
                             last_word_end = 0;
-                            last_word_width = 0.0;
                             broke_line = true;
                         } else {
                             // No word boundary found - break at current position (character break)
                             lines.push(std::mem::take(&mut current_line));
                             line_width = 0.0;
                             last_word_end = 0;
-                            last_word_width = 0.0;
                             broke_line = true;
                         }
                     }
@@ -446,7 +614,6 @@ impl TextLayoutEngine {
                         lines.push(std::mem::take(&mut current_line));
                         line_width = 0.0;
                         last_word_end = 0;
-                        last_word_width = 0.0;
                         broke_line = true;
                     }
                     LineBreakMode::None => {
@@ -457,40 +624,26 @@ impl TextLayoutEngine {
                 // After breaking, we still need to add the current glyph (unless it's whitespace at line start)
                 if broke_line {
                     // Skip leading whitespace on new lines
-                    if current_line.is_empty() && glyph.codepoint.is_whitespace() {
+                    if skip_leading(&current_line, glyph.codepoint) {
                         continue;
                     }
 
-                    // Add the current glyph that triggered the overflow
+                    // Add the current glyph that triggered the overflow (a
+                    // tab's advance depends on where the new line left off)
+                    line_width += Self::glyph_advance(shaped, glyph, line_width, options, tab_stop);
                     current_line.push(*glyph);
-                    line_width += advance;
-
-                    // Update word boundary if this glyph is a word break
-                    if is_word_break {
-                        last_word_end = current_line.len();
-                        last_word_width = line_width;
-                    }
                     continue; // Move to next glyph
                 }
             }
 
             // Skip leading whitespace on new lines
-            if current_line.is_empty() && glyph.codepoint.is_whitespace() {
+            if skip_leading(&current_line, glyph.codepoint) {
                 continue;
             }
 
             // Add glyph to current line
             current_line.push(*glyph);
             line_width += advance;
-
-            // Update word boundary tracking AFTER adding the glyph
-            // This way, when we break at last_word_end, all content up to and including
-            // the space is on the current line, and remaining content goes to next line
-            if is_word_break {
-                // Mark position AFTER this whitespace as potential break point
-                last_word_end = current_line.len();
-                last_word_width = line_width;
-            }
         }
 
         // Add remaining line
@@ -558,6 +711,7 @@ mod tests {
                 x_offset: 0,
                 y_offset: 0,
                 codepoint: c,
+                bidi_level: 0,
             });
         }
 
@@ -794,6 +948,158 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_tab_advances_to_absolute_stop() {
+        let engine = TextLayoutEngine::new();
+        let options = LayoutOptions {
+            line_break: LineBreakMode::None,
+            tab_width: TabWidth::Spaces(4.0),
+            ..Default::default()
+        };
+        // Mock glyphs: 10px characters, ~5px space -> 4-space stops every ~20px
+        let tab_stop = options.tab_width.to_pixels(313.0 * 16.0 / 1000.0);
+
+        // "b" lands on the first stop whatever the width before the tab
+        for text in ["a\tb", "\tb", "a \tb"] {
+            let shaped = create_mock_shaped_text(text);
+            let line = engine.create_line(&shaped, 0.0, 0.0, &options, tab_stop);
+            let b = line.glyphs.iter().find(|g| g.codepoint == 'b').unwrap();
+            assert!((b.x - tab_stop).abs() < 0.01, "{text:?}: b at {}", b.x);
+        }
+
+        // Text already past the first stop moves on to the next one
+        let shaped = create_mock_shaped_text("abcdefgh\tb");
+        let line = engine.create_line(&shaped, 0.0, 0.0, &options, tab_stop);
+        let b = line.glyphs.last().unwrap();
+        assert!((b.x - tab_stop * 4.0).abs() < 0.01, "b at {}", b.x);
+    }
+
+    #[test]
+    fn test_letter_spacing_not_after_last_glyph() {
+        let engine = TextLayoutEngine::new();
+        let shaped = create_mock_shaped_text("abc");
+        let width = |letter_spacing| {
+            let options = LayoutOptions::default().letter_spacing(letter_spacing);
+            engine.create_line(&shaped, 0.0, 0.0, &options, 20.0).width
+        };
+        assert!((width(2.0) - width(0.0) - 4.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_ligatures_and_letter_spacing_with_system_font() {
+        use crate::font::FontFace;
+
+        let Some(font) = [
+            "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf",
+            "/System/Library/Fonts/Supplemental/Arial.ttf",
+            "C:\\Windows\\Fonts\\arial.ttf",
+        ]
+        .iter()
+        .find_map(|path| FontFace::from_file(std::path::Path::new(path)).ok()) else {
+            // No known system font to shape with
+            return;
+        };
+
+        let engine = TextLayoutEngine::new();
+        let glyphs = |options: &LayoutOptions| {
+            let layout = engine.layout("fi", &font, 32.0, options);
+            let codepoints: Vec<char> = layout.glyphs().map(|g| g.codepoint).collect();
+            (codepoints, layout.width)
+        };
+
+        let (ligated, width) = glyphs(&LayoutOptions::default());
+        let (separate, _) = glyphs(&LayoutOptions::default().ligatures(false));
+        assert_eq!(separate, ['f', 'i']);
+        assert!(ligated.len() <= separate.len());
+
+        let (spaced, spaced_width) = glyphs(&LayoutOptions::default().letter_spacing(3.0));
+        let gaps = spaced.len().saturating_sub(1) as f32;
+        assert!((spaced_width - width - 3.0 * gaps).abs() < 0.01);
+        let (_, separate_spaced) = glyphs(
+            &LayoutOptions::default()
+                .ligatures(false)
+                .letter_spacing(3.0),
+        );
+        let (_, separate_width) = glyphs(&LayoutOptions::default().ligatures(false));
+        assert!((separate_spaced - separate_width - 3.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_preformatted_keeps_leading_whitespace() {
+        let engine = TextLayoutEngine::new();
+        let text = "    indented line";
+        let shaped = create_mock_shaped_text(text);
+        let wrapped = |preformatted| {
+            let options = LayoutOptions {
+                max_width: Some(1000.0),
+                preformatted,
+                ..Default::default()
+            };
+            engine.break_lines(text, &shaped, 1000.0, &options, 20.0)
+        };
+
+        assert_eq!(wrapped(false)[0][0].codepoint, 'i');
+        assert_eq!(wrapped(true)[0].len(), text.chars().count());
+    }
+
+    #[test]
+    fn test_cjk_wraps_between_characters() {
+        let engine = TextLayoutEngine::new();
+        let text = "这是一个很长的中文句子需要换行显示";
+        let shaped = create_mock_shaped_text(text);
+        let options = LayoutOptions {
+            max_width: Some(45.0),
+            ..Default::default()
+        };
+        let lines = engine.break_lines(text, &shaped, 45.0, &options, 20.0);
+
+        // 10px ideographs, no spaces: four per line
+        verify_content_preserved(&lines, text);
+        assert_eq!(lines.len(), 5);
+        assert!(lines[..4].iter().all(|line| line.len() == 4));
+
+        // Closing punctuation never starts a line - its ideograph moves with it
+        let text = "你好世界。";
+        let shaped = create_mock_shaped_text(text);
+        let lines = engine.break_lines(text, &shaped, 40.0, &options, 20.0);
+        let line_text: Vec<String> = lines
+            .iter()
+            .map(|line| line.iter().map(|g| g.codepoint).collect())
+            .collect();
+        assert_eq!(line_text, ["你好世", "界。"]);
+    }
+
+    #[test]
+    fn test_bidi_reorders_rtl_run() {
+        let engine = TextLayoutEngine::new();
+        let text = "abc ابج def";
+        let mut shaped = create_mock_shaped_text(text);
+        for (range, level) in crate::shaper::bidi_runs(text) {
+            for glyph in &mut shaped.glyphs {
+                if range.contains(&(glyph.cluster as usize)) {
+                    glyph.bidi_level = level;
+                }
+            }
+        }
+        let line = engine.create_line(&shaped, 0.0, 0.0, &LayoutOptions::default(), 20.0);
+
+        // Spaces around the Arabic run resolve to the LTR paragraph level
+        let x_of = |c: char| line.glyphs.iter().find(|g| g.codepoint == c).unwrap().x;
+        let arabic = ['ا', 'ب', 'ج'];
+        assert!(arabic
+            .iter()
+            .all(|&c| x_of(c) > x_of(' ') && x_of(c) < x_of('d')));
+        assert!(x_of('ا') > x_of('ب') && x_of('ب') > x_of('ج'));
+        assert!(x_of('c') < x_of('ج'));
+
+        // Glyphs are in visual order but keep their logical byte offsets
+        let visual: String = line.glyphs.iter().map(|g| g.codepoint).collect();
+        assert_eq!(visual, "abc جبا def");
+        let alef = line.glyphs.iter().find(|g| g.codepoint == 'ا').unwrap();
+        assert_eq!(alef.logical_index as usize, text.find('ا').unwrap());
+        assert!(alef.rtl);
+    }
+
     #[test]
     #[cfg(target_os = "macos")]
     fn test_helvetica_trailing_space() {
@@ -23,6 +23,7 @@ pub mod atlas;
 pub mod emoji;
 pub mod font;
 pub mod layout;
+pub mod linebreak;
 pub mod rasterizer;
 pub mod registry;
 pub mod renderer;
@@ -64,9 +65,10 @@ pub fn global_font_registry() -> Arc<Mutex<registry::FontRegistry>> {
 // Re-export html-escape for entity decoding
 pub use html_escape::decode_html_entities;
 pub use layout::{
-    LayoutOptions, LineBreakMode, PositionedGlyph, TextAlignment, TextAnchor, TextLayout,
+    LayoutOptions, LineBreakMode, PositionedGlyph, TabWidth, TextAlignment, TextAnchor, TextLayout,
     TextLayoutEngine,
 };
+pub use linebreak::BreakDictionary;
 pub use rasterizer::{GlyphFormat, GlyphRasterizer, RasterizedGlyph};
 pub use registry::{FontRegistry, GenericFont};
 pub use renderer::{ColorSpan, GlyphInstance, PreparedText, TextRenderer};
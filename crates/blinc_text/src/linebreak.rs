@@ -0,0 +1,146 @@
+//! Line break opportunities
+//!
+//! Uses the Unicode Line Breaking Algorithm (UAX #14) so CJK text may wrap
+//! between ideographs while no-break rules still hold: closing punctuation
+//! never starts a line and non-breaking spaces glue their neighbours.
+//!
+//! Scripts written without spaces (Thai, Lao, Khmer, Myanmar) have no break
+//! opportunities under UAX #14 alone. A [`BreakDictionary`] segments those
+//! runs into words so they can wrap at word boundaries.
+
+use std::collections::HashSet;
+
+use unicode_linebreak::{break_property, linebreaks, BreakClass};
+
+/// Word list for segmenting scripts that don't separate words with spaces
+#[derive(Debug, Clone, Default)]
+pub struct BreakDictionary {
+    words: HashSet<String>,
+    /// Longest word length in chars (bounds the match search)
+    max_chars: usize,
+}
+
+impl BreakDictionary {
+    /// Create a dictionary from a word list
+    pub fn new<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut dictionary = Self::default();
+        for word in words {
+            dictionary.insert(word);
+        }
+        dictionary
+    }
+
+    /// Add a word
+    pub fn insert(&mut self, word: impl Into<String>) {
+        let word = word.into();
+        self.max_chars = self.max_chars.max(word.chars().count());
+        self.words.insert(word);
+    }
+
+    /// Number of words
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Whether the dictionary has no words
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Byte offsets of word starts inside `run` (excluding offset 0)
+    ///
+    /// Greedy longest match; characters not covered by any word stay
+    /// attached to the preceding word.
+    fn word_starts(&self, run: &str) -> Vec<usize> {
+        let chars: Vec<usize> = run
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(run.len()))
+            .collect();
+        let mut starts = Vec::new();
+        let mut i = 0;
+        while i + 1 < chars.len() {
+            let longest = (1..=self.max_chars.min(chars.len() - 1 - i))
+                .rev()
+                .find(|&n| self.words.contains(&run[chars[i]..chars[i + n]]));
+            match longest {
+                Some(n) => {
+                    if i > 0 {
+                        starts.push(chars[i]);
+                    }
+                    i += n;
+                }
+                None => i += 1,
+            }
+        }
+        starts
+    }
+}
+
+/// Byte offsets where a line may start, in ascending order
+///
+/// Offset 0 and the end of the text are not included. Mandatory breaks
+/// (newlines) are included alongside allowed ones.
+pub fn break_opportunities(text: &str, dictionary: Option<&BreakDictionary>) -> Vec<usize> {
+    let mut breaks: Vec<usize> = linebreaks(text)
+        .map(|(i, _)| i)
+        .filter(|&i| i < text.len())
+        .collect();
+
+    if let Some(dictionary) = dictionary.filter(|d| !d.is_empty()) {
+        let mut run_start = None;
+        for (i, c) in text
+            .char_indices()
+            .chain(std::iter::once((text.len(), ' ')))
+        {
+            let complex = break_property(c as u32) == BreakClass::ComplexContext;
+            match (complex, run_start) {
+                (true, None) => run_start = Some(i),
+                (false, Some(start)) => {
+                    let run = &text[start..i];
+                    breaks.extend(dictionary.word_starts(run).into_iter().map(|o| start + o));
+                    run_start = None;
+                }
+                _ => {}
+            }
+        }
+        breaks.sort_unstable();
+        breaks.dedup();
+    }
+
+    breaks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cjk_breaks_between_ideographs_but_not_before_closing_punctuation() {
+        let text = "你好世界。";
+        let breaks = break_opportunities(text, None);
+        // Before 好 and 世 and 界, but not before 。
+        assert_eq!(breaks, vec![3, 6, 9]);
+    }
+
+    #[test]
+    fn test_no_break_at_non_breaking_space() {
+        assert!(break_opportunities("10\u{a0}kg", None).is_empty());
+        assert_eq!(break_opportunities("10 kg", None), vec![3]);
+    }
+
+    #[test]
+    fn test_dictionary_segments_thai() {
+        // "สวัสดีครับ" = "สวัสดี" + "ครับ"
+        let text = "สวัสดีครับ";
+        assert!(break_opportunities(text, None).is_empty());
+
+        let dictionary = BreakDictionary::new(["สวัสดี", "ครับ"]);
+        let breaks = break_opportunities(text, Some(&dictionary));
+        assert_eq!(breaks, vec!["สวัสดี".len()]);
+    }
+}
@@ -470,6 +470,7 @@ impl TextRenderer {
                                     codepoint: positioned.codepoint,
                                     x: positioned.x + x_offset,
                                     y: positioned.y,
+                                    ..*positioned
                                 };
 
                                 // Use color rasterization for emoji font
@@ -651,11 +652,7 @@ impl TextRenderer {
 
         // Second pass: build glyph instances with per-glyph colors
         // We need to map glyph cluster (byte position) to color
-        let byte_positions: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
-
-        for (i, (positioned, glyph_info)) in
-            positioned_glyphs.iter().zip(glyph_infos.iter()).enumerate()
-        {
+        for (positioned, glyph_info) in positioned_glyphs.iter().zip(glyph_infos.iter()) {
             let glyph_info = match glyph_info {
                 Some(info) => *info,
                 None => continue,
@@ -665,9 +662,8 @@ impl TextRenderer {
                 continue;
             }
 
-            // Get the byte position for this glyph's cluster to determine color
-            let byte_pos = byte_positions.get(i).copied().unwrap_or(0);
-            let color = get_color_for_byte_pos(byte_pos);
+            // The glyph's cluster (byte position) determines its color
+            let color = get_color_for_byte_pos(positioned.logical_index as usize);
 
             // positioned.x is the pen position from the shaper
             // bearing_x is the offset from pen position to the glyph's left edge
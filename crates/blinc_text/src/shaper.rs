@@ -3,8 +3,11 @@
 //! Converts text strings into positioned glyph sequences with proper
 //! kerning, ligatures, and OpenType feature support.
 
+use std::ops::Range;
+
 use crate::font::FontFace;
-use rustybuzz::{Face, UnicodeBuffer};
+use rustybuzz::{Direction, Face, UnicodeBuffer};
+use unicode_bidi::BidiInfo;
 
 /// A shaped glyph with position information
 #[derive(Debug, Clone, Copy)]
@@ -23,12 +26,18 @@ pub struct ShapedGlyph {
     pub y_advance: i32,
     /// Index in the original string (cluster)
     pub cluster: u32,
+    /// Resolved bidi embedding level (even = LTR, odd = RTL)
+    pub bidi_level: u8,
 }
 
 /// Result of shaping a text string
 #[derive(Debug, Clone)]
 pub struct ShapedText {
-    /// Shaped glyphs in visual order
+    /// Shaped glyphs in logical order
+    ///
+    /// Right-to-left runs are shaped as RTL but stored in source order, so
+    /// line breaking and selection can walk them like any other text.
+    /// Layout reorders each line for display.
     pub glyphs: Vec<ShapedGlyph>,
     /// Total advance width in font units
     pub total_advance: i32,
@@ -64,56 +73,7 @@ impl TextShaper {
 
     /// Shape a text string using the given font
     pub fn shape(&self, text: &str, font_face: &FontFace, font_size: f32) -> ShapedText {
-        // Create rustybuzz Face from font data with correct face index
-        let face = match Face::from_slice(font_face.data(), font_face.face_index()) {
-            Some(f) => f,
-            None => {
-                // Fallback: return basic glyph sequence without shaping
-                return self.fallback_shape(text, font_face, font_size);
-            }
-        };
-
-        // Create and fill the Unicode buffer
-        let mut buffer = UnicodeBuffer::new();
-        buffer.push_str(text);
-
-        // Shape the buffer
-        let output = rustybuzz::shape(&face, &[], buffer);
-
-        // Extract glyph information
-        let glyph_infos = output.glyph_infos();
-        let glyph_positions = output.glyph_positions();
-
-        let mut glyphs = Vec::with_capacity(glyph_infos.len());
-        let mut total_advance = 0i32;
-
-        for (info, pos) in glyph_infos.iter().zip(glyph_positions.iter()) {
-            // Find the original character for this cluster
-            let codepoint = text
-                .char_indices()
-                .find(|(i, _)| *i as u32 == info.cluster)
-                .map(|(_, c)| c)
-                .unwrap_or('\u{FFFD}');
-
-            glyphs.push(ShapedGlyph {
-                glyph_id: info.glyph_id as u16,
-                codepoint,
-                x_offset: pos.x_offset,
-                y_offset: pos.y_offset,
-                x_advance: pos.x_advance,
-                y_advance: pos.y_advance,
-                cluster: info.cluster,
-            });
-
-            total_advance += pos.x_advance;
-        }
-
-        ShapedText {
-            glyphs,
-            total_advance,
-            font_size,
-            units_per_em: font_face.metrics().units_per_em,
-        }
+        self.shape_with_features(text, font_face, font_size, &[])
     }
 
     /// Fallback shaping when rustybuzz fails
@@ -121,21 +81,24 @@ impl TextShaper {
         let mut glyphs = Vec::new();
         let mut total_advance = 0i32;
 
-        for (cluster, c) in text.char_indices() {
-            let glyph_id = font_face.glyph_id(c).unwrap_or(0);
-            let advance = font_face.glyph_advance(glyph_id).unwrap_or(500) as i32;
-
-            glyphs.push(ShapedGlyph {
-                glyph_id,
-                codepoint: c,
-                x_offset: 0,
-                y_offset: 0,
-                x_advance: advance,
-                y_advance: 0,
-                cluster: cluster as u32,
-            });
-
-            total_advance += advance;
+        for (range, level) in bidi_runs(text) {
+            for (i, c) in text[range.clone()].char_indices() {
+                let glyph_id = font_face.glyph_id(c).unwrap_or(0);
+                let advance = font_face.glyph_advance(glyph_id).unwrap_or(500) as i32;
+
+                glyphs.push(ShapedGlyph {
+                    glyph_id,
+                    codepoint: c,
+                    x_offset: 0,
+                    y_offset: 0,
+                    x_advance: advance,
+                    y_advance: 0,
+                    cluster: (range.start + i) as u32,
+                    bidi_level: level,
+                });
+
+                total_advance += advance;
+            }
         }
 
         ShapedText {
@@ -154,40 +117,55 @@ impl TextShaper {
         font_size: f32,
         features: &[rustybuzz::Feature],
     ) -> ShapedText {
+        // Create rustybuzz Face from font data with correct face index
         let face = match Face::from_slice(font_face.data(), font_face.face_index()) {
             Some(f) => f,
-            None => return self.fallback_shape(text, font_face, font_size),
+            None => {
+                // Fallback: return basic glyph sequence without shaping
+                return self.fallback_shape(text, font_face, font_size);
+            }
         };
 
-        let mut buffer = UnicodeBuffer::new();
-        buffer.push_str(text);
-
-        let output = rustybuzz::shape(&face, features, buffer);
-
-        let glyph_infos = output.glyph_infos();
-        let glyph_positions = output.glyph_positions();
-
-        let mut glyphs = Vec::with_capacity(glyph_infos.len());
+        let mut glyphs = Vec::with_capacity(text.len());
         let mut total_advance = 0i32;
 
-        for (info, pos) in glyph_infos.iter().zip(glyph_positions.iter()) {
-            let codepoint = text
-                .char_indices()
-                .find(|(i, _)| *i as u32 == info.cluster)
-                .map(|(_, c)| c)
-                .unwrap_or('\u{FFFD}');
-
-            glyphs.push(ShapedGlyph {
-                glyph_id: info.glyph_id as u16,
-                codepoint,
-                x_offset: pos.x_offset,
-                y_offset: pos.y_offset,
-                x_advance: pos.x_advance,
-                y_advance: pos.y_advance,
-                cluster: info.cluster,
+        // Shape each directional run on its own so RTL scripts get RTL
+        // shaping (joining forms, mirrored punctuation)
+        for (range, level) in bidi_runs(text) {
+            let mut buffer = UnicodeBuffer::new();
+            buffer.push_str(&text[range.clone()]);
+            buffer.set_direction(if level % 2 == 1 {
+                Direction::RightToLeft
+            } else {
+                Direction::LeftToRight
             });
 
-            total_advance += pos.x_advance;
+            let output = rustybuzz::shape(&face, features, buffer);
+            let run_start = glyphs.len();
+
+            for (info, pos) in output.glyph_infos().iter().zip(output.glyph_positions()) {
+                let cluster = range.start + info.cluster as usize;
+                // Find the original character for this cluster
+                let codepoint = text[cluster..].chars().next().unwrap_or('\u{FFFD}');
+
+                glyphs.push(ShapedGlyph {
+                    glyph_id: info.glyph_id as u16,
+                    codepoint,
+                    x_offset: pos.x_offset,
+                    y_offset: pos.y_offset,
+                    x_advance: pos.x_advance,
+                    y_advance: pos.y_advance,
+                    cluster: cluster as u32,
+                    bidi_level: level,
+                });
+
+                total_advance += pos.x_advance;
+            }
+
+            // HarfBuzz emits RTL runs in visual order - store them logically
+            if level % 2 == 1 {
+                glyphs[run_start..].reverse();
+            }
         }
 
         ShapedText {
@@ -204,3 +182,27 @@ impl Default for TextShaper {
         Self::new()
     }
 }
+
+/// Split text into runs of equal bidi embedding level
+///
+/// Levels come from the Unicode Bidirectional Algorithm with the paragraph
+/// direction taken from the first strong character, so neutrals between
+/// runs of opposite direction resolve per the UBA rather than per character.
+pub(crate) fn bidi_runs(text: &str) -> Vec<(Range<usize>, u8)> {
+    if text.is_ascii() {
+        // No strong RTL characters possible
+        return vec![(0..text.len(), 0)];
+    }
+
+    let info = BidiInfo::new(text, None);
+    let mut runs = Vec::new();
+    let mut start = 0;
+    for (i, _) in text.char_indices().skip(1) {
+        if info.levels[i] != info.levels[start] {
+            runs.push((start..i, info.levels[start].number()));
+            start = i;
+        }
+    }
+    runs.push((start..text.len(), info.levels[start].number()));
+    runs
+}
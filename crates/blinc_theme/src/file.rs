@@ -0,0 +1,505 @@
+//! Theme bundle files (TOML/JSON)
+//!
+//! Lets color, spacing, radius and shadow tokens be authored in a file and
+//! loaded at runtime instead of being compiled in.
+//!
+//! ```toml
+//! name = "Ocean"
+//!
+//! [light.colors]
+//! primary = "#0EA5E9"
+//! success_bg = { hex = "#40A02B", alpha = 0.1 }
+//!
+//! [light.radii]
+//! md = 8.0
+//!
+//! [dark.colors]
+//! primary = "#38BDF8"
+//!
+//! [dark.shadows.md]
+//! y = 6.0
+//! blur = 10.0
+//! color = "#00000080"
+//! ```
+//!
+//! Tokens keep the field names of [`ColorTokens`]; spacing, radius and
+//! shadow keys drop their `space_`/`radius_`/`shadow_` prefix (`4`, `md`,
+//! `2xl`). Colors are `"#RRGGBB"`, `"#RRGGBBAA"`, `{ hex, alpha }` or an
+//! `[r, g, b, a]` array of floats.
+//!
+//! Any token a file leaves out inherits from the default [`BlincTheme`]
+//! variant of the same scheme ([`BlincTheme::light`] / [`BlincTheme::dark`]),
+//! as do typography and animation tokens, which files don't cover.
+
+use crate::theme::{ColorScheme, Theme, ThemeBundle};
+use crate::themes::BlincTheme;
+use crate::tokens::*;
+use blinc_core::Color;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Error loading or saving a theme bundle file
+#[derive(Debug)]
+pub enum ThemeFileError {
+    /// TOML could not be parsed
+    TomlParse(toml::de::Error),
+    /// Bundle could not be written as TOML
+    TomlWrite(toml::ser::Error),
+    /// JSON could not be parsed or written
+    Json(serde_json::Error),
+    /// A color value is not a valid color
+    InvalidColor { token: String, value: String },
+}
+
+impl std::fmt::Display for ThemeFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThemeFileError::TomlParse(e) => write!(f, "invalid theme TOML: {}", e),
+            ThemeFileError::TomlWrite(e) => write!(f, "failed to write theme TOML: {}", e),
+            ThemeFileError::Json(e) => write!(f, "invalid theme JSON: {}", e),
+            ThemeFileError::InvalidColor { token, value } => {
+                write!(f, "invalid color '{}' for token '{}'", value, token)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ThemeFileError {}
+
+impl ThemeBundle {
+    /// Load a bundle from TOML
+    ///
+    /// The result can be passed straight to [`ThemeState::init`](crate::ThemeState::init).
+    pub fn from_toml(source: &str) -> Result<Self, ThemeFileError> {
+        let file: BundleFile = toml::from_str(source).map_err(ThemeFileError::TomlParse)?;
+        file.into_bundle()
+    }
+
+    /// Write every token of both variants as TOML
+    pub fn to_toml(&self) -> Result<String, ThemeFileError> {
+        toml::to_string_pretty(&BundleFile::from_bundle(self)).map_err(ThemeFileError::TomlWrite)
+    }
+
+    /// Load a bundle from JSON (same layout as the TOML format)
+    pub fn from_json(source: &str) -> Result<Self, ThemeFileError> {
+        let file: BundleFile = serde_json::from_str(source).map_err(ThemeFileError::Json)?;
+        file.into_bundle()
+    }
+
+    /// Write every token of both variants as JSON
+    pub fn to_json(&self) -> Result<String, ThemeFileError> {
+        serde_json::to_string_pretty(&BundleFile::from_bundle(self)).map_err(ThemeFileError::Json)
+    }
+}
+
+/// A theme variant loaded from a file
+#[derive(Debug)]
+struct FileTheme {
+    name: String,
+    scheme: ColorScheme,
+    colors: ColorTokens,
+    typography: TypographyTokens,
+    spacing: SpacingTokens,
+    radii: RadiusTokens,
+    shadows: ShadowTokens,
+    animations: AnimationTokens,
+}
+
+impl Theme for FileTheme {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn color_scheme(&self) -> ColorScheme {
+        self.scheme
+    }
+
+    fn colors(&self) -> &ColorTokens {
+        &self.colors
+    }
+
+    fn typography(&self) -> &TypographyTokens {
+        &self.typography
+    }
+
+    fn spacing(&self) -> &SpacingTokens {
+        &self.spacing
+    }
+
+    fn radii(&self) -> &RadiusTokens {
+        &self.radii
+    }
+
+    fn shadows(&self) -> &ShadowTokens {
+        &self.shadows
+    }
+
+    fn animations(&self) -> &AnimationTokens {
+        &self.animations
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct BundleFile {
+    #[serde(default = "default_name")]
+    name: String,
+    #[serde(default)]
+    light: VariantFile,
+    #[serde(default)]
+    dark: VariantFile,
+}
+
+fn default_name() -> String {
+    "Custom".to_string()
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct VariantFile {
+    colors: BTreeMap<String, ColorValue>,
+    spacing: BTreeMap<String, f32>,
+    radii: BTreeMap<String, f32>,
+    shadows: BTreeMap<String, ShadowFile>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct ShadowFile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    y: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blur: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spread: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<ColorValue>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum ColorValue {
+    Hex(String),
+    HexAlpha { hex: String, alpha: f32 },
+    Rgba([f32; 4]),
+}
+
+impl ColorValue {
+    /// Shortest representation that reloads to exactly `color`
+    fn from_color(color: Color) -> Self {
+        let byte = |c: f32| {
+            let b = (c * 255.0).round();
+            ((0.0..=255.0).contains(&b) && b / 255.0 == c).then_some(b as u8)
+        };
+        match (byte(color.r), byte(color.g), byte(color.b)) {
+            (Some(r), Some(g), Some(b)) => {
+                let hex = format!("#{:02X}{:02X}{:02X}", r, g, b);
+                if color.a == 1.0 {
+                    ColorValue::Hex(hex)
+                } else if let Some(a) = byte(color.a) {
+                    ColorValue::Hex(format!("{}{:02X}", hex, a))
+                } else {
+                    ColorValue::HexAlpha {
+                        hex,
+                        alpha: color.a,
+                    }
+                }
+            }
+            _ => ColorValue::Rgba(color.to_array()),
+        }
+    }
+
+    fn to_color(&self, token: &str) -> Result<Color, ThemeFileError> {
+        let invalid = |value: &str| ThemeFileError::InvalidColor {
+            token: token.to_string(),
+            value: value.to_string(),
+        };
+        match self {
+            ColorValue::Hex(hex) => parse_hex(hex).ok_or_else(|| invalid(hex)),
+            ColorValue::HexAlpha { hex, alpha } => parse_hex(hex)
+                .map(|color| color.with_alpha(*alpha))
+                .ok_or_else(|| invalid(hex)),
+            ColorValue::Rgba([r, g, b, a]) => Ok(Color::rgba(*r, *g, *b, *a)),
+        }
+    }
+}
+
+/// Parse `#RRGGBB` or `#RRGGBBAA` (the `#` is optional)
+fn parse_hex(hex: &str) -> Option<Color> {
+    let digits = hex.trim().trim_start_matches('#');
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let value = u32::from_str_radix(digits, 16).ok()?;
+    match digits.len() {
+        6 => Some(Color::from_hex(value)),
+        8 => Some(Color::from_hex(value >> 8).with_alpha((value & 0xFF) as f32 / 255.0)),
+        _ => None,
+    }
+}
+
+/// Generates the save/load functions mapping file keys to token fields
+macro_rules! token_fields {
+    (
+        $save:ident, $load:ident, $tokens:ty, $field_ty:ty => $value:ty,
+        [$($key:literal => $field:ident),* $(,)?]
+    ) => {
+        fn $save(tokens: &$tokens, to: impl Fn(&$field_ty) -> $value) -> BTreeMap<String, $value> {
+            BTreeMap::from([$(($key.to_string(), to(&tokens.$field))),*])
+        }
+
+        fn $load(
+            tokens: &mut $tokens,
+            map: &BTreeMap<String, $value>,
+            from: impl Fn(&str, &$value, &$field_ty) -> Result<$field_ty, ThemeFileError>,
+        ) -> Result<(), ThemeFileError> {
+            for (key, value) in map {
+                match key.as_str() {
+                    $($key => tokens.$field = from(key, value, &tokens.$field)?,)*
+                    _ => tracing::warn!("Ignoring unknown theme token '{}'", key),
+                }
+            }
+            Ok(())
+        }
+    };
+}
+
+token_fields!(save_colors, load_colors, ColorTokens, Color => ColorValue, [
+    "primary" => primary,
+    "primary_hover" => primary_hover,
+    "primary_active" => primary_active,
+    "secondary" => secondary,
+    "secondary_hover" => secondary_hover,
+    "secondary_active" => secondary_active,
+    "success" => success,
+    "success_bg" => success_bg,
+    "warning" => warning,
+    "warning_bg" => warning_bg,
+    "error" => error,
+    "error_bg" => error_bg,
+    "info" => info,
+    "info_bg" => info_bg,
+    "background" => background,
+    "surface" => surface,
+    "surface_elevated" => surface_elevated,
+    "surface_overlay" => surface_overlay,
+    "text_primary" => text_primary,
+    "text_secondary" => text_secondary,
+    "text_tertiary" => text_tertiary,
+    "text_inverse" => text_inverse,
+    "text_link" => text_link,
+    "border" => border,
+    "border_hover" => border_hover,
+    "border_focus" => border_focus,
+    "border_error" => border_error,
+    "input_bg" => input_bg,
+    "input_bg_hover" => input_bg_hover,
+    "input_bg_focus" => input_bg_focus,
+    "input_bg_disabled" => input_bg_disabled,
+    "selection" => selection,
+    "selection_text" => selection_text,
+    "accent" => accent,
+    "accent_subtle" => accent_subtle,
+    "tooltip_bg" => tooltip_bg,
+    "tooltip_text" => tooltip_text,
+]);
+
+token_fields!(save_spacing, load_spacing, SpacingTokens, f32 => f32, [
+    "0" => space_0,
+    "0_5" => space_0_5,
+    "1" => space_1,
+    "1_5" => space_1_5,
+    "2" => space_2,
+    "2_5" => space_2_5,
+    "3" => space_3,
+    "3_5" => space_3_5,
+    "4" => space_4,
+    "5" => space_5,
+    "6" => space_6,
+    "7" => space_7,
+    "8" => space_8,
+    "9" => space_9,
+    "10" => space_10,
+    "11" => space_11,
+    "12" => space_12,
+    "14" => space_14,
+    "16" => space_16,
+    "20" => space_20,
+    "24" => space_24,
+    "28" => space_28,
+    "32" => space_32,
+]);
+
+token_fields!(save_radii, load_radii, RadiusTokens, f32 => f32, [
+    "none" => radius_none,
+    "sm" => radius_sm,
+    "default" => radius_default,
+    "md" => radius_md,
+    "lg" => radius_lg,
+    "xl" => radius_xl,
+    "2xl" => radius_2xl,
+    "3xl" => radius_3xl,
+    "full" => radius_full,
+]);
+
+token_fields!(save_shadows, load_shadows, ShadowTokens, Shadow => ShadowFile, [
+    "sm" => shadow_sm,
+    "default" => shadow_default,
+    "md" => shadow_md,
+    "lg" => shadow_lg,
+    "xl" => shadow_xl,
+    "2xl" => shadow_2xl,
+    "inner" => shadow_inner,
+    "none" => shadow_none,
+]);
+
+impl ShadowFile {
+    fn from_shadow(shadow: &Shadow) -> Self {
+        Self {
+            x: Some(shadow.offset_x),
+            y: Some(shadow.offset_y),
+            blur: Some(shadow.blur),
+            spread: Some(shadow.spread),
+            color: Some(ColorValue::from_color(shadow.color)),
+        }
+    }
+
+    /// Apply the fields that are set on top of `base`
+    fn to_shadow(&self, token: &str, base: &Shadow) -> Result<Shadow, ThemeFileError> {
+        Ok(Shadow {
+            offset_x: self.x.unwrap_or(base.offset_x),
+            offset_y: self.y.unwrap_or(base.offset_y),
+            blur: self.blur.unwrap_or(base.blur),
+            spread: self.spread.unwrap_or(base.spread),
+            color: match &self.color {
+                Some(color) => color.to_color(token)?,
+                None => base.color,
+            },
+        })
+    }
+}
+
+impl VariantFile {
+    fn from_theme(theme: &dyn Theme) -> Self {
+        Self {
+            colors: save_colors(theme.colors(), |c| ColorValue::from_color(*c)),
+            spacing: save_spacing(theme.spacing(), |v| *v),
+            radii: save_radii(theme.radii(), |v| *v),
+            shadows: save_shadows(theme.shadows(), ShadowFile::from_shadow),
+        }
+    }
+
+    fn into_theme(self, name: &str, base: BlincTheme) -> Result<FileTheme, ThemeFileError> {
+        let mut colors = base.colors().clone();
+        let mut spacing = base.spacing().clone();
+        let mut radii = base.radii().clone();
+        let mut shadows = base.shadows().clone();
+
+        load_colors(&mut colors, &self.colors, |key, value, _| {
+            value.to_color(key)
+        })?;
+        load_spacing(&mut spacing, &self.spacing, |_, value, _| Ok(*value))?;
+        load_radii(&mut radii, &self.radii, |_, value, _| Ok(*value))?;
+        load_shadows(&mut shadows, &self.shadows, |key, value, current| {
+            value.to_shadow(key, current)
+        })?;
+
+        Ok(FileTheme {
+            name: name.to_string(),
+            scheme: base.color_scheme(),
+            colors,
+            typography: base.typography().clone(),
+            spacing,
+            radii,
+            shadows,
+            animations: base.animations().clone(),
+        })
+    }
+}
+
+impl BundleFile {
+    fn from_bundle(bundle: &ThemeBundle) -> Self {
+        Self {
+            name: bundle.name.clone(),
+            light: VariantFile::from_theme(bundle.light.as_ref()),
+            dark: VariantFile::from_theme(bundle.dark.as_ref()),
+        }
+    }
+
+    fn into_bundle(self) -> Result<ThemeBundle, ThemeFileError> {
+        let light = self.light.into_theme(&self.name, BlincTheme::light())?;
+        let dark = self.dark.into_theme(&self.name, BlincTheme::dark())?;
+        Ok(ThemeBundle::new(self.name, light, dark))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_same_tokens(a: &dyn Theme, b: &dyn Theme) {
+        assert_eq!(a.colors(), b.colors());
+        assert_eq!(a.spacing(), b.spacing());
+        assert_eq!(a.radii(), b.radii());
+        assert_eq!(a.shadows(), b.shadows());
+    }
+
+    #[test]
+    fn test_toml_round_trip() {
+        let bundle = BlincTheme::bundle();
+        let toml = bundle.to_toml().unwrap();
+        let loaded = ThemeBundle::from_toml(&toml).unwrap();
+
+        assert_eq!(loaded.name, "Blinc");
+        assert_same_tokens(bundle.light.as_ref(), loaded.light.as_ref());
+        assert_same_tokens(bundle.dark.as_ref(), loaded.dark.as_ref());
+        assert_eq!(loaded.dark.color_scheme(), ColorScheme::Dark);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let bundle = BlincTheme::bundle();
+        let loaded = ThemeBundle::from_json(&bundle.to_json().unwrap()).unwrap();
+        assert_same_tokens(bundle.light.as_ref(), loaded.light.as_ref());
+        assert_same_tokens(bundle.dark.as_ref(), loaded.dark.as_ref());
+    }
+
+    #[test]
+    fn test_missing_tokens_inherit_defaults() {
+        let loaded = ThemeBundle::from_toml(
+            r##"
+            name = "Ocean"
+
+            [light.colors]
+            primary = "#FF0000"
+            selection = { hex = "#0000FF", alpha = 0.25 }
+
+            [light.shadows.md]
+            blur = 12.0
+            "##,
+        )
+        .unwrap();
+
+        let default_light = BlincTheme::light();
+        let light = loaded.light.colors();
+        assert_eq!(light.primary, Color::from_hex(0xFF0000));
+        assert_eq!(light.selection, Color::from_hex(0x0000FF).with_alpha(0.25));
+        assert_eq!(light.secondary, default_light.colors().secondary);
+
+        let md = &loaded.light.shadows().shadow_md;
+        assert_eq!(md.blur, 12.0);
+        assert_eq!(md.offset_y, default_light.shadows().shadow_md.offset_y);
+
+        assert_same_tokens(loaded.dark.as_ref(), &BlincTheme::dark());
+    }
+
+    #[test]
+    fn test_invalid_color_errors() {
+        let err = ThemeBundle::from_toml("[dark.colors]\nprimary = \"#12345\"").unwrap_err();
+        assert!(matches!(
+            err,
+            ThemeFileError::InvalidColor { ref token, .. } if token == "primary"
+        ));
+    }
+}
@@ -49,6 +49,11 @@
 //!
 //! - [`BlincTheme`]: Default theme derived from Catppuccin design system
 //! - Platform-specific themes for macOS, Windows, Linux, iOS, Android
+//! - [`HighContrastTheme`]: WCAG AAA variant derived from any bundle via
+//!   [`ThemeBundle::high_contrast`]
+//!
+//! Custom bundles can also be loaded from TOML or JSON files at runtime with
+//! [`ThemeBundle::from_toml`] / [`ThemeBundle::from_json`] (see [`file`]).
 //!
 //! # Dynamic Overrides
 //!
@@ -67,7 +72,9 @@
 //! theme.clear_overrides();
 //! ```
 
+pub mod file;
 pub mod platform;
+pub mod scale;
 pub mod state;
 pub mod theme;
 pub mod themes;
@@ -77,10 +84,15 @@ pub mod tokens;
 pub mod watcher;
 
 // Re-export commonly used types
-pub use platform::{detect_system_color_scheme, Platform};
+pub use file::ThemeFileError;
+pub use platform::{detect_system_color_scheme, detect_system_text_scale, Platform};
+pub use scale::{
+    scaled_text_size, set_system_text_scale, set_text_scale, set_typography_scale, text_scale,
+    typography_scale, MAX_TEXT_SCALE, MIN_TEXT_SCALE,
+};
 pub use state::{set_redraw_callback, ThemeState};
 pub use theme::{ColorScheme, Theme, ThemeBundle};
-pub use themes::{platform::platform_theme_bundle, BlincTheme};
+pub use themes::{platform::platform_theme_bundle, BlincTheme, HighContrastTheme};
 pub use tokens::*;
 
 #[cfg(feature = "watcher")]
@@ -45,6 +45,18 @@ fn detect_gtk_color_scheme() -> Option<ColorScheme> {
     None
 }
 
+/// GNOME's accessibility text scaling factor
+pub fn detect_text_scale() -> Option<f32> {
+    let output = std::process::Command::new("gsettings")
+        .args(["get", "org.gnome.desktop.interface", "text-scaling-factor"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout).trim().parse().ok()
+}
+
 fn detect_xdg_color_scheme() -> Option<ColorScheme> {
     // XDG Desktop Portal color scheme preference
     // Could use D-Bus to query org.freedesktop.portal.Settings
@@ -100,3 +100,20 @@ pub fn detect_system_color_scheme() -> ColorScheme {
         ColorScheme::Light
     }
 }
+
+/// Detect the system text scale factor (accessibility "larger text")
+///
+/// Returns `None` where the platform doesn't expose one to us; mobile
+/// backends report Dynamic Type / font scale via
+/// [`set_system_text_scale`](crate::set_system_text_scale) instead.
+pub fn detect_system_text_scale() -> Option<f32> {
+    #[cfg(target_os = "linux")]
+    {
+        linux::detect_text_scale()
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
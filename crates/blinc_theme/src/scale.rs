@@ -0,0 +1,92 @@
+//! Global text scaling (dynamic type)
+//!
+//! All text sizes are multiplied by a global factor at render time, so users
+//! (or the OS accessibility setting) can enlarge text without the app
+//! rebuilding its UI. The effective factor is the user scale times the system
+//! scale, clamped to [`MIN_TEXT_SCALE`]..=[`MAX_TEXT_SCALE`] to stay readable.
+//!
+//! Typography helpers can also follow a [`TypographyScale`] instead of their
+//! fixed heading sizes.
+
+use crate::tokens::TypographyScale;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::RwLock;
+
+/// Smallest effective text scale
+pub const MIN_TEXT_SCALE: f32 = 0.75;
+
+/// Largest effective text scale
+pub const MAX_TEXT_SCALE: f32 = 3.0;
+
+/// App/user text scale (f32 bits)
+static USER_TEXT_SCALE: AtomicU32 = AtomicU32::new(0x3F80_0000); // 1.0
+
+/// System dynamic-type factor (f32 bits)
+static SYSTEM_TEXT_SCALE: AtomicU32 = AtomicU32::new(0x3F80_0000); // 1.0
+
+/// Modular scale used by heading helpers (None = built-in sizes)
+static TYPOGRAPHY_SCALE: RwLock<Option<TypographyScale>> = RwLock::new(None);
+
+/// Set the app/user text scale (1.0 = unscaled)
+pub fn set_text_scale(scale: f32) {
+    store(&USER_TEXT_SCALE, scale);
+}
+
+/// Set the system dynamic-type factor
+///
+/// Called by the platform layer with the OS accessibility text size
+/// (e.g. iOS Dynamic Type, Android font scale, GNOME text-scaling-factor).
+pub fn set_system_text_scale(scale: f32) {
+    store(&SYSTEM_TEXT_SCALE, scale);
+}
+
+/// Effective text scale (user × system, clamped to readable bounds)
+pub fn text_scale() -> f32 {
+    let user = f32::from_bits(USER_TEXT_SCALE.load(Ordering::Relaxed));
+    let system = f32::from_bits(SYSTEM_TEXT_SCALE.load(Ordering::Relaxed));
+    (user * system).clamp(MIN_TEXT_SCALE, MAX_TEXT_SCALE)
+}
+
+/// Apply the effective text scale to a font size
+pub fn scaled_text_size(size: f32) -> f32 {
+    size * text_scale()
+}
+
+/// Use a modular scale for heading sizes (None restores the built-in sizes)
+pub fn set_typography_scale(scale: Option<TypographyScale>) {
+    *TYPOGRAPHY_SCALE.write().unwrap() = scale;
+    crate::state::notify_layout_change();
+}
+
+/// The modular scale used for headings, if one is set
+pub fn typography_scale() -> Option<TypographyScale> {
+    *TYPOGRAPHY_SCALE.read().unwrap()
+}
+
+fn store(slot: &AtomicU32, scale: f32) {
+    // Ignore nonsense values rather than collapsing all text
+    if !scale.is_finite() || scale <= 0.0 {
+        tracing::warn!("Ignoring invalid text scale {}", scale);
+        return;
+    }
+    if slot.swap(scale.to_bits(), Ordering::Relaxed) != scale.to_bits() {
+        crate::state::notify_layout_change();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typography_scale_headings() {
+        let scale = TypographyScale::new(16.0, 1.25);
+        assert_eq!(scale.heading(6), 16.0);
+        assert_eq!(scale.heading(5), 20.0);
+        assert!((scale.heading(1) - 16.0 * 1.25f32.powi(5)).abs() < 1e-4);
+        // Out-of-range levels clamp
+        assert_eq!(scale.heading(0), scale.heading(1));
+        assert_eq!(scale.heading(9), scale.heading(6));
+        assert!((scale.step(-1) - 12.8).abs() < 1e-4);
+    }
+}
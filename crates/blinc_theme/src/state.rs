@@ -4,7 +4,7 @@
 //! - Visual tokens (colors, shadows) can be animated and only trigger repaints
 //! - Layout tokens (spacing, typography, radii) trigger partial layout recomputation
 
-use crate::theme::{ColorScheme, ThemeBundle};
+use crate::theme::{ColorScheme, Theme, ThemeBundle};
 use crate::tokens::*;
 use blinc_animation::{AnimatedValue, AnimationScheduler, SchedulerHandle, SpringConfig};
 use blinc_core::Color;
@@ -32,6 +32,14 @@ fn trigger_redraw() {
     }
 }
 
+/// Flag a layout change (e.g. text scale) and trigger a redraw
+pub(crate) fn notify_layout_change() {
+    if let Some(state) = THEME_STATE.get() {
+        state.needs_layout.store(true, Ordering::SeqCst);
+    }
+    trigger_redraw();
+}
+
 /// Theme transition animation state
 #[derive(Default)]
 struct ThemeTransition {
@@ -49,6 +57,12 @@ pub struct ThemeState {
     /// The current theme bundle (light/dark pair)
     bundle: ThemeBundle,
 
+    /// High-contrast variant of `bundle`
+    high_contrast_bundle: ThemeBundle,
+
+    /// Whether the high-contrast variant is active
+    high_contrast: AtomicBool,
+
     /// Current color scheme
     scheme: RwLock<ColorScheme>,
 
@@ -98,6 +112,8 @@ impl ThemeState {
         let theme = bundle.for_scheme(scheme);
 
         let state = ThemeState {
+            high_contrast_bundle: bundle.high_contrast(),
+            high_contrast: AtomicBool::new(false),
             bundle,
             scheme: RwLock::new(scheme),
             colors: RwLock::new(theme.colors().clone()),
@@ -140,6 +156,10 @@ impl ThemeState {
         let bundle = platform_theme_bundle();
         let scheme = detect_system_color_scheme();
         Self::init(bundle, scheme);
+
+        if let Some(scale) = crate::platform::detect_system_text_scale() {
+            crate::scale::set_system_text_scale(scale);
+        }
     }
 
     /// Get the global theme state instance
@@ -170,55 +190,86 @@ impl ThemeState {
                 *current,
                 scheme
             );
-            // Get current colors before switching
-            let old_colors = self.colors.read().unwrap().clone();
-
             *current = scheme;
             drop(current);
 
-            // Get new theme tokens
-            let theme = self.bundle.for_scheme(scheme);
-            let new_colors = theme.colors().clone();
-
-            // Update non-color tokens immediately (they don't animate)
-            *self.shadows.write().unwrap() = theme.shadows().clone();
-            *self.spacing.write().unwrap() = theme.spacing().clone();
-            *self.typography.write().unwrap() = theme.typography().clone();
-            *self.radii.write().unwrap() = theme.radii().clone();
-            *self.animations.write().unwrap() = theme.animations().clone();
-
-            // Try to animate colors if scheduler handle is available
-            let handle_opt = self.scheduler_handle.read().unwrap().clone();
-            if let Some(handle) = handle_opt {
-                // Start animated transition using AnimatedValue
-                let mut transition = self.transition.lock().unwrap();
-                transition.from_colors = Some(old_colors.clone());
-                transition.to_colors = Some(new_colors.clone());
-
-                // Create AnimatedValue for progress (0 to 100, scaled to avoid spring epsilon issues)
-                // The animation scheduler's background thread will tick this automatically
-                let mut progress = AnimatedValue::new(handle, 0.0, SpringConfig::gentle());
-                progress.set_target(100.0);
-                transition.progress = Some(progress);
-
-                // Initialize colors to starting point (old colors at progress=0)
-                // This ensures immediate visual feedback before first tick
-                drop(transition);
-                *self.colors.write().unwrap() = old_colors;
-            } else {
-                // No scheduler, instant swap
-                *self.colors.write().unwrap() = new_colors;
-            }
+            self.apply_theme(self.active_bundle().for_scheme(scheme));
+        }
+    }
 
-            // Mark for repaint and layout
-            self.needs_repaint.store(true, Ordering::SeqCst);
-            self.needs_layout.store(true, Ordering::SeqCst);
+    /// Whether the high-contrast variant of the bundle is active
+    pub fn is_high_contrast(&self) -> bool {
+        self.high_contrast.load(Ordering::SeqCst)
+    }
 
-            // Trigger UI redraw
-            trigger_redraw();
+    /// Switch to or from the high-contrast variant of the current bundle
+    ///
+    /// Keeps the current color scheme; colors animate like a scheme switch.
+    pub fn set_high_contrast(&self, enabled: bool) {
+        if self.high_contrast.swap(enabled, Ordering::SeqCst) != enabled {
+            tracing::debug!("ThemeState::set_high_contrast({})", enabled);
+            self.apply_theme(self.active_bundle().for_scheme(self.scheme()));
         }
     }
 
+    /// Toggle the high-contrast variant
+    pub fn toggle_high_contrast(&self) {
+        self.set_high_contrast(!self.is_high_contrast());
+    }
+
+    /// The bundle currently in use (normal or high-contrast)
+    fn active_bundle(&self) -> &ThemeBundle {
+        if self.is_high_contrast() {
+            &self.high_contrast_bundle
+        } else {
+            &self.bundle
+        }
+    }
+
+    /// Switch all tokens to `theme`, animating colors if possible
+    fn apply_theme(&self, theme: Arc<dyn Theme>) {
+        // Get current colors before switching
+        let old_colors = self.colors.read().unwrap().clone();
+        let new_colors = theme.colors().clone();
+
+        // Update non-color tokens immediately (they don't animate)
+        *self.shadows.write().unwrap() = theme.shadows().clone();
+        *self.spacing.write().unwrap() = theme.spacing().clone();
+        *self.typography.write().unwrap() = theme.typography().clone();
+        *self.radii.write().unwrap() = theme.radii().clone();
+        *self.animations.write().unwrap() = theme.animations().clone();
+
+        // Try to animate colors if scheduler handle is available
+        let handle_opt = self.scheduler_handle.read().unwrap().clone();
+        if let Some(handle) = handle_opt {
+            // Start animated transition using AnimatedValue
+            let mut transition = self.transition.lock().unwrap();
+            transition.from_colors = Some(old_colors.clone());
+            transition.to_colors = Some(new_colors.clone());
+
+            // Create AnimatedValue for progress (0 to 100, scaled to avoid spring epsilon issues)
+            // The animation scheduler's background thread will tick this automatically
+            let mut progress = AnimatedValue::new(handle, 0.0, SpringConfig::gentle());
+            progress.set_target(100.0);
+            transition.progress = Some(progress);
+
+            // Initialize colors to starting point (old colors at progress=0)
+            // This ensures immediate visual feedback before first tick
+            drop(transition);
+            *self.colors.write().unwrap() = old_colors;
+        } else {
+            // No scheduler, instant swap
+            *self.colors.write().unwrap() = new_colors;
+        }
+
+        // Mark for repaint and layout
+        self.needs_repaint.store(true, Ordering::SeqCst);
+        self.needs_layout.store(true, Ordering::SeqCst);
+
+        // Trigger UI redraw
+        trigger_redraw();
+    }
+
     /// Update theme colors based on animation progress
     ///
     /// This should be called during the render loop to update interpolated colors.
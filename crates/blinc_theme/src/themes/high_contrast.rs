@@ -0,0 +1,307 @@
+//! High-contrast theme variants
+//!
+//! Derives an accessible variant from any theme instead of authoring one by
+//! hand: surfaces are pushed towards white (light) or black (dark), text is
+//! darkened/lightened until it reaches WCAG AAA (7:1) against every surface,
+//! borders and brand colors reach 4.5:1, and subtle shadows are dropped.
+//!
+//! Colors that already meet their target are left unchanged.
+
+use crate::theme::{ColorScheme, Theme, ThemeBundle};
+use crate::tokens::*;
+use blinc_core::Color;
+
+/// WCAG AAA contrast for normal text
+pub const CONTRAST_AAA: f32 = 7.0;
+
+/// WCAG AA contrast for normal text, used for borders and brand colors
+pub const CONTRAST_AA: f32 = 4.5;
+
+/// Surfaces lighter/darker than this are left alone; others are pushed
+/// towards the scheme's extreme so 7:1 text is achievable on them
+const LIGHT_SURFACE_MIN_LUMINANCE: f32 = 0.8;
+const DARK_SURFACE_MAX_LUMINANCE: f32 = 0.02;
+
+/// Shadows fainter than this are removed
+const SUBTLE_SHADOW_ALPHA: f32 = 0.2;
+
+/// A high-contrast variant derived from another theme
+#[derive(Clone, Debug)]
+pub struct HighContrastTheme {
+    name: String,
+    scheme: ColorScheme,
+    colors: ColorTokens,
+    typography: TypographyTokens,
+    spacing: SpacingTokens,
+    radii: RadiusTokens,
+    shadows: ShadowTokens,
+    animations: AnimationTokens,
+}
+
+impl HighContrastTheme {
+    /// Derive a high-contrast variant of `theme`
+    pub fn from_theme(theme: &dyn Theme) -> Self {
+        Self {
+            name: format!("{} High Contrast", theme.name()),
+            scheme: theme.color_scheme(),
+            colors: high_contrast_colors(theme.colors(), theme.color_scheme()),
+            typography: theme.typography().clone(),
+            spacing: theme.spacing().clone(),
+            radii: theme.radii().clone(),
+            shadows: strong_shadows(theme.shadows()),
+            animations: theme.animations().clone(),
+        }
+    }
+}
+
+impl Theme for HighContrastTheme {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn color_scheme(&self) -> ColorScheme {
+        self.scheme
+    }
+
+    fn colors(&self) -> &ColorTokens {
+        &self.colors
+    }
+
+    fn typography(&self) -> &TypographyTokens {
+        &self.typography
+    }
+
+    fn spacing(&self) -> &SpacingTokens {
+        &self.spacing
+    }
+
+    fn radii(&self) -> &RadiusTokens {
+        &self.radii
+    }
+
+    fn shadows(&self) -> &ShadowTokens {
+        &self.shadows
+    }
+
+    fn animations(&self) -> &AnimationTokens {
+        &self.animations
+    }
+}
+
+impl ThemeBundle {
+    /// Derive a high-contrast bundle from this one
+    ///
+    /// See [`HighContrastTheme`] for what changes. Use
+    /// [`ThemeState::set_high_contrast`](crate::ThemeState::set_high_contrast)
+    /// to switch to it at runtime.
+    pub fn high_contrast(&self) -> ThemeBundle {
+        ThemeBundle::new(
+            format!("{} High Contrast", self.name),
+            HighContrastTheme::from_theme(self.light.as_ref()),
+            HighContrastTheme::from_theme(self.dark.as_ref()),
+        )
+    }
+}
+
+/// Move `color` towards `target` just far enough to satisfy `meets`
+///
+/// Returns `color` unchanged if it already satisfies `meets`, and `target`
+/// if even that doesn't. Alpha is preserved.
+fn adjust_towards(color: Color, target: Color, meets: impl Fn(Color) -> bool) -> Color {
+    if meets(color) {
+        return color;
+    }
+    let at = |t: f32| Color::lerp(&color, &target, t).with_alpha(color.a);
+    if !meets(at(1.0)) {
+        return at(1.0);
+    }
+    // Smallest step that meets the requirement
+    let (mut lo, mut hi) = (0.0f32, 1.0f32);
+    for _ in 0..20 {
+        let mid = (lo + hi) / 2.0;
+        if meets(at(mid)) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    at(hi)
+}
+
+/// Adjust a foreground color until it reaches `ratio` against every background
+fn ensure_contrast(fg: Color, backgrounds: &[Color], ratio: f32, towards: Color) -> Color {
+    adjust_towards(fg, towards, |c| {
+        backgrounds.iter().all(|bg| c.contrast_ratio(bg) >= ratio)
+    })
+}
+
+fn high_contrast_colors(colors: &ColorTokens, scheme: ColorScheme) -> ColorTokens {
+    let mut c = colors.clone();
+
+    // Light themes push surfaces to white and ink to black; dark the reverse
+    let (paper, ink) = match scheme {
+        ColorScheme::Light => (Color::WHITE, Color::BLACK),
+        ColorScheme::Dark => (Color::BLACK, Color::WHITE),
+    };
+    let surface_ok = |color: Color| match scheme {
+        ColorScheme::Light => color.relative_luminance() >= LIGHT_SURFACE_MIN_LUMINANCE,
+        ColorScheme::Dark => color.relative_luminance() <= DARK_SURFACE_MAX_LUMINANCE,
+    };
+
+    for surface in [
+        &mut c.background,
+        &mut c.surface,
+        &mut c.surface_elevated,
+        &mut c.surface_overlay,
+        &mut c.input_bg,
+        &mut c.input_bg_hover,
+        &mut c.input_bg_focus,
+        &mut c.input_bg_disabled,
+    ] {
+        *surface = adjust_towards(*surface, paper, surface_ok);
+    }
+
+    let surfaces = [
+        c.background,
+        c.surface,
+        c.surface_elevated,
+        c.surface_overlay,
+        c.input_bg,
+        c.input_bg_hover,
+        c.input_bg_focus,
+        c.input_bg_disabled,
+    ];
+
+    for text in [
+        &mut c.text_primary,
+        &mut c.text_secondary,
+        &mut c.text_tertiary,
+        &mut c.text_link,
+        &mut c.selection_text,
+    ] {
+        *text = ensure_contrast(*text, &surfaces, CONTRAST_AAA, ink);
+    }
+
+    for strong in [
+        &mut c.primary,
+        &mut c.primary_hover,
+        &mut c.primary_active,
+        &mut c.secondary,
+        &mut c.secondary_hover,
+        &mut c.secondary_active,
+        &mut c.success,
+        &mut c.warning,
+        &mut c.error,
+        &mut c.info,
+        &mut c.accent,
+        &mut c.border,
+        &mut c.border_hover,
+        &mut c.border_focus,
+        &mut c.border_error,
+    ] {
+        *strong = ensure_contrast(*strong, &surfaces, CONTRAST_AA, ink);
+    }
+
+    // Text on primary buttons
+    c.text_inverse = ensure_contrast(
+        c.text_inverse,
+        &[c.primary, c.primary_hover, c.primary_active],
+        CONTRAST_AA,
+        paper,
+    );
+
+    // Tooltips are inverted: dark on light themes, light on dark themes
+    c.tooltip_bg = adjust_towards(c.tooltip_bg, ink, |color| {
+        color.contrast_ratio(&paper) >= CONTRAST_AAA
+    });
+    c.tooltip_text = ensure_contrast(c.tooltip_text, &[c.tooltip_bg], CONTRAST_AAA, paper);
+
+    c
+}
+
+fn strong_shadows(shadows: &ShadowTokens) -> ShadowTokens {
+    let keep = |shadow: &Shadow| {
+        if shadow.color.a < SUBTLE_SHADOW_ALPHA {
+            Shadow::none()
+        } else {
+            shadow.clone()
+        }
+    };
+    ShadowTokens {
+        shadow_sm: keep(&shadows.shadow_sm),
+        shadow_default: keep(&shadows.shadow_default),
+        shadow_md: keep(&shadows.shadow_md),
+        shadow_lg: keep(&shadows.shadow_lg),
+        shadow_xl: keep(&shadows.shadow_xl),
+        shadow_2xl: keep(&shadows.shadow_2xl),
+        shadow_inner: keep(&shadows.shadow_inner),
+        shadow_none: Shadow::none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::themes::platform::platform_theme_bundle;
+    use crate::themes::BlincTheme;
+
+    fn assert_text_contrast(theme: &dyn Theme) {
+        let c = theme.colors();
+        let surfaces = [c.background, c.surface, c.surface_elevated, c.input_bg];
+        for (name, text) in [
+            ("text_primary", c.text_primary),
+            ("text_secondary", c.text_secondary),
+            ("text_tertiary", c.text_tertiary),
+            ("text_link", c.text_link),
+        ] {
+            for bg in surfaces {
+                let ratio = text.contrast_ratio(&bg);
+                assert!(
+                    ratio >= CONTRAST_AAA,
+                    "{}: {} on {:?} is only {:.2}:1",
+                    theme.name(),
+                    name,
+                    bg,
+                    ratio
+                );
+            }
+        }
+        let tooltip = c.tooltip_text.contrast_ratio(&c.tooltip_bg);
+        assert!(
+            tooltip >= CONTRAST_AAA,
+            "{}: tooltip {:.2}:1",
+            theme.name(),
+            tooltip
+        );
+    }
+
+    #[test]
+    fn test_high_contrast_meets_aaa() {
+        for bundle in [BlincTheme::bundle(), platform_theme_bundle()] {
+            let hc = bundle.high_contrast();
+            assert_text_contrast(hc.light.as_ref());
+            assert_text_contrast(hc.dark.as_ref());
+            assert_eq!(hc.dark.color_scheme(), ColorScheme::Dark);
+        }
+    }
+
+    #[test]
+    fn test_max_contrast_colors_unchanged() {
+        let colors = ColorTokens {
+            background: Color::WHITE,
+            surface: Color::WHITE,
+            text_primary: Color::BLACK,
+            ..Default::default()
+        };
+        let hc = high_contrast_colors(&colors, ColorScheme::Light);
+        assert_eq!(hc.background, Color::WHITE);
+        assert_eq!(hc.text_primary, Color::BLACK);
+    }
+
+    #[test]
+    fn test_subtle_shadows_removed() {
+        let shadows = strong_shadows(&ShadowTokens::light());
+        assert_eq!(shadows.shadow_sm, Shadow::none());
+        assert_eq!(shadows.shadow_2xl, ShadowTokens::light().shadow_2xl);
+    }
+}
@@ -1,6 +1,8 @@
 //! Built-in themes
 
 mod blinc;
+mod high_contrast;
 pub mod platform;
 
 pub use blinc::*;
+pub use high_contrast::*;
@@ -62,7 +62,7 @@ pub enum ColorToken {
 }
 
 /// Complete set of semantic color tokens
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ColorTokens {
     // Brand colors
     pub primary: Color,
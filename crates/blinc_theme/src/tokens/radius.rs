@@ -15,7 +15,7 @@ pub enum RadiusToken {
 }
 
 /// Complete set of border radius tokens
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct RadiusTokens {
     pub radius_none: f32,
     pub radius_sm: f32,
@@ -16,7 +16,7 @@ pub enum ShadowToken {
 }
 
 /// A box shadow definition
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Shadow {
     pub offset_x: f32,
     pub offset_y: f32,
@@ -89,7 +89,7 @@ impl From<&Shadow> for blinc_core::Shadow {
 }
 
 /// Complete set of shadow tokens
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ShadowTokens {
     pub shadow_sm: Shadow,
     pub shadow_default: Shadow,
@@ -29,7 +29,7 @@ pub enum SpacingToken {
 }
 
 /// Complete set of spacing tokens (4px base scale)
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct SpacingTokens {
     pub space_0: f32,
     pub space_0_5: f32,
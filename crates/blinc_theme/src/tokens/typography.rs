@@ -254,3 +254,44 @@ impl Default for TypographyTokens {
         }
     }
 }
+
+/// Modular type scale: each step multiplies the base size by `ratio`
+///
+/// Headings are derived from it with `h6` at the base size and `h1` five
+/// steps up, so `TypographyScale::new(14.0, TypographyScale::MAJOR_THIRD)`
+/// gives 14, 17.5, 21.9, 27.3, 34.2, 42.7.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TypographyScale {
+    /// Size of step 0 (h6) in logical pixels
+    pub base: f32,
+    /// Ratio between consecutive steps
+    pub ratio: f32,
+}
+
+impl TypographyScale {
+    pub const MINOR_THIRD: f32 = 1.2;
+    pub const MAJOR_THIRD: f32 = 1.25;
+    pub const PERFECT_FOURTH: f32 = 4.0 / 3.0;
+    pub const GOLDEN_RATIO: f32 = 1.618;
+
+    pub const fn new(base: f32, ratio: f32) -> Self {
+        Self { base, ratio }
+    }
+
+    /// Size at `step` (negative steps go below the base size)
+    pub fn step(&self, step: i32) -> f32 {
+        self.base * self.ratio.powi(step)
+    }
+
+    /// Size for heading `level` (1-6, clamped)
+    pub fn heading(&self, level: u8) -> f32 {
+        let level = level.clamp(1, 6) as i32;
+        self.step(6 - level)
+    }
+}
+
+impl Default for TypographyScale {
+    fn default() -> Self {
+        Self::new(14.0, Self::MINOR_THIRD)
+    }
+}
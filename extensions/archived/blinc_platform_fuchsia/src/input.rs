@@ -472,11 +472,11 @@ pub fn convert_key(key_event: &KeyEvent) -> InputEvent {
         KeyState::Released => PlatformKeyState::Released,
     };
 
-    InputEvent::Keyboard(KeyboardEvent {
-        key: fuchsia_key_to_blinc(key_event.key),
-        state: platform_state,
-        modifiers: convert_modifiers(&key_event.modifiers),
-    })
+    InputEvent::Keyboard(KeyboardEvent::new(
+        fuchsia_key_to_blinc(key_event.key),
+        platform_state,
+        convert_modifiers(&key_event.modifiers),
+    ))
 }
 
 fn convert_modifiers(mods: &KeyModifiers) -> Modifiers {
@@ -8,6 +8,8 @@ use android_activity::AndroidApp;
 #[cfg(target_os = "android")]
 use jni::{objects::JValue, JNIEnv, JavaVM};
 
+use blinc_platform::PowerState;
+
 #[cfg(target_os = "android")]
 use tracing::{debug, warn};
 
@@ -221,6 +223,100 @@ fn check_dark_mode(
     Ok((ui_mode & UI_MODE_NIGHT_MASK) == UI_MODE_NIGHT_YES)
 }
 
+/// Read battery and power-saving state
+///
+/// Queries `BatteryManager` for the charge level and charging state, and
+/// `PowerManager.isPowerSaveMode()` for Battery Saver.
+/// Returns the default (plugged in, no level) if the query fails.
+#[cfg(target_os = "android")]
+pub fn get_power_state(app: &AndroidApp) -> PowerState {
+    let vm = match unsafe { JavaVM::from_raw(app.vm_as_ptr() as *mut _) } {
+        Ok(vm) => vm,
+        Err(e) => {
+            warn!("Failed to get JavaVM: {:?}", e);
+            return PowerState::default();
+        }
+    };
+
+    let mut env = match vm.attach_current_thread() {
+        Ok(env) => env,
+        Err(e) => {
+            warn!("Failed to attach JNI thread: {:?}", e);
+            return PowerState::default();
+        }
+    };
+
+    let activity = match app.activity_as_ptr() {
+        ptr if !ptr.is_null() => unsafe { jni::objects::JObject::from_raw(ptr as *mut _) },
+        _ => {
+            warn!("Activity pointer is null");
+            return PowerState::default();
+        }
+    };
+
+    query_power_state(&mut env, &activity).unwrap_or_else(|e| {
+        warn!("Failed to query power state: {:?}", e);
+        PowerState::default()
+    })
+}
+
+#[cfg(target_os = "android")]
+fn query_power_state(
+    env: &mut JNIEnv,
+    activity: &jni::objects::JObject,
+) -> Result<PowerState, jni::errors::Error> {
+    // BatteryManager: activity.getSystemService("batterymanager")
+    let battery_service = env.new_string("batterymanager")?;
+    let battery = env
+        .call_method(
+            activity,
+            "getSystemService",
+            "(Ljava/lang/String;)Ljava/lang/Object;",
+            &[JValue::Object(&battery_service)],
+        )?
+        .l()?;
+
+    // BatteryManager.BATTERY_PROPERTY_CAPACITY = 4 (percent, or
+    // Integer.MIN_VALUE if unsupported)
+    const BATTERY_PROPERTY_CAPACITY: i32 = 4;
+    let capacity = env
+        .call_method(
+            &battery,
+            "getIntProperty",
+            "(I)I",
+            &[JValue::Int(BATTERY_PROPERTY_CAPACITY)],
+        )?
+        .i()?;
+    let charging = env.call_method(&battery, "isCharging", "()Z", &[])?.z()?;
+
+    // PowerManager: activity.getSystemService("power")
+    let power_service = env.new_string("power")?;
+    let power = env
+        .call_method(
+            activity,
+            "getSystemService",
+            "(Ljava/lang/String;)Ljava/lang/Object;",
+            &[JValue::Object(&power_service)],
+        )?
+        .l()?;
+    let power_save = env
+        .call_method(&power, "isPowerSaveMode", "()Z", &[])?
+        .z()?;
+
+    debug!(
+        "Power state: capacity={}%, charging={}, power_save={}",
+        capacity, charging, power_save
+    );
+
+    Ok(PowerState {
+        on_battery: !charging,
+        low_power_mode: power_save,
+        level: (0..=100)
+            .contains(&capacity)
+            .then(|| capacity as f32 / 100.0),
+    })
+}
+
 // Placeholder implementations for non-Android builds
 #[cfg(not(target_os = "android"))]
 pub fn get_display_density(_app: &()) -> f64 {
@@ -236,3 +332,8 @@ pub fn get_display_dpi(_app: &()) -> i32 {
 pub fn is_dark_mode(_app: &()) -> bool {
     false
 }
+
+#[cfg(not(target_os = "android"))]
+pub fn get_power_state(_app: &()) -> PowerState {
+    PowerState::default()
+}
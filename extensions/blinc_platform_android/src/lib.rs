@@ -39,7 +39,7 @@ pub mod window;
 
 pub use assets::AndroidAssetLoader;
 pub use event_loop::{AndroidEventLoop, AndroidWakeProxy};
-pub use jni_utils::{get_display_density, get_display_dpi, is_dark_mode};
+pub use jni_utils::{get_display_density, get_display_dpi, get_power_state, is_dark_mode};
 pub use window::AndroidWindow;
 
 // Re-export native bridge types
@@ -100,6 +100,11 @@ impl Platform for AndroidPlatform {
     fn scale_factor(&self) -> f64 {
         1.0
     }
+
+    #[cfg(target_os = "android")]
+    fn power_state(&self) -> blinc_platform::PowerState {
+        get_power_state(&self.app)
+    }
 }
 
 // Placeholder implementation for non-Android builds
@@ -0,0 +1,54 @@
+//! File Dialog Example
+//!
+//! Manual check for native dialogs: press O to pick a file, M to pick several,
+//! S to choose a save location. The chosen path(s) are printed; the window
+//! keeps rendering while a dialog is open.
+//!
+//! Run with: cargo run -p blinc_platform_desktop --example file_dialog
+
+use blinc_platform::prelude::*;
+use blinc_platform_desktop::{DesktopPlatform, FileFilter};
+
+fn main() -> Result<()> {
+    let platform = DesktopPlatform::new()?;
+    let event_loop = platform
+        .create_event_loop_with_config(WindowConfig::new("File Dialog Example").size(480, 320))?;
+
+    let filters = vec![
+        FileFilter::new("Text", &["txt", "md"]),
+        FileFilter::new("Rust", &["rs"]),
+    ];
+
+    event_loop.run(move |event, window| {
+        match event {
+            Event::Input(InputEvent::Keyboard(KeyboardEvent {
+                key,
+                state: KeyState::Pressed,
+                ..
+            })) => match key {
+                Key::O => {
+                    let dialog = window.open_file_dialog(&filters);
+                    std::thread::spawn(move || {
+                        println!("Opened: {:?}", pollster::block_on(dialog));
+                    });
+                }
+                Key::M => {
+                    let dialog = window.open_files_dialog(&filters);
+                    std::thread::spawn(move || {
+                        println!("Opened: {:?}", pollster::block_on(dialog));
+                    });
+                }
+                Key::S => {
+                    let dialog = window.save_file_dialog("untitled.txt", &filters);
+                    std::thread::spawn(move || {
+                        println!("Save to: {:?}", pollster::block_on(dialog));
+                    });
+                }
+                _ => {}
+            },
+            Event::Window(WindowEvent::CloseRequested) => return ControlFlow::Exit,
+            _ => {}
+        }
+        ControlFlow::Continue
+    })
+}
@@ -0,0 +1,47 @@
+//! Tray Icon Example
+//!
+//! Manual check for the system tray: clicking "Say hello" should print a
+//! message, and "Quit" should exit the app.
+//!
+//! Run with: cargo run -p blinc_platform_desktop --example tray --features tray
+
+use blinc_platform::prelude::*;
+use blinc_platform_desktop::{DesktopPlatform, TrayIcon, TrayMenu};
+
+fn main() -> Result<()> {
+    // Simple 16x16 solid-color icon
+    let icon: Vec<u8> = std::iter::repeat([0x3b, 0x82, 0xf6, 0xff])
+        .take(16 * 16)
+        .flatten()
+        .collect();
+
+    let menu = TrayMenu::new()
+        .item("Say hello", || println!("Hello from the tray!"))
+        .disabled_item("Blinc tray example")
+        .separator()
+        .item("Quit", || std::process::exit(0));
+
+    let _tray = match TrayIcon::new()
+        .tooltip("Blinc")
+        .icon_rgba(icon, 16, 16)
+        .menu(menu)
+        .on_click(|| println!("Tray icon clicked"))
+        .on_double_click(|| println!("Tray icon double-clicked"))
+        .build()
+    {
+        Ok(tray) => Some(tray),
+        Err(e) => {
+            eprintln!("Tray unavailable: {}", e);
+            None
+        }
+    };
+
+    let platform = DesktopPlatform::new()?;
+    let event_loop =
+        platform.create_event_loop_with_config(WindowConfig::new("Tray Example").size(320, 200))?;
+
+    event_loop.run(|event, _window| match event {
+        Event::Window(WindowEvent::CloseRequested) => ControlFlow::Exit,
+        _ => ControlFlow::Continue,
+    })
+}
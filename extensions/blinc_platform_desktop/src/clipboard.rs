@@ -0,0 +1,52 @@
+//! Desktop clipboard access
+//!
+//! Thin wrapper over `arboard` for plain-text clipboard reads and writes.
+
+use std::sync::Mutex;
+
+/// System clipboard for macOS, Windows, and Linux
+///
+/// The underlying handle is opened lazily on first use and reopened if the
+/// platform connection is lost.
+#[derive(Default)]
+pub struct DesktopClipboard {
+    inner: Mutex<Option<arboard::Clipboard>>,
+}
+
+impl DesktopClipboard {
+    /// Create a clipboard handle
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_clipboard<R>(&self, f: impl FnOnce(&mut arboard::Clipboard) -> R) -> Option<R> {
+        let mut guard = self.inner.lock().ok()?;
+        if guard.is_none() {
+            match arboard::Clipboard::new() {
+                Ok(clipboard) => *guard = Some(clipboard),
+                Err(e) => {
+                    tracing::warn!("Failed to open system clipboard: {}", e);
+                    return None;
+                }
+            }
+        }
+        guard.as_mut().map(f)
+    }
+
+    /// Read plain text from the clipboard
+    pub fn get_text(&self) -> Option<String> {
+        self.with_clipboard(|c| c.get_text().ok()).flatten()
+    }
+
+    /// Replace the clipboard contents with plain text
+    pub fn set_text(&self, text: &str) {
+        let result = self.with_clipboard(|c| c.set_text(text.to_string()));
+        if let Some(Err(e)) = result {
+            tracing::warn!("Failed to write to system clipboard: {}", e);
+            // Drop the handle so the next access reconnects
+            if let Ok(mut guard) = self.inner.lock() {
+                *guard = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Native file open/save dialogs
+//!
+//! Dialogs are async (via `rfd`) so they never block the render loop, and are
+//! parented to the app window so they appear as sheets/modals on top of it.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use blinc_platform_desktop::dialog::FileFilter;
+//!
+//! let dialog = window.open_file_dialog(&[FileFilter::new("Images", &["png", "jpg"])]);
+//! std::thread::spawn(move || {
+//!     if let Some(path) = pollster::block_on(dialog) {
+//!         println!("picked {}", path.display());
+//!     }
+//! });
+//! ```
+
+use std::future::Future;
+use std::path::PathBuf;
+
+use crate::window::DesktopWindow;
+
+/// A named group of file extensions shown in a dialog's file-type selector
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileFilter {
+    /// Label shown to the user (e.g. "Images")
+    pub name: String,
+    /// Extensions without the leading dot (e.g. `["png", "jpg"]`)
+    pub extensions: Vec<String>,
+}
+
+impl FileFilter {
+    /// Create a filter
+    ///
+    /// Extensions may be given as `"png"`, `".png"` or `"*.png"`; they are
+    /// normalized to the bare extension. Empty entries are dropped.
+    pub fn new(name: impl Into<String>, extensions: &[&str]) -> Self {
+        let mut normalized: Vec<String> = Vec::with_capacity(extensions.len());
+        for ext in extensions {
+            let ext = ext.trim().trim_start_matches('*').trim_start_matches('.');
+            if !ext.is_empty() && !normalized.iter().any(|e| e == ext) {
+                normalized.push(ext.to_string());
+            }
+        }
+        Self {
+            name: name.into(),
+            extensions: normalized,
+        }
+    }
+
+    /// Whether this filter matches `path`'s extension (case-insensitive)
+    pub fn matches(&self, path: &std::path::Path) -> bool {
+        path.extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| self.extensions.iter().any(|f| f.eq_ignore_ascii_case(e)))
+    }
+}
+
+fn dialog_for(window: &DesktopWindow, filters: &[FileFilter]) -> rfd::AsyncFileDialog {
+    let mut dialog = rfd::AsyncFileDialog::new().set_parent(window.winit_window());
+    // A filter without extensions would hide every file
+    for filter in filters.iter().filter(|f| !f.extensions.is_empty()) {
+        dialog = dialog.add_filter(&filter.name, &filter.extensions);
+    }
+    dialog
+}
+
+impl DesktopWindow {
+    /// Show a native "open file" dialog
+    ///
+    /// Resolves to `None` if the user cancels.
+    pub fn open_file_dialog(
+        &self,
+        filters: &[FileFilter],
+    ) -> impl Future<Output = Option<PathBuf>> + Send + 'static {
+        let pick = dialog_for(self, filters).pick_file();
+        async move { pick.await.map(|file| file.path().to_path_buf()) }
+    }
+
+    /// Show a native "open files" dialog allowing multiple selection
+    ///
+    /// Resolves to an empty list if the user cancels.
+    pub fn open_files_dialog(
+        &self,
+        filters: &[FileFilter],
+    ) -> impl Future<Output = Vec<PathBuf>> + Send + 'static {
+        let pick = dialog_for(self, filters).pick_files();
+        async move {
+            pick.await
+                .unwrap_or_default()
+                .into_iter()
+                .map(|file| file.path().to_path_buf())
+                .collect()
+        }
+    }
+
+    /// Show a native "save file" dialog
+    ///
+    /// `default_name` pre-fills the file name. Resolves to `None` if the user cancels.
+    pub fn save_file_dialog(
+        &self,
+        default_name: &str,
+        filters: &[FileFilter],
+    ) -> impl Future<Output = Option<PathBuf>> + Send + 'static {
+        let save = dialog_for(self, filters)
+            .set_file_name(default_name)
+            .save_file();
+        async move { save.await.map(|file| file.path().to_path_buf()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn test_filter_normalizes_extensions() {
+        let filter = FileFilter::new("Images", &["png", ".jpg", "*.gif", "", "png"]);
+        assert_eq!(filter.name, "Images");
+        assert_eq!(filter.extensions, vec!["png", "jpg", "gif"]);
+    }
+
+    #[test]
+    fn test_filter_matches_case_insensitively() {
+        let filter = FileFilter::new("Rust", &["rs"]);
+        assert!(filter.matches(Path::new("src/main.rs")));
+        assert!(filter.matches(Path::new("LIB.RS")));
+        assert!(!filter.matches(Path::new("README")));
+        assert!(!filter.matches(Path::new("build.toml")));
+    }
+}
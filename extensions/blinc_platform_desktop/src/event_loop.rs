@@ -1,9 +1,11 @@
 //! Desktop event loop implementation using winit
 
 use crate::input;
+use crate::power::PowerMonitor;
 use crate::window::DesktopWindow;
 use blinc_platform::{
-    ControlFlow, Event, EventLoop, LifecycleEvent, PlatformError, Window, WindowConfig, WindowEvent,
+    ControlFlow, Event, EventLoop, LifecycleEvent, PlatformError, Window, WindowConfig,
+    WindowEvent, WindowState,
 };
 use winit::application::ApplicationHandler;
 use winit::event::{StartCause, WindowEvent as WinitWindowEvent};
@@ -67,7 +69,8 @@ impl EventLoop for DesktopEventLoop {
     where
         F: FnMut(Event, &Self::Window) -> ControlFlow + 'static,
     {
-        let mut app = DesktopApp::new(self.window_config, handler);
+        let power = PowerMonitor::start(self.wake_proxy.clone());
+        let mut app = DesktopApp::new(self.window_config, handler, power);
         self.event_loop
             .run_app(&mut app)
             .map_err(|e| PlatformError::EventLoop(e.to_string()))
@@ -81,24 +84,41 @@ where
 {
     window_config: WindowConfig,
     window: Option<DesktopWindow>,
+    /// Geometry saved on exit when the config has a state file
+    window_state: WindowState,
     handler: F,
     modifiers: ModifiersState,
+    /// Whether a dead key is waiting for the key it composes with
+    composing: bool,
     mouse_position: (f32, f32),
     should_exit: bool,
+    /// Reports battery/power-saving changes
+    power: PowerMonitor,
 }
 
 impl<F> DesktopApp<F>
 where
     F: FnMut(Event, &DesktopWindow) -> ControlFlow,
 {
-    fn new(window_config: WindowConfig, handler: F) -> Self {
+    fn new(window_config: WindowConfig, handler: F, power: PowerMonitor) -> Self {
+        let window_state = window_config
+            .saved_state
+            .clone()
+            .unwrap_or_else(|| WindowState {
+                width: window_config.width,
+                height: window_config.height,
+                ..Default::default()
+            });
         Self {
             window_config,
             window: None,
+            window_state,
             handler,
             modifiers: ModifiersState::empty(),
+            composing: false,
             mouse_position: (0.0, 0.0),
             should_exit: false,
+            power,
         }
     }
 
@@ -110,6 +130,16 @@ where
             }
         }
     }
+
+    /// Record the window's current geometry for `remember_state`
+    fn track_window_state(&mut self) {
+        if self.window_config.state_file.is_none() {
+            return;
+        }
+        if let Some(ref window) = self.window {
+            self.window_state.capture(window);
+        }
+    }
 }
 
 impl<F> ApplicationHandler for DesktopApp<F>
@@ -137,6 +167,16 @@ where
     }
 
     fn new_events(&mut self, _event_loop: &ActiveEventLoop, cause: StartCause) {
+        // Keep the tray responsive (GTK-driven on Linux)
+        crate::tray::pump_tray_events();
+
+        // Deliver power changes once there's a window to deliver them to
+        if self.window.is_some() {
+            if let Some(state) = self.power.take_change() {
+                self.handle_event(Event::Lifecycle(LifecycleEvent::PowerStateChanged(state)));
+            }
+        }
+
         // Request redraw on wait timeout (frame tick)
         if matches!(cause, StartCause::WaitCancelled { .. } | StartCause::Poll) {
             if let Some(ref window) = self.window {
@@ -160,6 +200,10 @@ where
             }
 
             WinitWindowEvent::Resized(size) => {
+                if let Some(ref window) = self.window {
+                    window.clear_pending_resize();
+                }
+                self.track_window_state();
                 self.handle_event(Event::Window(WindowEvent::Resized {
                     width: size.width,
                     height: size.height,
@@ -167,6 +211,7 @@ where
             }
 
             WinitWindowEvent::Moved(pos) => {
+                self.track_window_state();
                 self.handle_event(Event::Window(WindowEvent::Moved { x: pos.x, y: pos.y }));
             }
 
@@ -184,6 +229,17 @@ where
             }
 
             WinitWindowEvent::RedrawRequested => {
+                // Fullscreen/decoration toggles don't always produce a Resized
+                // event, so report the current size before drawing
+                let pending_size = self
+                    .window
+                    .as_ref()
+                    .filter(|w| w.take_pending_resize())
+                    .map(|w| w.size());
+                if let Some((width, height)) = pending_size {
+                    self.handle_event(Event::Window(WindowEvent::Resized { width, height }));
+                }
+
                 self.handle_event(Event::Frame);
                 if self.should_exit {
                     event_loop.exit();
@@ -195,9 +251,40 @@ where
             }
 
             WinitWindowEvent::KeyboardInput { event, .. } => {
-                let input_event =
-                    input::convert_keyboard_event(&event.logical_key, event.state, self.modifiers);
+                let mut input_event = input::convert_keyboard_event(
+                    &event.logical_key,
+                    event.physical_key,
+                    event.text.as_deref(),
+                    event.state,
+                    self.modifiers,
+                );
+                // A dead key starts a composition; the next key's text
+                // (the composed character) commits it instead of arriving
+                // as plain key text
+                let mut composition = None;
+                if let blinc_platform::InputEvent::Keyboard(ref mut keyboard) = input_event {
+                    if keyboard.state == blinc_platform::KeyState::Pressed {
+                        if let blinc_platform::Key::Dead(accent) = keyboard.key {
+                            self.composing = true;
+                            composition = Some(blinc_platform::CompositionEvent::Update(
+                                accent.map(String::from).unwrap_or_default(),
+                            ));
+                        } else if self.composing && !keyboard.key.is_modifier() {
+                            // Keys that produce nothing (arrows, Escape) cancel
+                            self.composing = false;
+                            composition = Some(match keyboard.text.take() {
+                                Some(text) => blinc_platform::CompositionEvent::Commit(text),
+                                None => blinc_platform::CompositionEvent::Update(String::new()),
+                            });
+                        }
+                    }
+                }
                 self.handle_event(Event::Input(input_event));
+                if let Some(composition) = composition {
+                    self.handle_event(Event::Input(blinc_platform::InputEvent::Composition(
+                        composition,
+                    )));
+                }
                 // Request immediate redraw so text input changes render instantly
                 if let Some(ref window) = self.window {
                     window.request_redraw();
@@ -264,6 +351,15 @@ where
         }
     }
 
+    fn exiting(&mut self, _event_loop: &ActiveEventLoop) {
+        self.track_window_state();
+        if let Some(ref path) = self.window_config.state_file {
+            if let Err(e) = self.window_state.save(path) {
+                tracing::warn!("Failed to save window state to {:?}: {}", path, e);
+            }
+        }
+    }
+
     fn memory_warning(&mut self, _event_loop: &ActiveEventLoop) {
         self.handle_event(Event::Lifecycle(LifecycleEvent::LowMemory));
     }
@@ -1,11 +1,13 @@
 //! Desktop input conversion (winit -> blinc_platform)
 
 use blinc_platform::{
-    InputEvent, Key, KeyState, KeyboardEvent, Modifiers, MouseButton, MouseEvent, ScrollPhase,
-    TouchEvent,
+    InputEvent, Key, KeyCode, KeyState, KeyboardEvent, Modifiers, MouseButton, MouseEvent,
+    ScrollPhase, TouchEvent,
 };
 use winit::event::{ElementState, MouseButton as WinitMouseButton, Touch, TouchPhase};
-use winit::keyboard::{Key as WinitKey, ModifiersState, NamedKey};
+use winit::keyboard::{
+    Key as WinitKey, KeyCode as WinitKeyCode, ModifiersState, NamedKey, PhysicalKey,
+};
 
 /// Convert winit mouse button to blinc MouseButton
 pub fn convert_mouse_button(button: WinitMouseButton) -> MouseButton {
@@ -63,7 +65,7 @@ pub fn convert_key(key: &WinitKey) -> Key {
             // Modifier keys
             NamedKey::Shift => Key::Shift,
             NamedKey::Control => Key::Ctrl,
-            NamedKey::Alt => Key::Alt,
+            NamedKey::Alt | NamedKey::AltGraph => Key::Alt,
             NamedKey::Super => Key::Meta,
 
             // Function keys
@@ -135,19 +137,139 @@ pub fn convert_key(key: &WinitKey) -> Key {
                 _ => Key::Char(ch),
             }
         }
+        WinitKey::Dead(accent) => Key::Dead(*accent),
         _ => Key::Unknown,
     }
 }
 
+/// Convert winit physical key to blinc KeyCode
+pub fn convert_key_code(key: PhysicalKey) -> KeyCode {
+    let PhysicalKey::Code(code) = key else {
+        return KeyCode::Unidentified;
+    };
+    match code {
+        // Letter row keys
+        WinitKeyCode::KeyA => KeyCode::KeyA,
+        WinitKeyCode::KeyB => KeyCode::KeyB,
+        WinitKeyCode::KeyC => KeyCode::KeyC,
+        WinitKeyCode::KeyD => KeyCode::KeyD,
+        WinitKeyCode::KeyE => KeyCode::KeyE,
+        WinitKeyCode::KeyF => KeyCode::KeyF,
+        WinitKeyCode::KeyG => KeyCode::KeyG,
+        WinitKeyCode::KeyH => KeyCode::KeyH,
+        WinitKeyCode::KeyI => KeyCode::KeyI,
+        WinitKeyCode::KeyJ => KeyCode::KeyJ,
+        WinitKeyCode::KeyK => KeyCode::KeyK,
+        WinitKeyCode::KeyL => KeyCode::KeyL,
+        WinitKeyCode::KeyM => KeyCode::KeyM,
+        WinitKeyCode::KeyN => KeyCode::KeyN,
+        WinitKeyCode::KeyO => KeyCode::KeyO,
+        WinitKeyCode::KeyP => KeyCode::KeyP,
+        WinitKeyCode::KeyQ => KeyCode::KeyQ,
+        WinitKeyCode::KeyR => KeyCode::KeyR,
+        WinitKeyCode::KeyS => KeyCode::KeyS,
+        WinitKeyCode::KeyT => KeyCode::KeyT,
+        WinitKeyCode::KeyU => KeyCode::KeyU,
+        WinitKeyCode::KeyV => KeyCode::KeyV,
+        WinitKeyCode::KeyW => KeyCode::KeyW,
+        WinitKeyCode::KeyX => KeyCode::KeyX,
+        WinitKeyCode::KeyY => KeyCode::KeyY,
+        WinitKeyCode::KeyZ => KeyCode::KeyZ,
+
+        // Number row keys
+        WinitKeyCode::Digit0 => KeyCode::Digit0,
+        WinitKeyCode::Digit1 => KeyCode::Digit1,
+        WinitKeyCode::Digit2 => KeyCode::Digit2,
+        WinitKeyCode::Digit3 => KeyCode::Digit3,
+        WinitKeyCode::Digit4 => KeyCode::Digit4,
+        WinitKeyCode::Digit5 => KeyCode::Digit5,
+        WinitKeyCode::Digit6 => KeyCode::Digit6,
+        WinitKeyCode::Digit7 => KeyCode::Digit7,
+        WinitKeyCode::Digit8 => KeyCode::Digit8,
+        WinitKeyCode::Digit9 => KeyCode::Digit9,
+
+        // Function keys
+        WinitKeyCode::F1 => KeyCode::F1,
+        WinitKeyCode::F2 => KeyCode::F2,
+        WinitKeyCode::F3 => KeyCode::F3,
+        WinitKeyCode::F4 => KeyCode::F4,
+        WinitKeyCode::F5 => KeyCode::F5,
+        WinitKeyCode::F6 => KeyCode::F6,
+        WinitKeyCode::F7 => KeyCode::F7,
+        WinitKeyCode::F8 => KeyCode::F8,
+        WinitKeyCode::F9 => KeyCode::F9,
+        WinitKeyCode::F10 => KeyCode::F10,
+        WinitKeyCode::F11 => KeyCode::F11,
+        WinitKeyCode::F12 => KeyCode::F12,
+
+        // Special keys
+        WinitKeyCode::Space => KeyCode::Space,
+        WinitKeyCode::Enter | WinitKeyCode::NumpadEnter => KeyCode::Enter,
+        WinitKeyCode::Escape => KeyCode::Escape,
+        WinitKeyCode::Backspace => KeyCode::Backspace,
+        WinitKeyCode::Tab => KeyCode::Tab,
+        WinitKeyCode::Delete => KeyCode::Delete,
+        WinitKeyCode::Insert => KeyCode::Insert,
+        WinitKeyCode::Home => KeyCode::Home,
+        WinitKeyCode::End => KeyCode::End,
+        WinitKeyCode::PageUp => KeyCode::PageUp,
+        WinitKeyCode::PageDown => KeyCode::PageDown,
+
+        // Arrow keys
+        WinitKeyCode::ArrowLeft => KeyCode::ArrowLeft,
+        WinitKeyCode::ArrowRight => KeyCode::ArrowRight,
+        WinitKeyCode::ArrowUp => KeyCode::ArrowUp,
+        WinitKeyCode::ArrowDown => KeyCode::ArrowDown,
+
+        // Modifier keys
+        WinitKeyCode::ShiftLeft => KeyCode::ShiftLeft,
+        WinitKeyCode::ShiftRight => KeyCode::ShiftRight,
+        WinitKeyCode::ControlLeft => KeyCode::ControlLeft,
+        WinitKeyCode::ControlRight => KeyCode::ControlRight,
+        WinitKeyCode::AltLeft => KeyCode::AltLeft,
+        WinitKeyCode::AltRight => KeyCode::AltRight,
+        WinitKeyCode::SuperLeft => KeyCode::MetaLeft,
+        WinitKeyCode::SuperRight => KeyCode::MetaRight,
+
+        // Punctuation and symbol keys
+        WinitKeyCode::Minus => KeyCode::Minus,
+        WinitKeyCode::Equal => KeyCode::Equal,
+        WinitKeyCode::BracketLeft => KeyCode::BracketLeft,
+        WinitKeyCode::BracketRight => KeyCode::BracketRight,
+        WinitKeyCode::Backslash => KeyCode::Backslash,
+        WinitKeyCode::Semicolon => KeyCode::Semicolon,
+        WinitKeyCode::Quote => KeyCode::Quote,
+        WinitKeyCode::Comma => KeyCode::Comma,
+        WinitKeyCode::Period => KeyCode::Period,
+        WinitKeyCode::Slash => KeyCode::Slash,
+        WinitKeyCode::Backquote => KeyCode::Backquote,
+        WinitKeyCode::IntlBackslash => KeyCode::IntlBackslash,
+
+        _ => KeyCode::Unidentified,
+    }
+}
+
 /// Convert winit keyboard event to blinc InputEvent
+///
+/// `key` is the layout-resolved key and `physical` the key's position, so
+/// the physical Y key on a German layout arrives as [`Key::Z`] with
+/// [`KeyCode::KeyY`]. `text` is what winit reports the press produced
+/// (including AltGr combinations and composed dead keys).
 pub fn convert_keyboard_event(
     key: &WinitKey,
+    physical: PhysicalKey,
+    text: Option<&str>,
     state: ElementState,
     modifiers: ModifiersState,
 ) -> InputEvent {
+    let state = convert_key_state(state);
     InputEvent::Keyboard(KeyboardEvent {
         key: convert_key(key),
-        state: convert_key_state(state),
+        code: convert_key_code(physical),
+        text: text
+            .filter(|text| state == KeyState::Pressed && !text.is_empty())
+            .map(str::to_string),
+        state,
         modifiers: convert_modifiers(modifiers),
     })
 }
@@ -220,3 +342,65 @@ pub fn scroll_event(delta_x: f32, delta_y: f32, phase: TouchPhase) -> InputEvent
 pub fn scroll_end_event() -> InputEvent {
     InputEvent::ScrollEnd
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keyboard(event: InputEvent) -> KeyboardEvent {
+        match event {
+            InputEvent::Keyboard(event) => event,
+            other => panic!("expected keyboard event, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_german_layout_reports_layout_key_and_physical_code() {
+        // The physical Y key on a German (QWERTZ) layout types "z"
+        let event = keyboard(convert_keyboard_event(
+            &WinitKey::Character("z".into()),
+            PhysicalKey::Code(WinitKeyCode::KeyY),
+            Some("z"),
+            ElementState::Pressed,
+            ModifiersState::empty(),
+        ));
+        assert_eq!(event.key, Key::Z);
+        assert_eq!(event.code, KeyCode::KeyY);
+        assert_eq!(event.text.as_deref(), Some("z"));
+
+        // AltGr+Q types "@"; Windows reports AltGr as Ctrl+Alt
+        let event = keyboard(convert_keyboard_event(
+            &WinitKey::Character("@".into()),
+            PhysicalKey::Code(WinitKeyCode::KeyQ),
+            Some("@"),
+            ElementState::Pressed,
+            ModifiersState::CONTROL | ModifiersState::ALT,
+        ));
+        assert_eq!(event.key, Key::Char('@'));
+        assert_eq!(event.code, KeyCode::KeyQ);
+        assert_eq!(event.text.as_deref(), Some("@"));
+    }
+
+    #[test]
+    fn test_dead_key_and_release_carry_no_text() {
+        let event = keyboard(convert_keyboard_event(
+            &WinitKey::Dead(Some('´')),
+            PhysicalKey::Code(WinitKeyCode::Equal),
+            None,
+            ElementState::Pressed,
+            ModifiersState::empty(),
+        ));
+        assert_eq!(event.key, Key::Dead(Some('´')));
+        assert_eq!(event.code, KeyCode::Equal);
+        assert_eq!(event.text, None);
+
+        let event = keyboard(convert_keyboard_event(
+            &WinitKey::Character("z".into()),
+            PhysicalKey::Code(WinitKeyCode::KeyY),
+            Some("z"),
+            ElementState::Released,
+            ModifiersState::empty(),
+        ));
+        assert_eq!(event.text, None);
+    }
+}
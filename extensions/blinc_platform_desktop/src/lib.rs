@@ -30,14 +30,22 @@
 //! }
 //! ```
 
+pub mod clipboard;
+pub mod dialog;
 pub mod event_loop;
 pub mod input;
+pub mod monitor;
+pub mod power;
+pub mod tray;
 pub mod window;
 
+pub use clipboard::DesktopClipboard;
+pub use dialog::FileFilter;
 pub use event_loop::{DesktopEventLoop, WakeProxy};
+pub use tray::{TrayHandle, TrayIcon, TrayMenu, TrayMenuItem};
 pub use window::DesktopWindow;
 
-use blinc_platform::{Platform, PlatformError, WindowConfig};
+use blinc_platform::{MonitorInfo, Platform, PlatformError, PowerState, WindowConfig};
 
 /// Desktop platform implementation
 ///
@@ -64,6 +72,14 @@ impl Platform for DesktopPlatform {
         // Default scale factor; actual value comes from window
         1.0
     }
+
+    fn available_monitors(&self) -> Vec<MonitorInfo> {
+        monitor::available_monitors()
+    }
+
+    fn power_state(&self) -> PowerState {
+        power::power_state()
+    }
 }
 
 impl DesktopPlatform {
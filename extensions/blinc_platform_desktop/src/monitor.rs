@@ -0,0 +1,50 @@
+//! Monitor enumeration
+//!
+//! winit only exposes monitors while the event loop is running, so the event
+//! loop snapshots them on resume and `DesktopPlatform::available_monitors`
+//! reads the latest snapshot.
+
+use blinc_platform::MonitorInfo;
+use std::sync::RwLock;
+use winit::event_loop::ActiveEventLoop;
+use winit::monitor::MonitorHandle;
+
+static MONITORS: RwLock<Vec<MonitorInfo>> = RwLock::new(Vec::new());
+
+/// Convert a winit monitor handle to platform monitor info
+pub(crate) fn monitor_info(handle: &MonitorHandle, primary: Option<&MonitorHandle>) -> MonitorInfo {
+    let position = handle.position();
+    let size = handle.size();
+    MonitorInfo {
+        name: handle.name(),
+        position: (position.x, position.y),
+        size: (size.width, size.height),
+        scale_factor: handle.scale_factor(),
+        refresh_rate: handle
+            .refresh_rate_millihertz()
+            .map(|mhz| mhz as f32 / 1000.0),
+        is_primary: primary == Some(handle),
+    }
+}
+
+/// Snapshot the connected monitors
+///
+/// Returns the handles (in the same order as the snapshot) so callers can
+/// map a `MonitorInfo` index back to a winit monitor.
+pub(crate) fn refresh_monitors(event_loop: &ActiveEventLoop) -> Vec<MonitorHandle> {
+    let handles: Vec<MonitorHandle> = event_loop.available_monitors().collect();
+    let primary = event_loop.primary_monitor();
+    let infos = handles
+        .iter()
+        .map(|h| monitor_info(h, primary.as_ref()))
+        .collect();
+    if let Ok(mut monitors) = MONITORS.write() {
+        *monitors = infos;
+    }
+    handles
+}
+
+/// Latest monitor snapshot (empty until the event loop has started)
+pub fn available_monitors() -> Vec<MonitorInfo> {
+    MONITORS.read().map(|m| m.clone()).unwrap_or_default()
+}
@@ -0,0 +1,184 @@
+//! Battery and power-saving state on desktop
+//!
+//! Linux reads `/sys/class/power_supply` and the ACPI platform profile,
+//! macOS asks `pmset`, and Windows uses `GetSystemPowerStatus`. The event
+//! loop polls through [`PowerMonitor`] and emits
+//! `LifecycleEvent::PowerStateChanged` when the state changes.
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use blinc_platform::PowerState;
+
+use crate::event_loop::WakeProxy;
+
+/// How often the monitor re-reads the power state
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Read the current power state
+///
+/// Unsupported systems and read failures report a plugged-in device with no
+/// battery level.
+pub fn power_state() -> PowerState {
+    read_power_state().unwrap_or_default()
+}
+
+#[cfg(target_os = "linux")]
+fn read_power_state() -> Option<PowerState> {
+    let mut state =
+        PowerState::from_power_supply_dir(std::path::Path::new("/sys/class/power_supply"))?;
+    state.low_power_mode = std::fs::read_to_string("/sys/firmware/acpi/platform_profile")
+        .is_ok_and(|profile| profile.trim() == "low-power");
+    Some(state)
+}
+
+#[cfg(target_os = "macos")]
+fn read_power_state() -> Option<PowerState> {
+    let pmset = |args: &[&str]| {
+        let output = std::process::Command::new("pmset")
+            .args(args)
+            .output()
+            .ok()?;
+        String::from_utf8(output.stdout).ok()
+    };
+    let mut state = parse_pmset_batt(&pmset(&["-g", "batt"])?);
+    state.low_power_mode = pmset(&["-g"]).is_some_and(|settings| parse_pmset_low_power(&settings));
+    Some(state)
+}
+
+#[cfg(target_os = "windows")]
+fn read_power_state() -> Option<PowerState> {
+    use windows::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+
+    let mut status = SYSTEM_POWER_STATUS::default();
+    unsafe { GetSystemPowerStatus(&mut status) }.ok()?;
+
+    // 128 = no system battery, 255 = unknown
+    let has_battery = status.BatteryFlag != 128 && status.BatteryFlag != 255;
+    Some(PowerState {
+        on_battery: has_battery && status.ACLineStatus == 0,
+        // Bit 0: battery saver is on
+        low_power_mode: status.SystemStatusFlag & 1 != 0,
+        level: (has_battery && status.BatteryLifePercent <= 100)
+            .then(|| status.BatteryLifePercent as f32 / 100.0),
+    })
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn read_power_state() -> Option<PowerState> {
+    None
+}
+
+/// Parse `pmset -g batt`:
+///
+/// ```text
+/// Now drawing from 'Battery Power'
+///  -InternalBattery-0 (id=4653155)    85%; discharging; 4:10 remaining present: true
+/// ```
+#[cfg(any(target_os = "macos", test))]
+fn parse_pmset_batt(output: &str) -> PowerState {
+    let level = output
+        .split_whitespace()
+        .find_map(|token| token.strip_suffix("%;"))
+        .and_then(|percent| percent.parse::<f32>().ok())
+        .map(|percent| (percent / 100.0).clamp(0.0, 1.0));
+    PowerState {
+        on_battery: output.contains("'Battery Power'"),
+        low_power_mode: false,
+        level,
+    }
+}
+
+/// Whether `pmset -g` reports Low Power Mode on (`lowpowermode 1`)
+#[cfg(any(target_os = "macos", test))]
+fn parse_pmset_low_power(output: &str) -> bool {
+    output.lines().any(|line| {
+        let mut fields = line.split_whitespace();
+        matches!(
+            (fields.next(), fields.next()),
+            (Some("lowpowermode" | "powermode"), Some("1"))
+        )
+    })
+}
+
+/// Polls [`power_state`] on a background thread and hands changes to the
+/// event loop
+pub(crate) struct PowerMonitor {
+    /// Latest state not yet taken by the event loop
+    pending: Arc<Mutex<Option<PowerState>>>,
+}
+
+impl PowerMonitor {
+    /// Start polling; `wake` is signalled whenever the state changes
+    ///
+    /// The first reading counts as a change, so the event loop learns the
+    /// initial state. The thread stops once the monitor is dropped.
+    pub(crate) fn start(wake: WakeProxy) -> Self {
+        let pending = Arc::new(Mutex::new(None));
+        let shared = Arc::downgrade(&pending);
+        let spawned = thread::Builder::new()
+            .name("blinc-power".to_string())
+            .spawn(move || {
+                let mut last = None;
+                while let Some(pending) = shared.upgrade() {
+                    let state = power_state();
+                    if last != Some(state) {
+                        last = Some(state);
+                        if let Ok(mut pending) = pending.lock() {
+                            *pending = Some(state);
+                        }
+                        wake.wake();
+                    }
+                    drop(pending);
+                    thread::sleep(POLL_INTERVAL);
+                }
+            });
+        if let Err(e) = spawned {
+            tracing::warn!("Failed to start power monitor: {}", e);
+        }
+        Self { pending }
+    }
+
+    /// The new state, if it changed since the last call
+    pub(crate) fn take_change(&self) -> Option<PowerState> {
+        self.pending.lock().ok()?.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_power_state_is_plausible() {
+        let state = power_state();
+        if let Some(level) = state.level {
+            assert!((0.0..=1.0).contains(&level), "level {level}");
+        }
+    }
+
+    #[test]
+    fn test_parse_pmset() {
+        let batt = "Now drawing from 'Battery Power'\n -InternalBattery-0 (id=4653155)\t85%; discharging; 4:10 remaining present: true\n";
+        let state = parse_pmset_batt(batt);
+        assert!(state.on_battery);
+        assert_eq!(state.level, Some(0.85));
+
+        let batt = "Now drawing from 'AC Power'\n -InternalBattery-0 (id=4653155)\t100%; charged; 0:00 remaining present: true\n";
+        let state = parse_pmset_batt(batt);
+        assert!(!state.on_battery);
+        assert_eq!(state.level, Some(1.0));
+
+        // Desktop Macs have no battery line
+        assert_eq!(
+            parse_pmset_batt("Now drawing from 'AC Power'\n").level,
+            None
+        );
+
+        assert!(parse_pmset_low_power(
+            "System-wide power settings:\nCurrently in use:\n standby              1\n lowpowermode         1\n"
+        ));
+        assert!(!parse_pmset_low_power(" standby 1\n lowpowermode 0\n"));
+    }
+}
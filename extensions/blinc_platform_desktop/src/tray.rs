@@ -0,0 +1,486 @@
+//! System tray icon and context menu
+//!
+//! The menu model ([`TrayMenu`]) is plain data and always available. Showing
+//! the icon requires the `tray` feature, which uses the `tray-icon` crate
+//! (Win32 on Windows, NSStatusItem on macOS, AppIndicator on Linux).
+//!
+//! # Example
+//!
+//! ```ignore
+//! use blinc_platform_desktop::tray::{TrayIcon, TrayMenu};
+//!
+//! let menu = TrayMenu::new()
+//!     .item("Open", || println!("open"))
+//!     .separator()
+//!     .item("Quit", || std::process::exit(0));
+//!
+//! let _tray = TrayIcon::new()
+//!     .tooltip("My App")
+//!     .icon_rgba(icon_bytes, 32, 32)
+//!     .menu(menu)
+//!     .on_click(|| println!("tray clicked"))
+//!     .build()?;
+//! ```
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use blinc_platform::PlatformError;
+
+/// Callback invoked for tray icon and menu events
+pub type TrayCallback = Arc<dyn Fn() + Send + Sync>;
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+fn next_id(prefix: &str) -> String {
+    format!("{}-{}", prefix, NEXT_ID.fetch_add(1, Ordering::Relaxed))
+}
+
+/// An entry in a tray menu
+#[derive(Clone)]
+pub enum TrayMenuItem {
+    /// Clickable item
+    Item {
+        /// Unique id used to route activation back to the callback
+        id: String,
+        /// Item label
+        label: String,
+        /// Whether the item can be activated
+        enabled: bool,
+        /// Called when the item is activated
+        callback: Option<TrayCallback>,
+    },
+    /// Separator line
+    Separator,
+    /// Nested menu
+    Submenu {
+        /// Submenu label
+        label: String,
+        /// Submenu items
+        menu: TrayMenu,
+    },
+}
+
+impl std::fmt::Debug for TrayMenuItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Item {
+                id, label, enabled, ..
+            } => f
+                .debug_struct("Item")
+                .field("id", id)
+                .field("label", label)
+                .field("enabled", enabled)
+                .finish(),
+            Self::Separator => f.write_str("Separator"),
+            Self::Submenu { label, menu } => f
+                .debug_struct("Submenu")
+                .field("label", label)
+                .field("menu", menu)
+                .finish(),
+        }
+    }
+}
+
+/// Tray context menu
+#[derive(Clone, Debug, Default)]
+pub struct TrayMenu {
+    items: Vec<TrayMenuItem>,
+}
+
+impl TrayMenu {
+    /// Create an empty menu
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a clickable item
+    pub fn item<F>(mut self, label: impl Into<String>, callback: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.items.push(TrayMenuItem::Item {
+            id: next_id("tray-item"),
+            label: label.into(),
+            enabled: true,
+            callback: Some(Arc::new(callback)),
+        });
+        self
+    }
+
+    /// Add a greyed-out item that cannot be activated
+    pub fn disabled_item(mut self, label: impl Into<String>) -> Self {
+        self.items.push(TrayMenuItem::Item {
+            id: next_id("tray-item"),
+            label: label.into(),
+            enabled: false,
+            callback: None,
+        });
+        self
+    }
+
+    /// Add a separator line
+    pub fn separator(mut self) -> Self {
+        self.items.push(TrayMenuItem::Separator);
+        self
+    }
+
+    /// Add a nested submenu
+    pub fn submenu(mut self, label: impl Into<String>, menu: TrayMenu) -> Self {
+        self.items.push(TrayMenuItem::Submenu {
+            label: label.into(),
+            menu,
+        });
+        self
+    }
+
+    /// Top-level items
+    pub fn items(&self) -> &[TrayMenuItem] {
+        &self.items
+    }
+
+    /// Find the callback for an item id, searching submenus
+    pub fn callback(&self, id: &str) -> Option<&TrayCallback> {
+        self.items.iter().find_map(|item| match item {
+            TrayMenuItem::Item {
+                id: item_id,
+                enabled: true,
+                callback,
+                ..
+            } if item_id == id => callback.as_ref(),
+            TrayMenuItem::Submenu { menu, .. } => menu.callback(id),
+            _ => None,
+        })
+    }
+
+    /// Activate the item with the given id
+    ///
+    /// Returns `true` if an enabled item with a callback was found.
+    pub fn activate(&self, id: &str) -> bool {
+        match self.callback(id) {
+            Some(callback) => {
+                callback();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// RGBA icon image for the tray
+#[derive(Clone, Debug)]
+pub struct TrayIconImage {
+    /// Pixel data, 4 bytes per pixel, row-major
+    pub rgba: Vec<u8>,
+    /// Width in pixels
+    pub width: u32,
+    /// Height in pixels
+    pub height: u32,
+}
+
+/// Tray icon builder
+#[derive(Default)]
+pub struct TrayIcon {
+    tooltip: Option<String>,
+    icon: Option<TrayIconImage>,
+    menu: Option<TrayMenu>,
+    on_click: Option<TrayCallback>,
+    on_double_click: Option<TrayCallback>,
+}
+
+impl TrayIcon {
+    /// Create a tray icon builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the hover tooltip
+    pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Set the icon from raw RGBA pixels
+    pub fn icon_rgba(mut self, rgba: Vec<u8>, width: u32, height: u32) -> Self {
+        self.icon = Some(TrayIconImage {
+            rgba,
+            width,
+            height,
+        });
+        self
+    }
+
+    /// Set the context menu
+    pub fn menu(mut self, menu: TrayMenu) -> Self {
+        self.menu = Some(menu);
+        self
+    }
+
+    /// Called when the icon is clicked with the primary button
+    pub fn on_click<F>(mut self, callback: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.on_click = Some(Arc::new(callback));
+        self
+    }
+
+    /// Called when the icon is double-clicked (Windows only; ignored elsewhere)
+    pub fn on_double_click<F>(mut self, callback: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.on_double_click = Some(Arc::new(callback));
+        self
+    }
+
+    /// Show the tray icon
+    ///
+    /// The icon stays visible until the returned handle is dropped. Returns
+    /// `PlatformError::Unavailable` if the system has no tray (e.g. a Linux
+    /// desktop without a StatusNotifier host).
+    #[cfg(feature = "tray")]
+    pub fn build(self) -> Result<TrayHandle, PlatformError> {
+        backend::build(self)
+    }
+
+    /// Show the tray icon
+    ///
+    /// Always fails: this build was compiled without the `tray` feature.
+    #[cfg(not(feature = "tray"))]
+    pub fn build(self) -> Result<TrayHandle, PlatformError> {
+        Err(PlatformError::Unsupported(
+            "tray icons require the `tray` feature".to_string(),
+        ))
+    }
+}
+
+/// Handle keeping a tray icon alive
+///
+/// Dropping the handle removes the icon and its callbacks.
+pub struct TrayHandle {
+    #[cfg(feature = "tray")]
+    inner: backend::Handle,
+}
+
+impl TrayHandle {
+    /// Update the hover tooltip
+    #[allow(unused_variables)]
+    pub fn set_tooltip(&self, tooltip: &str) {
+        #[cfg(feature = "tray")]
+        self.inner.set_tooltip(tooltip);
+    }
+}
+
+/// Process pending tray events
+///
+/// On Linux the tray is driven by GTK, which needs its events pumped from the
+/// main thread; the desktop event loop calls this every iteration. No-op elsewhere.
+pub fn pump_tray_events() {
+    #[cfg(all(feature = "tray", target_os = "linux"))]
+    backend::pump();
+}
+
+#[cfg(feature = "tray")]
+mod backend {
+    use std::collections::HashMap;
+    use std::sync::{Mutex, OnceLock};
+
+    use tray_icon::menu::{IsMenuItem, Menu, MenuEvent, MenuItem, PredefinedMenuItem, Submenu};
+    use tray_icon::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent};
+
+    use super::{next_id, TrayCallback, TrayIcon, TrayMenu, TrayMenuItem};
+    use blinc_platform::PlatformError;
+
+    #[derive(Default)]
+    struct IconCallbacks {
+        on_click: Option<TrayCallback>,
+        on_double_click: Option<TrayCallback>,
+    }
+
+    #[derive(Default)]
+    struct Registry {
+        menus: HashMap<String, TrayMenu>,
+        icons: HashMap<String, IconCallbacks>,
+    }
+
+    /// Callbacks for every live tray, looked up by the ids tray-icon reports
+    fn registry() -> &'static Mutex<Registry> {
+        static REGISTRY: OnceLock<Mutex<Registry>> = OnceLock::new();
+        REGISTRY.get_or_init(|| {
+            MenuEvent::set_event_handler(Some(|event: MenuEvent| {
+                // Clone the callback out so it runs without holding the lock
+                let callback = registry().lock().ok().and_then(|r| {
+                    r.menus
+                        .values()
+                        .find_map(|menu| menu.callback(&event.id.0).cloned())
+                });
+                if let Some(callback) = callback {
+                    callback();
+                }
+            }));
+            TrayIconEvent::set_event_handler(Some(|event: TrayIconEvent| {
+                let callback = registry().lock().ok().and_then(|r| match &event {
+                    TrayIconEvent::Click {
+                        id,
+                        button: MouseButton::Left,
+                        button_state: MouseButtonState::Up,
+                        ..
+                    } => r.icons.get(&id.0).and_then(|c| c.on_click.clone()),
+                    TrayIconEvent::DoubleClick {
+                        id,
+                        button: MouseButton::Left,
+                        ..
+                    } => r.icons.get(&id.0).and_then(|c| c.on_double_click.clone()),
+                    _ => None,
+                });
+                if let Some(callback) = callback {
+                    callback();
+                }
+            }));
+            Mutex::new(Registry::default())
+        })
+    }
+
+    pub(super) struct Handle {
+        tray: tray_icon::TrayIcon,
+    }
+
+    impl Handle {
+        pub(super) fn set_tooltip(&self, tooltip: &str) {
+            if let Err(e) = self.tray.set_tooltip(Some(tooltip)) {
+                tracing::warn!("Failed to set tray tooltip: {}", e);
+            }
+        }
+    }
+
+    impl Drop for Handle {
+        fn drop(&mut self) {
+            if let Ok(mut r) = registry().lock() {
+                r.menus.remove(&self.tray.id().0);
+                r.icons.remove(&self.tray.id().0);
+            }
+        }
+    }
+
+    fn append_items(
+        menu: &TrayMenu,
+        append: &mut dyn FnMut(&dyn IsMenuItem) -> tray_icon::menu::Result<()>,
+    ) -> tray_icon::menu::Result<()> {
+        for item in menu.items() {
+            match item {
+                TrayMenuItem::Item {
+                    id, label, enabled, ..
+                } => append(&MenuItem::with_id(id.as_str(), label, *enabled, None))?,
+                TrayMenuItem::Separator => append(&PredefinedMenuItem::separator())?,
+                TrayMenuItem::Submenu { label, menu } => {
+                    let submenu = Submenu::new(label, true);
+                    append_items(menu, &mut |child| submenu.append(child))?;
+                    append(&submenu)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn build(config: TrayIcon) -> Result<super::TrayHandle, PlatformError> {
+        #[cfg(target_os = "linux")]
+        gtk::init().map_err(|e| PlatformError::Unavailable(format!("GTK unavailable: {}", e)))?;
+
+        let id = next_id("tray");
+        let mut builder = TrayIconBuilder::new().with_id(id.as_str());
+
+        if let Some(tooltip) = &config.tooltip {
+            builder = builder.with_tooltip(tooltip);
+        }
+
+        if let Some(image) = config.icon {
+            let icon = tray_icon::Icon::from_rgba(image.rgba, image.width, image.height)
+                .map_err(|e| PlatformError::Other(format!("Invalid tray icon: {}", e)))?;
+            builder = builder.with_icon(icon);
+        }
+
+        if let Some(menu) = &config.menu {
+            let native = Menu::new();
+            append_items(menu, &mut |item| native.append(item))
+                .map_err(|e| PlatformError::Other(format!("Failed to build tray menu: {}", e)))?;
+            builder = builder.with_menu(Box::new(native));
+        }
+
+        // The Linux backend dlopens libappindicator and panics if it is missing
+        let tray = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| builder.build()))
+            .map_err(|_| PlatformError::Unavailable("no system tray available".to_string()))?
+            .map_err(|e| {
+                PlatformError::Unavailable(format!("Failed to create tray icon: {}", e))
+            })?;
+
+        if let Ok(mut r) = registry().lock() {
+            if let Some(menu) = config.menu {
+                r.menus.insert(id.clone(), menu);
+            }
+            r.icons.insert(
+                id,
+                IconCallbacks {
+                    on_click: config.on_click,
+                    on_double_click: config.on_double_click,
+                },
+            );
+        }
+
+        Ok(super::TrayHandle {
+            inner: Handle { tray },
+        })
+    }
+
+    #[cfg(target_os = "linux")]
+    pub(super) fn pump() {
+        while gtk::events_pending() {
+            gtk::main_iteration_do(false);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    #[test]
+    fn test_menu_model_construction() {
+        let clicks = Arc::new(AtomicUsize::new(0));
+        let clicks_for_item = Arc::clone(&clicks);
+
+        let menu = TrayMenu::new()
+            .item("Open", move || {
+                clicks_for_item.fetch_add(1, Ordering::SeqCst);
+            })
+            .disabled_item("Status: idle")
+            .separator()
+            .submenu("More", TrayMenu::new().item("About", || {}));
+
+        assert_eq!(menu.items().len(), 4);
+        assert!(matches!(menu.items()[2], TrayMenuItem::Separator));
+
+        let TrayMenuItem::Item { id: open, .. } = &menu.items()[0] else {
+            panic!("expected item");
+        };
+        assert!(menu.activate(open));
+        assert_eq!(clicks.load(Ordering::SeqCst), 1);
+
+        // Items inside submenus are found too
+        let TrayMenuItem::Submenu { menu: more, .. } = &menu.items()[3] else {
+            panic!("expected submenu");
+        };
+        let TrayMenuItem::Item { id: about, .. } = &more.items()[0] else {
+            panic!("expected item");
+        };
+        assert_ne!(open, about);
+        assert!(menu.activate(about));
+
+        let TrayMenuItem::Item { id: disabled, .. } = &menu.items()[1] else {
+            panic!("expected item");
+        };
+        assert!(!menu.activate(disabled));
+        assert!(!menu.activate("missing"));
+    }
+}
@@ -2,15 +2,63 @@
 
 use blinc_platform::{Cursor, Window, WindowConfig};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
-use winit::dpi::LogicalSize;
+use std::sync::{Arc, Mutex};
+use winit::dpi::{LogicalSize, PhysicalPosition, PhysicalSize};
 use winit::event_loop::ActiveEventLoop;
-use winit::window::{Window as WinitWindow, WindowAttributes};
+use winit::window::{Fullscreen, Window as WinitWindow, WindowAttributes, WindowLevel};
 
 /// Desktop window wrapping a winit window
 pub struct DesktopWindow {
     window: Arc<WinitWindow>,
     focused: AtomicBool,
+    resize: Mutex<ResizeState>,
+}
+
+/// Fullscreen and synthetic-resize bookkeeping, kept apart from winit
+#[derive(Debug, Default)]
+struct ResizeState {
+    /// Windowed size to restore when leaving fullscreen
+    windowed_size: Option<PhysicalSize<u32>>,
+    /// Set when a state change may resize the window without the OS
+    /// reporting it; the event loop then emits a synthetic resize
+    pending: bool,
+}
+
+/// What a fullscreen request should do to the window
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum FullscreenChange {
+    /// Already in the requested state
+    Unchanged,
+    /// Go fullscreen
+    Enter,
+    /// Leave fullscreen, restoring the windowed size if one was saved
+    Exit(Option<PhysicalSize<u32>>),
+}
+
+impl ResizeState {
+    /// Record a fullscreen request made at window size `size`
+    fn set_fullscreen(
+        &mut self,
+        fullscreen: bool,
+        is_fullscreen: bool,
+        size: PhysicalSize<u32>,
+    ) -> FullscreenChange {
+        if fullscreen == is_fullscreen {
+            return FullscreenChange::Unchanged;
+        }
+        self.pending = true;
+        if fullscreen {
+            self.windowed_size = Some(size);
+            FullscreenChange::Enter
+        } else {
+            FullscreenChange::Exit(self.windowed_size.take())
+        }
+    }
+
+    /// Take the pending synthetic resize, if any
+    fn take_pending(&mut self) -> bool {
+        std::mem::take(&mut self.pending)
+    }
 }
 
 impl DesktopWindow {
@@ -24,10 +72,29 @@ impl DesktopWindow {
             .with_inner_size(LogicalSize::new(config.width, config.height))
             .with_resizable(config.resizable)
             .with_decorations(config.decorations)
-            .with_transparent(config.transparent);
+            .with_transparent(config.transparent)
+            .with_maximized(config.maximized);
+
+        if config.always_on_top {
+            attrs = attrs.with_window_level(WindowLevel::AlwaysOnTop);
+        }
+
+        // Place the window on the requested monitor (falls back to primary)
+        let handles = crate::monitor::refresh_monitors(event_loop);
+        let monitors = crate::monitor::available_monitors();
+        let target = config
+            .resolve_monitor(&monitors)
+            .and_then(|info| monitors.iter().position(|m| m == info))
+            .and_then(|index| handles.get(index).cloned());
+
+        if let Some((x, y)) = config.initial_position(&monitors) {
+            attrs = attrs.with_position(PhysicalPosition::new(x, y));
+        }
 
         if config.fullscreen {
-            attrs = attrs.with_fullscreen(Some(winit::window::Fullscreen::Borderless(None)));
+            // Without an explicit monitor, let the OS use the current one
+            let monitor = config.monitor.and(target);
+            attrs = attrs.with_fullscreen(Some(Fullscreen::Borderless(monitor)));
         }
 
         let window = event_loop.create_window(attrs)?;
@@ -35,6 +102,7 @@ impl DesktopWindow {
         Ok(Self {
             window: Arc::new(window),
             focused: AtomicBool::new(true),
+            resize: Mutex::new(ResizeState::default()),
         })
     }
 
@@ -52,6 +120,16 @@ impl DesktopWindow {
     pub(crate) fn set_focused(&self, focused: bool) {
         self.focused.store(focused, Ordering::Relaxed);
     }
+
+    /// Take the pending synthetic resize, if any (called by event loop)
+    pub(crate) fn take_pending_resize(&self) -> bool {
+        self.resize.lock().unwrap().take_pending()
+    }
+
+    /// Clear the pending resize once the OS has reported one (called by event loop)
+    pub(crate) fn clear_pending_resize(&self) {
+        self.resize.lock().unwrap().pending = false;
+    }
 }
 
 impl Window for DesktopWindow {
@@ -77,6 +155,56 @@ impl Window for DesktopWindow {
         self.window.set_title(title);
     }
 
+    fn set_fullscreen(&self, fullscreen: bool) {
+        let change = self.resize.lock().unwrap().set_fullscreen(
+            fullscreen,
+            self.is_fullscreen(),
+            self.window.inner_size(),
+        );
+        match change {
+            FullscreenChange::Unchanged => return,
+            FullscreenChange::Enter => {
+                self.window
+                    .set_fullscreen(Some(Fullscreen::Borderless(None)));
+            }
+            FullscreenChange::Exit(restore) => {
+                self.window.set_fullscreen(None);
+                if let Some(size) = restore {
+                    let _ = self.window.request_inner_size(size);
+                }
+            }
+        }
+        self.window.request_redraw();
+    }
+
+    fn is_fullscreen(&self) -> bool {
+        self.window.fullscreen().is_some()
+    }
+
+    fn is_maximized(&self) -> bool {
+        self.window.is_maximized()
+    }
+
+    fn position(&self) -> Option<(i32, i32)> {
+        let position = self.window.outer_position().ok()?;
+        Some((position.x, position.y))
+    }
+
+    fn set_always_on_top(&self, always_on_top: bool) {
+        self.window.set_window_level(if always_on_top {
+            WindowLevel::AlwaysOnTop
+        } else {
+            WindowLevel::Normal
+        });
+    }
+
+    fn set_decorations(&self, decorations: bool) {
+        self.window.set_decorations(decorations);
+        // Decorations change the inner size on some platforms
+        self.resize.lock().unwrap().pending = true;
+        self.window.request_redraw();
+    }
+
     fn set_cursor(&self, cursor: Cursor) {
         use winit::window::CursorIcon;
         let icon = match cursor {
@@ -119,3 +247,51 @@ impl Window for DesktopWindow {
 // Safety: Window operations are thread-safe via winit's internal synchronization
 unsafe impl Send for DesktopWindow {}
 unsafe impl Sync for DesktopWindow {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fullscreen_enter_exit_restores_size() {
+        let mut state = ResizeState::default();
+        let windowed = PhysicalSize::new(800, 600);
+
+        // Enter saves the windowed size and asks for a synthetic resize
+        assert_eq!(
+            state.set_fullscreen(true, false, windowed),
+            FullscreenChange::Enter
+        );
+        assert!(state.take_pending());
+        assert!(!state.take_pending());
+
+        // Exit hands the saved size back for restoring, once
+        let fullscreen = PhysicalSize::new(1920, 1080);
+        assert_eq!(
+            state.set_fullscreen(false, true, fullscreen),
+            FullscreenChange::Exit(Some(windowed))
+        );
+        assert!(state.take_pending());
+        assert_eq!(state.windowed_size, None);
+    }
+
+    #[test]
+    fn test_fullscreen_unchanged_and_started_fullscreen() {
+        let mut state = ResizeState::default();
+        let size = PhysicalSize::new(1920, 1080);
+
+        // Requesting the current state does nothing
+        assert_eq!(
+            state.set_fullscreen(true, true, size),
+            FullscreenChange::Unchanged
+        );
+        assert!(!state.take_pending());
+
+        // A window created fullscreen has no windowed size to restore
+        assert_eq!(
+            state.set_fullscreen(false, true, size),
+            FullscreenChange::Exit(None)
+        );
+        assert!(state.take_pending());
+    }
+}
@@ -7,9 +7,11 @@ use crate::window::IOSWindow;
 use blinc_platform::{Platform, PlatformError};
 
 #[cfg(target_os = "ios")]
-use objc2_foundation::MainThreadMarker;
+use blinc_platform::PowerState;
 #[cfg(target_os = "ios")]
-use objc2_ui_kit::UIScreen;
+use objc2_foundation::{MainThreadMarker, NSProcessInfo};
+#[cfg(target_os = "ios")]
+use objc2_ui_kit::{UIDevice, UIDeviceBatteryState, UIScreen};
 
 #[cfg(target_os = "ios")]
 use tracing::info;
@@ -33,6 +35,31 @@ impl IOSPlatform {
         let screen = UIScreen::mainScreen(mtm);
         screen.scale() as f64
     }
+
+    /// Read battery state from `UIDevice` and Low Power Mode from `NSProcessInfo`
+    ///
+    /// Battery level and state are only available on the main thread.
+    #[allow(unused_unsafe)]
+    fn read_power_state() -> PowerState {
+        let low_power_mode = unsafe { NSProcessInfo::processInfo().isLowPowerModeEnabled() };
+        let Some(mtm) = MainThreadMarker::new() else {
+            return PowerState {
+                low_power_mode,
+                ..PowerState::default()
+            };
+        };
+
+        let device = UIDevice::currentDevice(mtm);
+        unsafe { device.setBatteryMonitoringEnabled(true) };
+        // -1.0 when unknown (e.g. the simulator)
+        let level = unsafe { device.batteryLevel() };
+        let state = unsafe { device.batteryState() };
+        PowerState {
+            on_battery: state == UIDeviceBatteryState::Unplugged,
+            low_power_mode,
+            level: (0.0..=1.0).contains(&level).then_some(level),
+        }
+    }
 }
 
 impl Platform for IOSPlatform {
@@ -73,6 +100,11 @@ impl Platform for IOSPlatform {
     fn scale_factor(&self) -> f64 {
         1.0
     }
+
+    #[cfg(target_os = "ios")]
+    fn power_state(&self) -> PowerState {
+        Self::read_power_state()
+    }
 }
 
 /// iOS main entry point
@@ -2,7 +2,7 @@
 //!
 //! Converts UITouch events to Blinc input events.
 
-use blinc_platform::{InputEvent, TouchEvent};
+use blinc_platform::{InputEvent, PenEvent, PenPhase, TouchEvent};
 
 /// Touch phase from UITouch
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -30,6 +30,30 @@ pub struct Touch {
     pub phase: TouchPhase,
     /// Force of the touch (0.0 - 1.0 on 3D Touch devices)
     pub force: f32,
+    /// Orientation when the touch comes from an Apple Pencil
+    pub pencil: Option<PencilAngles>,
+}
+
+/// Apple Pencil orientation from UITouch, in radians
+///
+/// `altitude` is `altitudeAngle` (π/2 = upright), `azimuth` is
+/// `azimuthAngle(in:)` measured from the +x axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PencilAngles {
+    pub altitude: f32,
+    pub azimuth: f32,
+}
+
+impl PencilAngles {
+    /// Tilt along x and y in degrees (0 when the Pencil is upright)
+    pub fn tilt(&self) -> (f32, f32) {
+        // Flat on the glass would divide by zero
+        let tan_altitude = self.altitude.max(1e-3).tan();
+        (
+            (self.azimuth.cos() / tan_altitude).atan().to_degrees(),
+            (self.azimuth.sin() / tan_altitude).atan().to_degrees(),
+        )
+    }
 }
 
 impl Touch {
@@ -41,6 +65,7 @@ impl Touch {
             y,
             phase,
             force: 0.0,
+            pencil: None,
         }
     }
 
@@ -52,12 +77,37 @@ impl Touch {
             y,
             phase,
             force,
+            pencil: None,
         }
     }
+
+    /// Mark this touch as coming from an Apple Pencil
+    pub fn with_pencil(mut self, altitude: f32, azimuth: f32) -> Self {
+        self.pencil = Some(PencilAngles { altitude, azimuth });
+        self
+    }
+}
+
+/// Pen event for an Apple Pencil touch, `None` for finger touches
+pub fn pen_event(touch: &Touch) -> Option<PenEvent> {
+    let angles = touch.pencil?;
+    let phase = match touch.phase {
+        TouchPhase::Began => PenPhase::Down,
+        TouchPhase::Moved => PenPhase::Moved,
+        TouchPhase::Ended => PenPhase::Up,
+        TouchPhase::Cancelled => PenPhase::Cancelled,
+    };
+    let (tilt_x, tilt_y) = angles.tilt();
+    Some(PenEvent::new(phase, touch.x, touch.y, touch.force).with_tilt(tilt_x, tilt_y))
 }
 
 /// Convert an iOS touch to a Blinc input event
+///
+/// Apple Pencil touches become pen events.
 pub fn convert_touch(touch: &Touch) -> InputEvent {
+    if let Some(pen) = pen_event(touch) {
+        return InputEvent::Pen(pen);
+    }
     match touch.phase {
         TouchPhase::Began => InputEvent::Touch(TouchEvent::Started {
             id: touch.id,
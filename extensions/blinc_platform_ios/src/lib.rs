@@ -45,7 +45,10 @@ pub use app::{
 };
 pub use assets::IOSAssetLoader;
 pub use event_loop::{IOSEventLoop, IOSWakeProxy};
-pub use input::{convert_touch, convert_touches, Gesture, GestureDetector, Touch, TouchPhase};
+pub use input::{
+    convert_touch, convert_touches, pen_event, Gesture, GestureDetector, PencilAngles, Touch,
+    TouchPhase,
+};
 pub use native_bridge::{
     blinc_native_bridge_is_ready, blinc_set_native_call_fn, IOSNativeBridgeAdapter,
 };
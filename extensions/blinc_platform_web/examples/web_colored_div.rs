@@ -0,0 +1,91 @@
+//! Web Colored Div Example
+//!
+//! Renders a rounded, colored div centered in a browser canvas. Resize the
+//! window or zoom the page to see the backbuffer follow the canvas size and
+//! `devicePixelRatio`.
+//!
+//! Build and serve:
+//! ```bash
+//! cargo build --target wasm32-unknown-unknown --example web_colored_div -p blinc_platform_web
+//! wasm-bindgen --target web --out-dir pkg \
+//!     target/wasm32-unknown-unknown/debug/examples/web_colored_div.wasm
+//! ```
+//!
+//! Then load `pkg/web_colored_div.js` from a page containing
+//! `<canvas id="blinc-canvas" style="width: 100vw; height: 100vh"></canvas>`.
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use blinc_core::Color;
+    use blinc_gpu::{GpuPaintContext, RendererConfig};
+    use blinc_layout::prelude::*;
+    use blinc_layout::RenderTree;
+    use blinc_platform::{ControlFlow, Event, EventLoop, Window, WindowEvent};
+    use blinc_platform_web::{WebEventLoop, WebSurface, WebWindow};
+    use wasm_bindgen::prelude::*;
+
+    fn build_ui(width: f32, height: f32) -> Div {
+        div()
+            .w(width)
+            .h(height)
+            .bg(Color::rgba(0.08, 0.08, 0.1, 1.0))
+            .items_center()
+            .justify_center()
+            .child(
+                div()
+                    .w(240.0)
+                    .h(160.0)
+                    .rounded(16.0)
+                    .bg(Color::rgba(0.25, 0.55, 0.95, 1.0)),
+            )
+    }
+
+    #[wasm_bindgen(start)]
+    pub fn start() {
+        wasm_bindgen_futures::spawn_local(async {
+            if let Err(e) = run().await {
+                web_sys::console::error_1(&JsValue::from_str(&e));
+            }
+        });
+    }
+
+    async fn run() -> Result<(), String> {
+        let window = WebWindow::from_canvas_id("blinc-canvas").map_err(|e| e.to_string())?;
+        let (renderer, surface) = WebSurface::create(&window, RendererConfig::default())
+            .await
+            .map_err(|e| e.to_string())?;
+        let gpu = Rc::new(RefCell::new((renderer, surface)));
+
+        WebEventLoop::new(window)
+            .run(move |event, window| {
+                let (renderer, surface) = &mut *gpu.borrow_mut();
+                match event {
+                    Event::Window(WindowEvent::Resized { width, height }) => {
+                        surface.resize(renderer, width, height);
+                    }
+                    Event::Frame => {
+                        let (width, height) = window.logical_size();
+                        let (physical_w, physical_h) = surface.size();
+
+                        let mut tree = RenderTree::from_element(&build_ui(width, height));
+                        tree.set_scale_factor(window.scale_factor() as f32);
+                        tree.compute_layout(width, height);
+
+                        let mut ctx = GpuPaintContext::new(physical_w as f32, physical_h as f32);
+                        tree.render(&mut ctx);
+                        let batch = ctx.take_batch();
+
+                        if let Err(e) = surface.render(renderer, &batch, [0.0, 0.0, 0.0, 1.0]) {
+                            tracing::warn!("Frame dropped: {:?}", e);
+                        }
+                    }
+                    _ => {}
+                }
+                ControlFlow::Continue
+            })
+            .map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,68 @@
+//! Web platform implementation
+//!
+//! Implements the Platform trait for browsers using an HTML canvas.
+
+use blinc_platform::{Platform, PlatformError};
+
+use crate::event_loop::WebEventLoop;
+use crate::window::WebWindow;
+
+/// Element id of the canvas used by [`Platform::create_event_loop`]
+pub const DEFAULT_CANVAS_ID: &str = "blinc-canvas";
+
+/// Browser platform rendering into an HTML canvas
+pub struct WebPlatform {
+    /// `devicePixelRatio` at startup
+    scale_factor: f64,
+}
+
+impl WebPlatform {
+    /// Create an event loop for the canvas with the given element id
+    #[cfg(target_arch = "wasm32")]
+    pub fn create_event_loop_for(&self, canvas_id: &str) -> Result<WebEventLoop, PlatformError> {
+        Ok(WebEventLoop::new(WebWindow::from_canvas_id(canvas_id)?))
+    }
+}
+
+impl Platform for WebPlatform {
+    type Window = WebWindow;
+    type EventLoop = WebEventLoop;
+
+    fn new() -> Result<Self, PlatformError> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let window = web_sys::window()
+                .ok_or_else(|| PlatformError::InitFailed("no global window".to_string()))?;
+            Ok(Self {
+                scale_factor: window.device_pixel_ratio(),
+            })
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            Ok(Self { scale_factor: 1.0 })
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "web"
+    }
+
+    fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    fn create_event_loop(&self) -> Result<Self::EventLoop, PlatformError> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.create_event_loop_for(DEFAULT_CANVAS_ID)
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            Err(PlatformError::Unsupported(
+                "Web platform is only available on wasm32".to_string(),
+            ))
+        }
+    }
+}
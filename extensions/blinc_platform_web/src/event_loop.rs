@@ -0,0 +1,351 @@
+//! Web event loop implementation
+//!
+//! Driven by `requestAnimationFrame`. DOM listeners on the canvas queue
+//! converted events; each animation frame drains the queue, re-syncs the
+//! canvas size with `devicePixelRatio`, and emits `Event::Frame` when a
+//! redraw was requested.
+
+use blinc_platform::{ControlFlow, Event, EventLoop, PlatformError};
+
+use crate::window::WebWindow;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Wake proxy for the web event loop
+///
+/// Use this to request a frame from animation code. The flag is checked on
+/// the next `requestAnimationFrame` tick.
+#[derive(Clone)]
+pub struct WebWakeProxy {
+    wake_requested: Arc<AtomicBool>,
+}
+
+impl WebWakeProxy {
+    /// Create a new wake proxy
+    pub fn new() -> Self {
+        Self {
+            wake_requested: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Wake up the event loop
+    pub fn wake(&self) {
+        self.wake_requested.store(true, Ordering::SeqCst);
+    }
+
+    /// Check if a wake was requested and clear the flag
+    pub fn take_wake_request(&self) -> bool {
+        self.wake_requested.swap(false, Ordering::SeqCst)
+    }
+}
+
+impl Default for WebWakeProxy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Browser event loop
+///
+/// The browser owns the real event loop, so [`EventLoop::run`] installs the
+/// DOM listeners and the animation-frame callback, then returns immediately.
+/// The handler keeps running until it returns [`ControlFlow::Exit`].
+pub struct WebEventLoop {
+    window: WebWindow,
+    wake_proxy: WebWakeProxy,
+}
+
+impl WebEventLoop {
+    /// Create an event loop for a canvas-backed window
+    pub fn new(window: WebWindow) -> Self {
+        Self {
+            window,
+            wake_proxy: WebWakeProxy::new(),
+        }
+    }
+
+    /// The window this loop drives (e.g. for creating the render surface)
+    pub fn window(&self) -> &WebWindow {
+        &self.window
+    }
+
+    /// Get a wake proxy for animation code
+    pub fn wake_proxy(&self) -> WebWakeProxy {
+        self.wake_proxy.clone()
+    }
+}
+
+impl EventLoop for WebEventLoop {
+    type Window = WebWindow;
+
+    #[cfg(target_arch = "wasm32")]
+    fn run<F>(self, handler: F) -> Result<(), PlatformError>
+    where
+        F: FnMut(Event, &Self::Window) -> ControlFlow + 'static,
+    {
+        dom::install(self.window, self.wake_proxy, handler)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn run<F>(self, _handler: F) -> Result<(), PlatformError>
+    where
+        F: FnMut(Event, &Self::Window) -> ControlFlow + 'static,
+    {
+        Err(PlatformError::Unsupported(
+            "Web event loop is only available on wasm32".to_string(),
+        ))
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod dom {
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    use blinc_platform::{
+        ControlFlow, Event, InputEvent, LifecycleEvent, MouseEvent, PlatformError, Window,
+        WindowEvent,
+    };
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen::JsCast;
+
+    use super::WebWakeProxy;
+    use crate::input::{self, Pointer, PointerKind, PointerPhase};
+    use crate::window::WebWindow;
+
+    type Handler = Box<dyn FnMut(Event, &WebWindow) -> ControlFlow>;
+
+    /// State shared between the DOM listeners and the frame callback
+    struct Shared {
+        window: WebWindow,
+        wake_proxy: WebWakeProxy,
+        /// Events queued by DOM listeners, drained once per animation frame
+        queue: RefCell<Vec<Event>>,
+        handler: RefCell<Handler>,
+        exited: Cell<bool>,
+    }
+
+    impl Shared {
+        fn push(&self, event: Event) {
+            self.queue.borrow_mut().push(event);
+        }
+
+        /// Deliver one event; returns false once the handler asked to exit
+        fn dispatch(&self, event: Event) -> bool {
+            if self.exited.get() {
+                return false;
+            }
+            let flow = (self.handler.borrow_mut())(event, &self.window);
+            if flow == ControlFlow::Exit {
+                self.exited.set(true);
+            }
+            !self.exited.get()
+        }
+
+        fn frame(&self) -> bool {
+            for event in self.window.sync_canvas_size() {
+                self.push(Event::Window(event));
+            }
+
+            let events = std::mem::take(&mut *self.queue.borrow_mut());
+            let had_events = !events.is_empty();
+            for event in events {
+                if !self.dispatch(event) {
+                    return false;
+                }
+            }
+
+            let wake = self.wake_proxy.take_wake_request();
+            if self.window.take_redraw_request() || wake || had_events {
+                return self.dispatch(Event::Frame);
+            }
+            true
+        }
+    }
+
+    fn listen<E, F>(target: &web_sys::EventTarget, name: &str, mut f: F)
+    where
+        E: wasm_bindgen::convert::FromWasmAbi + 'static,
+        F: FnMut(E) + 'static,
+    {
+        let closure = Closure::<dyn FnMut(E)>::new(move |e: E| f(e));
+        let _ = target.add_event_listener_with_callback(name, closure.as_ref().unchecked_ref());
+        // Listeners live for the lifetime of the page
+        closure.forget();
+    }
+
+    fn request_animation_frame(f: &Closure<dyn FnMut(f64)>) {
+        if let Some(window) = web_sys::window() {
+            let _ = window.request_animation_frame(f.as_ref().unchecked_ref());
+        }
+    }
+
+    fn pointer(e: &web_sys::PointerEvent, phase: PointerPhase) -> Pointer {
+        Pointer {
+            id: e.pointer_id(),
+            kind: PointerKind::from_dom(&e.pointer_type()),
+            phase,
+            x: e.offset_x() as f32,
+            y: e.offset_y() as f32,
+            pressure: e.pressure(),
+            tilt_x: e.tilt_x() as f32,
+            tilt_y: e.tilt_y() as f32,
+            button: e.button(),
+            buttons: e.buttons(),
+        }
+    }
+
+    pub(super) fn install<F>(
+        window: WebWindow,
+        wake_proxy: WebWakeProxy,
+        handler: F,
+    ) -> Result<(), PlatformError>
+    where
+        F: FnMut(Event, &WebWindow) -> ControlFlow + 'static,
+    {
+        let dom_window = web_sys::window()
+            .ok_or_else(|| PlatformError::EventLoop("no global window".to_string()))?;
+        let canvas: web_sys::EventTarget = window.canvas().clone().into();
+
+        let shared = Rc::new(Shared {
+            window,
+            wake_proxy,
+            queue: RefCell::new(vec![Event::Lifecycle(LifecycleEvent::Resumed)]),
+            handler: RefCell::new(Box::new(handler)),
+            exited: Cell::new(false),
+        });
+
+        // Pointer input (mouse, pen and touch share one event stream)
+        for (name, phase) in [
+            ("pointerdown", PointerPhase::Down),
+            ("pointermove", PointerPhase::Move),
+            ("pointerup", PointerPhase::Up),
+            ("pointercancel", PointerPhase::Cancel),
+        ] {
+            let shared = shared.clone();
+            listen(&canvas, name, move |e: web_sys::PointerEvent| {
+                if phase == PointerPhase::Down {
+                    // Keep receiving moves while dragging outside the canvas
+                    let _ = shared.window.canvas().set_pointer_capture(e.pointer_id());
+                    let _ = shared.window.canvas().focus();
+                }
+                if let Some(event) = input::convert_pointer(&pointer(&e, phase)) {
+                    shared.push(Event::Input(event));
+                }
+            });
+        }
+        {
+            let shared = shared.clone();
+            listen(&canvas, "pointerenter", move |_: web_sys::PointerEvent| {
+                shared.push(Event::Input(InputEvent::Mouse(MouseEvent::Entered)));
+            });
+        }
+        {
+            let shared = shared.clone();
+            listen(&canvas, "pointerleave", move |_: web_sys::PointerEvent| {
+                shared.push(Event::Input(InputEvent::Mouse(MouseEvent::Left)));
+            });
+        }
+
+        // Wheel scrolling - keep the page itself from scrolling
+        {
+            let shared = shared.clone();
+            listen(&canvas, "wheel", move |e: web_sys::WheelEvent| {
+                e.prevent_default();
+                shared.push(Event::Input(input::convert_wheel(
+                    e.delta_x(),
+                    e.delta_y(),
+                    e.delta_mode(),
+                )));
+            });
+        }
+
+        // Keyboard (canvas is focusable via tabindex)
+        for (name, pressed) in [("keydown", true), ("keyup", false)] {
+            let shared = shared.clone();
+            listen(&canvas, name, move |e: web_sys::KeyboardEvent| {
+                let modifiers = input::convert_modifiers(
+                    e.shift_key(),
+                    e.ctrl_key(),
+                    e.alt_key(),
+                    e.meta_key(),
+                );
+                // Keep Tab, Space and arrows from moving browser focus or scrolling
+                if matches!(
+                    e.key().as_str(),
+                    "Tab" | " " | "ArrowUp" | "ArrowDown" | "ArrowLeft" | "ArrowRight"
+                ) {
+                    e.prevent_default();
+                }
+                shared.push(Event::Input(input::convert_keyboard_event(
+                    &e.key(),
+                    pressed,
+                    modifiers,
+                )));
+            });
+        }
+
+        // Focus
+        for (name, focused) in [("focus", true), ("blur", false)] {
+            let shared = shared.clone();
+            listen(&canvas, name, move |_: web_sys::FocusEvent| {
+                shared.window.set_focused(focused);
+                shared.push(Event::Window(WindowEvent::Focused(focused)));
+            });
+        }
+
+        // Page visibility maps to lifecycle
+        if let Some(document) = dom_window.document() {
+            let shared = shared.clone();
+            let doc = document.clone();
+            listen(
+                &document.into(),
+                "visibilitychange",
+                move |_: web_sys::Event| {
+                    let visible = doc.visibility_state() == web_sys::VisibilityState::Visible;
+                    shared.window.set_visible(visible);
+                    shared.push(Event::Lifecycle(if visible {
+                        LifecycleEvent::Resumed
+                    } else {
+                        LifecycleEvent::Suspended
+                    }));
+                },
+            );
+        }
+
+        // Canvas resizes (layout or devicePixelRatio) are picked up every
+        // frame by `sync_canvas_size`; the observer just makes sure a frame
+        // is drawn at the new size even when nothing else is animating.
+        {
+            let canvas = shared.window.canvas().clone();
+            let shared = shared.clone();
+            let on_resize = Closure::<dyn FnMut(js_sys::Array)>::new(move |_entries| {
+                shared.window.request_redraw();
+            });
+            if let Ok(observer) = web_sys::ResizeObserver::new(on_resize.as_ref().unchecked_ref()) {
+                observer.observe(&canvas);
+            }
+            on_resize.forget();
+        }
+
+        // requestAnimationFrame loop
+        let frame: Rc<RefCell<Option<Closure<dyn FnMut(f64)>>>> = Rc::new(RefCell::new(None));
+        let next = frame.clone();
+        *frame.borrow_mut() = Some(Closure::new(move |_timestamp: f64| {
+            if !shared.frame() {
+                // Handler exited: drop the callback to stop the loop
+                let _ = next.borrow_mut().take();
+                return;
+            }
+            if let Some(callback) = next.borrow().as_ref() {
+                request_animation_frame(callback);
+            }
+        }));
+        if let Some(callback) = frame.borrow().as_ref() {
+            request_animation_frame(callback);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,381 @@
+//! Web input handling
+//!
+//! Converts DOM pointer, keyboard and wheel events to Blinc input events.
+//!
+//! The conversions take plain values read off the DOM event (`event.key()`,
+//! `event.pointer_type()`, ...) so they can be exercised without a browser.
+
+use blinc_platform::{
+    InputEvent, Key, KeyState, KeyboardEvent, Modifiers, MouseButton, MouseEvent, PenEvent,
+    PenPhase, ScrollPhase, TouchEvent,
+};
+
+/// `PointerEvent.buttons` bit for a pen's barrel button
+const PEN_BARREL_BUTTON: u16 = 2;
+
+/// `PointerEvent.buttons` bit for a pen's eraser
+const PEN_ERASER_BUTTON: u16 = 32;
+
+/// Lines per wheel "page" when the browser reports `DOM_DELTA_PAGE`
+const LINES_PER_PAGE: f32 = 10.0;
+
+/// Pixel-to-line divisor, matching the desktop backend's pixel delta scaling
+const PIXELS_PER_SCROLL_UNIT: f32 = 10.0;
+
+/// Device that produced a pointer event (`PointerEvent.pointerType`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointerKind {
+    Mouse,
+    Pen,
+    Touch,
+}
+
+impl PointerKind {
+    /// Parse a DOM `pointerType` string, defaulting to mouse
+    pub fn from_dom(pointer_type: &str) -> Self {
+        match pointer_type {
+            "touch" => PointerKind::Touch,
+            "pen" => PointerKind::Pen,
+            _ => PointerKind::Mouse,
+        }
+    }
+}
+
+/// Pointer event phase (`pointerdown`/`pointermove`/`pointerup`/`pointercancel`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointerPhase {
+    Down,
+    Move,
+    Up,
+    Cancel,
+}
+
+/// A DOM pointer event in canvas-local logical pixels
+#[derive(Clone, Debug)]
+pub struct Pointer {
+    /// `PointerEvent.pointerId`
+    pub id: i32,
+    pub kind: PointerKind,
+    pub phase: PointerPhase,
+    /// X position relative to the canvas, in CSS pixels
+    pub x: f32,
+    /// Y position relative to the canvas, in CSS pixels
+    pub y: f32,
+    /// `PointerEvent.pressure` (0.0 - 1.0)
+    pub pressure: f32,
+    /// `PointerEvent.tiltX` in degrees (0 for devices without tilt)
+    pub tilt_x: f32,
+    /// `PointerEvent.tiltY` in degrees (0 for devices without tilt)
+    pub tilt_y: f32,
+    /// `MouseEvent.button` (0 = primary)
+    pub button: i16,
+    /// `MouseEvent.buttons` bitmask of held buttons
+    pub buttons: u16,
+}
+
+/// Convert a DOM `MouseEvent.button` index to a Blinc mouse button
+pub fn convert_mouse_button(button: i16) -> MouseButton {
+    match button {
+        0 => MouseButton::Left,
+        1 => MouseButton::Middle,
+        2 => MouseButton::Right,
+        3 => MouseButton::Back,
+        4 => MouseButton::Forward,
+        n => MouseButton::Other(n.max(0) as u16),
+    }
+}
+
+/// Convert DOM modifier flags to Blinc modifiers
+pub fn convert_modifiers(shift: bool, ctrl: bool, alt: bool, meta: bool) -> Modifiers {
+    Modifiers {
+        shift,
+        ctrl,
+        alt,
+        meta,
+    }
+}
+
+/// Convert a pointer event to a Blinc input event
+///
+/// Touch pointers become touch events, pen pointers pen events and mouse
+/// pointers mouse events. Returns `None` for events with no Blinc
+/// equivalent (mouse cancel).
+pub fn convert_pointer(pointer: &Pointer) -> Option<InputEvent> {
+    let (x, y) = (pointer.x, pointer.y);
+
+    if pointer.kind == PointerKind::Pen {
+        let phase = match pointer.phase {
+            PointerPhase::Down => PenPhase::Down,
+            PointerPhase::Move => PenPhase::Moved,
+            PointerPhase::Up => PenPhase::Up,
+            PointerPhase::Cancel => PenPhase::Cancelled,
+        };
+        let pen = PenEvent::new(phase, x, y, pointer.pressure)
+            .with_tilt(pointer.tilt_x, pointer.tilt_y)
+            .with_buttons(
+                pointer.buttons & PEN_BARREL_BUTTON != 0,
+                pointer.buttons & PEN_ERASER_BUTTON != 0,
+            );
+        return Some(InputEvent::Pen(pen));
+    }
+
+    if pointer.kind == PointerKind::Touch {
+        let id = pointer.id as u64;
+        let pressure = pointer.pressure;
+        let touch = match pointer.phase {
+            PointerPhase::Down => TouchEvent::Started { id, x, y, pressure },
+            PointerPhase::Move => TouchEvent::Moved { id, x, y, pressure },
+            PointerPhase::Up => TouchEvent::Ended { id, x, y },
+            PointerPhase::Cancel => TouchEvent::Cancelled { id },
+        };
+        return Some(InputEvent::Touch(touch));
+    }
+
+    let button = convert_mouse_button(pointer.button);
+    let mouse = match pointer.phase {
+        PointerPhase::Down => MouseEvent::ButtonPressed { button, x, y },
+        PointerPhase::Move => MouseEvent::Moved { x, y },
+        PointerPhase::Up => MouseEvent::ButtonReleased { button, x, y },
+        PointerPhase::Cancel => return None,
+    };
+    Some(InputEvent::Mouse(mouse))
+}
+
+/// Convert a DOM `WheelEvent` to a Blinc scroll event
+///
+/// `delta_mode` is `WheelEvent.deltaMode` (0 = pixels, 1 = lines, 2 = pages).
+/// Deltas are normalized to lines, like winit's `LineDelta`.
+/// DOM wheel deltas are positive when scrolling down; Blinc follows the
+/// desktop convention where positive deltas scroll content up, so the sign
+/// is flipped.
+pub fn convert_wheel(delta_x: f64, delta_y: f64, delta_mode: u32) -> InputEvent {
+    let scale = match delta_mode {
+        1 => 1.0,
+        2 => LINES_PER_PAGE,
+        _ => 1.0 / PIXELS_PER_SCROLL_UNIT,
+    };
+    InputEvent::Scroll {
+        delta_x: -(delta_x as f32) * scale,
+        delta_y: -(delta_y as f32) * scale,
+        phase: ScrollPhase::Moved,
+    }
+}
+
+/// Convert a DOM `KeyboardEvent.key` value to a Blinc key
+pub fn convert_key(key: &str) -> Key {
+    match key {
+        // Special keys
+        " " | "Spacebar" => Key::Space,
+        "Enter" => Key::Enter,
+        "Escape" | "Esc" => Key::Escape,
+        "Backspace" => Key::Backspace,
+        "Tab" => Key::Tab,
+        "Delete" | "Del" => Key::Delete,
+        "Insert" => Key::Insert,
+        "Home" => Key::Home,
+        "End" => Key::End,
+        "PageUp" => Key::PageUp,
+        "PageDown" => Key::PageDown,
+        "Dead" => Key::Dead(None),
+
+        // Arrow keys
+        "ArrowLeft" | "Left" => Key::Left,
+        "ArrowRight" | "Right" => Key::Right,
+        "ArrowUp" | "Up" => Key::Up,
+        "ArrowDown" | "Down" => Key::Down,
+
+        // Modifier keys
+        "Shift" => Key::Shift,
+        "Control" => Key::Ctrl,
+        "Alt" => Key::Alt,
+        "Meta" | "OS" => Key::Meta,
+
+        // Function keys
+        "F1" => Key::F1,
+        "F2" => Key::F2,
+        "F3" => Key::F3,
+        "F4" => Key::F4,
+        "F5" => Key::F5,
+        "F6" => Key::F6,
+        "F7" => Key::F7,
+        "F8" => Key::F8,
+        "F9" => Key::F9,
+        "F10" => Key::F10,
+        "F11" => Key::F11,
+        "F12" => Key::F12,
+
+        _ => {
+            let mut chars = key.chars();
+            match (chars.next(), chars.next()) {
+                // Single printable character
+                (Some(ch), None) => convert_char(ch),
+                // Named key we don't map ("Dead", "CapsLock", ...)
+                _ => Key::Unknown,
+            }
+        }
+    }
+}
+
+fn convert_char(ch: char) -> Key {
+    match ch.to_ascii_uppercase() {
+        'A' => Key::A,
+        'B' => Key::B,
+        'C' => Key::C,
+        'D' => Key::D,
+        'E' => Key::E,
+        'F' => Key::F,
+        'G' => Key::G,
+        'H' => Key::H,
+        'I' => Key::I,
+        'J' => Key::J,
+        'K' => Key::K,
+        'L' => Key::L,
+        'M' => Key::M,
+        'N' => Key::N,
+        'O' => Key::O,
+        'P' => Key::P,
+        'Q' => Key::Q,
+        'R' => Key::R,
+        'S' => Key::S,
+        'T' => Key::T,
+        'U' => Key::U,
+        'V' => Key::V,
+        'W' => Key::W,
+        'X' => Key::X,
+        'Y' => Key::Y,
+        'Z' => Key::Z,
+        '0' => Key::Num0,
+        '1' => Key::Num1,
+        '2' => Key::Num2,
+        '3' => Key::Num3,
+        '4' => Key::Num4,
+        '5' => Key::Num5,
+        '6' => Key::Num6,
+        '7' => Key::Num7,
+        '8' => Key::Num8,
+        '9' => Key::Num9,
+        '-' => Key::Minus,
+        '=' => Key::Equals,
+        '[' => Key::LeftBracket,
+        ']' => Key::RightBracket,
+        '\\' => Key::Backslash,
+        ';' => Key::Semicolon,
+        '\'' => Key::Quote,
+        ',' => Key::Comma,
+        '.' => Key::Period,
+        '/' => Key::Slash,
+        '`' => Key::Grave,
+        _ => Key::Char(ch),
+    }
+}
+
+/// Convert a DOM keyboard event to a Blinc input event
+pub fn convert_keyboard_event(key: &str, pressed: bool, modifiers: Modifiers) -> InputEvent {
+    // Single-character keys are already resolved by the browser's layout
+    let text = (pressed && key.chars().count() == 1).then(|| key.to_string());
+    InputEvent::Keyboard(KeyboardEvent {
+        text,
+        ..KeyboardEvent::new(
+            convert_key(key),
+            if pressed {
+                KeyState::Pressed
+            } else {
+                KeyState::Released
+            },
+            modifiers,
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_touch_pointer_maps_to_touch_event() {
+        let pointer = Pointer {
+            id: 7,
+            kind: PointerKind::from_dom("touch"),
+            phase: PointerPhase::Down,
+            x: 10.0,
+            y: 20.0,
+            pressure: 0.5,
+            tilt_x: 0.0,
+            tilt_y: 0.0,
+            button: 0,
+            buttons: 1,
+        };
+        match convert_pointer(&pointer) {
+            Some(InputEvent::Touch(TouchEvent::Started { id, x, y, pressure })) => {
+                assert_eq!((id, x, y, pressure), (7, 10.0, 20.0, 0.5));
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_pen_pointer_maps_to_pen_event() {
+        let pointer = Pointer {
+            id: 3,
+            kind: PointerKind::from_dom("pen"),
+            phase: PointerPhase::Move,
+            x: 5.0,
+            y: 6.0,
+            pressure: 0.7,
+            tilt_x: 20.0,
+            tilt_y: -10.0,
+            button: -1,
+            buttons: 1 | PEN_BARREL_BUTTON,
+        };
+        match convert_pointer(&pointer) {
+            Some(InputEvent::Pen(pen)) => {
+                assert_eq!(pen.phase, PenPhase::Moved);
+                assert_eq!((pen.x, pen.y, pen.pressure), (5.0, 6.0, 0.7));
+                assert_eq!((pen.tilt_x, pen.tilt_y), (20.0, -10.0));
+                assert!(pen.barrel_button && !pen.eraser);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_mouse_pointer_maps_buttons() {
+        let pointer = Pointer {
+            id: 1,
+            kind: PointerKind::from_dom("mouse"),
+            phase: PointerPhase::Up,
+            x: 1.0,
+            y: 2.0,
+            pressure: 0.0,
+            tilt_x: 0.0,
+            tilt_y: 0.0,
+            button: 2,
+            buttons: 0,
+        };
+        assert!(matches!(
+            convert_pointer(&pointer),
+            Some(InputEvent::Mouse(MouseEvent::ButtonReleased {
+                button: MouseButton::Right,
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_dom_keys() {
+        assert_eq!(convert_key("a"), Key::A);
+        assert_eq!(convert_key("ArrowLeft"), Key::Left);
+        assert_eq!(convert_key(" "), Key::Space);
+        assert_eq!(convert_key("é"), Key::Char('é'));
+        assert_eq!(convert_key("CapsLock"), Key::Unknown);
+    }
+
+    #[test]
+    fn test_wheel_direction_matches_desktop() {
+        // Scrolling down one line in the DOM is a negative line delta in Blinc
+        match convert_wheel(0.0, 1.0, 1) {
+            InputEvent::Scroll { delta_y, .. } => assert_eq!(delta_y, -1.0),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Blinc Web Platform
+//!
+//! HTML canvas integration and WebGPU/WebGL rendering for browsers.
+//!
+//! This crate implements the `blinc_platform` traits for `wasm32` targets,
+//! providing DOM input, canvas resizing, and a `requestAnimationFrame`
+//! driven frame loop.
+//!
+//! # Architecture
+//!
+//! - **HTML canvas** as the render surface (`wgpu::SurfaceTarget::Canvas`)
+//! - **WebGPU** preferred, **WebGL2** fallback via wgpu
+//! - **Pointer events** unify mouse, pen and touch input
+//! - **ResizeObserver** + `devicePixelRatio` keep the backbuffer sharp
+//!
+//! # Usage
+//!
+//! ```ignore
+//! use blinc_platform::{ControlFlow, Event, EventLoop};
+//! use blinc_platform_web::{WebEventLoop, WebWindow};
+//!
+//! #[wasm_bindgen(start)]
+//! pub fn start() {
+//!     let window = WebWindow::from_canvas_id("blinc-canvas").unwrap();
+//!     WebEventLoop::new(window).run(|event, window| {
+//!         if let Event::Frame = event {
+//!             // Render frame
+//!         }
+//!         ControlFlow::Continue
+//!     }).unwrap();
+//! }
+//! ```
+//!
+//! # Building for the Web
+//!
+//! ```bash
+//! cargo build --target wasm32-unknown-unknown --example web_colored_div -p blinc_platform_web
+//! wasm-bindgen --target web --out-dir pkg \
+//!     target/wasm32-unknown-unknown/debug/examples/web_colored_div.wasm
+//! ```
+
+pub mod app;
+pub mod event_loop;
+pub mod input;
+#[cfg(target_arch = "wasm32")]
+pub mod surface;
+pub mod window;
+
+// Re-export public types
+pub use app::{WebPlatform, DEFAULT_CANVAS_ID};
+pub use event_loop::{WebEventLoop, WebWakeProxy};
+pub use input::{
+    convert_key, convert_keyboard_event, convert_modifiers, convert_mouse_button, convert_pointer,
+    convert_wheel, Pointer, PointerKind, PointerPhase,
+};
+#[cfg(target_arch = "wasm32")]
+pub use surface::WebSurface;
+pub use window::{backbuffer_size, css_cursor, WebWindow, MAX_BACKBUFFER_DIMENSION};
@@ -0,0 +1,167 @@
+//! Canvas render surface
+//!
+//! Creates a wgpu surface from the window's canvas. Frames are rendered into
+//! a [`Backbuffer`] and copied to the canvas, since browsers don't allow
+//! sampling the swapchain (glass effects read the previous frame from the
+//! backbuffer instead).
+
+use blinc_gpu::renderer::RendererError;
+use blinc_gpu::{Backbuffer, BackbufferConfig, GpuRenderer, PrimitiveBatch, RendererConfig};
+use blinc_platform::Window;
+
+use crate::window::WebWindow;
+
+/// A wgpu surface bound to a canvas
+pub struct WebSurface {
+    surface: wgpu::Surface<'static>,
+    config: wgpu::SurfaceConfiguration,
+    /// Offscreen render target; `None` when the canvas can't be a copy
+    /// destination, in which case frames render straight to the surface
+    backbuffer: Option<Backbuffer>,
+}
+
+impl WebSurface {
+    /// Create a renderer and a configured surface for a canvas window
+    ///
+    /// Prefers WebGPU and falls back to WebGL2 when the browser does not
+    /// expose `navigator.gpu`.
+    pub async fn create(
+        window: &WebWindow,
+        renderer_config: RendererConfig,
+    ) -> Result<(GpuRenderer, WebSurface), RendererError> {
+        let instance = wgpu::util::new_instance_with_webgpu_detection(&wgpu::InstanceDescriptor {
+            backends: wgpu::Backends::BROWSER_WEBGPU | wgpu::Backends::GL,
+            ..Default::default()
+        })
+        .await;
+
+        let surface = instance
+            .create_surface(wgpu::SurfaceTarget::Canvas(window.canvas().clone()))
+            .map_err(RendererError::SurfaceError)?;
+
+        let mut renderer =
+            GpuRenderer::with_instance_and_surface(instance, &surface, renderer_config).await?;
+
+        let caps = surface.get_capabilities(renderer.adapter());
+        let use_backbuffer = caps.usages.contains(wgpu::TextureUsages::COPY_DST);
+
+        let (width, height) = window.size();
+        let config = wgpu::SurfaceConfiguration {
+            usage: if use_backbuffer {
+                wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_DST
+            } else {
+                wgpu::TextureUsages::RENDER_ATTACHMENT
+            },
+            format: renderer.texture_format(),
+            width,
+            height,
+            present_mode: wgpu::PresentMode::Fifo,
+            alpha_mode: caps
+                .alpha_modes
+                .first()
+                .copied()
+                .unwrap_or(wgpu::CompositeAlphaMode::Auto),
+            view_formats: vec![],
+            desired_maximum_frame_latency: 2,
+        };
+        surface.configure(renderer.device(), &config);
+        renderer.resize(width, height);
+
+        let backbuffer = use_backbuffer.then(|| {
+            Backbuffer::new(
+                renderer.device(),
+                width,
+                height,
+                BackbufferConfig {
+                    format: renderer.texture_format(),
+                    ..Default::default()
+                },
+            )
+        });
+        tracing::info!(
+            "Canvas surface {}x{} ({:?}, backbuffer: {})",
+            width,
+            height,
+            config.format,
+            use_backbuffer
+        );
+
+        Ok((
+            renderer,
+            WebSurface {
+                surface,
+                config,
+                backbuffer,
+            },
+        ))
+    }
+
+    /// Reconfigure the surface and backbuffer for a new physical size
+    ///
+    /// Call on `WindowEvent::Resized`, which the window emits whenever the
+    /// canvas CSS size or `devicePixelRatio` changes.
+    pub fn resize(&mut self, renderer: &mut GpuRenderer, width: u32, height: u32) {
+        if width == 0 || height == 0 || (width, height) == self.size() {
+            return;
+        }
+        self.config.width = width;
+        self.config.height = height;
+        self.surface.configure(renderer.device(), &self.config);
+        if let Some(backbuffer) = &mut self.backbuffer {
+            backbuffer.resize(renderer.device(), width, height);
+        }
+        renderer.resize(width, height);
+    }
+
+    /// Current backbuffer size in physical pixels
+    pub fn size(&self) -> (u32, u32) {
+        (self.config.width, self.config.height)
+    }
+
+    /// Whether frames go through the offscreen backbuffer
+    pub fn has_backbuffer(&self) -> bool {
+        self.backbuffer.is_some()
+    }
+
+    /// Render a batch and present it to the canvas
+    pub fn render(
+        &mut self,
+        renderer: &mut GpuRenderer,
+        batch: &PrimitiveBatch,
+        clear_color: [f64; 4],
+    ) -> Result<(), wgpu::SurfaceError> {
+        let frame = match self.surface.get_current_texture() {
+            Ok(frame) => frame,
+            Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
+                self.surface.configure(renderer.device(), &self.config);
+                self.surface.get_current_texture()?
+            }
+            Err(e) => return Err(e),
+        };
+
+        match &mut self.backbuffer {
+            Some(backbuffer) => {
+                renderer.render_with_clear(backbuffer.write_target(), batch, clear_color);
+
+                let mut encoder =
+                    renderer
+                        .device()
+                        .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                            label: Some("Blinc Canvas Present"),
+                        });
+                backbuffer.copy_to_surface(&mut encoder, &frame.texture);
+                renderer.queue().submit(std::iter::once(encoder.finish()));
+                backbuffer.swap();
+            }
+            None => {
+                let view = frame
+                    .texture
+                    .create_view(&wgpu::TextureViewDescriptor::default());
+                renderer.render_with_clear(&view, batch, clear_color);
+            }
+        }
+
+        frame.present();
+        Ok(())
+    }
+}
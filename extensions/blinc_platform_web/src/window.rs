@@ -0,0 +1,273 @@
+//! Web window implementation
+//!
+//! Wraps an HTML canvas element. The canvas' CSS size is the logical window
+//! size; its `width`/`height` attributes are the physical backbuffer size,
+//! kept in sync with `devicePixelRatio` so rendering stays sharp on HiDPI
+//! displays and when the page is zoomed.
+
+use std::cell::Cell;
+
+use blinc_platform::{Cursor, Window, WindowEvent};
+
+/// Largest backbuffer edge we will request (WebGPU/WebGL2 guaranteed minimum)
+pub const MAX_BACKBUFFER_DIMENSION: u32 = 8192;
+
+/// Compute the physical backbuffer size for a canvas
+///
+/// Rounds `css * device_pixel_ratio` and clamps each edge to
+/// `1..=MAX_BACKBUFFER_DIMENSION` so surface configuration never fails on a
+/// collapsed or gigantic canvas.
+pub fn backbuffer_size(css_width: f32, css_height: f32, device_pixel_ratio: f64) -> (u32, u32) {
+    let edge = |css: f32| {
+        let physical = (css as f64 * device_pixel_ratio).round();
+        (physical.max(1.0) as u32).min(MAX_BACKBUFFER_DIMENSION)
+    };
+    (edge(css_width), edge(css_height))
+}
+
+/// Browser window backed by an HTML canvas
+pub struct WebWindow {
+    /// Canvas element used as the render surface
+    #[cfg(target_arch = "wasm32")]
+    canvas: web_sys::HtmlCanvasElement,
+    /// Canvas size in CSS pixels
+    css_size: Cell<(f32, f32)>,
+    /// Current `devicePixelRatio`
+    scale_factor: Cell<f64>,
+    /// Backbuffer size in physical pixels
+    physical_size: Cell<(u32, u32)>,
+    focused: Cell<bool>,
+    visible: Cell<bool>,
+    redraw_requested: Cell<bool>,
+}
+
+// SAFETY: wasm32 is single-threaded; the window never leaves the main thread
+unsafe impl Send for WebWindow {}
+
+impl WebWindow {
+    /// Create a detached window with the given CSS size and pixel ratio
+    ///
+    /// Used for tests and for computing sizes off the main thread.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn new(css_width: f32, css_height: f32, device_pixel_ratio: f64) -> Self {
+        Self {
+            css_size: Cell::new((css_width, css_height)),
+            scale_factor: Cell::new(device_pixel_ratio),
+            physical_size: Cell::new(backbuffer_size(css_width, css_height, device_pixel_ratio)),
+            focused: Cell::new(true),
+            visible: Cell::new(true),
+            redraw_requested: Cell::new(true),
+        }
+    }
+
+    /// Wrap an existing canvas element
+    #[cfg(target_arch = "wasm32")]
+    pub fn from_canvas(canvas: web_sys::HtmlCanvasElement) -> Self {
+        let window = Self {
+            canvas,
+            css_size: Cell::new((0.0, 0.0)),
+            scale_factor: Cell::new(1.0),
+            physical_size: Cell::new((0, 0)),
+            focused: Cell::new(true),
+            visible: Cell::new(true),
+            redraw_requested: Cell::new(true),
+        };
+        // Make the canvas focusable so it receives keyboard events
+        window.canvas.set_tab_index(0);
+        let _ = window.sync_canvas_size();
+        window
+    }
+
+    /// Look up a canvas by element id and wrap it
+    #[cfg(target_arch = "wasm32")]
+    pub fn from_canvas_id(id: &str) -> Result<Self, blinc_platform::PlatformError> {
+        use wasm_bindgen::JsCast;
+
+        let canvas = web_sys::window()
+            .and_then(|w| w.document())
+            .and_then(|d| d.get_element_by_id(id))
+            .ok_or_else(|| {
+                blinc_platform::PlatformError::WindowCreation(format!("no element #{}", id))
+            })?
+            .dyn_into::<web_sys::HtmlCanvasElement>()
+            .map_err(|_| {
+                blinc_platform::PlatformError::WindowCreation(format!("#{} is not a canvas", id))
+            })?;
+        Ok(Self::from_canvas(canvas))
+    }
+
+    /// The canvas element backing this window
+    #[cfg(target_arch = "wasm32")]
+    pub fn canvas(&self) -> &web_sys::HtmlCanvasElement {
+        &self.canvas
+    }
+
+    /// Re-read the canvas' CSS size and `devicePixelRatio` from the DOM
+    ///
+    /// Returns the window events produced by the change (see [`Self::update_size`]).
+    #[cfg(target_arch = "wasm32")]
+    pub fn sync_canvas_size(&self) -> Vec<WindowEvent> {
+        let rect = self.canvas.get_bounding_client_rect();
+        let dpr = web_sys::window()
+            .map(|w| w.device_pixel_ratio())
+            .unwrap_or(1.0);
+        self.update_size(rect.width() as f32, rect.height() as f32, dpr)
+    }
+
+    /// Apply a new CSS size and pixel ratio
+    ///
+    /// Resizes the backbuffer when the physical size changes and returns the
+    /// resulting `ScaleFactorChanged` / `Resized` events (in that order) so the
+    /// caller can reconfigure the surface.
+    pub fn update_size(
+        &self,
+        css_width: f32,
+        css_height: f32,
+        device_pixel_ratio: f64,
+    ) -> Vec<WindowEvent> {
+        let mut events = Vec::new();
+        self.css_size.set((css_width, css_height));
+
+        if (self.scale_factor.get() - device_pixel_ratio).abs() > f64::EPSILON {
+            self.scale_factor.set(device_pixel_ratio);
+            events.push(WindowEvent::ScaleFactorChanged {
+                scale_factor: device_pixel_ratio,
+            });
+        }
+
+        let physical = backbuffer_size(css_width, css_height, device_pixel_ratio);
+        if physical != self.physical_size.get() {
+            self.physical_size.set(physical);
+            #[cfg(target_arch = "wasm32")]
+            {
+                self.canvas.set_width(physical.0);
+                self.canvas.set_height(physical.1);
+            }
+            events.push(WindowEvent::Resized {
+                width: physical.0,
+                height: physical.1,
+            });
+            self.redraw_requested.set(true);
+        }
+
+        events
+    }
+
+    /// Update focus state from `focus`/`blur` events
+    pub fn set_focused(&self, focused: bool) {
+        self.focused.set(focused);
+    }
+
+    /// Update visibility from `visibilitychange` events
+    pub fn set_visible(&self, visible: bool) {
+        self.visible.set(visible);
+    }
+
+    /// Check if a redraw was requested and clear the flag
+    pub fn take_redraw_request(&self) -> bool {
+        self.redraw_requested.replace(false)
+    }
+}
+
+/// CSS `cursor` value for a Blinc cursor
+pub fn css_cursor(cursor: Cursor) -> &'static str {
+    match cursor {
+        Cursor::Default => "default",
+        Cursor::Pointer => "pointer",
+        Cursor::Text => "text",
+        Cursor::Crosshair => "crosshair",
+        Cursor::Move => "move",
+        Cursor::NotAllowed => "not-allowed",
+        Cursor::ResizeNS => "ns-resize",
+        Cursor::ResizeEW => "ew-resize",
+        Cursor::ResizeNESW => "nesw-resize",
+        Cursor::ResizeNWSE => "nwse-resize",
+        Cursor::Grab => "grab",
+        Cursor::Grabbing => "grabbing",
+        Cursor::Wait => "wait",
+        Cursor::Progress => "progress",
+        Cursor::None => "none",
+    }
+}
+
+impl Window for WebWindow {
+    fn size(&self) -> (u32, u32) {
+        self.physical_size.get()
+    }
+
+    fn logical_size(&self) -> (f32, f32) {
+        self.css_size.get()
+    }
+
+    fn scale_factor(&self) -> f64 {
+        self.scale_factor.get()
+    }
+
+    fn set_title(&self, _title: &str) {
+        #[cfg(target_arch = "wasm32")]
+        if let Some(document) = web_sys::window().and_then(|w| w.document()) {
+            document.set_title(_title);
+        }
+    }
+
+    fn set_cursor(&self, _cursor: Cursor) {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = self
+                .canvas
+                .style()
+                .set_property("cursor", css_cursor(_cursor));
+        }
+    }
+
+    fn request_redraw(&self) {
+        self.redraw_requested.set(true);
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused.get()
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible.get()
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backbuffer_tracks_device_pixel_ratio() {
+        let window = WebWindow::new(400.0, 300.0, 1.0);
+        assert_eq!(window.size(), (400, 300));
+
+        // Moving to a HiDPI display doubles the backbuffer, not the layout size
+        let events = window.update_size(400.0, 300.0, 2.0);
+        assert!(matches!(
+            events[0],
+            WindowEvent::ScaleFactorChanged { scale_factor } if scale_factor == 2.0
+        ));
+        assert!(matches!(
+            events[1],
+            WindowEvent::Resized {
+                width: 800,
+                height: 600
+            }
+        ));
+        assert_eq!(window.logical_size(), (400.0, 300.0));
+        assert!(window.take_redraw_request());
+
+        // Same size again produces no events
+        assert!(window.update_size(400.0, 300.0, 2.0).is_empty());
+    }
+
+    #[test]
+    fn test_backbuffer_is_clamped() {
+        assert_eq!(backbuffer_size(0.0, 0.0, 2.0), (1, 1));
+        assert_eq!(
+            backbuffer_size(10_000.0, 50.0, 1.5),
+            (MAX_BACKBUFFER_DIMENSION, 75)
+        );
+    }
+}